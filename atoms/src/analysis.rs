@@ -0,0 +1,142 @@
+//! Structural analysis: molecule detection, dipole moments, and RDFs
+//!
+//! Turns the molecular `Simulation` into something measurable without
+//! touching the force code in `physics.rs`: which atoms currently form a
+//! molecule, how polarized each one is, and how ordered the whole box is.
+
+use glam::Vec2;
+use std::collections::HashMap;
+use std::f32::consts::PI;
+
+use crate::physics::{minimum_image, Element, Simulation};
+
+/// Follow `parent` links to the representative of `i`'s set, flattening the
+/// path as it goes (standard union-find with path compression)
+fn find(parent: &mut [usize], i: usize) -> usize {
+    if parent[i] != i {
+        parent[i] = find(parent, parent[i]);
+    }
+    parent[i]
+}
+
+impl Simulation {
+    /// Connected components of the bond graph — each inner vector holds the
+    /// atom indices making up one molecule, found via union-find over
+    /// `self.bonds`
+    pub fn molecules(&self) -> Vec<Vec<usize>> {
+        let n = self.atoms.len();
+        let mut parent: Vec<usize> = (0..n).collect();
+
+        for bond in &self.bonds {
+            let root_a = find(&mut parent, bond.atom_a);
+            let root_b = find(&mut parent, bond.atom_b);
+            if root_a != root_b {
+                parent[root_a] = root_b;
+            }
+        }
+
+        let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+        for i in 0..n {
+            let root = find(&mut parent, i);
+            groups.entry(root).or_default().push(i);
+        }
+
+        groups.into_values().collect()
+    }
+
+    /// Charge-weighted dipole moment of a molecule, relative to its
+    /// (unweighted) center of mass: `Σ q_i * (r_i - r_com)`
+    pub fn dipole_moment(&self, molecule: &[usize]) -> Vec2 {
+        if molecule.is_empty() {
+            return Vec2::ZERO;
+        }
+
+        let mut com = Vec2::ZERO;
+        for &i in molecule {
+            com += self.atoms[i].position;
+        }
+        com /= molecule.len() as f32;
+
+        let mut dipole = Vec2::ZERO;
+        for &i in molecule {
+            dipole += self.atoms[i].charge() * (self.atoms[i].position - com);
+        }
+        dipole
+    }
+
+    /// Magnitude of the summed dipole moment over every molecule currently
+    /// in the box
+    pub fn total_dipole(&self) -> f32 {
+        let mut total = Vec2::ZERO;
+        for molecule in self.molecules() {
+            total += self.dipole_moment(&molecule);
+        }
+        total.length()
+    }
+
+    /// Radial distribution function g(r) between two element types:
+    /// histograms pairwise distances into `bins` buckets up to `r_max` and
+    /// normalizes each bin by the area of its annular shell, so a
+    /// structureless gas reads as flat and liquid/solid ordering in
+    /// `init_water` or `init_salt` shows up as peaks
+    pub fn radial_distribution(
+        &self,
+        element_a: Element,
+        element_b: Element,
+        bins: usize,
+        r_max: f32,
+    ) -> Vec<f32> {
+        let mut histogram = vec![0.0_f32; bins];
+        if bins == 0 || r_max <= 0.0 {
+            return histogram;
+        }
+
+        let group_a: Vec<usize> = self
+            .atoms
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.element == element_a)
+            .map(|(i, _)| i)
+            .collect();
+        let group_b: Vec<usize> = self
+            .atoms
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.element == element_b)
+            .map(|(i, _)| i)
+            .collect();
+
+        let bin_width = r_max / bins as f32;
+        let mut pair_count = 0usize;
+
+        for &i in &group_a {
+            for &j in &group_b {
+                if i == j {
+                    continue;
+                }
+                let r = minimum_image(self.atoms[j].position - self.atoms[i].position, self.box_size);
+                let dist = r.length();
+                if dist < r_max {
+                    let bin = ((dist / bin_width) as usize).min(bins - 1);
+                    histogram[bin] += 1.0;
+                    pair_count += 1;
+                }
+            }
+        }
+
+        if pair_count == 0 {
+            return histogram;
+        }
+
+        // Normalize each bin by the area of its annular shell (2D shell
+        // area scales with r instead of r² as in 3D)
+        for (bin, count) in histogram.iter_mut().enumerate() {
+            let r_inner = bin as f32 * bin_width;
+            let r_outer = r_inner + bin_width;
+            let shell_area = PI * (r_outer * r_outer - r_inner * r_inner);
+            *count /= shell_area.max(1e-6) * pair_count as f32;
+        }
+
+        histogram
+    }
+}
@@ -0,0 +1,183 @@
+//! Barnes-Hut tree for approximate Coulomb forces
+//!
+//! Computing Coulomb interactions with an O(n²) pairwise loop stops scaling
+//! well past `MAX_ATOMS`. This builds a quadtree
+//! over charged atoms each step and approximates the force on each atom from
+//! distant clusters as a single monopole (total charge at the cluster's
+//! center of charge), falling back to exact pairwise evaluation for nearby
+//! atoms — the classic Barnes-Hut O(n log n) approximation.
+
+use glam::Vec2;
+
+use crate::physics::{minimum_image, K_COULOMB};
+
+/// Opening-angle criterion: a node is treated as a single point if
+/// (node size / distance) < THETA
+const THETA: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy)]
+struct Body {
+    position: Vec2,
+    charge: f32,
+    index: usize,
+}
+
+enum Node {
+    Empty,
+    Leaf(Body),
+    Internal {
+        center: Vec2,
+        half_size: f32,
+        total_charge: f32,
+        center_of_charge: Vec2,
+        children: Box<[Node; 4]>,
+    },
+}
+
+/// A Barnes-Hut quadtree built over charged atoms for one simulation step.
+/// The tree's own spatial partitioning is built from raw (unwrapped)
+/// positions — periodicity is applied only where forces are actually
+/// evaluated, via `box_size`.
+pub struct BarnesHutTree {
+    root: Node,
+    box_size: Option<Vec2>,
+}
+
+impl BarnesHutTree {
+    /// Build a tree from (position, charge) pairs, skipping neutral atoms.
+    /// `box_size` enables the minimum-image convention in `coulomb_force`
+    /// for simulations running in a periodic box.
+    pub fn build(positions: &[Vec2], charges: &[f32], box_size: Option<Vec2>) -> Self {
+        let bodies: Vec<Body> = positions
+            .iter()
+            .zip(charges.iter())
+            .enumerate()
+            .filter(|(_, (_, &q))| q.abs() > 0.01)
+            .map(|(index, (&position, &charge))| Body { position, charge, index })
+            .collect();
+
+        if bodies.is_empty() {
+            return Self { root: Node::Empty, box_size };
+        }
+
+        let (min, max) = bodies.iter().fold(
+            (bodies[0].position, bodies[0].position),
+            |(min, max), b| (min.min(b.position), max.max(b.position)),
+        );
+        let center = (min + max) * 0.5;
+        let half_size = (max - min).max_element().max(1.0) * 0.5 + 0.1;
+
+        let mut root = Node::Empty;
+        for body in bodies {
+            insert(&mut root, body, center, half_size);
+        }
+
+        Self { root, box_size }
+    }
+
+    /// Approximate Coulomb force on the atom at `position` with `charge`,
+    /// excluding self-interaction via `self_index`
+    pub fn coulomb_force(&self, self_index: usize, position: Vec2, charge: f32) -> Vec2 {
+        accumulate_force(&self.root, self_index, position, charge, self.box_size)
+    }
+}
+
+fn quadrant(center: Vec2, point: Vec2) -> usize {
+    match (point.x >= center.x, point.y >= center.y) {
+        (false, false) => 0,
+        (true, false) => 1,
+        (false, true) => 2,
+        (true, true) => 3,
+    }
+}
+
+fn child_center(center: Vec2, half_size: f32, quadrant: usize) -> Vec2 {
+    let offset = half_size * 0.5;
+    match quadrant {
+        0 => center + Vec2::new(-offset, -offset),
+        1 => center + Vec2::new(offset, -offset),
+        2 => center + Vec2::new(-offset, offset),
+        _ => center + Vec2::new(offset, offset),
+    }
+}
+
+fn insert(node: &mut Node, body: Body, center: Vec2, half_size: f32) {
+    match node {
+        Node::Empty => *node = Node::Leaf(body),
+        Node::Leaf(existing) => {
+            let existing = *existing;
+            let mut children: [Node; 4] = [Node::Empty, Node::Empty, Node::Empty, Node::Empty];
+
+            let q1 = quadrant(center, existing.position);
+            insert(&mut children[q1], existing, child_center(center, half_size, q1), half_size * 0.5);
+            let q2 = quadrant(center, body.position);
+            insert(&mut children[q2], body, child_center(center, half_size, q2), half_size * 0.5);
+
+            *node = Node::Internal {
+                center,
+                half_size,
+                total_charge: existing.charge + body.charge,
+                center_of_charge: weighted_center(existing, body),
+                children: Box::new(children),
+            };
+        }
+        Node::Internal { center, half_size, total_charge, center_of_charge, children } => {
+            let new_total = *total_charge + body.charge;
+            if new_total.abs() >= 1e-9 {
+                *center_of_charge = (*center_of_charge * *total_charge + body.position * body.charge) / new_total;
+            }
+            // Net charge crossing zero: the charge-weighted center is
+            // undefined, so keep the previous center_of_charge rather than
+            // divide by ~0 and poison the node with an Infinity/NaN
+            *total_charge = new_total;
+
+            let q = quadrant(*center, body.position);
+            let child_c = child_center(*center, *half_size, q);
+            insert(&mut children[q], body, child_c, *half_size * 0.5);
+        }
+    }
+}
+
+fn weighted_center(a: Body, b: Body) -> Vec2 {
+    if (a.charge + b.charge).abs() < 1e-9 {
+        (a.position + b.position) * 0.5
+    } else {
+        (a.position * a.charge + b.position * b.charge) / (a.charge + b.charge)
+    }
+}
+
+fn accumulate_force(
+    node: &Node,
+    self_index: usize,
+    position: Vec2,
+    charge: f32,
+    box_size: Option<Vec2>,
+) -> Vec2 {
+    match node {
+        Node::Empty => Vec2::ZERO,
+        Node::Leaf(body) => {
+            if body.index == self_index {
+                return Vec2::ZERO;
+            }
+            coulomb_pair_force(position, charge, body.position, body.charge, box_size)
+        }
+        Node::Internal { center_of_charge, total_charge, half_size, children, .. } => {
+            let dist = (position - *center_of_charge).length();
+            if dist > 1e-6 && (2.0 * *half_size / dist) < THETA {
+                coulomb_pair_force(position, charge, *center_of_charge, *total_charge, box_size)
+            } else {
+                children
+                    .iter()
+                    .map(|c| accumulate_force(c, self_index, position, charge, box_size))
+                    .sum()
+            }
+        }
+    }
+}
+
+fn coulomb_pair_force(pos_a: Vec2, q_a: f32, pos_b: Vec2, q_b: f32, box_size: Option<Vec2>) -> Vec2 {
+    let r = minimum_image(pos_a - pos_b, box_size);
+    let dist = r.length().max(0.5);
+    let force_mag = K_COULOMB * q_a * q_b / (dist * dist);
+    (r / dist) * force_mag
+}
@@ -0,0 +1,67 @@
+//! Live energy and temperature plots
+//!
+//! Tracks a rolling window of the simulation's kinetic energy and
+//! instantaneous temperature and draws them as `egui_plot` line charts, so
+//! drift (e.g. from the damping term) is visible instead of only being
+//! readable from the single-frame status bar numbers.
+
+use crate::physics::Simulation;
+use egui::Context;
+use egui_plot::{Line, Plot, PlotPoints};
+use std::collections::VecDeque;
+
+/// Maximum number of samples kept for the rolling plot
+const HISTORY_LEN: usize = 600;
+
+#[derive(Debug, Default)]
+pub struct EnergyHistory {
+    time: f32,
+    energy: VecDeque<[f64; 2]>,
+    temperature: VecDeque<[f64; 2]>,
+}
+
+impl EnergyHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sample the simulation's current energy/temperature, advancing time by `dt`
+    pub fn record(&mut self, sim: &Simulation, dt: f32) {
+        self.time += dt;
+
+        self.energy.push_back([self.time as f64, sim.kinetic_energy() as f64]);
+        self.temperature.push_back([self.time as f64, sim.instantaneous_temperature() as f64]);
+
+        while self.energy.len() > HISTORY_LEN {
+            self.energy.pop_front();
+        }
+        while self.temperature.len() > HISTORY_LEN {
+            self.temperature.pop_front();
+        }
+    }
+
+    /// Draw the rolling energy/temperature plots in a bottom panel
+    pub fn draw(&self, ctx: &Context) {
+        egui::TopBottomPanel::bottom("energy_plot").min_height(150.0).show(ctx, |ui| {
+            ui.columns(2, |cols| {
+                cols[0].label("Kinetic Energy");
+                Plot::new("energy_plot_inner")
+                    .height(120.0)
+                    .show(&mut cols[0], |plot_ui| {
+                        plot_ui.line(Line::new(PlotPoints::from(
+                            self.energy.iter().copied().collect::<Vec<_>>(),
+                        )));
+                    });
+
+                cols[1].label("Temperature");
+                Plot::new("temperature_plot_inner")
+                    .height(120.0)
+                    .show(&mut cols[1], |plot_ui| {
+                        plot_ui.line(Line::new(PlotPoints::from(
+                            self.temperature.iter().copied().collect::<Vec<_>>(),
+                        )));
+                    });
+            });
+        });
+    }
+}
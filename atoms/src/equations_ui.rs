@@ -5,6 +5,7 @@
 use egui::{Context, RichText, Color32};
 
 /// An equation with its name and formula
+#[derive(Clone, Copy)]
 pub struct Equation {
     pub name: &'static str,
     pub formula: &'static str,
@@ -104,3 +105,35 @@ pub const ATOMS_VARIABLES: &[(&str, &str)] = &[
     ("T", "Temperature"),
     ("m", "Atomic mass"),
 ];
+
+pub const QUANTUM_POTENTIAL_EQUATIONS: &[Equation] = &[
+    Equation {
+        name: "Mie Potential",
+        formula: "V(r) = C·ε[(σ/r)^λr - (σ/r)^λa]",
+        description: "Generalized LJ; LJ is the λr=12, λa=6 case",
+    },
+    Equation {
+        name: "Mie Prefactor",
+        formula: "C = (λr/(λr-λa))·(λr/λa)^(λa/(λr-λa))",
+        description: "Normalizes the potential minimum to -ε",
+    },
+    Equation {
+        name: "Feynman-Hibbs Effective Potential",
+        formula: "V_FH(r) = V(r) + (ℏ²β/24μ)[V″(r) + (2/r)V′(r)]",
+        description: "1st-order quantum correction for light atoms (H₂, He, Ne)",
+    },
+    Equation {
+        name: "de Boer Parameter",
+        formula: "Λ = ℏ/(σ√(με))",
+        description: "Quantum delocalization vs. potential length scale",
+    },
+];
+
+pub const QUANTUM_POTENTIAL_VARIABLES: &[(&str, &str)] = &[
+    ("λr", "Mie repulsive exponent"),
+    ("λa", "Mie attractive exponent"),
+    ("β", "Inverse thermal energy, 1/(k_B·T)"),
+    ("μ", "Reduced mass of the atom pair"),
+    ("ℏ", "Reduced Planck constant"),
+    ("Λ", "de Boer quantum parameter"),
+];
@@ -20,12 +20,21 @@
 mod physics;
 mod renderer;
 mod equations_ui;
+mod scripting;
+mod diagnostics;
+mod scenes;
+mod barnes_hut;
+mod potential;
+mod analysis;
 
 use common::{Camera2D, GraphicsContext};
 use glam::Vec2;
 use physics::{Element, Simulation};
 use renderer::Renderer;
-use equations_ui::{draw_equations_sidebar, ATOMS_EQUATIONS, ATOMS_VARIABLES};
+use equations_ui::{
+    draw_equations_sidebar, ATOMS_EQUATIONS, ATOMS_VARIABLES, QUANTUM_POTENTIAL_EQUATIONS,
+    QUANTUM_POTENTIAL_VARIABLES,
+};
 use winit::{
     event::{ElementState, Event, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
@@ -50,6 +59,9 @@ struct App {
     current_element: Element,
     modifiers: ModifiersState,
     egui: EguiState,
+    energy_history: diagnostics::EnergyHistory,
+    scene_manager: scenes::SceneManager,
+    recorder: common::capture::FrameRecorder,
 }
 
 impl App {
@@ -90,6 +102,9 @@ impl App {
                 state: egui_state,
                 renderer: egui_renderer,
             },
+            energy_history: diagnostics::EnergyHistory::new(),
+            scene_manager: scenes::SceneManager::new(scenes::Preset::Water),
+            recorder: common::capture::FrameRecorder::new("recordings/atoms"),
         }
     }
 
@@ -98,6 +113,37 @@ impl App {
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
     }
 
+    /// de Boer quantum parameter for an H-H pair, using the same LJ
+    /// well depth/diameter `physics::step` uses for short-range interactions.
+    /// Large values (H₂ is a classic case) are where the Feynman-Hibbs
+    /// correction in `potential::mie_fh_potential` starts to matter.
+    fn hydrogen_de_boer_parameter(&self) -> f32 {
+        let sigma = Element::Hydrogen.radius() * 2.0 * physics::LJ_SIGMA;
+        let mie = potential::MieParams::lennard_jones(physics::LJ_EPSILON, sigma);
+        let reduced_mass = Element::Hydrogen.mass() / 2.0;
+        mie.de_boer_parameter(reduced_mass)
+    }
+
+    /// How much the first-order Feynman-Hibbs correction shifts the H-H
+    /// potential at the equilibrium bond distance, at the simulation's
+    /// current temperature
+    fn hydrogen_fh_correction(&self) -> f32 {
+        let sigma = Element::Hydrogen.radius() * 2.0 * physics::LJ_SIGMA;
+        let mie = potential::MieParams::lennard_jones(physics::LJ_EPSILON, sigma);
+        let reduced_mass = Element::Hydrogen.mass() / 2.0;
+        let r = Element::Hydrogen.radius() * 2.0;
+
+        let classical = mie.potential(r);
+        let corrected = potential::mie_fh_potential(
+            r,
+            &mie,
+            self.simulation.temperature,
+            reduced_mass,
+            potential::FhOrder::First,
+        );
+        corrected - classical
+    }
+
     fn update(&mut self, dt: f32) {
         if !self.paused {
             // Substep for stability
@@ -106,6 +152,7 @@ impl App {
             for _ in 0..substeps {
                 self.simulation.step(sub_dt);
             }
+            self.energy_history.record(&self.simulation, dt);
         }
     }
 
@@ -122,12 +169,17 @@ impl App {
         // Build egui UI
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
         let full_output = self.egui.ctx.run(raw_input, |ctx| {
-            draw_equations_sidebar(
-                ctx,
-                "Molecular Dynamics",
-                ATOMS_EQUATIONS,
-                ATOMS_VARIABLES,
-            );
+            let equations: Vec<_> = ATOMS_EQUATIONS
+                .iter()
+                .copied()
+                .chain(QUANTUM_POTENTIAL_EQUATIONS.iter().copied())
+                .collect();
+            let variables: Vec<_> = ATOMS_VARIABLES
+                .iter()
+                .copied()
+                .chain(QUANTUM_POTENTIAL_VARIABLES.iter().copied())
+                .collect();
+            draw_equations_sidebar(ctx, "Molecular Dynamics", &equations, &variables);
 
             egui::TopBottomPanel::top("status").show(ctx, |ui| {
                 ui.horizontal(|ui| {
@@ -139,6 +191,13 @@ impl App {
                     ui.separator();
                     ui.label(format!("Element: {:?}", self.current_element));
                     ui.separator();
+                    ui.label(format!("Λ(H-H) = {:.3}", self.hydrogen_de_boer_parameter()));
+                    ui.separator();
+                    ui.label(format!(
+                        "FH ΔV(H-H) = {:.4}",
+                        self.hydrogen_fh_correction()
+                    ));
+                    ui.separator();
                     if self.paused {
                         ui.label(egui::RichText::new("PAUSED").color(egui::Color32::YELLOW));
                     } else {
@@ -146,6 +205,8 @@ impl App {
                     }
                 });
             });
+
+            self.energy_history.draw(ctx);
         });
 
         self.egui.state.handle_platform_output(&self.ctx.window, full_output.platform_output);
@@ -198,6 +259,16 @@ impl App {
         }
 
         self.ctx.queue.submit(std::iter::once(encoder.finish()));
+
+        if self.recorder.recording {
+            if let Err(err) = self
+                .recorder
+                .capture_if_recording(&self.ctx.device, &self.ctx.queue, &output.texture)
+            {
+                log::warn!("frame capture failed: {err}");
+            }
+        }
+
         output.present();
 
         Ok(())
@@ -223,19 +294,22 @@ impl App {
             KeyCode::Space => self.paused = !self.paused,
             KeyCode::KeyG => self.show_grid = !self.show_grid,
             KeyCode::KeyR => {
-                self.simulation.clear();
+                self.scene_manager.reload(&mut self.simulation);
             }
             KeyCode::Digit1 => {
-                self.simulation.init_water(10);
+                self.scene_manager.switch_to(&mut self.simulation, scenes::Preset::Water);
             }
             KeyCode::Digit2 => {
-                self.simulation.init_salt(15);
+                self.scene_manager.switch_to(&mut self.simulation, scenes::Preset::Salt);
             }
             KeyCode::Digit3 => {
-                self.simulation.init_organic(8);
+                self.scene_manager.switch_to(&mut self.simulation, scenes::Preset::Organic);
             }
             KeyCode::Digit4 => {
-                self.simulation.init_random(50);
+                self.scene_manager.switch_to(&mut self.simulation, scenes::Preset::Random);
+            }
+            KeyCode::Tab => {
+                self.scene_manager.cycle_next(&mut self.simulation);
             }
             KeyCode::KeyH => self.current_element = Element::Hydrogen,
             KeyCode::KeyC => self.current_element = Element::Carbon,
@@ -252,6 +326,13 @@ impl App {
             KeyCode::ArrowDown | KeyCode::KeyS => self.camera.position.y -= self.camera.zoom * 0.1,
             KeyCode::ArrowLeft | KeyCode::KeyA => self.camera.position.x -= self.camera.zoom * 0.1,
             KeyCode::ArrowRight | KeyCode::KeyD => self.camera.position.x += self.camera.zoom * 0.1,
+            KeyCode::KeyV => {
+                if self.recorder.recording {
+                    self.recorder.stop();
+                } else {
+                    self.recorder.start();
+                }
+            }
             _ => {}
         }
     }
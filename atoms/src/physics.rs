@@ -20,6 +20,25 @@ pub const LJ_SIGMA: f32 = 0.5;
 pub const BOND_DISTANCE: f32 = 1.2;
 pub const BOND_STRENGTH: f32 = 50.0;
 
+/// Angle-bending stiffness for the harmonic i-j-k bond-angle potential
+pub const K_ANGLE: f32 = 30.0;
+
+/// Minimum-image displacement: `delta` wrapped into `[-box_size/2,
+/// box_size/2]` on each axis when a periodic box is active, so a pair near
+/// opposite edges of the box resolves to their true (short) periodic
+/// separation instead of the raw one
+pub(crate) fn minimum_image(delta: Vec2, box_size: Option<Vec2>) -> Vec2 {
+    match box_size {
+        Some(size) => delta - size * (delta / size).round(),
+        None => delta,
+    }
+}
+
+fn wrap_axis(x: f32, size: f32) -> f32 {
+    let half = size * 0.5;
+    (x + half).rem_euclid(size) - half
+}
+
 /// Element types with their properties
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Element {
@@ -87,6 +106,21 @@ impl Element {
         }
     }
 
+    /// Equilibrium bond angle around this element when it's the central
+    /// atom of a bonded triple, used by the angle-bending force in
+    /// `Simulation::accelerations_at`
+    pub fn equilibrium_angle(&self) -> f32 {
+        match self {
+            Element::Carbon => 109.5_f32.to_radians(),   // tetrahedral (sp3)
+            Element::Nitrogen => 107.0_f32.to_radians(), // pyramidal (sp3, one lone pair)
+            Element::Oxygen => 104.5_f32.to_radians(),   // bent (sp3, two lone pairs)
+            // Hydrogen, Sodium, and Chlorine only ever form a single bond
+            // (`max_bonds() == 1`), so they're never the vertex of a bonded
+            // triple and this value is never read
+            Element::Hydrogen | Element::Sodium | Element::Chlorine => PI,
+        }
+    }
+
     pub fn symbol(&self) -> &'static str {
         match self {
             Element::Hydrogen => "H",
@@ -159,13 +193,56 @@ impl Bond {
     }
 }
 
+/// Which scheme [`Simulation::step`] uses to advance positions and
+/// velocities. Semi-implicit Euler (a single force evaluation per step)
+/// drains or pumps energy over long runs, drifting the measured
+/// temperature; `VelocityVerlet` and `Rk4` trade extra force evaluations
+/// per step for much better energy conservation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Integrator {
+    Euler,
+    VelocityVerlet,
+    Rk4,
+}
+
+/// How `Simulation::step` controls kinetic energy each frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Thermostat {
+    /// No velocity rescaling; energy is whatever the integrator conserves
+    None,
+    /// Multiply every velocity by the fixed `damping` factor each step
+    Damping,
+    /// Berendsen velocity rescaling toward `temperature` over a coupling
+    /// time `tau`
+    Berendsen { tau: f32 },
+}
+
 /// The physics simulation
 pub struct Simulation {
     pub atoms: Vec<Atom>,
     pub bonds: Vec<Bond>,
     pub temperature: f32,
     pub damping: f32,
+    pub integrator: Integrator,
+    pub thermostat: Thermostat,
+    /// When set, the simulation runs in a periodic box of this size
+    /// instead of reflecting soft walls: positions wrap back into
+    /// `[-box_size/2, box_size/2]` after integration, and every pairwise
+    /// distance uses the minimum-image convention
+    pub box_size: Option<Vec2>,
+    /// Maximum H···Z distance a donor-hydrogen/acceptor pair is still
+    /// considered for the hydrogen-bond force
+    pub hbond_cutoff: f32,
+    /// Exponent `n` in the `cos^n(angle)` angular falloff of the
+    /// hydrogen-bond force — higher values demand a more linear X-H···Z
+    /// arrangement before the bond contributes much strength
+    pub hbond_angular_exponent: f32,
+    /// Overall strength of the hydrogen-bond attraction
+    pub hbond_strength: f32,
     next_id: usize,
+    /// Accelerations from the previous `VelocityVerlet` step, reused as
+    /// `a_old` in `v += 0.5*(a_old + a_new)*dt`
+    last_accelerations: Vec<Vec2>,
 }
 
 impl Simulation {
@@ -175,8 +252,34 @@ impl Simulation {
             bonds: Vec::new(),
             temperature: 300.0, // Room temperature in Kelvin
             damping: 0.98,
+            integrator: Integrator::VelocityVerlet,
+            thermostat: Thermostat::Damping,
+            box_size: None,
+            hbond_cutoff: 2.5,
+            hbond_angular_exponent: 4.0,
+            hbond_strength: 20.0,
             next_id: 0,
+            last_accelerations: Vec::new(),
+        }
+    }
+
+    /// Total kinetic energy of all atoms: Σ ½mv²
+    pub fn kinetic_energy(&self) -> f32 {
+        self.atoms
+            .iter()
+            .map(|a| 0.5 * a.mass() * a.velocity.length_squared())
+            .sum()
+    }
+
+    /// Instantaneous temperature from the equipartition theorem:
+    /// KE = (dof/2) N k_B T, with 2 degrees of freedom per atom in 2D and
+    /// k_B = 1 (scaled units)
+    pub fn instantaneous_temperature(&self) -> f32 {
+        let n = self.atoms.len();
+        if n == 0 {
+            return 0.0;
         }
+        self.kinetic_energy() / (n as f32)
     }
 
     pub fn add_atom(&mut self, element: Element, position: Vec2) -> usize {
@@ -284,6 +387,26 @@ impl Simulation {
         self.next_id = 0;
     }
 
+    /// Recenter every atom so the simulation's center of mass sits at the
+    /// origin — the center of the periodic box, when one is set
+    pub fn reposition_center_of_mass(&mut self) {
+        if self.atoms.is_empty() {
+            return;
+        }
+
+        let mut com = Vec2::ZERO;
+        let mut total_mass = 0.0;
+        for atom in &self.atoms {
+            com += atom.position * atom.mass();
+            total_mass += atom.mass();
+        }
+        com /= total_mass;
+
+        for atom in self.atoms.iter_mut() {
+            atom.position -= com;
+        }
+    }
+
     pub fn create_bond(&mut self, a: usize, b: usize) -> bool {
         // Check if bond already exists
         for bond in &self.bonds {
@@ -307,32 +430,31 @@ impl Simulation {
         true
     }
 
-    /// Step the simulation forward
-    pub fn step(&mut self, dt: f32) {
-        let n = self.atoms.len();
-        if n == 0 {
-            return;
-        }
-
-        // Calculate forces
+    /// Acceleration on every atom for an arbitrary snapshot of positions
+    /// (masses/charges/radii/bonds come from `self.atoms`/`self.bonds`,
+    /// which don't change mid-step): Coulomb via a Barnes-Hut tree,
+    /// short-range Lennard-Jones, and bond spring forces
+    fn accelerations_at(&self, positions: &[Vec2]) -> Vec<Vec2> {
+        let n = positions.len();
         let mut forces = vec![Vec2::ZERO; n];
 
-        // Coulomb forces between all pairs
+        // Coulomb forces via a Barnes-Hut tree: O(n log n) instead of O(n²),
+        // so the simulation keeps up past MAX_ATOMS
+        let charges: Vec<f32> = self.atoms.iter().map(|a| a.charge()).collect();
+        let tree = crate::barnes_hut::BarnesHutTree::build(positions, &charges, self.box_size);
+        for i in 0..n {
+            if charges[i].abs() > 0.01 {
+                forces[i] += tree.coulomb_force(i, positions[i], charges[i]);
+            }
+        }
+
+        // Short-range pairwise interactions (Lennard-Jones)
         for i in 0..n {
             for j in (i + 1)..n {
-                let r = self.atoms[j].position - self.atoms[i].position;
+                let r = minimum_image(positions[j] - positions[i], self.box_size);
                 let dist = r.length().max(0.5);
                 let r_hat = r / dist;
 
-                // Coulomb force
-                let q1 = self.atoms[i].charge();
-                let q2 = self.atoms[j].charge();
-                if q1.abs() > 0.01 && q2.abs() > 0.01 {
-                    let coulomb_force = K_COULOMB * q1 * q2 / (dist * dist);
-                    forces[i] -= r_hat * coulomb_force;
-                    forces[j] += r_hat * coulomb_force;
-                }
-
                 // Lennard-Jones potential (short-range repulsion/attraction)
                 let sigma = (self.atoms[i].radius() + self.atoms[j].radius()) * LJ_SIGMA;
                 let sr6 = (sigma / dist).powi(6);
@@ -349,7 +471,7 @@ impl Simulation {
             let a = bond.atom_a;
             let b = bond.atom_b;
 
-            let r = self.atoms[b].position - self.atoms[a].position;
+            let r = minimum_image(positions[b] - positions[a], self.box_size);
             let dist = r.length().max(0.01);
             let r_hat = r / dist;
 
@@ -362,24 +484,258 @@ impl Simulation {
             forces[b] -= spring_force;
         }
 
-        // Update velocities and positions
-        for (i, atom) in self.atoms.iter_mut().enumerate() {
-            let accel = forces[i] / atom.mass();
-            atom.velocity += accel * dt;
-            atom.velocity *= self.damping;
-            atom.position += atom.velocity * dt;
+        // Harmonic angle bending: for every atom bonded to two or more
+        // neighbors, push each pair of bond vectors toward the central
+        // element's equilibrium angle so water bends to ~104.5° and
+        // methane's four C-H bonds spread toward the tetrahedral ~109.5°
+        // instead of the molecule collapsing flat.
+        //
+        // A proper dihedral (torsion) term over bonded quadruples i-j-k-l,
+        // as suggested alongside this, needs an out-of-plane angle between
+        // the i-j-k and j-k-l planes — but this simulation is strictly 2D,
+        // so every atom is already coplanar and that angle is degenerate.
+        // It's left out rather than faked.
+        for j in 0..n {
+            let neighbors = &self.atoms[j].bonds;
+            if neighbors.len() < 2 {
+                continue;
+            }
+            let theta0 = self.atoms[j].element.equilibrium_angle();
+
+            for a_idx in 0..neighbors.len() {
+                for b_idx in (a_idx + 1)..neighbors.len() {
+                    let i = neighbors[a_idx];
+                    let k = neighbors[b_idx];
+
+                    let a = minimum_image(positions[i] - positions[j], self.box_size);
+                    let b = minimum_image(positions[k] - positions[j], self.box_size);
+                    let len_a = a.length();
+                    let len_b = b.length();
+                    if len_a < 1e-6 || len_b < 1e-6 {
+                        continue;
+                    }
+
+                    let a_hat = a / len_a;
+                    let b_hat = b / len_b;
+                    let cos_theta = a_hat.dot(b_hat).clamp(-1.0, 1.0);
+                    let sin_theta = (1.0 - cos_theta * cos_theta).sqrt().max(1e-6);
+                    let theta = cos_theta.acos();
+
+                    let dv_dtheta = K_ANGLE * (theta - theta0);
+                    let force_i = -(dv_dtheta / sin_theta) * (b_hat - a_hat * cos_theta) / len_a;
+                    let force_k = -(dv_dtheta / sin_theta) * (a_hat - b_hat * cos_theta) / len_b;
+
+                    forces[i] += force_i;
+                    forces[k] += force_k;
+                    forces[j] -= force_i + force_k;
+                }
+            }
+        }
+
+        // Hydrogen bonds: for every covalent donor(X)-hydrogen(H) pair
+        // where X is electronegative (O or N), look for a nearby unbonded
+        // acceptor Z (also O or N) within `hbond_cutoff` and pull H and Z
+        // together with a force that grows sharply as the X-H···Z angle
+        // straightens toward 180°. This is the attractive force for
+        // potential `V = -hbond_strength * s^n / r²`, with `s = cos(theta)`
+        // the alignment of H→Z with the X→H bond direction and `r` the
+        // H···Z distance, differentiated directly so the force on X, H,
+        // and Z sums to zero.
+        for bond in &self.bonds {
+            let (h, x) = if self.atoms[bond.atom_a].element == Element::Hydrogen {
+                (bond.atom_a, bond.atom_b)
+            } else if self.atoms[bond.atom_b].element == Element::Hydrogen {
+                (bond.atom_b, bond.atom_a)
+            } else {
+                continue;
+            };
+            if !matches!(self.atoms[x].element, Element::Oxygen | Element::Nitrogen) {
+                continue;
+            }
+
+            for z in 0..n {
+                if z == h || z == x {
+                    continue;
+                }
+                if !matches!(self.atoms[z].element, Element::Oxygen | Element::Nitrogen) {
+                    continue;
+                }
+                if self.atoms[x].bonds.contains(&z) {
+                    continue; // covalently bonded, not a hydrogen-bond acceptor
+                }
 
-            // Boundary conditions (soft walls)
-            let bound = 12.0;
-            if atom.position.x.abs() > bound {
-                atom.position.x = atom.position.x.signum() * bound;
-                atom.velocity.x *= -0.5;
+                let a = minimum_image(positions[x] - positions[h], self.box_size);
+                let b = minimum_image(positions[z] - positions[h], self.box_size);
+                let len_a = a.length();
+                let len_b = b.length();
+                if len_a < 1e-6 || len_b < 1e-6 || len_b > self.hbond_cutoff {
+                    continue;
+                }
+
+                let a_hat = a / len_a;
+                let b_hat = b / len_b;
+                let cos_phi = a_hat.dot(b_hat).clamp(-1.0, 1.0);
+                let s = -cos_phi; // cos(angle between X→H and H→Z); 1 when linear
+                if s <= 0.0 {
+                    continue; // bent past perpendicular: no hydrogen-bond character
+                }
+
+                let n_exp = self.hbond_angular_exponent;
+                let s_pow_nm1 = s.powf(n_exp - 1.0);
+                let s_pow_n = s_pow_nm1 * s;
+                let r2 = len_b * len_b;
+
+                let ds_da = (a_hat * cos_phi - b_hat) / len_a;
+                let ds_db = (b_hat * cos_phi - a_hat) / len_b;
+
+                let angular_coeff = self.hbond_strength * n_exp * s_pow_nm1 / r2;
+                let force_x = angular_coeff * ds_da;
+                let force_z =
+                    angular_coeff * ds_db - (2.0 * self.hbond_strength * s_pow_n / (r2 * len_b)) * b_hat;
+                let force_h = -(force_x + force_z);
+
+                forces[x] += force_x;
+                forces[z] += force_z;
+                forces[h] += force_h;
+            }
+        }
+
+        forces
+            .iter()
+            .zip(&self.atoms)
+            .map(|(f, atom)| *f / atom.mass())
+            .collect()
+    }
+
+    /// Acceleration on every atom at its current position
+    fn accelerations(&self) -> Vec<Vec2> {
+        let positions: Vec<Vec2> = self.atoms.iter().map(|a| a.position).collect();
+        self.accelerations_at(&positions)
+    }
+
+    /// Apply the selected thermostat and soft-wall boundary reflection to
+    /// every atom; shared by all three integrators since it's a per-step
+    /// velocity correction, not part of the conservative force evaluation
+    fn apply_thermostat_and_bounds(&mut self, dt: f32) {
+        match self.thermostat {
+            Thermostat::None => {}
+            Thermostat::Damping => {
+                for atom in self.atoms.iter_mut() {
+                    atom.velocity *= self.damping;
+                }
+            }
+            Thermostat::Berendsen { tau } => {
+                let t_measured = self.instantaneous_temperature();
+                if t_measured > 1e-6 && tau > 1e-6 {
+                    let raw = 1.0 + (dt / tau) * (self.temperature / t_measured - 1.0);
+                    let scale = raw.max(0.0).sqrt().clamp(0.8, 1.2);
+                    for atom in self.atoms.iter_mut() {
+                        atom.velocity *= scale;
+                    }
+                }
             }
-            if atom.position.y.abs() > bound {
-                atom.position.y = atom.position.y.signum() * bound;
-                atom.velocity.y *= -0.5;
+        }
+
+        match self.box_size {
+            Some(size) => {
+                // Periodic box: wrap positions back in instead of reflecting
+                for atom in self.atoms.iter_mut() {
+                    atom.position.x = wrap_axis(atom.position.x, size.x);
+                    atom.position.y = wrap_axis(atom.position.y, size.y);
+                }
+            }
+            None => {
+                let bound = 12.0;
+                for atom in self.atoms.iter_mut() {
+                    if atom.position.x.abs() > bound {
+                        atom.position.x = atom.position.x.signum() * bound;
+                        atom.velocity.x *= -0.5;
+                    }
+                    if atom.position.y.abs() > bound {
+                        atom.position.y = atom.position.y.signum() * bound;
+                        atom.velocity.y *= -0.5;
+                    }
+                }
             }
         }
+    }
+
+    /// Semi-implicit Euler: a single force evaluation per step
+    fn step_euler(&mut self, dt: f32) {
+        let accelerations = self.accelerations();
+        for (i, atom) in self.atoms.iter_mut().enumerate() {
+            atom.velocity += accelerations[i] * dt;
+            atom.position += atom.velocity * dt;
+        }
+    }
+
+    /// Velocity-Verlet: advance positions using the previous step's
+    /// acceleration, recompute forces at the new positions, then use the
+    /// average of the old and new accelerations to advance velocity
+    fn step_velocity_verlet(&mut self, dt: f32) {
+        if self.last_accelerations.len() != self.atoms.len() {
+            self.last_accelerations = self.accelerations();
+        }
+
+        for (i, atom) in self.atoms.iter_mut().enumerate() {
+            atom.position += atom.velocity * dt + 0.5 * self.last_accelerations[i] * dt * dt;
+        }
+
+        let new_accelerations = self.accelerations();
+        for (i, atom) in self.atoms.iter_mut().enumerate() {
+            atom.velocity += 0.5 * (self.last_accelerations[i] + new_accelerations[i]) * dt;
+        }
+
+        self.last_accelerations = new_accelerations;
+    }
+
+    /// Classic 4th-order Runge-Kutta: the state is (positions, velocities)
+    /// and the derivative of that state is (velocities, accelerations).
+    /// Evaluating the derivative at four stages and combining them with the
+    /// usual 1/6 weighting gives much better long-term accuracy than Euler
+    /// or Verlet, at the cost of three extra force evaluations per step.
+    fn step_rk4(&mut self, dt: f32) {
+        let pos0: Vec<Vec2> = self.atoms.iter().map(|a| a.position).collect();
+        let vel0: Vec<Vec2> = self.atoms.iter().map(|a| a.velocity).collect();
+
+        let derivative = |positions: &[Vec2], velocities: &[Vec2]| -> (Vec<Vec2>, Vec<Vec2>) {
+            (velocities.to_vec(), self.accelerations_at(positions))
+        };
+
+        let (k1_vel, k1_acc) = derivative(&pos0, &vel0);
+
+        let pos_k2: Vec<Vec2> = pos0.iter().zip(&k1_vel).map(|(p, v)| *p + *v * dt * 0.5).collect();
+        let vel_k2: Vec<Vec2> = vel0.iter().zip(&k1_acc).map(|(v, a)| *v + *a * dt * 0.5).collect();
+        let (k2_vel, k2_acc) = derivative(&pos_k2, &vel_k2);
+
+        let pos_k3: Vec<Vec2> = pos0.iter().zip(&k2_vel).map(|(p, v)| *p + *v * dt * 0.5).collect();
+        let vel_k3: Vec<Vec2> = vel0.iter().zip(&k2_acc).map(|(v, a)| *v + *a * dt * 0.5).collect();
+        let (k3_vel, k3_acc) = derivative(&pos_k3, &vel_k3);
+
+        let pos_k4: Vec<Vec2> = pos0.iter().zip(&k3_vel).map(|(p, v)| *p + *v * dt).collect();
+        let vel_k4: Vec<Vec2> = vel0.iter().zip(&k3_acc).map(|(v, a)| *v + *a * dt).collect();
+        let (k4_vel, k4_acc) = derivative(&pos_k4, &vel_k4);
+
+        for (i, atom) in self.atoms.iter_mut().enumerate() {
+            atom.position += (k1_vel[i] + 2.0 * k2_vel[i] + 2.0 * k3_vel[i] + k4_vel[i]) * (dt / 6.0);
+            atom.velocity += (k1_acc[i] + 2.0 * k2_acc[i] + 2.0 * k3_acc[i] + k4_acc[i]) * (dt / 6.0);
+        }
+    }
+
+    /// Step the simulation forward, using whichever [`Integrator`] is
+    /// currently selected
+    pub fn step(&mut self, dt: f32) {
+        if self.atoms.is_empty() {
+            return;
+        }
+
+        match self.integrator {
+            Integrator::Euler => self.step_euler(dt),
+            Integrator::VelocityVerlet => self.step_velocity_verlet(dt),
+            Integrator::Rk4 => self.step_rk4(dt),
+        }
+
+        self.apply_thermostat_and_bounds(dt);
 
         // Try to form new bonds (simple proximity-based bonding)
         self.try_form_bonds();
@@ -405,7 +761,8 @@ impl Simulation {
                     continue;
                 }
 
-                let dist = (self.atoms[j].position - self.atoms[i].position).length();
+                let r = minimum_image(self.atoms[j].position - self.atoms[i].position, self.box_size);
+                let dist = r.length();
                 let bond_threshold = (self.atoms[i].radius() + self.atoms[j].radius()) * BOND_DISTANCE;
 
                 if dist < bond_threshold {
@@ -419,24 +776,11 @@ impl Simulation {
         }
     }
 
-    /// Calculate total kinetic energy
-    pub fn kinetic_energy(&self) -> f32 {
-        self.atoms
-            .iter()
-            .map(|a| 0.5 * a.mass() * a.velocity.length_squared())
-            .sum()
-    }
-
-    /// Calculate average temperature from kinetic energy
+    /// Average temperature from kinetic energy; an alias for
+    /// [`Simulation::instantaneous_temperature`] kept for callers that read
+    /// off a per-step measurement rather than an instantaneous sample
     pub fn measured_temperature(&self) -> f32 {
-        if self.atoms.is_empty() {
-            return 0.0;
-        }
-
-        // T = 2 * KE / (k_B * N * dimensions)
-        // Simplified with k_B = 1
-        let ke = self.kinetic_energy();
-        2.0 * ke / (self.atoms.len() as f32 * 2.0)
+        self.instantaneous_temperature()
     }
 }
 
@@ -0,0 +1,149 @@
+//! Interatomic potentials beyond classical Lennard-Jones
+//!
+//! Generalizes the 12-6 Lennard-Jones potential used in `physics.rs` to the
+//! full Mie(λr, λa) family, and adds the Feynman-Hibbs quantum correction
+//! that captures the delocalization of light atoms (H₂, He, Ne) which the
+//! purely classical potential ignores.
+
+/// Reduced Planck constant (scaled, matches the rest of the crate's units)
+pub const HBAR: f32 = 1.0;
+/// Boltzmann constant (scaled; `Simulation::instantaneous_temperature` in
+/// `physics.rs` likewise assumes k_B = 1)
+pub const K_BOLTZMANN: f32 = 1.0;
+
+/// Parameters for a Mie(λr, λa) pair potential. Lennard-Jones is the
+/// λr = 12, λa = 6 special case.
+#[derive(Debug, Clone, Copy)]
+pub struct MieParams {
+    /// Well depth
+    pub epsilon: f32,
+    /// Collision diameter (V(σ) = 0)
+    pub sigma: f32,
+    /// Repulsive exponent
+    pub lambda_r: f32,
+    /// Attractive exponent
+    pub lambda_a: f32,
+}
+
+impl MieParams {
+    /// The classical 12-6 Lennard-Jones potential expressed as a Mie potential
+    pub fn lennard_jones(epsilon: f32, sigma: f32) -> Self {
+        Self {
+            epsilon,
+            sigma,
+            lambda_r: 12.0,
+            lambda_a: 6.0,
+        }
+    }
+
+    /// Prefactor `C = (λr/(λr-λa))·(λr/λa)^(λa/(λr-λa))` that normalizes the
+    /// potential minimum to `-epsilon`
+    fn prefactor(&self) -> f32 {
+        let diff = self.lambda_r - self.lambda_a;
+        (self.lambda_r / diff) * (self.lambda_r / self.lambda_a).powf(self.lambda_a / diff)
+    }
+
+    /// `V(r) = C·ε[(σ/r)^λr - (σ/r)^λa]`
+    pub fn potential(&self, r: f32) -> f32 {
+        let c = self.prefactor();
+        let sr_r = (self.sigma / r).powf(self.lambda_r);
+        let sr_a = (self.sigma / r).powf(self.lambda_a);
+        c * self.epsilon * (sr_r - sr_a)
+    }
+
+    /// `V'(r)`, analytic first derivative
+    pub fn d_potential(&self, r: f32) -> f32 {
+        let c = self.prefactor();
+        let sr_r = (self.sigma / r).powf(self.lambda_r);
+        let sr_a = (self.sigma / r).powf(self.lambda_a);
+        -c * self.epsilon * (self.lambda_r * sr_r - self.lambda_a * sr_a) / r
+    }
+
+    /// `V''(r)`, analytic second derivative
+    pub fn d2_potential(&self, r: f32) -> f32 {
+        let c = self.prefactor();
+        let sr_r = (self.sigma / r).powf(self.lambda_r);
+        let sr_a = (self.sigma / r).powf(self.lambda_a);
+        c * self.epsilon
+            * (self.lambda_r * (self.lambda_r + 1.0) * sr_r
+                - self.lambda_a * (self.lambda_a + 1.0) * sr_a)
+            / (r * r)
+    }
+
+    /// `V'''(r)`, needed for the second-order FH correction
+    pub fn d3_potential(&self, r: f32) -> f32 {
+        let c = self.prefactor();
+        let sr_r = (self.sigma / r).powf(self.lambda_r);
+        let sr_a = (self.sigma / r).powf(self.lambda_a);
+        -c * self.epsilon
+            * (self.lambda_r * (self.lambda_r + 1.0) * (self.lambda_r + 2.0) * sr_r
+                - self.lambda_a * (self.lambda_a + 1.0) * (self.lambda_a + 2.0) * sr_a)
+            / (r * r * r)
+    }
+
+    /// `V''''(r)`, needed for the second-order FH correction
+    pub fn d4_potential(&self, r: f32) -> f32 {
+        let c = self.prefactor();
+        let sr_r = (self.sigma / r).powf(self.lambda_r);
+        let sr_a = (self.sigma / r).powf(self.lambda_a);
+        c * self.epsilon
+            * (self.lambda_r * (self.lambda_r + 1.0) * (self.lambda_r + 2.0) * (self.lambda_r + 3.0)
+                * sr_r
+                - self.lambda_a
+                    * (self.lambda_a + 1.0)
+                    * (self.lambda_a + 2.0)
+                    * (self.lambda_a + 3.0)
+                    * sr_a)
+            / (r * r * r * r)
+    }
+
+    /// de Boer quantum parameter `Λ = ℏ/(σ√(με))`: the ratio of the thermal
+    /// de Broglie wavelength scale to the potential's length scale. Large Λ
+    /// (light atoms like He, H₂, Ne) means quantum delocalization can't be
+    /// ignored and the Feynman-Hibbs correction matters.
+    pub fn de_boer_parameter(&self, reduced_mass: f32) -> f32 {
+        HBAR / (self.sigma * (reduced_mass * self.epsilon).sqrt())
+    }
+}
+
+/// Order of the Feynman-Hibbs semiclassical expansion to apply
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FhOrder {
+    /// Classical potential, no quantum correction
+    Classical,
+    /// First-order (quadratic in ℏ) correction
+    First,
+    /// First- and second-order (quartic in ℏ) correction
+    Second,
+}
+
+/// Feynman-Hibbs quantum-corrected effective potential at `temperature` for a
+/// pair with reduced mass `reduced_mass`, up to `order`:
+///
+/// First order: `V_FH(r) = V(r) + (ℏ²β/24μ)·[V''(r) + (2/r)V'(r)]`
+/// Second order adds: `(ℏ²β)²/(1152μ²)·[V''''(r) + (8/r)V'''(r)]`
+pub fn mie_fh_potential(
+    r: f32,
+    params: &MieParams,
+    temperature: f32,
+    reduced_mass: f32,
+    order: FhOrder,
+) -> f32 {
+    let v = params.potential(r);
+    if order == FhOrder::Classical {
+        return v;
+    }
+
+    let beta = 1.0 / (K_BOLTZMANN * temperature.max(1e-6));
+    let first_order = (HBAR * HBAR * beta) / (24.0 * reduced_mass)
+        * (params.d2_potential(r) + (2.0 / r) * params.d_potential(r));
+
+    if order == FhOrder::First {
+        return v + first_order;
+    }
+
+    let second_order = (HBAR * HBAR * beta).powi(2) / (1152.0 * reduced_mass * reduced_mass)
+        * (params.d4_potential(r) + (8.0 / r) * params.d3_potential(r));
+
+    v + first_order + second_order
+}
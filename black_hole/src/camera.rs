@@ -0,0 +1,201 @@
+//! Camera abstraction for the black hole viewer
+//!
+//! `App` used to drive an orbit-only camera directly through
+//! `camera_yaw`/`camera_pitch`/`camera_distance` fields. `Camera` pulls
+//! that behavior behind a trait with two implementations: `OrbitCamera`,
+//! unchanged in behavior from before, and `Flycam`, a velocity-based
+//! free-fly camera that can cross into the lensed region and fly near the
+//! photon sphere instead of only orbiting it from a fixed distance. `App`
+//! holds `Box<dyn Camera>` and queries the active one for its world-space
+//! position and to forward input events.
+
+use glam::Vec3;
+use winit::keyboard::KeyCode;
+
+/// A camera `App` can query for a world-space eye position and drive with
+/// input events, regardless of which implementation is active
+pub trait Camera {
+    /// Eye position in world space, passed straight to `Renderer3D::update`
+    fn position(&self) -> [f32; 3];
+    fn update(&mut self, dt: f32);
+    fn handle_key(&mut self, key: KeyCode, pressed: bool);
+    fn handle_mouse_drag(&mut self, dx: f32, dy: f32);
+    fn handle_scroll(&mut self, delta: f32);
+    fn reset(&mut self);
+}
+
+/// The original camera: orbits the origin at a fixed distance, driven by
+/// mouse drag (yaw/pitch) and scroll (distance)
+pub struct OrbitCamera {
+    pub distance: f32,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl OrbitCamera {
+    pub fn new() -> Self {
+        Self {
+            distance: 20.0,
+            yaw: 0.0,
+            pitch: 0.3,
+        }
+    }
+}
+
+impl Camera for OrbitCamera {
+    fn position(&self) -> [f32; 3] {
+        [
+            self.distance * self.pitch.cos() * self.yaw.sin(),
+            self.distance * self.pitch.sin(),
+            self.distance * self.pitch.cos() * self.yaw.cos(),
+        ]
+    }
+
+    fn update(&mut self, _dt: f32) {}
+
+    fn handle_key(&mut self, _key: KeyCode, _pressed: bool) {}
+
+    fn handle_mouse_drag(&mut self, dx: f32, dy: f32) {
+        self.yaw += dx * 0.01;
+        self.pitch = (self.pitch + dy * 0.01).clamp(-1.5, 1.5);
+    }
+
+    fn handle_scroll(&mut self, delta: f32) {
+        self.distance = (self.distance - delta * 2.0).clamp(5.0, 100.0);
+    }
+
+    fn reset(&mut self) {
+        self.distance = 20.0;
+        self.yaw = 0.0;
+        self.pitch = 0.3;
+    }
+}
+
+/// How quickly `Flycam`'s velocity decays toward zero once keys are
+/// released, expressed as a half-life in seconds
+const FLYCAM_VELOCITY_HALF_LIFE: f32 = 0.15;
+
+/// Acceleration applied along the camera basis while a movement key is held
+const FLYCAM_THRUST: f32 = 40.0;
+
+/// Radians of yaw/pitch turned per pixel of mouse drag
+const FLYCAM_TURN_SENSITIVITY: f32 = 0.003;
+
+/// A velocity-based free-fly camera: WASD + Q/E accelerate it along its
+/// own basis, mouse drag turns it, and exponential damping brings it to a
+/// coast instead of an instant stop when keys are released
+pub struct Flycam {
+    pub position: Vec3,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub velocity: Vec3,
+
+    forward_held: bool,
+    back_held: bool,
+    left_held: bool,
+    right_held: bool,
+    up_held: bool,
+    down_held: bool,
+
+    /// Accumulated mouse drag (pixels) since the last `update`, applied to
+    /// yaw/pitch and then zeroed
+    mouse_dx: f32,
+    mouse_dy: f32,
+}
+
+impl Flycam {
+    pub fn new() -> Self {
+        Self {
+            position: Vec3::new(0.0, 0.0, 20.0),
+            yaw: 0.0,
+            pitch: 0.0,
+            velocity: Vec3::ZERO,
+            forward_held: false,
+            back_held: false,
+            left_held: false,
+            right_held: false,
+            up_held: false,
+            down_held: false,
+            mouse_dx: 0.0,
+            mouse_dy: 0.0,
+        }
+    }
+
+    /// Camera-space `(forward, right)` basis derived from yaw/pitch
+    fn basis(&self) -> (Vec3, Vec3) {
+        let forward = -Vec3::new(
+            self.yaw.sin() * self.pitch.cos(),
+            self.pitch.sin(),
+            self.yaw.cos() * self.pitch.cos(),
+        );
+        let right = Vec3::new(self.yaw.cos(), 0.0, -self.yaw.sin());
+        (forward, right)
+    }
+}
+
+impl Camera for Flycam {
+    fn position(&self) -> [f32; 3] {
+        self.position.into()
+    }
+
+    fn update(&mut self, dt: f32) {
+        self.yaw += self.mouse_dx * FLYCAM_TURN_SENSITIVITY;
+        self.pitch = (self.pitch - self.mouse_dy * FLYCAM_TURN_SENSITIVITY).clamp(-1.5, 1.5);
+        self.mouse_dx = 0.0;
+        self.mouse_dy = 0.0;
+
+        let (forward, right) = self.basis();
+        let mut thrust = Vec3::ZERO;
+        if self.forward_held {
+            thrust += forward;
+        }
+        if self.back_held {
+            thrust -= forward;
+        }
+        if self.right_held {
+            thrust += right;
+        }
+        if self.left_held {
+            thrust -= right;
+        }
+        if self.up_held {
+            thrust += Vec3::Y;
+        }
+        if self.down_held {
+            thrust -= Vec3::Y;
+        }
+        if thrust != Vec3::ZERO {
+            self.velocity += thrust.normalize() * FLYCAM_THRUST * dt;
+        }
+
+        let damping = 0.5_f32.powf(dt / FLYCAM_VELOCITY_HALF_LIFE);
+        self.velocity *= damping;
+
+        self.position += self.velocity * dt;
+    }
+
+    fn handle_key(&mut self, key: KeyCode, pressed: bool) {
+        match key {
+            KeyCode::KeyW => self.forward_held = pressed,
+            KeyCode::KeyS => self.back_held = pressed,
+            KeyCode::KeyA => self.left_held = pressed,
+            KeyCode::KeyD => self.right_held = pressed,
+            KeyCode::KeyE => self.up_held = pressed,
+            KeyCode::KeyQ => self.down_held = pressed,
+            _ => {}
+        }
+    }
+
+    fn handle_mouse_drag(&mut self, dx: f32, dy: f32) {
+        self.mouse_dx += dx;
+        self.mouse_dy += dy;
+    }
+
+    fn handle_scroll(&mut self, _delta: f32) {
+        // Flying through the scene doesn't zoom; scroll has no effect here
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
@@ -104,6 +104,59 @@ pub const BLACK_HOLE_3D_VARIABLES: &[(&str, &str)] = &[
     ("k_B", "Boltzmann constant"),
 ];
 
+// ============================================================================
+// Black Hole (3D) Equations - Kerr (Rotating) Geometry
+// ============================================================================
+
+pub const BLACK_HOLE_3D_EQUATIONS_KERR: &[Equation] = &[
+    Equation {
+        name: "Spin Parameter",
+        formula: "a = J/(Mc)",
+        description: "Angular momentum per unit mass (length units)",
+    },
+    Equation {
+        name: "Kerr Horizons",
+        formula: "r± = GM/c² ± √((GM/c²)² − a²)",
+        description: "Outer (event) and inner (Cauchy) horizons",
+    },
+    Equation {
+        name: "Ergosphere Boundary",
+        formula: "r_ergo = GM/c² + √((GM/c²)² − a²cos²θ)",
+        description: "Region where spacetime itself must co-rotate",
+    },
+    Equation {
+        name: "ISCO Radii (Bardeen-Press-Teukolsky)",
+        formula: "r_ISCO/M = 3 + Z₂ ∓ √((3−Z₁)(3+Z₁+2Z₂))",
+        description: "Prograde (−) and retrograde (+) stable orbits",
+    },
+    Equation {
+        name: "Lense-Thirring Precession",
+        formula: "Ω = 2GJ/(c²r³)",
+        description: "Frame-dragging angular velocity at radius r",
+    },
+];
+
+pub const BLACK_HOLE_3D_VARIABLES_KERR: &[(&str, &str)] = &[
+    ("a", "Spin parameter, J/(Mc)"),
+    ("J", "Angular momentum"),
+    ("θ", "Colatitude from the spin axis"),
+    ("r±", "Outer/inner Kerr horizons"),
+    ("r_ergo", "Ergosphere radius"),
+    ("Z₁, Z₂", "ISCO intermediate terms (depend on a/M)"),
+    ("Ω", "Frame-dragging angular velocity"),
+];
+
+/// Pick the Schwarzschild or Kerr equation/variable sets based on whether
+/// the selected black hole has nonzero spin, so the sidebar automatically
+/// reflects the geometry actually being simulated
+pub fn kerr_or_schwarzschild(spin: f32) -> (&'static [Equation], &'static [(&'static str, &'static str)]) {
+    if spin.abs() > 1e-6 {
+        (BLACK_HOLE_3D_EQUATIONS_KERR, BLACK_HOLE_3D_VARIABLES_KERR)
+    } else {
+        (BLACK_HOLE_3D_EQUATIONS, BLACK_HOLE_3D_VARIABLES)
+    }
+}
+
 // ============================================================================
 // Black Hole (2D) Equations - Light Deflection
 // ============================================================================
@@ -0,0 +1,242 @@
+//! Scalar-field contour overlay via 2D marching squares
+//!
+//! Lets the 2D lensing view show the field the rays are actually bending
+//! through, not just the rays themselves: a user-chosen scalar (Newtonian
+//! potential or local deflection magnitude) sampled on a grid over the
+//! current view, contoured at a handful of iso-values. This is the 2D
+//! counterpart of `quantum_sim::orbitals`'s marching-cubes isosurface —
+//! same idea (classify corners against an iso-value, look up which edges
+//! are crossed, interpolate the crossing point), one dimension down, so a
+//! cell has 4 corners and 16 cases instead of 8 corners and 256.
+
+use glam::Vec2;
+
+use crate::physics::{BlackHole, C, G};
+
+/// Which scalar field the overlay contours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// Newtonian potential magnitude `GM/r`
+    Potential,
+    /// Local light-deflection magnitude `GM/(c²r²)` — the weak-field bending
+    /// acceleration a ray passing at radius `r` would feel
+    DeflectionMagnitude,
+}
+
+impl FieldKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            FieldKind::Potential => "Potential",
+            FieldKind::DeflectionMagnitude => "Deflection",
+        }
+    }
+}
+
+/// Sample `kind` at world point `p`. Diverges as `p` approaches the black
+/// hole's position, so callers must keep away from `r ≈ 0` themselves (see
+/// `extract_contours`'s `min_r` skip).
+fn sample(kind: FieldKind, black_hole: &BlackHole, p: Vec2) -> f32 {
+    let bh_pos = Vec2::new(black_hole.position.x, black_hole.position.y);
+    let r = (p - bh_pos).length().max(1e-4);
+    match kind {
+        FieldKind::Potential => G * black_hole.mass / r,
+        FieldKind::DeflectionMagnitude => (G * black_hole.mass) / (C * C * r * r),
+    }
+}
+
+/// Pick `density` iso-values for `kind`, spread radially between just
+/// outside the black hole and the farthest corner of the view box, so the
+/// contour set adapts to both the current zoom and black hole mass instead
+/// of a fixed, easily-out-of-range constant
+pub fn default_iso_values(
+    black_hole: &BlackHole,
+    kind: FieldKind,
+    view_min: Vec2,
+    view_max: Vec2,
+    density: usize,
+) -> Vec<f32> {
+    let bh_pos = Vec2::new(black_hole.position.x, black_hole.position.y);
+    let corners = [
+        view_min,
+        Vec2::new(view_max.x, view_min.y),
+        view_max,
+        Vec2::new(view_min.x, view_max.y),
+    ];
+    let max_r = corners
+        .iter()
+        .map(|c| (*c - bh_pos).length())
+        .fold(0.0_f32, f32::max)
+        .max(1.0);
+    let min_r = (black_hole.schwarzschild_radius * 1.5).max(1e-3);
+
+    (1..=density)
+        .map(|i| {
+            let t = i as f32 / (density as f32 + 1.0);
+            let r = min_r + (max_r - min_r) * t;
+            sample(kind, black_hole, bh_pos + Vec2::new(r, 0.0))
+        })
+        .collect()
+}
+
+/// A cell's four edges, crossed between the corners `Bottom`: 0-1,
+/// `Right`: 1-2, `Top`: 2-3, `Left`: 3-0 (corners run counter-clockwise from
+/// the bottom-left)
+#[derive(Clone, Copy)]
+enum Edge {
+    Bottom,
+    Right,
+    Top,
+    Left,
+}
+
+/// Non-ambiguous single-segment marching-squares cases, indexed by the
+/// 4-bit corner case (bit `c` set when corner `c` is below the iso-value).
+/// Cases 5 and 10 — the two diagonal "both opposite corners inside" saddles
+/// — are `None` here and resolved in `extract_contours` by testing the
+/// cell center instead.
+const EDGE_PAIRS: [Option<(Edge, Edge)>; 16] = [
+    None,
+    Some((Edge::Left, Edge::Bottom)),
+    Some((Edge::Bottom, Edge::Right)),
+    Some((Edge::Left, Edge::Right)),
+    Some((Edge::Right, Edge::Top)),
+    None,
+    Some((Edge::Bottom, Edge::Top)),
+    Some((Edge::Left, Edge::Top)),
+    Some((Edge::Top, Edge::Left)),
+    Some((Edge::Bottom, Edge::Top)),
+    None,
+    Some((Edge::Right, Edge::Top)),
+    Some((Edge::Left, Edge::Right)),
+    Some((Edge::Bottom, Edge::Right)),
+    Some((Edge::Left, Edge::Bottom)),
+    None,
+];
+
+fn edge_point(edge: Edge, iso: f32, corner_pos: &[Vec2; 4], corner_val: &[f32; 4]) -> Vec2 {
+    let (a, b) = match edge {
+        Edge::Bottom => (0, 1),
+        Edge::Right => (1, 2),
+        Edge::Top => (2, 3),
+        Edge::Left => (3, 0),
+    };
+    interpolate(iso, corner_pos[a], corner_pos[b], corner_val[a], corner_val[b])
+}
+
+fn interpolate(iso: f32, p0: Vec2, p1: Vec2, d0: f32, d1: f32) -> Vec2 {
+    if (d1 - d0).abs() < 1e-6 {
+        return p0;
+    }
+    let t = ((iso - d0) / (d1 - d0)).clamp(0.0, 1.0);
+    p0 + (p1 - p0) * t
+}
+
+/// Trace iso-contours of `kind`'s field over the axis-aligned view box
+/// `[view_min, view_max]` at `resolution` cells per axis, for every value in
+/// `iso_values`, returning world-space line segments. Cells whose center
+/// sits inside `1.5 * schwarzschild_radius` are skipped outright, since the
+/// field diverges there and any interpolated crossing would be meaningless.
+pub fn extract_contours(
+    black_hole: &BlackHole,
+    kind: FieldKind,
+    view_min: Vec2,
+    view_max: Vec2,
+    resolution: usize,
+    iso_values: &[f32],
+) -> Vec<(Vec2, Vec2)> {
+    let mut segments = Vec::new();
+    if resolution == 0 {
+        return segments;
+    }
+
+    let bh_pos = Vec2::new(black_hole.position.x, black_hole.position.y);
+    let min_r = black_hole.schwarzschild_radius * 1.5;
+
+    let size = view_max - view_min;
+    let step = Vec2::new(size.x / resolution as f32, size.y / resolution as f32);
+
+    // Corner values are sampled once per grid point and reused by every
+    // neighbouring cell, same as the 3D isosurface extractor
+    let samples = resolution + 1;
+    let mut grid = vec![0.0_f32; samples * samples];
+    let idx = |i: usize, j: usize| i * samples + j;
+    for i in 0..samples {
+        for j in 0..samples {
+            let p = view_min + Vec2::new(i as f32 * step.x, j as f32 * step.y);
+            grid[idx(i, j)] = sample(kind, black_hole, p);
+        }
+    }
+
+    for &iso in iso_values {
+        for i in 0..resolution {
+            for j in 0..resolution {
+                let corner_pos = [
+                    view_min + Vec2::new(i as f32 * step.x, j as f32 * step.y),
+                    view_min + Vec2::new((i + 1) as f32 * step.x, j as f32 * step.y),
+                    view_min + Vec2::new((i + 1) as f32 * step.x, (j + 1) as f32 * step.y),
+                    view_min + Vec2::new(i as f32 * step.x, (j + 1) as f32 * step.y),
+                ];
+
+                let center = (corner_pos[0] + corner_pos[2]) * 0.5;
+                if (center - bh_pos).length() < min_r {
+                    continue;
+                }
+
+                let corner_val = [
+                    grid[idx(i, j)],
+                    grid[idx(i + 1, j)],
+                    grid[idx(i + 1, j + 1)],
+                    grid[idx(i, j + 1)],
+                ];
+
+                let mut case_index = 0usize;
+                for (c, &v) in corner_val.iter().enumerate() {
+                    if v < iso {
+                        case_index |= 1 << c;
+                    }
+                }
+
+                if case_index == 0 || case_index == 15 {
+                    continue;
+                }
+
+                if case_index == 5 || case_index == 10 {
+                    // Saddle: both diagonal pairs disagree with their
+                    // neighbours on which side of iso they're on, so either
+                    // of the two edge pairings is locally consistent with
+                    // the corners alone. Break the tie with the exact field
+                    // value at the cell center: if it falls on the same
+                    // side as corner 0, corners 0 and 2 are joined through
+                    // the center and the segments cut off corners 1 and 3;
+                    // otherwise they cut off corners 0 and 2.
+                    let center_val = sample(kind, black_hole, center);
+                    let corner0_inside = corner_val[0] < iso;
+                    let center_inside = center_val < iso;
+
+                    let pairs = if corner0_inside == center_inside {
+                        [(Edge::Left, Edge::Top), (Edge::Bottom, Edge::Right)]
+                    } else {
+                        [(Edge::Left, Edge::Bottom), (Edge::Right, Edge::Top)]
+                    };
+
+                    for (e0, e1) in pairs {
+                        segments.push((
+                            edge_point(e0, iso, &corner_pos, &corner_val),
+                            edge_point(e1, iso, &corner_pos, &corner_val),
+                        ));
+                    }
+                    continue;
+                }
+
+                if let Some((e0, e1)) = EDGE_PAIRS[case_index] {
+                    segments.push((
+                        edge_point(e0, iso, &corner_pos, &corner_val),
+                        edge_point(e1, iso, &corner_pos, &corner_val),
+                    ));
+                }
+            }
+        }
+    }
+
+    segments
+}
@@ -10,16 +10,30 @@
 //! - Left mouse drag: Orbit camera
 //! - Scroll: Zoom in/out
 //! - +/-: Adjust black hole mass
+//! - [/]: Adjust black hole spin (switches Schwarzschild/Kerr geometry)
 //! - Space: Pause/resume disk animation
 //! - R: Reset view
+//! - C: Toggle free-fly camera (WASD/Q/E fly through the scene instead of
+//!   orbiting, mouse drag looks around instead of orbiting)
+//! - V: Cycle the lensing background skybox, loaded from subdirectories of
+//!   a `skyboxes/` directory in the working directory (see `skybox.rs`); does
+//!   nothing if none are found
 
 mod physics;
 mod renderer;
+mod equations_ui;
+mod camera;
+mod skybox;
+
+use std::path::Path;
 
 use common::GraphicsContext;
 use glam::Vec3;
 use physics::BlackHole;
 use renderer::Renderer3D;
+use equations_ui::kerr_or_schwarzschild;
+use camera::{Camera, Flycam, OrbitCamera};
+use skybox::{discover_skyboxes, SkyboxSet};
 use winit::{
     event::{ElementState, Event, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
@@ -30,13 +44,17 @@ struct App {
     ctx: GraphicsContext,
     renderer: Renderer3D,
     black_hole: BlackHole,
-    camera_distance: f32,
-    camera_yaw: f32,
-    camera_pitch: f32,
+    camera: Box<dyn Camera>,
+    using_flycam: bool,
     time: f32,
     paused: bool,
     mouse_pressed: bool,
     last_mouse_pos: Option<(f64, f64)>,
+
+    /// Skybox sets found under `skyboxes/` at startup, cycled with `V`; see
+    /// `skybox.rs`
+    skyboxes: Vec<SkyboxSet>,
+    current_skybox: Option<usize>,
 }
 
 impl App {
@@ -44,36 +62,60 @@ impl App {
         let renderer = Renderer3D::new(&ctx);
         let black_hole = BlackHole::new(Vec3::ZERO, 1.0);
 
+        let skyboxes = discover_skyboxes(Path::new("skyboxes"));
+
         Self {
             ctx,
             renderer,
             black_hole,
-            camera_distance: 20.0,
-            camera_yaw: 0.0,
-            camera_pitch: 0.3,
+            camera: Box::new(OrbitCamera::new()),
+            using_flycam: false,
             time: 0.0,
             paused: false,
             mouse_pressed: false,
             last_mouse_pos: None,
+            skyboxes,
+            current_skybox: None,
         }
     }
 
-    fn camera_position(&self) -> [f32; 3] {
-        [
-            self.camera_distance * self.camera_pitch.cos() * self.camera_yaw.sin(),
-            self.camera_distance * self.camera_pitch.sin(),
-            self.camera_distance * self.camera_pitch.cos() * self.camera_yaw.cos(),
-        ]
+    /// Load the next skybox in `self.skyboxes` (wrapping around; with none
+    /// loaded yet, the first one is loaded), logging
+    /// failures instead of panicking since a missing/corrupt face image
+    /// shouldn't crash the viewer
+    fn cycle_skybox(&mut self) {
+        if self.skyboxes.is_empty() {
+            return;
+        }
+
+        let next = match self.current_skybox {
+            Some(i) => (i + 1) % self.skyboxes.len(),
+            None => 0,
+        };
+
+        let set = &self.skyboxes[next];
+        let paths: Vec<&str> = set.faces.iter().map(|p| p.to_str().unwrap_or("")).collect();
+        let paths: [&str; 6] = paths.try_into().expect("SkyboxSet::faces has exactly 6 entries");
+
+        match self.renderer.set_skybox(&self.ctx, paths) {
+            Ok(()) => {
+                println!("Skybox: {}", set.name);
+                self.current_skybox = Some(next);
+            }
+            Err(err) => eprintln!("failed to load skybox `{}`: {err}", set.name),
+        }
     }
 
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.ctx.resize(new_size);
+        self.renderer.resize(&self.ctx, new_size.width, new_size.height);
     }
 
     fn update(&mut self, dt: f32) {
         if !self.paused {
             self.time += dt;
         }
+        self.camera.update(dt);
     }
 
     fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
@@ -84,8 +126,8 @@ impl App {
 
         self.renderer.update(
             &self.ctx.queue,
-            self.camera_position(),
-            &self.black_hole,
+            self.camera.position(),
+            std::slice::from_ref(&self.black_hole),
             self.time,
         );
 
@@ -104,23 +146,58 @@ impl App {
         Ok(())
     }
 
+    /// Report which equation set (Schwarzschild or Kerr) is now active,
+    /// since this binary has no egui sidebar to show it visually
+    fn log_active_geometry(&self) {
+        let (equations, _) = kerr_or_schwarzschild(self.black_hole.spin);
+        let geometry = if self.black_hole.spin.abs() > 1e-6 { "Kerr" } else { "Schwarzschild" };
+        println!(
+            "Geometry: {geometry} (spin a = {:.4}, {} equations active)",
+            self.black_hole.spin,
+            equations.len()
+        );
+    }
+
     fn handle_key(&mut self, key: KeyCode, state: ElementState) {
-        if state != ElementState::Pressed {
+        let pressed = state == ElementState::Pressed;
+        // The active camera gets every key event (press and release), since
+        // `Flycam` tracks which movement keys are currently held
+        self.camera.handle_key(key, pressed);
+
+        if !pressed {
             return;
         }
 
         match key {
             KeyCode::Space => self.paused = !self.paused,
-            KeyCode::KeyR => {
-                self.camera_distance = 20.0;
-                self.camera_yaw = 0.0;
-                self.camera_pitch = 0.3;
+            KeyCode::KeyR => self.camera.reset(),
+            KeyCode::KeyC => {
+                self.using_flycam = !self.using_flycam;
+                self.camera = if self.using_flycam {
+                    Box::new(Flycam::new())
+                } else {
+                    Box::new(OrbitCamera::new())
+                };
             }
+            KeyCode::KeyV => self.cycle_skybox(),
             KeyCode::Equal | KeyCode::NumpadAdd => {
-                self.black_hole = BlackHole::new(Vec3::ZERO, self.black_hole.mass * 1.2);
+                self.black_hole = BlackHole::new(Vec3::ZERO, self.black_hole.mass * 1.2)
+                    .with_spin(self.black_hole.spin);
             }
             KeyCode::Minus | KeyCode::NumpadSubtract => {
-                self.black_hole = BlackHole::new(Vec3::ZERO, (self.black_hole.mass / 1.2).max(0.1));
+                self.black_hole = BlackHole::new(Vec3::ZERO, (self.black_hole.mass / 1.2).max(0.1))
+                    .with_spin(self.black_hole.spin);
+            }
+            // Spin the black hole up/down; crossing zero switches the
+            // equations set (and frame-dragging dynamics) between
+            // Schwarzschild and Kerr
+            KeyCode::BracketRight => {
+                self.black_hole = self.black_hole.with_spin(self.black_hole.spin + 0.05 * self.black_hole.mass);
+                self.log_active_geometry();
+            }
+            KeyCode::BracketLeft => {
+                self.black_hole = self.black_hole.with_spin(self.black_hole.spin - 0.05 * self.black_hole.mass);
+                self.log_active_geometry();
             }
             _ => {}
         }
@@ -129,18 +206,16 @@ impl App {
     fn handle_mouse_move(&mut self, x: f64, y: f64) {
         if self.mouse_pressed {
             if let Some((last_x, last_y)) = self.last_mouse_pos {
-                let dx = (x - last_x) as f32 * 0.01;
-                let dy = (y - last_y) as f32 * 0.01;
-
-                self.camera_yaw += dx;
-                self.camera_pitch = (self.camera_pitch + dy).clamp(-1.5, 1.5);
+                let dx = (x - last_x) as f32;
+                let dy = (y - last_y) as f32;
+                self.camera.handle_mouse_drag(dx, dy);
             }
             self.last_mouse_pos = Some((x, y));
         }
     }
 
     fn handle_scroll(&mut self, delta: f32) {
-        self.camera_distance = (self.camera_distance - delta * 2.0).clamp(5.0, 100.0);
+        self.camera.handle_scroll(delta);
     }
 }
 
@@ -8,22 +8,38 @@
 //!
 //! Controls:
 //! - Click: Spawn light rays from click position
-//! - Scroll: Zoom in/out
+//! - Middle/right mouse drag: Pan camera (coasts to a stop on release)
+//! - Scroll: Zoom in/out, toward the point under the cursor
 //! - Arrow keys: Pan camera
 //! - R: Reset rays
 //! - Space: Toggle continuous ray emission
-//! - +/-: Adjust black hole mass
+//! - +/-: Adjust the selected mass
+//! - F: Toggle the potential/deflection field contour overlay
+//! - G: Cycle the overlay's iso-contour density
+//! - N: Add a mass near the selected one
+//! - X: Remove the selected mass (at least one always remains)
+//! - Tab: Select the next mass
+//! - I/K/J/L: Nudge the selected mass up/down/left/right
+//! - O: Toggle binary orbital motion (active with exactly two masses)
+//! - M: Toggle the detector-plane lensing map (intensity histogram and
+//!   deflection curve) for the selected mass
+//! - ,/.: While the lensing map is active, sweep the source off-axis so the
+//!   Einstein ring splits into arcs
 
 mod physics;
 mod renderer;
 mod equations_ui;
+mod field;
+mod lensing_map;
 
 use common::{Camera2D, GraphicsContext};
+use field::FieldKind;
 use glam::{Vec2, Vec3};
-use physics::{BlackHole, LightRay2D};
-use renderer::Renderer2D;
+use lensing_map::LensingMap;
+use physics::{BinaryOrbit, BlackHole, LightRay2D};
+use renderer::{Renderer2D, MAX_BLACK_HOLES};
 use equations_ui::{draw_equations_sidebar, BLACK_HOLE_2D_EQUATIONS, BLACK_HOLE_2D_VARIABLES};
-use std::f32::consts::PI;
+use std::f32::consts::{PI, TAU};
 use winit::{
     event::{ElementState, Event, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
@@ -43,12 +59,36 @@ struct App {
     ctx: GraphicsContext,
     renderer: Renderer2D,
     camera: Camera2D,
-    black_hole: BlackHole,
+    black_holes: Vec<BlackHole>,
+    /// Index into `black_holes` that +/-, N, X, Tab and the nudge keys act on
+    selected_mass: usize,
+    /// When set (and `black_holes.len() == 2`), overrides both masses'
+    /// positions each frame from a circular orbit about their barycenter
+    binary_orbit: Option<BinaryOrbit>,
     rays: Vec<LightRay2D>,
     time: f32,
     continuous_emission: bool,
     emission_angle: f32,
     egui: EguiState,
+    /// Latest cursor position in physical pixels (origin top-left, Y down),
+    /// updated on every `CursorMoved` so a later `MouseInput`/`MouseWheel`
+    /// event always has a position to act on
+    cursor_pos: (f64, f64),
+    /// Which button is currently driving a camera drag, if any
+    drag_button: Option<MouseButton>,
+    /// Cursor position at the start of the drag / after the last processed
+    /// `CursorMoved` while dragging
+    last_drag_pos: (f64, f64),
+    /// World-units-per-second estimate of the drag, carried over after
+    /// release so panning coasts to a stop instead of snapping still
+    pan_velocity: Vec2,
+    /// Whether the marching-squares field-overlay contours are drawn
+    show_field_overlay: bool,
+    /// Number of iso-contours the overlay draws; cycled with a key
+    field_density: usize,
+    /// Detector-plane lensing map for the selected mass, rebuilt whenever it
+    /// is toggled on or the source is swept; `None` while hidden
+    lensing_map: Option<LensingMap>,
 }
 
 impl App {
@@ -57,7 +97,7 @@ impl App {
         let mut camera = Camera2D::new(ctx.aspect_ratio());
         camera.zoom = 15.0;
 
-        let black_hole = BlackHole::new(Vec3::ZERO, 1.0);
+        let black_holes = vec![BlackHole::new(Vec3::ZERO, 1.0)];
 
         // Initial rays from the right side
         let rays = Self::create_parallel_rays(Vec2::new(10.0, 0.0), -PI, 20, 8.0);
@@ -81,7 +121,9 @@ impl App {
             ctx,
             renderer,
             camera,
-            black_hole,
+            black_holes,
+            selected_mass: 0,
+            binary_orbit: None,
             rays,
             time: 0.0,
             continuous_emission: false,
@@ -91,9 +133,37 @@ impl App {
                 state: egui_state,
                 renderer: egui_renderer,
             },
+            cursor_pos: (0.0, 0.0),
+            drag_button: None,
+            last_drag_pos: (0.0, 0.0),
+            pan_velocity: Vec2::ZERO,
+            show_field_overlay: false,
+            field_density: 5,
+            lensing_map: None,
         }
     }
 
+    /// Current camera view box in world space, `(min, max)`
+    fn view_bounds(&self) -> (Vec2, Vec2) {
+        let half = Vec2::new(self.camera.zoom * self.camera.aspect_ratio, self.camera.zoom);
+        let center = Vec2::new(self.camera.position.x, self.camera.position.y);
+        (center - half, center + half)
+    }
+
+    /// Mass-weighted center of all current black holes, e.g. for aiming
+    /// click-spawned rays at the system as a whole rather than one hole
+    fn system_barycenter(&self) -> Vec2 {
+        let total_mass: f32 = self.black_holes.iter().map(|bh| bh.mass).sum();
+        if total_mass <= 0.0 {
+            return Vec2::ZERO;
+        }
+        self.black_holes
+            .iter()
+            .map(|bh| Vec2::new(bh.position.x, bh.position.y) * bh.mass)
+            .fold(Vec2::ZERO, |a, b| a + b)
+            / total_mass
+    }
+
     fn create_parallel_rays(origin: Vec2, angle: f32, count: usize, spread: f32) -> Vec<LightRay2D> {
         let mut rays = Vec::new();
         let direction = Vec2::new(angle.cos(), angle.sin());
@@ -134,21 +204,70 @@ impl App {
     }
 
     fn trace_all_rays(&mut self) {
-        let bh_pos = Vec2::new(self.black_hole.position.x, self.black_hole.position.y);
+        let black_holes = &self.black_holes;
+        for ray in self.rays.iter_mut() {
+            Self::trace_ray(ray, black_holes);
+        }
+    }
 
-        for ray in &mut self.rays {
-            ray.trace(bh_pos, self.black_hole.mass, 2000, 0.05);
+    /// Trace a single ray against `black_holes`: the exact single-body
+    /// geodesic integrator ([`LightRay2D::trace`]) when there's exactly one
+    /// mass, since it's more accurate than the general case, or the
+    /// superposed multi-body integrator ([`LightRay2D::trace_multi`])
+    /// otherwise
+    fn trace_ray(ray: &mut LightRay2D, black_holes: &[BlackHole]) {
+        match black_holes {
+            [] => {}
+            [bh] => {
+                let bh_pos = Vec2::new(bh.position.x, bh.position.y);
+                ray.trace(bh_pos, bh.mass, 2000, 0.05);
+            }
+            _ => {
+                ray.trace_multi(black_holes, 4000, 0.03);
+            }
+        }
+    }
+
+    /// Rebuild the lensing map's ray bundle against the selected mass, if the
+    /// map is currently shown
+    fn recompute_lensing_map(&mut self) {
+        if let Some(map) = &mut self.lensing_map {
+            let bh = &self.black_holes[self.selected_mass.min(self.black_holes.len() - 1)];
+            map.recompute(bh, 8.0, 400);
         }
     }
 
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.ctx.resize(new_size);
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
+        self.renderer.resize(&self.ctx, new_size.width, new_size.height);
     }
 
     fn update(&mut self, dt: f32) {
         self.time += dt;
 
+        // Coast the drag velocity to a stop once the button is released,
+        // same exponential-decay shape `CameraController` uses for its own
+        // damped movement
+        if self.drag_button.is_none() && self.pan_velocity.length_squared() > 1e-6 {
+            self.camera.position.x += self.pan_velocity.x * dt;
+            self.camera.position.y += self.pan_velocity.y * dt;
+            self.pan_velocity *= (-6.0 * dt).exp();
+        }
+
+        // Re-derive both masses' positions from the orbit each frame and
+        // re-trace every ray so the lensing pattern animates live
+        if let Some(orbit) = self.binary_orbit {
+            if let [a, b] = self.black_holes.as_mut_slice() {
+                let mass_ratio = a.mass / (a.mass + b.mass).max(1e-6);
+                let (pos_a, pos_b) = orbit.positions(self.time, mass_ratio);
+                a.position = Vec3::new(pos_a.x, pos_a.y, 0.0);
+                b.position = Vec3::new(pos_b.x, pos_b.y, 0.0);
+            }
+            self.trace_all_rays();
+            self.recompute_lensing_map();
+        }
+
         if self.continuous_emission {
             self.emission_angle += dt * 0.5;
 
@@ -159,8 +278,7 @@ impl App {
             );
 
             let mut new_ray = LightRay2D::new(source, -source.normalize());
-            let bh_pos = Vec2::new(self.black_hole.position.x, self.black_hole.position.y);
-            new_ray.trace(bh_pos, self.black_hole.mass, 2000, 0.05);
+            Self::trace_ray(&mut new_ray, &self.black_holes);
 
             self.rays.push(new_ray);
 
@@ -179,13 +297,35 @@ impl App {
 
         self.renderer.update_camera(&self.ctx.queue, &self.camera);
         self.renderer
-            .update_black_hole(&self.ctx.queue, &self.black_hole, self.time);
-
-        let ray_ranges = self.renderer.update_rays(&self.ctx.queue, &self.rays);
+            .update_black_holes(&self.ctx.queue, &self.black_holes, self.time);
+
+        let mut ray_ranges =
+            self.renderer
+                .update_rays(&self.ctx.device, &self.ctx.queue, &self.rays);
+        let ray_vertex_count = ray_ranges
+            .iter()
+            .map(|(start, count)| start + count)
+            .max()
+            .unwrap_or(0);
+
+        if self.show_field_overlay {
+            // The overlay contours a single scalar field, so with more than
+            // one mass it follows whichever is selected
+            let bh = &self.black_holes[self.selected_mass.min(self.black_holes.len() - 1)];
+            let (view_min, view_max) = self.view_bounds();
+            let kind = FieldKind::Potential;
+            let iso_values =
+                field::default_iso_values(bh, kind, view_min, view_max, self.field_density);
+            let segments = field::extract_contours(bh, kind, view_min, view_max, 48, &iso_values);
+            ray_ranges.extend(self.renderer.update_field_overlay(
+                &self.ctx.queue,
+                &segments,
+                ray_vertex_count,
+            ));
+        }
 
         // Build egui UI
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
-        let schwarzschild_radius = 2.0 * self.black_hole.mass;
         let full_output = self.egui.ctx.run(raw_input, |ctx| {
             draw_equations_sidebar(
                 ctx,
@@ -194,14 +334,47 @@ impl App {
                 BLACK_HOLE_2D_VARIABLES,
             );
 
+            if let Some(map) = &self.lensing_map {
+                map.draw(ctx);
+            }
+
             egui::TopBottomPanel::top("status").show(ctx, |ui| {
                 ui.horizontal(|ui| {
-                    ui.label(format!("Mass: {:.2}", self.black_hole.mass));
-                    ui.separator();
-                    ui.label(format!("râ‚›: {:.2}", schwarzschild_radius));
-                    ui.separator();
+                    for (i, bh) in self.black_holes.iter().enumerate() {
+                        let label = format!("#{}: M={:.2} râ‚›={:.2}", i, bh.mass, bh.schwarzschild_radius);
+                        if i == self.selected_mass {
+                            ui.label(egui::RichText::new(label).color(egui::Color32::YELLOW));
+                        } else {
+                            ui.label(label);
+                        }
+                        ui.separator();
+                    }
                     ui.label(format!("Rays: {}", self.rays.len()));
                     ui.separator();
+                    let max_deflection = self
+                        .rays
+                        .iter()
+                        .filter(|r| !r.captured)
+                        .map(|r| r.deflection)
+                        .fold(0.0_f32, f32::max);
+                    ui.label(format!("Max deflection: {:.2}°", max_deflection.to_degrees()));
+                    ui.separator();
+                    if self.binary_orbit.is_some() {
+                        ui.label(egui::RichText::new("ORBITING (O)").color(egui::Color32::LIGHT_BLUE));
+                        ui.separator();
+                    }
+                    if self.show_field_overlay {
+                        ui.label(format!("Field overlay: {} contours (F/G)", self.field_density));
+                        ui.separator();
+                    }
+                    if let Some(map) = &self.lensing_map {
+                        ui.label(egui::RichText::new(format!(
+                            "LENSING MAP (M, ,/. offset={:.1})",
+                            map.source_offset
+                        ))
+                        .color(egui::Color32::LIGHT_GREEN));
+                        ui.separator();
+                    }
                     if self.continuous_emission {
                         ui.label(egui::RichText::new("EMITTING").color(egui::Color32::GREEN));
                     } else {
@@ -229,7 +402,8 @@ impl App {
                 label: Some("Render Encoder"),
             });
 
-        self.renderer.render(&mut encoder, &view, &ray_ranges);
+        self.renderer
+            .render(&mut encoder, &view, &ray_ranges, self.black_holes.len() as u32);
 
         self.egui.renderer.update_buffers(
             &self.ctx.device,
@@ -277,12 +451,13 @@ impl App {
 
         let click_pos = Vec2::new(world_x, world_y);
 
-        // Create rays pointing toward the black hole
-        let mut new_rays = Self::create_radial_rays(click_pos, Vec2::ZERO, 15, 0.3);
+        // Create rays pointing toward the (possibly several) black holes'
+        // shared center of mass
+        let target = self.system_barycenter();
+        let mut new_rays = Self::create_radial_rays(click_pos, target, 15, 0.3);
 
-        let bh_pos = Vec2::new(self.black_hole.position.x, self.black_hole.position.y);
         for ray in &mut new_rays {
-            ray.trace(bh_pos, self.black_hole.mass, 2000, 0.05);
+            Self::trace_ray(ray, &self.black_holes);
         }
 
         self.rays.extend(new_rays);
@@ -311,26 +486,159 @@ impl App {
                 }
             }
             KeyCode::Equal | KeyCode::NumpadAdd => {
-                self.black_hole = BlackHole::new(Vec3::ZERO, self.black_hole.mass * 1.2);
-                self.rays = Self::create_parallel_rays(Vec2::new(10.0, 0.0), -PI, 20, 8.0);
+                self.rescale_selected_mass(1.2);
                 self.trace_all_rays();
+                self.recompute_lensing_map();
             }
             KeyCode::Minus | KeyCode::NumpadSubtract => {
-                self.black_hole = BlackHole::new(Vec3::ZERO, (self.black_hole.mass / 1.2).max(0.1));
-                self.rays = Self::create_parallel_rays(Vec2::new(10.0, 0.0), -PI, 20, 8.0);
+                self.rescale_selected_mass(1.0 / 1.2);
                 self.trace_all_rays();
+                self.recompute_lensing_map();
             }
             KeyCode::ArrowUp | KeyCode::KeyW => self.camera.position.y += self.camera.zoom * 0.1,
             KeyCode::ArrowDown | KeyCode::KeyS => self.camera.position.y -= self.camera.zoom * 0.1,
             KeyCode::ArrowLeft | KeyCode::KeyA => self.camera.position.x -= self.camera.zoom * 0.1,
             KeyCode::ArrowRight | KeyCode::KeyD => self.camera.position.x += self.camera.zoom * 0.1,
+            KeyCode::KeyF => self.show_field_overlay = !self.show_field_overlay,
+            KeyCode::KeyG => {
+                self.field_density = match self.field_density {
+                    3 => 5,
+                    5 => 8,
+                    8 => 12,
+                    _ => 3,
+                }
+            }
+            KeyCode::KeyN => {
+                // Add a new mass offset from the selected one, up to however
+                // many the renderer's fixed-size GPU buffer can hold;
+                // disables any active orbit, since it no longer describes
+                // exactly two bodies
+                if self.black_holes.len() < MAX_BLACK_HOLES {
+                    self.binary_orbit = None;
+                    let offset = Vec3::new(4.0, 0.0, 0.0);
+                    let mass = self.black_holes[self.selected_mass].mass;
+                    let position = self.black_holes[self.selected_mass].position + offset;
+                    self.black_holes.push(BlackHole::new(position, mass));
+                    self.selected_mass = self.black_holes.len() - 1;
+                    self.trace_all_rays();
+                    self.recompute_lensing_map();
+                }
+            }
+            KeyCode::KeyX => {
+                if self.black_holes.len() > 1 {
+                    self.black_holes.remove(self.selected_mass);
+                    self.selected_mass = self.selected_mass.min(self.black_holes.len() - 1);
+                    if self.black_holes.len() != 2 {
+                        self.binary_orbit = None;
+                    }
+                    self.trace_all_rays();
+                    self.recompute_lensing_map();
+                }
+            }
+            KeyCode::Tab => {
+                self.selected_mass = (self.selected_mass + 1) % self.black_holes.len();
+                self.recompute_lensing_map();
+            }
+            KeyCode::KeyI | KeyCode::KeyK | KeyCode::KeyJ | KeyCode::KeyL => {
+                if self.binary_orbit.is_none() {
+                    let step = self.camera.zoom * 0.05;
+                    let delta = match key {
+                        KeyCode::KeyI => Vec3::new(0.0, step, 0.0),
+                        KeyCode::KeyK => Vec3::new(0.0, -step, 0.0),
+                        KeyCode::KeyJ => Vec3::new(-step, 0.0, 0.0),
+                        _ => Vec3::new(step, 0.0, 0.0),
+                    };
+                    self.black_holes[self.selected_mass].position += delta;
+                    self.trace_all_rays();
+                    self.recompute_lensing_map();
+                }
+            }
+            KeyCode::KeyO => {
+                if self.binary_orbit.is_some() {
+                    self.binary_orbit = None;
+                } else if let [a, b] = self.black_holes.as_slice() {
+                    let separation = (a.position - b.position).length().max(1.0);
+                    let period = TAU * (separation.powi(3) / (a.mass + b.mass).max(1e-3)).sqrt();
+                    self.binary_orbit = Some(BinaryOrbit::new(separation, period));
+                }
+            }
+            KeyCode::KeyM => {
+                if self.lensing_map.is_some() {
+                    self.lensing_map = None;
+                } else {
+                    self.lensing_map = Some(LensingMap::new(25.0, 6.0, 40));
+                    self.recompute_lensing_map();
+                }
+            }
+            KeyCode::Comma | KeyCode::Period => {
+                if self.lensing_map.is_some() {
+                    let step = match key {
+                        KeyCode::Comma => -0.2,
+                        _ => 0.2,
+                    };
+                    if let Some(map) = &mut self.lensing_map {
+                        map.source_offset += step;
+                    }
+                    self.recompute_lensing_map();
+                }
+            }
             _ => {}
         }
     }
 
+    /// Scale the selected mass's `BlackHole` by `factor`, keeping its
+    /// position and recomputing `schwarzschild_radius`/a fresh horizon via
+    /// `BlackHole::new`
+    fn rescale_selected_mass(&mut self, factor: f32) {
+        let bh = &mut self.black_holes[self.selected_mass];
+        *bh = BlackHole::new(bh.position, (bh.mass * factor).max(0.1));
+    }
+
     fn handle_scroll(&mut self, delta: f32) {
-        self.camera.zoom *= 1.0 - delta * 0.1;
-        self.camera.zoom = self.camera.zoom.clamp(1.0, 50.0);
+        let ndc_x = (self.cursor_pos.0 as f32 / self.ctx.size.width as f32) * 2.0 - 1.0;
+        let ndc_y = 1.0 - (self.cursor_pos.1 as f32 / self.ctx.size.height as f32) * 2.0;
+        let factor = 1.0 - delta * 0.1;
+        self.camera
+            .zoom_toward(Vec2::new(ndc_x, ndc_y), factor, 1.0, 50.0);
+    }
+
+    /// Track the cursor position and, if a drag button is held, pan the
+    /// camera by the movement since the last event and refresh the coasting
+    /// velocity for when the button is released
+    fn handle_cursor_moved(&mut self, x: f64, y: f64) {
+        if self.drag_button.is_some() {
+            let dx = (x - self.last_drag_pos.0) as f32;
+            let dy = (y - self.last_drag_pos.1) as f32;
+            let delta = self.camera.pan_screen(
+                dx,
+                dy,
+                self.ctx.size.width as f32,
+                self.ctx.size.height as f32,
+            );
+
+            // Events arrive roughly once per frame at display refresh rate;
+            // treat this event's delta as "per 1/60s" to get a world-per-
+            // second estimate for the post-release coast
+            self.pan_velocity = delta * 60.0;
+            self.last_drag_pos = (x, y);
+        }
+
+        self.cursor_pos = (x, y);
+    }
+
+    /// Begin a middle/right-drag pan: latch the drag button and reset the
+    /// coasting velocity so a fresh drag doesn't inherit the last one's coast
+    fn start_drag(&mut self, button: MouseButton) {
+        self.drag_button = Some(button);
+        self.last_drag_pos = self.cursor_pos;
+        self.pan_velocity = Vec2::ZERO;
+    }
+
+    /// Release a drag, letting `update` coast `pan_velocity` to a stop
+    fn end_drag(&mut self, button: MouseButton) {
+        if self.drag_button == Some(button) {
+            self.drag_button = None;
+        }
     }
 
     fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
@@ -364,15 +672,21 @@ fn main() {
                         match event {
                             WindowEvent::CloseRequested => elwt.exit(),
                             WindowEvent::Resized(size) => app.resize(*size),
-                            WindowEvent::MouseInput {
-                                state: ElementState::Pressed,
-                                button: MouseButton::Left,
-                                ..
-                            } => {
-                                // Will be handled with cursor position
-                            }
+                            WindowEvent::MouseInput { state, button, .. } => match (button, state) {
+                                (MouseButton::Left, ElementState::Pressed) => {
+                                    let (x, y) = app.cursor_pos;
+                                    app.handle_click(x, y);
+                                }
+                                (MouseButton::Middle | MouseButton::Right, ElementState::Pressed) => {
+                                    app.start_drag(*button);
+                                }
+                                (MouseButton::Middle | MouseButton::Right, ElementState::Released) => {
+                                    app.end_drag(*button);
+                                }
+                                _ => {}
+                            },
                             WindowEvent::CursorMoved { position, .. } => {
-                                // Store for click handling
+                                app.handle_cursor_moved(position.x, position.y);
                             }
                             WindowEvent::KeyboardInput {
                                 event:
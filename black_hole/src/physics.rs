@@ -3,19 +3,23 @@
 //! Implements Schwarzschild spacetime geodesics for simulating
 //! how light bends around a non-rotating black hole.
 
-use glam::{Vec2, Vec3};
-use std::f32::consts::PI;
+use glam::{Quat, Vec2, Vec3};
+use std::f32::consts::{PI, TAU};
 
 /// Physical constants (scaled for simulation)
 pub const C: f32 = 1.0; // Speed of light (normalized)
 pub const G: f32 = 1.0; // Gravitational constant (normalized)
 
-/// Schwarzschild black hole
+/// Schwarzschild (or Kerr, once spun up) black hole
 #[derive(Debug, Clone, Copy)]
 pub struct BlackHole {
     pub position: Vec3,
     pub mass: f32,
     pub schwarzschild_radius: f32,
+    /// Spin parameter `a = J/(Mc)`, in the same length units as `mass` since
+    /// this module normalizes `G = c = 1`. Zero means Schwarzschild; nonzero
+    /// switches the geometry to Kerr and enables frame dragging.
+    pub spin: f32,
 }
 
 impl BlackHole {
@@ -27,9 +31,19 @@ impl BlackHole {
             position,
             mass,
             schwarzschild_radius,
+            spin: 0.0,
         }
     }
 
+    /// Spin the black hole up, clamped to `|a| <= GM/c²` (the Kerr bound
+    /// beyond which the horizon formula would go imaginary, i.e. a naked
+    /// singularity)
+    pub fn with_spin(mut self, spin: f32) -> Self {
+        let max_spin = G * self.mass / (C * C);
+        self.spin = spin.clamp(-max_spin, max_spin);
+        self
+    }
+
     /// Check if a point is inside the event horizon
     pub fn is_inside_horizon(&self, point: Vec3) -> bool {
         (point - self.position).length() < self.schwarzschild_radius
@@ -43,6 +57,55 @@ impl BlackHole {
         }
         -G * self.mass / r
     }
+
+    /// Angular momentum `J = aMc`
+    pub fn angular_momentum(&self) -> f32 {
+        self.spin * self.mass * C
+    }
+
+    /// Outer/inner Kerr horizons `r± = GM/c² ± √((GM/c²)² − a²)`
+    pub fn horizons(&self) -> (f32, f32) {
+        let rg = G * self.mass / (C * C);
+        let discriminant = (rg * rg - self.spin * self.spin).max(0.0).sqrt();
+        (rg + discriminant, rg - discriminant)
+    }
+
+    /// Ergosphere boundary at colatitude `theta` (radians from the spin
+    /// axis): `r_ergo = GM/c² + √((GM/c²)² − a²cos²θ)`
+    pub fn ergosphere_radius(&self, theta: f32) -> f32 {
+        let rg = G * self.mass / (C * C);
+        let cos_theta = theta.cos();
+        let discriminant = (rg * rg - self.spin * self.spin * cos_theta * cos_theta).max(0.0);
+        rg + discriminant.sqrt()
+    }
+
+    /// Prograde/retrograde innermost stable circular orbit radii (Bardeen-
+    /// Press-Teukolsky formula), in the same length units as `mass`
+    pub fn isco_radii(&self) -> (f32, f32) {
+        let rg = G * self.mass / (C * C);
+        if rg < 1e-9 {
+            return (0.0, 0.0);
+        }
+        let a_star = (self.spin / rg).clamp(-1.0, 1.0);
+
+        let z1 = 1.0
+            + (1.0 - a_star * a_star).cbrt() * ((1.0 + a_star).cbrt() + (1.0 - a_star).cbrt());
+        let z2 = (3.0 * a_star * a_star + z1 * z1).sqrt();
+        let gap = ((3.0 - z1) * (3.0 + z1 + 2.0 * z2)).max(0.0).sqrt();
+
+        let prograde = rg * (3.0 + z2 - gap);
+        let retrograde = rg * (3.0 + z2 + gap);
+        (prograde, retrograde)
+    }
+
+    /// Lense-Thirring frame-dragging angular velocity at radius `r`:
+    /// `Ω ≈ 2GJ/(c²r³)`
+    pub fn frame_drag_omega(&self, r: f32) -> f32 {
+        if r < 1e-6 {
+            return 0.0;
+        }
+        2.0 * G * self.angular_momentum() / (C * C * r * r * r)
+    }
 }
 
 impl Default for BlackHole {
@@ -51,6 +114,58 @@ impl Default for BlackHole {
     }
 }
 
+/// Circular two-body orbit about a fixed barycenter, for animating a binary
+/// black hole system: `positions` is evaluated directly from elapsed time
+/// (same style as `solar_voyage::ephemeris::OrbitalElements::state_at_time`)
+/// rather than integrated step by step, so the orbit never accumulates error
+/// and can be scrubbed or reset for free.
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryOrbit {
+    /// Distance between the two masses, in the same length units as
+    /// `BlackHole::mass`
+    pub separation: f32,
+    /// Orbital period, in the same time units as the `t` passed to
+    /// `positions`
+    pub period: f32,
+    /// Orbital phase at `t = 0`, radians
+    pub phase0: f32,
+}
+
+impl BinaryOrbit {
+    pub fn new(separation: f32, period: f32) -> Self {
+        Self {
+            separation,
+            period,
+            phase0: 0.0,
+        }
+    }
+
+    /// World-space positions `(body_a, body_b)` at time `t`, splitting
+    /// `separation` across the barycenter in inverse proportion to mass so
+    /// heavier bodies sit closer to the center (`mass_ratio = mass_a /
+    /// (mass_a + mass_b)`)
+    pub fn positions(&self, t: f32, mass_ratio: f32) -> (Vec2, Vec2) {
+        let mass_ratio = mass_ratio.clamp(0.0, 1.0);
+        let theta = self.phase0 + TAU * t / self.period.max(1e-3);
+        let dir = Vec2::new(theta.cos(), theta.sin());
+
+        let r_a = self.separation * (1.0 - mass_ratio);
+        let r_b = self.separation * mass_ratio;
+
+        (dir * r_a, -dir * r_b)
+    }
+}
+
+/// Which light-bending model a [`LightRay`] trace uses. `Newtonian` is the
+/// original `-GM/r²` force (plus its ad-hoc photon-sphere `enhancement`
+/// fudge) driving [`LightRay::trace`]; `Exact` is the true Schwarzschild
+/// null-geodesic orbit equation driving [`LightRay::trace_geodesic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BendingModel {
+    Newtonian,
+    Exact,
+}
+
 /// A light ray for ray tracing through curved spacetime
 #[derive(Debug, Clone, Copy)]
 pub struct LightRay {
@@ -94,10 +209,244 @@ impl LightRay {
 
         Some(dir)
     }
+
+    /// Trace the ray per `model`, picking between the Newtonian
+    /// approximation ([`LightRay::trace`]) and the exact Schwarzschild
+    /// null-geodesic orbit equation ([`LightRay::trace_geodesic`])
+    pub fn trace_with_model(&self, black_hole: &BlackHole, steps: usize, step_size: f32, model: BendingModel) -> Option<Vec3> {
+        match model {
+            BendingModel::Newtonian => self.trace(black_hole, steps, step_size),
+            BendingModel::Exact => self.trace_geodesic(black_hole, steps, step_size),
+        }
+    }
+
+    /// Adaptive-step variant of [`LightRay::trace_geodesic`]: instead of a
+    /// fixed `dφ`, each step is taken with embedded Runge–Kutta–Fehlberg
+    /// (RKF45) — 4th- and 5th-order estimates from the same six stage
+    /// evaluations via [`rkf45_orbit_step`] — and accepted only once their
+    /// difference (the local error) is below `tolerance`. The next `dφ` is
+    /// then rescaled by `0.9·(tolerance/error)^0.2`, clamped to 0.1×–5× the
+    /// current step, so large steps are spent in near-flat space while the
+    /// step shrinks automatically near the horizon/photon sphere where `u`
+    /// changes fastest. Falls back to [`LightRay::trace`] in the same
+    /// degenerate-plane cases `trace_geodesic` does. Returns the escaped
+    /// direction (or `None` on capture) plus the number of steps actually
+    /// taken, bounded by `max_steps`.
+    pub fn trace_adaptive(&self, black_hole: &BlackHole, tolerance: f32, max_steps: usize) -> (Option<Vec3>, usize) {
+        let rs = black_hole.schwarzschild_radius;
+        let r_vec0 = self.position - black_hole.position;
+        let r0 = r_vec0.length();
+
+        if r0 < rs {
+            return (None, 0);
+        }
+
+        let plane_normal = r_vec0.cross(self.direction).normalize_or_zero();
+        if plane_normal.length_squared() < 1e-9 {
+            return (self.trace(black_hole, max_steps, 0.01), max_steps);
+        }
+
+        let r_hat0 = r_vec0 / r0;
+        let phi_hat0 = plane_normal.cross(r_hat0).normalize_or_zero();
+
+        let v_r = self.direction.dot(r_hat0);
+        let v_t = self.direction.dot(phi_hat0);
+        if v_t.abs() < 1e-6 {
+            return (self.trace(black_hole, max_steps, 0.01), max_steps);
+        }
+
+        let mut u = 1.0 / r0;
+        let mut du_dphi = -v_r / (v_t * r0);
+        let mut phi = 0.0_f32;
+        let mut dphi = 0.01_f32;
+        let mut steps_taken = 0;
+
+        while steps_taken < max_steps {
+            let ((u4, du4), (u5, du5)) = rkf45_orbit_step(u, du_dphi, rs, dphi);
+            let error = ((u5 - u4).powi(2) + (du5 - du4).powi(2)).sqrt();
+
+            if error <= tolerance || dphi <= 1e-6 {
+                u = u5;
+                du_dphi = du5;
+                phi += dphi;
+                steps_taken += 1;
+
+                if u <= 0.0 {
+                    // Swept past escape (r → ∞) between steps
+                    break;
+                }
+                if 1.0 / u <= rs {
+                    return (None, steps_taken);
+                }
+                if 1.0 / u >= 50.0 {
+                    break;
+                }
+            }
+
+            let scale = if error > 1e-12 {
+                0.9 * (tolerance / error).powf(0.2)
+            } else {
+                5.0
+            };
+            dphi *= scale.clamp(0.1, 5.0);
+        }
+
+        let r_hat_now = Quat::from_axis_angle(plane_normal, phi) * r_hat0;
+        let phi_hat_now = plane_normal.cross(r_hat_now).normalize_or_zero();
+
+        let r_now = (1.0 / u).max(1e-6);
+        let dr_dphi = -du_dphi / (u * u).max(1e-9);
+        let radial_component = dr_dphi / r_now;
+
+        let dir = (r_hat_now * radial_component + phi_hat_now).normalize_or_zero();
+        (Some(dir), steps_taken)
+    }
+
+    /// Trace the ray using the exact light-bending orbit equation in the
+    /// equatorial plane: with `u = 1/r` as a function of the azimuthal
+    /// angle `φ`, light obeys `d²u/dφ² + u = (3/2)·rs·u²` — the
+    /// `(3/2)rs·u²` term is the full general-relativistic correction that
+    /// produces correct deflection and a genuine unstable photon sphere at
+    /// `r = 1.5·rs`, unlike `trace`'s Newtonian force plus photon-sphere
+    /// `enhancement` hack.
+    ///
+    /// The incoming 3D ray is projected into its orbital plane (spanned by
+    /// the initial radius vector and direction, since a central force keeps
+    /// a geodesic planar), `(u, du/dφ)` is stepped forward with RK4 in `φ`
+    /// using `step_size` as `dφ`, and the 3D direction is reconstructed by
+    /// rotating the plane's radial basis vector by the total angle swept.
+    /// Returns `None` on horizon capture (`u → 1/rs`), `Some(direction)` on
+    /// escape past `r = 50`, matching `trace`'s contract.
+    pub fn trace_geodesic(&self, black_hole: &BlackHole, steps: usize, step_size: f32) -> Option<Vec3> {
+        let rs = black_hole.schwarzschild_radius;
+        let r_vec0 = self.position - black_hole.position;
+        let r0 = r_vec0.length();
+
+        if r0 < rs {
+            return None;
+        }
+
+        let plane_normal = r_vec0.cross(self.direction).normalize_or_zero();
+        if plane_normal.length_squared() < 1e-9 {
+            // Direction points straight at (or away from) the black hole:
+            // there is no well-defined orbital plane, so fall back to the
+            // Newtonian integrator rather than divide by a near-zero
+            // tangential component below
+            return self.trace(black_hole, steps, step_size);
+        }
+
+        let r_hat0 = r_vec0 / r0;
+        let phi_hat0 = plane_normal.cross(r_hat0).normalize_or_zero();
+
+        let v_r = self.direction.dot(r_hat0);
+        let v_t = self.direction.dot(phi_hat0);
+        if v_t.abs() < 1e-6 {
+            return self.trace(black_hole, steps, step_size);
+        }
+
+        let mut u = 1.0 / r0;
+        let mut du_dphi = -v_r / (v_t * r0);
+        let mut phi = 0.0_f32;
+
+        for _ in 0..steps {
+            let (new_u, new_du) = rk4_orbit_step(u, du_dphi, rs, step_size);
+            u = new_u;
+            du_dphi = new_du;
+            phi += step_size;
+
+            if u <= 0.0 {
+                // Swept past escape (r → ∞) between steps
+                break;
+            }
+
+            if 1.0 / u <= rs {
+                return None;
+            }
+
+            if 1.0 / u >= 50.0 {
+                break;
+            }
+        }
+
+        let r_hat_now = Quat::from_axis_angle(plane_normal, phi) * r_hat0;
+        let phi_hat_now = plane_normal.cross(r_hat_now).normalize_or_zero();
+
+        let r_now = (1.0 / u).max(1e-6);
+        let dr_dphi = -du_dphi / (u * u).max(1e-9);
+        let radial_component = dr_dphi / r_now;
+
+        let dir = (r_hat_now * radial_component + phi_hat_now).normalize_or_zero();
+        Some(dir)
+    }
+}
+
+/// RK4 step of the Schwarzschild light-bending orbit equation
+/// `d²u/dφ² + u = (3/2)·rs·u²` over `dphi`, carried as the first-order
+/// system `(u, du/dφ)`
+fn rk4_orbit_step(u: f32, du: f32, rs: f32, dphi: f32) -> (f32, f32) {
+    let f = |u: f32, du: f32| (du, -u + 1.5 * rs * u * u);
+
+    let (k1_u, k1_du) = f(u, du);
+    let (k2_u, k2_du) = f(u + k1_u * dphi * 0.5, du + k1_du * dphi * 0.5);
+    let (k3_u, k3_du) = f(u + k2_u * dphi * 0.5, du + k2_du * dphi * 0.5);
+    let (k4_u, k4_du) = f(u + k3_u * dphi, du + k3_du * dphi);
+
+    let new_u = u + (k1_u + 2.0 * k2_u + 2.0 * k3_u + k4_u) * dphi / 6.0;
+    let new_du = du + (k1_du + 2.0 * k2_du + 2.0 * k3_du + k4_du) * dphi / 6.0;
+
+    (new_u, new_du)
+}
+
+/// One embedded Runge–Kutta–Fehlberg (RKF45) step of the Schwarzschild
+/// orbit equation `d²u/dφ² + u = (3/2)·rs·u²`, carried as `(u, du/dφ)`.
+/// Returns both the 4th-order and 5th-order estimates of the state after
+/// `dphi` from the same six stage evaluations; the difference between them
+/// is the local error estimate [`LightRay::trace_adaptive`] uses to accept
+/// or shrink the step.
+fn rkf45_orbit_step(u: f32, du: f32, rs: f32, dphi: f32) -> ((f32, f32), (f32, f32)) {
+    let f = |u: f32, du: f32| (du, -u + 1.5 * rs * u * u);
+
+    let (k1_u, k1_du) = f(u, du);
+    let (k2_u, k2_du) = f(u + dphi * (k1_u / 4.0), du + dphi * (k1_du / 4.0));
+    let (k3_u, k3_du) = f(
+        u + dphi * (3.0 / 32.0 * k1_u + 9.0 / 32.0 * k2_u),
+        du + dphi * (3.0 / 32.0 * k1_du + 9.0 / 32.0 * k2_du),
+    );
+    let (k4_u, k4_du) = f(
+        u + dphi * (1932.0 / 2197.0 * k1_u - 7200.0 / 2197.0 * k2_u + 7296.0 / 2197.0 * k3_u),
+        du + dphi * (1932.0 / 2197.0 * k1_du - 7200.0 / 2197.0 * k2_du + 7296.0 / 2197.0 * k3_du),
+    );
+    let (k5_u, k5_du) = f(
+        u + dphi * (439.0 / 216.0 * k1_u - 8.0 * k2_u + 3680.0 / 513.0 * k3_u - 845.0 / 4104.0 * k4_u),
+        du + dphi * (439.0 / 216.0 * k1_du - 8.0 * k2_du + 3680.0 / 513.0 * k3_du - 845.0 / 4104.0 * k4_du),
+    );
+    let (k6_u, k6_du) = f(
+        u + dphi
+            * (-8.0 / 27.0 * k1_u + 2.0 * k2_u - 3544.0 / 2565.0 * k3_u + 1859.0 / 4104.0 * k4_u
+                - 11.0 / 40.0 * k5_u),
+        du + dphi
+            * (-8.0 / 27.0 * k1_du + 2.0 * k2_du - 3544.0 / 2565.0 * k3_du + 1859.0 / 4104.0 * k4_du
+                - 11.0 / 40.0 * k5_du),
+    );
+
+    let u4 = u + dphi * (25.0 / 216.0 * k1_u + 1408.0 / 2565.0 * k3_u + 2197.0 / 4104.0 * k4_u - 1.0 / 5.0 * k5_u);
+    let du4 =
+        du + dphi * (25.0 / 216.0 * k1_du + 1408.0 / 2565.0 * k3_du + 2197.0 / 4104.0 * k4_du - 1.0 / 5.0 * k5_du);
+
+    let u5 = u
+        + dphi
+            * (16.0 / 135.0 * k1_u + 6656.0 / 12825.0 * k3_u + 28561.0 / 56430.0 * k4_u - 9.0 / 50.0 * k5_u
+                + 2.0 / 55.0 * k6_u);
+    let du5 = du
+        + dphi
+            * (16.0 / 135.0 * k1_du + 6656.0 / 12825.0 * k3_du + 28561.0 / 56430.0 * k4_du - 9.0 / 50.0 * k5_du
+                + 2.0 / 55.0 * k6_du);
+
+    ((u4, du4), (u5, du5))
 }
 
 /// Calculate gravitational acceleration (for light bending)
-fn gravitational_acceleration(pos: Vec3, black_hole: &BlackHole) -> Vec3 {
+fn gravitational_acceleration(pos: Vec3, vel: Vec3, black_hole: &BlackHole) -> Vec3 {
     let r_vec = pos - black_hole.position;
     let r = r_vec.length();
 
@@ -117,28 +466,44 @@ fn gravitational_acceleration(pos: Vec3, black_hole: &BlackHole) -> Vec3 {
         1.0
     };
 
-    -r_hat * (G * black_hole.mass / (r * r)) * enhancement * 3.0
+    let newtonian = -r_hat * (G * black_hole.mass / (r * r)) * enhancement * 3.0;
+    newtonian + frame_drag_acceleration(r, vel, black_hole)
+}
+
+/// Lense-Thirring frame dragging, approximated as a gravitomagnetic
+/// Lorentz-force term `a = -2v × Ω`, where `Ω` is the local frame-dragging
+/// angular velocity. This co-rotates light paths passing through the
+/// ergosphere of a spinning (Kerr) black hole; it vanishes for `spin == 0`.
+fn frame_drag_acceleration(r: f32, vel: Vec3, black_hole: &BlackHole) -> Vec3 {
+    if black_hole.spin.abs() < 1e-9 {
+        return Vec3::ZERO;
+    }
+
+    // The accretion disk lies in the XZ plane, so the spin axis is Y
+    let spin_axis = Vec3::Y;
+    let omega = spin_axis * black_hole.frame_drag_omega(r);
+    -2.0 * vel.cross(omega)
 }
 
 /// RK4 integration step for geodesic motion
 fn rk4_step(pos: Vec3, vel: Vec3, black_hole: &BlackHole, dt: f32) -> (Vec3, Vec3) {
     // k1
-    let a1 = gravitational_acceleration(pos, black_hole);
+    let a1 = gravitational_acceleration(pos, vel, black_hole);
     let k1_v = a1;
     let k1_x = vel;
 
     // k2
-    let a2 = gravitational_acceleration(pos + k1_x * dt * 0.5, black_hole);
+    let a2 = gravitational_acceleration(pos + k1_x * dt * 0.5, vel + k1_v * dt * 0.5, black_hole);
     let k2_v = a2;
     let k2_x = vel + k1_v * dt * 0.5;
 
     // k3
-    let a3 = gravitational_acceleration(pos + k2_x * dt * 0.5, black_hole);
+    let a3 = gravitational_acceleration(pos + k2_x * dt * 0.5, vel + k2_v * dt * 0.5, black_hole);
     let k3_v = a3;
     let k3_x = vel + k2_v * dt * 0.5;
 
     // k4
-    let a4 = gravitational_acceleration(pos + k3_x * dt, black_hole);
+    let a4 = gravitational_acceleration(pos + k3_x * dt, vel + k3_v * dt, black_hole);
     let k4_v = a4;
     let k4_x = vel + k3_v * dt;
 
@@ -154,6 +519,44 @@ pub struct LightRay2D {
     pub position: Vec2,
     pub direction: Vec2,
     pub path: Vec<Vec2>,
+    /// Whether the ray fell past the event horizon on its last `trace`
+    pub captured: bool,
+    /// Total bend of the ray relative to a straight line (`Δφ_total - π`),
+    /// valid once `trace` has run
+    pub deflection: f32,
+}
+
+/// Newtonian-plus-photon-sphere-fudge acceleration at `pos` from every hole
+/// in `black_holes`, summing each `-3·G·M_i·enhancement_i·r̂_i / |r - r_i|²` contribution —
+/// the 2D counterpart of `gravitational_acceleration`'s single-body formula,
+/// generalized by superposition since there's no single orbital plane once
+/// more than one mass is present. A hole the ray is already inside (within
+/// `1.01 * schwarzschild_radius`) contributes nothing, same horizon-adjacent
+/// guard `gravitational_acceleration` uses.
+fn multi_body_acceleration(pos: Vec2, black_holes: &[BlackHole]) -> Vec2 {
+    let mut accel = Vec2::ZERO;
+
+    for bh in black_holes {
+        let bh_pos = Vec2::new(bh.position.x, bh.position.y);
+        let r_vec = pos - bh_pos;
+        let r = r_vec.length();
+
+        if r < bh.schwarzschild_radius * 1.01 {
+            continue;
+        }
+
+        let r_hat = r_vec / r;
+        let photon_sphere = 1.5 * bh.schwarzschild_radius;
+        let enhancement = if r < photon_sphere * 2.0 {
+            1.0 + 2.0 * (photon_sphere / r).powi(2)
+        } else {
+            1.0
+        };
+
+        accel -= r_hat * (G * bh.mass / (r * r)) * enhancement * 3.0;
+    }
+
+    accel
 }
 
 impl LightRay2D {
@@ -162,54 +565,173 @@ impl LightRay2D {
             position,
             direction: direction.normalize(),
             path: vec![position],
+            captured: false,
+            deflection: 0.0,
         }
     }
 
-    /// Trace the ray and store the path
+    /// Trace the ray with the exact Schwarzschild null-geodesic orbit
+    /// equation, the 2D counterpart of [`LightRay::trace_geodesic`]: with
+    /// `u = 1/r` as a function of the orbital angle `φ`, light obeys
+    /// `d²u/dφ² + u = (3/2)·rs·u²`, stepped with RK4 via the shared
+    /// [`rk4_orbit_step`] instead of the old fixed-size Newtonian-plus-
+    /// photon-sphere-fudge stepping. `step_size` is the base `Δφ` at
+    /// `r = rs`; the actual step scales with `r` so it shrinks approaching
+    /// the photon sphere (where `u` changes fastest) and grows far away,
+    /// spending the `steps` budget where the bending actually happens.
+    ///
+    /// Returns `true` if the ray escapes past the view bound, `false` if
+    /// captured by the horizon; `self.captured` mirrors the result and
+    /// `self.deflection` holds the total deflection angle for display.
     pub fn trace(&mut self, black_hole_pos: Vec2, mass: f32, steps: usize, step_size: f32) -> bool {
         let rs = 2.0 * G * mass / (C * C);
-        let mut pos = self.position;
-        let mut dir = self.direction;
+        let view_bound = 30.0;
+
+        let r_vec0 = self.position - black_hole_pos;
+        let r0 = r_vec0.length();
+
+        if r0 <= rs {
+            self.captured = true;
+            self.deflection = 0.0;
+            self.path.push(black_hole_pos);
+            return false;
+        }
+
+        let r_hat0 = r_vec0 / r0;
+        // 90° rotation of r_hat0, i.e. the orbital-plane tangential basis
+        // vector (this is 2D, so there's only one choice of plane)
+        let phi_hat0 = Vec2::new(-r_hat0.y, r_hat0.x);
+
+        let v_r = self.direction.dot(r_hat0);
+        let v_t = self.direction.dot(phi_hat0);
+
+        if v_t.abs() < 1e-6 {
+            // Aimed straight at (or away from) the hole: no orbital plane
+            // for the angle-parametrized integrator to advance through, so
+            // just march the straight line to the horizon or the bound
+            self.captured = v_r < 0.0;
+            self.deflection = 0.0;
+            self.path
+                .push(if v_r < 0.0 { black_hole_pos } else { self.position + self.direction * view_bound });
+            return v_r >= 0.0;
+        }
+
+        let mut u = 1.0 / r0;
+        let mut du_dphi = -v_r / (v_t * r0);
+        let mut phi = 0.0_f32;
+        let mut captured = false;
 
         for _ in 0..steps {
-            let r_vec = pos - black_hole_pos;
-            let r = r_vec.length();
+            let r = 1.0 / u.max(1e-9);
+            let dphi = (step_size * (r / rs)).clamp(step_size * 0.05, step_size * 50.0);
+
+            let (new_u, new_du) = rk4_orbit_step(u, du_dphi, rs, dphi);
+            u = new_u;
+            du_dphi = new_du;
+            phi += dphi;
 
-            // Captured by black hole
-            if r < rs {
+            if u <= 0.0 {
+                // Swept past r -> infinity between steps
+                break;
+            }
+
+            let r_now = 1.0 / u;
+
+            let (sin_phi, cos_phi) = phi.sin_cos();
+            let r_hat_now = Vec2::new(
+                r_hat0.x * cos_phi - r_hat0.y * sin_phi,
+                r_hat0.x * sin_phi + r_hat0.y * cos_phi,
+            );
+            self.path
+                .push(black_hole_pos + r_hat_now * r_now.min(view_bound * 2.0));
+
+            if r_now <= rs {
+                captured = true;
                 self.path.push(black_hole_pos);
-                return false;
+                break;
             }
 
-            // Escaped to infinity
-            if r > 30.0 {
-                self.path.push(pos + dir * 10.0);
-                return true;
+            if r_now >= view_bound {
+                break;
             }
+        }
 
-            // Calculate bending
-            let r_hat = r_vec / r;
-            let photon_sphere = 1.5 * rs;
-            let enhancement = if r < photon_sphere * 2.0 {
-                1.0 + 3.0 * (photon_sphere / r).powi(2)
-            } else {
-                1.0
-            };
+        self.captured = captured;
+        self.deflection = phi - PI;
+        !captured
+    }
 
-            let accel = -r_hat * (G * mass / (r * r)) * enhancement * 3.0;
+    /// Trace the ray through an arbitrary number of black holes by direct
+    /// velocity-Verlet integration of `multi_body_acceleration`, rather than
+    /// `trace`'s single-body `u(φ)` orbit equation: with more than one mass
+    /// there's no single orbital plane to parametrize by angle, so position
+    /// and direction are stepped forward in world space instead, the same
+    /// way this method's single-body ancestor worked before `trace` was
+    /// rewritten to the exact geodesic integrator.
+    ///
+    /// Escape/capture are judged against each hole individually (captured if
+    /// within any hole's horizon; escaped once `steps * step_size` worth of
+    /// travel has carried it past `view_bound` from every hole). Deflection
+    /// is measured as the angle between the final and initial directions,
+    /// which degrades gracefully to `trace`'s `Δφ_total - π` convention in
+    /// the single-hole case.
+    pub fn trace_multi(&mut self, black_holes: &[BlackHole], steps: usize, step_size: f32) -> bool {
+        let view_bound = 30.0;
+
+        let inside_horizon = |p: Vec2| {
+            black_holes.iter().find(|bh| {
+                (p - Vec2::new(bh.position.x, bh.position.y)).length() <= bh.schwarzschild_radius
+            })
+        };
 
-            // Update using velocity Verlet
-            let new_dir = (dir + accel * step_size).normalize();
-            pos += (dir + new_dir) * 0.5 * step_size;
-            dir = new_dir;
+        if let Some(bh) = inside_horizon(self.position) {
+            self.captured = true;
+            self.deflection = 0.0;
+            self.path.push(Vec2::new(bh.position.x, bh.position.y));
+            return false;
+        }
 
+        let initial_dir = self.direction;
+        let mut pos = self.position;
+        let mut dir = self.direction;
+        let mut captured = false;
+
+        for _ in 0..steps {
+            let (new_pos, new_dir) = verlet_multi_step(pos, dir, black_holes, step_size);
+            pos = new_pos;
+            dir = new_dir.normalize_or_zero();
             self.path.push(pos);
+
+            if inside_horizon(pos).is_some() {
+                captured = true;
+                break;
+            }
+
+            if black_holes
+                .iter()
+                .all(|bh| (pos - Vec2::new(bh.position.x, bh.position.y)).length() >= view_bound)
+            {
+                break;
+            }
         }
 
-        true
+        self.position = pos;
+        self.direction = dir;
+        self.captured = captured;
+        self.deflection = initial_dir.angle_between(dir);
+        !captured
     }
 }
 
+/// One velocity-Verlet step of `multi_body_acceleration` over `dt`
+fn verlet_multi_step(pos: Vec2, dir: Vec2, black_holes: &[BlackHole], dt: f32) -> (Vec2, Vec2) {
+    let a0 = multi_body_acceleration(pos, black_holes);
+    let new_pos = pos + dir * dt + a0 * 0.5 * dt * dt;
+    let a1 = multi_body_acceleration(new_pos, black_holes);
+    let new_dir = dir + (a0 + a1) * 0.5 * dt;
+    (new_pos, new_dir)
+}
+
 /// Accretion disk properties
 #[derive(Debug, Clone, Copy)]
 pub struct AccretionDisk {
@@ -243,32 +765,95 @@ impl AccretionDisk {
         self.temperature_inner * (1.0 - t) + self.temperature_outer * t
     }
 
-    /// Convert temperature to RGB color (blackbody approximation)
+    /// Observed color and intensity of the disk at `radius`, combining
+    /// gravitational redshift with the relativistic Doppler shift/beaming
+    /// of its Keplerian orbital motion, so the side of the disk rotating
+    /// toward the camera (`approaching`) renders blue-shifted and brighter
+    /// than the receding side. `view_dir` is the camera's line of sight;
+    /// `approaching` is whether this point's orbital motion carries it
+    /// toward the camera along that line.
+    ///
+    /// This is the physical counterpart of `LightRay`'s unused `wavelength`
+    /// field: rather than track a per-ray wavelength through the RK4
+    /// integration, the shift is folded directly into an effective
+    /// temperature via Wien's law (`T_obs = T_emit * g_grav * D`), then
+    /// converted to color the same way `temperature_to_color` already does.
+    pub fn observed_color(&self, radius: f32, view_dir: Vec3, approaching: bool) -> ([f32; 3], f32) {
+        // ISCO = 3*rs and rs = 2GM/c² (G = c = 1 in this module), so both
+        // the Schwarzschild radius and mass fall out of inner_radius alone
+        let rs = self.inner_radius / 3.0;
+        let mass = rs * 0.5;
+
+        let g_grav = (1.0 - rs / radius.max(rs * 1.001)).max(1e-4).sqrt();
+
+        let v = (G * mass / radius).sqrt().min(C * 0.999);
+        let beta = if approaching { v } else { -v };
+        let gamma = 1.0 / (1.0 - beta * beta).max(1e-4).sqrt();
+        let n_dot = view_dir.normalize_or_zero().dot(Vec3::Z);
+        let doppler = 1.0 / (gamma * (1.0 - beta * n_dot));
+
+        let temp = self.temperature_at(radius);
+        let effective_temp = (temp * g_grav * doppler).clamp(500.0, 40000.0);
+        let color = Self::temperature_to_color(effective_temp);
+
+        let beaming = doppler.powi(3) * doppler;
+        (color, beaming)
+    }
+
+    /// Convert a blackbody temperature to linear sRGB using Krystek's
+    /// rational approximation of the CIE 1931 Planckian locus, for the HDR
+    /// path (where a >1.0 component is meaningful). Use
+    /// [`AccretionDisk::temperature_to_color_srgb`] instead for an LDR
+    /// display target.
     pub fn temperature_to_color(temp: f32) -> [f32; 3] {
-        // Simplified blackbody color
-        let t = temp / 10000.0;
+        let (x, y) = Self::planckian_locus_xy(temp);
+        Self::xy_to_linear_srgb(x, y)
+    }
 
-        let r = if t < 0.5 {
-            1.0
-        } else {
-            (1.0 - (t - 0.5) * 0.5).clamp(0.5, 1.0)
-        };
+    /// Gamma-corrected (display-referred sRGB) variant of
+    /// [`AccretionDisk::temperature_to_color`], for the LDR path
+    pub fn temperature_to_color_srgb(temp: f32) -> [f32; 3] {
+        Self::temperature_to_color(temp).map(|c| {
+            if c <= 0.0031308 {
+                12.92 * c
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        })
+    }
+
+    /// CIE 1931 chromaticity (x, y) of the Planckian locus at `temp` Kelvin,
+    /// via Krystek's rational approximation (valid 1667 K-25000 K)
+    fn planckian_locus_xy(temp: f32) -> (f32, f32) {
+        let t = temp.clamp(1667.0, 25000.0);
 
-        let g = if t < 0.3 {
-            t / 0.3 * 0.8
-        } else if t < 0.7 {
-            0.8 + (t - 0.3) * 0.5
+        let x = if t <= 4000.0 {
+            -0.2661239e9 / t.powi(3) - 0.2343589e6 / t.powi(2) + 0.8776956e3 / t + 0.179910
         } else {
-            1.0 - (t - 0.7) * 0.3
+            -3.0258469e9 / t.powi(3) + 2.1070379e6 / t.powi(2) + 0.2226347e3 / t + 0.240390
         };
 
-        let b = if t < 0.5 {
-            t * 0.6
+        let y = if t <= 4000.0 {
+            -1.1063814 * x.powi(3) - 1.34811020 * x.powi(2) + 2.18555832 * x - 0.20219683
         } else {
-            0.3 + (t - 0.5) * 1.4
+            3.0817580 * x.powi(3) - 5.87338670 * x.powi(2) + 3.75112997 * x - 0.37001483
         };
 
-        [r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0)]
+        (x, y)
+    }
+
+    /// CIE xyY (with Y=1) to linear sRGB via the standard XYZ matrix,
+    /// normalized so the brightest channel is 1.0
+    fn xy_to_linear_srgb(x: f32, y: f32) -> [f32; 3] {
+        let x_xyz = x / y;
+        let z_xyz = (1.0 - x - y) / y;
+
+        let r = 3.2406 * x_xyz - 1.5372 - 0.4986 * z_xyz;
+        let g = -0.9689 * x_xyz + 1.8758 + 0.0415 * z_xyz;
+        let b = 0.0557 * x_xyz - 0.2040 + 1.0570 * z_xyz;
+
+        let peak = r.max(g).max(b).max(1e-6);
+        [(r / peak).max(0.0), (g / peak).max(0.0), (b / peak).max(0.0)]
     }
 }
 
@@ -348,3 +933,46 @@ pub fn generate_star_field(count: usize, radius: f32) -> Vec<(Vec3, f32)> {
 
     stars
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rkf45_agrees_with_rk4_for_a_small_step() {
+        // For a small enough dphi the 4th- and 5th-order RKF45 estimates
+        // should land close to a plain RK4 step over the same interval
+        let rs = 0.1;
+        let (u, du) = (0.5, -0.1);
+        let dphi = 1e-3;
+
+        let (rk4_u, rk4_du) = rk4_orbit_step(u, du, rs, dphi);
+        let ((u4, du4), (u5, du5)) = rkf45_orbit_step(u, du, rs, dphi);
+
+        assert!((u4 - rk4_u).abs() < 1e-5);
+        assert!((du4 - rk4_du).abs() < 1e-5);
+        assert!((u5 - u4).abs() < 1e-6, "4th/5th order estimates should nearly agree for a tiny step");
+        assert!((du5 - du4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn trace_adaptive_escapes_ray_aimed_away_from_black_hole() {
+        let black_hole = BlackHole::new(Vec3::ZERO, 1.0);
+        let ray = LightRay::new(Vec3::new(0.0, 0.0, 40.0), Vec3::new(0.1, 0.0, 1.0));
+
+        let (direction, steps) = ray.trace_adaptive(&black_hole, 1e-4, 1000);
+
+        assert!(direction.is_some(), "a ray starting far out and aimed outward should escape");
+        assert!(steps > 0);
+    }
+
+    #[test]
+    fn trace_adaptive_captures_ray_aimed_at_black_hole() {
+        let black_hole = BlackHole::new(Vec3::ZERO, 1.0);
+        let ray = LightRay::new(Vec3::new(0.0, 0.0, 10.0), Vec3::new(0.01, 0.0, -1.0));
+
+        let (direction, _steps) = ray.trace_adaptive(&black_hole, 1e-4, 1000);
+
+        assert!(direction.is_none(), "a ray aimed nearly straight at the horizon should be captured");
+    }
+}
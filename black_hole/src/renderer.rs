@@ -1,34 +1,108 @@
 //! Rendering system for black hole simulation
 
 use common::{Camera2D, CameraUniform, GraphicsContext};
-use glam::Vec2;
+use glam::{Vec2, Vec3};
 use wgpu::util::DeviceExt;
 
-use crate::physics::{BlackHole, LightRay2D};
+use crate::physics::{AccretionDisk, BlackHole, LightRay2D};
 
-/// Uniform data for black hole
+/// Errors that can occur while loading a skybox's six cubemap faces
+#[derive(Debug, thiserror::Error)]
+pub enum SkyboxError {
+    #[error("failed to read skybox face image: {0}")]
+    Image(#[from] image::ImageError),
+}
+
+/// Most black holes `Renderer2D` can draw event horizons/photon spheres for
+/// in one pass, matching `GeodesicComputeRenderer::MAX_STARS`'s style of a
+/// small fixed cap rather than a dynamically-sized buffer, since binary/
+/// cluster lensing setups in practice stay well under this
+pub const MAX_BLACK_HOLES: usize = 4;
+
+/// Uniform data for an arbitrary (up to `MAX_BLACK_HOLES`) set of black
+/// holes, the 2D multi-mass counterpart of `BlackHoleUniform`: `count` tells
+/// the shader how many of the fixed-size arrays are actually populated, the
+/// rest being zeroed and ignored
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct MultiBlackHoleUniform {
+    pub positions: [[f32; 4]; MAX_BLACK_HOLES],
+    pub masses: [f32; MAX_BLACK_HOLES],
+    pub schwarzschild_radii: [f32; MAX_BLACK_HOLES],
+    pub count: u32,
+    pub time: f32,
+    pub _padding: [f32; 2],
+}
+
+impl MultiBlackHoleUniform {
+    pub fn from_black_holes(black_holes: &[BlackHole], time: f32) -> Self {
+        let mut positions = [[0.0; 4]; MAX_BLACK_HOLES];
+        let mut masses = [0.0; MAX_BLACK_HOLES];
+        let mut schwarzschild_radii = [0.0; MAX_BLACK_HOLES];
+
+        let count = black_holes.len().min(MAX_BLACK_HOLES);
+        for (i, bh) in black_holes.iter().take(MAX_BLACK_HOLES).enumerate() {
+            positions[i] = [bh.position.x, bh.position.y, bh.position.z, 1.0];
+            masses[i] = bh.mass;
+            schwarzschild_radii[i] = bh.schwarzschild_radius;
+        }
+
+        Self {
+            positions,
+            masses,
+            schwarzschild_radii,
+            count: count as u32,
+            time,
+            _padding: [0.0; 2],
+        }
+    }
+}
+
+/// One entry of the 3D ray marcher's `black_holes_buffer` storage array,
+/// gathered in `Renderer3D::update_black_holes` from every hole in the
+/// scene; `fs_main` accumulates weak-field deflection from every live
+/// entry instead of lensing around a single fixed mass, and shades the
+/// equatorial disk between `disk_inner`/`disk_outer` using `spin` as the
+/// Keplerian velocity scale for Doppler beaming/redshift
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
-pub struct BlackHoleUniform {
+pub struct BlackHoleInstance {
     pub position: [f32; 4],
     pub mass: f32,
     pub schwarzschild_radius: f32,
-    pub time: f32,
-    pub _padding: f32,
+    pub disk_inner: f32,
+    pub disk_outer: f32,
+    pub spin: f32,
+    pub _padding: [f32; 3],
 }
 
-impl BlackHoleUniform {
-    pub fn from_black_hole(bh: &BlackHole, time: f32) -> Self {
+impl BlackHoleInstance {
+    pub fn from_black_hole(bh: &BlackHole) -> Self {
+        let disk = AccretionDisk::new(bh);
+
         Self {
             position: [bh.position.x, bh.position.y, bh.position.z, 1.0],
             mass: bh.mass,
             schwarzschild_radius: bh.schwarzschild_radius,
-            time,
-            _padding: 0.0,
+            disk_inner: disk.inner_radius,
+            disk_outer: disk.outer_radius,
+            spin: bh.spin,
+            _padding: [0.0; 3],
         }
     }
 }
 
+/// How many of the `black_holes_buffer` storage array's entries are valid
+/// this frame; WGSL storage buffers backing a fixed-capacity array still
+/// need their live length passed in separately
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct BlackHoleCount {
+    count: u32,
+    time: f32,
+    _padding: [f32; 2],
+}
+
 /// Line vertex for ray path visualization
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
@@ -52,6 +126,42 @@ impl LineVertex {
     }
 }
 
+/// Depth-tested compositing of the 2D analytic ray overlay on top of the 3D
+/// ray-marched scene: both `Renderer3D::render_composite` and
+/// `Renderer2D::render_onto` write/test against a shared `Depth32Float`
+/// buffer created by `make_depth_texture`, so the 2D geodesics draw
+/// correctly occluded behind the volumetric black hole instead of always
+/// appearing on top.
+fn make_depth_texture(device: &wgpu::Device, width: u32, height: u32) -> (wgpu::Texture, wgpu::TextureView) {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Composite Depth Texture"),
+        size: wgpu::Extent3d {
+            width: width.max(1),
+            height: height.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Depth32Float,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    (texture, view)
+}
+
+/// Shared by every pipeline that reads/writes the composite depth buffer
+fn composite_depth_stencil_state() -> wgpu::DepthStencilState {
+    wgpu::DepthStencilState {
+        format: wgpu::TextureFormat::Depth32Float,
+        depth_write_enabled: true,
+        depth_compare: wgpu::CompareFunction::Less,
+        stencil: wgpu::StencilState::default(),
+        bias: wgpu::DepthBiasState::default(),
+    }
+}
+
 /// 2D renderer for gravitational lensing visualization
 pub struct Renderer2D {
     line_pipeline: wgpu::RenderPipeline,
@@ -62,6 +172,8 @@ pub struct Renderer2D {
     camera_bind_group: wgpu::BindGroup,
     black_hole_bind_group: wgpu::BindGroup,
     max_vertices: usize,
+    depth_texture: wgpu::Texture,
+    depth_view: wgpu::TextureView,
 }
 
 impl Renderer2D {
@@ -82,10 +194,10 @@ impl Renderer2D {
             mapped_at_creation: false,
         });
 
-        // Black hole uniform buffer
+        // Black hole uniform buffer (holds up to MAX_BLACK_HOLES masses)
         let black_hole_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Black Hole Buffer"),
-            size: std::mem::size_of::<BlackHoleUniform>() as u64,
+            size: std::mem::size_of::<MultiBlackHoleUniform>() as u64,
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
@@ -146,6 +258,8 @@ impl Renderer2D {
             push_constant_ranges: &[],
         });
 
+        let (depth_texture, depth_view) = make_depth_texture(device, ctx.config.width, ctx.config.height);
+
         // Line render pipeline
         let line_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Line Pipeline"),
@@ -182,7 +296,7 @@ impl Renderer2D {
                 unclipped_depth: false,
                 conservative: false,
             },
-            depth_stencil: None,
+            depth_stencil: Some(composite_depth_stencil_state()),
             multisample: wgpu::MultisampleState::default(),
             multiview: None,
         });
@@ -216,7 +330,7 @@ impl Renderer2D {
                 unclipped_depth: false,
                 conservative: false,
             },
-            depth_stencil: None,
+            depth_stencil: Some(composite_depth_stencil_state()),
             multisample: wgpu::MultisampleState::default(),
             multiview: None,
         });
@@ -238,16 +352,28 @@ impl Renderer2D {
             camera_bind_group,
             black_hole_bind_group,
             max_vertices,
+            depth_texture,
+            depth_view,
         }
     }
 
+    /// Recreate the composite depth buffer at the new resolution
+    pub fn resize(&mut self, ctx: &GraphicsContext, width: u32, height: u32) {
+        let (depth_texture, depth_view) = make_depth_texture(&ctx.device, width, height);
+        self.depth_texture = depth_texture;
+        self.depth_view = depth_view;
+    }
+
     pub fn update_camera(&self, queue: &wgpu::Queue, camera: &Camera2D) {
         let uniform = CameraUniform::from_camera_2d(camera);
         queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[uniform]));
     }
 
-    pub fn update_black_hole(&self, queue: &wgpu::Queue, bh: &BlackHole, time: f32) {
-        let uniform = BlackHoleUniform::from_black_hole(bh, time);
+    /// Upload up to `MAX_BLACK_HOLES` masses; `render`'s circle pass draws
+    /// `2 * black_holes.len().min(MAX_BLACK_HOLES)` instances (event horizon
+    /// + photon sphere per hole) to match
+    pub fn update_black_holes(&self, queue: &wgpu::Queue, black_holes: &[BlackHole], time: f32) {
+        let uniform = MultiBlackHoleUniform::from_black_holes(black_holes, time);
         queue.write_buffer(
             &self.black_hole_buffer,
             0,
@@ -255,36 +381,59 @@ impl Renderer2D {
         );
     }
 
-    /// Convert light rays to vertex data
-    pub fn update_rays(&self, queue: &wgpu::Queue, rays: &[LightRay2D]) -> Vec<(u32, u32)> {
-        let mut vertices = Vec::new();
-        let mut ranges = Vec::new();
-
-        for ray in rays {
-            let start = vertices.len() as u32;
-
-            // Color gradient along the ray
-            let path_len = ray.path.len();
-            for (i, pos) in ray.path.iter().enumerate() {
-                let t = i as f32 / path_len.max(1) as f32;
-                let color = [
-                    1.0 - t * 0.3, // R: bright to slightly dimmer
-                    0.8 - t * 0.5, // G: yellow to orange
-                    0.2 + t * 0.3, // B: slight blue shift at end
-                    1.0 - t * 0.5, // A: fade out
-                ];
-
-                vertices.push(LineVertex {
-                    position: [pos.x, pos.y],
-                    color,
-                });
-            }
+    /// Convert light rays to vertex data. Each ray's vertices only depend on
+    /// that ray's own path, so rayon maps every `LightRay2D` to its own
+    /// `Vec<LineVertex>` in parallel; a parallel prefix sum over the per-ray
+    /// vertex counts then gives each ray's starting offset in the
+    /// concatenated buffer without a serial scan. If the concatenated
+    /// vertex count exceeds `max_vertices`, `line_buffer` is reallocated to
+    /// the next power of two large enough to hold it (instead of
+    /// truncating), so no ray is ever dropped and the returned ranges
+    /// always point at what was actually uploaded.
+    pub fn update_rays(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        rays: &[LightRay2D],
+    ) -> Vec<(u32, u32)> {
+        use rayon::prelude::*;
 
-            ranges.push((start, vertices.len() as u32 - start));
-        }
+        let per_ray: Vec<Vec<LineVertex>> = rays
+            .par_iter()
+            .map(|ray| {
+                let path_len = ray.path.len();
+                ray.path
+                    .iter()
+                    .enumerate()
+                    .map(|(i, pos)| {
+                        let t = i as f32 / path_len.max(1) as f32;
+                        let color = [
+                            1.0 - t * 0.3, // R: bright to slightly dimmer
+                            0.8 - t * 0.5, // G: yellow to orange
+                            0.2 + t * 0.3, // B: slight blue shift at end
+                            1.0 - t * 0.5, // A: fade out
+                        ];
+                        LineVertex { position: [pos.x, pos.y], color }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let counts: Vec<u32> = per_ray.iter().map(|v| v.len() as u32).collect();
+        let starts = Self::parallel_prefix_sum(&counts);
+        let ranges: Vec<(u32, u32)> = starts.iter().copied().zip(counts.iter().copied()).collect();
+
+        let vertices: Vec<LineVertex> = per_ray.into_iter().flatten().collect();
 
         if vertices.len() > self.max_vertices {
-            vertices.truncate(self.max_vertices);
+            let new_capacity = vertices.len().next_power_of_two();
+            self.line_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Line Buffer"),
+                size: (std::mem::size_of::<LineVertex>() * new_capacity) as u64,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            self.max_vertices = new_capacity;
         }
 
         queue.write_buffer(&self.line_buffer, 0, bytemuck::cast_slice(&vertices));
@@ -292,11 +441,65 @@ impl Renderer2D {
         ranges
     }
 
+    /// Parallel exclusive prefix sum (divide-and-conquer via `rayon::join`),
+    /// used by `update_rays` to turn per-ray vertex counts into each ray's
+    /// starting offset in the concatenated vertex buffer.
+    fn parallel_prefix_sum(counts: &[u32]) -> Vec<u32> {
+        if counts.len() <= 1 {
+            return vec![0; counts.len()];
+        }
+
+        let mid = counts.len() / 2;
+        let (left, right) = counts.split_at(mid);
+        let (left_starts, right_starts) = rayon::join(
+            || Self::parallel_prefix_sum(left),
+            || Self::parallel_prefix_sum(right),
+        );
+
+        let left_total: u32 = left.iter().sum();
+        let mut starts = left_starts;
+        starts.extend(right_starts.into_iter().map(|s| s + left_total));
+        starts
+    }
+
+    /// Append marching-squares field-overlay segments right after the ray
+    /// vertices already written by `update_rays` (`ray_vertex_count` is the
+    /// total vertex count that call produced), reusing the same line buffer
+    /// and pipeline. Each segment becomes its own 2-vertex range, which the
+    /// `LineStrip` pipeline renders as a single straight line.
+    pub fn update_field_overlay(
+        &self,
+        queue: &wgpu::Queue,
+        segments: &[(Vec2, Vec2)],
+        ray_vertex_count: u32,
+    ) -> Vec<(u32, u32)> {
+        let color = [0.3, 0.6, 1.0, 0.35];
+
+        let mut vertices: Vec<LineVertex> = Vec::with_capacity(segments.len() * 2);
+        for (a, b) in segments {
+            vertices.push(LineVertex { position: [a.x, a.y], color });
+            vertices.push(LineVertex { position: [b.x, b.y], color });
+        }
+
+        let room = self.max_vertices.saturating_sub(ray_vertex_count as usize);
+        if vertices.len() > room {
+            vertices.truncate(room - room % 2);
+        }
+
+        let offset = (ray_vertex_count as u64) * std::mem::size_of::<LineVertex>() as u64;
+        queue.write_buffer(&self.line_buffer, offset, bytemuck::cast_slice(&vertices));
+
+        (0..vertices.len() as u32 / 2)
+            .map(|i| (ray_vertex_count + i * 2, 2u32))
+            .collect()
+    }
+
     pub fn render(
         &self,
         encoder: &mut wgpu::CommandEncoder,
         view: &wgpu::TextureView,
         ray_ranges: &[(u32, u32)],
+        black_hole_count: u32,
     ) {
         let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             label: Some("Render Pass"),
@@ -313,16 +516,38 @@ impl Renderer2D {
                     store: wgpu::StoreOp::Store,
                 },
             })],
-            depth_stencil_attachment: None,
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &self.depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
             timestamp_writes: None,
             occlusion_query_set: None,
         });
 
-        // Draw event horizon and photon sphere
+        self.draw_overlay(&mut render_pass, ray_ranges, black_hole_count);
+    }
+
+    /// Draw the event-horizon/photon-sphere rings and light-ray paths onto
+    /// an already-open render pass, which carries whatever color/depth
+    /// attachments the caller set up. `render` uses this standalone against
+    /// a freshly cleared frame; `Renderer3D::render_composite` uses it atop
+    /// the ray-marched scene, testing depth instead of clearing it.
+    fn draw_overlay<'a>(
+        &'a self,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        ray_ranges: &[(u32, u32)],
+        black_hole_count: u32,
+    ) {
+        // Draw event horizon and photon sphere for every hole: instance
+        // `2*i` is hole `i`'s horizon, `2*i + 1` its photon sphere
         render_pass.set_pipeline(&self.circle_pipeline);
         render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
         render_pass.set_bind_group(1, &self.black_hole_bind_group, &[]);
-        render_pass.draw(0..65, 0..2); // 64 segments + closing vertex, 2 instances
+        render_pass.draw(0..65, 0..(2 * black_hole_count.max(1))); // 64 segments + closing vertex
 
         // Draw light rays
         render_pass.set_pipeline(&self.line_pipeline);
@@ -334,19 +559,241 @@ impl Renderer2D {
             }
         }
     }
+
+    /// Composite the 2D overlay onto an existing color/depth target instead
+    /// of clearing it, so it draws on top of (and is occluded by) whatever
+    /// was rendered into `view`/`depth_view` beforehand
+    pub fn render_onto(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        depth_view: &wgpu::TextureView,
+        ray_ranges: &[(u32, u32)],
+        black_hole_count: u32,
+    ) {
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("2D Overlay Composite Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        self.draw_overlay(&mut render_pass, ray_ranges, black_hole_count);
+    }
+
+    /// Render one frame into an offscreen `width` x `height` target instead
+    /// of the swapchain, and read it back as a CPU-side image. Lets callers
+    /// export frames at a resolution independent of the on-screen window
+    /// (or with no window/surface at all); `render_sequence` builds on this
+    /// to export a whole animation.
+    pub fn render_to_image(
+        &self,
+        ctx: &GraphicsContext,
+        width: u32,
+        height: u32,
+        ray_ranges: &[(u32, u32)],
+        black_hole_count: u32,
+    ) -> image::RgbaImage {
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Offscreen 2D Render Target"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: ctx.config.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = ctx.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Offscreen 2D Render Encoder"),
+        });
+        self.render(&mut encoder, &view, ray_ranges, black_hole_count);
+        ctx.queue.submit(std::iter::once(encoder.finish()));
+
+        common::capture::read_texture_to_image(&ctx.device, &ctx.queue, &texture)
+            .expect("offscreen readback should succeed")
+    }
+
+    /// Step `time` forward by `dt` for `frames` iterations, uploading
+    /// `rays`/`black_holes` and writing each frame to `path_pattern` (with
+    /// `{}` replaced by a zero-padded frame index) via `render_to_image`, so
+    /// a lensing animation can be exported deterministically without ever
+    /// opening a window.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_sequence(
+        &mut self,
+        ctx: &GraphicsContext,
+        camera: &Camera2D,
+        black_holes: &[BlackHole],
+        rays: &[LightRay2D],
+        width: u32,
+        height: u32,
+        frames: u32,
+        dt: f32,
+        path_pattern: &str,
+    ) -> image::ImageResult<()> {
+        let black_hole_count = black_holes.len().min(MAX_BLACK_HOLES) as u32;
+        let mut time = 0.0;
+
+        for frame in 0..frames {
+            self.update_camera(&ctx.queue, camera);
+            self.update_black_holes(&ctx.queue, black_holes, time);
+            let ray_ranges = self.update_rays(&ctx.device, &ctx.queue, rays);
+
+            let image = self.render_to_image(ctx, width, height, &ray_ranges, black_hole_count);
+            let path = path_pattern.replace("{}", &format!("{frame:05}"));
+            image.save(path)?;
+
+            time += dt;
+        }
+
+        Ok(())
+    }
+}
+
+/// Pixels above `threshold` are kept (scaled down to black otherwise) when
+/// extracting the bright pass that feeds the bloom blur
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct BrightParams {
+    pub threshold: f32,
+    pub _padding: [f32; 3],
+}
+
+/// Separable Gaussian blur direction and texel size for one blur pass
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct BlurParams {
+    pub direction: [f32; 2],
+    pub texel_size: [f32; 2],
+}
+
+/// Tone-mapping controls for the final composite pass
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct BloomUniform {
+    pub exposure: f32,
+    pub intensity: f32,
+    pub _padding: [f32; 2],
+}
+
+fn create_hdr_target(
+    device: &wgpu::Device,
+    width: u32,
+    height: u32,
+    format: wgpu::TextureFormat,
+    label: &str,
+) -> (wgpu::Texture, wgpu::TextureView) {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size: wgpu::Extent3d {
+            width: width.max(1),
+            height: height.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    (texture, view)
 }
 
-/// Full-screen ray marching renderer for 3D visualization
+/// Full-screen ray marching renderer for 3D visualization. Renders the
+/// Schwarzschild scene into an `Rgba16Float` HDR target so the photon
+/// sphere and inner accretion disk clip gracefully where their shaded
+/// color runs past 1.0, then runs a threshold/blur/composite
+/// bloom chain and a tone-mapping pass before writing to the swapchain.
 pub struct Renderer3D {
     pipeline: wgpu::RenderPipeline,
     camera_buffer: wgpu::Buffer,
-    black_hole_buffer: wgpu::Buffer,
+
+    /// Depth buffer `render`'s scene pass writes to (`pipeline`'s
+    /// `depth_stencil` is no longer `None`); `render_composite` instead
+    /// writes the scene depth into the caller's `depth_view` and hands that
+    /// to `Renderer2D::render_onto`, so the 2D geodesics draw correctly
+    /// occluded behind the volumetric hole
+    depth_texture: wgpu::Texture,
+    depth_view: wgpu::TextureView,
+
+    /// Storage array of up to `MAX_BLACK_HOLES` masses `fs_main` accumulates
+    /// weak-field deflection from, one hole at a time, instead of lensing
+    /// around a single fixed mass; `black_hole_count_buffer` tells the
+    /// shader how many entries are actually populated
+    black_holes_buffer: wgpu::Buffer,
+    black_hole_count_buffer: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
+
+    /// The lensing background: a cube texture the fragment shader samples in
+    /// the final escape direction of each deflected ray, bound as group 1
+    /// alongside `bind_group`'s camera/black-hole uniforms at group 0.
+    /// Starts as a 1x1 black cube until `set_skybox` loads real faces.
+    skybox_texture: wgpu::Texture,
+    skybox_view: wgpu::TextureView,
+    skybox_sampler: wgpu::Sampler,
+    skybox_bind_group_layout: wgpu::BindGroupLayout,
+    skybox_bind_group: wgpu::BindGroup,
+
+    hdr_format: wgpu::TextureFormat,
+    hdr_texture: wgpu::Texture,
+    hdr_view: wgpu::TextureView,
+    hdr_sampler: wgpu::Sampler,
+
+    bloom_texture_a: wgpu::Texture,
+    bloom_view_a: wgpu::TextureView,
+    bloom_texture_b: wgpu::Texture,
+    bloom_view_b: wgpu::TextureView,
+
+    bright_pipeline: wgpu::RenderPipeline,
+    bright_bind_group_layout: wgpu::BindGroupLayout,
+    bright_bind_group: wgpu::BindGroup,
+    bright_params: wgpu::Buffer,
+
+    blur_pipeline: wgpu::RenderPipeline,
+    blur_bind_group_layout: wgpu::BindGroupLayout,
+    blur_params_h: wgpu::Buffer,
+    blur_params_v: wgpu::Buffer,
+    blur_bind_group_h: wgpu::BindGroup,
+    blur_bind_group_v: wgpu::BindGroup,
+
+    tonemap_pipeline: wgpu::RenderPipeline,
+    tonemap_bind_group_layout: wgpu::BindGroupLayout,
+    tonemap_bind_group: wgpu::BindGroup,
+    bloom_uniform: wgpu::Buffer,
+
+    /// Tone-mapping exposure; multiplies HDR color before the ACES/Reinhard
+    /// curve is applied
+    pub exposure: f32,
+    /// Luminance threshold above which a pixel contributes to the bloom
+    pub bloom_threshold: f32,
 }
 
 impl Renderer3D {
     pub fn new(ctx: &GraphicsContext) -> Self {
         let device = &ctx.device;
+        let width = ctx.config.width;
+        let height = ctx.config.height;
+        let hdr_format = wgpu::TextureFormat::Rgba16Float;
 
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Black Hole 3D Shader"),
@@ -360,12 +807,17 @@ impl Renderer3D {
             mapped_at_creation: false,
         });
 
-        let black_hole_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("Black Hole Buffer"),
-            size: std::mem::size_of::<BlackHoleUniform>() as u64,
-            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        let black_holes_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Black Holes Buffer"),
+            size: (std::mem::size_of::<BlackHoleInstance>() * MAX_BLACK_HOLES) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
+        let black_hole_count_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Black Hole Count Buffer"),
+            contents: bytemuck::cast_slice(&[BlackHoleCount { count: 0, time: 0.0, _padding: [0.0; 2] }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
 
         let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("Bind Group Layout"),
@@ -383,6 +835,16 @@ impl Renderer3D {
                 wgpu::BindGroupLayoutEntry {
                     binding: 1,
                     visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Uniform,
                         has_dynamic_offset: false,
@@ -403,14 +865,36 @@ impl Renderer3D {
                 },
                 wgpu::BindGroupEntry {
                     binding: 1,
-                    resource: black_hole_buffer.as_entire_binding(),
+                    resource: black_holes_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: black_hole_count_buffer.as_entire_binding(),
                 },
             ],
         });
 
+        let skybox_bind_group_layout = Self::create_skybox_bind_group_layout(device);
+        let skybox_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Skybox Sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            ..Default::default()
+        });
+        let (skybox_texture, skybox_view) = Self::create_default_skybox(device, &ctx.queue);
+        let skybox_bind_group = Self::create_skybox_bind_group(
+            device,
+            &skybox_bind_group_layout,
+            &skybox_view,
+            &skybox_sampler,
+        );
+
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Pipeline Layout"),
-            bind_group_layouts: &[&bind_group_layout],
+            bind_group_layouts: &[&bind_group_layout, &skybox_bind_group_layout],
             push_constant_ranges: &[],
         });
 
@@ -427,7 +911,7 @@ impl Renderer3D {
                 module: &shader,
                 entry_point: "fs_main",
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: ctx.config.format,
+                    format: hdr_format,
                     blend: None,
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
@@ -442,56 +926,1259 @@ impl Renderer3D {
                 unclipped_depth: false,
                 conservative: false,
             },
-            depth_stencil: None,
+            depth_stencil: Some(composite_depth_stencil_state()),
             multisample: wgpu::MultisampleState::default(),
             multiview: None,
         });
 
-        Self {
-            pipeline,
-            camera_buffer,
-            black_hole_buffer,
-            bind_group,
-        }
-    }
+        let (depth_texture, depth_view) = make_depth_texture(device, width, height);
 
-    pub fn update(&self, queue: &wgpu::Queue, camera_pos: [f32; 3], bh: &BlackHole, time: f32) {
-        let camera_uniform = CameraUniform {
-            view_proj: glam::Mat4::IDENTITY.to_cols_array_2d(),
-            position: [camera_pos[0], camera_pos[1], camera_pos[2], 1.0],
-        };
-        queue.write_buffer(
-            &self.camera_buffer,
-            0,
-            bytemuck::cast_slice(&[camera_uniform]),
-        );
+        let (hdr_texture, hdr_view) = create_hdr_target(device, width, height, hdr_format, "HDR Scene Target");
+        let hdr_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("HDR Sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            ..Default::default()
+        });
 
-        let bh_uniform = BlackHoleUniform::from_black_hole(bh, time);
-        queue.write_buffer(
-            &self.black_hole_buffer,
-            0,
-            bytemuck::cast_slice(&[bh_uniform]),
-        );
-    }
+        let (bloom_width, bloom_height) = ((width / 2).max(1), (height / 2).max(1));
+        let (bloom_texture_a, bloom_view_a) =
+            create_hdr_target(device, bloom_width, bloom_height, hdr_format, "Bloom Target A");
+        let (bloom_texture_b, bloom_view_b) =
+            create_hdr_target(device, bloom_width, bloom_height, hdr_format, "Bloom Target B");
 
-    pub fn render(&self, encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
-        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("3D Render Pass"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
+        let texture_sampler_layout = |visibility: wgpu::ShaderStages| wgpu::BindGroupLayoutDescriptor {
+            label: Some("Texture+Sampler Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
                 },
-            })],
-            depth_stencil_attachment: None,
-            timestamp_writes: None,
-            occlusion_query_set: None,
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        };
+
+        // Bright pass: threshold the HDR scene into the half-res bloom chain
+        let bright_bind_group_layout = device.create_bind_group_layout(&texture_sampler_layout(wgpu::ShaderStages::FRAGMENT));
+        let bright_params = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Bright Params Buffer"),
+            size: std::mem::size_of::<BrightParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bright_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Bright Bind Group"),
+            layout: &bright_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&hdr_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: bright_params.as_entire_binding() },
+            ],
+        });
+        let bright_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Bright Pipeline Layout"),
+            bind_group_layouts: &[&bright_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let bright_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Bright Pass Pipeline"),
+            layout: Some(&bright_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_bright",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: hdr_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
         });
 
-        render_pass.set_pipeline(&self.pipeline);
-        render_pass.set_bind_group(0, &self.bind_group, &[]);
-        render_pass.draw(0..3, 0..1); // Full-screen triangle
+        // Separable blur: one pipeline, two passes (horizontal then vertical)
+        let blur_bind_group_layout = device.create_bind_group_layout(&texture_sampler_layout(wgpu::ShaderStages::FRAGMENT));
+        let blur_params_h = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Blur Params H Buffer"),
+            size: std::mem::size_of::<BlurParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let blur_params_v = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Blur Params V Buffer"),
+            size: std::mem::size_of::<BlurParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let blur_bind_group_h = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blur Bind Group H"),
+            layout: &blur_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&bloom_view_a) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: blur_params_h.as_entire_binding() },
+            ],
+        });
+        let blur_bind_group_v = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blur Bind Group V"),
+            layout: &blur_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&bloom_view_b) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: blur_params_v.as_entire_binding() },
+            ],
+        });
+        let blur_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Blur Pipeline Layout"),
+            bind_group_layouts: &[&blur_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let blur_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Blur Pipeline"),
+            layout: Some(&blur_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_blur",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: hdr_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        // Tonemap pass: additively composite the blurred bloom back over the
+        // HDR scene, then apply ACES/Reinhard tone mapping to the swapchain
+        let tonemap_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Tonemap Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let bloom_uniform = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Bloom Uniform Buffer"),
+            size: std::mem::size_of::<BloomUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let tonemap_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Tonemap Bind Group"),
+            layout: &tonemap_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&hdr_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(&bloom_view_a) },
+                wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::Sampler(&hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 3, resource: bloom_uniform.as_entire_binding() },
+            ],
+        });
+        let tonemap_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Tonemap Pipeline Layout"),
+            bind_group_layouts: &[&tonemap_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let tonemap_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Tonemap Pipeline"),
+            layout: Some(&tonemap_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_tonemap",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        let exposure = 1.0;
+        let bloom_threshold = 1.0;
+        ctx.queue.write_buffer(&bright_params, 0, bytemuck::cast_slice(&[BrightParams { threshold: bloom_threshold, _padding: [0.0; 3] }]));
+        ctx.queue.write_buffer(&blur_params_h, 0, bytemuck::cast_slice(&[BlurParams { direction: [1.0, 0.0], texel_size: [1.0 / bloom_width as f32, 1.0 / bloom_height as f32] }]));
+        ctx.queue.write_buffer(&blur_params_v, 0, bytemuck::cast_slice(&[BlurParams { direction: [0.0, 1.0], texel_size: [1.0 / bloom_width as f32, 1.0 / bloom_height as f32] }]));
+        ctx.queue.write_buffer(&bloom_uniform, 0, bytemuck::cast_slice(&[BloomUniform { exposure, intensity: 1.0, _padding: [0.0; 2] }]));
+
+        Self {
+            pipeline,
+            camera_buffer,
+            depth_texture,
+            depth_view,
+            black_holes_buffer,
+            black_hole_count_buffer,
+            bind_group,
+            skybox_texture,
+            skybox_view,
+            skybox_sampler,
+            skybox_bind_group_layout,
+            skybox_bind_group,
+            hdr_format,
+            hdr_texture,
+            hdr_view,
+            hdr_sampler,
+            bloom_texture_a,
+            bloom_view_a,
+            bloom_texture_b,
+            bloom_view_b,
+            bright_pipeline,
+            bright_bind_group_layout,
+            bright_bind_group,
+            bright_params,
+            blur_pipeline,
+            blur_bind_group_layout,
+            blur_params_h,
+            blur_params_v,
+            blur_bind_group_h,
+            blur_bind_group_v,
+            tonemap_pipeline,
+            tonemap_bind_group_layout,
+            tonemap_bind_group,
+            bloom_uniform,
+            exposure,
+            bloom_threshold,
+        }
+    }
+
+    /// Recreate the HDR and bloom targets at the new resolution
+    pub fn resize(&mut self, ctx: &GraphicsContext, width: u32, height: u32) {
+        let device = &ctx.device;
+
+        let (depth_texture, depth_view) = make_depth_texture(device, width, height);
+        self.depth_texture = depth_texture;
+        self.depth_view = depth_view;
+
+        let (hdr_texture, hdr_view) = create_hdr_target(device, width, height, self.hdr_format, "HDR Scene Target");
+        self.hdr_texture = hdr_texture;
+        self.hdr_view = hdr_view;
+
+        let (bloom_width, bloom_height) = ((width / 2).max(1), (height / 2).max(1));
+        let (bloom_texture_a, bloom_view_a) =
+            create_hdr_target(device, bloom_width, bloom_height, self.hdr_format, "Bloom Target A");
+        let (bloom_texture_b, bloom_view_b) =
+            create_hdr_target(device, bloom_width, bloom_height, self.hdr_format, "Bloom Target B");
+        self.bloom_texture_a = bloom_texture_a;
+        self.bloom_view_a = bloom_view_a;
+        self.bloom_texture_b = bloom_texture_b;
+        self.bloom_view_b = bloom_view_b;
+
+        self.bright_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Bright Bind Group"),
+            layout: &self.bright_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&self.hdr_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&self.hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: self.bright_params.as_entire_binding() },
+            ],
+        });
+
+        ctx.queue.write_buffer(&self.blur_params_h, 0, bytemuck::cast_slice(&[BlurParams { direction: [1.0, 0.0], texel_size: [1.0 / bloom_width as f32, 1.0 / bloom_height as f32] }]));
+        ctx.queue.write_buffer(&self.blur_params_v, 0, bytemuck::cast_slice(&[BlurParams { direction: [0.0, 1.0], texel_size: [1.0 / bloom_width as f32, 1.0 / bloom_height as f32] }]));
+
+        self.blur_bind_group_h = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blur Bind Group H"),
+            layout: &self.blur_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&self.bloom_view_a) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&self.hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: self.blur_params_h.as_entire_binding() },
+            ],
+        });
+        self.blur_bind_group_v = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blur Bind Group V"),
+            layout: &self.blur_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&self.bloom_view_b) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&self.hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: self.blur_params_v.as_entire_binding() },
+            ],
+        });
+
+        self.tonemap_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Tonemap Bind Group"),
+            layout: &self.tonemap_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&self.hdr_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(&self.bloom_view_a) },
+                wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::Sampler(&self.hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 3, resource: self.bloom_uniform.as_entire_binding() },
+            ],
+        });
+    }
+
+    /// Push `self.exposure`/`self.bloom_threshold` to the GPU; call after
+    /// mutating either field
+    pub fn update_bloom_settings(&self, queue: &wgpu::Queue) {
+        queue.write_buffer(&self.bright_params, 0, bytemuck::cast_slice(&[BrightParams { threshold: self.bloom_threshold, _padding: [0.0; 3] }]));
+        queue.write_buffer(&self.bloom_uniform, 0, bytemuck::cast_slice(&[BloomUniform { exposure: self.exposure, intensity: 1.0, _padding: [0.0; 2] }]));
+    }
+
+    fn create_skybox_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+        device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Skybox Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::Cube,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        })
+    }
+
+    fn create_skybox_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        view: &wgpu::TextureView,
+        sampler: &wgpu::Sampler,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Skybox Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+            ],
+        })
+    }
+
+    /// A 1x1 black cube, bound from `new` so the scene pipeline always has a
+    /// valid skybox to sample before `set_skybox` ever loads real faces
+    fn create_default_skybox(device: &wgpu::Device, queue: &wgpu::Queue) -> (wgpu::Texture, wgpu::TextureView) {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Default Skybox Texture"),
+            size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 6 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        for face in 0..6 {
+            queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d { x: 0, y: 0, z: face },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                &[0, 0, 0, 255],
+                wgpu::ImageDataLayout { offset: 0, bytes_per_row: Some(4), rows_per_image: Some(1) },
+                wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+            );
+        }
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::Cube),
+            ..Default::default()
+        });
+        (texture, view)
+    }
+
+    /// Decode six face images (in `+X, -X, +Y, -Y, +Z, -Z` order, matching
+    /// `skybox::FACE_NAMES`) and upload them as the layers of one cube
+    /// texture. All six faces must share the same dimensions.
+    fn load_cubemap(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        paths: [&str; 6],
+    ) -> Result<(wgpu::Texture, wgpu::TextureView), SkyboxError> {
+        let faces: Vec<_> = paths
+            .iter()
+            .map(|path| image::open(path).map(|img| img.to_rgba8()))
+            .collect::<Result<_, _>>()?;
+
+        let (width, height) = faces[0].dimensions();
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Skybox Texture"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 6 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+
+        for (face, image) in faces.iter().enumerate() {
+            queue.write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d { x: 0, y: 0, z: face as u32 },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                image.as_raw(),
+                wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(4 * width),
+                    rows_per_image: Some(height),
+                },
+                wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            );
+        }
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::Cube),
+            ..Default::default()
+        });
+        Ok((texture, view))
+    }
+
+    /// Load a new lensing background from six cubemap face images, replacing
+    /// whatever skybox (the default black cube, or a previously loaded one)
+    /// was bound before. The ray-marching fragment shader samples this cube
+    /// in the final escape direction of each deflected ray, so a real star
+    /// field shows the characteristic lensed doubling/ring distortion around
+    /// the black hole instead of the procedural points `GeodesicComputeRenderer`
+    /// draws.
+    pub fn set_skybox(&mut self, ctx: &GraphicsContext, paths: [&str; 6]) -> Result<(), SkyboxError> {
+        let (texture, view) = Self::load_cubemap(&ctx.device, &ctx.queue, paths)?;
+        self.skybox_bind_group = Self::create_skybox_bind_group(
+            &ctx.device,
+            &self.skybox_bind_group_layout,
+            &view,
+            &self.skybox_sampler,
+        );
+        self.skybox_texture = texture;
+        self.skybox_view = view;
+        Ok(())
+    }
+
+    pub fn update(&self, queue: &wgpu::Queue, camera_pos: [f32; 3], black_holes: &[BlackHole], time: f32) {
+        let camera_uniform = CameraUniform {
+            view_proj: glam::Mat4::IDENTITY.to_cols_array_2d(),
+            position: [camera_pos[0], camera_pos[1], camera_pos[2], 1.0],
+        };
+        queue.write_buffer(
+            &self.camera_buffer,
+            0,
+            bytemuck::cast_slice(&[camera_uniform]),
+        );
+
+        self.update_black_holes(queue, black_holes, time);
+    }
+
+    /// Upload up to `MAX_BLACK_HOLES` masses for `fs_main`'s N-body
+    /// deflection accumulation; for `r = p - c_i` and angular momentum
+    /// `h_i` about hole `i`, each live entry contributes a weak-field pull
+    /// `dv += -1.5 * h_i^2 * r / |r|^5` to the marching ray, terminating it
+    /// once `|r| < schwarzschild_radius_i` for any hole
+    pub fn update_black_holes(&self, queue: &wgpu::Queue, black_holes: &[BlackHole], time: f32) {
+        let instances: Vec<BlackHoleInstance> = black_holes
+            .iter()
+            .take(MAX_BLACK_HOLES)
+            .map(BlackHoleInstance::from_black_hole)
+            .collect();
+
+        queue.write_buffer(&self.black_holes_buffer, 0, bytemuck::cast_slice(&instances));
+        queue.write_buffer(
+            &self.black_hole_count_buffer,
+            0,
+            bytemuck::cast_slice(&[BlackHoleCount { count: instances.len() as u32, time, _padding: [0.0; 2] }]),
+        );
+    }
+
+    pub fn render(&self, encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
+        // Scene Pass: ray march the Schwarzschild geometry into the HDR target
+        {
+            let mut scene_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("3D HDR Scene Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.hdr_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &self.depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            scene_pass.set_pipeline(&self.pipeline);
+            scene_pass.set_bind_group(0, &self.bind_group, &[]);
+            scene_pass.set_bind_group(1, &self.skybox_bind_group, &[]);
+            scene_pass.draw(0..3, 0..1); // Full-screen triangle
+        }
+
+        self.composite_bloom(encoder, view);
+    }
+
+    /// Render one frame into an offscreen `width` x `height` target instead
+    /// of the swapchain, and read it back as a CPU-side image. Lets callers
+    /// export frames at a resolution independent of the on-screen window
+    /// (or with no window/surface at all); `render_sequence` builds on this
+    /// to export a whole animation.
+    pub fn render_to_image(&self, ctx: &GraphicsContext, width: u32, height: u32) -> image::RgbaImage {
+        let texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Offscreen 3D Render Target"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: ctx.config.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = ctx.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Offscreen 3D Render Encoder"),
+        });
+        self.render(&mut encoder, &view);
+        ctx.queue.submit(std::iter::once(encoder.finish()));
+
+        common::capture::read_texture_to_image(&ctx.device, &ctx.queue, &texture)
+            .expect("offscreen readback should succeed")
+    }
+
+    /// Step `time` forward by `dt` for `frames` iterations, uploading
+    /// `camera_pos`/`black_holes` and writing each frame to `path_pattern`
+    /// (with `{}` replaced by a zero-padded frame index) via
+    /// `render_to_image`, so a lensing animation can be exported
+    /// deterministically without ever opening a window.
+    pub fn render_sequence(
+        &self,
+        ctx: &GraphicsContext,
+        camera_pos: [f32; 3],
+        black_holes: &[BlackHole],
+        width: u32,
+        height: u32,
+        frames: u32,
+        dt: f32,
+        path_pattern: &str,
+    ) -> image::ImageResult<()> {
+        let mut time = 0.0;
+
+        for frame in 0..frames {
+            self.update(&ctx.queue, camera_pos, black_holes, time);
+
+            let image = self.render_to_image(ctx, width, height);
+            let path = path_pattern.replace("{}", &format!("{frame:05}"));
+            image.save(path)?;
+
+            time += dt;
+        }
+
+        Ok(())
+    }
+
+    /// Draw the 3D ray-marched scene and bloom composite, then the 2D
+    /// analytic overlay on top using `depth_view`'s per-fragment scene depth
+    /// so each geodesic line/ring is occluded correctly behind the
+    /// volumetric black hole instead of always drawing on top of it
+    pub fn render_composite(
+        &self,
+        renderer_2d: &Renderer2D,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        depth_view: &wgpu::TextureView,
+        ray_ranges: &[(u32, u32)],
+        black_hole_count: u32,
+    ) {
+        {
+            let mut scene_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("3D HDR Scene Pass (Composite)"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.hdr_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            scene_pass.set_pipeline(&self.pipeline);
+            scene_pass.set_bind_group(0, &self.bind_group, &[]);
+            scene_pass.set_bind_group(1, &self.skybox_bind_group, &[]);
+            scene_pass.draw(0..3, 0..1); // Full-screen triangle
+        }
+
+        self.composite_bloom(encoder, view);
+        renderer_2d.render_onto(encoder, view, depth_view, ray_ranges, black_hole_count);
+    }
+
+    /// Bright Pass -> Blur Pass Horizontal -> Blur Pass Vertical -> Tonemap
+    /// Pass, reading from `hdr_view` and writing the tone-mapped result to
+    /// the swapchain `view`
+    fn composite_bloom(&self, encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
+        {
+            let mut bright_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Bright Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.bloom_view_a,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            bright_pass.set_pipeline(&self.bright_pipeline);
+            bright_pass.set_bind_group(0, &self.bright_bind_group, &[]);
+            bright_pass.draw(0..3, 0..1);
+        }
+
+        {
+            let mut blur_h_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Blur Pass Horizontal"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.bloom_view_b,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            blur_h_pass.set_pipeline(&self.blur_pipeline);
+            blur_h_pass.set_bind_group(0, &self.blur_bind_group_h, &[]);
+            blur_h_pass.draw(0..3, 0..1);
+        }
+
+        {
+            let mut blur_v_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Blur Pass Vertical"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.bloom_view_a,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            blur_v_pass.set_pipeline(&self.blur_pipeline);
+            blur_v_pass.set_bind_group(0, &self.blur_bind_group_v, &[]);
+            blur_v_pass.draw(0..3, 0..1);
+        }
+
+        {
+            let mut tonemap_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Tonemap Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            tonemap_pass.set_pipeline(&self.tonemap_pipeline);
+            tonemap_pass.set_bind_group(0, &self.tonemap_bind_group, &[]);
+            tonemap_pass.draw(0..3, 0..1);
+        }
+    }
+}
+
+/// Camera data for generating a per-pixel ray in `cs_trace_geodesic`, since a
+/// compute kernel needs an explicit ray direction rather than the matrix
+/// `Renderer3D` leaves at `Mat4::IDENTITY`
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct GeodesicCameraUniform {
+    pub position: [f32; 4],
+    pub forward: [f32; 4],
+    pub right: [f32; 4],
+    pub up: [f32; 4],
+    pub tan_half_fov: f32,
+    pub aspect: f32,
+    pub _padding: [f32; 2],
+}
+
+impl GeodesicCameraUniform {
+    pub fn look_at(eye: Vec3, target: Vec3, fov_y_radians: f32, aspect: f32) -> Self {
+        let forward = (target - eye).normalize_or_zero();
+        let world_up = Vec3::Y;
+        let right = forward.cross(world_up).normalize_or_zero();
+        let up = right.cross(forward);
+
+        Self {
+            position: [eye.x, eye.y, eye.z, 1.0],
+            forward: [forward.x, forward.y, forward.z, 0.0],
+            right: [right.x, right.y, right.z, 0.0],
+            up: [up.x, up.y, up.z, 0.0],
+            tan_half_fov: (fov_y_radians * 0.5).tan(),
+            aspect,
+            _padding: [0.0; 2],
+        }
+    }
+}
+
+/// `BlackHole` + `AccretionDisk` parameters for `cs_trace_geodesic`, mirroring
+/// the CPU fields `LightRay::trace` and `AccretionDisk::temperature_at` read
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct GeodesicParams {
+    pub black_hole_position: [f32; 4],
+    pub black_hole_mass: f32,
+    pub schwarzschild_radius: f32,
+    pub spin: f32,
+    pub time: f32,
+    pub disk_inner_radius: f32,
+    pub disk_outer_radius: f32,
+    pub disk_temp_inner: f32,
+    pub disk_temp_outer: f32,
+}
+
+impl GeodesicParams {
+    pub fn new(black_hole: &BlackHole, disk: &AccretionDisk, time: f32) -> Self {
+        Self {
+            black_hole_position: [
+                black_hole.position.x,
+                black_hole.position.y,
+                black_hole.position.z,
+                1.0,
+            ],
+            black_hole_mass: black_hole.mass,
+            schwarzschild_radius: black_hole.schwarzschild_radius,
+            spin: black_hole.spin,
+            time,
+            disk_inner_radius: disk.inner_radius,
+            disk_outer_radius: disk.outer_radius,
+            disk_temp_inner: disk.temperature_inner,
+            disk_temp_outer: disk.temperature_outer,
+        }
+    }
+}
+
+/// A background star as uploaded to the `stars` storage buffer, matching
+/// `generate_star_field`'s `(Vec3, f32)` pairs
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct StarGpu {
+    pub position: [f32; 3],
+    pub brightness: f32,
+}
+
+impl From<(Vec3, f32)> for StarGpu {
+    fn from((position, brightness): (Vec3, f32)) -> Self {
+        Self {
+            position: position.into(),
+            brightness,
+        }
+    }
+}
+
+/// GPU compute alternative to `Renderer3D`'s fragment-shader ray march:
+/// `cs_trace_geodesic` launches one thread per output pixel, builds a camera
+/// ray from `GeodesicCameraUniform`, and integrates the Schwarzschild
+/// geodesic with the same RK4 step `LightRay::trace` runs on the CPU. Each
+/// thread walks until it crosses the horizon (black), hits the accretion
+/// disk plane between `inner_radius` and `outer_radius` (blackbody color
+/// from `temperature_at`), or escapes past r=50 (the `stars` background
+/// projected onto the sky). The result is written into `output_view`, a
+/// storage texture the existing render pass can blit to the swapchain with
+/// `blit`.
+pub struct GeodesicComputeRenderer {
+    compute_pipeline: wgpu::ComputePipeline,
+    compute_bind_group_layout: wgpu::BindGroupLayout,
+    compute_bind_group: wgpu::BindGroup,
+    camera_buffer: wgpu::Buffer,
+    params_buffer: wgpu::Buffer,
+    star_buffer: wgpu::Buffer,
+    num_stars: u32,
+    output_texture: wgpu::Texture,
+    output_view: wgpu::TextureView,
+    blit_pipeline: wgpu::RenderPipeline,
+    blit_bind_group_layout: wgpu::BindGroupLayout,
+    blit_bind_group: wgpu::BindGroup,
+    blit_sampler: wgpu::Sampler,
+    width: u32,
+    height: u32,
+}
+
+impl GeodesicComputeRenderer {
+    const OUTPUT_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+    const WORKGROUP_SIZE: u32 = 16;
+    const MAX_STARS: usize = 4096;
+
+    pub fn new(ctx: &GraphicsContext, width: u32, height: u32, stars: &[(Vec3, f32)]) -> Self {
+        let device = &ctx.device;
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Geodesic Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/geodesic_trace.wgsl").into()),
+        });
+
+        let camera_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Geodesic Camera Buffer"),
+            size: std::mem::size_of::<GeodesicCameraUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Geodesic Params Buffer"),
+            size: std::mem::size_of::<GeodesicParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let num_stars = stars.len().min(Self::MAX_STARS);
+        let star_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Geodesic Star Buffer"),
+            size: (std::mem::size_of::<StarGpu>() * Self::MAX_STARS) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let star_data: Vec<StarGpu> = stars.iter().take(Self::MAX_STARS).map(|&s| s.into()).collect();
+        ctx.queue.write_buffer(&star_buffer, 0, bytemuck::cast_slice(&star_data));
+
+        let (output_texture, output_view) = Self::create_output_texture(device, width, height);
+
+        let compute_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Geodesic Compute Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::StorageTexture {
+                            access: wgpu::StorageTextureAccess::WriteOnly,
+                            format: Self::OUTPUT_FORMAT,
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let compute_bind_group = Self::create_compute_bind_group(
+            device,
+            &compute_bind_group_layout,
+            &output_view,
+            &camera_buffer,
+            &params_buffer,
+            &star_buffer,
+        );
+
+        let compute_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Geodesic Compute Pipeline Layout"),
+            bind_group_layouts: &[&compute_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let compute_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Geodesic Compute Pipeline"),
+            layout: Some(&compute_pipeline_layout),
+            module: &shader,
+            entry_point: "cs_trace_geodesic",
+            compilation_options: Default::default(),
+        });
+
+        let blit_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Geodesic Blit Sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let blit_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Geodesic Blit Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let blit_bind_group =
+            Self::create_blit_bind_group(device, &blit_bind_group_layout, &output_view, &blit_sampler);
+
+        let blit_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Geodesic Blit Pipeline Layout"),
+            bind_group_layouts: &[&blit_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let blit_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Geodesic Blit Pipeline"),
+            layout: Some(&blit_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_blit",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        Self {
+            compute_pipeline,
+            compute_bind_group_layout,
+            compute_bind_group,
+            camera_buffer,
+            params_buffer,
+            star_buffer,
+            num_stars: num_stars as u32,
+            output_texture,
+            output_view,
+            blit_pipeline,
+            blit_bind_group_layout,
+            blit_bind_group,
+            blit_sampler,
+            width,
+            height,
+        }
+    }
+
+    fn create_output_texture(
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+    ) -> (wgpu::Texture, wgpu::TextureView) {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Geodesic Output Texture"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: Self::OUTPUT_FORMAT,
+            usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        (texture, view)
+    }
+
+    fn create_compute_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        output_view: &wgpu::TextureView,
+        camera_buffer: &wgpu::Buffer,
+        params_buffer: &wgpu::Buffer,
+        star_buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Geodesic Compute Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(output_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: camera_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: star_buffer.as_entire_binding(),
+                },
+            ],
+        })
+    }
+
+    fn create_blit_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        output_view: &wgpu::TextureView,
+        sampler: &wgpu::Sampler,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Geodesic Blit Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(output_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+            ],
+        })
+    }
+
+    /// Recreate the output texture at the new resolution; call on window resize
+    pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+        let (output_texture, output_view) = Self::create_output_texture(device, width, height);
+        self.output_texture = output_texture;
+        self.compute_bind_group = Self::create_compute_bind_group(
+            device,
+            &self.compute_bind_group_layout,
+            &output_view,
+            &self.camera_buffer,
+            &self.params_buffer,
+            &self.star_buffer,
+        );
+        self.blit_bind_group = Self::create_blit_bind_group(
+            device,
+            &self.blit_bind_group_layout,
+            &output_view,
+            &self.blit_sampler,
+        );
+        self.output_view = output_view;
+    }
+
+    pub fn update_camera(&self, queue: &wgpu::Queue, camera: GeodesicCameraUniform) {
+        queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[camera]));
+    }
+
+    pub fn update_params(&self, queue: &wgpu::Queue, black_hole: &BlackHole, disk: &AccretionDisk, time: f32) {
+        let params = GeodesicParams::new(black_hole, disk, time);
+        queue.write_buffer(&self.params_buffer, 0, bytemuck::cast_slice(&[params]));
+    }
+
+    /// Dispatch `cs_trace_geodesic`, one thread per output pixel
+    pub fn dispatch(&self, encoder: &mut wgpu::CommandEncoder) {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Geodesic Compute Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.compute_pipeline);
+        pass.set_bind_group(0, &self.compute_bind_group, &[]);
+        pass.dispatch_workgroups(
+            self.width.div_ceil(Self::WORKGROUP_SIZE),
+            self.height.div_ceil(Self::WORKGROUP_SIZE),
+            1,
+        );
+    }
+
+    /// Blit the storage texture `dispatch` wrote onto the swapchain view
+    pub fn blit(&self, encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Geodesic Blit Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&self.blit_pipeline);
+        render_pass.set_bind_group(0, &self.blit_bind_group, &[]);
+        render_pass.draw(0..3, 0..1); // Full-screen triangle
+    }
+
+    pub fn num_stars(&self) -> u32 {
+        self.num_stars
     }
 }
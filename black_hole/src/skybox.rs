@@ -0,0 +1,55 @@
+//! Skybox cubemap discovery
+//!
+//! Scans a `skyboxes/` directory in the working directory for subdirectories
+//! that each contain six face images, mirroring how
+//! `solar_voyage::scenario::discover_scenarios` finds `.rhai` scripts on
+//! disk instead of hard-coding a single asset path. `App` cycles through
+//! whatever sets are found with a key press, passing each one's `faces` to
+//! `Renderer3D::set_skybox`.
+
+use std::path::{Path, PathBuf};
+
+/// The six face filenames expected inside a skybox subdirectory, in
+/// `wgpu`'s cube texture layer order: `+X, -X, +Y, -Y, +Z, -Z`
+pub const FACE_NAMES: [&str; 6] = ["px.png", "nx.png", "py.png", "ny.png", "pz.png", "nz.png"];
+
+/// One discovered skybox: a display name (the subdirectory's file name) and
+/// its six face image paths, in `FACE_NAMES` order
+pub struct SkyboxSet {
+    pub name: String,
+    pub faces: [PathBuf; 6],
+}
+
+/// Find every subdirectory of `dir` that contains all six `FACE_NAMES`
+/// files, sorted by name for a deterministic cycling order
+pub fn discover_skyboxes(dir: &Path) -> Vec<SkyboxSet> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut sets = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let faces: Vec<PathBuf> = FACE_NAMES.iter().map(|name| path.join(name)).collect();
+        if !faces.iter().all(|face| face.is_file()) {
+            continue;
+        }
+
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("skybox")
+            .to_string();
+        sets.push(SkyboxSet {
+            name,
+            faces: faces.try_into().expect("FACE_NAMES has exactly 6 entries"),
+        });
+    }
+
+    sets.sort_by(|a, b| a.name.cmp(&b.name));
+    sets
+}
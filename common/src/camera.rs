@@ -1,6 +1,7 @@
 //! Camera system for 2D and 3D simulations
 
-use glam::{Mat4, Vec3};
+use glam::{EulerRot, Mat4, Quat, Vec2, Vec3, Vec4};
+use winit::{event::ElementState, keyboard::KeyCode};
 
 /// 2D orthographic camera
 #[derive(Debug, Clone)]
@@ -41,6 +42,64 @@ impl Camera2D {
     pub fn update_aspect_ratio(&mut self, aspect_ratio: f32) {
         self.aspect_ratio = aspect_ratio;
     }
+
+    /// Recompute `position`/`zoom` to frame the axis-aligned box `[min, max]`
+    /// with `padding` extra world units of margin on every side — a "frame
+    /// all" shortcut for e.g. fitting the current electron bounding box back
+    /// into view after panning away from it at high zoom. Falls back to
+    /// `min_zoom` for a degenerate (single-point) box instead of zooming to
+    /// an unusably tight frame.
+    pub fn frame_bounds(&mut self, min: Vec2, max: Vec2, padding: f32, min_zoom: f32) {
+        let center = (min + max) * 0.5;
+        self.position.x = center.x;
+        self.position.y = center.y;
+
+        let half_extent = (max - min) * 0.5 + Vec2::splat(padding);
+        let zoom_for_height = half_extent.y;
+        let zoom_for_width = half_extent.x / self.aspect_ratio.max(1e-6);
+        self.zoom = zoom_for_height.max(zoom_for_width).max(min_zoom);
+    }
+
+    /// Unproject a point in normalized device coordinates (`[-1, 1]`, Y up)
+    /// back into 2D world space through the inverse view-projection matrix.
+    /// Used to translate a mouse click into a world-space position, e.g.
+    /// dropping a measurement probe marker on a wavefunction plot.
+    pub fn screen_to_world(&self, ndc: Vec2) -> Vec2 {
+        let inv_view_proj = self.view_projection().inverse();
+        let world = inv_view_proj * Vec4::new(ndc.x, ndc.y, 0.0, 1.0);
+        Vec2::new(world.x / world.w, world.y / world.w)
+    }
+
+    /// Pan by a screen-space pixel delta (origin top-left, Y down), e.g. a
+    /// middle/right mouse drag: converts the delta to world units at the
+    /// current zoom level so a given pixel drag feels the same regardless of
+    /// how zoomed in the view is, and moves the camera so the world point
+    /// under the cursor follows the drag. Returns the world-space delta that
+    /// was applied, so a caller can turn it into an inertial pan velocity.
+    pub fn pan_screen(&mut self, dx: f32, dy: f32, screen_width: f32, screen_height: f32) -> Vec2 {
+        let world_per_pixel_x = (2.0 * self.zoom * self.aspect_ratio) / screen_width;
+        let world_per_pixel_y = (2.0 * self.zoom) / screen_height;
+        let delta = Vec2::new(-dx * world_per_pixel_x, dy * world_per_pixel_y);
+        self.position.x += delta.x;
+        self.position.y += delta.y;
+        delta
+    }
+
+    /// Scale `zoom` by `factor` (clamped to `[min_zoom, max_zoom]`) while
+    /// keeping the world-space point under `ndc` (normalized device
+    /// coordinates, `[-1, 1]`, Y up) fixed on screen, so scroll-to-zoom
+    /// converges on the point under the cursor instead of the camera center.
+    pub fn zoom_toward(&mut self, ndc: Vec2, factor: f32, min_zoom: f32, max_zoom: f32) {
+        let extents = Vec2::new(self.aspect_ratio, 1.0);
+        let world_before = self.position.truncate() + ndc * self.zoom * extents;
+
+        self.zoom = (self.zoom * factor).clamp(min_zoom, max_zoom);
+
+        let world_after = self.position.truncate() + ndc * self.zoom * extents;
+        let correction = world_before - world_after;
+        self.position.x += correction.x;
+        self.position.y += correction.y;
+    }
 }
 
 /// 3D perspective camera with orbital controls
@@ -55,24 +114,22 @@ pub struct Camera3D {
     pub far: f32,
     // Orbital parameters
     pub distance: f32,
-    pub yaw: f32,
-    pub pitch: f32,
+    /// Camera orientation around `target`, stored as a quaternion rather
+    /// than raw yaw/pitch floats so an arcball drag can accumulate
+    /// rotation from any direction without gimbal lock. Keyboard orbiting
+    /// and arcball dragging both end up composing a small incremental
+    /// quat into this value; `yaw()`/`pitch()` decompose it back out for
+    /// callers (snapshots, scene scripts) that still want those angles.
+    orientation: Quat,
 }
 
 impl Camera3D {
     pub fn new(aspect_ratio: f32) -> Self {
         let distance = 10.0;
-        let yaw = 0.0f32;
-        let pitch = 0.3f32;
-
-        let position = Vec3::new(
-            distance * pitch.cos() * yaw.sin(),
-            distance * pitch.sin(),
-            distance * pitch.cos() * yaw.cos(),
-        );
+        let orientation = Self::orientation_from_yaw_pitch(0.0, 0.3);
 
         Self {
-            position,
+            position: orientation * Vec3::Z * distance,
             target: Vec3::ZERO,
             up: Vec3::Y,
             fov: 45.0f32.to_radians(),
@@ -80,24 +137,121 @@ impl Camera3D {
             near: 0.1,
             far: 1000.0,
             distance,
-            yaw,
-            pitch,
+            orientation,
         }
     }
 
+    fn orientation_from_yaw_pitch(yaw: f32, pitch: f32) -> Quat {
+        // YXZ (yaw, then pitch, then roll) with pitch negated reproduces
+        // the original `distance * (cos(pitch)*sin(yaw), sin(pitch),
+        // cos(pitch)*cos(yaw))` offset when applied to `Vec3::Z`
+        Quat::from_euler(EulerRot::YXZ, yaw, -pitch, 0.0)
+    }
+
+    /// Current yaw angle (radians), decomposed from `orientation`
+    pub fn yaw(&self) -> f32 {
+        self.orientation.to_euler(EulerRot::YXZ).0
+    }
+
+    /// Current pitch angle (radians), decomposed from `orientation`
+    pub fn pitch(&self) -> f32 {
+        -self.orientation.to_euler(EulerRot::YXZ).1
+    }
+
+    /// Set yaw and pitch directly (pitch clamped as `orbit` clamps it),
+    /// rebuilding `orientation` from scratch. For callers restoring a saved
+    /// camera pose or resetting to a fixed angle, rather than dragging.
+    pub fn set_yaw_pitch(&mut self, yaw: f32, pitch: f32) {
+        self.orientation = Self::orientation_from_yaw_pitch(yaw, pitch.clamp(-1.5, 1.5));
+        self.update_orbital();
+    }
+
+    /// Set pitch directly, keeping the current yaw
+    pub fn set_pitch(&mut self, pitch: f32) {
+        self.set_yaw_pitch(self.yaw(), pitch);
+    }
+
+    /// Set yaw directly, keeping the current pitch
+    pub fn set_yaw(&mut self, yaw: f32) {
+        self.set_yaw_pitch(yaw, self.pitch());
+    }
+
+    /// World-space right vector of the current orientation, for screen-space
+    /// panning (dragging the view sideways moves `target` along this axis)
+    pub fn orientation_right(&self) -> Vec3 {
+        self.orientation * Vec3::X
+    }
+
+    /// World-space up vector of the current orientation, for screen-space
+    /// panning (dragging the view vertically moves `target` along this axis)
+    pub fn orientation_up(&self) -> Vec3 {
+        self.orientation * Vec3::Y
+    }
+
     /// Update camera position based on orbital parameters
     pub fn update_orbital(&mut self) {
-        self.position = self.target + Vec3::new(
-            self.distance * self.pitch.cos() * self.yaw.sin(),
-            self.distance * self.pitch.sin(),
-            self.distance * self.pitch.cos() * self.yaw.cos(),
-        );
+        self.position = self.target + self.orientation * Vec3::Z * self.distance;
     }
 
-    /// Orbit the camera around the target
+    /// Orbit the camera around the target by composing a world-space yaw
+    /// rotation and a rotation around the camera's local right axis for
+    /// pitch, then re-clamping pitch the same way the old yaw/pitch fields
+    /// were clamped (a quat has no intrinsic limit to clamp otherwise)
     pub fn orbit(&mut self, delta_yaw: f32, delta_pitch: f32) {
-        self.yaw += delta_yaw;
-        self.pitch = (self.pitch + delta_pitch).clamp(-1.5, 1.5);
+        let yaw_delta = Quat::from_rotation_y(delta_yaw);
+        let right = self.orientation * Vec3::X;
+        let pitch_delta = Quat::from_axis_angle(right, delta_pitch);
+        self.orientation = (yaw_delta * pitch_delta * self.orientation).normalize();
+
+        let clamped_pitch = self.pitch().clamp(-1.5, 1.5);
+        if clamped_pitch != self.pitch() {
+            self.orientation = Self::orientation_from_yaw_pitch(self.yaw(), clamped_pitch);
+        }
+
+        self.update_orbital();
+    }
+
+    /// Map a cursor position in normalized device coordinates (`[-1, 1]`,
+    /// Y up) onto a virtual unit arcball centered on the viewport: points
+    /// inside the unit circle project onto the sphere's front face, points
+    /// outside it slide onto the equator so a fast drag past the edge of
+    /// the window doesn't produce an undefined mapping
+    fn arcball_point(ndc: Vec2) -> Vec3 {
+        let r2 = ndc.x * ndc.x + ndc.y * ndc.y;
+        if r2 <= 1.0 {
+            Vec3::new(ndc.x, ndc.y, (1.0 - r2).sqrt())
+        } else {
+            let n = ndc.normalize();
+            Vec3::new(n.x, n.y, 0.0)
+        }
+    }
+
+    /// Rotate the camera by dragging from one cursor position to another,
+    /// both in normalized device coordinates (`[-1, 1]`, Y up). Builds the
+    /// rotation between the two arcball points directly (axis = a×b, angle
+    /// from a·b) rather than stepping yaw/pitch, so a single fast diagonal
+    /// drag rotates smoothly instead of clipping through the poles.
+    pub fn arcball_drag(&mut self, from: Vec2, to: Vec2) {
+        let a = Self::arcball_point(from);
+        let b = Self::arcball_point(to);
+
+        let axis = a.cross(b);
+        let axis_len = axis.length();
+
+        // Below this the drag is too small to normalize a stable axis
+        // from; treat it as no rotation rather than risk NaN from
+        // dividing by a near-zero length
+        const TOLERANCE: f32 = 1e-6;
+        if axis_len < TOLERANCE {
+            return;
+        }
+
+        let angle = a.dot(b).clamp(-1.0, 1.0).acos();
+        let rotation = Quat::from_axis_angle(axis / axis_len, angle);
+
+        // The drag rotates the view, so the camera orbits opposite the
+        // direction the sphere point moved
+        self.orientation = (rotation.inverse() * self.orientation).normalize();
         self.update_orbital();
     }
 
@@ -125,28 +279,244 @@ impl Camera3D {
     pub fn update_aspect_ratio(&mut self, aspect_ratio: f32) {
         self.aspect_ratio = aspect_ratio;
     }
+
+    /// Cast a ray from the camera through a point in screen space (pixels,
+    /// origin top-left, Y down) out into world space. Used for mouse-picking
+    /// world geometry under the cursor.
+    pub fn screen_ray(&self, screen_x: f32, screen_y: f32, screen_width: f32, screen_height: f32) -> (Vec3, Vec3) {
+        let ndc_x = 2.0 * screen_x / screen_width - 1.0;
+        let ndc_y = 1.0 - 2.0 * screen_y / screen_height;
+        self.screen_to_world_ray(Vec2::new(ndc_x, ndc_y))
+    }
+
+    /// Cast a ray from the camera through a point already given in
+    /// normalized device coordinates (`[-1, 1]`, Y up), by unprojecting the
+    /// near and far planes at that point through the inverse view-projection
+    /// matrix. Returns `(origin, direction)` with `direction` normalized.
+    pub fn screen_to_world_ray(&self, ndc: Vec2) -> (Vec3, Vec3) {
+        let inv_view_proj = self.view_projection().inverse();
+
+        let unproject = |ndc_z: f32| {
+            let clip = inv_view_proj * Vec4::new(ndc.x, ndc.y, ndc_z, 1.0);
+            clip.truncate() / clip.w
+        };
+
+        let near = unproject(0.0);
+        let far = unproject(1.0);
+
+        (near, (far - near).normalize())
+    }
+
+    /// Direction the camera faces, derived from `orientation` the same way
+    /// `update_orbital`'s target-to-camera offset is, just pointed the other
+    /// way — this is shared by orbital mode (where it's implied by
+    /// `target - position`) and free-fly mode (where there is no target to
+    /// derive it from)
+    fn look_direction(&self) -> Vec3 {
+        -(self.orientation * Vec3::Z)
+    }
+
+    /// Move the camera along its own view basis: `local.x` is right/left,
+    /// `local.y` is world-up/down, `local.z` is forward/back. Used by
+    /// free-fly mode, where the camera has no orbit target to stay locked
+    /// onto — `target` is kept one unit ahead purely so `view_matrix` still
+    /// has something to look at.
+    pub fn fly_move(&mut self, local: Vec3, dt: f32) {
+        let forward = self.look_direction();
+        let right = forward.cross(self.up).normalize();
+
+        self.position += (right * local.x + self.up * local.y + forward * local.z) * dt;
+        self.target = self.position + forward;
+    }
+
+    /// Rotate the free-fly view direction by a mouse-look delta, keeping
+    /// `target` one unit ahead of `position` along the new direction
+    pub fn fly_rotate(&mut self, delta_yaw: f32, delta_pitch: f32) {
+        let yaw_delta = Quat::from_rotation_y(delta_yaw);
+        let right = self.orientation * Vec3::X;
+        let pitch_delta = Quat::from_axis_angle(right, delta_pitch);
+        self.orientation = (yaw_delta * pitch_delta * self.orientation).normalize();
+
+        let clamped_pitch = self.pitch().clamp(-1.5, 1.5);
+        if clamped_pitch != self.pitch() {
+            self.orientation = Self::orientation_from_yaw_pitch(self.yaw(), clamped_pitch);
+        }
+
+        self.target = self.position + self.look_direction();
+    }
+}
+
+/// Unified delta-time camera controller, mirroring the learn-wgpu controller
+/// pattern: key/mouse/scroll input just accumulates state, and a single
+/// `update_camera`/`update_camera_2d` call per frame integrates it against
+/// `dt`. This replaces each simulation hand-rolling its own discrete
+/// per-keypress stepping, and applies exponential damping to movement so
+/// input eases in and out instead of jumping instantly to a fixed step.
+#[derive(Debug, Clone)]
+pub struct CameraController {
+    /// World units per second of held movement input
+    pub speed: f32,
+    /// Radians per pixel of accumulated mouse delta
+    pub mouse_sensitivity: f32,
+    /// Distance/zoom units per notch of scroll
+    pub scroll_sensitivity: f32,
+    /// Fraction of velocity that decays per second (`velocity *=
+    /// (-decay*dt).exp()`), so released keys ease to a stop
+    pub decay: f32,
+
+    move_forward: bool,
+    move_back: bool,
+    move_left: bool,
+    move_right: bool,
+    move_up: bool,
+    move_down: bool,
+
+    mouse_delta: (f32, f32),
+    scroll_delta: f32,
+    velocity: Vec3,
 }
 
+impl CameraController {
+    pub fn new(speed: f32, mouse_sensitivity: f32) -> Self {
+        Self {
+            speed,
+            mouse_sensitivity,
+            scroll_sensitivity: 5.0,
+            decay: 8.0,
+            move_forward: false,
+            move_back: false,
+            move_left: false,
+            move_right: false,
+            move_up: false,
+            move_down: false,
+            mouse_delta: (0.0, 0.0),
+            scroll_delta: 0.0,
+            velocity: Vec3::ZERO,
+        }
+    }
+
+    /// Track a movement key's pressed/released state. Unrecognized keys are
+    /// ignored so callers can forward every key event unconditionally.
+    pub fn process_key(&mut self, key: KeyCode, state: ElementState) -> bool {
+        let pressed = state == ElementState::Pressed;
+        match key {
+            KeyCode::KeyW | KeyCode::ArrowUp => self.move_forward = pressed,
+            KeyCode::KeyS | KeyCode::ArrowDown => self.move_back = pressed,
+            KeyCode::KeyA | KeyCode::ArrowLeft => self.move_left = pressed,
+            KeyCode::KeyD | KeyCode::ArrowRight => self.move_right = pressed,
+            KeyCode::KeyE | KeyCode::Space => self.move_up = pressed,
+            KeyCode::KeyQ => self.move_down = pressed,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Accumulate a mouse-look delta (pixels since the last frame); consumed
+    /// and reset by the next `update_camera`/`update_camera_2d` call
+    pub fn process_mouse(&mut self, dx: f32, dy: f32) {
+        self.mouse_delta.0 += dx;
+        self.mouse_delta.1 += dy;
+    }
+
+    /// Accumulate a scroll-wheel delta; consumed and reset by the next
+    /// `update_camera`/`update_camera_2d` call
+    pub fn process_scroll(&mut self, delta: f32) {
+        self.scroll_delta += delta;
+    }
+
+    /// Integrate one frame of accumulated input into an orbiting `Camera3D`:
+    /// mouse delta becomes an orbit rotation (pitch clamped to ±1.5 same as
+    /// `Camera3D::orbit`), scroll becomes zoom, and held movement keys build
+    /// up a damped velocity along the view basis that pans the orbit target,
+    /// with `update_orbital` re-deriving `position` from `target` afterward.
+    pub fn update_camera(&mut self, camera: &mut Camera3D, dt: f32) {
+        let (dx, dy) = self.mouse_delta;
+        camera.orbit(dx * self.mouse_sensitivity, -dy * self.mouse_sensitivity);
+        self.mouse_delta = (0.0, 0.0);
+
+        camera.zoom(self.scroll_delta * self.scroll_sensitivity * dt);
+        self.scroll_delta = 0.0;
+
+        let input = Vec3::new(
+            (self.move_right as i32 - self.move_left as i32) as f32,
+            (self.move_up as i32 - self.move_down as i32) as f32,
+            (self.move_forward as i32 - self.move_back as i32) as f32,
+        );
+        self.velocity += input * self.speed * dt;
+
+        let forward = (camera.target - camera.position).normalize_or_zero();
+        let right = forward.cross(camera.up).normalize_or_zero();
+        camera.target += (right * self.velocity.x + camera.up * self.velocity.y
+            + forward * self.velocity.z)
+            * dt;
+        camera.update_orbital();
+
+        self.velocity *= (-self.decay * dt).exp();
+    }
+
+    /// Integrate one frame of accumulated input into a `Camera2D`: held
+    /// left/right/up/down keys pan `position` scaled by the current zoom (so
+    /// panning speed stays visually constant regardless of zoom level), and
+    /// scroll zooms in/out
+    pub fn update_camera_2d(&mut self, camera: &mut Camera2D, dt: f32) {
+        self.mouse_delta = (0.0, 0.0);
+
+        let input = Vec3::new(
+            (self.move_right as i32 - self.move_left as i32) as f32,
+            (self.move_forward as i32 - self.move_back as i32) as f32,
+            0.0,
+        );
+        self.velocity += input * self.speed * dt;
+
+        camera.position.x += self.velocity.x * camera.zoom * dt;
+        camera.position.y += self.velocity.y * camera.zoom * dt;
+
+        camera.zoom = (camera.zoom * (1.0 - self.scroll_delta * self.scroll_sensitivity * dt))
+            .clamp(1.0, 100.0);
+        self.scroll_delta = 0.0;
+
+        self.velocity *= (-self.decay * dt).exp();
+    }
+}
+
+/// Fixed directional light used by Lambert-shaded pipelines (e.g. a lit
+/// sphere mesh), passed through the camera uniform so shaders don't need a
+/// separate lighting bind group
+const LIGHT_DIR: [f32; 4] = [0.40824829, 0.81649658, 0.40824829, 0.0];
+
 /// Camera uniform data for shaders
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct CameraUniform {
     pub view_proj: [[f32; 4]; 4],
+    /// Inverse of `view_proj`, so a shader can unproject a screen-space
+    /// point (e.g. for GPU-side picking or screen-space effects) without
+    /// inverting the matrix itself
+    pub inv_view_proj: [[f32; 4]; 4],
     pub position: [f32; 4],
+    /// World-space direction the fixed light shines from, for Lambert
+    /// shading (see `LIGHT_DIR`)
+    pub light_dir: [f32; 4],
 }
 
 impl CameraUniform {
     pub fn from_camera_3d(camera: &Camera3D) -> Self {
+        let view_proj = camera.view_projection();
         Self {
-            view_proj: camera.view_projection().to_cols_array_2d(),
+            view_proj: view_proj.to_cols_array_2d(),
+            inv_view_proj: view_proj.inverse().to_cols_array_2d(),
             position: [camera.position.x, camera.position.y, camera.position.z, 1.0],
+            light_dir: LIGHT_DIR,
         }
     }
 
     pub fn from_camera_2d(camera: &Camera2D) -> Self {
+        let view_proj = camera.view_projection();
         Self {
-            view_proj: camera.view_projection().to_cols_array_2d(),
+            view_proj: view_proj.to_cols_array_2d(),
+            inv_view_proj: view_proj.inverse().to_cols_array_2d(),
             position: [camera.position.x, camera.position.y, camera.position.z, 1.0],
+            light_dir: LIGHT_DIR,
         }
     }
 }
@@ -0,0 +1,141 @@
+//! Frame recording and PNG export
+//!
+//! Reads back the contents of a rendered surface texture into a CPU buffer
+//! and writes it out as a PNG, so simulations can export individual frames
+//! or a numbered sequence (a simple "video" of frames usable as a flipbook)
+//! without needing a dedicated video encoder dependency.
+
+use std::path::{Path, PathBuf};
+
+/// Records frames to disk as a numbered PNG sequence
+pub struct FrameRecorder {
+    output_dir: PathBuf,
+    frame_index: u32,
+    pub recording: bool,
+}
+
+impl FrameRecorder {
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            frame_index: 0,
+            recording: false,
+        }
+    }
+
+    pub fn start(&mut self) {
+        std::fs::create_dir_all(&self.output_dir).ok();
+        self.frame_index = 0;
+        self.recording = true;
+    }
+
+    pub fn stop(&mut self) {
+        self.recording = false;
+    }
+
+    /// Capture the given surface texture to disk if recording is active
+    ///
+    /// Blocks until the GPU readback completes; call after `queue.submit`.
+    pub fn capture_if_recording(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        texture: &wgpu::Texture,
+    ) -> Result<(), CaptureError> {
+        if !self.recording {
+            return Ok(());
+        }
+
+        let path = self.output_dir.join(format!("frame_{:05}.png", self.frame_index));
+        capture_texture_to_png(device, queue, texture, &path)?;
+        self.frame_index += 1;
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CaptureError {
+    #[error("failed to map readback buffer")]
+    MapFailed,
+    #[error("failed to encode PNG: {0}")]
+    Image(#[from] image::ImageError),
+}
+
+/// Read back a single RGBA8 texture into an in-memory image, without
+/// writing it to disk; blocks until the GPU readback completes. Used both
+/// by `capture_texture_to_png` and by renderers' own offscreen
+/// `render_to_image` paths that need a window-independent frame.
+pub fn read_texture_to_image(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+) -> Result<image::RgbaImage, CaptureError> {
+    let width = texture.width();
+    let height = texture.height();
+
+    // Row bytes must be padded to wgpu's copy alignment
+    let unpadded_bytes_per_row = width * 4;
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    let padded_bytes_per_row = unpadded_bytes_per_row.div_ceil(align) * align;
+
+    let buffer_size = (padded_bytes_per_row * height) as wgpu::BufferAddress;
+    let output_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("frame_capture_buffer"),
+        size: buffer_size,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("frame_capture_encoder"),
+    });
+    encoder.copy_texture_to_buffer(
+        wgpu::ImageCopyTexture {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::ImageCopyBuffer {
+            buffer: &output_buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_bytes_per_row),
+                rows_per_image: Some(height),
+            },
+        },
+        wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+    );
+    queue.submit(Some(encoder.finish()));
+
+    let slice = output_buffer.slice(..);
+    let (tx, rx) = std::sync::mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        let _ = tx.send(result);
+    });
+    device.poll(wgpu::Maintain::Wait);
+    rx.recv().ok().ok_or(CaptureError::MapFailed)?.map_err(|_| CaptureError::MapFailed)?;
+
+    let data = slice.get_mapped_range();
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    for row in 0..height {
+        let start = (row * padded_bytes_per_row) as usize;
+        pixels.extend_from_slice(&data[start..start + unpadded_bytes_per_row as usize]);
+    }
+    drop(data);
+    output_buffer.unmap();
+
+    image::RgbaImage::from_raw(width, height, pixels).ok_or(CaptureError::MapFailed)
+}
+
+/// Read back a single RGBA8 surface texture and write it to `path` as a PNG
+pub fn capture_texture_to_png(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    path: &Path,
+) -> Result<(), CaptureError> {
+    let image = read_texture_to_image(device, queue, texture)?;
+    image.save(path)?;
+    Ok(())
+}
@@ -11,6 +11,7 @@ use winit::{
 /// Holds all GPU resources needed for rendering
 pub struct GraphicsContext {
     pub surface: wgpu::Surface<'static>,
+    pub adapter: wgpu::Adapter,
     pub device: wgpu::Device,
     pub queue: wgpu::Queue,
     pub config: wgpu::SurfaceConfiguration,
@@ -20,8 +21,15 @@ pub struct GraphicsContext {
 
 impl GraphicsContext {
     /// Create a new graphics context with an associated window
+    ///
+    /// On wasm32, the window's canvas is appended to the document body (no
+    /// native window manager to parent it to) and the wgpu instance/limits
+    /// are narrowed to what WebGPU (falling back to WebGL2) actually offers.
     pub async fn new(title: &str, width: u32, height: u32) -> (Self, EventLoop<()>) {
+        #[cfg(not(target_arch = "wasm32"))]
         env_logger::init();
+        #[cfg(target_arch = "wasm32")]
+        console_log::init_with_level(log::Level::Warn).expect("Failed to init wasm logger");
 
         let event_loop = EventLoop::new().expect("Failed to create event loop");
         let window = Arc::new(
@@ -32,11 +40,30 @@ impl GraphicsContext {
                 .expect("Failed to create window")
         );
 
+        #[cfg(target_arch = "wasm32")]
+        {
+            use winit::platform::web::WindowExtWebSys;
+            web_sys::window()
+                .and_then(|win| win.document())
+                .and_then(|doc| doc.body())
+                .and_then(|body| {
+                    body.append_child(&web_sys::Element::from(window.canvas()?)).ok()
+                })
+                .expect("Failed to append canvas to document body");
+        }
+
         let size = window.inner_size();
 
-        // Create wgpu instance
+        // Create wgpu instance: native picks whichever backend is available,
+        // wasm targets the browser's WebGPU implementation (wgpu falls back
+        // to WebGL2 itself if WebGPU isn't present)
+        #[cfg(not(target_arch = "wasm32"))]
+        let backends = wgpu::Backends::all();
+        #[cfg(target_arch = "wasm32")]
+        let backends = wgpu::Backends::BROWSER_WEBGPU | wgpu::Backends::GL;
+
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::all(),
+            backends,
             ..Default::default()
         });
 
@@ -53,12 +80,26 @@ impl GraphicsContext {
             .await
             .expect("Failed to find suitable GPU adapter");
 
+        // WebGL2 (the wasm fallback) only supports wgpu's "downlevel" limits,
+        // so every wasm build requests those, WebGPU included; native uses
+        // the defaults.
+        #[cfg(not(target_arch = "wasm32"))]
+        let required_limits = wgpu::Limits::default();
+        #[cfg(target_arch = "wasm32")]
+        let required_limits = wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits());
+
+        // Opt into GPU timestamp queries when the adapter offers them, so
+        // per-pass profilers (e.g. solar_voyage's `PassProfiler`) have
+        // something to query; device features are fixed at creation time,
+        // so this has to happen here rather than lazily later
+        let optional_features = wgpu::Features::TIMESTAMP_QUERY & adapter.features();
+
         // Request device and queue
         let (device, queue) = adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
-                    required_features: wgpu::Features::empty(),
-                    required_limits: wgpu::Limits::default(),
+                    required_features: optional_features,
+                    required_limits,
                     label: None,
                 },
                 None,
@@ -90,6 +131,7 @@ impl GraphicsContext {
         (
             Self {
                 surface,
+                adapter,
                 device,
                 queue,
                 config,
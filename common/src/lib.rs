@@ -5,9 +5,12 @@
 
 pub mod graphics;
 pub mod camera;
+pub mod capture;
+pub mod time;
 
 pub use graphics::*;
 pub use camera::*;
+pub use time::Clock;
 
 /// Physical constants used in simulations
 pub mod constants {
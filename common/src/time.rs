@@ -0,0 +1,37 @@
+//! Monotonic clock abstraction over native and wasm32 targets
+//!
+//! `std::time::Instant` panics on `wasm32-unknown-unknown` (there's no OS
+//! clock to query), so every render loop needs a small indirection to stay
+//! portable. `Clock` wraps `std::time::Instant` natively and `web_time::Instant`
+//! (a thin wrapper over `performance.now()`) on wasm, exposing just the
+//! `elapsed`-since-last-tick measurement the fixed-timestep loops need.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+/// A monotonic timer used to measure real elapsed time between frames
+pub struct Clock {
+    last: Instant,
+}
+
+impl Clock {
+    pub fn new() -> Self {
+        Self { last: Instant::now() }
+    }
+
+    /// Seconds elapsed since the last call to `tick`, and resets the clock
+    pub fn tick(&mut self) -> f32 {
+        let now = Instant::now();
+        let dt = (now - self.last).as_secs_f32();
+        self.last = now;
+        dt
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
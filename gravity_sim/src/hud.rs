@@ -0,0 +1,211 @@
+//! Performance HUD: rolling FPS, a frame-time sparkline, and radial gauges
+//! for live tunables
+//!
+//! Painted directly with `egui::Painter` rather than built out of regular
+//! widgets or `egui_plot`, the same approach `solar_voyage`'s cockpit HUD
+//! takes for its own radial gauges. `radial_gauge` is the reusable
+//! primitive: an arc from a normalized `0.0..=1.0` value, colored along a
+//! green-to-red ramp, with a label along the top and a numeric readout in
+//! the middle of the ring.
+
+use egui::{Color32, Painter, Pos2, Stroke, Ui, Vec2};
+use std::collections::VecDeque;
+
+/// How many past frame times are kept: enough for the averaged FPS readout
+/// and to draw a dense sparkline without the plot looking sparse
+const HISTORY_LEN: usize = 120;
+
+/// A small ring buffer of recent frame times, fed one `dt` per frame, used
+/// for an averaged ms/FPS readout and the frame-time history sparkline
+/// instead of a single jittery per-frame number
+pub struct FrameTimeHistory {
+    samples: VecDeque<f32>,
+}
+
+impl FrameTimeHistory {
+    pub fn new() -> Self {
+        Self {
+            samples: VecDeque::with_capacity(HISTORY_LEN),
+        }
+    }
+
+    pub fn push(&mut self, dt: f32) {
+        if self.samples.len() == HISTORY_LEN {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(dt);
+    }
+
+    /// Average frame time in milliseconds, over whatever history has
+    /// accumulated so far (0 before the first frame)
+    pub fn avg_ms(&self) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let avg_dt: f32 = self.samples.iter().sum::<f32>() / self.samples.len() as f32;
+        avg_dt * 1000.0
+    }
+
+    pub fn avg_fps(&self) -> f32 {
+        let avg_ms = self.avg_ms();
+        if avg_ms <= 0.0 {
+            0.0
+        } else {
+            1000.0 / avg_ms
+        }
+    }
+}
+
+/// Color ramp for a gauge's arc: green at 0, yellow in the middle, red at 1
+fn ramp_color(value: f32) -> Color32 {
+    let value = value.clamp(0.0, 1.0);
+    if value < 0.5 {
+        let t = value / 0.5;
+        Color32::from_rgb((t * 255.0) as u8, 255, 0)
+    } else {
+        let t = (value - 0.5) / 0.5;
+        Color32::from_rgb(255, (255.0 * (1.0 - t)) as u8, 0)
+    }
+}
+
+/// Paint one radial gauge: a background ring, a foreground arc scaled by
+/// `value` (normalized against `min`/`max`), a label at the top, and a
+/// numeric readout in the center
+pub fn radial_gauge(ui: &mut Ui, label: &str, value: f32, min: f32, max: f32, display: &str) {
+    let size = Vec2::splat(72.0);
+    let (rect, _response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    let center = rect.center();
+    let radius = rect.width() * 0.5 - 4.0;
+    let normalized = ((value - min) / (max - min).max(1e-6)).clamp(0.0, 1.0);
+
+    paint_arc(&painter, center, radius, 0.0, 1.0, Color32::from_gray(60));
+    paint_arc(&painter, center, radius, 0.0, normalized, ramp_color(normalized));
+
+    painter.text(
+        Pos2::new(center.x, rect.top()),
+        egui::Align2::CENTER_TOP,
+        label,
+        egui::FontId::proportional(11.0),
+        Color32::LIGHT_GRAY,
+    );
+    painter.text(
+        center,
+        egui::Align2::CENTER_CENTER,
+        display,
+        egui::FontId::monospace(13.0),
+        Color32::WHITE,
+    );
+}
+
+/// Paint a `start..end` (both normalized `0.0..=1.0` around the gauge)
+/// stretch of a circular arc, swept clockwise from the top
+fn paint_arc(painter: &Painter, center: Pos2, radius: f32, start: f32, end: f32, color: Color32) {
+    const SEGMENTS: usize = 48;
+    let from = (start * SEGMENTS as f32).round() as usize;
+    let to = (end * SEGMENTS as f32).round() as usize;
+    if to <= from {
+        return;
+    }
+
+    let mut points = Vec::with_capacity(to - from + 1);
+    for i in from..=to {
+        let t = i as f32 / SEGMENTS as f32;
+        let angle = std::f32::consts::TAU * t - std::f32::consts::FRAC_PI_2;
+        points.push(Pos2::new(
+            center.x + angle.cos() * radius,
+            center.y + angle.sin() * radius,
+        ));
+    }
+    painter.add(egui::Shape::line(points, Stroke::new(5.0, color)));
+}
+
+/// Paint a small filled-area sparkline of recent frame times (milliseconds),
+/// scaled against `ceiling_ms` so a steady frame rate reads as a low, flat
+/// band and spikes are obviously visible
+fn paint_frame_time_graph(ui: &mut Ui, history: &FrameTimeHistory, ceiling_ms: f32) {
+    let size = Vec2::new(160.0, 48.0);
+    let (rect, _response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    painter.rect_filled(rect, 2.0, Color32::from_black_alpha(80));
+
+    let samples = &history.samples;
+    if samples.len() < 2 {
+        return;
+    }
+
+    let plot_height = |ms: f32| (ms / ceiling_ms).clamp(0.0, 1.0) * rect.height();
+    let x_step = rect.width() / (HISTORY_LEN - 1) as f32;
+    let start_x = rect.right() - x_step * (samples.len() - 1) as f32;
+
+    let mut top_points = Vec::with_capacity(samples.len());
+    for (i, &dt) in samples.iter().enumerate() {
+        let x = start_x + x_step * i as f32;
+        let y = rect.bottom() - plot_height(dt * 1000.0);
+        top_points.push(Pos2::new(x, y));
+    }
+
+    let mut fill_points = top_points.clone();
+    fill_points.push(Pos2::new(top_points.last().unwrap().x, rect.bottom()));
+    fill_points.push(Pos2::new(top_points[0].x, rect.bottom()));
+    painter.add(egui::Shape::convex_polygon(
+        fill_points,
+        Color32::from_rgba_unmultiplied(100, 200, 255, 60),
+        Stroke::NONE,
+    ));
+    painter.add(egui::Shape::line(top_points, Stroke::new(1.5, Color32::from_rgb(100, 200, 255))));
+
+    painter.text(
+        rect.left_top(),
+        egui::Align2::LEFT_TOP,
+        format!("{ceiling_ms:.0} ms"),
+        egui::FontId::monospace(9.0),
+        Color32::GRAY,
+    );
+}
+
+/// The performance diagnostics overlay: FPS + frame-time sparkline, and
+/// radial gauges for the substep count and the system's total energy (as
+/// drift from the value recorded when the scene was last (re)loaded),
+/// anchored to the bottom-right of the viewport
+pub fn draw_performance_hud(
+    ctx: &egui::Context,
+    frame_times: &FrameTimeHistory,
+    substeps: u32,
+    energy: f32,
+    initial_energy: Option<f32>,
+) {
+    egui::Area::new(egui::Id::new("performance_hud"))
+        .anchor(egui::Align2::RIGHT_BOTTOM, Vec2::new(-12.0, -12.0))
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style())
+                .fill(Color32::from_black_alpha(160))
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{:.1} ms  ({:.0} FPS)",
+                            frame_times.avg_ms(),
+                            frame_times.avg_fps()
+                        ))
+                        .monospace()
+                        .color(Color32::LIGHT_GREEN),
+                    );
+                    paint_frame_time_graph(ui, frame_times, 33.3);
+
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        radial_gauge(ui, "SUBSTEPS", substeps as f32, 1.0, 8.0, &format!("{substeps}"));
+
+                        let drift_pct = match initial_energy {
+                            Some(initial) if initial.abs() > 1e-6 => {
+                                ((energy - initial) / initial.abs()).abs() * 100.0
+                            }
+                            _ => 0.0,
+                        };
+                        radial_gauge(ui, "E DRIFT", drift_pct, 0.0, 50.0, &format!("{drift_pct:.1}%"));
+                    });
+                });
+        });
+}
@@ -7,28 +7,49 @@
 //! - Interactive camera controls
 //!
 //! Controls:
-//! - Scroll: Zoom in/out
+//! - Scroll: Zoom in/out (eases to a stop instead of snapping)
 //! - Arrow keys / WASD: Pan camera
+//! - Mouse drag (any button): Pan camera
 //! - Space: Pause/resume simulation
 //! - 1/2/3: Load different presets
 //! - R: Reset current simulation
+//!
+//! Scenes can also be authored as `.rhai` scripts (see `scene_scripts_2d.rs`)
+//! dropped into `gravity_sim/scenes/` under the working directory; they show
+//! up in the status bar's "Scene script" dropdown alongside the built-in
+//! presets. The directory is scanned once at startup, so new scripts are
+//! picked up by restarting the app.
 
 mod physics;
+mod quadtree;
 mod renderer;
 mod equations_ui;
+mod hud;
+mod scene_scripts_2d;
+
+use std::path::Path;
 
-use common::{Camera2D, GraphicsContext};
+use common::{Camera2D, CameraController, GraphicsContext};
 use glam::Vec3;
 use physics::Simulation;
 use renderer::Renderer;
 use equations_ui::{draw_equations_sidebar, GRAVITY_EQUATIONS, GRAVITY_VARIABLES};
+use hud::{draw_performance_hud, FrameTimeHistory};
+use scene_scripts_2d::{discover_scenes, ScenePreset};
 use winit::{
     event::{ElementState, Event, KeyEvent, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
     keyboard::{KeyCode, PhysicalKey},
 };
 
-const MAX_PARTICLES: usize = 2000;
+// The Barnes-Hut approximation in `quadtree.rs` (selected whenever
+// `Simulation::theta > 0.0`) keeps `Simulation::step` at roughly O(n log n),
+// so this can go well past the old direct-solver-era cap of 2000.
+const MAX_PARTICLES: usize = 20000;
+
+// Substeps per frame, for integrator stability; surfaced read-only in the
+// performance HUD's gauge rather than made configurable at runtime.
+const SUBSTEPS: u32 = 4;
 
 struct EguiState {
     ctx: egui::Context,
@@ -41,9 +62,34 @@ struct App {
     renderer: Renderer,
     simulation: Simulation,
     camera: Camera2D,
+    camera_controller: CameraController,
     paused: bool,
     current_preset: u8,
     egui: EguiState,
+
+    /// Scene scripts found under `scenes/` at startup; `current_preset == 0`
+    /// means the active scene came from one of these instead of the
+    /// hard-coded 1/2/3 presets
+    scene_scripts: Vec<ScenePreset>,
+    selected_script: Option<usize>,
+    show_starfield: bool,
+    show_velocity_vectors: bool,
+
+    /// Any mouse button drags the camera (there's no orbit to reserve the
+    /// left button for, unlike the 3D viewer); tracked so `CursorMoved`
+    /// knows whether to pan
+    dragging: bool,
+    last_mouse_pos: Option<(f64, f64)>,
+    /// Accumulated scroll input not yet applied to `camera.zoom`, decayed
+    /// each frame so a wheel flick eases to a stop instead of snapping
+    zoom_velocity: f32,
+
+    /// Recent frame times for the performance HUD's FPS readout and sparkline
+    frame_times: FrameTimeHistory,
+    /// `total_energy()` recorded the last time a preset/scene was (re)loaded,
+    /// so the HUD's energy gauge can show drift from that baseline rather
+    /// than a raw, hard-to-interpret number
+    initial_energy: Option<f32>,
 }
 
 impl App {
@@ -72,11 +118,15 @@ impl App {
             1,
         );
 
+        let scene_scripts = discover_scenes(Path::new("gravity_sim/scenes"));
+        let initial_energy = Some(simulation.total_energy());
+
         Self {
             ctx,
             renderer,
             simulation,
             camera,
+            camera_controller: CameraController::new(10.0, 0.0),
             paused: false,
             current_preset: 1,
             egui: EguiState {
@@ -84,20 +134,88 @@ impl App {
                 state: egui_state,
                 renderer: egui_renderer,
             },
+            scene_scripts,
+            selected_script: None,
+            show_starfield: true,
+            show_velocity_vectors: false,
+            dragging: false,
+            last_mouse_pos: None,
+            zoom_velocity: 0.0,
+            frame_times: FrameTimeHistory::new(),
+            initial_energy,
         }
     }
 
+    fn handle_mouse_button(&mut self, pressed: bool) {
+        self.dragging = pressed;
+        if !pressed {
+            self.last_mouse_pos = None;
+        }
+    }
+
+    fn handle_mouse_move(&mut self, x: f64, y: f64) {
+        if self.dragging {
+            if let Some((last_x, last_y)) = self.last_mouse_pos {
+                let dx = (x - last_x) as f32;
+                let dy = (y - last_y) as f32;
+                self.camera.pan_screen(
+                    dx,
+                    dy,
+                    self.ctx.size.width as f32,
+                    self.ctx.size.height as f32,
+                );
+            }
+            self.last_mouse_pos = Some((x, y));
+        }
+    }
+
+    /// Clear the simulation and rebuild it from `self.scene_scripts[index]`,
+    /// applying its `config()` toggles to the camera and overlay flags
+    fn load_scene_script(&mut self, index: usize) {
+        let Some(preset) = self.scene_scripts.get(index) else {
+            return;
+        };
+
+        self.simulation.bodies.clear();
+        if let Err(err) = preset.init(&mut self.simulation) {
+            eprintln!("failed to run scene script `{}`: {err}", preset.name);
+            return;
+        }
+
+        let config = preset.config();
+        self.show_starfield = config.show_starfield;
+        self.show_velocity_vectors = config.show_velocity_vectors;
+        self.camera.zoom = config.camera_zoom;
+        self.camera.position = config.camera_position.extend(0.0);
+
+        self.current_preset = 0;
+        self.selected_script = Some(index);
+        self.initial_energy = Some(self.simulation.total_energy());
+    }
+
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.ctx.resize(new_size);
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
     }
 
-    fn update(&mut self, _dt: f32) {
+    fn update(&mut self, dt: f32) {
+        self.frame_times.push(dt);
+        self.camera_controller.update_camera_2d(&mut self.camera, dt);
+
+        // Ease the accumulated scroll input into zoom and decay it, so a
+        // wheel flick glides to a stop over a few frames instead of
+        // snapping straight to the new zoom
+        if self.zoom_velocity.abs() > 1e-4 {
+            self.camera.zoom = (self.camera.zoom * (1.0 - self.zoom_velocity * 5.0 * dt)).clamp(1.0, 100.0);
+            self.zoom_velocity *= (-8.0 * dt).exp();
+        } else {
+            self.zoom_velocity = 0.0;
+        }
+
         if !self.paused {
             // Substep for stability
-            let substeps = 4;
-            let sub_dt = _dt / substeps as f32;
-            for _ in 0..substeps {
+            let sub_dt = dt / SUBSTEPS as f32;
+            for _ in 0..SUBSTEPS {
                 self.simulation.step(sub_dt);
             }
         }
@@ -115,6 +233,8 @@ impl App {
             .update_instances(&self.ctx.queue, &self.simulation.bodies);
 
         // Build egui UI
+        let mut scene_to_load: Option<usize> = None;
+
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
         let full_output = self.egui.ctx.run(raw_input, |ctx| {
             draw_equations_sidebar(
@@ -141,10 +261,45 @@ impl App {
                     } else {
                         ui.label(egui::RichText::new("RUNNING").color(egui::Color32::GREEN));
                     }
+
+                    if !self.scene_scripts.is_empty() {
+                        ui.separator();
+                        let current_label = self
+                            .selected_script
+                            .and_then(|i| self.scene_scripts.get(i))
+                            .map(|preset| preset.name.as_str())
+                            .unwrap_or("(built-in preset)");
+
+                        egui::ComboBox::from_label("Scene script")
+                            .selected_text(current_label)
+                            .show_ui(ui, |ui| {
+                                for i in 0..self.scene_scripts.len() {
+                                    let name = self.scene_scripts[i].name.clone();
+                                    if ui
+                                        .selectable_label(self.selected_script == Some(i), name)
+                                        .clicked()
+                                    {
+                                        scene_to_load = Some(i);
+                                    }
+                                }
+                            });
+                    }
                 });
             });
+
+            draw_performance_hud(
+                ctx,
+                &self.frame_times,
+                SUBSTEPS,
+                self.simulation.total_energy(),
+                self.initial_energy,
+            );
         });
 
+        if let Some(index) = scene_to_load {
+            self.load_scene_script(index);
+        }
+
         self.egui.state.handle_platform_output(&self.ctx.window, full_output.platform_output);
         let tris = self.egui.ctx.tessellate(full_output.shapes, full_output.pixels_per_point);
         for (id, image_delta) in &full_output.textures_delta.set {
@@ -202,6 +357,8 @@ impl App {
     }
 
     fn handle_key(&mut self, key: KeyCode, state: ElementState) {
+        self.camera_controller.process_key(key, state);
+
         if state != ElementState::Pressed {
             return;
         }
@@ -212,21 +369,20 @@ impl App {
             KeyCode::Digit1 => self.load_preset(1),
             KeyCode::Digit2 => self.load_preset(2),
             KeyCode::Digit3 => self.load_preset(3),
-            KeyCode::ArrowUp | KeyCode::KeyW => self.camera.position.y += self.camera.zoom * 0.1,
-            KeyCode::ArrowDown | KeyCode::KeyS => self.camera.position.y -= self.camera.zoom * 0.1,
-            KeyCode::ArrowLeft | KeyCode::KeyA => self.camera.position.x -= self.camera.zoom * 0.1,
-            KeyCode::ArrowRight | KeyCode::KeyD => self.camera.position.x += self.camera.zoom * 0.1,
             _ => {}
         }
     }
 
     fn handle_scroll(&mut self, delta: f32) {
-        self.camera.zoom *= 1.0 - delta * 0.1;
-        self.camera.zoom = self.camera.zoom.clamp(1.0, 100.0);
+        self.zoom_velocity += delta;
     }
 
     fn load_preset(&mut self, preset: u8) {
         self.current_preset = preset;
+        self.selected_script = None;
+        self.show_starfield = true;
+        self.show_velocity_vectors = false;
+
         match preset {
             1 => {
                 self.simulation.init_solar_system();
@@ -234,17 +390,19 @@ impl App {
                 self.camera.position = Vec3::ZERO;
             }
             2 => {
-                self.simulation.init_disk(500);
+                self.simulation.init_disk(8000);
                 self.camera.zoom = 20.0;
                 self.camera.position = Vec3::ZERO;
             }
             3 => {
-                self.simulation.init_galaxy_collision(300);
+                self.simulation.init_galaxy_collision(5000);
                 self.camera.zoom = 25.0;
                 self.camera.position = Vec3::ZERO;
             }
             _ => {}
         }
+
+        self.initial_energy = Some(self.simulation.total_energy());
     }
 
     fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
@@ -274,6 +432,12 @@ fn main() {
                         match event {
                             WindowEvent::CloseRequested => elwt.exit(),
                             WindowEvent::Resized(size) => app.resize(*size),
+                            WindowEvent::MouseInput { state, .. } => {
+                                app.handle_mouse_button(*state == ElementState::Pressed);
+                            }
+                            WindowEvent::CursorMoved { position, .. } => {
+                                app.handle_mouse_move(position.x, position.y);
+                            }
                             WindowEvent::KeyboardInput {
                                 event:
                                     KeyEvent {
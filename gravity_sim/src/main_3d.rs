@@ -8,24 +8,41 @@
 //!
 //! Controls:
 //! - Left mouse drag: Orbit camera
-//! - Scroll: Zoom in/out
+//! - Right/middle mouse drag: Pan camera target
+//! - Scroll: Zoom in/out (eases to a stop instead of snapping)
 //! - 1/2/3: Load presets (Solar System, Accretion Disk, Galaxy Collision)
 //! - Space: Pause/resume
 //! - T: Toggle trails
 //! - G: Toggle grid
-//! - R: Reset view
+//! - R: Reset view (and return to orbital camera)
+//! - F: Toggle free-fly camera mode
+//! - WASD/QE (free-fly only): Move/strafe/vertical; mouse drag looks around
+//! - Left click (without dragging): Select the body under the cursor
+//! - I: Toggle the gravitational potential iso-surface
 //! - +/-: Adjust time scale
+//! - F5/F9: Save/load a snapshot of the current bodies, time scale, and
+//!   camera pose (also available as buttons in the status bar)
 
 mod physics;
 mod physics_3d;
 mod renderer;
 mod renderer_3d;
 mod equations_ui;
+mod scene_scripts;
+mod octree;
+mod marching_cubes;
+mod snapshot;
+mod gpu_solver;
+mod mesh_pool;
+
+use std::path::PathBuf;
 
 use common::{Camera3D, GraphicsContext};
+use glam::{Vec2, Vec3};
 use physics_3d::Simulation3D;
 use renderer_3d::Renderer3D;
 use equations_ui::{draw_equations_sidebar, GRAVITY_3D_EQUATIONS, GRAVITY_3D_VARIABLES};
+use scene_scripts::{discover_scenes, ScenePreset};
 use winit::{
     event::{ElementState, Event, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
@@ -34,6 +51,150 @@ use winit::{
 
 const MAX_PARTICLES: usize = 2000;
 
+/// Fixed physics timestep (240 Hz). Stepping the simulation in fixed
+/// increments — rather than splitting whatever `dt` the frame happened to
+/// deliver into a fixed number of substeps — makes orbits reproducible
+/// regardless of display refresh rate and avoids energy drift when a frame
+/// hitches.
+const PHYSICS_DT: f32 = 1.0 / 240.0;
+
+/// Free-fly camera move speed, in world units per second
+const FLY_SPEED: f32 = 15.0;
+
+/// A left-click release further than this from its press position (in
+/// pixels) counts as an orbit/look drag rather than a pick
+const CLICK_DRAG_THRESHOLD: f32 = 4.0;
+
+/// Grid resolution (per axis) for the gravitational potential iso-surface.
+/// The potential is sampled at `ISO_RESOLUTION³` grid points and marching
+/// cubes visits the `(ISO_RESOLUTION - 1)³` cells between them every frame
+/// it's enabled, so this stays modest rather than scaling with particle count.
+const ISO_RESOLUTION: usize = 24;
+
+/// World units of padding added around the bodies' bounding box before
+/// sampling the potential field, so the well shape isn't clipped right at
+/// the outermost body
+const ISO_PADDING: f32 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CameraMode {
+    /// Mouse-drag orbits around a fixed target (the default)
+    Orbital,
+    /// WASD/QE flies the eye freely along its own view basis; mouse-drag
+    /// looks around instead of orbiting
+    FreeFly,
+}
+
+/// Continuous WASD/QE movement keys for free-fly mode, tracked as
+/// press/release state rather than handled on keydown like the toggle keys
+#[derive(Debug, Clone, Copy, Default)]
+struct FlyKeys {
+    forward: bool,
+    backward: bool,
+    left: bool,
+    right: bool,
+    up: bool,
+    down: bool,
+}
+
+/// What the selection panel asked for, applied after `egui::Context::run`
+/// returns (the panel closure only borrows `self` immutably)
+enum SelectionAction {
+    None,
+    ToggleFollow,
+    Deselect,
+}
+
+/// Side panel showing the picked body's stats and orbital elements, with a
+/// follow-cam toggle and a way to clear the selection
+fn draw_selection_panel(
+    ctx: &egui::Context,
+    sim: &Simulation3D,
+    index: usize,
+    following: bool,
+) -> SelectionAction {
+    let mut action = SelectionAction::None;
+    let Some(body) = sim.bodies.get(index) else {
+        return action;
+    };
+
+    egui::SidePanel::left("selection_panel")
+        .resizable(true)
+        .default_width(240.0)
+        .show(ctx, |ui| {
+            ui.heading(
+                egui::RichText::new(body.name.as_deref().unwrap_or("Selected Body"))
+                    .color(egui::Color32::LIGHT_BLUE),
+            );
+            ui.separator();
+
+            egui::Grid::new("selection_grid")
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Mass");
+                    ui.label(format!("{:.1}", body.mass));
+                    ui.end_row();
+
+                    ui.label("Speed");
+                    ui.label(format!("{:.2}", body.velocity.length()));
+                    ui.end_row();
+
+                    ui.label("Position");
+                    ui.label(format!(
+                        "({:.2}, {:.2}, {:.2})",
+                        body.position.x, body.position.y, body.position.z
+                    ));
+                    ui.end_row();
+                });
+
+            ui.separator();
+            match sim.orbital_elements(index) {
+                Some(elements) => {
+                    let primary_name = sim.bodies[elements.primary_index]
+                        .name
+                        .as_deref()
+                        .unwrap_or("primary body");
+                    ui.label(format!("Orbiting {primary_name}"));
+
+                    egui::Grid::new("orbital_grid")
+                        .num_columns(2)
+                        .spacing([10.0, 4.0])
+                        .show(ui, |ui| {
+                            ui.label("Semi-major axis");
+                            ui.label(format!("{:.2}", elements.semi_major_axis));
+                            ui.end_row();
+
+                            ui.label("Eccentricity");
+                            ui.label(format!("{:.3}", elements.eccentricity));
+                            ui.end_row();
+
+                            ui.label("Period");
+                            ui.label(match elements.period {
+                                Some(t) => format!("{t:.1}"),
+                                None => "unbound".to_string(),
+                            });
+                            ui.end_row();
+                        });
+                }
+                None => {
+                    ui.label("No other body to orbit");
+                }
+            }
+
+            ui.separator();
+            let mut follow = following;
+            if ui.checkbox(&mut follow, "Follow").changed() {
+                action = SelectionAction::ToggleFollow;
+            }
+            if ui.button("Deselect").clicked() {
+                action = SelectionAction::Deselect;
+            }
+        });
+
+    action
+}
+
 struct EguiState {
     ctx: egui::Context,
     state: egui_winit::State,
@@ -48,23 +209,61 @@ struct App {
     paused: bool,
     show_grid: bool,
     show_trails: bool,
+    show_starfield: bool,
     mouse_pressed: bool,
+    /// Right- or middle-button drag pans `camera.target` instead of
+    /// orbiting it, since left-drag is already spoken for
+    pan_pressed: bool,
     last_mouse_pos: Option<(f64, f64)>,
+    cursor_pos: (f64, f64),
+    /// Accumulated scroll input not yet applied to `camera.distance`,
+    /// decayed each frame in `update` so a wheel flick decelerates over a
+    /// few frames instead of snapping straight to the new zoom
+    zoom_velocity: f32,
+    /// Cursor position at the start of the current left-button press, used
+    /// to tell a click (select) apart from a drag (orbit/look)
+    mouse_down_pos: Option<(f64, f64)>,
     current_preset: u8,
+    scene_scripts: Vec<ScenePreset>,
+    selected_script: Option<usize>,
+    camera_mode: CameraMode,
+    fly_keys: FlyKeys,
+    /// Index into `simulation.bodies` of the body picked via mouse click
+    selected_body: Option<usize>,
+    /// When set, `camera.target` is locked to the selected body each frame
+    follow_selected: bool,
+    show_iso_surface: bool,
+    /// Φ threshold the iso-surface is extracted at; more negative values
+    /// hug closer to individual bodies, less negative values trace a wider
+    /// combined well
+    iso_level: f32,
+    /// Leftover real time not yet consumed by a `PHYSICS_DT` step
+    accumulator: f32,
+    /// `accumulator / PHYSICS_DT`, for a renderer that wants to interpolate
+    /// body positions between fixed steps instead of snapping to the latest
+    interpolation_alpha: f32,
+    /// Result of the last F5/F9 snapshot save or load, shown in the status
+    /// bar until the next attempt replaces it
+    snapshot_status: Option<String>,
     egui: EguiState,
 }
 
+/// Where `F5`/`F9` and the status-bar Save/Load buttons read and write the
+/// snapshot file
+const SNAPSHOT_PATH: &str = "gravity_sim/snapshot.ron";
+
 impl App {
     fn new(ctx: GraphicsContext) -> Self {
-        let renderer = Renderer3D::new(&ctx, MAX_PARTICLES);
+        let renderer = Renderer3D::new(&ctx, MAX_PARTICLES, renderer_3d::DEFAULT_SAMPLE_COUNT);
         let mut camera = Camera3D::new(ctx.aspect_ratio());
         camera.distance = 40.0;
-        camera.pitch = 0.4;
-        camera.update_orbital();
+        camera.set_pitch(0.4);
 
         let mut simulation = Simulation3D::new();
         simulation.init_solar_system();
 
+        let scene_scripts = discover_scenes(&PathBuf::from("gravity_sim/scenes"));
+
         let egui_ctx = egui::Context::default();
         let egui_state = egui_winit::State::new(
             egui_ctx.clone(),
@@ -88,9 +287,25 @@ impl App {
             paused: false,
             show_grid: true,
             show_trails: true,
+            show_starfield: true,
             mouse_pressed: false,
+            pan_pressed: false,
             last_mouse_pos: None,
+            cursor_pos: (0.0, 0.0),
+            zoom_velocity: 0.0,
+            mouse_down_pos: None,
             current_preset: 1,
+            scene_scripts,
+            selected_script: None,
+            camera_mode: CameraMode::Orbital,
+            fly_keys: FlyKeys::default(),
+            selected_body: None,
+            follow_selected: false,
+            show_iso_surface: false,
+            iso_level: -50.0,
+            accumulator: 0.0,
+            interpolation_alpha: 0.0,
+            snapshot_status: None,
             egui: EguiState {
                 ctx: egui_ctx,
                 state: egui_state,
@@ -103,19 +318,115 @@ impl App {
         self.ctx.resize(new_size);
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
         self.renderer
-            .resize(&self.ctx.device, new_size.width, new_size.height);
+            .resize(&self.ctx, new_size.width, new_size.height);
+    }
+
+    /// Capture the live simulation and camera to [`SNAPSHOT_PATH`]
+    fn save_snapshot(&mut self) {
+        let snapshot = snapshot::Snapshot::capture(&self.simulation, &self.camera);
+        self.snapshot_status = Some(match snapshot.save_to_file(SNAPSHOT_PATH) {
+            Ok(()) => format!("Saved snapshot to {SNAPSHOT_PATH}"),
+            Err(err) => format!("Snapshot save failed: {err}"),
+        });
+    }
+
+    /// Replace the live simulation and camera with the contents of
+    /// [`SNAPSHOT_PATH`]
+    fn load_snapshot(&mut self) {
+        match snapshot::Snapshot::load_from_file(SNAPSHOT_PATH) {
+            Ok(snapshot) => {
+                self.simulation = snapshot.to_simulation();
+                snapshot.apply_camera(&mut self.camera);
+                self.selected_body = None;
+                self.follow_selected = false;
+                self.snapshot_status = Some(format!("Loaded snapshot from {SNAPSHOT_PATH}"));
+            }
+            Err(err) => self.snapshot_status = Some(format!("Snapshot load failed: {err}")),
+        }
     }
 
     fn update(&mut self, dt: f32) {
-        if !self.paused {
-            let substeps = 4;
-            let sub_dt = dt / substeps as f32;
-            for _ in 0..substeps {
-                self.simulation.step(sub_dt);
+        // Ease the accumulated scroll input into the camera distance and
+        // decay it, so a wheel flick glides to a stop over a few frames
+        // instead of snapping straight to the new zoom
+        if self.zoom_velocity.abs() > 1e-4 {
+            self.camera.zoom(self.zoom_velocity * dt * 10.0);
+            self.zoom_velocity *= (-8.0 * dt).exp();
+        } else {
+            self.zoom_velocity = 0.0;
+        }
+
+        if self.camera_mode == CameraMode::FreeFly {
+            let mut local = Vec3::ZERO;
+            if self.fly_keys.forward {
+                local.z += 1.0;
+            }
+            if self.fly_keys.backward {
+                local.z -= 1.0;
+            }
+            if self.fly_keys.right {
+                local.x += 1.0;
+            }
+            if self.fly_keys.left {
+                local.x -= 1.0;
+            }
+            if self.fly_keys.up {
+                local.y += 1.0;
+            }
+            if self.fly_keys.down {
+                local.y -= 1.0;
+            }
+            if local != Vec3::ZERO {
+                self.camera.fly_move(local.normalize() * FLY_SPEED, dt);
+            }
+        }
+
+        if self.paused {
+            return;
+        }
+
+        // Clamp the incoming frame time, not the accumulator: a single slow
+        // frame (e.g. a window resize) shouldn't force a burst of catch-up
+        // steps afterward.
+        self.accumulator += dt.min(0.25);
+
+        while self.accumulator >= PHYSICS_DT {
+            self.simulation.step(PHYSICS_DT);
+            self.accumulator -= PHYSICS_DT;
+        }
+
+        self.interpolation_alpha = self.accumulator / PHYSICS_DT;
+
+        if self.follow_selected {
+            match self.selected_body.and_then(|i| self.simulation.bodies.get(i)) {
+                Some(body) => {
+                    self.camera.target = body.position;
+                    if self.camera_mode == CameraMode::Orbital {
+                        self.camera.update_orbital();
+                    }
+                }
+                None => self.follow_selected = false,
             }
         }
     }
 
+    /// Cast a ray from the camera through `cursor` and select the nearest
+    /// body whose billboard sphere it intersects, if any
+    fn pick_body(&mut self, cursor: (f64, f64)) {
+        let (origin, dir) = self.camera.screen_ray(
+            cursor.0 as f32,
+            cursor.1 as f32,
+            self.ctx.size.width as f32,
+            self.ctx.size.height as f32,
+        );
+
+        self.selected_body = self.simulation.pick(origin, dir);
+
+        if self.selected_body.is_none() {
+            self.follow_selected = false;
+        }
+    }
+
     fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
         let output = self.ctx.surface.get_current_texture()?;
         let view = output
@@ -123,10 +434,31 @@ impl App {
             .create_view(&wgpu::TextureViewDescriptor::default());
 
         self.renderer.update_camera(&self.ctx.queue, &self.camera);
-        let (num_instances, trail_ranges) =
-            self.renderer.update_simulation(&self.ctx.queue, &self.simulation);
+        self.renderer.update_light(&self.ctx.queue, &self.simulation);
+        self.renderer.update_point_lights(&self.ctx.queue, &self.simulation);
+        let (num_instances, trail_ranges, mesh_draws) = self.renderer.update_simulation(
+            &self.ctx.queue,
+            &self.simulation,
+            self.selected_body,
+        );
+
+        let num_iso_vertices = if self.show_iso_surface {
+            self.simulation
+                .sample_potential_field(ISO_RESOLUTION, ISO_PADDING)
+                .map(|field| marching_cubes::extract_surface(&field, self.iso_level))
+                .map(|triangles| self.renderer.update_iso_surface(&self.ctx.queue, &triangles))
+                .unwrap_or(0)
+        } else {
+            0
+        };
 
-        // Build egui UI
+        // Build egui UI. The scene picker can only request a load here (the
+        // closure below borrows `self` immutably); the actual reload happens
+        // after `ctx.run` returns so it can borrow `self` mutably.
+        let mut scene_to_load: Option<usize> = None;
+        let mut selection_action = SelectionAction::None;
+        let mut save_requested = false;
+        let mut load_requested = false;
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
         let full_output = self.egui.ctx.run(raw_input, |ctx| {
             draw_equations_sidebar(
@@ -136,6 +468,11 @@ impl App {
                 GRAVITY_3D_VARIABLES,
             );
 
+            if let Some(index) = self.selected_body {
+                selection_action =
+                    draw_selection_panel(ctx, &self.simulation, index, self.follow_selected);
+            }
+
             egui::TopBottomPanel::top("status").show(ctx, |ui| {
                 ui.horizontal(|ui| {
                     ui.label(format!("Bodies: {}", self.simulation.bodies.len()));
@@ -150,6 +487,8 @@ impl App {
                     ui.separator();
                     ui.label(format!("Time: {:.1}x", self.simulation.time_scale));
                     ui.separator();
+                    ui.label(format!("Step α: {:.2}", self.interpolation_alpha));
+                    ui.separator();
                     if self.paused {
                         ui.label(egui::RichText::new("PAUSED").color(egui::Color32::YELLOW));
                     } else {
@@ -159,10 +498,90 @@ impl App {
                         ui.separator();
                         ui.label("Trails ON");
                     }
+                    if self.show_starfield {
+                        ui.separator();
+                        ui.label("Starfield ON");
+                    }
+                    ui.separator();
+                    ui.label(match self.camera_mode {
+                        CameraMode::Orbital => "Camera: Orbital",
+                        CameraMode::FreeFly => "Camera: Free-fly",
+                    });
+
+                    ui.separator();
+                    ui.checkbox(&mut self.simulation.use_barnes_hut, "Barnes-Hut");
+                    if self.simulation.use_barnes_hut {
+                        ui.add(
+                            egui::Slider::new(&mut self.simulation.theta, 0.1..=1.5)
+                                .text("θ")
+                                .fixed_decimals(2),
+                        );
+                    }
+
+                    ui.separator();
+                    ui.checkbox(&mut self.show_iso_surface, "Potential iso-surface");
+                    if self.show_iso_surface {
+                        ui.add(egui::Slider::new(&mut self.iso_level, -500.0..=-1.0).text("Φ level"));
+                    }
+
+                    ui.separator();
+                    if ui.button("Save (F5)").clicked() {
+                        save_requested = true;
+                    }
+                    if ui.button("Load (F9)").clicked() {
+                        load_requested = true;
+                    }
+                    if let Some(status) = &self.snapshot_status {
+                        ui.separator();
+                        ui.label(status);
+                    }
+
+                    if !self.scene_scripts.is_empty() {
+                        ui.separator();
+                        let current_label = self
+                            .selected_script
+                            .and_then(|i| self.scene_scripts.get(i))
+                            .map(|preset| preset.name.as_str())
+                            .unwrap_or("(built-in preset)");
+
+                        egui::ComboBox::from_label("Scene script")
+                            .selected_text(current_label)
+                            .show_ui(ui, |ui| {
+                                for i in 0..self.scene_scripts.len() {
+                                    let name = self.scene_scripts[i].name.clone();
+                                    if ui
+                                        .selectable_label(self.selected_script == Some(i), name)
+                                        .clicked()
+                                    {
+                                        scene_to_load = Some(i);
+                                    }
+                                }
+                            });
+                    }
                 });
             });
         });
 
+        if let Some(index) = scene_to_load {
+            self.load_scene_script(index);
+        }
+
+        if save_requested {
+            self.save_snapshot();
+        }
+        if load_requested {
+            self.load_snapshot();
+        }
+
+        match selection_action {
+            SelectionAction::None => {}
+            SelectionAction::ToggleFollow => self.follow_selected = !self.follow_selected,
+            SelectionAction::Deselect => {
+                self.selected_body = None;
+                self.follow_selected = false;
+            }
+        }
+
         self.egui.state.handle_platform_output(&self.ctx.window, full_output.platform_output);
         let tris = self.egui.ctx.tessellate(full_output.shapes, full_output.pixels_per_point);
         for (id, image_delta) in &full_output.textures_delta.set {
@@ -183,11 +602,14 @@ impl App {
 
         self.renderer.render(
             &mut encoder,
+            &self.ctx.queue,
             &view,
             num_instances,
             &trail_ranges,
             self.show_grid,
             self.show_trails,
+            num_iso_vertices,
+            &mesh_draws,
         );
 
         self.egui.renderer.update_buffers(
@@ -225,60 +647,182 @@ impl App {
         Ok(())
     }
 
-    fn handle_key(&mut self, key: KeyCode, state: ElementState) {
-        if state != ElementState::Pressed {
+    /// Reinitialize `self.simulation` by re-running the chosen `.rhai`
+    /// scene script's `init()`, applying its `config()` toggles to the
+    /// camera and rendering flags
+    fn load_scene_script(&mut self, index: usize) {
+        let Some(preset) = self.scene_scripts.get(index) else {
+            return;
+        };
+
+        let config = preset.config();
+        self.simulation.bodies.clear();
+        if let Err(err) = preset.init(&mut self.simulation) {
+            log::warn!("failed to run scene script: {err}");
             return;
         }
 
+        self.show_grid = config.show_grid;
+        self.show_trails = config.show_trails;
+        self.show_starfield = config.show_starfield;
+        self.camera.distance = config.camera_distance;
+        self.camera.set_pitch(config.camera_pitch);
+
+        self.current_preset = 0; // 0 = custom/scripted scene
+        self.selected_script = Some(index);
+        self.selected_body = None;
+        self.follow_selected = false;
+    }
+
+    fn handle_key(&mut self, key: KeyCode, state: ElementState) {
+        let pressed = state == ElementState::Pressed;
+
         match key {
-            KeyCode::Space => self.paused = !self.paused,
-            KeyCode::KeyG => self.show_grid = !self.show_grid,
-            KeyCode::KeyT => self.show_trails = !self.show_trails,
-            KeyCode::KeyR => {
-                self.camera.distance = 40.0;
-                self.camera.pitch = 0.4;
-                self.camera.yaw = 0.0;
-                self.camera.target = glam::Vec3::ZERO;
-                self.camera.update_orbital();
-            }
-            KeyCode::Digit1 => {
-                self.current_preset = 1;
-                self.simulation.init_solar_system();
-                self.camera.distance = 40.0;
-            }
-            KeyCode::Digit2 => {
-                self.current_preset = 2;
-                self.simulation.init_accretion_disk(500);
-                self.camera.distance = 50.0;
-            }
-            KeyCode::Digit3 => {
-                self.current_preset = 3;
-                self.simulation.init_galaxy_collision(300);
-                self.camera.distance = 80.0;
-            }
-            KeyCode::Equal | KeyCode::NumpadAdd => {
-                self.simulation.time_scale *= 1.5;
-            }
-            KeyCode::Minus | KeyCode::NumpadSubtract => {
-                self.simulation.time_scale /= 1.5;
-            }
+            // Continuous free-fly movement: tracked on both press and
+            // release so held keys keep moving the camera across frames
+            KeyCode::KeyW => self.fly_keys.forward = pressed,
+            KeyCode::KeyS => self.fly_keys.backward = pressed,
+            KeyCode::KeyA => self.fly_keys.left = pressed,
+            KeyCode::KeyD => self.fly_keys.right = pressed,
+            KeyCode::KeyE => self.fly_keys.up = pressed,
+            KeyCode::KeyQ => self.fly_keys.down = pressed,
+
+            _ if pressed => match key {
+                KeyCode::Space => self.paused = !self.paused,
+                KeyCode::KeyG => self.show_grid = !self.show_grid,
+                KeyCode::KeyT => self.show_trails = !self.show_trails,
+                KeyCode::KeyI => self.show_iso_surface = !self.show_iso_surface,
+                KeyCode::KeyF => {
+                    self.camera_mode = match self.camera_mode {
+                        CameraMode::Orbital => CameraMode::FreeFly,
+                        CameraMode::FreeFly => CameraMode::Orbital,
+                    };
+                }
+                KeyCode::KeyR => {
+                    self.camera.distance = 40.0;
+                    self.camera.target = Vec3::ZERO;
+                    self.camera.set_yaw_pitch(0.0, 0.4);
+                    self.camera_mode = CameraMode::Orbital;
+                    self.follow_selected = false;
+                }
+                KeyCode::Digit1 => {
+                    self.current_preset = 1;
+                    self.simulation.init_solar_system();
+                    self.camera.distance = 40.0;
+                    self.selected_body = None;
+                    self.follow_selected = false;
+                }
+                KeyCode::Digit2 => {
+                    self.current_preset = 2;
+                    self.simulation.init_accretion_disk(500);
+                    self.camera.distance = 50.0;
+                    self.selected_body = None;
+                    self.follow_selected = false;
+                }
+                KeyCode::Digit3 => {
+                    self.current_preset = 3;
+                    self.simulation.init_galaxy_collision(300);
+                    self.camera.distance = 80.0;
+                    self.selected_body = None;
+                    self.follow_selected = false;
+                }
+                KeyCode::Equal | KeyCode::NumpadAdd => {
+                    self.simulation.time_scale *= 1.5;
+                }
+                KeyCode::Minus | KeyCode::NumpadSubtract => {
+                    self.simulation.time_scale /= 1.5;
+                }
+                KeyCode::F5 => self.save_snapshot(),
+                KeyCode::F9 => self.load_snapshot(),
+                _ => {}
+            },
             _ => {}
         }
     }
 
     fn handle_mouse_move(&mut self, x: f64, y: f64) {
+        self.cursor_pos = (x, y);
+
         if self.mouse_pressed {
             if let Some((last_x, last_y)) = self.last_mouse_pos {
-                let dx = (x - last_x) as f32 * 0.01;
-                let dy = (y - last_y) as f32 * 0.01;
-                self.camera.orbit(dx, dy);
+                match self.camera_mode {
+                    CameraMode::Orbital => {
+                        let from = self.cursor_to_ndc(last_x, last_y);
+                        let to = self.cursor_to_ndc(x, y);
+                        self.camera.arcball_drag(from, to);
+                    }
+                    CameraMode::FreeFly => {
+                        let dx = (x - last_x) as f32 * 0.01;
+                        let dy = (y - last_y) as f32 * 0.01;
+                        self.camera.fly_rotate(dx, -dy);
+                    }
+                }
+            }
+            self.last_mouse_pos = Some((x, y));
+        } else if self.pan_pressed {
+            if let Some((last_x, last_y)) = self.last_mouse_pos {
+                let dx = (x - last_x) as f32;
+                let dy = (y - last_y) as f32;
+                self.pan_camera(dx, dy);
             }
             self.last_mouse_pos = Some((x, y));
         }
     }
 
+    /// Translate `camera.target` (and, via `update_orbital`, `position`) by
+    /// a screen-space pixel delta, scaled by the current orbit distance so a
+    /// given drag feels the same regardless of zoom level
+    fn pan_camera(&mut self, dx: f32, dy: f32) {
+        let right = self.camera.orientation_right();
+        let up = self.camera.orientation_up();
+        let scale = self.camera.distance * 0.0015;
+        self.camera.target += right * (-dx * scale) + up * (dy * scale);
+        self.camera.update_orbital();
+    }
+
+    /// Map a cursor position in physical pixels to normalized device
+    /// coordinates (`[-1, 1]`, Y up) for `Camera3D::arcball_drag`
+    fn cursor_to_ndc(&self, x: f64, y: f64) -> Vec2 {
+        let width = self.ctx.size.width as f32;
+        let height = self.ctx.size.height as f32;
+        Vec2::new(
+            (x as f32 / width) * 2.0 - 1.0,
+            1.0 - (y as f32 / height) * 2.0,
+        )
+    }
+
+    /// Left mouse button pressed or released: begins/ends an orbit drag, and
+    /// a press-release with negligible movement in between is treated as a
+    /// click that picks the body under the cursor
+    fn handle_mouse_button(&mut self, pressed: bool) {
+        self.mouse_pressed = pressed;
+
+        if pressed {
+            self.mouse_down_pos = Some(self.cursor_pos);
+            return;
+        }
+
+        self.last_mouse_pos = None;
+        if let Some((down_x, down_y)) = self.mouse_down_pos.take() {
+            let (x, y) = self.cursor_pos;
+            let dist = ((x - down_x).powi(2) + (y - down_y).powi(2)).sqrt() as f32;
+            if dist <= CLICK_DRAG_THRESHOLD {
+                self.pick_body(self.cursor_pos);
+            }
+        }
+    }
+
+    /// Right or middle mouse button pressed or released: begins/ends a pan
+    /// drag (`pan_camera`), independent of the left-button orbit/pick state
+    fn handle_pan_button(&mut self, pressed: bool) {
+        self.pan_pressed = pressed;
+        if !pressed {
+            self.last_mouse_pos = None;
+        }
+    }
+
     fn handle_scroll(&mut self, delta: f32) {
-        self.camera.zoom(delta * 3.0);
+        self.zoom_velocity += delta * 3.0;
     }
 
     fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
@@ -308,14 +852,15 @@ fn main() {
                         match event {
                             WindowEvent::CloseRequested => elwt.exit(),
                             WindowEvent::Resized(size) => app.resize(*size),
-                            WindowEvent::MouseInput { state, button, .. } => {
-                                if *button == MouseButton::Left {
-                                    app.mouse_pressed = *state == ElementState::Pressed;
-                                    if !app.mouse_pressed {
-                                        app.last_mouse_pos = None;
-                                    }
+                            WindowEvent::MouseInput { state, button, .. } => match button {
+                                MouseButton::Left => {
+                                    app.handle_mouse_button(*state == ElementState::Pressed);
                                 }
-                            }
+                                MouseButton::Right | MouseButton::Middle => {
+                                    app.handle_pan_button(*state == ElementState::Pressed);
+                                }
+                                _ => {}
+                            },
                             WindowEvent::CursorMoved { position, .. } => {
                                 app.handle_mouse_move(position.x, position.y);
                             }
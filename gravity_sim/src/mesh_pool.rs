@@ -0,0 +1,70 @@
+//! OBJ mesh loading for body rendering
+//!
+//! Parses `.obj` files via `tobj` into the same flattened position+normal
+//! vertex layout `Renderer3D`'s iso-surface pipeline already uses, merging
+//! every sub-mesh/group in the file into one combined vertex/index buffer
+//! so a model loads as a single indexed draw.
+
+use std::path::Path;
+
+use crate::renderer_3d::MeshVertex;
+
+/// Errors that can occur while loading an `.obj` model
+#[derive(Debug, thiserror::Error)]
+pub enum MeshLoadError {
+    #[error("failed to parse OBJ file: {0}")]
+    Parse(#[from] tobj::LoadError),
+}
+
+/// A parsed mesh's vertex/index data, ready to upload as GPU buffers
+pub struct LoadedMesh {
+    pub vertices: Vec<MeshVertex>,
+    pub indices: Vec<u32>,
+}
+
+/// Load and triangulate an `.obj` file, combining every model it contains
+/// into one vertex/index buffer pair. Per-vertex normals come from the file
+/// when present; a mesh with none gets the same straight-up (+Y) normal on
+/// every vertex.
+pub fn load_obj(path: &Path) -> Result<LoadedMesh, MeshLoadError> {
+    let (models, _materials) = tobj::load_obj(
+        path,
+        &tobj::LoadOptions {
+            triangulate: true,
+            single_index: true,
+            ..Default::default()
+        },
+    )?;
+
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for model in models {
+        let mesh = &model.mesh;
+        let base = vertices.len() as u32;
+        let vertex_count = mesh.positions.len() / 3;
+        let has_normals = mesh.normals.len() == mesh.positions.len();
+
+        for i in 0..vertex_count {
+            let position = [
+                mesh.positions[i * 3],
+                mesh.positions[i * 3 + 1],
+                mesh.positions[i * 3 + 2],
+            ];
+            let normal = if has_normals {
+                [
+                    mesh.normals[i * 3],
+                    mesh.normals[i * 3 + 1],
+                    mesh.normals[i * 3 + 2],
+                ]
+            } else {
+                [0.0, 1.0, 0.0]
+            };
+            vertices.push(MeshVertex { position, normal });
+        }
+
+        indices.extend(mesh.indices.iter().map(|&index| base + index));
+    }
+
+    Ok(LoadedMesh { vertices, indices })
+}
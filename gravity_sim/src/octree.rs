@@ -0,0 +1,195 @@
+//! Barnes-Hut octree approximation for N-body gravity
+//!
+//! Building an octree over the current body positions and using it to
+//! approximate far-field forces brings a step from O(N²) down to roughly
+//! O(N log N): a node whose width-to-distance ratio `s/d` is below the
+//! opening angle `theta` is treated as a single pseudo-particle at its
+//! aggregate center of mass, instead of visiting every body it contains.
+
+use glam::Vec3;
+
+use crate::physics_3d::{Body3D, G};
+
+/// Past this recursion depth, bodies are assumed to be effectively
+/// coincident (or pathologically close) and are merged into one
+/// pseudo-particle instead of subdividing forever
+const MAX_DEPTH: u32 = 32;
+
+struct OctreeNode {
+    center: Vec3,
+    half_width: f32,
+    mass: f32,
+    com: Vec3,
+    /// Index into the body list, set only on an un-subdivided leaf
+    body: Option<usize>,
+    children: Option<Box<[OctreeNode; 8]>>,
+}
+
+impl OctreeNode {
+    fn new_leaf(center: Vec3, half_width: f32) -> Self {
+        Self {
+            center,
+            half_width,
+            mass: 0.0,
+            com: Vec3::ZERO,
+            body: None,
+            children: None,
+        }
+    }
+
+    fn octant_of(&self, pos: Vec3) -> usize {
+        let mut index = 0;
+        if pos.x >= self.center.x {
+            index |= 1;
+        }
+        if pos.y >= self.center.y {
+            index |= 2;
+        }
+        if pos.z >= self.center.z {
+            index |= 4;
+        }
+        index
+    }
+
+    fn child_center(&self, octant: usize) -> Vec3 {
+        let offset = self.half_width * 0.5;
+        Vec3::new(
+            self.center.x + if octant & 1 != 0 { offset } else { -offset },
+            self.center.y + if octant & 2 != 0 { offset } else { -offset },
+            self.center.z + if octant & 4 != 0 { offset } else { -offset },
+        )
+    }
+
+    fn subdivide(&mut self) {
+        let half = self.half_width * 0.5;
+        let children = std::array::from_fn(|i| OctreeNode::new_leaf(self.child_center(i), half));
+        self.children = Some(Box::new(children));
+    }
+
+    fn insert(&mut self, index: usize, bodies: &[Body3D], depth: u32) {
+        let position = bodies[index].position;
+        let mass = bodies[index].mass;
+
+        let new_mass = self.mass + mass;
+        self.com = (self.com * self.mass + position * mass) / new_mass;
+        self.mass = new_mass;
+
+        match (self.body, self.children.is_some()) {
+            (None, false) => self.body = Some(index),
+            (Some(existing), false) => {
+                if depth >= MAX_DEPTH {
+                    // Too deep to keep splitting (bodies essentially on top
+                    // of each other) — leave them merged into this node's
+                    // aggregate mass/COM rather than recursing forever.
+                    return;
+                }
+                self.body = None;
+                self.subdivide();
+                self.insert_into_child(existing, bodies, depth + 1);
+                self.insert_into_child(index, bodies, depth + 1);
+            }
+            (None, true) => self.insert_into_child(index, bodies, depth + 1),
+            (Some(_), true) => unreachable!("a node is either a leaf or internal, never both"),
+        }
+    }
+
+    fn insert_into_child(&mut self, index: usize, bodies: &[Body3D], depth: u32) {
+        let octant = self.octant_of(bodies[index].position);
+        if let Some(children) = &mut self.children {
+            children[octant].insert(index, bodies, depth);
+        }
+    }
+
+    fn accumulate_acceleration(
+        &self,
+        index: usize,
+        bodies: &[Body3D],
+        theta: f32,
+        softening: f32,
+        accel: &mut Vec3,
+    ) {
+        if self.mass <= 0.0 {
+            return;
+        }
+
+        match (self.body, &self.children) {
+            (Some(leaf_index), None) => {
+                if leaf_index != index {
+                    *accel += point_mass_acceleration(bodies[index].position, self.com, self.mass, softening);
+                }
+            }
+            (None, Some(children)) => {
+                let offset = self.com - bodies[index].position;
+                let distance = offset.length();
+                let width = self.half_width * 2.0;
+
+                if distance > 0.0 && width / distance < theta {
+                    *accel += point_mass_acceleration(bodies[index].position, self.com, self.mass, softening);
+                } else {
+                    for child in children.iter() {
+                        child.accumulate_acceleration(index, bodies, theta, softening, accel);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Newtonian acceleration a point mass `mass` at `source` exerts on a body
+/// at `at`, with a softened denominator to avoid a singularity at `source`
+fn point_mass_acceleration(at: Vec3, source: Vec3, mass: f32, softening: f32) -> Vec3 {
+    let r = source - at;
+    let dist_sq = r.length_squared() + softening * softening;
+    let dist = dist_sq.sqrt();
+    if dist < 1e-6 {
+        return Vec3::ZERO;
+    }
+    r / dist * (G * mass / dist_sq)
+}
+
+fn bounding_cube(bodies: &[Body3D]) -> (Vec3, f32) {
+    let mut min = bodies[0].position;
+    let mut max = bodies[0].position;
+    for body in &bodies[1..] {
+        min = min.min(body.position);
+        max = max.max(body.position);
+    }
+
+    let center = (min + max) * 0.5;
+    // Pad past the tightest bounding extent so bodies sitting exactly on the
+    // boundary still fall strictly inside the root cube.
+    let half_width = (max - min).max_element().max(1.0) * 0.5 + 1.0;
+    (center, half_width)
+}
+
+/// A Barnes-Hut octree built from a snapshot of body positions, used to
+/// approximate the gravitational force on each body in roughly O(N log N)
+/// instead of the direct O(N²) all-pairs sum
+pub struct Octree {
+    root: OctreeNode,
+}
+
+impl Octree {
+    pub fn build(bodies: &[Body3D]) -> Option<Self> {
+        if bodies.is_empty() {
+            return None;
+        }
+
+        let (center, half_width) = bounding_cube(bodies);
+        let mut root = OctreeNode::new_leaf(center, half_width);
+        for i in 0..bodies.len() {
+            root.insert(i, bodies, 0);
+        }
+
+        Some(Self { root })
+    }
+
+    /// Approximate gravitational acceleration on body `index`, opening any
+    /// node whose width-to-distance ratio is `>= theta`
+    pub fn acceleration_on(&self, index: usize, bodies: &[Body3D], theta: f32, softening: f32) -> Vec3 {
+        let mut accel = Vec3::ZERO;
+        self.root.accumulate_acceleration(index, bodies, theta, softening, &mut accel);
+        accel
+    }
+}
@@ -3,6 +3,8 @@
 use glam::Vec2;
 use rand::Rng;
 
+use crate::quadtree::Quadtree;
+
 /// Gravitational constant (scaled for visualization)
 pub const G: f32 = 100.0;
 
@@ -45,11 +47,107 @@ impl Body {
     }
 }
 
+/// Parameters for sampling a disk of bodies around a central mass. Used by
+/// [`Simulation::init_disk`]/[`Simulation::init_galaxy_collision`] and
+/// exposed to scripted presets (see `scene_scripts_2d::EmitDisk`) so a scene
+/// can dial in the shape of the initial cloud instead of only a count.
+#[derive(Debug, Clone, Copy)]
+pub struct EmitterConfig {
+    pub count: usize,
+    pub central_mass: f32,
+    pub inner_radius: f32,
+    pub outer_radius: f32,
+    /// Exponent for the radius sampling: `1.0` samples uniformly in radius
+    /// (front-loads the center), `2.0` samples uniformly in *area*, giving a
+    /// flatter, more realistic disk profile
+    pub radius_power: f32,
+    pub mass_range: (f32, f32),
+    /// Fraction of the equilibrium orbital speed to randomly scatter each
+    /// body's velocity by, e.g. `0.1` varies speed +/-10% around circular
+    pub velocity_scatter: f32,
+}
+
+impl Default for EmitterConfig {
+    fn default() -> Self {
+        Self {
+            count: 500,
+            central_mass: 50000.0,
+            inner_radius: 1.5,
+            outer_radius: 9.5,
+            radius_power: 2.0,
+            mass_range: (10.0, 60.0),
+            velocity_scatter: 0.1,
+        }
+    }
+}
+
+/// Sample `config.count` bodies in an annulus around `center`, deriving each
+/// one's tangential velocity from the mass enclosed within its orbit
+/// (`config.central_mass` plus every other sampled body at a smaller
+/// radius) so the disk starts close to dynamical equilibrium instead of
+/// collapsing inward or flying apart on the first few steps.
+pub fn sample_disk(config: &EmitterConfig, center: Vec2, center_velocity: Vec2) -> Vec<Body> {
+    let mut rng = rand::thread_rng();
+
+    // angle/radius/mass sampled independently, before any velocity is derived
+    let mut samples: Vec<(f32, f32, f32)> = (0..config.count)
+        .map(|_| {
+            let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+            let u: f32 = rng.gen();
+            let span = config.outer_radius - config.inner_radius;
+            let radius = config.inner_radius + span * u.powf(1.0 / config.radius_power);
+            let mass = rng.gen_range(config.mass_range.0..config.mass_range.1);
+            (angle, radius, mass)
+        })
+        .collect();
+
+    // Sorting by radius lets enclosed mass accumulate in a single pass
+    samples.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    let mut enclosed_mass = config.central_mass;
+    let mut bodies = Vec::with_capacity(config.count);
+    for (angle, radius, mass) in samples {
+        let orbital_speed = (G * enclosed_mass / radius).sqrt();
+        let scatter = 1.0 + config.velocity_scatter * (rng.gen::<f32>() * 2.0 - 1.0);
+        let position = center + Vec2::new(angle.cos(), angle.sin()) * radius;
+        let velocity =
+            center_velocity + Vec2::new(-angle.sin(), angle.cos()) * orbital_speed * scatter;
+        bodies.push(Body::new(position, velocity, mass));
+        enclosed_mass += mass;
+    }
+
+    bodies
+}
+
+/// Which scheme [`Simulation::step`] uses to advance positions and
+/// velocities. Semi-implicit Euler (a single force evaluation per step)
+/// drains or pumps orbital energy over long runs; `VelocityVerlet` (still
+/// one force evaluation per step, reusing the previous step's
+/// accelerations) and `Rk4` (three extra force evaluations per step) give
+/// much better long-term energy conservation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Integrator {
+    Euler,
+    VelocityVerlet,
+    Rk4,
+}
+
 /// The physics simulation state
 pub struct Simulation {
     pub bodies: Vec<Body>,
     pub time_scale: f32,
     pub softening: f32, // Prevents singularities at close distances
+    /// Barnes-Hut opening angle θ: a node of the per-step [`Quadtree`] is
+    /// treated as a single pseudo-particle once its width-to-distance ratio
+    /// drops below this. `0.0` disables the approximation entirely and
+    /// falls back to the exact O(n²) direct sum, so results can be
+    /// validated against it.
+    pub theta: f32,
+    pub integrator: Integrator,
+    /// Accelerations from the previous `VelocityVerlet` step, reused as
+    /// `a_old` in `v += 0.5*(a_old + a_new)*dt` so the scheme only pays for
+    /// one new force evaluation per step instead of two
+    last_accelerations: Vec<Vec2>,
 }
 
 impl Simulation {
@@ -58,6 +156,9 @@ impl Simulation {
             bodies: Vec::new(),
             time_scale: 1.0,
             softening: 0.1,
+            theta: 0.5,
+            integrator: Integrator::VelocityVerlet,
+            last_accelerations: Vec::new(),
         }
     }
 
@@ -88,102 +189,186 @@ impl Simulation {
         }
     }
 
-    /// Initialize with random particles in a disk
+    /// Initialize with random particles in a disk, using the default
+    /// [`EmitterConfig`] with `count` substituted in
     pub fn init_disk(&mut self, count: usize) {
-        self.bodies.clear();
-
-        // Central mass
-        self.bodies.push(Body::new(
-            Vec2::ZERO,
-            Vec2::ZERO,
-            50000.0,
-        ));
-
-        let mut rng = rand::thread_rng();
-        for _ in 0..count {
-            let distance = 1.5 + rng.gen::<f32>() * 8.0;
-            let angle: f32 = rng.gen::<f32>() * std::f32::consts::TAU;
-            let position = Vec2::new(angle.cos() * distance, angle.sin() * distance);
-
-            // Orbital velocity with some randomness
-            let orbital_speed = (G * 50000.0 / distance).sqrt();
-            let speed_variation = 0.9 + rng.gen::<f32>() * 0.2;
-            let velocity = Vec2::new(
-                -angle.sin() * orbital_speed * speed_variation,
-                angle.cos() * orbital_speed * speed_variation,
-            );
+        self.init_disk_with(EmitterConfig {
+            count,
+            ..EmitterConfig::default()
+        });
+    }
 
-            let mass = 10.0 + rng.gen::<f32>() * 50.0;
-            self.bodies.push(Body::new(position, velocity, mass));
-        }
+    /// Initialize with a disk sampled from an explicit [`EmitterConfig`],
+    /// so scripted presets can dial central mass, inner/outer radius, and
+    /// particle count
+    pub fn init_disk_with(&mut self, config: EmitterConfig) {
+        self.bodies.clear();
+        self.bodies.push(Body::central(config.central_mass));
+        self.bodies
+            .extend(sample_disk(&config, Vec2::ZERO, Vec2::ZERO));
     }
 
-    /// Initialize with two colliding galaxies
+    /// Initialize with two colliding galaxies, each a disk sampled with the
+    /// default galaxy-collision [`EmitterConfig`]
     pub fn init_galaxy_collision(&mut self, particles_per_galaxy: usize) {
+        self.init_galaxy_collision_with(EmitterConfig {
+            count: particles_per_galaxy,
+            central_mass: 30000.0,
+            inner_radius: 0.5,
+            outer_radius: 4.5,
+            mass_range: (5.0, 20.0),
+            ..EmitterConfig::default()
+        });
+    }
+
+    /// Initialize two colliding galaxies, both disks sampled from `config`
+    /// (aside from their centers, approach velocities, and tint)
+    pub fn init_galaxy_collision_with(&mut self, config: EmitterConfig) {
         self.bodies.clear();
-        let mut rng = rand::thread_rng();
 
         for (center, center_vel, color_base) in [
             (Vec2::new(-5.0, 0.0), Vec2::new(0.5, 0.3), [0.3, 0.5, 1.0, 1.0]),
             (Vec2::new(5.0, 0.0), Vec2::new(-0.5, -0.3), [1.0, 0.5, 0.3, 1.0]),
         ] {
             // Central black hole
-            let mut central = Body::new(center, center_vel, 30000.0);
+            let mut central = Body::new(center, center_vel, config.central_mass);
             central.color = [1.0, 1.0, 0.8, 1.0];
             self.bodies.push(central);
 
             // Disk particles
-            for _ in 0..particles_per_galaxy {
-                let distance = 0.5 + rng.gen::<f32>() * 4.0;
-                let angle: f32 = rng.gen::<f32>() * std::f32::consts::TAU;
-                let position = center + Vec2::new(angle.cos() * distance, angle.sin() * distance);
-
-                let orbital_speed = (G * 30000.0 / distance).sqrt();
-                let velocity = center_vel + Vec2::new(
-                    -angle.sin() * orbital_speed,
-                    angle.cos() * orbital_speed,
-                );
-
-                let mass = 5.0 + rng.gen::<f32>() * 20.0;
-                let mut body = Body::new(position, velocity, mass);
+            for mut body in sample_disk(&config, center, center_vel) {
                 body.color = color_base;
                 self.bodies.push(body);
             }
         }
     }
 
-    /// Step the simulation forward by dt seconds
-    pub fn step(&mut self, dt: f32) {
-        let dt = dt * self.time_scale;
-        let n = self.bodies.len();
-
-        if n == 0 {
-            return;
-        }
-
-        // Calculate accelerations using leapfrog integration
+    /// Gravitational acceleration on every body for an arbitrary snapshot of
+    /// positions (masses come from `self.bodies`), used both for the normal
+    /// per-step force evaluation and for the perturbed intermediate states
+    /// that `VelocityVerlet` and `Rk4` evaluate forces at
+    fn accelerations_at(&self, positions: &[Vec2]) -> Vec<Vec2> {
+        let n = positions.len();
         let mut accelerations = vec![Vec2::ZERO; n];
 
-        for i in 0..n {
-            for j in (i + 1)..n {
-                let r = self.bodies[j].position - self.bodies[i].position;
-                let dist_sq = r.length_squared() + self.softening * self.softening;
-                let dist = dist_sq.sqrt();
-                let force_mag = G / dist_sq;
-                let force_dir = r / dist;
-
-                accelerations[i] += force_dir * force_mag * self.bodies[j].mass;
-                accelerations[j] -= force_dir * force_mag * self.bodies[i].mass;
+        if self.theta > 0.0 {
+            let probe_bodies: Vec<Body> = self
+                .bodies
+                .iter()
+                .zip(positions)
+                .map(|(body, &position)| Body { position, ..*body })
+                .collect();
+            if let Some(tree) = Quadtree::build(&probe_bodies) {
+                for i in 0..n {
+                    accelerations[i] = tree.acceleration_on(i, &probe_bodies, self.theta, self.softening);
+                }
+            }
+        } else {
+            for i in 0..n {
+                for j in (i + 1)..n {
+                    let r = positions[j] - positions[i];
+                    let dist_sq = r.length_squared() + self.softening * self.softening;
+                    let dist = dist_sq.sqrt();
+                    let force_mag = G / dist_sq;
+                    let force_dir = r / dist;
+
+                    accelerations[i] += force_dir * force_mag * self.bodies[j].mass;
+                    accelerations[j] -= force_dir * force_mag * self.bodies[i].mass;
+                }
             }
         }
 
-        // Update velocities and positions
+        accelerations
+    }
+
+    /// Gravitational acceleration on every body at its current position
+    fn accelerations(&self) -> Vec<Vec2> {
+        let positions: Vec<Vec2> = self.bodies.iter().map(|b| b.position).collect();
+        self.accelerations_at(&positions)
+    }
+
+    /// Semi-implicit Euler: a single force evaluation per step. Cheap, but
+    /// bleeds or adds orbital energy over long runs.
+    fn step_euler(&mut self, dt: f32) {
+        let accelerations = self.accelerations();
         for (i, body) in self.bodies.iter_mut().enumerate() {
             body.velocity += accelerations[i] * dt;
             body.position += body.velocity * dt;
         }
     }
 
+    /// Velocity-Verlet: advance positions using the previous step's
+    /// acceleration, recompute forces at the new positions, then use the
+    /// average of the old and new accelerations to advance velocity. Reusing
+    /// `last_accelerations` keeps this at one force evaluation per step,
+    /// the same as Euler, with much better energy conservation for orbital
+    /// motion.
+    fn step_velocity_verlet(&mut self, dt: f32) {
+        if self.last_accelerations.len() != self.bodies.len() {
+            self.last_accelerations = self.accelerations();
+        }
+
+        for (i, body) in self.bodies.iter_mut().enumerate() {
+            body.position += body.velocity * dt + 0.5 * self.last_accelerations[i] * dt * dt;
+        }
+
+        let new_accelerations = self.accelerations();
+        for (i, body) in self.bodies.iter_mut().enumerate() {
+            body.velocity += 0.5 * (self.last_accelerations[i] + new_accelerations[i]) * dt;
+        }
+
+        self.last_accelerations = new_accelerations;
+    }
+
+    /// Classic 4th-order Runge-Kutta: the state is (positions, velocities)
+    /// and the derivative of that state is (velocities, accelerations).
+    /// Evaluating the derivative at four stages (start, two midpoints,
+    /// end) and combining them with the usual 1/6 weighting gives much
+    /// better long-term accuracy than Euler or Verlet, at the cost of
+    /// three extra force evaluations per step.
+    fn step_rk4(&mut self, dt: f32) {
+        let pos0: Vec<Vec2> = self.bodies.iter().map(|b| b.position).collect();
+        let vel0: Vec<Vec2> = self.bodies.iter().map(|b| b.velocity).collect();
+
+        let derivative = |positions: &[Vec2], velocities: &[Vec2]| -> (Vec<Vec2>, Vec<Vec2>) {
+            (velocities.to_vec(), self.accelerations_at(positions))
+        };
+
+        let (k1_vel, k1_acc) = derivative(&pos0, &vel0);
+
+        let pos_k2: Vec<Vec2> = pos0.iter().zip(&k1_vel).map(|(p, v)| *p + *v * dt * 0.5).collect();
+        let vel_k2: Vec<Vec2> = vel0.iter().zip(&k1_acc).map(|(v, a)| *v + *a * dt * 0.5).collect();
+        let (k2_vel, k2_acc) = derivative(&pos_k2, &vel_k2);
+
+        let pos_k3: Vec<Vec2> = pos0.iter().zip(&k2_vel).map(|(p, v)| *p + *v * dt * 0.5).collect();
+        let vel_k3: Vec<Vec2> = vel0.iter().zip(&k2_acc).map(|(v, a)| *v + *a * dt * 0.5).collect();
+        let (k3_vel, k3_acc) = derivative(&pos_k3, &vel_k3);
+
+        let pos_k4: Vec<Vec2> = pos0.iter().zip(&k3_vel).map(|(p, v)| *p + *v * dt).collect();
+        let vel_k4: Vec<Vec2> = vel0.iter().zip(&k3_acc).map(|(v, a)| *v + *a * dt).collect();
+        let (k4_vel, k4_acc) = derivative(&pos_k4, &vel_k4);
+
+        for (i, body) in self.bodies.iter_mut().enumerate() {
+            body.position += (k1_vel[i] + 2.0 * k2_vel[i] + 2.0 * k3_vel[i] + k4_vel[i]) * (dt / 6.0);
+            body.velocity += (k1_acc[i] + 2.0 * k2_acc[i] + 2.0 * k3_acc[i] + k4_acc[i]) * (dt / 6.0);
+        }
+    }
+
+    /// Step the simulation forward by dt seconds, using whichever
+    /// [`Integrator`] is currently selected
+    pub fn step(&mut self, dt: f32) {
+        let dt = dt * self.time_scale;
+        if self.bodies.is_empty() {
+            return;
+        }
+
+        match self.integrator {
+            Integrator::Euler => self.step_euler(dt),
+            Integrator::VelocityVerlet => self.step_velocity_verlet(dt),
+            Integrator::Rk4 => self.step_rk4(dt),
+        }
+    }
+
     /// Get the center of mass of all bodies
     pub fn center_of_mass(&self) -> Vec2 {
         let mut total_mass = 0.0;
@@ -200,6 +385,31 @@ impl Simulation {
             Vec2::ZERO
         }
     }
+
+    /// Total kinetic plus gravitational potential energy of the system, for
+    /// watching conservation drift as `theta`/`integrator`/substep count
+    /// change. The potential term is the same direct O(n²) pairwise sum
+    /// `accelerations_at` falls back to when `theta == 0.0`, since a
+    /// diagnostic is a poor place to introduce another Barnes-Hut
+    /// approximation error on top of whatever the stepper itself used.
+    pub fn total_energy(&self) -> f32 {
+        let kinetic: f32 = self
+            .bodies
+            .iter()
+            .map(|body| 0.5 * body.mass * body.velocity.length_squared())
+            .sum();
+
+        let mut potential = 0.0;
+        for i in 0..self.bodies.len() {
+            for j in (i + 1)..self.bodies.len() {
+                let r = self.bodies[j].position - self.bodies[i].position;
+                let dist = (r.length_squared() + self.softening * self.softening).sqrt();
+                potential -= G * self.bodies[i].mass * self.bodies[j].mass / dist;
+            }
+        }
+
+        kinetic + potential
+    }
 }
 
 impl Default for Simulation {
@@ -1,9 +1,14 @@
 //! 3D N-body gravitational physics simulation
 
+use common::GraphicsContext;
 use glam::Vec3;
 use rand::Rng;
 use std::f32::consts::{PI, TAU};
 
+use crate::gpu_solver::GpuNBodySolver;
+use crate::octree::Octree;
+use crate::renderer_3d::MeshHandle;
+
 /// Gravitational constant (scaled for visualization)
 pub const G: f32 = 100.0;
 
@@ -18,6 +23,9 @@ pub struct Body3D {
     pub trail: Vec<Vec3>,
     pub trail_max_length: usize,
     pub name: Option<String>,
+    /// When set, this body draws as real geometry via `body_mesh_pipeline`
+    /// instead of a billboard impostor
+    pub mesh: Option<MeshHandle>,
 }
 
 impl Body3D {
@@ -41,6 +49,7 @@ impl Body3D {
             trail: Vec::new(),
             trail_max_length: 200,
             name: None,
+            mesh: None,
         }
     }
 
@@ -64,6 +73,11 @@ impl Body3D {
         self
     }
 
+    pub fn with_mesh(mut self, mesh: MeshHandle) -> Self {
+        self.mesh = Some(mesh);
+        self
+    }
+
     pub fn update_trail(&mut self) {
         self.trail.push(self.position);
         if self.trail.len() > self.trail_max_length {
@@ -79,8 +93,35 @@ pub struct Simulation3D {
     pub softening: f32,
     pub elapsed_time: f32,
     pub record_trails: bool,
+    /// Use the Barnes-Hut octree approximation instead of the direct O(N²)
+    /// all-pairs sum. Kept toggleable so small scenes can fall back to the
+    /// exact path for accuracy comparison.
+    pub use_barnes_hut: bool,
+    /// Barnes-Hut opening angle θ: a node is treated as a single
+    /// pseudo-particle once its width-to-distance ratio drops below this
+    pub theta: f32,
+    /// When true, `step_gpu` is expected to be called instead of `step`;
+    /// purely informational bookkeeping for callers, since the two paths
+    /// share nothing but can't run in the same frame without fighting over
+    /// `bodies`
+    pub use_gpu: bool,
+    gpu_solver: Option<GpuNBodySolver>,
+    gpu_steps_since_energy_check: u32,
+    /// `total_energy()` sampled every `GPU_ENERGY_CHECK_INTERVAL` GPU
+    /// steps. The GPU path syncs positions/velocities back every frame for
+    /// rendering, but recomputing the O(N²) energy sum itself every frame
+    /// would undo the point of moving to the GPU in the first place.
+    pub gpu_energy: Option<f32>,
 }
 
+/// Below this many bodies, the O(N²) direct sum is already cheap and an
+/// octree rebuild every step isn't worth its overhead
+const BARNES_HUT_MIN_BODIES: usize = 64;
+
+/// How often `step_gpu` recomputes the (expensive, O(N²)) `total_energy`
+/// diagnostic, in GPU steps
+const GPU_ENERGY_CHECK_INTERVAL: u32 = 30;
+
 impl Simulation3D {
     pub fn new() -> Self {
         Self {
@@ -89,6 +130,12 @@ impl Simulation3D {
             softening: 0.1,
             elapsed_time: 0.0,
             record_trails: true,
+            use_barnes_hut: true,
+            theta: 0.5,
+            use_gpu: false,
+            gpu_solver: None,
+            gpu_steps_since_energy_check: 0,
+            gpu_energy: None,
         }
     }
 
@@ -250,16 +297,24 @@ impl Simulation3D {
         // Calculate accelerations using leapfrog integration
         let mut accelerations = vec![Vec3::ZERO; n];
 
-        for i in 0..n {
-            for j in (i + 1)..n {
-                let r = self.bodies[j].position - self.bodies[i].position;
-                let dist_sq = r.length_squared() + self.softening * self.softening;
-                let dist = dist_sq.sqrt();
-                let force_mag = G / dist_sq;
-                let force_dir = r / dist;
-
-                accelerations[i] += force_dir * force_mag * self.bodies[j].mass;
-                accelerations[j] -= force_dir * force_mag * self.bodies[i].mass;
+        if self.use_barnes_hut && n >= BARNES_HUT_MIN_BODIES {
+            if let Some(tree) = Octree::build(&self.bodies) {
+                for i in 0..n {
+                    accelerations[i] = tree.acceleration_on(i, &self.bodies, self.theta, self.softening);
+                }
+            }
+        } else {
+            for i in 0..n {
+                for j in (i + 1)..n {
+                    let r = self.bodies[j].position - self.bodies[i].position;
+                    let dist_sq = r.length_squared() + self.softening * self.softening;
+                    let dist = dist_sq.sqrt();
+                    let force_mag = G / dist_sq;
+                    let force_dir = r / dist;
+
+                    accelerations[i] += force_dir * force_mag * self.bodies[j].mass;
+                    accelerations[j] -= force_dir * force_mag * self.bodies[i].mass;
+                }
             }
         }
 
@@ -274,6 +329,90 @@ impl Simulation3D {
         }
     }
 
+    /// Step the simulation forward on the GPU via a ping-pong compute
+    /// pipeline, instead of `step`'s CPU direct-sum/Barnes-Hut integrator.
+    /// The solver is lazily created (and recreated whenever the body count
+    /// changes), and every call reseeds it from the current `bodies`, so
+    /// callers can flip `use_gpu` on and off without any extra setup.
+    pub fn step_gpu(&mut self, ctx: &GraphicsContext, dt: f32) {
+        let dt = dt * self.time_scale;
+        let n = self.bodies.len();
+
+        if n == 0 {
+            return;
+        }
+
+        self.elapsed_time += dt;
+
+        let solver = match &mut self.gpu_solver {
+            Some(solver) if solver.max_bodies() == n => solver,
+            _ => self.gpu_solver.insert(GpuNBodySolver::new(&ctx.device, &ctx.queue, &self.bodies)),
+        };
+        solver.upload(&ctx.queue, &self.bodies);
+
+        let state = solver.step(&ctx.device, &ctx.queue, n as u32, dt, self.softening);
+        for (body, (position, velocity)) in self.bodies.iter_mut().zip(state) {
+            body.position = position;
+            body.velocity = velocity;
+
+            if self.record_trails {
+                body.update_trail();
+            }
+        }
+
+        self.gpu_steps_since_energy_check += 1;
+        if self.gpu_steps_since_energy_check >= GPU_ENERGY_CHECK_INTERVAL {
+            self.gpu_steps_since_energy_check = 0;
+            self.gpu_energy = Some(self.total_energy());
+        }
+    }
+
+    /// Closest body hit by the ray `ray_origin + t * ray_dir`, testing each
+    /// body as a sphere of its own `radius` and keeping the smallest
+    /// positive `t`. Used to turn a mouse cursor (already unprojected into
+    /// a world-space ray by `Camera3D::screen_ray`) into a selection.
+    pub fn pick(&self, ray_origin: Vec3, ray_dir: Vec3) -> Option<usize> {
+        self.bodies
+            .iter()
+            .enumerate()
+            .filter_map(|(i, body)| Self::ray_sphere_hit(ray_origin, ray_dir, body.position, body.radius).map(|t| (t, i)))
+            .min_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap())
+            .map(|(_, i)| i)
+    }
+
+    /// Nearest positive intersection distance of ray `origin + t*dir` with
+    /// the sphere at `center`, or `None` if it misses or is entirely
+    /// behind `origin`
+    fn ray_sphere_hit(origin: Vec3, dir: Vec3, center: Vec3, radius: f32) -> Option<f32> {
+        let oc = origin - center;
+        let b = oc.dot(dir);
+        let c = oc.length_squared() - radius * radius;
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let sqrt_d = discriminant.sqrt();
+        let t_near = -b - sqrt_d;
+        if t_near >= 0.0 {
+            return Some(t_near);
+        }
+        let t_far = -b + sqrt_d;
+        (t_far >= 0.0).then_some(t_far)
+    }
+
+    /// Index of the body that should act as the scene's point light: the
+    /// most massive body, which is always the Sun, an accretion disk's
+    /// central object, or a galaxy collision's black hole in every preset
+    /// this simulation ships with
+    pub fn light_source(&self) -> Option<usize> {
+        self.bodies
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.mass.partial_cmp(&b.mass).unwrap())
+            .map(|(i, _)| i)
+    }
+
     pub fn center_of_mass(&self) -> Vec3 {
         let mut total_mass = 0.0;
         let mut com = Vec3::ZERO;
@@ -305,6 +444,128 @@ impl Simulation3D {
 
         kinetic + potential
     }
+
+    /// Osculating Keplerian elements of `bodies[index]`'s orbit around the
+    /// most massive *other* body, treated as a fixed primary for this
+    /// instant. `None` if there's no other body for it to orbit.
+    pub fn orbital_elements(&self, index: usize) -> Option<OrbitalElements> {
+        let primary_index = self
+            .bodies
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .max_by(|(_, a), (_, b)| a.mass.partial_cmp(&b.mass).unwrap())
+            .map(|(i, _)| i)?;
+
+        let primary = &self.bodies[primary_index];
+        let body = self.bodies.get(index)?;
+
+        let mu = G * primary.mass;
+        let r_vec = body.position - primary.position;
+        let v_vec = body.velocity - primary.velocity;
+        let r = r_vec.length();
+
+        // Vis-viva / specific orbital energy: ε = v²/2 - μ/r = -μ/2a
+        let energy = 0.5 * v_vec.length_squared() - mu / r;
+        let semi_major_axis = -mu / (2.0 * energy);
+
+        // Eccentricity vector: e⃗ = (v⃗ × h⃗)/μ - r̂
+        let h = r_vec.cross(v_vec);
+        let eccentricity = ((v_vec.cross(h)) / mu - r_vec / r).length();
+
+        let period = (semi_major_axis > 0.0)
+            .then(|| TAU * (semi_major_axis.powi(3) / mu).sqrt());
+
+        Some(OrbitalElements {
+            primary_index,
+            semi_major_axis,
+            eccentricity,
+            period,
+        })
+    }
+
+    /// Sample the combined potential field Φ over a cubic `resolution`³
+    /// grid spanning the bodies' bounding box, padded by `padding` world
+    /// units on each side so the iso-surface doesn't get clipped at the
+    /// edge of the outermost body. `None` with fewer than 2 bodies, since a
+    /// single point mass has no interesting well shape to surface.
+    pub fn sample_potential_field(&self, resolution: usize, padding: f32) -> Option<PotentialField> {
+        if self.bodies.len() < 2 || resolution < 2 {
+            return None;
+        }
+
+        let mut min = self.bodies[0].position;
+        let mut max = self.bodies[0].position;
+        for body in &self.bodies {
+            min = min.min(body.position);
+            max = max.max(body.position);
+        }
+        min -= Vec3::splat(padding);
+        max += Vec3::splat(padding);
+
+        let extent = (max - min).max_element();
+        let cell_size = extent / (resolution - 1) as f32;
+        let dims = [resolution; 3];
+
+        let mut values = Vec::with_capacity(resolution * resolution * resolution);
+        for k in 0..resolution {
+            for j in 0..resolution {
+                for i in 0..resolution {
+                    let p = min + Vec3::new(i as f32, j as f32, k as f32) * cell_size;
+                    let mut phi = 0.0;
+                    for body in &self.bodies {
+                        phi -= G * body.mass / ((p - body.position).length() + self.softening);
+                    }
+                    values.push(phi);
+                }
+            }
+        }
+
+        Some(PotentialField {
+            origin: min,
+            cell_size,
+            dims,
+            values,
+        })
+    }
+}
+
+/// Osculating orbit of a body around another, derived from its
+/// instantaneous position and velocity relative to the primary — see
+/// [`Simulation3D::orbital_elements`]
+#[derive(Debug, Clone, Copy)]
+pub struct OrbitalElements {
+    pub primary_index: usize,
+    pub semi_major_axis: f32,
+    pub eccentricity: f32,
+    /// `None` for an unbound (parabolic/hyperbolic) trajectory
+    pub period: Option<f32>,
+}
+
+/// A regular grid sampling of the combined gravitational potential
+/// Φ(p) = Σ -G·mᵢ / (|p - rᵢ| + ε), used to extract a "gravity well"
+/// iso-surface via marching cubes. The grid is cubic (same resolution and
+/// cell size on every axis) so marching cubes can walk it as a stack of
+/// unit cubes.
+pub struct PotentialField {
+    pub origin: Vec3,
+    pub cell_size: f32,
+    pub dims: [usize; 3],
+    pub values: Vec<f32>,
+}
+
+impl PotentialField {
+    fn index(&self, i: usize, j: usize, k: usize) -> usize {
+        (k * self.dims[1] + j) * self.dims[0] + i
+    }
+
+    pub fn value(&self, i: usize, j: usize, k: usize) -> f32 {
+        self.values[self.index(i, j, k)]
+    }
+
+    pub fn corner_position(&self, i: usize, j: usize, k: usize) -> Vec3 {
+        self.origin + Vec3::new(i as f32, j as f32, k as f32) * self.cell_size
+    }
 }
 
 impl Default for Simulation3D {
@@ -0,0 +1,191 @@
+//! Barnes-Hut quadtree approximation for N-body gravity
+//!
+//! The 2D counterpart to [`crate::octree::Octree`]: building a quadtree over
+//! the current body positions and using it to approximate far-field forces
+//! brings a step from O(N²) down to roughly O(N log N), so `init_disk` and
+//! `init_galaxy_collision` runs stay interactive well past the body counts
+//! the direct pairwise sum can handle.
+
+use glam::Vec2;
+
+use crate::physics::{Body, G};
+
+/// Past this recursion depth, bodies are assumed to be effectively
+/// coincident (or pathologically close) and are merged into one
+/// pseudo-particle instead of subdividing forever
+const MAX_DEPTH: u32 = 32;
+
+struct QuadtreeNode {
+    center: Vec2,
+    half_width: f32,
+    mass: f32,
+    com: Vec2,
+    /// Index into the body list, set only on an un-subdivided leaf
+    body: Option<usize>,
+    children: Option<Box<[QuadtreeNode; 4]>>,
+}
+
+impl QuadtreeNode {
+    fn new_leaf(center: Vec2, half_width: f32) -> Self {
+        Self {
+            center,
+            half_width,
+            mass: 0.0,
+            com: Vec2::ZERO,
+            body: None,
+            children: None,
+        }
+    }
+
+    fn quadrant_of(&self, pos: Vec2) -> usize {
+        let mut index = 0;
+        if pos.x >= self.center.x {
+            index |= 1;
+        }
+        if pos.y >= self.center.y {
+            index |= 2;
+        }
+        index
+    }
+
+    fn child_center(&self, quadrant: usize) -> Vec2 {
+        let offset = self.half_width * 0.5;
+        Vec2::new(
+            self.center.x + if quadrant & 1 != 0 { offset } else { -offset },
+            self.center.y + if quadrant & 2 != 0 { offset } else { -offset },
+        )
+    }
+
+    fn subdivide(&mut self) {
+        let half = self.half_width * 0.5;
+        let children = std::array::from_fn(|i| QuadtreeNode::new_leaf(self.child_center(i), half));
+        self.children = Some(Box::new(children));
+    }
+
+    fn insert(&mut self, index: usize, bodies: &[Body], depth: u32) {
+        let position = bodies[index].position;
+        let mass = bodies[index].mass;
+
+        let new_mass = self.mass + mass;
+        self.com = (self.com * self.mass + position * mass) / new_mass;
+        self.mass = new_mass;
+
+        match (self.body, self.children.is_some()) {
+            (None, false) => self.body = Some(index),
+            (Some(existing), false) => {
+                if depth >= MAX_DEPTH {
+                    // Too deep to keep splitting (bodies essentially on top
+                    // of each other) — leave them merged into this node's
+                    // aggregate mass/COM rather than recursing forever.
+                    return;
+                }
+                self.body = None;
+                self.subdivide();
+                self.insert_into_child(existing, bodies, depth + 1);
+                self.insert_into_child(index, bodies, depth + 1);
+            }
+            (None, true) => self.insert_into_child(index, bodies, depth + 1),
+            (Some(_), true) => unreachable!("a node is either a leaf or internal, never both"),
+        }
+    }
+
+    fn insert_into_child(&mut self, index: usize, bodies: &[Body], depth: u32) {
+        let quadrant = self.quadrant_of(bodies[index].position);
+        if let Some(children) = &mut self.children {
+            children[quadrant].insert(index, bodies, depth);
+        }
+    }
+
+    fn accumulate_acceleration(
+        &self,
+        index: usize,
+        bodies: &[Body],
+        theta: f32,
+        softening: f32,
+        accel: &mut Vec2,
+    ) {
+        if self.mass <= 0.0 {
+            return;
+        }
+
+        match (self.body, &self.children) {
+            (Some(leaf_index), None) => {
+                if leaf_index != index {
+                    *accel += point_mass_acceleration(bodies[index].position, self.com, self.mass, softening);
+                }
+            }
+            (None, Some(children)) => {
+                let offset = self.com - bodies[index].position;
+                let distance = offset.length();
+                let width = self.half_width * 2.0;
+
+                if distance > 0.0 && width / distance < theta {
+                    *accel += point_mass_acceleration(bodies[index].position, self.com, self.mass, softening);
+                } else {
+                    for child in children.iter() {
+                        child.accumulate_acceleration(index, bodies, theta, softening, accel);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Newtonian acceleration a point mass `mass` at `source` exerts on a body
+/// at `at`, with a softened denominator to avoid a singularity at `source`
+fn point_mass_acceleration(at: Vec2, source: Vec2, mass: f32, softening: f32) -> Vec2 {
+    let r = source - at;
+    let dist_sq = r.length_squared() + softening * softening;
+    let dist = dist_sq.sqrt();
+    if dist < 1e-6 {
+        return Vec2::ZERO;
+    }
+    r / dist * (G * mass / dist_sq)
+}
+
+fn bounding_square(bodies: &[Body]) -> (Vec2, f32) {
+    let mut min = bodies[0].position;
+    let mut max = bodies[0].position;
+    for body in &bodies[1..] {
+        min = min.min(body.position);
+        max = max.max(body.position);
+    }
+
+    let center = (min + max) * 0.5;
+    // Pad past the tightest bounding extent so bodies sitting exactly on the
+    // boundary still fall strictly inside the root square.
+    let half_width = (max - min).max_element().max(1.0) * 0.5 + 1.0;
+    (center, half_width)
+}
+
+/// A Barnes-Hut quadtree built from a snapshot of body positions, used to
+/// approximate the gravitational force on each body in roughly O(N log N)
+/// instead of the direct O(N²) all-pairs sum
+pub struct Quadtree {
+    root: QuadtreeNode,
+}
+
+impl Quadtree {
+    pub fn build(bodies: &[Body]) -> Option<Self> {
+        if bodies.is_empty() {
+            return None;
+        }
+
+        let (center, half_width) = bounding_square(bodies);
+        let mut root = QuadtreeNode::new_leaf(center, half_width);
+        for i in 0..bodies.len() {
+            root.insert(i, bodies, 0);
+        }
+
+        Some(Self { root })
+    }
+
+    /// Approximate gravitational acceleration on body `index`, opening any
+    /// node whose width-to-distance ratio is `>= theta`
+    pub fn acceleration_on(&self, index: usize, bodies: &[Body], theta: f32, softening: f32) -> Vec2 {
+        let mut accel = Vec2::ZERO;
+        self.root.accumulate_acceleration(index, bodies, theta, softening, &mut accel);
+        accel
+    }
+}
@@ -1,8 +1,22 @@
 //! 3D Rendering system for gravity simulation
+//!
+//! `Renderer3D` is the perspective-camera sibling to the flat `Renderer`:
+//! it builds its view-projection matrix from a real `Camera3D` (eye,
+//! target, up, fov/aspect/near/far) rather than assuming z=0, carries a
+//! `Depth32Float` depth buffer with `Less` testing (recreated in
+//! `resize`) for the grid, trails, iso-surface and mesh-drawn bodies, and
+//! expands `ParticleInstance` billboards in view space using the camera's
+//! right/up vectors so they stay camera-facing from any angle. Billboards
+//! are drawn into the HDR target without a depth attachment, so they are
+//! not depth-tested against each other or against the rest of the scene.
+
+use std::cell::RefCell;
+use std::path::Path;
 
 use common::{Camera3D, GraphicsContext};
 use wgpu::util::DeviceExt;
 
+use crate::mesh_pool;
 use crate::physics_3d::{Body3D, Simulation3D};
 
 /// Camera uniform with view matrix for billboarding
@@ -24,6 +38,79 @@ impl CameraUniform3D {
     }
 }
 
+/// Point light driving the sphere-impostor shading of particles, positioned
+/// at whichever body `Simulation3D::light_source` designates (the Sun, an
+/// accretion disk's center, a galaxy collision's black hole)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LightUniform {
+    pub position: [f32; 4],
+    pub color: [f32; 4],
+    pub attenuation: f32,
+    pub _padding: [f32; 3],
+}
+
+/// Maximum number of emissive bodies the `point_lights` storage buffer can
+/// carry at once; a scene rarely has more than a handful of stars, so a
+/// small fixed cap avoids a dynamically-sized buffer
+const MAX_POINT_LIGHTS: usize = 16;
+
+/// One entry of the `point_lights` storage buffer, gathered in
+/// `Renderer3D::update_point_lights` from every emissive body in the scene
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct PointLight {
+    pub position: [f32; 4],
+    pub color: [f32; 4],
+    pub intensity: f32,
+    pub _padding: [f32; 3],
+}
+
+/// How many of the `point_lights` storage buffer's entries are valid this
+/// frame; WGSL storage buffers backing a fixed-capacity array still need
+/// their live length passed in separately
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct LightCount {
+    count: u32,
+    _padding: [u32; 3],
+}
+
+/// Depth-aware soft-particle and distance-fog parameters shared by the
+/// particle and trail fragment shaders via the camera bind group.
+/// `soft_distance` is the eye-space range over which a billboard's alpha
+/// fades out as it nears the scene depth behind it; `fog_density` drives
+/// `1 - exp(-density * eye_depth)` blended toward `fog_color`
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct FogUniform {
+    fog_color: [f32; 3],
+    fog_density: f32,
+    soft_distance: f32,
+    _padding: [f32; 3],
+}
+
+/// Layout `wgpu::RenderPass::draw_indirect` reads its arguments from: the
+/// vertex/instance counts `cs_cull` compacts survivors into, reset to
+/// `instance_count: 0` before every dispatch and incremented per survivor
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct DrawIndirectArgs {
+    vertex_count: u32,
+    instance_count: u32,
+    first_vertex: u32,
+    first_instance: u32,
+}
+
+/// How many of `instance_buffer`'s entries `cs_cull` should actually test
+/// this frame, since the buffer itself is sized for `max_instances`
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct CullParams {
+    instance_count: u32,
+    _padding: [u32; 3],
+}
+
 /// Instance data for GPU rendering
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
@@ -31,13 +118,18 @@ pub struct ParticleInstance {
     pub position: [f32; 3],
     pub radius: f32,
     pub color: [f32; 4],
+    /// Nonzero marks this body as a light source rather than a lit
+    /// surface: `fs_particle_3d` is expected to output its color directly
+    /// and skip Lambert/Blinn-Phong shading for it
+    pub emissive: f32,
 }
 
 impl ParticleInstance {
-    const ATTRIBS: [wgpu::VertexAttribute; 3] = wgpu::vertex_attr_array![
+    const ATTRIBS: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
         2 => Float32x3,
         3 => Float32,
         4 => Float32x4,
+        5 => Float32,
     ];
 
     pub fn layout() -> wgpu::VertexBufferLayout<'static> {
@@ -91,6 +183,72 @@ impl TrailVertex {
     }
 }
 
+/// Vertex of the gravitational potential iso-surface mesh, Lambert-shaded
+/// from a flat per-triangle normal
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct MeshVertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+}
+
+impl MeshVertex {
+    const ATTRIBS: [wgpu::VertexAttribute; 2] = wgpu::vertex_attr_array![
+        5 => Float32x3,
+        6 => Float32x3,
+    ];
+
+    pub fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<MeshVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
+/// Opaque handle to a loaded `.obj` model, returned by `Renderer3D::load_mesh`
+/// and stashed on any `Body3D` that should render as real geometry instead
+/// of a billboard impostor
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MeshHandle(usize);
+
+/// Per-instance model transform for a body drawn with `body_mesh_pipeline`:
+/// a uniform scale from the body's radius plus its position and color,
+/// mirroring the information `ParticleInstance` carries for billboards
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct BodyMeshInstance {
+    pub position: [f32; 3],
+    pub scale: f32,
+    pub color: [f32; 4],
+}
+
+impl BodyMeshInstance {
+    const ATTRIBS: [wgpu::VertexAttribute; 2] = wgpu::vertex_attr_array![
+        7 => Float32x4,
+        8 => Float32x4,
+    ];
+
+    pub fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<BodyMeshInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
+/// A loaded model's GPU buffers plus the per-instance buffer
+/// `update_simulation` fills in with every body that references it
+struct BodyMesh {
+    vertex_buffer: wgpu::Buffer,
+    index_buffer: wgpu::Buffer,
+    index_count: u32,
+    instance_buffer: wgpu::Buffer,
+    instance_capacity: usize,
+}
+
 const QUAD_VERTICES: &[QuadVertex] = &[
     QuadVertex { position: [-1.0, -1.0] },
     QuadVertex { position: [1.0, -1.0] },
@@ -100,23 +258,179 @@ const QUAD_VERTICES: &[QuadVertex] = &[
     QuadVertex { position: [-1.0, 1.0] },
 ];
 
+/// Threshold the bright-pass keeps pixels above
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct BrightParams {
+    threshold: f32,
+    _padding: [f32; 3],
+}
+
+/// Per-pass parameters for the separable Gaussian blur used by the bloom chain
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct BlurParams {
+    direction: [f32; 2],
+    texel_size: [f32; 2],
+}
+
+/// Exposure and intensity controls for the HDR tonemap pass
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct BloomUniform {
+    exposure: f32,
+    intensity: f32,
+    _padding: [f32; 2],
+}
+
+/// Default MSAA sample count for the skybox/grid/trail/mesh pipelines,
+/// passed to `Renderer3D::new` by callers that don't need a different level
+pub const DEFAULT_SAMPLE_COUNT: u32 = 4;
+
+/// Fall back to 1 (no multisampling) if `requested` isn't a sample count
+/// `format` actually supports on this adapter
+fn choose_sample_count(adapter: &wgpu::Adapter, format: wgpu::TextureFormat, requested: u32) -> u32 {
+    let flags = adapter.get_texture_format_features(format).flags;
+    if requested > 1 && flags.sample_count_supported(requested) {
+        requested
+    } else {
+        1
+    }
+}
+
+/// Create a render-attachment + sampled-texture pair for the HDR/bloom chain
+fn create_hdr_target(
+    device: &wgpu::Device,
+    width: u32,
+    height: u32,
+    format: wgpu::TextureFormat,
+    label: &str,
+) -> (wgpu::Texture, wgpu::TextureView) {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size: wgpu::Extent3d {
+            width: width.max(1),
+            height: height.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    (texture, view)
+}
+
+/// Number of progressively half-resolution bloom passes chained together;
+/// each wider, blurrier mip widens the glow past what a single blur pass
+/// can reach without the kernel itself needing to grow
+const BLOOM_MIP_LEVELS: usize = 3;
+
+/// One level of the bloom mip chain: a same-size ping-pong pair that the
+/// separable blur reads/writes, plus the bind groups/params that feed it
+struct BloomMip {
+    texture_a: wgpu::Texture,
+    view_a: wgpu::TextureView,
+    texture_b: wgpu::Texture,
+    view_b: wgpu::TextureView,
+    blur_bind_group_h: wgpu::BindGroup,
+    blur_bind_group_v: wgpu::BindGroup,
+    blur_params_h: wgpu::Buffer,
+    blur_params_v: wgpu::Buffer,
+}
+
 pub struct Renderer3D {
-    particle_pipeline: wgpu::RenderPipeline,
     trail_pipeline: wgpu::RenderPipeline,
     grid_pipeline: wgpu::RenderPipeline,
     skybox_pipeline: wgpu::RenderPipeline,
+    mesh_pipeline: wgpu::RenderPipeline,
     quad_buffer: wgpu::Buffer,
     instance_buffer: wgpu::Buffer,
     trail_buffer: wgpu::Buffer,
+    mesh_buffer: wgpu::Buffer,
     camera_buffer: wgpu::Buffer,
+    light_buffer: wgpu::Buffer,
+    point_lights_buffer: wgpu::Buffer,
+    light_count_buffer: wgpu::Buffer,
+    // Soft-particle fade / distance fog knobs read by the particle and
+    // trail fragment shaders alongside the depth texture bound at index 5
+    fog_uniform: wgpu::Buffer,
+    camera_bind_group_layout: wgpu::BindGroupLayout,
     camera_bind_group: wgpu::BindGroup,
     depth_texture: wgpu::TextureView,
+
+    // Skybox/grid/trail/mesh draw into an MSAA color + depth target that
+    // resolves onto the swapchain view at the end of their passes; left at
+    // a plain 1-sample target (msaa_texture is None) on backends whose
+    // format capabilities don't advertise `sample_count`'s support
+    sample_count: u32,
+    msaa_texture: Option<wgpu::Texture>,
+    msaa_view: Option<wgpu::TextureView>,
+
     max_instances: usize,
     max_trail_vertices: usize,
+    max_mesh_vertices: usize,
+
+    // GPU frustum culling: `cs_cull` reads every candidate instance out of
+    // `instance_buffer`, tests its bounding sphere against the view-proj
+    // frustum, and compacts survivors into `culled_instance_buffer` while
+    // incrementing `indirect_args_buffer`'s instance count; `render` then
+    // draws from the culled buffer via `draw_indirect` instead of issuing
+    // a CPU-counted `draw` for every candidate up to `max_instances`
+    cull_pipeline: wgpu::ComputePipeline,
+    cull_bind_group_layout: wgpu::BindGroupLayout,
+    cull_bind_group: wgpu::BindGroup,
+    cull_params: wgpu::Buffer,
+    culled_instance_buffer: wgpu::Buffer,
+    indirect_args_buffer: wgpu::Buffer,
+
+    // Bodies with a `mesh: Some(handle)` draw real geometry via
+    // `body_mesh_pipeline` instead of billboard impostors; `body_meshes`
+    // sits behind a `RefCell` so `load_mesh` can append to it from `&self`,
+    // matching the rest of this struct's shared-reference API
+    body_mesh_pipeline: wgpu::RenderPipeline,
+    body_meshes: RefCell<Vec<BodyMesh>>,
+
+    // Particles render into an HDR offscreen target (letting glowing cores
+    // like the Sun or an accretion disk's center push well above 1.0
+    // luminance) which is then resolved onto the swapchain through a
+    // bright-pass -> separable blur -> tonemap bloom chain
+    particle_pipeline_hdr: wgpu::RenderPipeline,
+    hdr_format: wgpu::TextureFormat,
+    hdr_texture: wgpu::Texture,
+    hdr_view: wgpu::TextureView,
+    hdr_sampler: wgpu::Sampler,
+
+    // Bloom runs as a chain of `BLOOM_MIP_LEVELS` progressively
+    // half-resolution ping-pong pairs: mip 0 (half the swapchain size)
+    // receives the bright-pass threshold, each later mip is a downsample
+    // of the previous mip's blurred result, and the tonemap pass sums all
+    // of them back together to widen the glow past a single blur's reach
+    bloom_mips: Vec<BloomMip>,
+    downsample_bind_groups: Vec<wgpu::BindGroup>,
+
+    bright_pipeline: wgpu::RenderPipeline,
+    bright_bind_group_layout: wgpu::BindGroupLayout,
+    bright_bind_group: wgpu::BindGroup,
+    bright_params: wgpu::Buffer,
+
+    downsample_pipeline: wgpu::RenderPipeline,
+    downsample_bind_group_layout: wgpu::BindGroupLayout,
+
+    blur_pipeline: wgpu::RenderPipeline,
+    blur_bind_group_layout: wgpu::BindGroupLayout,
+
+    tonemap_pipeline: wgpu::RenderPipeline,
+    tonemap_bind_group_layout: wgpu::BindGroupLayout,
+    tonemap_bind_group: wgpu::BindGroup,
+    bloom_uniform: wgpu::Buffer,
 }
 
 impl Renderer3D {
-    pub fn new(ctx: &GraphicsContext, max_instances: usize) -> Self {
+    pub fn new(ctx: &GraphicsContext, max_instances: usize, sample_count: u32) -> Self {
         let device = &ctx.device;
         let max_trail_vertices = max_instances * 300;
 
@@ -133,28 +447,182 @@ impl Renderer3D {
             mapped_at_creation: false,
         });
 
+        // Point light driving the sphere-impostor shading; shares the
+        // camera's bind group since every pipeline already binds it at
+        // index 0 and the light is needed alongside the view/proj matrices
+        let light_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Light Buffer"),
+            contents: bytemuck::cast_slice(&[LightUniform {
+                position: [0.0, 0.0, 0.0, 1.0],
+                color: [1.0, 1.0, 1.0, 1.0],
+                attenuation: 0.02,
+                _padding: [0.0; 3],
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        // Storage buffer of point lights gathered from every emissive body
+        // (not just the single primary light above), for scenes with more
+        // than one luminous body, e.g. a binary star
+        let point_lights_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Point Lights Buffer"),
+            size: (std::mem::size_of::<PointLight>() * MAX_POINT_LIGHTS) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let light_count_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Light Count Buffer"),
+            contents: bytemuck::cast_slice(&[LightCount { count: 0, _padding: [0; 3] }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        // Soft-particle fade / distance fog parameters, shared by the
+        // particle and trail fragment shaders via the camera bind group
+        let fog_uniform = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Fog Uniform Buffer"),
+            contents: bytemuck::cast_slice(&[FogUniform {
+                fog_color: [0.0, 0.0, 0.0],
+                fog_density: 0.0,
+                soft_distance: 1.0,
+                _padding: [0.0; 3],
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        // MSAA: falls back to single-sampled if the adapter's format
+        // capabilities don't advertise support for the requested count
+        let sample_count = choose_sample_count(&ctx.adapter, ctx.config.format, sample_count);
+        let multisample_state = wgpu::MultisampleState {
+            count: sample_count,
+            ..Default::default()
+        };
+
+        let msaa_target = if sample_count > 1 {
+            Some(Self::create_msaa_color_texture(
+                device,
+                ctx.size.width,
+                ctx.size.height,
+                ctx.config.format,
+                sample_count,
+            ))
+        } else {
+            None
+        };
+        let (msaa_texture, msaa_view) = match msaa_target {
+            Some((texture, view)) => (Some(texture), Some(view)),
+            None => (None, None),
+        };
+
+        // Depth texture; also bound into the particle/trail fragment
+        // shaders (camera bind group binding 5) so they can fade billboard
+        // edges and apply distance fog against the already-drawn scene
+        let depth_texture =
+            Self::create_depth_texture(device, ctx.size.width, ctx.size.height, sample_count);
+
+        let depth_stencil_state = Some(wgpu::DepthStencilState {
+            format: wgpu::TextureFormat::Depth32Float,
+            depth_write_enabled: true,
+            depth_compare: wgpu::CompareFunction::Less,
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        });
+
         let camera_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("Camera Bind Group Layout"),
-                entries: &[wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Depth,
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: sample_count > 1,
+                        },
+                        count: None,
                     },
-                    count: None,
-                }],
+                ],
             });
 
         let camera_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Camera Bind Group"),
             layout: &camera_bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: camera_buffer.as_entire_binding(),
-            }],
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: camera_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: light_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: point_lights_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: light_count_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: fog_uniform.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 5,
+                    resource: wgpu::BindingResource::TextureView(&depth_texture),
+                },
+            ],
         });
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -163,20 +631,13 @@ impl Renderer3D {
             push_constant_ranges: &[],
         });
 
-        // Depth texture
-        let depth_texture = Self::create_depth_texture(device, ctx.size.width, ctx.size.height);
-
-        let depth_stencil_state = Some(wgpu::DepthStencilState {
-            format: wgpu::TextureFormat::Depth32Float,
-            depth_write_enabled: true,
-            depth_compare: wgpu::CompareFunction::Less,
-            stencil: wgpu::StencilState::default(),
-            bias: wgpu::DepthBiasState::default(),
-        });
-
-        // Particle pipeline
-        let particle_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Particle Pipeline"),
+        // Particles render into an HDR offscreen target instead of the
+        // swapchain directly, so a body's emitted brightness (scaled by
+        // mass in `update_simulation`) can push past 1.0 and still survive
+        // into the bloom chain instead of clipping at the particle pipeline
+        let hdr_format = wgpu::TextureFormat::Rgba16Float;
+        let particle_pipeline_hdr = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("HDR Particle Pipeline"),
             layout: Some(&pipeline_layout),
             vertex: wgpu::VertexState {
                 module: &shader,
@@ -188,7 +649,7 @@ impl Renderer3D {
                 module: &shader,
                 entry_point: "fs_particle_3d",
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: ctx.config.format,
+                    format: hdr_format,
                     blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
@@ -198,7 +659,7 @@ impl Renderer3D {
                 topology: wgpu::PrimitiveTopology::TriangleList,
                 ..Default::default()
             },
-            depth_stencil: depth_stencil_state.clone(),
+            depth_stencil: None,
             multisample: wgpu::MultisampleState::default(),
             multiview: None,
         });
@@ -228,7 +689,7 @@ impl Renderer3D {
                 ..Default::default()
             },
             depth_stencil: depth_stencil_state.clone(),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: multisample_state,
             multiview: None,
         });
 
@@ -257,7 +718,7 @@ impl Renderer3D {
                 ..Default::default()
             },
             depth_stencil: depth_stencil_state.clone(),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: multisample_state,
             multiview: None,
         });
 
@@ -286,7 +747,67 @@ impl Renderer3D {
                 ..Default::default()
             },
             depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
+            multisample: multisample_state,
+            multiview: None,
+        });
+
+        // Iso-surface mesh pipeline (potential field "gravity well")
+        let mesh_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Iso-Surface Mesh Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_mesh",
+                buffers: &[MeshVertex::layout()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_mesh",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: depth_stencil_state.clone(),
+            multisample: multisample_state,
+            multiview: None,
+        });
+
+        // Instanced indexed draws for bodies with a loaded `.obj` mesh;
+        // opaque like the skybox rather than alpha-blended, since a solid
+        // model (unlike a soft billboard disc) has no edge to feather
+        let body_mesh_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Body Mesh Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_body_mesh",
+                buffers: &[MeshVertex::layout(), BodyMeshInstance::layout()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_body_mesh",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: depth_stencil_state.clone(),
+            multisample: multisample_state,
             multiview: None,
         });
 
@@ -300,10 +821,114 @@ impl Renderer3D {
         let instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Instance Buffer"),
             size: (std::mem::size_of::<ParticleInstance>() * max_instances) as u64,
-            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            // STORAGE so `cs_cull` can read every candidate instance
+            // alongside the vertex pipeline's own use of this buffer
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // GPU frustum culling: instances survive `cs_cull`'s bounding-sphere
+        // test into this buffer, which the particle pipeline draws from via
+        // `draw_indirect` instead of the CPU-counted candidate list
+        let culled_instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Culled Instance Buffer"),
+            size: (std::mem::size_of::<ParticleInstance>() * max_instances) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
 
+        let indirect_args_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Indirect Draw Args Buffer"),
+            contents: bytemuck::cast_slice(&[DrawIndirectArgs {
+                vertex_count: 6,
+                instance_count: 0,
+                first_vertex: 0,
+                first_instance: 0,
+            }]),
+            usage: wgpu::BufferUsages::INDIRECT | wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let cull_params = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Cull Params Buffer"),
+            contents: bytemuck::cast_slice(&[CullParams { instance_count: 0, _padding: [0; 3] }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let cull_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Cull Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let cull_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Cull Bind Group"),
+            layout: &cull_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: instance_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: culled_instance_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: indirect_args_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 3, resource: cull_params.as_entire_binding() },
+            ],
+        });
+
+        let cull_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Cull Pipeline Layout"),
+            bind_group_layouts: &[&camera_bind_group_layout, &cull_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // Frustum planes are derived from `CameraUniform3D.view_proj`
+        // (bound at camera bind group index 0) directly inside `cs_cull`,
+        // so no CPU-side plane extraction is needed
+        let cull_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Frustum Cull Pipeline"),
+            layout: Some(&cull_pipeline_layout),
+            module: &shader,
+            entry_point: "cs_cull",
+            compilation_options: Default::default(),
+        });
+
         let trail_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Trail Buffer"),
             size: (std::mem::size_of::<TrailVertex>() * max_trail_vertices) as u64,
@@ -311,23 +936,428 @@ impl Renderer3D {
             mapped_at_creation: false,
         });
 
+        // Iso-surface triangle soup isn't instanced per-body like the
+        // particles, so its vertex budget is sized independently
+        let max_mesh_vertices = 65536;
+        let mesh_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Iso-Surface Mesh Buffer"),
+            size: (std::mem::size_of::<MeshVertex>() * max_mesh_vertices) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let hdr_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("HDR Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let (hdr_texture, hdr_view) =
+            create_hdr_target(device, ctx.size.width, ctx.size.height, hdr_format, "HDR");
+
+        // Bright pass: thresholds the HDR scene down into the half-res
+        // bloom target, keeping only pixels at or above `bright_params`
+        let bright_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Bright Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let bright_params = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Bright Params Buffer"),
+            contents: bytemuck::cast_slice(&[BrightParams { threshold: 1.0, _padding: [0.0; 3] }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let bright_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Bright Bind Group"),
+            layout: &bright_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&hdr_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: bright_params.as_entire_binding() },
+            ],
+        });
+
+        let bright_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Bright Pipeline Layout"),
+            bind_group_layouts: &[&bright_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let bright_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Bright Pass Pipeline"),
+            layout: Some(&bright_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_bright",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: hdr_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        // Separable blur: one pipeline, run twice (horizontal then
+        // vertical) against ping-ponged half-res bloom textures
+        let blur_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Blur Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let blur_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Blur Pipeline Layout"),
+            bind_group_layouts: &[&blur_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let blur_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Blur Pipeline"),
+            layout: Some(&blur_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_blur",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: hdr_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        // Downsample: a plain passthrough sample (no threshold) that feeds
+        // mip level N+1 from mip level N's already-blurred result, so the
+        // glow keeps widening instead of just getting blurrier at one size
+        let downsample_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Downsample Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+
+        let downsample_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Downsample Pipeline Layout"),
+            bind_group_layouts: &[&downsample_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let downsample_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Downsample Pipeline"),
+            layout: Some(&downsample_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_downsample",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: hdr_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        // Mip 0 is half the swapchain size (the blur only needs to look
+        // soft, not pixel-accurate); each later mip halves again, widening
+        // the eventual glow radius once the tonemap pass sums them all
+        let (bloom_mips, downsample_bind_groups) = Self::build_bloom_mips(
+            device,
+            ctx.size.width,
+            ctx.size.height,
+            hdr_format,
+            &hdr_sampler,
+            &blur_bind_group_layout,
+            &downsample_bind_group_layout,
+        );
+
+        // Tonemap pass: additively sums every bloom mip's blurred result
+        // with the sharp HDR scene, scaled by exposure/intensity, onto the
+        // swapchain — one binding per mip level plus the HDR scene itself
+        let tonemap_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Tonemap Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let bloom_uniform = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Bloom Uniform Buffer"),
+            contents: bytemuck::cast_slice(&[BloomUniform { exposure: 1.0, intensity: 1.0, _padding: [0.0; 2] }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let tonemap_bind_group = Self::create_tonemap_bind_group(
+            device,
+            &tonemap_bind_group_layout,
+            &hdr_view,
+            &bloom_mips,
+            &hdr_sampler,
+            &bloom_uniform,
+        );
+
+        let tonemap_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Tonemap Pipeline Layout"),
+            bind_group_layouts: &[&tonemap_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // Additive so the bloomed glow adds onto the already-opaque
+        // skybox/grid/trails/mesh pass instead of replacing it
+        let tonemap_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Tonemap Pipeline"),
+            layout: Some(&tonemap_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_tonemap",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One,
+                            dst_factor: wgpu::BlendFactor::One,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                        alpha: wgpu::BlendComponent::OVER,
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
         Self {
-            particle_pipeline,
             trail_pipeline,
             grid_pipeline,
             skybox_pipeline,
+            mesh_pipeline,
             quad_buffer,
             instance_buffer,
+            cull_pipeline,
+            cull_bind_group_layout,
+            cull_bind_group,
+            cull_params,
+            culled_instance_buffer,
+            indirect_args_buffer,
             trail_buffer,
+            mesh_buffer,
             camera_buffer,
+            light_buffer,
+            point_lights_buffer,
+            light_count_buffer,
+            fog_uniform,
+            camera_bind_group_layout,
             camera_bind_group,
             depth_texture,
+            sample_count,
+            msaa_texture,
+            msaa_view,
             max_instances,
             max_trail_vertices,
+            max_mesh_vertices,
+            body_mesh_pipeline,
+            body_meshes: RefCell::new(Vec::new()),
+            particle_pipeline_hdr,
+            hdr_format,
+            hdr_texture,
+            hdr_view,
+            hdr_sampler,
+            bloom_mips,
+            downsample_bind_groups,
+            bright_pipeline,
+            bright_bind_group_layout,
+            bright_bind_group,
+            bright_params,
+            downsample_pipeline,
+            downsample_bind_group_layout,
+            blur_pipeline,
+            blur_bind_group_layout,
+            tonemap_pipeline,
+            tonemap_bind_group_layout,
+            tonemap_bind_group,
+            bloom_uniform,
         }
     }
 
-    fn create_depth_texture(device: &wgpu::Device, width: u32, height: u32) -> wgpu::TextureView {
+    fn create_depth_texture(
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+        sample_count: u32,
+    ) -> wgpu::TextureView {
         let texture = device.create_texture(&wgpu::TextureDescriptor {
             label: Some("Depth Texture"),
             size: wgpu::Extent3d {
@@ -336,7 +1366,7 @@ impl Renderer3D {
                 depth_or_array_layers: 1,
             },
             mip_level_count: 1,
-            sample_count: 1,
+            sample_count,
             dimension: wgpu::TextureDimension::D2,
             format: wgpu::TextureFormat::Depth32Float,
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
@@ -345,8 +1375,237 @@ impl Renderer3D {
         texture.create_view(&wgpu::TextureViewDescriptor::default())
     }
 
-    pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
-        self.depth_texture = Self::create_depth_texture(device, width, height);
+    /// Multisampled color target the skybox/grid/trail/mesh pipelines
+    /// render into when `sample_count > 1`; its contents get resolved onto
+    /// the swapchain view at the end of each of those passes
+    fn create_msaa_color_texture(
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+        format: wgpu::TextureFormat,
+        sample_count: u32,
+    ) -> (wgpu::Texture, wgpu::TextureView) {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("MSAA Color Texture"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        (texture, view)
+    }
+
+    /// Build the `BLOOM_MIP_LEVELS`-deep chain of ping-pong pairs (mip 0 at
+    /// half `width`/`height`, each later mip half the one before) along
+    /// with the bind groups that downsample mip N's blurred result into
+    /// mip N+1
+    fn build_bloom_mips(
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+        format: wgpu::TextureFormat,
+        sampler: &wgpu::Sampler,
+        blur_bind_group_layout: &wgpu::BindGroupLayout,
+        downsample_bind_group_layout: &wgpu::BindGroupLayout,
+    ) -> (Vec<BloomMip>, Vec<wgpu::BindGroup>) {
+        let mut bloom_mips = Vec::with_capacity(BLOOM_MIP_LEVELS);
+        let mut downsample_bind_groups = Vec::with_capacity(BLOOM_MIP_LEVELS - 1);
+
+        for level in 0..BLOOM_MIP_LEVELS {
+            let divisor = 1u32 << (level + 1);
+            let mip_width = (width / divisor).max(1);
+            let mip_height = (height / divisor).max(1);
+
+            let (texture_a, view_a) =
+                create_hdr_target(device, mip_width, mip_height, format, &format!("Bloom Mip {level} A"));
+            let (texture_b, view_b) =
+                create_hdr_target(device, mip_width, mip_height, format, &format!("Bloom Mip {level} B"));
+
+            let texel_size = [1.0 / mip_width as f32, 1.0 / mip_height as f32];
+            let blur_params_h = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Blur Params Horizontal"),
+                contents: bytemuck::cast_slice(&[BlurParams { direction: [1.0, 0.0], texel_size }]),
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            });
+            let blur_params_v = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Blur Params Vertical"),
+                contents: bytemuck::cast_slice(&[BlurParams { direction: [0.0, 1.0], texel_size }]),
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            });
+
+            // Horizontal pass reads the mip's bright/downsample result
+            // (texture A) and writes texture B; vertical pass reads B back
+            // into A, leaving the finished blur in texture A
+            let blur_bind_group_h = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Blur Bind Group Horizontal"),
+                layout: blur_bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view_a) },
+                    wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+                    wgpu::BindGroupEntry { binding: 2, resource: blur_params_h.as_entire_binding() },
+                ],
+            });
+            let blur_bind_group_v = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Blur Bind Group Vertical"),
+                layout: blur_bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view_b) },
+                    wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+                    wgpu::BindGroupEntry { binding: 2, resource: blur_params_v.as_entire_binding() },
+                ],
+            });
+
+            if level > 0 {
+                let source_view = &bloom_mips[level - 1].view_a;
+                downsample_bind_groups.push(device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("Downsample Bind Group"),
+                    layout: downsample_bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(source_view) },
+                        wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+                    ],
+                }));
+            }
+
+            bloom_mips.push(BloomMip {
+                texture_a,
+                view_a,
+                texture_b,
+                view_b,
+                blur_bind_group_h,
+                blur_bind_group_v,
+                blur_params_h,
+                blur_params_v,
+            });
+        }
+
+        (bloom_mips, downsample_bind_groups)
+    }
+
+    /// Bind the sharp HDR scene plus every bloom mip's blurred result
+    /// (`bloom_mips` must have exactly `BLOOM_MIP_LEVELS` entries) for the
+    /// tonemap pass to sum together
+    fn create_tonemap_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        hdr_view: &wgpu::TextureView,
+        bloom_mips: &[BloomMip],
+        sampler: &wgpu::Sampler,
+        bloom_uniform: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Tonemap Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(hdr_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(&bloom_mips[0].view_a) },
+                wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::TextureView(&bloom_mips[1].view_a) },
+                wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::TextureView(&bloom_mips[2].view_a) },
+                wgpu::BindGroupEntry { binding: 4, resource: wgpu::BindingResource::Sampler(sampler) },
+                wgpu::BindGroupEntry { binding: 5, resource: bloom_uniform.as_entire_binding() },
+            ],
+        })
+    }
+
+    /// Recreate the depth buffer and the HDR/bloom offscreen targets to
+    /// match a new swapchain size, along with every bind group and
+    /// texel-size uniform that references them
+    pub fn resize(&mut self, ctx: &GraphicsContext, width: u32, height: u32) {
+        let device = &ctx.device;
+        self.depth_texture =
+            Self::create_depth_texture(device, width, height, self.sample_count);
+
+        // The camera bind group holds the depth texture view directly
+        // (binding 5), so it has to be rebuilt against the resized one
+        self.camera_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Camera Bind Group"),
+            layout: &self.camera_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: self.camera_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: self.light_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: self.point_lights_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 3, resource: self.light_count_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 4, resource: self.fog_uniform.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 5, resource: wgpu::BindingResource::TextureView(&self.depth_texture) },
+            ],
+        });
+
+        if self.sample_count > 1 {
+            let (msaa_texture, msaa_view) = Self::create_msaa_color_texture(
+                device,
+                width,
+                height,
+                ctx.config.format,
+                self.sample_count,
+            );
+            self.msaa_texture = Some(msaa_texture);
+            self.msaa_view = Some(msaa_view);
+        }
+
+        let (hdr_texture, hdr_view) = create_hdr_target(device, width, height, self.hdr_format, "HDR");
+        self.hdr_texture = hdr_texture;
+        self.hdr_view = hdr_view;
+
+        let (bloom_mips, downsample_bind_groups) = Self::build_bloom_mips(
+            device,
+            width,
+            height,
+            self.hdr_format,
+            &self.hdr_sampler,
+            &self.blur_bind_group_layout,
+            &self.downsample_bind_group_layout,
+        );
+        self.bloom_mips = bloom_mips;
+        self.downsample_bind_groups = downsample_bind_groups;
+
+        self.bright_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Bright Bind Group"),
+            layout: &self.bright_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&self.hdr_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&self.hdr_sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: self.bright_params.as_entire_binding() },
+            ],
+        });
+
+        self.tonemap_bind_group = Self::create_tonemap_bind_group(
+            device,
+            &self.tonemap_bind_group_layout,
+            &self.hdr_view,
+            &self.bloom_mips,
+            &self.hdr_sampler,
+            &self.bloom_uniform,
+        );
+    }
+
+    /// Luminance threshold (in the HDR scene's linear color space) above
+    /// which the bright pass lets a pixel through to the blur chain
+    pub fn set_bloom_threshold(&self, queue: &wgpu::Queue, threshold: f32) {
+        let params = BrightParams { threshold, _padding: [0.0; 3] };
+        queue.write_buffer(&self.bright_params, 0, bytemuck::cast_slice(&[params]));
+    }
+
+    /// Exposure and bloom-intensity multipliers applied by the tonemap pass
+    pub fn set_bloom_params(&self, queue: &wgpu::Queue, exposure: f32, intensity: f32) {
+        let uniform = BloomUniform { exposure, intensity, _padding: [0.0; 2] };
+        queue.write_buffer(&self.bloom_uniform, 0, bytemuck::cast_slice(&[uniform]));
+    }
+
+    /// `soft_distance` is the eye-space depth range billboards fade over
+    /// as they near the scene depth behind them; `fog_density` and
+    /// `fog_color` drive the exponential distance fog blended on top of
+    /// particles and trails
+    pub fn set_fog_params(&self, queue: &wgpu::Queue, fog_color: [f32; 3], fog_density: f32, soft_distance: f32) {
+        let uniform = FogUniform { fog_color, fog_density, soft_distance, _padding: [0.0; 3] };
+        queue.write_buffer(&self.fog_uniform, 0, bytemuck::cast_slice(&[uniform]));
     }
 
     pub fn update_camera(&self, queue: &wgpu::Queue, camera: &Camera3D) {
@@ -354,20 +1613,121 @@ impl Renderer3D {
         queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[uniform]));
     }
 
+    /// Position the scene's point light at `sim.light_source()`'s body,
+    /// tinted by its color; falls back to a white light at the origin if
+    /// the simulation has no bodies
+    pub fn update_light(&self, queue: &wgpu::Queue, sim: &Simulation3D) {
+        let uniform = match sim.light_source().map(|i| &sim.bodies[i]) {
+            Some(body) => LightUniform {
+                position: [body.position.x, body.position.y, body.position.z, 1.0],
+                color: body.color,
+                attenuation: 0.02,
+                _padding: [0.0; 3],
+            },
+            None => LightUniform {
+                position: [0.0, 0.0, 0.0, 1.0],
+                color: [1.0, 1.0, 1.0, 1.0],
+                attenuation: 0.02,
+                _padding: [0.0; 3],
+            },
+        };
+        queue.write_buffer(&self.light_buffer, 0, bytemuck::cast_slice(&[uniform]));
+    }
+
+    /// A body counts as emissive (a self-lit star rather than a lit planet)
+    /// once its mass is within half of the scene's most massive body,
+    /// generalizing `Simulation3D::light_source()`'s single-star heuristic
+    /// to binary and multi-star systems
+    fn is_emissive(body: &Body3D, max_mass: f32) -> bool {
+        body.mass >= max_mass * 0.5
+    }
+
+    /// Gather every emissive body into the `point_lights` storage buffer
+    /// consumed by `fs_particle_3d`'s Lambert + Blinn-Phong shading
+    pub fn update_point_lights(&self, queue: &wgpu::Queue, sim: &Simulation3D) {
+        let max_mass = sim
+            .bodies
+            .iter()
+            .map(|b| b.mass)
+            .fold(0.0f32, f32::max);
+
+        let lights: Vec<PointLight> = sim
+            .bodies
+            .iter()
+            .filter(|body| Self::is_emissive(body, max_mass))
+            .take(MAX_POINT_LIGHTS)
+            .map(|body| {
+                let intensity = 1.0 + body.mass / 5000.0;
+                PointLight {
+                    position: [body.position.x, body.position.y, body.position.z, 1.0],
+                    color: body.color,
+                    intensity,
+                    _padding: [0.0; 3],
+                }
+            })
+            .collect();
+
+        queue.write_buffer(&self.point_lights_buffer, 0, bytemuck::cast_slice(&lights));
+        queue.write_buffer(
+            &self.light_count_buffer,
+            0,
+            bytemuck::cast_slice(&[LightCount { count: lights.len() as u32, _padding: [0; 3] }]),
+        );
+    }
+
     pub fn update_simulation(
         &self,
         queue: &wgpu::Queue,
         sim: &Simulation3D,
-    ) -> (u32, Vec<(u32, u32)>) {
-        // Update particle instances
+        selected: Option<usize>,
+    ) -> (u32, Vec<(u32, u32)>, Vec<(MeshHandle, u32)>) {
+        // Update particle instances. Bodies render into the HDR offscreen
+        // target, so color is scaled by mass rather than clamped to 1.0:
+        // a massive body (the Sun, an accretion disk's center) comes out
+        // well above white and blooms, while ordinary planets stay close
+        // to their plain `body.color`.
+        //
+        // Bodies with a loaded mesh skip the billboard path entirely — they
+        // draw as real geometry via `body_mesh_pipeline` below instead.
+        let max_mass = sim
+            .bodies
+            .iter()
+            .map(|b| b.mass)
+            .fold(0.0f32, f32::max);
+
         let instances: Vec<ParticleInstance> = sim
             .bodies
             .iter()
+            .enumerate()
+            .filter(|(_, body)| body.mesh.is_none())
             .take(self.max_instances)
-            .map(|body| ParticleInstance {
-                position: [body.position.x, body.position.y, body.position.z],
-                radius: body.radius,
-                color: body.color,
+            .map(|(i, body)| {
+                let brightness = 1.0 + body.mass / 5000.0;
+                let color = [
+                    body.color[0] * brightness,
+                    body.color[1] * brightness,
+                    body.color[2] * brightness,
+                    body.color[3],
+                ];
+                let emissive = if Self::is_emissive(body, max_mass) { 1.0 } else { 0.0 };
+
+                if selected == Some(i) {
+                    // Highlight the selected body: a brighter, slightly
+                    // larger billboard rather than a separate outline pass
+                    ParticleInstance {
+                        position: [body.position.x, body.position.y, body.position.z],
+                        radius: body.radius * 1.3,
+                        color: [color[0] + 0.4, color[1] + 0.4, color[2] + 0.4, color[3]],
+                        emissive,
+                    }
+                } else {
+                    ParticleInstance {
+                        position: [body.position.x, body.position.y, body.position.z],
+                        radius: body.radius,
+                        color,
+                        emissive,
+                    }
+                }
             })
             .collect();
 
@@ -407,25 +1767,164 @@ impl Renderer3D {
             queue.write_buffer(&self.trail_buffer, 0, bytemuck::cast_slice(&trail_vertices));
         }
 
-        (instances.len() as u32, trail_ranges)
+        // Bucket bodies with a mesh by handle, upload each bucket into that
+        // mesh's own instance buffer, and report back how many instances to
+        // draw per mesh so `render` can issue one `draw_indexed` per handle
+        let body_meshes = self.body_meshes.borrow();
+        let mut mesh_instances: Vec<Vec<BodyMeshInstance>> =
+            (0..body_meshes.len()).map(|_| Vec::new()).collect();
+
+        for body in &sim.bodies {
+            if let Some(handle) = body.mesh {
+                if let Some(bucket) = mesh_instances.get_mut(handle.0) {
+                    bucket.push(BodyMeshInstance {
+                        position: [body.position.x, body.position.y, body.position.z],
+                        scale: body.radius,
+                        color: body.color,
+                    });
+                }
+            }
+        }
+
+        let mut mesh_draws = Vec::new();
+        for (i, bucket) in mesh_instances.into_iter().enumerate() {
+            if bucket.is_empty() {
+                continue;
+            }
+            let mesh = &body_meshes[i];
+            let count = bucket.len().min(mesh.instance_capacity);
+            queue.write_buffer(&mesh.instance_buffer, 0, bytemuck::cast_slice(&bucket[..count]));
+            mesh_draws.push((MeshHandle(i), count as u32));
+        }
+
+        (instances.len() as u32, trail_ranges, mesh_draws)
+    }
+
+    /// Upload the iso-surface triangle soup (3 positions per triangle, as
+    /// returned by [`crate::marching_cubes::extract_surface`]), deriving a
+    /// flat per-triangle normal for Lambert shading. Returns the number of
+    /// vertices to draw.
+    pub fn update_iso_surface(&self, queue: &wgpu::Queue, triangles: &[glam::Vec3]) -> u32 {
+        let vertex_count = triangles.len().min(self.max_mesh_vertices);
+
+        let vertices: Vec<MeshVertex> = triangles[..vertex_count]
+            .chunks_exact(3)
+            .flat_map(|tri| {
+                let normal = (tri[1] - tri[0]).cross(tri[2] - tri[0]).normalize_or_zero();
+                tri.iter().map(move |p| MeshVertex {
+                    position: [p.x, p.y, p.z],
+                    normal: [normal.x, normal.y, normal.z],
+                })
+            })
+            .collect();
+
+        if !vertices.is_empty() {
+            queue.write_buffer(&self.mesh_buffer, 0, bytemuck::cast_slice(&vertices));
+        }
+
+        vertices.len() as u32
+    }
+
+    /// Parse `path` as an `.obj` model and upload it as GPU buffers,
+    /// returning a handle `Body3D::with_mesh` can attach to any body that
+    /// should render as real geometry instead of a billboard impostor. A
+    /// model that fails to load gets an empty, harmless placeholder mesh
+    /// (zero index count, so `render` skips drawing it) rather than a
+    /// panic, so one bad asset path doesn't take the whole scene down.
+    pub fn load_mesh(&self, ctx: &GraphicsContext, path: &Path) -> MeshHandle {
+        let loaded = mesh_pool::load_obj(path).unwrap_or_else(|err| {
+            log::warn!("failed to load mesh {}: {err}", path.display());
+            mesh_pool::LoadedMesh { vertices: Vec::new(), indices: Vec::new() }
+        });
+
+        let device = &ctx.device;
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Body Mesh Vertex Buffer"),
+            contents: bytemuck::cast_slice(&loaded.vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Body Mesh Index Buffer"),
+            contents: bytemuck::cast_slice(&loaded.indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+        let instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Body Mesh Instance Buffer"),
+            size: (std::mem::size_of::<BodyMeshInstance>() * self.max_instances) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut body_meshes = self.body_meshes.borrow_mut();
+        body_meshes.push(BodyMesh {
+            vertex_buffer,
+            index_buffer,
+            index_count: loaded.indices.len() as u32,
+            instance_buffer,
+            instance_capacity: self.max_instances,
+        });
+
+        MeshHandle(body_meshes.len() - 1)
+    }
+
+    /// Dispatches `cs_cull` over every candidate in `instance_buffer` up to
+    /// `num_instances`, resetting `indirect_args_buffer`'s survivor count to
+    /// zero first so each frame starts from an empty `culled_instance_buffer`
+    fn cull_instances(&self, encoder: &mut wgpu::CommandEncoder, queue: &wgpu::Queue, num_instances: u32) {
+        queue.write_buffer(
+            &self.indirect_args_buffer,
+            0,
+            bytemuck::cast_slice(&[DrawIndirectArgs {
+                vertex_count: 6,
+                instance_count: 0,
+                first_vertex: 0,
+                first_instance: 0,
+            }]),
+        );
+        queue.write_buffer(
+            &self.cull_params,
+            0,
+            bytemuck::cast_slice(&[CullParams { instance_count: num_instances, _padding: [0; 3] }]),
+        );
+
+        let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Frustum Cull Pass"),
+            timestamp_writes: None,
+        });
+        compute_pass.set_pipeline(&self.cull_pipeline);
+        compute_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        compute_pass.set_bind_group(1, &self.cull_bind_group, &[]);
+        compute_pass.dispatch_workgroups(num_instances.div_ceil(64), 1, 1);
     }
 
     pub fn render(
         &self,
         encoder: &mut wgpu::CommandEncoder,
+        queue: &wgpu::Queue,
         view: &wgpu::TextureView,
         num_instances: u32,
         trail_ranges: &[(u32, u32)],
         show_grid: bool,
         show_trails: bool,
+        num_iso_vertices: u32,
+        mesh_draws: &[(MeshHandle, u32)],
     ) {
+        // Skybox/grid/trail/mesh draw into the MSAA color target (when the
+        // adapter supports it) and resolve onto the swapchain view at the
+        // end of each pass; on backends without MSAA support this just
+        // draws straight into `view` as before
+        let (scene_view, scene_resolve_target) = match &self.msaa_view {
+            Some(msaa_view) => (msaa_view, Some(view)),
+            None => (view, None),
+        };
+
         // First pass: skybox (no depth)
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Skybox Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view,
-                    resolve_target: None,
+                    view: scene_view,
+                    resolve_target: scene_resolve_target,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
                         store: wgpu::StoreOp::Store,
@@ -446,8 +1945,8 @@ impl Renderer3D {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Main 3D Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view,
-                    resolve_target: None,
+                    view: scene_view,
+                    resolve_target: scene_resolve_target,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Load,
                         store: wgpu::StoreOp::Store,
@@ -483,14 +1982,159 @@ impl Renderer3D {
                 }
             }
 
-            // Particles
-            if num_instances > 0 {
-                render_pass.set_pipeline(&self.particle_pipeline);
+            // Gravitational potential iso-surface
+            if num_iso_vertices > 0 {
+                render_pass.set_pipeline(&self.mesh_pipeline);
                 render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
-                render_pass.set_vertex_buffer(0, self.quad_buffer.slice(..));
-                render_pass.set_vertex_buffer(1, self.instance_buffer.slice(..));
-                render_pass.draw(0..6, 0..num_instances);
+                render_pass.set_vertex_buffer(0, self.mesh_buffer.slice(..));
+                render_pass.draw(0..num_iso_vertices, 0..1);
+            }
+
+            // Bodies rendered as loaded meshes instead of billboards
+            if !mesh_draws.is_empty() {
+                let body_meshes = self.body_meshes.borrow();
+                render_pass.set_pipeline(&self.body_mesh_pipeline);
+                render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+                for (handle, count) in mesh_draws {
+                    let mesh = &body_meshes[handle.0];
+                    if mesh.index_count == 0 || *count == 0 {
+                        continue;
+                    }
+                    render_pass.set_vertex_buffer(0, mesh.vertex_buffer.slice(..));
+                    render_pass.set_vertex_buffer(1, mesh.instance_buffer.slice(..));
+                    render_pass
+                        .set_index_buffer(mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+                    render_pass.draw_indexed(0..mesh.index_count, 0, 0..*count);
+                }
+            }
+        }
+
+        // Third pass: particles into the HDR offscreen target, so glowing
+        // stars and black holes can exceed 1.0 luminance instead of
+        // clipping against the swapchain's 8-bit format
+        if num_instances > 0 {
+            self.cull_instances(encoder, queue, num_instances);
+
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("HDR Particle Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.hdr_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_pipeline(&self.particle_pipeline_hdr);
+            render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+            render_pass.set_vertex_buffer(0, self.quad_buffer.slice(..));
+            render_pass.set_vertex_buffer(1, self.culled_instance_buffer.slice(..));
+            render_pass.draw_indirect(&self.indirect_args_buffer, 0);
+        }
+
+        // Fourth: resolve the HDR particle pass onto the swapchain —
+        // bright-pass threshold, two-pass separable blur, then an additive
+        // tonemap composite over the grid/trails/mesh/skybox already drawn
+        if num_instances > 0 {
+            self.composite_bloom(encoder, view);
+        }
+    }
+
+    fn composite_bloom(&self, encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
+        // Mip 0 is thresholded straight from the HDR scene; each later mip
+        // is a downsample of the previous mip's already-blurred result, so
+        // this loop must run in order for the downsample bind groups (built
+        // against the previous mip's view) to read the right data
+        for (level, mip) in self.bloom_mips.iter().enumerate() {
+            {
+                let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("Bloom Bright/Downsample Pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &mip.view_a,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                if level == 0 {
+                    pass.set_pipeline(&self.bright_pipeline);
+                    pass.set_bind_group(0, &self.bright_bind_group, &[]);
+                } else {
+                    pass.set_pipeline(&self.downsample_pipeline);
+                    pass.set_bind_group(0, &self.downsample_bind_groups[level - 1], &[]);
+                }
+                pass.draw(0..3, 0..1);
+            }
+
+            {
+                let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("Bloom Blur Pass Horizontal"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &mip.view_b,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                pass.set_pipeline(&self.blur_pipeline);
+                pass.set_bind_group(0, &mip.blur_bind_group_h, &[]);
+                pass.draw(0..3, 0..1);
+            }
+
+            {
+                let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("Bloom Blur Pass Vertical"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &mip.view_a,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                pass.set_pipeline(&self.blur_pipeline);
+                pass.set_bind_group(0, &mip.blur_bind_group_v, &[]);
+                pass.draw(0..3, 0..1);
             }
         }
+
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Tonemap Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.tonemap_pipeline);
+            pass.set_bind_group(0, &self.tonemap_bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
     }
 }
@@ -0,0 +1,210 @@
+//! Scriptable scene presets via an embedded Rhai scene API
+//!
+//! Scripts live under a `gravity_sim/scenes/` directory in the working
+//! directory. Each
+//! `.rhai` file exposes two functions:
+//!
+//! - `config()` returns a map of scene toggles (`starfield`, `grid`,
+//!   `trails`, `camera_distance`, `camera_pitch`) with any subset present;
+//!   missing keys fall back to `SceneConfig::default()`.
+//! - `init()` returns an array of body maps (`x`, `y`, `z`, `vx`, `vy`,
+//!   `vz`, `mass`, optional `color: [r,g,b,a]` and `name`) describing the
+//!   bodies to populate the scene with.
+//!
+//! This mirrors the structured-data-return pattern used by the atoms
+//! crate's scripted presets: the script never touches simulation state
+//! directly, it just describes what to build and Rust applies it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use glam::Vec3;
+use rhai::{Engine, Scope, AST};
+use thiserror::Error;
+
+use crate::physics_3d::{Body3D, Simulation3D};
+
+#[derive(Debug, Error)]
+pub enum SceneScriptError {
+    #[error("failed to read scene script {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse scene script {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: Box<rhai::ParseError>,
+    },
+    #[error("error running `{function}` in {path}: {source}")]
+    Eval {
+        path: PathBuf,
+        function: &'static str,
+        #[source]
+        source: Box<rhai::EvalAltResult>,
+    },
+}
+
+/// Scene-level toggles a script's `config()` can set
+#[derive(Debug, Clone, Copy)]
+pub struct SceneConfig {
+    pub show_starfield: bool,
+    pub show_grid: bool,
+    pub show_trails: bool,
+    pub camera_distance: f32,
+    pub camera_pitch: f32,
+}
+
+impl Default for SceneConfig {
+    fn default() -> Self {
+        Self {
+            show_starfield: true,
+            show_grid: true,
+            show_trails: true,
+            camera_distance: 40.0,
+            camera_pitch: 0.4,
+        }
+    }
+}
+
+/// A loaded `.rhai` scene script, ready to produce a `SceneConfig` and
+/// populate a `Simulation3D`
+pub struct ScenePreset {
+    pub name: String,
+    pub path: PathBuf,
+    engine: Engine,
+    ast: AST,
+}
+
+impl ScenePreset {
+    pub fn load(path: &Path) -> Result<Self, SceneScriptError> {
+        let source = fs::read_to_string(path).map_err(|source| SceneScriptError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let engine = Engine::new();
+        let ast = engine.compile(&source).map_err(|source| SceneScriptError::Parse {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })?;
+
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("scene")
+            .to_string();
+
+        Ok(Self {
+            name,
+            path: path.to_path_buf(),
+            engine,
+            ast,
+        })
+    }
+
+    /// Run the script's `config()` function, falling back to defaults for
+    /// any field it doesn't set (or if the script has no `config()` at all)
+    pub fn config(&self) -> SceneConfig {
+        let mut defaults = SceneConfig::default();
+
+        let mut scope = Scope::new();
+        let Ok(map) = self
+            .engine
+            .call_fn::<rhai::Map>(&mut scope, &self.ast, "config", ())
+        else {
+            return defaults;
+        };
+
+        if let Some(v) = map.get("starfield").and_then(|v| v.as_bool().ok()) {
+            defaults.show_starfield = v;
+        }
+        if let Some(v) = map.get("grid").and_then(|v| v.as_bool().ok()) {
+            defaults.show_grid = v;
+        }
+        if let Some(v) = map.get("trails").and_then(|v| v.as_bool().ok()) {
+            defaults.show_trails = v;
+        }
+        if let Some(v) = map.get("camera_distance").and_then(|v| v.as_float().ok()) {
+            defaults.camera_distance = v as f32;
+        }
+        if let Some(v) = map.get("camera_pitch").and_then(|v| v.as_float().ok()) {
+            defaults.camera_pitch = v as f32;
+        }
+
+        defaults
+    }
+
+    /// Run the script's `init()` function and append the bodies it
+    /// describes to `sim` (the caller clears `sim.bodies` beforehand)
+    pub fn init(&self, sim: &mut Simulation3D) -> Result<(), SceneScriptError> {
+        let mut scope = Scope::new();
+        let bodies: rhai::Array = self
+            .engine
+            .call_fn(&mut scope, &self.ast, "init", ())
+            .map_err(|source| SceneScriptError::Eval {
+                path: self.path.clone(),
+                function: "init",
+                source,
+            })?;
+
+        for entry in bodies {
+            let Some(map) = entry.try_cast::<rhai::Map>() else {
+                continue;
+            };
+            sim.bodies.push(body_from_map(map));
+        }
+
+        Ok(())
+    }
+}
+
+fn map_f64(map: &rhai::Map, key: &str, default: f32) -> f32 {
+    map.get(key).and_then(|v| v.as_float().ok()).map(|v| v as f32).unwrap_or(default)
+}
+
+fn body_from_map(map: rhai::Map) -> Body3D {
+    let position = Vec3::new(map_f64(&map, "x", 0.0), map_f64(&map, "y", 0.0), map_f64(&map, "z", 0.0));
+    let velocity = Vec3::new(map_f64(&map, "vx", 0.0), map_f64(&map, "vy", 0.0), map_f64(&map, "vz", 0.0));
+    let mass = map_f64(&map, "mass", 1.0);
+
+    let mut body = Body3D::new(position, velocity, mass);
+
+    if let Some(color) = map.get("color").and_then(|v| v.clone().try_cast::<rhai::Array>()) {
+        if color.len() == 4 {
+            let c: Vec<f32> = color.iter().map(|v| v.as_float().unwrap_or(1.0) as f32).collect();
+            body = body.with_color([c[0], c[1], c[2], c[3]]);
+        }
+    }
+
+    if let Some(name) = map.get("name").and_then(|v| v.clone().into_string().ok()) {
+        body = body.with_name(&name);
+    }
+
+    body
+}
+
+/// Discover `.rhai` scripts in `dir`, skipping (and logging) any that fail
+/// to parse rather than aborting the whole scan
+pub fn discover_scenes(dir: &Path) -> Vec<ScenePreset> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut presets = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("rhai") {
+            continue;
+        }
+        match ScenePreset::load(&path) {
+            Ok(preset) => presets.push(preset),
+            Err(err) => log::warn!("skipping scene script: {err}"),
+        }
+    }
+
+    presets.sort_by(|a, b| a.name.cmp(&b.name));
+    presets
+}
@@ -0,0 +1,244 @@
+//! Scriptable scene presets for the 2D gravity simulation
+//!
+//! Mirrors the `config()`/`init()` scene-script pattern `scene_scripts.rs`
+//! established for the 3D viewer, adapted to `Body`'s 2D (`Vec2`) state and
+//! this binary's own toggles (starfield, velocity-vector overlay, camera
+//! zoom/position). Scripts live under a `gravity_sim/scenes/` directory in
+//! the working directory. `init()` builds each body through a registered
+//! `BodyBuilder(mass, x, y, vx, vy, color)` function rather than a bare map
+//! literal, mirroring the external Galactica scripts' `SpriteBuilder` API.
+//! Scripts can also call `EmitDisk(count, central_mass, inner_radius,
+//! outer_radius)` to sample a near-equilibrium disk via
+//! `physics::sample_disk` instead of placing every body by hand.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use glam::Vec2;
+use rhai::{Array, Engine, Map, Scope, AST};
+use thiserror::Error;
+
+use crate::physics::{sample_disk, Body, EmitterConfig, Simulation};
+
+#[derive(Debug, Error)]
+pub enum SceneScriptError {
+    #[error("failed to read scene script {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse scene script {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: Box<rhai::ParseError>,
+    },
+    #[error("error running `{function}` in {path}: {source}")]
+    Eval {
+        path: PathBuf,
+        function: &'static str,
+        #[source]
+        source: Box<rhai::EvalAltResult>,
+    },
+}
+
+/// Scene-level toggles a script's `config()` can set
+#[derive(Debug, Clone, Copy)]
+pub struct SceneConfig {
+    pub show_starfield: bool,
+    pub show_velocity_vectors: bool,
+    pub camera_zoom: f32,
+    pub camera_position: Vec2,
+}
+
+impl Default for SceneConfig {
+    fn default() -> Self {
+        Self {
+            show_starfield: true,
+            show_velocity_vectors: false,
+            camera_zoom: 15.0,
+            camera_position: Vec2::ZERO,
+        }
+    }
+}
+
+/// `mass, x, y, vx, vy, color` constructor exposed to scripts as
+/// `BodyBuilder(...)`, returning a body map `init()` collects into the
+/// array it returns
+fn body_builder(mass: f64, x: f64, y: f64, vx: f64, vy: f64, color: Array) -> Map {
+    let mut map = Map::new();
+    map.insert("mass".into(), mass.into());
+    map.insert("x".into(), x.into());
+    map.insert("y".into(), y.into());
+    map.insert("vx".into(), vx.into());
+    map.insert("vy".into(), vy.into());
+    map.insert("color".into(), color.into());
+    map
+}
+
+/// `count, central_mass, inner_radius, outer_radius` constructor exposed to
+/// scripts as `EmitDisk(...)`, sampling a dynamically-near-equilibrium disk
+/// (see `physics::sample_disk`) and returning it as an array of body maps
+/// that `init()` can splice straight into the array it returns, alongside
+/// any hand-placed `BodyBuilder(...)` bodies
+fn emit_disk(count: i64, central_mass: f64, inner_radius: f64, outer_radius: f64) -> Array {
+    let config = EmitterConfig {
+        count: count.max(0) as usize,
+        central_mass: central_mass as f32,
+        inner_radius: inner_radius as f32,
+        outer_radius: outer_radius as f32,
+        ..EmitterConfig::default()
+    };
+
+    sample_disk(&config, Vec2::ZERO, Vec2::ZERO)
+        .into_iter()
+        .map(|body| {
+            let map: Map = body_builder(
+                body.mass as f64,
+                body.position.x as f64,
+                body.position.y as f64,
+                body.velocity.x as f64,
+                body.velocity.y as f64,
+                body.color.iter().map(|&c| (c as f64).into()).collect(),
+            );
+            map.into()
+        })
+        .collect()
+}
+
+/// A loaded `.rhai` scene script, ready to produce a `SceneConfig` and
+/// populate a `Simulation`
+pub struct ScenePreset {
+    pub name: String,
+    pub path: PathBuf,
+    engine: Engine,
+    ast: AST,
+}
+
+impl ScenePreset {
+    pub fn load(path: &Path) -> Result<Self, SceneScriptError> {
+        let source = fs::read_to_string(path).map_err(|source| SceneScriptError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let mut engine = Engine::new();
+        engine.register_fn("BodyBuilder", body_builder);
+        engine.register_fn("EmitDisk", emit_disk);
+
+        let ast = engine.compile(&source).map_err(|source| SceneScriptError::Parse {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })?;
+
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("scene")
+            .to_string();
+
+        Ok(Self {
+            name,
+            path: path.to_path_buf(),
+            engine,
+            ast,
+        })
+    }
+
+    /// Run the script's `config()` function, falling back to defaults for
+    /// any field it doesn't set (or if the script has no `config()` at all)
+    pub fn config(&self) -> SceneConfig {
+        let mut defaults = SceneConfig::default();
+
+        let mut scope = Scope::new();
+        let Ok(map) = self.engine.call_fn::<Map>(&mut scope, &self.ast, "config", ()) else {
+            return defaults;
+        };
+
+        if let Some(v) = map.get("starfield").and_then(|v| v.as_bool().ok()) {
+            defaults.show_starfield = v;
+        }
+        if let Some(v) = map.get("velocity_vectors").and_then(|v| v.as_bool().ok()) {
+            defaults.show_velocity_vectors = v;
+        }
+        if let Some(v) = map.get("camera_zoom").and_then(|v| v.as_float().ok()) {
+            defaults.camera_zoom = v as f32;
+        }
+        if let Some(v) = map.get("camera_x").and_then(|v| v.as_float().ok()) {
+            defaults.camera_position.x = v as f32;
+        }
+        if let Some(v) = map.get("camera_y").and_then(|v| v.as_float().ok()) {
+            defaults.camera_position.y = v as f32;
+        }
+
+        defaults
+    }
+
+    /// Run the script's `init()` function and append the bodies it
+    /// describes to `sim` (the caller clears `sim.bodies` beforehand)
+    pub fn init(&self, sim: &mut Simulation) -> Result<(), SceneScriptError> {
+        let mut scope = Scope::new();
+        let bodies: Array = self
+            .engine
+            .call_fn(&mut scope, &self.ast, "init", ())
+            .map_err(|source| SceneScriptError::Eval {
+                path: self.path.clone(),
+                function: "init",
+                source,
+            })?;
+
+        for entry in bodies {
+            let Some(map) = entry.try_cast::<Map>() else {
+                continue;
+            };
+            sim.bodies.push(body_from_map(map));
+        }
+
+        Ok(())
+    }
+}
+
+fn map_f64(map: &Map, key: &str, default: f32) -> f32 {
+    map.get(key).and_then(|v| v.as_float().ok()).map(|v| v as f32).unwrap_or(default)
+}
+
+fn body_from_map(map: Map) -> Body {
+    let position = Vec2::new(map_f64(&map, "x", 0.0), map_f64(&map, "y", 0.0));
+    let velocity = Vec2::new(map_f64(&map, "vx", 0.0), map_f64(&map, "vy", 0.0));
+    let mass = map_f64(&map, "mass", 1.0);
+
+    let mut body = Body::new(position, velocity, mass);
+
+    if let Some(color) = map.get("color").and_then(|v| v.clone().try_cast::<Array>()) {
+        if color.len() == 4 {
+            let c: Vec<f32> = color.iter().map(|v| v.as_float().unwrap_or(1.0) as f32).collect();
+            body.color = [c[0], c[1], c[2], c[3]];
+        }
+    }
+
+    body
+}
+
+/// Discover `.rhai` scripts in `dir`, skipping (and logging) any that fail
+/// to parse rather than aborting the whole scan
+pub fn discover_scenes(dir: &Path) -> Vec<ScenePreset> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut presets = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("rhai") {
+            continue;
+        }
+        match ScenePreset::load(&path) {
+            Ok(preset) => presets.push(preset),
+            Err(err) => log::warn!("skipping scene script: {err}"),
+        }
+    }
+
+    presets.sort_by(|a, b| a.name.cmp(&b.name));
+    presets
+}
@@ -0,0 +1,143 @@
+//! Simulation snapshot save/load
+//!
+//! Captures the live body state (position, velocity, mass, radius, color),
+//! `time_scale`, and camera pose to a RON file, so an interesting emergent
+//! configuration — a captured mid-collision state — becomes a reproducible
+//! starting point instead of something reachable only by waiting for it
+//! again.
+
+use std::path::Path;
+
+use common::Camera3D;
+use glam::Vec3;
+use serde::{Deserialize, Serialize};
+
+use crate::physics_3d::{Body3D, Simulation3D};
+
+/// Bumped whenever the on-disk layout changes; [`Snapshot::load_from_file`]
+/// rejects a file whose `version` doesn't match rather than guessing at a
+/// migration.
+const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BodySnapshot {
+    position: [f32; 3],
+    velocity: [f32; 3],
+    mass: f32,
+    radius: f32,
+    color: [f32; 4],
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CameraSnapshot {
+    distance: f32,
+    yaw: f32,
+    pitch: f32,
+    target: [f32; 3],
+}
+
+/// A versioned capture of everything needed to resume a simulation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    version: u32,
+    bodies: Vec<BodySnapshot>,
+    time_scale: f32,
+    camera: CameraSnapshot,
+}
+
+/// Errors that can occur while saving or loading a snapshot
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("failed to read snapshot file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to parse snapshot file: {0}")]
+    Parse(#[from] ron::de::SpannedError),
+    #[error("failed to serialize snapshot: {0}")]
+    Serialize(#[from] ron::Error),
+    #[error("snapshot format version {found} unsupported (expected {expected})")]
+    VersionMismatch { found: u32, expected: u32 },
+}
+
+impl Snapshot {
+    /// Capture the current body state, time scale, and camera pose
+    pub fn capture(sim: &Simulation3D, camera: &Camera3D) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            bodies: sim
+                .bodies
+                .iter()
+                .map(|body| BodySnapshot {
+                    position: body.position.to_array(),
+                    velocity: body.velocity.to_array(),
+                    mass: body.mass,
+                    radius: body.radius,
+                    color: body.color,
+                    name: body.name.clone(),
+                })
+                .collect(),
+            time_scale: sim.time_scale,
+            camera: CameraSnapshot {
+                distance: camera.distance,
+                yaw: camera.yaw(),
+                pitch: camera.pitch(),
+                target: camera.target.to_array(),
+            },
+        }
+    }
+
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        let text = std::fs::read_to_string(path)?;
+        let snapshot: Self = ron::de::from_str(&text)?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::VersionMismatch {
+                found: snapshot.version,
+                expected: SNAPSHOT_VERSION,
+            });
+        }
+        Ok(snapshot)
+    }
+
+    /// Rebuild a `Simulation3D` with this snapshot's bodies and time scale
+    pub fn to_simulation(&self) -> Simulation3D {
+        let mut sim = Simulation3D::new();
+        sim.time_scale = self.time_scale;
+        sim.bodies = self
+            .bodies
+            .iter()
+            .map(|body| {
+                let mut rebuilt = Body3D::new(
+                    Vec3::from_array(body.position),
+                    Vec3::from_array(body.velocity),
+                    body.mass,
+                )
+                .with_color(body.color)
+                .with_radius(body.radius);
+                if let Some(name) = &body.name {
+                    rebuilt = rebuilt.with_name(name);
+                }
+                rebuilt
+            })
+            .collect();
+        sim
+    }
+
+    /// Apply this snapshot's camera pose to `camera`, re-deriving `position`
+    /// from the restored orbital parameters
+    pub fn apply_camera(&self, camera: &mut Camera3D) {
+        camera.distance = self.camera.distance;
+        camera.set_yaw_pitch(self.camera.yaw, self.camera.pitch);
+        camera.target = Vec3::from_array(self.camera.target);
+        camera.update_orbital();
+    }
+}
@@ -0,0 +1,224 @@
+//! Scripted 4D-visualization choreography via an embedded Rhai engine
+//!
+//! Mirrors the "script describes, Rust applies" philosophy `scene_scripts.rs`
+//! established for the gravity viewer, adapted from a one-shot description to
+//! a per-frame one: rather than handing a script a live handle onto
+//! `Hypercube4DSimulation` (which would let a runaway script reach into
+//! fields `App` never expected to change mid-frame), the functions below are
+//! registered as plain Rhai functions that push a [`Command`] onto a shared
+//! queue. `App::update` calls the script's `on_frame(t, dt)` hook once per
+//! frame and then drains the queue, applying each command to the simulation
+//! and camera it actually owns. The script itself never touches simulation
+//! state directly — it only describes, frame by frame, what should happen —
+//! and since Rhai has no file/network access by default and this is the only
+//! state it can reach at all, a script can't do anything beyond that small
+//! vocabulary.
+//!
+//! A script is a `.rhai` file with an `on_frame(t, dt)` function, where `t` is
+//! seconds since the script was loaded and `dt` is the current frame's delta
+//! time, e.g. a scripted demo that spins a 24-cell in XW for 3 seconds, morphs
+//! to a 5-cell, then orbits the camera:
+//!
+//! ```ignore
+//! fn on_frame(t, dt) {
+//!     if t < 3.0 {
+//!         rotate_xw(dt * 0.8);
+//!     } else if t < 3.2 {
+//!         set_polytope("5_cell");
+//!     } else {
+//!         orbit_camera(dt * 0.2, 0.0);
+//!     }
+//! }
+//! ```
+//!
+//! Passed on the command line and hot-reloaded on save via
+//! [`ChoreographyScript::reload_if_changed`]; when no script is loaded, `App`
+//! falls back to its normal interactive key handling.
+
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::SystemTime;
+
+use rhai::{Engine, Scope, AST};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ChoreographyError {
+    #[error("failed to read choreography script {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse choreography script {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: Box<rhai::ParseError>,
+    },
+}
+
+/// One of the six coordinate planes a scripted rotation can turn in, mirroring
+/// [`crate::hypercube::Plane4D`] without depending on Rhai's dynamic typing to
+/// carry it across the script boundary — scripts call one of six distinct
+/// `rotate_*` functions instead, each pushing the matching variant.
+#[derive(Debug, Clone, Copy)]
+pub enum RotationPlane {
+    Xy,
+    Xz,
+    Xw,
+    Yz,
+    Yw,
+    Zw,
+}
+
+/// A command queued by a registered Rhai function, drained and applied by the
+/// caller once per frame
+#[derive(Debug, Clone)]
+pub enum Command {
+    SetPolytope(String),
+    RotatePlane(RotationPlane, f32),
+    SetAutoRotate(bool),
+    SetPerspective(bool),
+    SetIsoclinic(bool),
+    OrbitCamera(f32, f32),
+    ZoomCamera(f32),
+}
+
+fn register_commands(engine: &mut Engine, queue: &Rc<RefCell<Vec<Command>>>) {
+    let q = queue.clone();
+    engine.register_fn("set_polytope", move |name: &str| {
+        q.borrow_mut().push(Command::SetPolytope(name.to_string()));
+    });
+
+    let q = queue.clone();
+    engine.register_fn("rotate_xy", move |angle: f64| {
+        q.borrow_mut().push(Command::RotatePlane(RotationPlane::Xy, angle as f32));
+    });
+    let q = queue.clone();
+    engine.register_fn("rotate_xz", move |angle: f64| {
+        q.borrow_mut().push(Command::RotatePlane(RotationPlane::Xz, angle as f32));
+    });
+    let q = queue.clone();
+    engine.register_fn("rotate_xw", move |angle: f64| {
+        q.borrow_mut().push(Command::RotatePlane(RotationPlane::Xw, angle as f32));
+    });
+    let q = queue.clone();
+    engine.register_fn("rotate_yz", move |angle: f64| {
+        q.borrow_mut().push(Command::RotatePlane(RotationPlane::Yz, angle as f32));
+    });
+    let q = queue.clone();
+    engine.register_fn("rotate_yw", move |angle: f64| {
+        q.borrow_mut().push(Command::RotatePlane(RotationPlane::Yw, angle as f32));
+    });
+    let q = queue.clone();
+    engine.register_fn("rotate_zw", move |angle: f64| {
+        q.borrow_mut().push(Command::RotatePlane(RotationPlane::Zw, angle as f32));
+    });
+
+    let q = queue.clone();
+    engine.register_fn("set_auto_rotate", move |enabled: bool| {
+        q.borrow_mut().push(Command::SetAutoRotate(enabled));
+    });
+    let q = queue.clone();
+    engine.register_fn("set_perspective", move |enabled: bool| {
+        q.borrow_mut().push(Command::SetPerspective(enabled));
+    });
+    let q = queue.clone();
+    engine.register_fn("set_isoclinic", move |enabled: bool| {
+        q.borrow_mut().push(Command::SetIsoclinic(enabled));
+    });
+
+    let q = queue.clone();
+    engine.register_fn("orbit_camera", move |delta_yaw: f64, delta_pitch: f64| {
+        q.borrow_mut().push(Command::OrbitCamera(delta_yaw as f32, delta_pitch as f32));
+    });
+    let q = queue.clone();
+    engine.register_fn("zoom_camera", move |delta: f64| {
+        q.borrow_mut().push(Command::ZoomCamera(delta as f32));
+    });
+}
+
+/// A loaded `.rhai` choreography script, driving the 4D visualizer frame by
+/// frame through its `on_frame(t, dt)` hook
+pub struct ChoreographyScript {
+    path: PathBuf,
+    last_modified: SystemTime,
+    engine: Engine,
+    ast: AST,
+    queue: Rc<RefCell<Vec<Command>>>,
+    elapsed: f32,
+}
+
+impl ChoreographyScript {
+    pub fn load(path: &Path) -> Result<Self, ChoreographyError> {
+        let source = fs::read_to_string(path).map_err(|source| ChoreographyError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let last_modified = fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+
+        let mut engine = Engine::new();
+        let queue = Rc::new(RefCell::new(Vec::new()));
+        register_commands(&mut engine, &queue);
+
+        let ast = engine.compile(&source).map_err(|source| ChoreographyError::Parse {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            last_modified,
+            engine,
+            ast,
+            queue,
+            elapsed: 0.0,
+        })
+    }
+
+    /// Re-load from disk if the file's mtime has advanced since the last
+    /// load, so a script can be edited and saved while the app is running
+    /// and pick up the change on the very next frame, with no filesystem
+    /// watcher or extra dependency required
+    pub fn reload_if_changed(&mut self) {
+        let Ok(modified) = fs::metadata(&self.path).and_then(|metadata| metadata.modified()) else {
+            return;
+        };
+        if modified <= self.last_modified {
+            return;
+        }
+
+        match Self::load(&self.path) {
+            Ok(fresh) => {
+                log::info!("reloaded choreography script {}", self.path.display());
+                *self = fresh;
+            }
+            Err(err) => log::warn!("not reloading choreography script, still has errors: {err}"),
+        }
+    }
+
+    /// Advance by `dt`, call the script's `on_frame(t, dt)` hook (if the
+    /// script doesn't define one, nothing is queued and the failed call is
+    /// logged as a warning), and return the commands it queued this frame
+    /// for the caller to apply
+    pub fn advance(&mut self, dt: f32) -> Vec<Command> {
+        self.elapsed += dt;
+
+        let mut scope = Scope::new();
+        if let Err(err) = self.engine.call_fn::<()>(
+            &mut scope,
+            &self.ast,
+            "on_frame",
+            (self.elapsed as f64, dt as f64),
+        ) {
+            log::warn!("error running on_frame in {}: {}", self.path.display(), err);
+        }
+
+        self.queue.borrow_mut().drain(..).collect()
+    }
+}
@@ -0,0 +1,199 @@
+//! State-consistency checking for qubit registers
+//!
+//! Ports the idea behind Herwig's `BasicConsistency` pass — which verifies
+//! momentum, charge and other conservation laws against separate absolute
+//! and relative tolerances before accepting an event — to the quantum-state
+//! types. Gate circuits built from [`crate::quantum_state`] can silently
+//! drift away from unitarity through repeated `normalize()` calls; a
+//! [`ConsistencyChecker`] catches that drift and reports exactly which
+//! invariant failed and by how much.
+
+use crate::quantum_state::{Qubit, ThreeQubit, TwoQubit};
+use crate::wavefunction::Complex;
+
+/// A single invariant violation, with the measured deviation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub invariant: &'static str,
+    pub deviation: f32,
+    pub tolerance: f32,
+}
+
+/// Result of running a [`ConsistencyChecker`] over a state
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConsistencyReport {
+    pub violations: Vec<Violation>,
+}
+
+impl ConsistencyReport {
+    pub fn is_consistent(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Checks quantum states for conservation-law violations within configurable
+/// absolute and relative tolerances
+#[derive(Debug, Clone, Copy)]
+pub struct ConsistencyChecker {
+    /// Absolute tolerance, compared directly against the deviation
+    pub abs_tol: f32,
+    /// Relative tolerance, compared against deviation / expected magnitude
+    pub rel_tol: f32,
+    /// Check that total measurement probability sums to 1
+    pub check_normalization: bool,
+    /// Check that probability components are non-negative
+    pub check_non_negative: bool,
+}
+
+impl ConsistencyChecker {
+    pub fn new(abs_tol: f32, rel_tol: f32) -> Self {
+        Self {
+            abs_tol,
+            rel_tol,
+            check_normalization: true,
+            check_non_negative: true,
+        }
+    }
+
+    /// Whether a deviation from an expected value of 1.0 passes tolerance
+    fn within_tolerance(&self, deviation: f32) -> bool {
+        deviation.abs() <= self.abs_tol || deviation.abs() <= self.rel_tol
+    }
+
+    fn check_total_probability(&self, total: f32, report: &mut ConsistencyReport) {
+        if !self.check_normalization {
+            return;
+        }
+        let deviation = (total - 1.0).abs();
+        if !self.within_tolerance(deviation) {
+            report.violations.push(Violation {
+                invariant: "total probability sums to 1",
+                deviation,
+                tolerance: self.abs_tol.max(self.rel_tol),
+            });
+        }
+    }
+
+    fn check_probabilities_non_negative(&self, probs: &[f32], report: &mut ConsistencyReport) {
+        if !self.check_non_negative {
+            return;
+        }
+        if let Some(&min) = probs.iter().min_by(|a, b| a.partial_cmp(b).unwrap()) {
+            if min < -self.abs_tol {
+                report.violations.push(Violation {
+                    invariant: "probability components are non-negative",
+                    deviation: -min,
+                    tolerance: self.abs_tol,
+                });
+            }
+        }
+    }
+
+    /// Validate a single qubit
+    pub fn check_qubit(&self, q: &Qubit) -> ConsistencyReport {
+        let mut report = ConsistencyReport::default();
+        self.check_total_probability(q.prob_zero() + q.prob_one(), &mut report);
+        self.check_probabilities_non_negative(&[q.prob_zero(), q.prob_one()], &mut report);
+        report
+    }
+
+    /// Validate a two-qubit state
+    pub fn check_two_qubit(&self, state: &TwoQubit) -> ConsistencyReport {
+        let mut report = ConsistencyReport::default();
+        let probs = state.probabilities();
+        self.check_total_probability(probs.iter().sum(), &mut report);
+        self.check_probabilities_non_negative(&probs, &mut report);
+        report
+    }
+
+    /// Validate a three-qubit state
+    pub fn check_three_qubit(&self, state: &ThreeQubit) -> ConsistencyReport {
+        let mut report = ConsistencyReport::default();
+        let probs = state.probabilities();
+        self.check_total_probability(probs.iter().sum(), &mut report);
+        self.check_probabilities_non_negative(&probs, &mut report);
+        report
+    }
+
+    /// Check that a 2x2 gate matrix is unitary: ‖U†U − I‖ within tolerance
+    pub fn check_unitary_2x2(&self, gate: &[[Complex; 2]; 2]) -> ConsistencyReport {
+        let mut report = ConsistencyReport::default();
+
+        let mut max_deviation: f32 = 0.0;
+        for i in 0..2 {
+            for j in 0..2 {
+                // (U†U)_ij = sum_k conj(U_ki) * U_kj
+                let mut sum = Complex::ZERO;
+                for k in 0..2 {
+                    sum = sum + gate[k][i].conj() * gate[k][j];
+                }
+                let expected = if i == j { Complex::ONE } else { Complex::ZERO };
+                let deviation = (sum - expected).norm();
+                max_deviation = max_deviation.max(deviation);
+            }
+        }
+
+        if !self.within_tolerance(max_deviation) {
+            report.violations.push(Violation {
+                invariant: "gate is unitary (‖U†U − I‖)",
+                deviation: max_deviation,
+                tolerance: self.abs_tol.max(self.rel_tol),
+            });
+        }
+
+        report
+    }
+}
+
+impl Default for ConsistencyChecker {
+    fn default() -> Self {
+        Self::new(1e-4, 1e-4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantum_state::Qubit;
+
+    #[test]
+    fn normalized_qubit_passes() {
+        let checker = ConsistencyChecker::default();
+        let report = checker.check_qubit(&Qubit::plus());
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn denormalized_qubit_flags_violation() {
+        let checker = ConsistencyChecker::default();
+        let skewed = Qubit {
+            alpha: Qubit::ZERO.alpha * 2.0,
+            beta: Qubit::ZERO.beta,
+        };
+        let report = checker.check_qubit(&skewed);
+        assert!(!report.is_consistent());
+        assert_eq!(report.violations[0].invariant, "total probability sums to 1");
+    }
+
+    #[test]
+    fn identity_gate_is_unitary() {
+        let checker = ConsistencyChecker::default();
+        let identity = [
+            [Complex::ONE, Complex::ZERO],
+            [Complex::ZERO, Complex::ONE],
+        ];
+        let report = checker.check_unitary_2x2(&identity);
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn non_unitary_gate_flags_violation() {
+        let checker = ConsistencyChecker::default();
+        let scaled = [
+            [Complex::new(2.0, 0.0), Complex::ZERO],
+            [Complex::ZERO, Complex::ONE],
+        ];
+        let report = checker.check_unitary_2x2(&scaled);
+        assert!(!report.is_consistent());
+    }
+}
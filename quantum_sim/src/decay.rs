@@ -0,0 +1,281 @@
+//! Hadron decay channels and phase-space sampling
+//!
+//! Inspired by Herwig's meson decayers (e.g. `OniumToOniumPiPiDecayer`),
+//! which pick a decay channel by weight, sample final-state kinematics from
+//! phase space, and weight by a matrix element. A [`Hadron`] carries a table
+//! of [`DecayChannel`]s; `sample_decay` selects one proportional to its
+//! branching ratio and generates daughter momenta via two- or three-body
+//! phase space.
+
+use glam::Vec3;
+use rand::Rng;
+use std::f32::consts::PI;
+
+use crate::quarks::HadronType;
+
+/// A daughter particle produced by a decay
+#[derive(Debug, Clone)]
+pub struct DecayProduct {
+    pub name: &'static str,
+    pub mass: f32,
+    pub momentum: Vec3,
+    pub energy: f32,
+}
+
+/// One possible decay channel for a hadron
+#[derive(Debug, Clone)]
+pub struct DecayChannel {
+    pub daughters: Vec<(&'static str, f32)>, // (name, mass)
+    pub branching_ratio: f32,
+    /// Simple matrix-element weight used to reject/accept sampled kinematics
+    pub matrix_element_weight: fn(&[DecayProduct]) -> f32,
+}
+
+/// A hadron with a table of decay channels
+pub struct Hadron {
+    pub hadron_type: HadronType,
+    pub mass: f32,
+    pub channels: Vec<DecayChannel>,
+}
+
+fn uniform_weight(_products: &[DecayProduct]) -> f32 {
+    1.0
+}
+
+impl Hadron {
+    /// Build the decay table for a known hadron type (scaled masses/BRs)
+    pub fn new(hadron_type: HadronType) -> Self {
+        let (mass, channels) = match hadron_type {
+            HadronType::PionZero => (
+                0.135,
+                vec![DecayChannel {
+                    daughters: vec![("γ", 0.0), ("γ", 0.0)],
+                    branching_ratio: 1.0,
+                    matrix_element_weight: uniform_weight,
+                }],
+            ),
+            HadronType::PionPlus => (
+                0.139,
+                vec![DecayChannel {
+                    daughters: vec![("μ+", 0.106), ("νμ", 0.0)],
+                    branching_ratio: 1.0,
+                    matrix_element_weight: uniform_weight,
+                }],
+            ),
+            HadronType::PionMinus => (
+                0.139,
+                vec![DecayChannel {
+                    daughters: vec![("μ-", 0.106), ("ν̄μ", 0.0)],
+                    branching_ratio: 1.0,
+                    matrix_element_weight: uniform_weight,
+                }],
+            ),
+            HadronType::Jpsi => (
+                3.097,
+                vec![
+                    DecayChannel {
+                        daughters: vec![("e+", 0.0005), ("e-", 0.0005)],
+                        branching_ratio: 0.06,
+                        matrix_element_weight: uniform_weight,
+                    },
+                    DecayChannel {
+                        daughters: vec![("π+", 0.139), ("π-", 0.139), ("π0", 0.135)],
+                        branching_ratio: 0.94,
+                        matrix_element_weight: dalitz_phasespace_weight,
+                    },
+                ],
+            ),
+            HadronType::Kaon => (
+                0.494,
+                vec![DecayChannel {
+                    daughters: vec![("μ+", 0.106), ("νμ", 0.0)],
+                    branching_ratio: 1.0,
+                    matrix_element_weight: uniform_weight,
+                }],
+            ),
+            // Protons, neutrons, and their antiparticles are effectively
+            // stable on simulation timescales
+            HadronType::Proton | HadronType::Neutron | HadronType::AntiProton | HadronType::AntiNeutron => {
+                (0.938, Vec::new())
+            }
+        };
+
+        Self { hadron_type, mass, channels }
+    }
+
+    /// Select a channel proportional to branching ratio and sample daughter
+    /// kinematics from phase space, weighted by the channel's matrix element
+    pub fn sample_decay(&self, rng: &mut impl Rng) -> Option<Vec<DecayProduct>> {
+        if self.channels.is_empty() {
+            return None;
+        }
+
+        let total: f32 = self.channels.iter().map(|c| c.branching_ratio).sum();
+        let mut roll = rng.gen::<f32>() * total;
+        let channel = self
+            .channels
+            .iter()
+            .find(|c| {
+                roll -= c.branching_ratio;
+                roll <= 0.0
+            })
+            .unwrap_or_else(|| self.channels.last().unwrap());
+
+        match channel.daughters.len() {
+            2 => Some(two_body_decay(self.mass, channel, rng)),
+            3 => Some(three_body_decay(self.mass, channel, rng)),
+            _ => None,
+        }
+    }
+}
+
+/// Two-body phase space: back-to-back momenta with magnitude fixed by the
+/// relativistic two-body decay formula
+fn two_body_decay(parent_mass: f32, channel: &DecayChannel, rng: &mut impl Rng) -> Vec<DecayProduct> {
+    let (name_a, m_a) = channel.daughters[0];
+    let (name_b, m_b) = channel.daughters[1];
+
+    // |p| = sqrt(lambda(M^2, m_a^2, m_b^2)) / (2M), Källén triangle function
+    let m2 = parent_mass * parent_mass;
+    let lambda = (m2 - (m_a + m_b).powi(2)) * (m2 - (m_a - m_b).powi(2));
+    let p_mag = (lambda.max(0.0)).sqrt() / (2.0 * parent_mass);
+
+    let dir = random_direction(rng);
+
+    vec![
+        DecayProduct {
+            name: name_a,
+            mass: m_a,
+            momentum: dir * p_mag,
+            energy: (p_mag * p_mag + m_a * m_a).sqrt(),
+        },
+        DecayProduct {
+            name: name_b,
+            mass: m_b,
+            momentum: -dir * p_mag,
+            energy: (p_mag * p_mag + m_b * m_b).sqrt(),
+        },
+    ]
+}
+
+/// Three-body phase space: sample the Dalitz invariant m12² uniformly
+/// between its kinematic limits, build the momenta from it, and
+/// accept/reject against the channel's matrix element
+fn three_body_decay(parent_mass: f32, channel: &DecayChannel, rng: &mut impl Rng) -> Vec<DecayProduct> {
+    let masses: Vec<f32> = channel.daughters.iter().map(|(_, m)| *m).collect();
+    let m_sum: f32 = masses.iter().sum();
+
+    loop {
+        // Dalitz variable m12^2, sampled uniformly between its kinematic
+        // limits; m23^2 follows from the random decay directions chosen in
+        // `assign_three_body_momenta`
+        let m12_min = (masses[0] + masses[1]).powi(2);
+        let m12_max = (parent_mass - masses[2]).powi(2);
+        let m12_sq = rng.gen_range(m12_min..m12_max.max(m12_min + 1e-6));
+
+        if m_sum > parent_mass {
+            // Kinematically forbidden; fall back to a degenerate split
+            return degenerate_three_body(parent_mass, channel, rng);
+        }
+
+        let products = assign_three_body_momenta(parent_mass, channel, m12_sq, rng);
+        let weight = (channel.matrix_element_weight)(&products);
+
+        if rng.gen::<f32>() < weight.clamp(0.0, 1.0) {
+            return products;
+        }
+    }
+}
+
+/// Split the parent into the fictitious "12" system and particle 3 (a
+/// two-body decay at `sqrt(m12_sq)` vs `m3`), then boost particles 1 and 2 —
+/// split apart in the "12" system's own rest frame — back into the parent
+/// frame. This is the standard sequential two-body construction for
+/// three-body phase space: it's what ties the directions of 1 and 2 to the
+/// sampled Dalitz invariant `m12_sq` instead of drawing them independently,
+/// so the three returned energies/momenta are mutually consistent and sum to
+/// `parent_mass` exactly (up to the mass-shell floors clamped below).
+fn assign_three_body_momenta(
+    parent_mass: f32,
+    channel: &DecayChannel,
+    m12_sq: f32,
+    rng: &mut impl Rng,
+) -> Vec<DecayProduct> {
+    let m1 = channel.daughters[0].1;
+    let m2 = channel.daughters[1].1;
+    let m3 = channel.daughters[2].1;
+    let m12 = m12_sq.sqrt().max(m1 + m2);
+
+    // Step 1: parent -> "12" + 3, a two-body decay with |p| from the Källén
+    // triangle function
+    let lambda_12 = (parent_mass * parent_mass - (m12 + m3).powi(2))
+        * (parent_mass * parent_mass - (m12 - m3).powi(2));
+    let p12 = (lambda_12.max(0.0)).sqrt() / (2.0 * parent_mass);
+    let e12 = (p12 * p12 + m12 * m12).sqrt();
+    let e3 = (p12 * p12 + m3 * m3).sqrt();
+
+    let dir12 = random_direction(rng);
+    let mom12 = dir12 * p12;
+    let mom3 = -mom12;
+
+    // Step 2: "12" -> 1 + 2 in the "12" system's own rest frame
+    let lambda_1 = (m12_sq - (m1 + m2).powi(2)) * (m12_sq - (m1 - m2).powi(2));
+    let p_star = (lambda_1.max(0.0)).sqrt() / (2.0 * m12);
+    let e1_star = (p_star * p_star + m1 * m1).sqrt();
+    let e2_star = (p_star * p_star + m2 * m2).sqrt();
+
+    let dir_star = random_direction(rng);
+    let mom1_star = dir_star * p_star;
+    let mom2_star = -mom1_star;
+
+    // Boost 1 and 2 from the "12" rest frame into the parent frame, along
+    // the "12" system's own direction of travel there
+    let beta12 = p12 / e12;
+    let (mom1, e1) = boost(mom1_star, e1_star, dir12, beta12);
+    let (mom2, e2) = boost(mom2_star, e2_star, dir12, beta12);
+
+    vec![
+        DecayProduct { name: channel.daughters[0].0, mass: m1, momentum: mom1, energy: e1 },
+        DecayProduct { name: channel.daughters[1].0, mass: m2, momentum: mom2, energy: e2 },
+        DecayProduct { name: channel.daughters[2].0, mass: m3, momentum: mom3, energy: e3 },
+    ]
+}
+
+/// Boost a 4-momentum `(mom_star, e_star)`, given in a frame moving with
+/// speed `beta` (as a fraction of c) along `boost_dir` relative to the
+/// target frame, into that target frame
+fn boost(mom_star: Vec3, e_star: f32, boost_dir: Vec3, beta: f32) -> (Vec3, f32) {
+    let gamma = 1.0 / (1.0 - beta * beta).max(1e-12).sqrt();
+    let p_parallel_star = mom_star.dot(boost_dir);
+    let p_perp = mom_star - p_parallel_star * boost_dir;
+
+    let e = gamma * (e_star + beta * p_parallel_star);
+    let p_parallel = gamma * (p_parallel_star + beta * e_star);
+
+    (p_perp + p_parallel * boost_dir, e)
+}
+
+fn degenerate_three_body(parent_mass: f32, channel: &DecayChannel, rng: &mut impl Rng) -> Vec<DecayProduct> {
+    let share = parent_mass / 3.0;
+    (0..3)
+        .map(|i| {
+            let (name, mass) = channel.daughters[i];
+            DecayProduct { name, mass, momentum: random_direction(rng) * 0.01, energy: share }
+        })
+        .collect()
+}
+
+/// Matrix-element weight for the J/ψ → π+π-π0 channel: falls off away from
+/// the Dalitz-plot center, mimicking a simple phase-space suppression
+fn dalitz_phasespace_weight(products: &[DecayProduct]) -> f32 {
+    let total_p: f32 = products.iter().map(|p| p.momentum.length()).sum();
+    (total_p / 3.0).min(1.0)
+}
+
+/// Uniformly distributed unit direction
+fn random_direction(rng: &mut impl Rng) -> Vec3 {
+    let cos_theta = rng.gen_range(-1.0f32..1.0);
+    let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+    let phi = rng.gen_range(0.0..2.0 * PI);
+    Vec3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta)
+}
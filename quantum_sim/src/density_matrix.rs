@@ -0,0 +1,103 @@
+//! Density matrix representation of mixed quantum states
+//!
+//! Pure-state types like [`crate::quantum_state::Qubit`] cannot represent
+//! statistical mixtures (e.g. a noisy channel output, or the reduced state
+//! of one half of an entangled pair). `DensityMatrix` stores the full ρ and
+//! provides partial trace and fidelity, which the teleportation simulation
+//! needs to report how closely Bob's qubit matches what Alice sent.
+
+use crate::quantum_state::Qubit;
+use crate::wavefunction::Complex;
+
+/// An `n`-qubit density matrix, stored as a 2ⁿ×2ⁿ row-major array
+#[derive(Debug, Clone)]
+pub struct DensityMatrix {
+    pub dim: usize,
+    pub entries: Vec<Complex>,
+}
+
+impl DensityMatrix {
+    /// Pure state density matrix ρ = |ψ⟩⟨ψ| for a single qubit
+    pub fn from_qubit(q: &Qubit) -> Self {
+        Self::from_pure_state(&[q.alpha, q.beta])
+    }
+
+    /// Pure state density matrix ρ = |ψ⟩⟨ψ| for an arbitrary amplitude vector
+    pub fn from_pure_state(amplitudes: &[Complex]) -> Self {
+        let dim = amplitudes.len();
+        let mut entries = vec![Complex::ZERO; dim * dim];
+        for i in 0..dim {
+            for j in 0..dim {
+                entries[i * dim + j] = amplitudes[i] * amplitudes[j].conj();
+            }
+        }
+        Self { dim, entries }
+    }
+
+    /// Statistical mixture Σ pᵢ |ψᵢ⟩⟨ψᵢ| of several pure states
+    pub fn mixture(states: &[(f32, Vec<Complex>)]) -> Self {
+        let dim = states.first().map(|(_, amps)| amps.len()).unwrap_or(0);
+        let mut entries = vec![Complex::ZERO; dim * dim];
+        for (p, amps) in states {
+            for i in 0..dim {
+                for j in 0..dim {
+                    entries[i * dim + j] = entries[i * dim + j] + amps[i] * amps[j].conj() * *p;
+                }
+            }
+        }
+        Self { dim, entries }
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Complex {
+        self.entries[i * self.dim + j]
+    }
+
+    /// Trace of the matrix (should be 1 for a normalized state)
+    pub fn trace(&self) -> f32 {
+        (0..self.dim).map(|i| self.get(i, i).re).sum()
+    }
+
+    /// Reduced density matrix of a two-qubit state, tracing out one qubit
+    ///
+    /// `trace_out` selects which qubit (0 = first/most significant, 1 =
+    /// second) is discarded.
+    pub fn partial_trace_two_qubit(&self, trace_out: usize) -> DensityMatrix {
+        assert_eq!(self.dim, 4, "partial_trace_two_qubit expects a 4x4 matrix");
+
+        let mut entries = vec![Complex::ZERO; 4];
+        for keep_i in 0..2 {
+            for keep_j in 0..2 {
+                let mut sum = Complex::ZERO;
+                for traced in 0..2 {
+                    let (i, j) = if trace_out == 0 {
+                        (traced * 2 + keep_i, traced * 2 + keep_j)
+                    } else {
+                        (keep_i * 2 + traced, keep_j * 2 + traced)
+                    };
+                    sum = sum + self.get(i, j);
+                }
+                entries[keep_i * 2 + keep_j] = sum;
+            }
+        }
+
+        DensityMatrix { dim: 2, entries }
+    }
+
+    /// State fidelity F = ⟨ψ|ρ|ψ⟩ between this density matrix and a pure
+    /// reference state — used to verify teleportation correctness
+    pub fn fidelity_with_pure_state(&self, amplitudes: &[Complex]) -> f32 {
+        assert_eq!(amplitudes.len(), self.dim);
+        let mut sum = Complex::ZERO;
+        for i in 0..self.dim {
+            for j in 0..self.dim {
+                sum = sum + amplitudes[i].conj() * self.get(i, j) * amplitudes[j];
+            }
+        }
+        sum.re.clamp(0.0, 1.0)
+    }
+
+    /// Fidelity between a single qubit's density matrix and a reference qubit
+    pub fn qubit_fidelity(&self, reference: &Qubit) -> f32 {
+        self.fidelity_with_pure_state(&[reference.alpha, reference.beta])
+    }
+}
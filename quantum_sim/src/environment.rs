@@ -0,0 +1,205 @@
+//! Equirectangular HDR environment maps converted to cubemaps on the GPU
+//!
+//! Follows the learn-wgpu equirect-to-cubemap approach: the source `.hdr`
+//! image is uploaded as a plain 2D texture, then a compute shader writes
+//! each of the 6 cube faces by reconstructing a world direction per texel,
+//! converting it to equirectangular UV, and sampling the source with
+//! `textureLoad`.
+
+use std::path::Path;
+use wgpu::util::DeviceExt;
+
+/// Errors that can occur while loading or converting an environment map
+#[derive(Debug, thiserror::Error)]
+pub enum EnvironmentError {
+    #[error("failed to read HDR image: {0}")]
+    Image(#[from] image::ImageError),
+}
+
+/// A cubemap environment map ready to be bound as a skybox background
+pub struct EnvironmentMap {
+    pub(crate) cubemap_view: wgpu::TextureView,
+    pub(crate) sampler: wgpu::Sampler,
+}
+
+/// Converts equirectangular `.hdr` images into cubemaps via a compute shader
+pub struct EquirectToCubemap {
+    pipeline: wgpu::ComputePipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    face_size: u32,
+}
+
+impl EquirectToCubemap {
+    pub fn new(device: &wgpu::Device, face_size: u32) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Equirect To Cubemap Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/equirect_to_cubemap.wgsl").into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Equirect To Cubemap Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::StorageTexture {
+                        access: wgpu::StorageTextureAccess::WriteOnly,
+                        format: wgpu::TextureFormat::Rgba32Float,
+                        view_dimension: wgpu::TextureViewDimension::D2Array,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Equirect To Cubemap Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Equirect To Cubemap Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "main",
+            compilation_options: Default::default(),
+        });
+
+        Self {
+            pipeline,
+            bind_group_layout,
+            face_size,
+        }
+    }
+
+    /// Load an equirectangular `.hdr` file and convert it to a 6-layer
+    /// `rgba32float` cubemap, dispatching one compute invocation per texel
+    /// per face
+    pub fn convert(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        path: impl AsRef<Path>,
+    ) -> Result<EnvironmentMap, EnvironmentError> {
+        let equirect = image::open(path)?.to_rgba32f();
+        let (width, height) = equirect.dimensions();
+
+        let equirect_texture = device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label: Some("Equirect Source Texture"),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba32Float,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            bytemuck::cast_slice(equirect.as_raw()),
+        );
+        let equirect_view = equirect_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let cubemap_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Cubemap Texture"),
+            size: wgpu::Extent3d {
+                width: self.face_size,
+                height: self.face_size,
+                depth_or_array_layers: 6,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba32Float,
+            usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let cubemap_storage_view = cubemap_texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::D2Array),
+            ..Default::default()
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Equirect To Cubemap Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&equirect_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&cubemap_storage_view),
+                },
+            ],
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Equirect To Cubemap Encoder"),
+        });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Equirect To Cubemap Pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            let workgroups = self.face_size.div_ceil(8);
+            pass.dispatch_workgroups(workgroups, workgroups, 6);
+        }
+        queue.submit(Some(encoder.finish()));
+
+        let cubemap_view = cubemap_texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::Cube),
+            ..Default::default()
+        });
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Cubemap Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        Ok(EnvironmentMap {
+            cubemap_view,
+            sampler,
+        })
+    }
+}
+
+/// Path 3D scenes check for a skybox backdrop; missing or unreadable is not
+/// fatal, the scene just falls back to the flat clear color
+pub const DEFAULT_ENVIRONMENT_PATH: &str = "assets/environment.hdr";
+
+/// Convenience wrapper for 3D apps: load [`DEFAULT_ENVIRONMENT_PATH`] and
+/// convert it to a 512-texel cubemap, warning and returning `None` instead
+/// of failing to start if it's missing
+pub fn load_default_environment(device: &wgpu::Device, queue: &wgpu::Queue) -> Option<EnvironmentMap> {
+    match EquirectToCubemap::new(device, 512).convert(device, queue, DEFAULT_ENVIRONMENT_PATH) {
+        Ok(env) => Some(env),
+        Err(err) => {
+            eprintln!("No skybox loaded ({DEFAULT_ENVIRONMENT_PATH}): {err}");
+            None
+        }
+    }
+}
@@ -22,41 +22,51 @@ pub fn draw_equations_sidebar(
         .min_width(280.0)
         .max_width(350.0)
         .resizable(true)
-        .show(ctx, |ui| {
-            ui.vertical_centered(|ui| {
-                ui.heading(RichText::new(title).color(Color32::from_rgb(100, 200, 255)));
-            });
-
-            ui.add_space(10.0);
-            ui.separator();
-            ui.add_space(5.0);
-
-            // Equations section
-            ui.label(RichText::new("Equations").strong().color(Color32::from_rgb(255, 200, 100)));
-            ui.add_space(5.0);
-
-            for eq in equations {
-                draw_equation(ui, eq);
-                ui.add_space(8.0);
-            }
-
-            ui.add_space(10.0);
-            ui.separator();
-            ui.add_space(5.0);
-
-            // Variables section
-            ui.label(RichText::new("Variables").strong().color(Color32::from_rgb(255, 200, 100)));
-            ui.add_space(5.0);
-
-            for (symbol, meaning) in variables {
-                ui.horizontal(|ui| {
-                    ui.label(RichText::new(*symbol).color(Color32::from_rgb(150, 255, 150))
-                        .font(FontId::new(14.0, FontFamily::Monospace)));
-                    ui.label(RichText::new("=").color(Color32::GRAY));
-                    ui.label(RichText::new(*meaning).color(Color32::LIGHT_GRAY));
-                });
-            }
+        .show(ctx, |ui| draw_equations_content(ui, title, equations, variables));
+}
+
+/// The equations sidebar's contents, factored out of [`draw_equations_sidebar`]
+/// so a caller that already owns a `Ui` (e.g. an `egui_dock` tab) can draw the
+/// same content without it being wrapped in its own `SidePanel`
+pub fn draw_equations_content(
+    ui: &mut egui::Ui,
+    title: &str,
+    equations: &[Equation],
+    variables: &[(&str, &str)],
+) {
+    ui.vertical_centered(|ui| {
+        ui.heading(RichText::new(title).color(Color32::from_rgb(100, 200, 255)));
+    });
+
+    ui.add_space(10.0);
+    ui.separator();
+    ui.add_space(5.0);
+
+    // Equations section
+    ui.label(RichText::new("Equations").strong().color(Color32::from_rgb(255, 200, 100)));
+    ui.add_space(5.0);
+
+    for eq in equations {
+        draw_equation(ui, eq);
+        ui.add_space(8.0);
+    }
+
+    ui.add_space(10.0);
+    ui.separator();
+    ui.add_space(5.0);
+
+    // Variables section
+    ui.label(RichText::new("Variables").strong().color(Color32::from_rgb(255, 200, 100)));
+    ui.add_space(5.0);
+
+    for (symbol, meaning) in variables {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(*symbol).color(Color32::from_rgb(150, 255, 150))
+                .font(FontId::new(14.0, FontFamily::Monospace)));
+            ui.label(RichText::new("=").color(Color32::GRAY));
+            ui.label(RichText::new(*meaning).color(Color32::LIGHT_GRAY));
         });
+    }
 }
 
 /// Draw a single equation with name, formula, and description
@@ -321,3 +331,37 @@ pub const HYPERCUBE_VARIABLES: &[(&str, &str)] = &[
     ("P₃", "Projected 3D point"),
     ("P₄", "Original 4D point"),
 ];
+
+// ============================================
+// Superdense Coding Equations
+// ============================================
+
+pub const SUPERDENSE_EQUATIONS: &[Equation] = &[
+    Equation {
+        name: "Bell State (Φ⁺)",
+        formula: "|Φ⁺⟩ = (|00⟩ + |11⟩)/√2",
+        description: "Pair shared before encoding",
+    },
+    Equation {
+        name: "Encoding",
+        formula: "(b0,b1) → I, X, Z, or XZ",
+        description: "Alice's gate on her qubit",
+    },
+    Equation {
+        name: "Decoding",
+        formula: "H₀ · CNOT₀₁ |encoded⟩",
+        description: "Bob's disentangling circuit",
+    },
+    Equation {
+        name: "Channel Capacity",
+        formula: "2 classical bits / 1 qubit sent",
+        description: "The superdense coding gain",
+    },
+];
+
+pub const SUPERDENSE_VARIABLES: &[(&str, &str)] = &[
+    ("b0, b1", "Classical bits to send"),
+    ("I,X,Z,XZ", "Alice's encoding gates"),
+    ("CNOT₀₁", "Control on qubit 0, target qubit 1"),
+    ("H₀", "Hadamard on qubit 0"),
+];
@@ -0,0 +1,552 @@
+//! Headless driver for [`HallSimulation`]: steps the simulation, samples a
+//! set of pluggable [`Measurement`]s once per frame, and feeds a render
+//! snapshot to a set of pluggable [`Renderer`]s.
+//!
+//! `main_hall.rs` drives the simulation interactively through a windowed
+//! event loop; this is the batch-mode counterpart for sweeps, regression
+//! plots, and recorded video, with no GPU or window required.
+
+use crate::hall_effect::{EdgeChannel, EdgeSide, HallSimulation, Spin};
+use glam::Vec2;
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// One named scalar sampled from the simulation once per frame
+pub trait Measurement {
+    fn sample(&mut self, sim: &HallSimulation) -> (String, f32);
+}
+
+/// Consumes a frame: the simulation state after stepping, plus this frame's
+/// measurement samples
+pub trait Renderer {
+    fn render_frame(&mut self, sim: &HallSimulation, samples: &[(String, f32)]) -> io::Result<()>;
+
+    /// Called once after the last frame, e.g. to flush buffered output
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Owns a [`HallSimulation`] plus its measurement and rendering pipelines,
+/// and drives them forward frame by frame
+pub struct Driver {
+    pub simulation: HallSimulation,
+    pub steps_per_frame: u32,
+    pub dt: f32,
+    measurements: Vec<Box<dyn Measurement>>,
+    renderers: Vec<Box<dyn Renderer>>,
+}
+
+impl Driver {
+    pub fn new(simulation: HallSimulation, steps_per_frame: u32, dt: f32) -> Self {
+        Self {
+            simulation,
+            steps_per_frame,
+            dt,
+            measurements: Vec::new(),
+            renderers: Vec::new(),
+        }
+    }
+
+    pub fn add_measurement(&mut self, measurement: Box<dyn Measurement>) {
+        self.measurements.push(measurement);
+    }
+
+    pub fn add_renderer(&mut self, renderer: Box<dyn Renderer>) {
+        self.renderers.push(renderer);
+    }
+
+    /// Advance `frames` frames, each `steps_per_frame` simulation steps,
+    /// sampling every measurement and feeding the resulting snapshot to
+    /// every renderer after each frame
+    pub fn run(&mut self, frames: u32) -> io::Result<()> {
+        for _ in 0..frames {
+            for _ in 0..self.steps_per_frame {
+                self.simulation.step(self.dt);
+            }
+
+            let samples: Vec<(String, f32)> = self
+                .measurements
+                .iter_mut()
+                .map(|measurement| measurement.sample(&self.simulation))
+                .collect();
+
+            for renderer in &mut self.renderers {
+                renderer.render_frame(&self.simulation, &samples)?;
+            }
+        }
+
+        for renderer in &mut self.renderers {
+            renderer.finish()?;
+        }
+        Ok(())
+    }
+}
+
+/// Hall conductance σ_xy in units of e²/h
+pub struct HallConductanceMeasurement;
+
+impl Measurement for HallConductanceMeasurement {
+    fn sample(&mut self, sim: &HallSimulation) -> (String, f32) {
+        ("sigma_xy".to_string(), sim.hall_conductance)
+    }
+}
+
+/// Longitudinal conductance σ_xx, approximated as the fraction of bulk
+/// (non-edge) guiding centers whose contour is extended rather than
+/// localized — the dissipative channel that vanishes on a plateau and
+/// peaks as the Fermi level crosses a level's percolating cluster
+pub struct LongitudinalConductanceMeasurement;
+
+impl Measurement for LongitudinalConductanceMeasurement {
+    fn sample(&mut self, sim: &HallSimulation) -> (String, f32) {
+        let bulk: Vec<_> = sim.electrons.iter().filter(|e| !e.is_edge_state).collect();
+        let sigma_xx = if bulk.is_empty() {
+            0.0
+        } else {
+            bulk.iter().filter(|e| !e.localized).count() as f32 / bulk.len() as f32
+        };
+        ("sigma_xx".to_string(), sigma_xx)
+    }
+}
+
+/// Filling factor ν
+pub struct FillingFactorMeasurement;
+
+impl Measurement for FillingFactorMeasurement {
+    fn sample(&mut self, sim: &HallSimulation) -> (String, f32) {
+        ("filling_factor".to_string(), sim.filling_factor)
+    }
+}
+
+/// Total electron energy, summed over Landau level and Zeeman shift
+pub struct TotalEnergyMeasurement;
+
+impl Measurement for TotalEnergyMeasurement {
+    fn sample(&mut self, sim: &HallSimulation) -> (String, f32) {
+        let zeeman = sim.zeeman_energy();
+        let total: f32 = sim
+            .electrons
+            .iter()
+            .map(|e| {
+                let base = sim.cyclotron_freq * (e.landau_level as f32 + 0.5);
+                let spin_shift = match e.spin {
+                    Spin::Up => -zeeman / 2.0,
+                    Spin::Down => zeeman / 2.0,
+                };
+                base + spin_shift
+            })
+            .sum();
+        ("total_energy".to_string(), total)
+    }
+}
+
+/// Simulation time
+pub struct TimeMeasurement;
+
+impl Measurement for TimeMeasurement {
+    fn sample(&mut self, sim: &HallSimulation) -> (String, f32) {
+        ("time".to_string(), sim.time)
+    }
+}
+
+/// Logs one CSV row of measurement columns per frame, writing the header
+/// from the first frame's sample names
+pub struct CsvRenderer {
+    writer: BufWriter<File>,
+    header_written: bool,
+}
+
+impl CsvRenderer {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            header_written: false,
+        })
+    }
+}
+
+impl Renderer for CsvRenderer {
+    fn render_frame(&mut self, _sim: &HallSimulation, samples: &[(String, f32)]) -> io::Result<()> {
+        if !self.header_written {
+            let header: Vec<&str> = samples.iter().map(|(name, _)| name.as_str()).collect();
+            writeln!(self.writer, "{}", header.join(","))?;
+            self.header_written = true;
+        }
+
+        let row: Vec<String> = samples.iter().map(|(_, value)| format!("{value:.6}")).collect();
+        writeln!(self.writer, "{}", row.join(","))
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+fn to_u8(color: [f32; 4]) -> [u8; 3] {
+    [
+        (color[0].clamp(0.0, 1.0) * 255.0) as u8,
+        (color[1].clamp(0.0, 1.0) * 255.0) as u8,
+        (color[2].clamp(0.0, 1.0) * 255.0) as u8,
+    ]
+}
+
+/// Rasterizes electrons, cyclotron orbits, edge channels, and the level
+/// diagram into raw YUV4MPEG2 frames, writable straight to a `.y4m` file
+/// and pipeable into any encoder that reads that format (e.g. ffmpeg),
+/// without pulling in a video-encoding dependency of our own
+pub struct Y4mRenderer {
+    writer: BufWriter<File>,
+    width: u32,
+    height: u32,
+    fps: u32,
+    header_written: bool,
+}
+
+impl Y4mRenderer {
+    /// `width`/`height` are rounded down to even numbers, required by
+    /// 4:2:0 chroma subsampling
+    pub fn create(path: &Path, width: u32, height: u32, fps: u32) -> io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            width: width & !1,
+            height: height & !1,
+            fps,
+            header_written: false,
+        })
+    }
+
+    fn world_to_pixel(&self, sim: &HallSimulation, pos: Vec2) -> (i32, i32) {
+        let half_w = sim.width / 2.0;
+        let half_h = sim.height / 2.0;
+        let x = ((pos.x + half_w) / sim.width * self.width as f32) as i32;
+        let y = ((half_h - pos.y) / sim.height * self.height as f32) as i32;
+        (x, y)
+    }
+
+    fn set_pixel(&self, rgb: &mut [[u8; 3]], x: i32, y: i32, color: [u8; 3]) {
+        if x < 0 || y < 0 || x as u32 >= self.width || y as u32 >= self.height {
+            return;
+        }
+        rgb[y as usize * self.width as usize + x as usize] = color;
+    }
+
+    /// Bresenham line between two world-space points
+    fn draw_line(&self, rgb: &mut [[u8; 3]], sim: &HallSimulation, from: Vec2, to: Vec2, color: [u8; 3]) {
+        let (mut x0, mut y0) = self.world_to_pixel(sim, from);
+        let (x1, y1) = self.world_to_pixel(sim, to);
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        loop {
+            self.set_pixel(rgb, x0, y0, color);
+            if x0 == x1 && y0 == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x0 += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y0 += sy;
+            }
+        }
+    }
+
+    fn draw_circle(&self, rgb: &mut [[u8; 3]], sim: &HallSimulation, center: Vec2, radius: f32, color: [u8; 3]) {
+        const SEGMENTS: u32 = 24;
+        for i in 0..SEGMENTS {
+            let angle = i as f32 * std::f32::consts::TAU / SEGMENTS as f32;
+            let point = center + Vec2::new(radius * angle.cos(), radius * angle.sin());
+            let (x, y) = self.world_to_pixel(sim, point);
+            self.set_pixel(rgb, x, y, color);
+        }
+    }
+
+    fn draw_sample_boundary(&self, rgb: &mut [[u8; 3]], sim: &HallSimulation) {
+        let half_w = sim.width / 2.0;
+        let half_h = sim.height / 2.0;
+        let corners = [
+            Vec2::new(-half_w, -half_h),
+            Vec2::new(half_w, -half_h),
+            Vec2::new(half_w, half_h),
+            Vec2::new(-half_w, half_h),
+        ];
+        for i in 0..4 {
+            self.draw_line(rgb, sim, corners[i], corners[(i + 1) % 4], [90, 90, 90]);
+        }
+    }
+
+    fn draw_edge_channel(&self, rgb: &mut [[u8; 3]], sim: &HallSimulation, channel: &EdgeChannel) {
+        let half_w = sim.width / 2.0;
+        let half_h = sim.height / 2.0;
+        let (from, to) = match channel.side {
+            EdgeSide::Top => (Vec2::new(-half_w, half_h), Vec2::new(half_w, half_h)),
+            EdgeSide::Bottom => (Vec2::new(-half_w, -half_h), Vec2::new(half_w, -half_h)),
+            EdgeSide::Left => (Vec2::new(-half_w, -half_h), Vec2::new(-half_w, half_h)),
+            EdgeSide::Right => (Vec2::new(half_w, -half_h), Vec2::new(half_w, half_h)),
+        };
+        self.draw_line(rgb, sim, from, to, [80, 200, 255]);
+    }
+
+    /// A small bar chart of each Landau sub-level's filling fraction,
+    /// reserved along the bottom sixth of the frame
+    fn draw_level_diagram(&self, rgb: &mut [[u8; 3]], sim: &HallSimulation) {
+        let levels = sim.get_level_diagram();
+        if levels.is_empty() {
+            return;
+        }
+
+        let strip_height = (self.height / 6).max(4);
+        let bar_width = (self.width / levels.len() as u32).max(1);
+
+        for (i, (_energy, fraction, color)) in levels.iter().enumerate() {
+            let bar_height = (fraction * strip_height as f32) as u32;
+            let x0 = i as u32 * bar_width;
+            let c = to_u8(*color);
+            for y in (self.height - bar_height)..self.height {
+                for x in x0..(x0 + bar_width).min(self.width) {
+                    self.set_pixel(rgb, x as i32, y as i32, c);
+                }
+            }
+        }
+    }
+
+    fn write_frame(&mut self, rgb: &[[u8; 3]]) -> io::Result<()> {
+        let w = self.width as usize;
+        let h = self.height as usize;
+
+        let mut y_plane = vec![0u8; w * h];
+        let mut u_plane = vec![0u8; (w / 2) * (h / 2)];
+        let mut v_plane = vec![0u8; (w / 2) * (h / 2)];
+
+        for (i, &[r, g, b]) in rgb.iter().enumerate() {
+            let (r, g, b) = (r as f32, g as f32, b as f32);
+            y_plane[i] = (0.299 * r + 0.587 * g + 0.114 * b).round().clamp(0.0, 255.0) as u8;
+        }
+
+        for row in (0..h).step_by(2) {
+            for col in (0..w).step_by(2) {
+                let mut u_sum = 0.0f32;
+                let mut v_sum = 0.0f32;
+                let mut count = 0.0f32;
+                for dy in 0..2 {
+                    for dx in 0..2 {
+                        let (ry, rx) = (row + dy, col + dx);
+                        if ry >= h || rx >= w {
+                            continue;
+                        }
+                        let [r, g, b] = rgb[ry * w + rx];
+                        let (r, g, b) = (r as f32, g as f32, b as f32);
+                        u_sum += -0.169 * r - 0.331 * g + 0.5 * b + 128.0;
+                        v_sum += 0.5 * r - 0.419 * g - 0.081 * b + 128.0;
+                        count += 1.0;
+                    }
+                }
+                let uv_index = (row / 2) * (w / 2) + col / 2;
+                u_plane[uv_index] = (u_sum / count).round().clamp(0.0, 255.0) as u8;
+                v_plane[uv_index] = (v_sum / count).round().clamp(0.0, 255.0) as u8;
+            }
+        }
+
+        writeln!(self.writer, "FRAME")?;
+        self.writer.write_all(&y_plane)?;
+        self.writer.write_all(&u_plane)?;
+        self.writer.write_all(&v_plane)?;
+        Ok(())
+    }
+}
+
+impl Renderer for Y4mRenderer {
+    fn render_frame(&mut self, sim: &HallSimulation, _samples: &[(String, f32)]) -> io::Result<()> {
+        if !self.header_written {
+            writeln!(self.writer, "YUV4MPEG2 W{} H{} F{}:1 Ip A1:1 C420jpeg", self.width, self.height, self.fps)?;
+            self.header_written = true;
+        }
+
+        let mut rgb = vec![[0u8; 3]; (self.width * self.height) as usize];
+
+        self.draw_sample_boundary(&mut rgb, sim);
+        for channel in &sim.edge_channels {
+            self.draw_edge_channel(&mut rgb, sim, channel);
+        }
+        for (center, radius, color) in sim.get_orbits().iter().take(40) {
+            self.draw_circle(&mut rgb, sim, *center, *radius, to_u8(*color));
+        }
+        for (pos, color, _is_edge, localized) in sim.get_electron_data() {
+            let c = to_u8(color);
+            let c = if localized { [c[0] / 3, c[1] / 3, c[2] / 3] } else { c };
+            let (x, y) = self.world_to_pixel(sim, pos);
+            self.set_pixel(&mut rgb, x, y, c);
+        }
+        self.draw_level_diagram(&mut rgb, sim);
+
+        self.write_frame(&rgb)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// One sampled point of a magnetic-field sweep
+#[derive(Debug, Clone, Copy)]
+pub struct SweepPoint {
+    pub magnetic_field: f32,
+    pub filling_factor: f32,
+    pub hall_conductance: f32,
+    pub sigma_xx: f32,
+}
+
+impl SweepPoint {
+    fn csv_header() -> &'static str {
+        "magnetic_field,filling_factor,hall_conductance,sigma_xx"
+    }
+
+    fn to_csv_row(self) -> String {
+        format!(
+            "{:.6},{:.6},{:.6},{:.6}",
+            self.magnetic_field, self.filling_factor, self.hall_conductance, self.sigma_xx
+        )
+    }
+
+    fn from_csv_row(row: &str) -> Option<Self> {
+        let mut fields = row.split(',');
+        Some(Self {
+            magnetic_field: fields.next()?.parse().ok()?,
+            filling_factor: fields.next()?.parse().ok()?,
+            hall_conductance: fields.next()?.parse().ok()?,
+            sigma_xx: fields.next()?.parse().ok()?,
+        })
+    }
+}
+
+/// Dump a sweep's results as a CSV string, header first
+pub fn sweep_to_csv(points: &[SweepPoint]) -> String {
+    let mut out = String::from(SweepPoint::csv_header());
+    out.push('\n');
+    for point in points {
+        out.push_str(&point.to_csv_row());
+        out.push('\n');
+    }
+    out
+}
+
+/// Parameters identifying a sweep run; hashed together to form its disk
+/// cache key, so two sweeps with the same physical setup share cached
+/// B-points even across separate processes
+#[derive(Debug, Clone, Copy)]
+pub struct SweepParams {
+    pub width: f32,
+    pub height: f32,
+    /// Electron areal density; the electron count at each B-point is
+    /// `density * width * height`, rounded
+    pub density: f32,
+    pub temperature: f32,
+    /// Disambiguates otherwise-identical runs in the cache key. Note the
+    /// disorder landscape itself is generated from `rand::thread_rng()`
+    /// (see [`crate::hall_effect::DisorderPotential::generate`]) and isn't
+    /// currently seedable, so this doesn't yet guarantee bit-identical
+    /// disorder across cache hits and misses — only a consistent key.
+    pub disorder_seed: u64,
+    pub relax_steps: u32,
+    pub dt: f32,
+}
+
+impl SweepParams {
+    fn cache_key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.width.to_bits().hash(&mut hasher);
+        self.height.to_bits().hash(&mut hasher);
+        self.density.to_bits().hash(&mut hasher);
+        self.temperature.to_bits().hash(&mut hasher);
+        self.disorder_seed.hash(&mut hasher);
+        self.relax_steps.hash(&mut hasher);
+        self.dt.to_bits().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Drives a [`HallSimulation`] across a range of magnetic-field values,
+/// re-filling to a fixed electron density and letting the system relax at
+/// each point, to trace out the canonical σ_xy/σ_xx staircase. Each
+/// B-point is cached to disk keyed by a hash of [`SweepParams`], so an
+/// interrupted or repeated sweep skips points it already computed.
+pub struct SweepRunner {
+    params: SweepParams,
+    cache_dir: PathBuf,
+}
+
+impl SweepRunner {
+    pub fn new(params: SweepParams, cache_dir: impl Into<PathBuf>) -> Self {
+        Self { params, cache_dir: cache_dir.into() }
+    }
+
+    fn cache_path(&self) -> PathBuf {
+        self.cache_dir.join(format!("sweep_{:016x}.csv", self.params.cache_key()))
+    }
+
+    fn load_cache(&self) -> Vec<SweepPoint> {
+        let Ok(file) = File::open(self.cache_path()) else { return Vec::new() };
+        BufReader::new(file)
+            .lines()
+            .skip(1)
+            .filter_map(|line| SweepPoint::from_csv_row(&line.ok()?))
+            .collect()
+    }
+
+    fn append_cache(&self, point: SweepPoint) -> io::Result<()> {
+        let path = self.cache_path();
+        let write_header = !path.exists();
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+        if write_header {
+            writeln!(file, "{}", SweepPoint::csv_header())?;
+        }
+        writeln!(file, "{}", point.to_csv_row())
+    }
+
+    /// Sweep `magnetic_field` across `b_values`, skipping any value already
+    /// present in the disk cache, and return every point sorted by field
+    /// strength
+    pub fn run(&self, b_values: &[f32]) -> io::Result<Vec<SweepPoint>> {
+        std::fs::create_dir_all(&self.cache_dir)?;
+
+        let mut points = self.load_cache();
+        let cached: HashSet<u32> = points.iter().map(|p| p.magnetic_field.to_bits()).collect();
+        let num_electrons = (self.params.density * self.params.width * self.params.height).round() as usize;
+
+        for &b in b_values {
+            if cached.contains(&b.to_bits()) {
+                continue;
+            }
+
+            let mut sim = HallSimulation::new(self.params.width, self.params.height, b);
+            sim.temperature = self.params.temperature;
+            sim.fill_electrons(num_electrons);
+            for _ in 0..self.params.relax_steps {
+                sim.step(self.params.dt);
+            }
+
+            let point = SweepPoint {
+                magnetic_field: b,
+                filling_factor: sim.filling_factor,
+                hall_conductance: sim.hall_conductance,
+                sigma_xx: sim.sigma_xx,
+            };
+            self.append_cache(point)?;
+            points.push(point);
+        }
+
+        points.sort_by(|a, b| a.magnetic_field.partial_cmp(&b.magnetic_field).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(points)
+    }
+}
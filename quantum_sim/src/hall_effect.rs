@@ -6,6 +6,7 @@
 //! - Edge states and chiral transport
 //! - Hall conductance quantization
 
+use crate::wavefunction::Complex;
 use glam::{Vec2, Vec3};
 use rand::Rng;
 use std::f32::consts::PI;
@@ -15,6 +16,103 @@ const HBAR: f32 = 1.0;
 const E_CHARGE: f32 = 1.0;
 const M_EFF: f32 = 1.0; // Effective electron mass
 
+/// Number of Gaussian impurity bumps making up the disorder landscape
+const N_IMPURITIES: u32 = 40;
+/// Impurity amplitude standard deviation, as a fraction of ℏωc
+const DISORDER_AMPLITUDE_FRACTION: f32 = 0.3;
+/// Impurity correlation length, in multiples of the magnetic length
+const CORRELATION_LENGTH_FACTOR: f32 = 3.0;
+/// Maximum number of contour-following steps before giving up and calling a
+/// guiding center localized rather than extended
+const PERCOLATION_MAX_STEPS: u32 = 600;
+/// Bohr magneton (scaled for visualization)
+const MU_B: f32 = 0.5;
+/// Landau level Gaussian broadening width Γ, as a fraction of ℏωc
+const LEVEL_BROADENING_FRACTION: f32 = 0.15;
+
+/// Fermi-Dirac occupation `f(E) = 1/(exp((E-μ)/kT) + 1)`. Falls back to a
+/// hard step at `T → 0` to avoid dividing by zero.
+fn fermi_dirac(energy: f32, mu: f32, kt: f32) -> f32 {
+    if kt <= 1e-6 {
+        return if energy < mu { 1.0 } else { 0.0 };
+    }
+    1.0 / (((energy - mu) / kt).exp() + 1.0)
+}
+
+/// Which spin branch a split Landau sub-level or electron belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spin {
+    Up,
+    Down,
+}
+
+/// Sample from a standard normal distribution via the Box-Muller transform,
+/// since nothing in this crate already depends on `rand_distr`
+fn sample_gaussian(rng: &mut impl Rng, std_dev: f32) -> f32 {
+    let u1: f32 = rng.gen::<f32>().max(1e-6);
+    let u2: f32 = rng.gen();
+    std_dev * (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+/// A single Gaussian impurity bump contributing to [`DisorderPotential`]
+#[derive(Debug, Clone, Copy)]
+struct Impurity {
+    center: Vec2,
+    amplitude: f32,
+}
+
+/// A smooth random impurity potential `V(x, y)`, built as a sum of Gaussian
+/// bumps at random positions with Gaussian-distributed amplitudes and a
+/// shared correlation length ξ. This is what gives Landau levels real
+/// width instead of the single-point plateaus a disorder-free sample would
+/// produce: guiding centers drift along its equipotentials, and whether a
+/// contour closes in the bulk or percolates to the sample edge is exactly
+/// what separates localized states from the current-carrying extended ones.
+#[derive(Debug, Clone)]
+pub struct DisorderPotential {
+    impurities: Vec<Impurity>,
+    correlation_length: f32,
+}
+
+impl DisorderPotential {
+    /// Scatter `num_impurities` Gaussian bumps uniformly over the sample,
+    /// with amplitudes drawn from a normal distribution of standard
+    /// deviation `amplitude_std` and a shared correlation length `xi`
+    pub fn generate(width: f32, height: f32, xi: f32, num_impurities: u32, amplitude_std: f32) -> Self {
+        let mut rng = rand::thread_rng();
+        let impurities = (0..num_impurities)
+            .map(|_| Impurity {
+                center: Vec2::new(
+                    rng.gen::<f32>() * width - width / 2.0,
+                    rng.gen::<f32>() * height - height / 2.0,
+                ),
+                amplitude: sample_gaussian(&mut rng, amplitude_std),
+            })
+            .collect();
+
+        Self { impurities, correlation_length: xi }
+    }
+
+    /// Total disorder potential at `pos`
+    pub fn potential(&self, pos: Vec2) -> f32 {
+        let two_xi_sq = 2.0 * self.correlation_length * self.correlation_length;
+        self.impurities
+            .iter()
+            .map(|imp| imp.amplitude * (-(pos - imp.center).length_squared() / two_xi_sq).exp())
+            .sum()
+    }
+
+    /// Gradient ∇V at `pos`, used to drive equipotential drift
+    pub fn gradient(&self, pos: Vec2) -> Vec2 {
+        let xi_sq = self.correlation_length * self.correlation_length;
+        self.impurities.iter().fold(Vec2::ZERO, |grad, imp| {
+            let delta = pos - imp.center;
+            let bump = imp.amplitude * (-delta.length_squared() / (2.0 * xi_sq)).exp();
+            grad - delta * (bump / xi_sq)
+        })
+    }
+}
+
 /// Electron in 2D electron gas
 #[derive(Debug, Clone)]
 pub struct Electron {
@@ -24,10 +122,27 @@ pub struct Electron {
     pub landau_level: u32,    // Which Landau level
     pub guiding_center: Vec2, // Center of cyclotron orbit
     pub is_edge_state: bool,
+    /// Whether this guiding center's equipotential contour closes inside
+    /// the bulk (localized, carries no net current) rather than percolating
+    /// to the sample edge (extended). Always `false` for edge states, which
+    /// carry current by construction.
+    pub localized: bool,
+    /// Which spin sub-level this electron was placed into
+    pub spin: Spin,
+    /// Two-component spinor (ψ_up, ψ_down). Starts as a pure state matching
+    /// `spin`; spin-orbit coupling mixes the components each step, so a
+    /// nonzero `spin_orbit_alpha` can rotate an electron away from its
+    /// initial branch.
+    pub spin_up: Complex,
+    pub spin_down: Complex,
 }
 
 impl Electron {
-    pub fn new(position: Vec2, landau_level: u32) -> Self {
+    pub fn new(position: Vec2, landau_level: u32, spin: Spin) -> Self {
+        let (spin_up, spin_down) = match spin {
+            Spin::Up => (Complex::ONE, Complex::ZERO),
+            Spin::Down => (Complex::ZERO, Complex::ONE),
+        };
         Self {
             position,
             velocity: Vec2::ZERO,
@@ -35,6 +150,10 @@ impl Electron {
             landau_level,
             guiding_center: position,
             is_edge_state: false,
+            localized: true,
+            spin,
+            spin_up,
+            spin_down,
         }
     }
 }
@@ -43,27 +162,44 @@ impl Electron {
 #[derive(Debug, Clone)]
 pub struct LandauLevel {
     pub n: u32,              // Level index (0, 1, 2, ...)
-    pub energy: f32,         // E = ℏωc(n + 1/2)
+    pub energy: f32,         // E = ℏωc(n + 1/2) ± E_Z/2
     pub degeneracy: u32,     // Number of states
     pub filled: u32,         // Number of electrons
     pub color: [f32; 4],     // Visualization color
+    pub spin: Spin,          // Which spin branch this sub-level is
 }
 
 impl LandauLevel {
-    pub fn new(n: u32, cyclotron_freq: f32, degeneracy: u32) -> Self {
-        let energy = HBAR * cyclotron_freq * (n as f32 + 0.5);
-
-        // Color gradient from blue (n=0) to red (high n)
+    /// Build the spin-up and spin-down sub-levels for orbital index `n`,
+    /// split by the Zeeman energy `E_Z` and each holding half the
+    /// disorder-free degeneracy
+    pub fn new_spin_pair(n: u32, cyclotron_freq: f32, zeeman_energy: f32, degeneracy: u32) -> [Self; 2] {
+        let base_energy = HBAR * cyclotron_freq * (n as f32 + 0.5);
+
+        // Color gradient from blue (n=0) to red (high n); spin-down is
+        // drawn slightly darker so the two branches read as distinct
         let t = (n as f32 / 5.0).min(1.0);
-        let color = [0.2 + 0.6 * t, 0.3, 0.9 - 0.6 * t, 0.8];
-
-        Self {
-            n,
-            energy,
-            degeneracy,
-            filled: 0,
-            color,
-        }
+        let up_color = [0.2 + 0.6 * t, 0.3, 0.9 - 0.6 * t, 0.8];
+        let down_color = [up_color[0] * 0.6, up_color[1] * 0.6, up_color[2] * 0.6, 0.8];
+
+        [
+            Self {
+                n,
+                energy: base_energy - zeeman_energy / 2.0,
+                degeneracy: degeneracy / 2,
+                filled: 0,
+                color: up_color,
+                spin: Spin::Up,
+            },
+            Self {
+                n,
+                energy: base_energy + zeeman_energy / 2.0,
+                degeneracy: degeneracy / 2,
+                filled: 0,
+                color: down_color,
+                spin: Spin::Down,
+            },
+        ]
     }
 
     pub fn filling_fraction(&self) -> f32 {
@@ -92,6 +228,44 @@ pub enum EdgeSide {
     Right,
 }
 
+/// A current or voltage probe placed along the sample boundary for
+/// multi-terminal Landauer-Büttiker transport measurements
+#[derive(Debug, Clone, Copy)]
+pub struct Contact {
+    /// Arc-length position around the perimeter, increasing
+    /// counterclockwise from the bottom-left corner
+    pub boundary_position: f32,
+    /// `Some(current)` biases this contact with an externally driven
+    /// current (a source or drain); `None` makes it a floating voltage
+    /// probe whose chemical potential is solved for
+    pub applied_current: Option<f32>,
+    /// Chemical potential μ_i: given for current-biased contacts, solved
+    /// by [`HallSimulation::transport_resistances`] for floating probes
+    pub chemical_potential: f32,
+}
+
+impl Contact {
+    /// A current-biased contact (source or drain)
+    pub fn biased(boundary_position: f32, current: f32) -> Self {
+        Self { boundary_position, applied_current: Some(current), chemical_potential: 0.0 }
+    }
+
+    /// A floating voltage probe
+    pub fn floating(boundary_position: f32) -> Self {
+        Self { boundary_position, applied_current: None, chemical_potential: 0.0 }
+    }
+}
+
+/// Four-terminal transport result from [`HallSimulation::transport_resistances`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransportResult {
+    /// R_xy = h/(ν e²), read from probes on opposite edges
+    pub hall_resistance: f32,
+    /// R_xx, read between the source and the downstream probe, which share
+    /// an edge with no current injected between them; zero on a plateau
+    pub longitudinal_resistance: f32,
+}
+
 /// Quantum Hall simulation
 pub struct HallSimulation {
     /// Electrons in the system
@@ -121,12 +295,56 @@ pub struct HallSimulation {
     pub show_edge_states: bool,
     /// Temperature (affects Fermi distribution)
     pub temperature: f32,
+    /// Smooth random impurity potential driving localization
+    pub disorder: DisorderPotential,
+    /// Landé g-factor, sets the Zeeman splitting `E_Z = g·μ_B·B` between
+    /// each Landau level's spin-up and spin-down sub-levels
+    pub g_factor: f32,
+    /// Spin-orbit coupling strength α in `offdiag = α(kx - i·ky)`; zero
+    /// disables mixing and spin stays a good quantum number
+    pub spin_orbit_alpha: f32,
+    /// Current and voltage probes for multi-terminal transport measurements
+    pub contacts: Vec<Contact>,
+    /// Chemical potential μ, solved by [`Self::fill_electrons`] so that the
+    /// thermal (Fermi-Dirac) occupation matches the target electron count
+    pub chemical_potential: f32,
+    /// Gaussian broadening width Γ of each Landau sub-level's density of
+    /// states, a disorder parameter; zero would recover delta-function
+    /// levels
+    pub level_broadening: f32,
+    /// Longitudinal conductivity, proportional to the squared density of
+    /// states at the Fermi energy. Peaks as μ crosses a broadened Landau
+    /// level and vanishes in the gap between levels, producing
+    /// Shubnikov-de Haas oscillations as `magnetic_field` is swept.
+    pub sigma_xx: f32,
 }
 
 impl HallSimulation {
     pub fn new(width: f32, height: f32, magnetic_field: f32) -> Self {
-        let cyclotron_freq = E_CHARGE * magnetic_field / M_EFF;
+        Self::with_effective_mass(width, height, magnetic_field, M_EFF)
+    }
+
+    /// Construct from a loaded [`crate::model::PhysicsModel`], using its
+    /// Landau-level effective mass in place of the hardcoded `M_EFF`
+    pub fn from_model(
+        width: f32,
+        height: f32,
+        magnetic_field: f32,
+        model: &crate::model::PhysicsModel,
+    ) -> Self {
+        Self::with_effective_mass(width, height, magnetic_field, model.landau_effective_mass)
+    }
+
+    fn with_effective_mass(width: f32, height: f32, magnetic_field: f32, m_eff: f32) -> Self {
+        let cyclotron_freq = E_CHARGE * magnetic_field / m_eff;
         let magnetic_length = (HBAR / (E_CHARGE * magnetic_field)).sqrt();
+        let disorder = DisorderPotential::generate(
+            width,
+            height,
+            magnetic_length * CORRELATION_LENGTH_FACTOR,
+            N_IMPURITIES,
+            DISORDER_AMPLITUDE_FRACTION * HBAR * cyclotron_freq,
+        );
 
         let mut sim = Self {
             electrons: Vec::new(),
@@ -143,54 +361,136 @@ impl HallSimulation {
             time: 0.0,
             show_edge_states: true,
             temperature: 0.1,
+            disorder,
+            g_factor: 2.0,
+            spin_orbit_alpha: 0.0,
+            contacts: Vec::new(),
+            chemical_potential: 0.0,
+            level_broadening: LEVEL_BROADENING_FRACTION * cyclotron_freq,
+            sigma_xx: 0.0,
         };
 
         sim.initialize_levels(5);
         sim
     }
 
-    /// Initialize Landau levels
+    /// Zeeman energy splitting `E_Z = g·μ_B·B` between spin branches
+    pub fn zeeman_energy(&self) -> f32 {
+        self.g_factor * MU_B * self.magnetic_field
+    }
+
+    /// Initialize Landau levels, each split into spin-up/spin-down
+    /// sub-levels by the Zeeman energy so plateaus appear at every integer
+    /// ν rather than just the even ones
     fn initialize_levels(&mut self, num_levels: u32) {
         self.landau_levels.clear();
 
         // Degeneracy ~ area * eB / (2πℏ)
         let degeneracy = ((self.width * self.height * E_CHARGE * self.magnetic_field)
             / (2.0 * PI * HBAR)) as u32;
+        let zeeman_energy = self.zeeman_energy();
 
         for n in 0..num_levels {
-            self.landau_levels.push(LandauLevel::new(n, self.cyclotron_freq, degeneracy));
+            self.landau_levels.extend(LandauLevel::new_spin_pair(n, self.cyclotron_freq, zeeman_energy, degeneracy));
+        }
+
+        self.landau_levels.sort_by(|a, b| a.energy.partial_cmp(&b.energy).unwrap_or(std::cmp::Ordering::Equal));
+    }
+
+    /// Total expected electron count for chemical potential `mu` at the
+    /// current temperature: `Σ_i degeneracy_i · f(E_i, μ, kT)`
+    fn occupation_at(&self, mu: f32) -> f32 {
+        let kt = self.temperature.max(1e-4);
+        self.landau_levels
+            .iter()
+            .map(|l| l.degeneracy as f32 * fermi_dirac(l.energy, mu, kt))
+            .sum()
+    }
+
+    /// Solve for the chemical potential μ whose thermal occupation matches
+    /// `target` electrons, by bisection (occupation is monotonic in μ)
+    fn solve_chemical_potential(&self, target: f32) -> f32 {
+        let kt = self.temperature.max(1e-4);
+        let min_energy = self.landau_levels.iter().map(|l| l.energy).fold(f32::INFINITY, f32::min);
+        let max_energy = self.landau_levels.iter().map(|l| l.energy).fold(f32::NEG_INFINITY, f32::max);
+        let mut lo = min_energy - 20.0 * kt;
+        let mut hi = max_energy + 20.0 * kt;
+
+        for _ in 0..40 {
+            let mid = 0.5 * (lo + hi);
+            if self.occupation_at(mid) < target {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
         }
+        0.5 * (lo + hi)
     }
 
-    /// Fill electrons up to a certain Fermi level
+    /// Total density of states at `energy`, summed as a Gaussian of width
+    /// [`Self::level_broadening`] per Landau sub-level rather than a set of
+    /// delta functions — this is what lets `sigma_xx` vary continuously as
+    /// μ sweeps through a level instead of switching on a knife edge
+    pub fn density_of_states(&self, energy: f32) -> f32 {
+        let gamma = self.level_broadening.max(1e-4);
+        let norm = gamma * (2.0 * PI).sqrt();
+        self.landau_levels
+            .iter()
+            .map(|l| {
+                let delta = energy - l.energy;
+                l.degeneracy as f32 * (-(delta * delta) / (2.0 * gamma * gamma)).exp() / norm
+            })
+            .sum()
+    }
+
+    /// Longitudinal conductivity from the density of states at the Fermi
+    /// energy, normalized against the on-resonance peak DOS so it reads
+    /// roughly in `[0, 1]`: near 1 when μ sits on a broadened level, near 0
+    /// in the gap between levels
+    fn update_longitudinal_conductivity(&mut self) {
+        if self.landau_levels.is_empty() {
+            self.sigma_xx = 0.0;
+            return;
+        }
+
+        let gamma = self.level_broadening.max(1e-4);
+        let avg_degeneracy = self.landau_levels.iter().map(|l| l.degeneracy as f32).sum::<f32>()
+            / self.landau_levels.len() as f32;
+        let peak_dos = avg_degeneracy / (gamma * (2.0 * PI).sqrt());
+
+        let dos = self.density_of_states(self.chemical_potential);
+        self.sigma_xx = if peak_dos > 0.0 { (dos / peak_dos).powi(2) } else { 0.0 };
+    }
+
+    /// Fill electrons according to thermal Fermi-Dirac occupation: solve
+    /// for the chemical potential μ that gives `num_electrons` total, then
+    /// populate each Landau sub-level with `degeneracy · f(E, μ, kT)`
+    /// electrons (rounded) rather than a hard cutoff, so filling smoothly
+    /// rounds out near plateau transitions instead of switching abruptly
     pub fn fill_electrons(&mut self, num_electrons: usize) {
         self.electrons.clear();
 
-        // Reset filling
-        for level in &mut self.landau_levels {
-            level.filled = 0;
-        }
+        let mu = self.solve_chemical_potential(num_electrons as f32);
+        self.chemical_potential = mu;
+        let kt = self.temperature.max(1e-4);
 
         let mut rng = rand::thread_rng();
-        let mut remaining = num_electrons;
         let width = self.width;
         let height = self.height;
         let magnetic_length = self.magnetic_length;
 
-        // Collect level info first to avoid borrow issues
-        let level_info: Vec<(u32, u32)> = self.landau_levels.iter()
-            .map(|l| (l.n, l.degeneracy))
+        // Collect level info first to avoid borrow issues. `landau_levels`
+        // is already sorted by energy, so filling it in order fills the
+        // lowest-energy sub-levels (and spin branches) first.
+        let level_info: Vec<(u32, Spin, u32, f32)> = self.landau_levels.iter()
+            .map(|l| (l.n, l.spin, l.degeneracy, l.energy))
             .collect();
 
         let mut level_idx = 0;
-        for (n, degeneracy) in level_info {
-            if remaining == 0 {
-                break;
-            }
-
-            let to_fill = remaining.min(degeneracy as usize);
+        for (n, spin, degeneracy, energy) in level_info {
+            let to_fill = ((degeneracy as f32 * fermi_dirac(energy, mu, kt)).round() as usize)
+                .min(degeneracy as usize);
             self.landau_levels[level_idx].filled = to_fill as u32;
-            remaining -= to_fill;
 
             // Create electrons in this level
             for _ in 0..to_fill {
@@ -198,7 +498,7 @@ impl HallSimulation {
                 let y = rng.gen::<f32>() * height - height / 2.0;
                 let pos = Vec2::new(x, y);
 
-                let mut electron = Electron::new(pos, n);
+                let mut electron = Electron::new(pos, n, spin);
                 electron.phase = rng.gen::<f32>() * 2.0 * PI;
 
                 // Check if edge state - inline distance calculation
@@ -209,6 +509,7 @@ impl HallSimulation {
                 let edge_dist = dx_left.min(dx_right).min(dy_bottom).min(dy_top);
 
                 electron.is_edge_state = edge_dist < magnetic_length * 2.0;
+                electron.localized = !electron.is_edge_state && !self.percolates(pos);
 
                 self.electrons.push(electron);
             }
@@ -256,16 +557,129 @@ impl HallSimulation {
         }
     }
 
+    /// Total potential gradient at `pos`: the uniform Hall electric field
+    /// plus the disorder landscape, sampled near the guiding center. Drift
+    /// is always perpendicular to this (`v = (∇V).perp() / B`), so a
+    /// disorder-free sample reduces exactly to the old uniform E×B drift.
+    fn total_gradient(&self, pos: Vec2) -> Vec2 {
+        self.electric_field + self.disorder.gradient(pos)
+    }
+
+    /// Follow a guiding center's equipotential contour by repeatedly
+    /// stepping perpendicular to the total potential's gradient. Returns
+    /// `true` if the contour reaches the sample boundary within
+    /// [`PERCOLATION_MAX_STEPS`] (an extended, percolating state) or
+    /// `false` if it closes on itself inside the bulk first (localized).
+    fn percolates(&self, start: Vec2) -> bool {
+        let step = self.magnetic_length * 0.5;
+        let half_w = self.width / 2.0;
+        let half_h = self.height / 2.0;
+
+        let mut pos = start;
+        for i in 0..PERCOLATION_MAX_STEPS {
+            let grad = self.total_gradient(pos);
+            let Some(direction) = grad.try_normalize() else { break };
+            pos += direction.perp() * step;
+
+            if pos.x.abs() > half_w || pos.y.abs() > half_h {
+                return true;
+            }
+            if i > 4 && (pos - start).length() < step {
+                return false; // contour closed back on its starting point
+            }
+        }
+        false
+    }
+
     /// Update physical observables
     fn update_observables(&mut self) {
         // Filling factor ν = n_e * h / (eB)
         let electron_density = self.electrons.len() as f32 / (self.width * self.height);
         self.filling_factor = electron_density * 2.0 * PI * HBAR / (E_CHARGE * self.magnetic_field);
 
-        // Quantized Hall conductance σ_xy = ν * e²/h
-        // For integer QHE, ν rounds to integer
-        let nu_int = self.filling_factor.round();
-        self.hall_conductance = nu_int; // In units of e²/h
+        // σ_xy counts Landau levels lying fully below the Fermi level: a
+        // level that's completely full contributes a full e²/h step, and a
+        // partially-filled level only contributes once the Fermi level has
+        // actually crossed its single percolating (extended) cluster —
+        // while E_F still sits among that level's localized states, the
+        // conductance stays pinned at the lower plateau.
+        let mut conductance = 0.0;
+        for level in &self.landau_levels {
+            let fraction = level.filling_fraction();
+            if fraction >= 0.999 {
+                conductance += 1.0;
+            } else if fraction > 0.0 {
+                let has_extended = self.electrons.iter().any(|e| {
+                    e.landau_level == level.n && e.spin == level.spin && (e.is_edge_state || !e.localized)
+                });
+                if has_extended {
+                    conductance += 1.0;
+                }
+            }
+        }
+        self.hall_conductance = conductance;
+
+        self.update_longitudinal_conductivity();
+    }
+
+    /// Place a current or voltage probe along the sample boundary
+    pub fn add_contact(&mut self, contact: Contact) {
+        self.contacts.push(contact);
+    }
+
+    /// Solve the chiral Landauer-Büttiker current law `I_i = (e²/h) Σ_j
+    /// (T_ji μ_j − T_ij μ_i)` for this sample's `contacts` and derive the
+    /// four-terminal Hall and longitudinal resistances.
+    ///
+    /// With purely chiral edge transport, transmission `T_ij` is `ν`
+    /// (the integer filling factor) from a contact to its immediate
+    /// downstream neighbor around the perimeter and zero everywhere else.
+    /// That collapses the usual N-terminal linear solve to a single walk
+    /// around the ring: a floating probe (I_i = 0) simply inherits the
+    /// chemical potential of whichever upstream contact last injected
+    /// current, only changing at the next current-biased contact. This is
+    /// the textbook reason chiral edge states give an exactly quantized
+    /// `R_xy` and `R_xx = 0` along a clean edge. Contacts are read off in
+    /// boundary order as the standard four-terminal Hall-bar arrangement:
+    /// source, downstream probe, drain, upstream probe.
+    pub fn transport_resistances(&mut self) -> TransportResult {
+        if self.contacts.len() < 2 {
+            return TransportResult { hall_resistance: 0.0, longitudinal_resistance: 0.0 };
+        }
+
+        let nu = self.filling_factor.round().max(1.0);
+
+        let mut order: Vec<usize> = (0..self.contacts.len()).collect();
+        order.sort_by(|&a, &b| {
+            self.contacts[a]
+                .boundary_position
+                .partial_cmp(&self.contacts[b].boundary_position)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let n = order.len();
+        let start_pos = order
+            .iter()
+            .position(|&i| self.contacts[i].applied_current.is_some())
+            .unwrap_or(0);
+        let drive_current = self.contacts[order[start_pos]].applied_current.unwrap_or(nu);
+
+        let mut last_mu = self.contacts[order[start_pos]].chemical_potential;
+        for step in 1..=n {
+            let idx = order[(start_pos + step) % n];
+            last_mu = match self.contacts[idx].applied_current {
+                Some(current) => last_mu - current / nu,
+                None => last_mu,
+            };
+            self.contacts[idx].chemical_potential = last_mu;
+        }
+
+        let mu = |offset: usize| self.contacts[order[(start_pos + offset) % n]].chemical_potential;
+
+        let hall_resistance = if n >= 4 { (mu(3) - mu(1)) / drive_current } else { 1.0 / nu };
+        let longitudinal_resistance = if n >= 3 { (mu(1) - mu(0)) / drive_current } else { 0.0 };
+
+        TransportResult { hall_resistance, longitudinal_resistance }
     }
 
     /// Simulation step
@@ -278,6 +692,9 @@ impl HallSimulation {
         let magnetic_field = self.magnetic_field;
         let width = self.width;
         let height = self.height;
+        let disorder = &self.disorder;
+        let zeeman_energy = self.zeeman_energy();
+        let spin_orbit_alpha = self.spin_orbit_alpha;
 
         for electron in &mut self.electrons {
             // Cyclotron motion
@@ -337,12 +754,43 @@ impl HallSimulation {
                     radius * electron.phase.sin(),
                 );
                 electron.position = electron.guiding_center + offset;
+                electron.velocity = Vec2::new(
+                    -radius * omega * electron.phase.sin(),
+                    radius * omega * electron.phase.cos(),
+                );
 
-                // Guiding center drift in crossed E×B fields
-                let drift = electric_field.perp() / magnetic_field;
+                // Guiding center drift along equipotentials of the total
+                // potential (uniform Hall field + disorder landscape),
+                // sampled near the guiding center rather than assuming a
+                // uniform E×B drift everywhere
+                let total_grad = electric_field + disorder.gradient(electron.guiding_center);
+                let drift = total_grad.perp() / magnetic_field;
                 electron.guiding_center += drift * dt;
             }
 
+            // Zeeman phase evolution of each spinor component, plus
+            // optional spin-orbit mixing that rotates weight between them
+            // (`offdiag = α(kx - i·ky)`) so spin stops being a good quantum
+            // number once `spin_orbit_alpha` is nonzero
+            let e_up = HBAR * omega * (electron.landau_level as f32 + 0.5) - zeeman_energy / 2.0;
+            let e_down = HBAR * omega * (electron.landau_level as f32 + 0.5) + zeeman_energy / 2.0;
+            electron.spin_up = electron.spin_up * Complex::exp_i(-e_up * dt / HBAR);
+            electron.spin_down = electron.spin_down * Complex::exp_i(-e_down * dt / HBAR);
+
+            if spin_orbit_alpha != 0.0 {
+                let offdiag = Complex::new(spin_orbit_alpha * electron.velocity.x, -spin_orbit_alpha * electron.velocity.y);
+                let new_up = electron.spin_up + offdiag.conj() * electron.spin_down * dt;
+                let new_down = electron.spin_down + offdiag * electron.spin_up * dt;
+                let norm = (new_up.norm_sq() + new_down.norm_sq()).sqrt().max(1e-6);
+                electron.spin_up = new_up * (1.0 / norm);
+                electron.spin_down = new_down * (1.0 / norm);
+            }
+            electron.spin = if electron.spin_up.norm_sq() >= electron.spin_down.norm_sq() {
+                Spin::Up
+            } else {
+                Spin::Down
+            };
+
             // Boundary conditions - inline
             let half_w = width / 2.0;
             let half_h = height / 2.0;
@@ -445,26 +893,47 @@ impl HallSimulation {
         self.magnetic_field = b.max(0.1);
         self.cyclotron_freq = E_CHARGE * self.magnetic_field / M_EFF;
         self.magnetic_length = (HBAR / (E_CHARGE * self.magnetic_field)).sqrt();
+        self.disorder = DisorderPotential::generate(
+            self.width,
+            self.height,
+            self.magnetic_length * CORRELATION_LENGTH_FACTOR,
+            N_IMPURITIES,
+            DISORDER_AMPLITUDE_FRACTION * HBAR * self.cyclotron_freq,
+        );
+        self.level_broadening = LEVEL_BROADENING_FRACTION * self.cyclotron_freq;
 
         self.initialize_levels(5);
         self.fill_electrons(self.electrons.len());
     }
 
-    /// Get render data
-    pub fn get_electron_data(&self) -> Vec<(Vec2, [f32; 4], bool)> {
+    /// Get render data: position, color, whether it's an edge state, and
+    /// whether its contour is localized in the bulk rather than extended
+    pub fn get_electron_data(&self) -> Vec<(Vec2, [f32; 4], bool, bool)> {
         self.electrons
             .iter()
             .map(|e| {
                 let color = if e.is_edge_state {
-                    [1.0, 0.8, 0.2, 1.0] // Yellow for edge states
+                    // Spin-up edge states stay the original yellow; spin-down
+                    // is tinted orange so the two branches read as distinct
+                    match e.spin {
+                        Spin::Up => [1.0, 0.8, 0.2, 1.0],
+                        Spin::Down => [1.0, 0.5, 0.1, 1.0],
+                    }
                 } else {
-                    self.landau_levels[e.landau_level as usize].color
+                    self.level_for(e.landau_level, e.spin)
+                        .map(|level| level.color)
+                        .unwrap_or([0.5, 0.5, 0.5, 0.8])
                 };
-                (e.position, color, e.is_edge_state)
+                (e.position, color, e.is_edge_state, e.localized)
             })
             .collect()
     }
 
+    /// Find the sub-level matching a given orbital index and spin branch
+    fn level_for(&self, n: u32, spin: Spin) -> Option<&LandauLevel> {
+        self.landau_levels.iter().find(|l| l.n == n && l.spin == spin)
+    }
+
     /// Get cyclotron orbit visualization
     pub fn get_orbits(&self) -> Vec<(Vec2, f32, [f32; 4])> {
         self.electrons
@@ -472,12 +941,48 @@ impl HallSimulation {
             .filter(|e| !e.is_edge_state)
             .map(|e| {
                 let radius = self.cyclotron_radius(e.landau_level);
-                let color = self.landau_levels[e.landau_level as usize].color;
+                let color = self.level_for(e.landau_level, e.spin)
+                    .map(|level| level.color)
+                    .unwrap_or([0.5, 0.5, 0.5, 0.8]);
                 (e.guiding_center, radius, color)
             })
             .collect()
     }
 
+    /// Squared Landau-level wavefunction amplitude |ψ|² at `pos`, approximated
+    /// as the sum over electrons of a Gaussian rim of width `magnetic_length`
+    /// centered on each electron's cyclotron radius around its guiding
+    /// center — the ring-shaped density each Landau orbital actually has,
+    /// rather than the discrete point drawn for that electron.
+    pub fn probability_density(&self, pos: Vec2) -> f32 {
+        let rim_width = self.magnetic_length * 0.5;
+        self.electrons
+            .iter()
+            .map(|e| {
+                let ring_radius = self.cyclotron_radius(e.landau_level);
+                let r = (pos - e.guiding_center).length();
+                let d = r - ring_radius;
+                (-(d * d) / (2.0 * rim_width * rim_width)).exp()
+            })
+            .sum()
+    }
+
+    /// Iso-contour segments of [`Self::probability_density`] at `threshold`,
+    /// sampled on a `grid_res`×`grid_res` grid over the sample box, ready to
+    /// feed into `QuantumRenderer::render_lines`
+    pub fn probability_contours(&self, grid_res: usize, threshold: f32) -> Vec<(Vec2, Vec2)> {
+        let bounds = crate::marching_squares::GridBounds2D {
+            min: Vec2::new(-self.width / 2.0, -self.height / 2.0),
+            max: Vec2::new(self.width / 2.0, self.height / 2.0),
+        };
+        crate::marching_squares::extract_contours(
+            |pos| self.probability_density(pos),
+            bounds,
+            grid_res,
+            threshold,
+        )
+    }
+
     /// Get energy level diagram data
     pub fn get_level_diagram(&self) -> Vec<(f32, f32, [f32; 4])> {
         self.landau_levels
@@ -0,0 +1,58 @@
+//! Live σ_xy-vs-B plateau plot panel
+//!
+//! Tracks a rolling history of (B, σ_xy) samples as the user sweeps the
+//! magnetic field with Up/Down and draws them alongside horizontal markers
+//! at integer ν, so the characteristic staircase of quantized Hall
+//! plateaus is visible instead of only readable from the single-frame
+//! status bar numbers.
+
+use egui::Context;
+use egui_plot::{HLine, Line, Plot, PlotPoints};
+use std::collections::VecDeque;
+
+/// Maximum number of samples kept for the rolling plot
+const HISTORY_LEN: usize = 600;
+
+/// How many quantized plateaus to draw marker lines for
+const MAX_PLATEAU: i32 = 6;
+
+#[derive(Debug, Default)]
+pub struct PlateauHistory {
+    samples: VecDeque<[f64; 2]>,
+}
+
+impl PlateauHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one (B, σ_xy) sample; call whenever `set_magnetic_field` changes the field
+    pub fn record(&mut self, magnetic_field: f32, hall_conductance: f32) {
+        self.samples.push_back([magnetic_field as f64, hall_conductance as f64]);
+
+        while self.samples.len() > HISTORY_LEN {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Draw the rolling σ_xy-vs-B plot in a bottom panel, with horizontal
+    /// markers at σ_xy = 1, 2, 3, ... e²/h showing the quantized plateaus
+    pub fn draw(&self, ctx: &Context) {
+        egui::TopBottomPanel::bottom("hall_plateau_plot").min_height(180.0).show(ctx, |ui| {
+            ui.label("σ_xy vs B (quantized Hall plateaus)");
+            Plot::new("hall_plateau_plot_inner")
+                .height(150.0)
+                .x_axis_label("B (T)")
+                .y_axis_label("σ_xy (e²/h)")
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(PlotPoints::from(
+                        self.samples.iter().copied().collect::<Vec<_>>(),
+                    )));
+
+                    for n in 1..=MAX_PLATEAU {
+                        plot_ui.hline(HLine::new(n as f64).color(egui::Color32::from_gray(120)));
+                    }
+                });
+        });
+    }
+}
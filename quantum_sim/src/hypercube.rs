@@ -6,82 +6,33 @@
 use glam::{Mat4, Vec3, Vec4};
 use std::f32::consts::PI;
 
-/// A point in 4D space
-#[derive(Debug, Clone, Copy)]
-pub struct Vec4D {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
+/// A point in 4D space. A plain alias for `glam::Vec4` so the rotate/project
+/// pipeline below can batch onto glam's SIMD-backed `Mat4`/`Vec4` ops instead
+/// of a hand-rolled scalar struct.
+pub type Vec4D = Vec4;
+
+/// Perspective/orthographic projection from 4D to 3D, kept as an extension
+/// trait on `Vec4D` (rather than free functions) so call sites read the same
+/// as when this was a bespoke struct: `v.project_to_3d(w_distance)`.
+pub trait Vec4DExt {
+    /// Project to 3D using perspective projection: similar to 3D->2D but
+    /// with w as the "depth" axis
+    fn project_to_3d(&self, w_distance: f32) -> Vec3;
+    /// Orthographic projection (ignore w)
+    fn project_orthographic(&self) -> Vec3;
 }
 
-impl Vec4D {
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
-        Self { x, y, z, w }
-    }
-
-    pub fn zero() -> Self {
-        Self::new(0.0, 0.0, 0.0, 0.0)
-    }
-
-    pub fn length(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
-    }
-
-    pub fn normalize(&self) -> Self {
-        let len = self.length();
-        if len > 1e-10 {
-            Self::new(self.x / len, self.y / len, self.z / len, self.w / len)
-        } else {
-            *self
-        }
-    }
-
-    /// Project to 3D using perspective projection
-    pub fn project_to_3d(&self, w_distance: f32) -> Vec3 {
-        // Perspective projection from 4D to 3D
-        // Similar to 3D→2D but with w as the "depth" axis
+impl Vec4DExt for Vec4D {
+    fn project_to_3d(&self, w_distance: f32) -> Vec3 {
         let scale = w_distance / (w_distance - self.w);
         Vec3::new(self.x * scale, self.y * scale, self.z * scale)
     }
 
-    /// Orthographic projection (ignore w)
-    pub fn project_orthographic(&self) -> Vec3 {
+    fn project_orthographic(&self) -> Vec3 {
         Vec3::new(self.x, self.y, self.z)
     }
 }
 
-impl std::ops::Add for Vec4D {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self {
-        Self::new(
-            self.x + rhs.x,
-            self.y + rhs.y,
-            self.z + rhs.z,
-            self.w + rhs.w,
-        )
-    }
-}
-
-impl std::ops::Sub for Vec4D {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self {
-        Self::new(
-            self.x - rhs.x,
-            self.y - rhs.y,
-            self.z - rhs.z,
-            self.w - rhs.w,
-        )
-    }
-}
-
-impl std::ops::Mul<f32> for Vec4D {
-    type Output = Self;
-    fn mul(self, rhs: f32) -> Self {
-        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
-    }
-}
-
 /// 4x4 rotation matrix for 4D rotations
 /// In 4D, there are 6 basic rotation planes: XY, XZ, XW, YZ, YW, ZW
 #[derive(Debug, Clone, Copy)]
@@ -174,6 +125,289 @@ impl Default for Rotation4D {
     }
 }
 
+/// One of the six coordinate planes a simple 4D rotation can turn in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Plane4D {
+    Xy,
+    Xz,
+    Xw,
+    Yz,
+    Yw,
+    Zw,
+}
+
+/// Geometric product of two basis blades of Cl(4,0), each given as a 4-bit
+/// mask over {e_x, e_y, e_z, e_w} (bit 0 = x, ... bit 3 = w).
+///
+/// Walks the set bits of `b` from lowest to highest, counting how many bits
+/// of the accumulator sit above each one (each such pair needs a swap to
+/// bring the two blades into sorted order, flipping the sign) and then
+/// toggling that bit in the accumulator (cancelling it to identity if it was
+/// already present, since every basis vector here squares to `+1`).
+fn blade_product(a: u32, b: u32) -> (u32, f32) {
+    let mut sign = 1.0_f32;
+    let mut acc = a;
+    let mut remaining = b;
+    while remaining != 0 {
+        let bit = remaining & remaining.wrapping_neg();
+        let higher = acc & !(bit | bit.wrapping_sub(1));
+        if higher.count_ones() % 2 == 1 {
+            sign = -sign;
+        }
+        if acc & bit != 0 {
+            acc &= !bit;
+        } else {
+            acc |= bit;
+        }
+        remaining &= !bit;
+    }
+    (acc, sign)
+}
+
+/// Geometric product of two full Cl(4,0) multivectors, each stored as 16
+/// blade coefficients indexed by bitmask (index 0 = scalar, 1 = e_x, ...,
+/// 15 = the e_xyzw pseudoscalar).
+fn multivector_mul(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+    let mut result = [0.0_f32; 16];
+    for ma in 0..16u32 {
+        if a[ma as usize] == 0.0 {
+            continue;
+        }
+        for mb in 0..16u32 {
+            if b[mb as usize] == 0.0 {
+                continue;
+            }
+            let (mask, sign) = blade_product(ma, mb);
+            result[mask as usize] += sign * a[ma as usize] * b[mb as usize];
+        }
+    }
+    result
+}
+
+fn vec4d_to_multivector(v: Vec4D) -> [f32; 16] {
+    let mut m = [0.0_f32; 16];
+    m[0b0001] = v.x;
+    m[0b0010] = v.y;
+    m[0b0100] = v.z;
+    m[0b1000] = v.w;
+    m
+}
+
+fn multivector_to_vec4d(m: [f32; 16]) -> Vec4D {
+    Vec4D::new(m[0b0001], m[0b0010], m[0b0100], m[0b1000])
+}
+
+/// A rotor in the even subalgebra of Cl(4,0): a scalar, the six bivectors
+/// (one per rotation plane), and the e_xyzw pseudoscalar.
+///
+/// Unlike [`Rotation4D`], which applies six plane rotations sequentially (so
+/// the result depends on the order the `if` blocks happen to run in and two
+/// independent double rotations don't compose smoothly), a unit rotor
+/// (`self * self.reverse() == identity`) represents the *combined* rotation
+/// as a single order-independent object. Rotating a vector is the sandwich
+/// product `v' = R v reverse(R)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Rotor4D {
+    pub s: f32,
+    pub b_xy: f32,
+    pub b_xz: f32,
+    pub b_xw: f32,
+    pub b_yz: f32,
+    pub b_yw: f32,
+    pub b_zw: f32,
+    pub q_xyzw: f32,
+}
+
+impl Rotor4D {
+    /// The rotor that leaves every vector unchanged
+    pub fn identity() -> Self {
+        Self {
+            s: 1.0,
+            b_xy: 0.0,
+            b_xz: 0.0,
+            b_xw: 0.0,
+            b_yz: 0.0,
+            b_yw: 0.0,
+            b_zw: 0.0,
+            q_xyzw: 0.0,
+        }
+    }
+
+    /// The rotor `exp(theta/2 * e_ij) = cos(theta/2) + sin(theta/2) * e_ij`
+    /// for a simple rotation by `angle` in a single coordinate plane
+    pub fn plane_rotation(plane: Plane4D, angle: f32) -> Self {
+        let (sin_half, cos_half) = (angle * 0.5).sin_cos();
+        let mut rotor = Self::identity();
+        rotor.s = cos_half;
+        match plane {
+            Plane4D::Xy => rotor.b_xy = sin_half,
+            Plane4D::Xz => rotor.b_xz = sin_half,
+            Plane4D::Xw => rotor.b_xw = sin_half,
+            Plane4D::Yz => rotor.b_yz = sin_half,
+            Plane4D::Yw => rotor.b_yw = sin_half,
+            Plane4D::Zw => rotor.b_zw = sin_half,
+        }
+        rotor
+    }
+
+    fn to_multivector(self) -> [f32; 16] {
+        let mut m = [0.0_f32; 16];
+        m[0b0000] = self.s;
+        m[0b0011] = self.b_xy;
+        m[0b0101] = self.b_xz;
+        m[0b1001] = self.b_xw;
+        m[0b0110] = self.b_yz;
+        m[0b1010] = self.b_yw;
+        m[0b1100] = self.b_zw;
+        m[0b1111] = self.q_xyzw;
+        m
+    }
+
+    fn from_multivector(m: [f32; 16]) -> Self {
+        Self {
+            s: m[0b0000],
+            b_xy: m[0b0011],
+            b_xz: m[0b0101],
+            b_xw: m[0b1001],
+            b_yz: m[0b0110],
+            b_yw: m[0b1010],
+            b_zw: m[0b1100],
+            q_xyzw: m[0b1111],
+        }
+    }
+
+    /// The reverse `reverse(R)`: each bivector term picks up a sign flip
+    /// (reversing the order of its two basis vectors), while the scalar and
+    /// pseudoscalar terms are unchanged
+    pub fn reverse(&self) -> Self {
+        Self {
+            s: self.s,
+            b_xy: -self.b_xy,
+            b_xz: -self.b_xz,
+            b_xw: -self.b_xw,
+            b_yz: -self.b_yz,
+            b_yw: -self.b_yw,
+            b_zw: -self.b_zw,
+            q_xyzw: self.q_xyzw,
+        }
+    }
+
+    /// Euclidean norm of the 8 components; a unit rotor has norm 1
+    pub fn norm(&self) -> f32 {
+        (self.s * self.s
+            + self.b_xy * self.b_xy
+            + self.b_xz * self.b_xz
+            + self.b_xw * self.b_xw
+            + self.b_yz * self.b_yz
+            + self.b_yw * self.b_yw
+            + self.b_zw * self.b_zw
+            + self.q_xyzw * self.q_xyzw)
+            .sqrt()
+    }
+
+    /// Rescale back onto the unit rotor manifold, countering the drift that
+    /// accumulates from repeated per-step integration
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        if norm > 1e-10 {
+            Self {
+                s: self.s / norm,
+                b_xy: self.b_xy / norm,
+                b_xz: self.b_xz / norm,
+                b_xw: self.b_xw / norm,
+                b_yz: self.b_yz / norm,
+                b_yw: self.b_yw / norm,
+                b_zw: self.b_zw / norm,
+                q_xyzw: self.q_xyzw / norm,
+            }
+        } else {
+            Self::identity()
+        }
+    }
+
+    /// Rotate `v` by the sandwich product `R v reverse(R)`, computed as two
+    /// geometric products: `R * v` (an even times a vector, giving an odd
+    /// vector + trivector multivector), then that result times `reverse(R)`.
+    /// The trivector terms cancel for a unit rotor, so only the vector grade
+    /// of the final product is read back out.
+    pub fn rotate(&self, v: Vec4D) -> Vec4D {
+        let rotor = self.to_multivector();
+        let reverse = self.reverse().to_multivector();
+        let odd = multivector_mul(&rotor, &vec4d_to_multivector(v));
+        let result = multivector_mul(&odd, &reverse);
+        multivector_to_vec4d(result)
+    }
+
+    /// Build the composite 4x4 rotation matrix for this rotor by rotating
+    /// the four basis vectors once and reading off the columns, so a whole
+    /// frame's worth of vertices can be transformed with a single
+    /// `Mat4 * Vec4` each instead of re-deriving the rotor's action per call
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from_cols(
+            self.rotate(Vec4D::new(1.0, 0.0, 0.0, 0.0)),
+            self.rotate(Vec4D::new(0.0, 1.0, 0.0, 0.0)),
+            self.rotate(Vec4D::new(0.0, 0.0, 1.0, 0.0)),
+            self.rotate(Vec4D::new(0.0, 0.0, 0.0, 1.0)),
+        )
+    }
+
+    /// Advance this rotor by one step of angular velocity bivector `omega`
+    /// (the six `Rotation4D` plane fields, reused here as a bivector rather
+    /// than as accumulated angles), via the first-order update
+    /// `R <- normalize((1 + dt/2 * omega) * R)`. This avoids the
+    /// gimbal-lock-like artifacts of integrating six angles independently.
+    pub fn integrate(&self, omega: &Rotation4D, dt: f32) -> Self {
+        let half_dt = 0.5 * dt;
+        let delta = Self {
+            s: 1.0,
+            b_xy: half_dt * omega.xy,
+            b_xz: half_dt * omega.xz,
+            b_xw: half_dt * omega.xw,
+            b_yz: half_dt * omega.yz,
+            b_yw: half_dt * omega.yw,
+            b_zw: half_dt * omega.zw,
+            q_xyzw: 0.0,
+        };
+        (delta * *self).normalize()
+    }
+
+    /// Normalized linear interpolation between two rotors, for smooth
+    /// transitions between preset orientations. Takes the shorter path by
+    /// flipping `b` onto the same side of the double cover as `a` first.
+    pub fn slerp(a: &Rotor4D, b: &Rotor4D, t: f32) -> Self {
+        let dot = a.s * b.s
+            + a.b_xy * b.b_xy
+            + a.b_xz * b.b_xz
+            + a.b_xw * b.b_xw
+            + a.b_yz * b.b_yz
+            + a.b_yw * b.b_yw
+            + a.b_zw * b.b_zw
+            + a.q_xyzw * b.q_xyzw;
+        let sign = if dot < 0.0 { -1.0 } else { 1.0 };
+        Self {
+            s: a.s + (sign * b.s - a.s) * t,
+            b_xy: a.b_xy + (sign * b.b_xy - a.b_xy) * t,
+            b_xz: a.b_xz + (sign * b.b_xz - a.b_xz) * t,
+            b_xw: a.b_xw + (sign * b.b_xw - a.b_xw) * t,
+            b_yz: a.b_yz + (sign * b.b_yz - a.b_yz) * t,
+            b_yw: a.b_yw + (sign * b.b_yw - a.b_yw) * t,
+            b_zw: a.b_zw + (sign * b.b_zw - a.b_zw) * t,
+            q_xyzw: a.q_xyzw + (sign * b.q_xyzw - a.q_xyzw) * t,
+        }
+        .normalize()
+    }
+}
+
+impl std::ops::Mul for Rotor4D {
+    type Output = Rotor4D;
+
+    /// The geometric product of two rotors, which stays in the even
+    /// subalgebra and so composes two rotations into a single rotor
+    fn mul(self, rhs: Rotor4D) -> Rotor4D {
+        Rotor4D::from_multivector(multivector_mul(&self.to_multivector(), &rhs.to_multivector()))
+    }
+}
+
 /// Edge connecting two vertices
 #[derive(Debug, Clone, Copy)]
 pub struct Edge4D {
@@ -189,10 +423,531 @@ pub struct Polytope4D {
     pub edges: Vec<Edge4D>,
     /// Optional: faces (for more complex rendering)
     pub faces: Vec<Vec<usize>>,
+    /// 3-cells, each a list of indices into `faces` bounding it; empty for
+    /// the built-in generators below, populated when loading a 4OFF file
+    pub cells: Vec<Vec<usize>>,
     /// Name of the polytope
     pub name: String,
 }
 
+/// Errors that can occur while reading or writing a 4OFF file
+#[derive(Debug, thiserror::Error)]
+pub enum OffError {
+    #[error("failed to read/write OFF data: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("malformed OFF data: {0}")]
+    Malformed(String),
+}
+
+fn next_off_line<R: std::io::BufRead>(
+    lines: &mut std::io::Lines<R>,
+) -> Result<String, OffError> {
+    for line in lines {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        return Ok(trimmed.to_string());
+    }
+    Err(OffError::Malformed("unexpected end of OFF data".to_string()))
+}
+
+/// Parse an OFF index-list line (`n i0 i1 ... i(n-1)`), returning the
+/// indices and checking that the declared count matches
+fn parse_off_index_list(line: &str) -> Result<Vec<usize>, OffError> {
+    let mut tokens = line.split_whitespace();
+    let count: usize = tokens
+        .next()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| OffError::Malformed("missing index-list length".to_string()))?;
+    let indices: Vec<usize> = tokens.filter_map(|s| s.parse().ok()).collect();
+    if indices.len() != count {
+        return Err(OffError::Malformed(format!(
+            "index list declared {count} entries but found {}",
+            indices.len()
+        )));
+    }
+    Ok(indices)
+}
+
+/// All 12 even permutations of the indices `[0, 1, 2, 3]`, found by
+/// generating all 24 permutations and keeping the ones with an even number
+/// of inversions relative to sorted order
+fn even_permutations_of_4() -> Vec<[usize; 4]> {
+    fn permute(arr: &mut [usize; 4], k: usize, out: &mut Vec<[usize; 4]>) {
+        if k == arr.len() {
+            let inversions: usize = (0..4)
+                .flat_map(|i| (i + 1..4).map(move |j| (i, j)))
+                .filter(|&(i, j)| arr[i] > arr[j])
+                .count();
+            if inversions % 2 == 0 {
+                out.push(*arr);
+            }
+            return;
+        }
+        for i in k..arr.len() {
+            arr.swap(k, i);
+            permute(arr, k + 1, out);
+            arr.swap(k, i);
+        }
+    }
+
+    let mut out = Vec::with_capacity(12);
+    permute(&mut [0, 1, 2, 3], 0, &mut out);
+    out
+}
+
+/// Connect every pair of vertices separated by the minimum nonzero distance
+/// found among all pairs (the shared edge length of a vertex-transitive
+/// polytope), within a small relative tolerance for floating-point noise
+fn edges_at_minimum_distance(vertices: &[Vec4D]) -> Vec<Edge4D> {
+    let dist_sq = |a: Vec4D, b: Vec4D| {
+        let d = a - b;
+        d.x * d.x + d.y * d.y + d.z * d.z + d.w * d.w
+    };
+
+    let mut min_dist_sq = f32::MAX;
+    for i in 0..vertices.len() {
+        for j in (i + 1)..vertices.len() {
+            let d = dist_sq(vertices[i], vertices[j]);
+            if d > 1e-6 && d < min_dist_sq {
+                min_dist_sq = d;
+            }
+        }
+    }
+
+    let mut edges = Vec::new();
+    for i in 0..vertices.len() {
+        for j in (i + 1)..vertices.len() {
+            let d = dist_sq(vertices[i], vertices[j]);
+            if (d - min_dist_sq).abs() < min_dist_sq * 0.01 {
+                edges.push(Edge4D { v1: i, v2: j });
+            }
+        }
+    }
+    edges
+}
+
+/// Dot product of two 4D vectors, used by the coplanarity test below
+fn dot4(a: Vec4D, b: Vec4D) -> f32 {
+    a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+}
+
+/// Whether four points lie in a common 2D affine plane, tested via the Gram
+/// determinant of the edge vectors from `a`: three vectors spanning at most
+/// a 2D subspace are linearly dependent, so the determinant of their pairwise
+/// dot products vanishes. Cheap relative-tolerance check since the
+/// determinant's magnitude scales with the vectors' lengths.
+fn is_coplanar_quad(a: Vec4D, b: Vec4D, c: Vec4D, d: Vec4D) -> bool {
+    let u = b - a;
+    let v = d - a;
+    let w = c - a;
+    let g = [
+        [dot4(u, u), dot4(u, v), dot4(u, w)],
+        [dot4(v, u), dot4(v, v), dot4(v, w)],
+        [dot4(w, u), dot4(w, v), dot4(w, w)],
+    ];
+    let det = g[0][0] * (g[1][1] * g[2][2] - g[1][2] * g[2][1])
+        - g[0][1] * (g[1][0] * g[2][2] - g[1][2] * g[2][0])
+        + g[0][2] * (g[1][0] * g[2][1] - g[1][1] * g[2][0]);
+    let scale = (g[0][0] * g[1][1] * g[2][2]).abs().max(1e-6);
+    det.abs() < scale * 1e-3
+}
+
+/// Canonical key for a 4-cycle up to rotation and direction, so the same
+/// face found starting from a different edge/vertex dedupes to one entry
+fn canonical_quad_key(quad: [usize; 4]) -> [usize; 4] {
+    let [a, b, c, d] = quad;
+    let variants = [
+        [a, b, c, d],
+        [b, c, d, a],
+        [c, d, a, b],
+        [d, a, b, c],
+        [a, d, c, b],
+        [d, c, b, a],
+        [c, b, a, d],
+        [b, a, d, c],
+    ];
+    variants.into_iter().min().unwrap()
+}
+
+/// Find the quadrilateral 2-faces of a polytope by searching its edge graph
+/// for minimal 4-cycles (cycles whose two diagonals are *not* edges, so they
+/// aren't just a chord away from being two triangles) and keeping the ones
+/// whose four vertices are coplanar. This is how the tesseract's 24 square
+/// faces fall out without hand-coding "differs in exactly two coordinates":
+/// a square face's boundary is exactly an unchorded, coplanar 4-cycle.
+/// Polytopes whose 2-faces are triangles or pentagons (the 16-/24-/600-/
+/// 120-cell) simply yield no faces here.
+fn find_quad_faces(vertices: &[Vec4D], edges: &[Edge4D]) -> Vec<Vec<usize>> {
+    let n = vertices.len();
+    let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut edge_set = std::collections::HashSet::new();
+    for edge in edges {
+        adjacency[edge.v1].push(edge.v2);
+        adjacency[edge.v2].push(edge.v1);
+        edge_set.insert((edge.v1.min(edge.v2), edge.v1.max(edge.v2)));
+    }
+    let is_edge = |a: usize, b: usize| edge_set.contains(&(a.min(b), a.max(b)));
+
+    let mut faces = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+
+    for a in 0..n {
+        for &b in &adjacency[a] {
+            if b <= a {
+                continue;
+            }
+            for &c in &adjacency[b] {
+                if c == a {
+                    continue;
+                }
+                for &d in &adjacency[c] {
+                    if d == a || d == b {
+                        continue;
+                    }
+                    if !is_edge(d, a) {
+                        continue;
+                    }
+                    // Reject chorded cycles: a real quad face has no edge
+                    // across either diagonal
+                    if is_edge(a, c) || is_edge(b, d) {
+                        continue;
+                    }
+                    let key = canonical_quad_key([a, b, c, d]);
+                    if !seen.insert(key) {
+                        continue;
+                    }
+                    if is_coplanar_quad(vertices[a], vertices[b], vertices[c], vertices[d]) {
+                        faces.push(vec![a, b, c, d]);
+                    }
+                }
+            }
+        }
+    }
+    faces
+}
+
+/// Determinant of a small square matrix (up to 4x4), via cofactor expansion
+/// along the first row. Used to test linear/affine independence through the
+/// Gram determinant: a set of vectors is dependent iff it vanishes.
+fn det_square(m: &[Vec<f32>]) -> f32 {
+    let n = m.len();
+    if n == 1 {
+        return m[0][0];
+    }
+    if n == 2 {
+        return m[0][0] * m[1][1] - m[0][1] * m[1][0];
+    }
+    let mut result = 0.0;
+    let mut sign = 1.0;
+    for col in 0..n {
+        let minor: Vec<Vec<f32>> = m[1..]
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(c, _)| c != col)
+                    .map(|(_, &v)| v)
+                    .collect()
+            })
+            .collect();
+        result += sign * m[0][col] * det_square(&minor);
+        sign = -sign;
+    }
+    result
+}
+
+/// Whether a set of up to four 4D vectors are linearly independent, checked
+/// via the Gram determinant of their pairwise dot products (nonzero iff
+/// independent), scaled by the diagonal so the tolerance is unit-free
+fn is_affinely_independent(vectors: &[Vec4D]) -> bool {
+    if vectors.is_empty() {
+        return true;
+    }
+    let n = vectors.len();
+    let gram: Vec<Vec<f32>> = (0..n)
+        .map(|i| (0..n).map(|j| dot4(vectors[i], vectors[j])).collect())
+        .collect();
+    let scale: f32 = (0..n).map(|i| gram[i][i]).product::<f32>().abs().max(1e-9);
+    det_square(&gram).abs() > scale * 1e-6
+}
+
+/// Greedily pick 5 affinely independent vertices to seed the initial
+/// 5-simplex for [`convex_hull_4d`]. Returns `None` if the point set is
+/// degenerate (fewer than 5 points, or all points confined to a hyperplane).
+fn find_initial_simplex(vertices: &[Vec4D]) -> Option<[usize; 5]> {
+    if vertices.len() < 5 {
+        return None;
+    }
+    let mut picked: Vec<usize> = vec![0];
+    for candidate in 1..vertices.len() {
+        if picked.len() == 5 {
+            break;
+        }
+        let base = vertices[picked[0]];
+        let mut vectors: Vec<Vec4D> = picked[1..].iter().map(|&i| vertices[i] - base).collect();
+        vectors.push(vertices[candidate] - base);
+        if is_affinely_independent(&vectors) {
+            picked.push(candidate);
+        }
+    }
+    if picked.len() == 5 {
+        Some([picked[0], picked[1], picked[2], picked[3], picked[4]])
+    } else {
+        None
+    }
+}
+
+/// Generalized 4D cross product: the vector orthogonal to all three inputs,
+/// found by cofactor-expanding the 4x4 determinant whose first row is the
+/// basis vectors `(e_x, e_y, e_z, e_w)` and whose other three rows are `u`,
+/// `v`, `w`. This is the 4D analogue of the 3D cross product, giving a
+/// tetrahedral facet's hyperplane normal from three of its edge vectors.
+fn cross4(u: Vec4D, v: Vec4D, w: Vec4D) -> Vec4D {
+    let minor3 = |a0: f32, a1: f32, a2: f32, b0: f32, b1: f32, b2: f32, c0: f32, c1: f32, c2: f32| {
+        a0 * (b1 * c2 - b2 * c1) - a1 * (b0 * c2 - b2 * c0) + a2 * (b0 * c1 - b1 * c0)
+    };
+    Vec4D::new(
+        minor3(u.y, u.z, u.w, v.y, v.z, v.w, w.y, w.z, w.w),
+        -minor3(u.x, u.z, u.w, v.x, v.z, v.w, w.x, w.z, w.w),
+        minor3(u.x, u.y, u.w, v.x, v.y, v.w, w.x, w.y, w.w),
+        -minor3(u.x, u.y, u.z, v.x, v.y, v.z, w.x, w.y, w.z),
+    )
+}
+
+/// A tetrahedral facet of an in-progress 4D convex hull: four vertex
+/// indices plus the outward hyperplane normal/offset (`dot(normal, x) =
+/// offset` on the plane, `> offset` strictly outside the hull)
+#[derive(Debug, Clone)]
+struct Facet4D {
+    verts: [usize; 4],
+    normal: Vec4D,
+    offset: f32,
+}
+
+/// The four triangular ridges of a tetrahedral facet, each omitting one
+/// vertex
+fn facet_ridges(verts: [usize; 4]) -> [[usize; 3]; 4] {
+    [
+        [verts[1], verts[2], verts[3]],
+        [verts[0], verts[2], verts[3]],
+        [verts[0], verts[1], verts[3]],
+        [verts[0], verts[1], verts[2]],
+    ]
+}
+
+fn sorted_triangle(tri: [usize; 3]) -> [usize; 3] {
+    let mut t = tri;
+    t.sort_unstable();
+    t
+}
+
+/// Build a facet from four vertex indices, orienting its normal away from
+/// `interior` (a point known to stay inside the hull for its entire growth,
+/// e.g. the seed simplex's centroid)
+fn make_facet(verts: [usize; 4], vertices: &[Vec4D], interior: Vec4D) -> Facet4D {
+    let v0 = vertices[verts[0]];
+    let e1 = vertices[verts[1]] - v0;
+    let e2 = vertices[verts[2]] - v0;
+    let e3 = vertices[verts[3]] - v0;
+    let mut normal = cross4(e1, e2, e3);
+    let mut offset = dot4(normal, v0);
+    if dot4(normal, interior) > offset {
+        normal = normal * -1.0;
+        offset = -offset;
+    }
+    Facet4D { verts, normal, offset }
+}
+
+/// Quantized key identifying a facet's supporting hyperplane, so facets
+/// produced by triangulating the same flat 3-cell (e.g. the six tetrahedra
+/// beneath-beyond triangulation gives a cube cell) collapse to one cell
+fn hyperplane_key(normal: Vec4D, offset: f32) -> (i64, i64, i64, i64, i64) {
+    let len = normal.length().max(1e-9);
+    let n = normal * (1.0 / len);
+    let o = offset / len;
+    const SCALE: f32 = 1000.0;
+    (
+        (n.x * SCALE).round() as i64,
+        (n.y * SCALE).round() as i64,
+        (n.z * SCALE).round() as i64,
+        (n.w * SCALE).round() as i64,
+        (o * SCALE).round() as i64,
+    )
+}
+
+/// Derive a `Polytope4D`'s connectivity purely from its vertex positions, via
+/// an incremental (beneath-beyond) 4D convex hull.
+///
+/// Starts from an initial non-degenerate 5-simplex, then for each remaining
+/// point finds the facets it sees beyond (its "visible" set), replaces them
+/// with new facets coning the point to the horizon ridges (the triangular
+/// boundary between visible and non-visible facets), and repeats. The final
+/// tetrahedral facets are then merged: two facets sharing a triangular ridge
+/// collapse that ridge away if they lie in the same hyperplane (it was only
+/// an artifact of triangulating one flat 3-cell), otherwise the ridge is a
+/// genuine 2-face between two different cells.
+///
+/// Degenerate input (fewer than 5 points, or all points coplanar/degenerate)
+/// is reported by returning the bare point cloud with no edges or faces
+/// rather than panicking.
+pub fn convex_hull_4d(vertices: &[Vec4D]) -> Polytope4D {
+    let Some(simplex) = find_initial_simplex(vertices) else {
+        return Polytope4D {
+            vertices: vertices.to_vec(),
+            edges: Vec::new(),
+            faces: Vec::new(),
+            cells: Vec::new(),
+            name: "Convex hull (degenerate input)".to_string(),
+        };
+    };
+
+    let interior = simplex.iter().fold(Vec4D::ZERO, |acc, &i| acc + vertices[i]) * 0.2;
+
+    let mut facets: Vec<Facet4D> = (0..5)
+        .map(|skip| {
+            let mut verts = [0usize; 4];
+            let mut w = 0;
+            for (i, &v) in simplex.iter().enumerate() {
+                if i == skip {
+                    continue;
+                }
+                verts[w] = v;
+                w += 1;
+            }
+            make_facet(verts, vertices, interior)
+        })
+        .collect();
+
+    let mut included: std::collections::HashSet<usize> = simplex.iter().copied().collect();
+
+    for (p_idx, &p) in vertices.iter().enumerate() {
+        if included.contains(&p_idx) {
+            continue;
+        }
+
+        let visible_set: std::collections::HashSet<usize> = facets
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| dot4(f.normal, p) > f.offset + f.normal.length() * 1e-4)
+            .map(|(idx, _)| idx)
+            .collect();
+
+        if visible_set.is_empty() {
+            continue;
+        }
+
+        let mut ridge_owners: std::collections::HashMap<[usize; 3], Vec<usize>> =
+            std::collections::HashMap::new();
+        for (idx, f) in facets.iter().enumerate() {
+            for ridge in facet_ridges(f.verts) {
+                ridge_owners.entry(sorted_triangle(ridge)).or_default().push(idx);
+            }
+        }
+
+        let mut horizon: Vec<[usize; 3]> = Vec::new();
+        for &vi in &visible_set {
+            for ridge in facet_ridges(facets[vi].verts) {
+                let owners = &ridge_owners[&sorted_triangle(ridge)];
+                if owners.iter().any(|o| !visible_set.contains(o)) {
+                    horizon.push(ridge);
+                }
+            }
+        }
+
+        let mut new_facets: Vec<Facet4D> = facets
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !visible_set.contains(idx))
+            .map(|(_, f)| f.clone())
+            .collect();
+
+        for ridge in horizon {
+            let verts = [ridge[0], ridge[1], ridge[2], p_idx];
+            new_facets.push(make_facet(verts, vertices, interior));
+        }
+
+        facets = new_facets;
+        included.insert(p_idx);
+    }
+
+    let mut cluster_ids: std::collections::HashMap<(i64, i64, i64, i64, i64), usize> =
+        std::collections::HashMap::new();
+    let cell_of: Vec<usize> = facets
+        .iter()
+        .map(|f| {
+            let key = hyperplane_key(f.normal, f.offset);
+            let next_id = cluster_ids.len();
+            *cluster_ids.entry(key).or_insert(next_id)
+        })
+        .collect();
+    let num_cells = cluster_ids.len();
+
+    let mut triangle_owners: std::collections::HashMap<[usize; 3], Vec<usize>> =
+        std::collections::HashMap::new();
+    for (idx, f) in facets.iter().enumerate() {
+        for ridge in facet_ridges(f.verts) {
+            triangle_owners.entry(sorted_triangle(ridge)).or_default().push(idx);
+        }
+    }
+
+    let mut faces: Vec<Vec<usize>> = Vec::new();
+    let mut cells: Vec<Vec<usize>> = vec![Vec::new(); num_cells];
+    let mut triangles: Vec<_> = triangle_owners.into_iter().collect();
+    triangles.sort_unstable_by_key(|(tri, _)| *tri);
+
+    for (tri, owners) in triangles {
+        match owners.as_slice() {
+            [a] => {
+                let face_idx = faces.len();
+                faces.push(tri.to_vec());
+                cells[cell_of[*a]].push(face_idx);
+            }
+            [a, b] => {
+                if cell_of[*a] == cell_of[*b] {
+                    continue; // internal triangulation seam within one flat cell
+                }
+                let face_idx = faces.len();
+                faces.push(tri.to_vec());
+                cells[cell_of[*a]].push(face_idx);
+                cells[cell_of[*b]].push(face_idx);
+            }
+            _ => {} // a manifold hull never shares a ridge among 3+ facets
+        }
+    }
+
+    Polytope4D {
+        vertices: vertices.to_vec(),
+        edges: edges_from_faces(&faces),
+        faces,
+        cells,
+        name: "Convex hull".to_string(),
+    }
+}
+
+/// Collect the boundary edges of a set of polygonal faces, deduplicating
+/// edges shared between adjacent faces
+fn edges_from_faces(faces: &[Vec<usize>]) -> Vec<Edge4D> {
+    let mut seen = std::collections::HashSet::new();
+    let mut edges = Vec::new();
+    for face in faces {
+        let n = face.len();
+        for i in 0..n {
+            let (a, b) = (face[i], face[(i + 1) % n]);
+            let key = (a.min(b), a.max(b));
+            if seen.insert(key) {
+                edges.push(Edge4D {
+                    v1: key.0,
+                    v2: key.1,
+                });
+            }
+        }
+    }
+    edges
+}
+
 impl Polytope4D {
     /// Create a tesseract (4D hypercube)
     pub fn tesseract(size: f32) -> Self {
@@ -226,10 +981,13 @@ impl Polytope4D {
             }
         }
 
+        let faces = find_quad_faces(&vertices, &edges);
+
         Self {
             vertices,
             edges,
-            faces: Vec::new(),
+            faces,
+            cells: Vec::new(),
             name: "Tesseract".to_string(),
         }
     }
@@ -257,10 +1015,13 @@ impl Polytope4D {
             }
         }
 
+        let faces = find_quad_faces(&vertices, &edges);
+
         Self {
             vertices,
             edges,
-            faces: Vec::new(),
+            faces,
+            cells: Vec::new(),
             name: "16-cell".to_string(),
         }
     }
@@ -297,10 +1058,13 @@ impl Polytope4D {
             }
         }
 
+        let faces = find_quad_faces(&vertices, &edges);
+
         Self {
             vertices,
             edges,
-            faces: Vec::new(),
+            faces,
+            cells: Vec::new(),
             name: "24-cell".to_string(),
         }
     }
@@ -325,22 +1089,264 @@ impl Polytope4D {
             }
         }
 
+        let faces = find_quad_faces(&vertices, &edges);
+
         Self {
             vertices,
             edges,
-            faces: Vec::new(),
+            faces,
+            cells: Vec::new(),
             name: "5-cell (Pentatope)".to_string(),
         }
     }
+
+    /// Create a 600-cell (regular 4-polytope with 120 vertices and 600
+    /// tetrahedral cells)
+    pub fn cell_600(size: f32) -> Self {
+        let phi = (1.0 + 5.0_f32.sqrt()) / 2.0;
+        let mut vertices = Vec::with_capacity(120);
+
+        // 8 permutations of (±1, 0, 0, 0)
+        for axis in 0..4 {
+            for &sign in &[-1.0_f32, 1.0] {
+                let mut v = [0.0_f32; 4];
+                v[axis] = sign * size;
+                vertices.push(Vec4D::new(v[0], v[1], v[2], v[3]));
+            }
+        }
+
+        // 16 points (±½, ±½, ±½, ±½)
+        let half = 0.5 * size;
+        for &sx in &[-1.0_f32, 1.0] {
+            for &sy in &[-1.0_f32, 1.0] {
+                for &sz in &[-1.0_f32, 1.0] {
+                    for &sw in &[-1.0_f32, 1.0] {
+                        vertices.push(Vec4D::new(sx * half, sy * half, sz * half, sw * half));
+                    }
+                }
+            }
+        }
+
+        // 96 even permutations of (±φ/2, ±½, ±1/(2φ), 0)
+        let values = [phi / 2.0 * size, 0.5 * size, 1.0 / (2.0 * phi) * size, 0.0];
+        for perm in even_permutations_of_4() {
+            for mask in 0..8u32 {
+                let mut coords = [0.0_f32; 4];
+                let mut bit = 0;
+                for (slot, &value_index) in perm.iter().enumerate() {
+                    if value_index == 3 {
+                        coords[slot] = 0.0;
+                    } else {
+                        let sign = if (mask >> bit) & 1 == 1 { -1.0 } else { 1.0 };
+                        coords[slot] = sign * values[value_index];
+                        bit += 1;
+                    }
+                }
+                vertices.push(Vec4D::new(coords[0], coords[1], coords[2], coords[3]));
+            }
+        }
+
+        let edges = edges_at_minimum_distance(&vertices);
+
+        let faces = find_quad_faces(&vertices, &edges);
+
+        Self {
+            vertices,
+            edges,
+            faces,
+            cells: Vec::new(),
+            name: "600-cell".to_string(),
+        }
+    }
+
+    /// Create a 120-cell (regular 4-polytope with 600 vertices and 120
+    /// dodecahedral cells) as the dual of the 600-cell: one vertex per
+    /// tetrahedral cell of the 600-cell (its centroid), with an edge between
+    /// any two cells that share a triangular face (i.e. 3 of their 4
+    /// vertices)
+    pub fn cell_120(size: f32) -> Self {
+        let six_hundred = Self::cell_600(size);
+        let n = six_hundred.vertices.len();
+
+        let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for edge in &six_hundred.edges {
+            adjacency[edge.v1].push(edge.v2);
+            adjacency[edge.v2].push(edge.v1);
+        }
+
+        // Each tetrahedral cell is 4 mutually-adjacent vertices; picking `a`
+        // as the smallest index in the cell means every cell is found
+        // exactly once, from its smallest-indexed vertex
+        let mut cells: Vec<[usize; 4]> = Vec::new();
+        for a in 0..n {
+            let neighbors: Vec<usize> = adjacency[a].iter().copied().filter(|&v| v > a).collect();
+            for bi in 0..neighbors.len() {
+                let b = neighbors[bi];
+                for ci in (bi + 1)..neighbors.len() {
+                    let c = neighbors[ci];
+                    if !adjacency[b].contains(&c) {
+                        continue;
+                    }
+                    for di in (ci + 1)..neighbors.len() {
+                        let d = neighbors[di];
+                        if !adjacency[b].contains(&d) || !adjacency[c].contains(&d) {
+                            continue;
+                        }
+                        cells.push([a, b, c, d]);
+                    }
+                }
+            }
+        }
+
+        let vertices: Vec<Vec4D> = cells
+            .iter()
+            .map(|cell| {
+                let sum = cell
+                    .iter()
+                    .fold(Vec4D::ZERO, |acc, &i| acc + six_hundred.vertices[i]);
+                sum * 0.25
+            })
+            .collect();
+
+        let mut edges = Vec::new();
+        for i in 0..cells.len() {
+            for j in (i + 1)..cells.len() {
+                let shared = cells[i].iter().filter(|v| cells[j].contains(v)).count();
+                if shared == 3 {
+                    edges.push(Edge4D { v1: i, v2: j });
+                }
+            }
+        }
+
+        let faces = find_quad_faces(&vertices, &edges);
+
+        Self {
+            vertices,
+            edges,
+            faces,
+            cells: Vec::new(),
+            name: "120-cell".to_string(),
+        }
+    }
+
+    /// Read a 4-dimensional OFF (`4OFF`) polytope: a header line, a counts
+    /// line (`NVertices NEdges NFaces NCells`), the 4D vertex coordinates,
+    /// each face as a vertex-index list, then each cell as a face-index
+    /// list. `edges` is always derived from the face boundaries rather than
+    /// read from the file, matching how plain OFF never lists edges
+    /// explicitly.
+    pub fn from_off<R: std::io::Read>(reader: R) -> Result<Self, OffError> {
+        let mut lines = std::io::BufRead::lines(std::io::BufReader::new(reader));
+
+        let header = next_off_line(&mut lines)?;
+        if header != "4OFF" {
+            return Err(OffError::Malformed(format!(
+                "expected a `4OFF` header, found `{header}`"
+            )));
+        }
+
+        let counts_line = next_off_line(&mut lines)?;
+        let mut counts = counts_line.split_whitespace();
+        let mut next_count = |what: &str| -> Result<usize, OffError> {
+            counts
+                .next()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| OffError::Malformed(format!("missing {what} count")))
+        };
+        let num_vertices = next_count("vertex")?;
+        let _num_edges = next_count("edge")?;
+        let num_faces = next_count("face")?;
+        let num_cells = next_count("cell")?;
+
+        let mut vertices = Vec::with_capacity(num_vertices);
+        for _ in 0..num_vertices {
+            let line = next_off_line(&mut lines)?;
+            let mut coords = line.split_whitespace();
+            let mut next_coord = || -> Result<f32, OffError> {
+                coords
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| OffError::Malformed("expected 4 vertex coordinates".to_string()))
+            };
+            vertices.push(Vec4D::new(
+                next_coord()?,
+                next_coord()?,
+                next_coord()?,
+                next_coord()?,
+            ));
+        }
+
+        let mut faces = Vec::with_capacity(num_faces);
+        for _ in 0..num_faces {
+            faces.push(parse_off_index_list(&next_off_line(&mut lines)?)?);
+        }
+
+        let mut cells = Vec::with_capacity(num_cells);
+        for _ in 0..num_cells {
+            cells.push(parse_off_index_list(&next_off_line(&mut lines)?)?);
+        }
+
+        let edges = edges_from_faces(&faces);
+
+        Ok(Self {
+            vertices,
+            edges,
+            faces,
+            cells,
+            name: "Imported OFF polytope".to_string(),
+        })
+    }
+
+    /// Write this polytope as a 4OFF file. A polytope with no `faces` (e.g.
+    /// the 5-cell, whose 4-cycles are all chorded, so `find_quad_faces` finds
+    /// none) encodes each edge as a degenerate two-vertex face instead, so
+    /// `from_off` derives the same edges back out. Otherwise only `faces` is
+    /// written, and `from_off` derives the edges from their boundaries.
+    pub fn to_off<W: std::io::Write>(&self, mut writer: W) -> Result<(), OffError> {
+        let faces: Vec<Vec<usize>> = if self.faces.is_empty() {
+            self.edges.iter().map(|e| vec![e.v1, e.v2]).collect()
+        } else {
+            self.faces.clone()
+        };
+
+        writeln!(writer, "4OFF")?;
+        writeln!(
+            writer,
+            "{} {} {} {}",
+            self.vertices.len(),
+            self.edges.len(),
+            faces.len(),
+            self.cells.len()
+        )?;
+        for v in &self.vertices {
+            writeln!(writer, "{} {} {} {}", v.x, v.y, v.z, v.w)?;
+        }
+        for face in &faces {
+            write!(writer, "{}", face.len())?;
+            for idx in face {
+                write!(writer, " {idx}")?;
+            }
+            writeln!(writer)?;
+        }
+        for cell in &self.cells {
+            write!(writer, "{}", cell.len())?;
+            for idx in cell {
+                write!(writer, " {idx}")?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
 }
 
 /// 4D visualization simulation
 pub struct Hypercube4DSimulation {
     /// Current polytope
     pub polytope: Polytope4D,
-    /// Current rotation angles
-    pub rotation: Rotation4D,
-    /// Angular velocities for animation
+    /// Current orientation, as a geometric-algebra rotor
+    pub rotation: Rotor4D,
+    /// Angular velocity bivector for animation (reuses the six `Rotation4D`
+    /// plane fields, but as a bivector rather than accumulated angles)
     pub angular_velocity: Rotation4D,
     /// Projection distance (for perspective)
     pub projection_distance: f32,
@@ -350,34 +1356,71 @@ pub struct Hypercube4DSimulation {
     pub projected_vertices: Vec<Vec3>,
     /// Colors for vertices based on w coordinate
     pub vertex_colors: Vec<[f32; 4]>,
+    /// Direction the shaded-face renderer treats as incoming light, for the
+    /// Lambertian `get_faces_3d` shading
+    pub light_direction: Vec3,
+    /// The current orientation's composite 4x4 rotation matrix, rebuilt once
+    /// per [`Self::update_projection`] call rather than per vertex. Exposed
+    /// so callers (e.g. a GPU instancing path) can upload it directly and
+    /// transform vertices on-device instead of reading it back off the CPU.
+    pub rotation_matrix: Mat4,
     /// Time
     pub time: f32,
     /// Auto-rotate
     pub auto_rotate: bool,
+    /// Triangulated 3-cell facets of the current polytope (vertex-index
+    /// triples), and which of them bound each cell — built once per
+    /// [`Self::set_polytope`] via [`convex_hull_4d`] rather than from the
+    /// hand-rolled quad `faces` used by [`Self::get_faces_3d`], since a cell
+    /// needs its full triangulated boundary and `convex_hull_4d` is the only
+    /// place that already solves "which facets bound which cell" generically
+    cell_faces: Vec<[usize; 3]>,
+    cell_groups: Vec<Vec<usize>>,
+    /// Whether [`Self::angular_velocity`] is currently driven by
+    /// [`Self::set_isoclinic`] rather than the default single-plane tumble
+    pub isoclinic_mode: bool,
+    /// Ratio of the ZW plane's spin rate to the XY plane's when isoclinic
+    /// mode is on; `1.0` gives the fully symmetric Clifford "both-ways"
+    /// double rotation, other values give a skew double rotation
+    pub isoclinic_ratio: f32,
 }
 
+/// Base angular speed (radians/sec) used by [`Hypercube4DSimulation::set_isoclinic`]
+const ISOCLINIC_BASE_SPEED: f32 = 0.4;
+
+/// The `angular_velocity` a freshly-created simulation tumbles with, and what
+/// [`Hypercube4DSimulation::set_isoclinic`] restores when switched back off
+const DEFAULT_ANGULAR_VELOCITY: Rotation4D = Rotation4D {
+    xy: 0.0,
+    xz: 0.0,
+    xw: 0.5,
+    yz: 0.0,
+    yw: 0.3,
+    zw: 0.0,
+};
+
 impl Hypercube4DSimulation {
     pub fn new() -> Self {
         let polytope = Polytope4D::tesseract(1.0);
         let n = polytope.vertices.len();
+        let (cell_faces, cell_groups) = compute_cell_structure(&polytope.vertices);
 
         let mut sim = Self {
             polytope,
-            rotation: Rotation4D::new(),
-            angular_velocity: Rotation4D {
-                xy: 0.0,
-                xz: 0.0,
-                xw: 0.5, // Rotate in XW plane
-                yz: 0.0,
-                yw: 0.3, // And YW plane
-                zw: 0.0,
-            },
+            rotation: Rotor4D::identity(),
+            angular_velocity: DEFAULT_ANGULAR_VELOCITY,
             projection_distance: 3.0,
             use_perspective: true,
             projected_vertices: vec![Vec3::ZERO; n],
             vertex_colors: vec![[1.0, 1.0, 1.0, 1.0]; n],
+            light_direction: Vec3::new(0.4, 0.6, 1.0).normalize(),
+            rotation_matrix: Mat4::IDENTITY,
             time: 0.0,
             auto_rotate: true,
+            cell_faces,
+            cell_groups,
+            isoclinic_mode: false,
+            isoclinic_ratio: 1.0,
         };
 
         sim.update_projection();
@@ -387,21 +1430,29 @@ impl Hypercube4DSimulation {
     /// Set the polytope type
     pub fn set_polytope(&mut self, polytope: Polytope4D) {
         let n = polytope.vertices.len();
+        let (cell_faces, cell_groups) = compute_cell_structure(&polytope.vertices);
         self.polytope = polytope;
         self.projected_vertices = vec![Vec3::ZERO; n];
         self.vertex_colors = vec![[1.0, 1.0, 1.0, 1.0]; n];
+        self.cell_faces = cell_faces;
+        self.cell_groups = cell_groups;
         self.update_projection();
     }
 
-    /// Update projected vertices
+    /// Update projected vertices. The rotor's matrix form is built once here
+    /// (six `sin_cos` calls total, folded into the rotor itself) rather than
+    /// re-walking the rotor's sandwich product per vertex, so the inner loop
+    /// is a single `Mat4 * Vec4` that glam can vectorize.
     fn update_projection(&mut self) {
         let n = self.polytope.vertices.len();
         self.projected_vertices.resize(n, Vec3::ZERO);
         self.vertex_colors.resize(n, [1.0, 1.0, 1.0, 1.0]);
 
+        self.rotation_matrix = self.rotation.to_mat4();
+
         for i in 0..n {
-            // Apply rotation
-            let rotated = self.rotation.rotate(self.polytope.vertices[i]);
+            // Apply the cached composite rotation
+            let rotated = self.rotation_matrix * self.polytope.vertices[i];
 
             // Project to 3D
             self.projected_vertices[i] = if self.use_perspective {
@@ -422,31 +1473,71 @@ impl Hypercube4DSimulation {
         self.time += dt;
 
         if self.auto_rotate {
-            self.rotation.xy += self.angular_velocity.xy * dt;
-            self.rotation.xz += self.angular_velocity.xz * dt;
-            self.rotation.xw += self.angular_velocity.xw * dt;
-            self.rotation.yz += self.angular_velocity.yz * dt;
-            self.rotation.yw += self.angular_velocity.yw * dt;
-            self.rotation.zw += self.angular_velocity.zw * dt;
+            self.rotation = self.rotation.integrate(&self.angular_velocity, dt);
         }
 
         self.update_projection();
     }
 
-    /// Manually rotate
-    pub fn rotate_xw(&mut self, angle: f32) {
-        self.rotation.xw += angle;
+    /// Manually rotate in one of the six coordinate planes. Left-multiplying
+    /// the accumulated rotor (rather than rotating the polytope's vertices
+    /// directly) is what lets successive calls in different planes compose
+    /// through [`Self::rotation_matrix`] without gimbal-lock artifacts.
+    pub fn rotate_plane(&mut self, plane: Plane4D, angle: f32) {
+        self.rotation = (Rotor4D::plane_rotation(plane, angle) * self.rotation).normalize();
         self.update_projection();
     }
 
+    pub fn rotate_xy(&mut self, angle: f32) {
+        self.rotate_plane(Plane4D::Xy, angle);
+    }
+
+    pub fn rotate_xz(&mut self, angle: f32) {
+        self.rotate_plane(Plane4D::Xz, angle);
+    }
+
+    pub fn rotate_xw(&mut self, angle: f32) {
+        self.rotate_plane(Plane4D::Xw, angle);
+    }
+
+    pub fn rotate_yz(&mut self, angle: f32) {
+        self.rotate_plane(Plane4D::Yz, angle);
+    }
+
     pub fn rotate_yw(&mut self, angle: f32) {
-        self.rotation.yw += angle;
-        self.update_projection();
+        self.rotate_plane(Plane4D::Yw, angle);
     }
 
     pub fn rotate_zw(&mut self, angle: f32) {
-        self.rotation.zw += angle;
-        self.update_projection();
+        self.rotate_plane(Plane4D::Zw, angle);
+    }
+
+    /// Turn isoclinic (double) rotation on or off. While on, `angular_velocity`
+    /// spins the XY and ZW planes together at [`Self::isoclinic_ratio`] instead
+    /// of the default single-plane tumble, which for the tesseract produces
+    /// the characteristic Clifford "both-ways" motion where every vertex moves
+    /// along a circle of the same radius. Turning it back off restores the
+    /// default tumble.
+    pub fn set_isoclinic(&mut self, enabled: bool) {
+        self.isoclinic_mode = enabled;
+        self.angular_velocity = if enabled {
+            Rotation4D {
+                xy: ISOCLINIC_BASE_SPEED,
+                zw: ISOCLINIC_BASE_SPEED * self.isoclinic_ratio,
+                ..Rotation4D::new()
+            }
+        } else {
+            DEFAULT_ANGULAR_VELOCITY
+        };
+    }
+
+    /// Change the ZW:XY speed ratio while isoclinic mode is active, keeping
+    /// `angular_velocity` in sync
+    pub fn set_isoclinic_ratio(&mut self, ratio: f32) {
+        self.isoclinic_ratio = ratio;
+        if self.isoclinic_mode {
+            self.angular_velocity.zw = ISOCLINIC_BASE_SPEED * ratio;
+        }
     }
 
     /// Get edge render data
@@ -471,6 +1562,48 @@ impl Hypercube4DSimulation {
             .collect()
     }
 
+    /// Get shaded triangle render data for the polytope's quad faces:
+    /// each face is fan-triangulated from its projected vertices, given a
+    /// flat normal from the triangle's cross product, and Lambert-shaded
+    /// against `light_direction`, modulating the averaged w-based vertex
+    /// color of its corners. Combine with [`Self::get_edges_3d`] for a
+    /// wireframe-over-solid look, or use alone for shaded-cell rendering.
+    pub fn get_faces_3d(&self) -> Vec<(Vec3, Vec3, Vec3, [f32; 4])> {
+        let mut triangles = Vec::new();
+
+        for face in &self.polytope.faces {
+            if face.len() < 3 {
+                continue;
+            }
+
+            let points: Vec<Vec3> = face.iter().map(|&i| self.projected_vertices[i]).collect();
+            let n = face.len() as f32;
+            let base_color = face.iter().fold([0.0_f32; 4], |mut acc, &i| {
+                let c = self.vertex_colors[i];
+                acc[0] += c[0] / n;
+                acc[1] += c[1] / n;
+                acc[2] += c[2] / n;
+                acc[3] += c[3] / n;
+                acc
+            });
+
+            for i in 1..points.len() - 1 {
+                let (p0, p1, p2) = (points[0], points[i], points[i + 1]);
+                let normal = (p1 - p0).cross(p2 - p0).normalize_or_zero();
+                let intensity = normal.dot(self.light_direction).max(0.0);
+                let shaded = [
+                    base_color[0] * intensity,
+                    base_color[1] * intensity,
+                    base_color[2] * intensity,
+                    base_color[3],
+                ];
+                triangles.push((p0, p1, p2, shaded));
+            }
+        }
+
+        triangles
+    }
+
     /// Get vertex render data
     pub fn get_vertices_3d(&self) -> Vec<(Vec3, [f32; 4])> {
         self.projected_vertices
@@ -484,6 +1617,82 @@ impl Hypercube4DSimulation {
     pub fn current_polytope_name(&self) -> &str {
         &self.polytope.name
     }
+
+    /// Get solid-cell render data: one mesh per 3-cell (the tesseract's 8
+    /// cubes, the 16-cell's 16 tetrahedra, and so on), each triangulated
+    /// and flat-colored, sorted back-to-front by average rotated-w depth so
+    /// alpha-blended nested cells composite in the right order
+    pub fn get_cells_3d(&self) -> Vec<CellMesh> {
+        let total = self.cell_groups.len();
+        let mut meshes: Vec<CellMesh> = self
+            .cell_groups
+            .iter()
+            .enumerate()
+            .map(|(cell_index, face_indices)| {
+                let mut triangles = Vec::with_capacity(face_indices.len());
+                let mut w_sum = 0.0f32;
+                let mut w_count = 0u32;
+
+                for &fi in face_indices {
+                    let [a, b, c] = self.cell_faces[fi];
+                    triangles.push((
+                        self.projected_vertices[a],
+                        self.projected_vertices[b],
+                        self.projected_vertices[c],
+                    ));
+                    for &vi in &[a, b, c] {
+                        w_sum += (self.rotation_matrix * self.polytope.vertices[vi]).w;
+                        w_count += 1;
+                    }
+                }
+
+                CellMesh {
+                    triangles,
+                    color: cell_color(cell_index, total),
+                    depth: if w_count > 0 { w_sum / w_count as f32 } else { 0.0 },
+                }
+            })
+            .collect();
+
+        // `project_to_3d` puts the eye on the +w side, so the smallest w is
+        // farthest away and is drawn first
+        meshes.sort_by(|a, b| a.depth.partial_cmp(&b.depth).unwrap_or(std::cmp::Ordering::Equal));
+        meshes
+    }
+}
+
+/// A single 3-cell's triangulated, flat-colored boundary, ready to flatten
+/// into [`crate::renderer::QuantumRenderer::update_faces`]'s triangle list
+pub struct CellMesh {
+    pub triangles: Vec<(Vec3, Vec3, Vec3)>,
+    pub color: [f32; 4],
+    /// Average rotated (pre-projection) w coordinate of the cell's
+    /// vertices, used to depth-sort cells back-to-front for alpha blending
+    pub depth: f32,
+}
+
+/// Triangulate the polytope's 3-cell structure via [`convex_hull_4d`],
+/// independent of the hand-rolled quad `faces`/`cells` each preset builds
+/// for wireframe rendering: a cell's true triangulated boundary (needed to
+/// fill it as a solid) isn't recoverable from those quads without
+/// re-deriving cell adjacency, so the hull is rebuilt once here instead,
+/// keyed on the same vertex order the presets already use
+fn compute_cell_structure(vertices: &[Vec4D]) -> (Vec<[usize; 3]>, Vec<Vec<usize>>) {
+    let hull = convex_hull_4d(vertices);
+    let cell_faces = hull
+        .faces
+        .iter()
+        .map(|face| [face[0], face[1], face[2]])
+        .collect();
+    (cell_faces, hull.cells)
+}
+
+/// Evenly spaced, saturated hue per cell index, so adjacent cells read as
+/// visually distinct translucent solids
+fn cell_color(index: usize, total: usize) -> [f32; 4] {
+    let hue = if total == 0 { 0.0 } else { index as f32 / total as f32 };
+    let (r, g, b) = hsv_to_rgb(hue, 0.6, 1.0);
+    [r, g, b, 0.35]
 }
 
 impl Default for Hypercube4DSimulation {
@@ -543,4 +1752,80 @@ impl Hypercube4DSimulation {
         sim.set_polytope(Polytope4D::simplex_5(0.5));
         sim
     }
+
+    pub fn preset_600_cell() -> Self {
+        let mut sim = Self::new();
+        sim.set_polytope(Polytope4D::cell_600(0.8));
+        sim
+    }
+
+    pub fn preset_120_cell() -> Self {
+        let mut sim = Self::new();
+        sim.set_polytope(Polytope4D::cell_120(0.8));
+        sim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simplex_5_vertices(a: f32) -> Vec<Vec4D> {
+        vec![
+            Vec4D::new(a, a, a, -a / 5.0_f32.sqrt()),
+            Vec4D::new(a, -a, -a, -a / 5.0_f32.sqrt()),
+            Vec4D::new(-a, a, -a, -a / 5.0_f32.sqrt()),
+            Vec4D::new(-a, -a, a, -a / 5.0_f32.sqrt()),
+            Vec4D::new(0.0, 0.0, 0.0, a * 4.0 / 5.0_f32.sqrt()),
+        ]
+    }
+
+    #[test]
+    fn hull_of_5_cell_has_10_edges() {
+        let hull = convex_hull_4d(&simplex_5_vertices(1.0));
+        // The 5-cell is the 4-simplex: every pair of its 5 vertices is
+        // connected by an edge
+        assert_eq!(hull.edges.len(), 10);
+    }
+
+    #[test]
+    fn hull_of_5_cell_has_5_tetrahedral_cells() {
+        let hull = convex_hull_4d(&simplex_5_vertices(1.0));
+        // A 4-simplex has exactly 5 tetrahedral cells, one opposite each
+        // vertex; `cells` lists each one's triangular faces by index
+        assert_eq!(hull.cells.len(), 5);
+        for cell in &hull.cells {
+            assert_eq!(cell.len(), 4, "each cell of a 5-cell is a tetrahedron bounded by 4 triangles");
+        }
+    }
+
+    #[test]
+    fn degenerate_input_returns_bare_point_cloud() {
+        // Fewer than 5 points can't span a non-degenerate 4D hull
+        let vertices = vec![
+            Vec4D::new(0.0, 0.0, 0.0, 0.0),
+            Vec4D::new(1.0, 0.0, 0.0, 0.0),
+            Vec4D::new(0.0, 1.0, 0.0, 0.0),
+        ];
+        let hull = convex_hull_4d(&vertices);
+        assert!(hull.edges.is_empty());
+        assert!(hull.faces.is_empty());
+        assert_eq!(hull.vertices.len(), 3);
+    }
+
+    #[test]
+    fn coplanar_input_is_rejected_gracefully() {
+        // All points confined to a hyperplane (w = 0): no non-degenerate
+        // 4D simplex can be found
+        let vertices = vec![
+            Vec4D::new(0.0, 0.0, 0.0, 0.0),
+            Vec4D::new(1.0, 0.0, 0.0, 0.0),
+            Vec4D::new(0.0, 1.0, 0.0, 0.0),
+            Vec4D::new(0.0, 0.0, 1.0, 0.0),
+            Vec4D::new(1.0, 1.0, 1.0, 0.0),
+        ];
+        let hull = convex_hull_4d(&vertices);
+        assert!(hull.edges.is_empty());
+        assert!(hull.faces.is_empty());
+    }
 }
@@ -9,16 +9,32 @@
 //! - **Quantum Hall Effect**: Landau levels and edge states in 2D electron gases
 //! - **4D Visualization**: Hypercube and tesseract projections into 3D space
 
+pub mod consistency;
+pub mod decay;
+pub mod density_matrix;
+pub mod model;
+pub mod qcd;
 pub mod wavefunction;
 pub mod quantum_state;
 pub mod tunneling;
 pub mod orbitals;
+pub mod marching_cubes;
+pub mod marching_squares;
 pub mod teleportation;
+pub mod teleport_scripts;
+pub mod superdense;
+pub mod simulation;
 pub mod quarks;
+pub mod reconnection;
 pub mod hall_effect;
+pub mod hall_driver;
 pub mod hypercube;
+pub mod choreography;
 pub mod renderer;
 pub mod equations_ui;
+pub mod hall_plot;
+pub mod logging;
+pub mod environment;
 
 /// Physical constants for quantum simulations
 pub mod constants {
@@ -42,4 +58,7 @@ pub mod constants {
 
     /// Strong coupling constant (scaled for visualization)
     pub const ALPHA_S: f32 = 0.5;
+
+    /// QCD scale Λ_QCD (scaled for visualization)
+    pub const LAMBDA_QCD: f32 = 0.2;
 }
@@ -0,0 +1,85 @@
+//! Optional [Rerun](https://rerun.io) logging of simulation runs
+//!
+//! Enabled with the `rerun` feature so researchers can scrub history,
+//! overlay runs, and export data instead of only watching the live egui
+//! panel. With the feature disabled `SimLogger` is a zero-cost no-op, so
+//! call sites never need `#[cfg]` guards of their own.
+
+use common::{Camera2D, CameraUniform};
+
+/// Errors that can occur while starting a Rerun recording stream
+#[derive(Debug, thiserror::Error)]
+pub enum LoggingError {
+    #[cfg(feature = "rerun")]
+    #[error("failed to start Rerun recording stream: {0}")]
+    Rerun(#[from] rerun::RecordingStreamError),
+}
+
+/// Streams simulation state to a Rerun viewer, keyed on a `sim_step` timeline
+pub struct SimLogger {
+    #[cfg(feature = "rerun")]
+    stream: rerun::RecordingStream,
+    step: i64,
+}
+
+impl SimLogger {
+    /// Spawn a Rerun viewer and start a recording stream under `app_id`
+    pub fn new(app_id: &str) -> Result<Self, LoggingError> {
+        #[cfg(feature = "rerun")]
+        {
+            let stream = rerun::RecordingStreamBuilder::new(app_id).spawn()?;
+            return Ok(Self { stream, step: 0 });
+        }
+        #[cfg(not(feature = "rerun"))]
+        {
+            let _ = app_id;
+            Ok(Self { step: 0 })
+        }
+    }
+
+    /// Log one simulation step: the 1D probability density, the potential
+    /// profile, and the scalar transmission/reflection coefficients
+    pub fn log_step(&mut self, density: &[f32], potential: &[f32], transmission: f32, reflection: f32) {
+        #[cfg(feature = "rerun")]
+        {
+            self.stream.set_time_sequence("sim_step", self.step);
+            let _ = self.stream.log("tunneling/density", &rerun::BarChart::new(density.to_vec()));
+            let _ = self.stream.log("tunneling/potential", &rerun::BarChart::new(potential.to_vec()));
+            let _ = self.stream.log("tunneling/transmission", &rerun::Scalar::new(transmission as f64));
+            let _ = self.stream.log("tunneling/reflection", &rerun::Scalar::new(reflection as f64));
+        }
+        #[cfg(not(feature = "rerun"))]
+        {
+            let _ = (density, potential, transmission, reflection);
+        }
+
+        self.step += 1;
+    }
+
+    /// Log the active 2D camera as a transform plus its orthographic view
+    /// bounds, so recorded plots line up with what was actually on screen
+    pub fn log_camera(&self, camera: &Camera2D) {
+        #[cfg(feature = "rerun")]
+        {
+            let uniform = CameraUniform::from_camera_2d(camera);
+            let transform = rerun::Transform3D::from_translation([
+                uniform.position[0],
+                uniform.position[1],
+                uniform.position[2],
+            ]);
+            let _ = self.stream.log("tunneling/camera", &transform);
+
+            let half_width = camera.zoom * camera.aspect_ratio;
+            let half_height = camera.zoom;
+            let _ = self.stream.log(
+                "tunneling/camera/view_bounds",
+                &rerun::Boxes2D::from_half_sizes([(half_width, half_height)])
+                    .with_centers([(camera.position.x, camera.position.y)]),
+            );
+        }
+        #[cfg(not(feature = "rerun"))]
+        {
+            let _ = camera;
+        }
+    }
+}
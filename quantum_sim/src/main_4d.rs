@@ -11,31 +11,244 @@
 //! - Q/W: Rotate in XW plane
 //! - E/R: Rotate in YW plane
 //! - T/Y: Rotate in ZW plane
-//! - Arrow keys: Rotate 3D view
+//! - U/I: Rotate in XY plane
+//! - O/J: Rotate in XZ plane
+//! - K/L: Rotate in YZ plane
+//! - G: Toggle isoclinic (double) rotation of the XY/ZW planes
+//! - Left mouse drag: Orbit the 3D view (eases to a stop on release)
+//! - Arrow keys: Orbit the 3D view in fixed steps
+//! - Scroll: Zoom in/out
+//! - V: Toggle orbital / free-fly camera mode
+//! - WASD/QE (free-fly only): Move/strafe/vertical; mouse drag looks around
 //! - P: Toggle perspective/orthographic
+//! - F: Cycle wireframe / shaded-face / combined / solid-cell rendering
+//! - X: Export the current projection to `4d_export.obj` (also available as
+//!   an Export OBJ button on the Stats tab); includes solid-cell facets with
+//!   normals when that render mode is active
+//!
+//! Pass a `.rhai` choreography script as the first command-line argument to
+//! drive the visualization frame by frame instead of mashing the keys above
+//! (see `choreography.rs`); it's hot-reloaded whenever the file is saved, and
+//! interactive key control still works alongside it for manual overrides.
+//!
+//! The right-hand panel is an `egui_dock` tree of dockable tabs — Equations,
+//! Stats, and Rotation Rates — that can be split, resized, reordered, or
+//! closed; which tabs are open persists to `4d_dock_layout.txt` between runs.
 
 mod wavefunction;
 mod quantum_state;
 mod tunneling;
 mod orbitals;
+mod marching_cubes;
 mod teleportation;
 mod quarks;
+mod reconnection;
 mod hall_effect;
 mod hypercube;
 mod renderer;
 mod equations_ui;
+mod environment;
+mod choreography;
 
 use common::{Camera3D, GraphicsContext};
-use glam::Vec3;
-use hypercube::{Hypercube4DSimulation, Polytope4D};
-use renderer::{QuantumRenderer, PointInstance, hypercube_to_points};
-use equations_ui::{draw_equations_sidebar, HYPERCUBE_EQUATIONS, HYPERCUBE_VARIABLES};
+use glam::{Vec2, Vec3};
+use choreography::{ChoreographyScript, Command, RotationPlane};
+use egui_dock::{DockArea, DockState};
+use hypercube::{Hypercube4DSimulation, Plane4D, Polytope4D};
+use renderer::{QuantumRenderer, PointInstance, BlendMode, hypercube_to_points};
+use equations_ui::{draw_equations_content, HYPERCUBE_EQUATIONS, HYPERCUBE_VARIABLES};
 use winit::{
-    event::{ElementState, Event, KeyEvent, MouseScrollDelta, WindowEvent},
+    event::{ElementState, Event, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
     keyboard::{KeyCode, PhysicalKey},
 };
 
+/// Free-fly camera move speed, in world units per second
+const FLY_SPEED: f32 = 4.0;
+
+/// Whether the mouse drags an orbit around the polytope (the default) or
+/// looks around freely while WASD/QE move the eye, mirroring the gravity
+/// viewer's `CameraMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CameraMode {
+    Orbital,
+    FreeFly,
+}
+
+/// Continuous WASD/QE movement keys for free-fly mode, tracked as
+/// press/release state rather than handled on keydown like the toggle keys
+#[derive(Debug, Clone, Copy, Default)]
+struct FlyKeys {
+    forward: bool,
+    backward: bool,
+    left: bool,
+    right: bool,
+    up: bool,
+    down: bool,
+}
+
+/// Path (relative to the working directory the binary is launched from,
+/// matching how `scene_scripts.rs`/`teleport_scripts.rs` reference their
+/// `scenes/`/`protocols/` directories) the dock layout is saved to and
+/// reloaded from on the next launch
+const DOCK_LAYOUT_PATH: &str = "quantum_sim/4d_dock_layout.txt";
+
+/// Where `App::export_geometry` writes the current projection, overwritten
+/// on every export rather than timestamped, matching [`DOCK_LAYOUT_PATH`]'s
+/// fixed-path convention
+const EXPORT_OBJ_PATH: &str = "quantum_sim/4d_export.obj";
+
+/// One of the dockable panels the user can split, resize, tear off, or hide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DockTab {
+    Equations,
+    Stats,
+    RotationRates,
+}
+
+impl DockTab {
+    const ALL: [DockTab; 3] = [DockTab::Equations, DockTab::Stats, DockTab::RotationRates];
+
+    fn title(self) -> &'static str {
+        match self {
+            DockTab::Equations => "Equations",
+            DockTab::Stats => "Stats",
+            DockTab::RotationRates => "Rotation Rates",
+        }
+    }
+
+    fn from_title(title: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|tab| tab.title() == title)
+    }
+}
+
+/// Which tabs are present, written to [`DOCK_LAYOUT_PATH`] on exit and read
+/// back on the next launch so a tab the user closed stays closed. This is a
+/// deliberately narrow slice of `egui_dock`'s full tree (split direction and
+/// proportions reset to the default layout each launch) since nothing else
+/// in this crate depends on `serde` yet and pulling it in just to serialize
+/// the tree verbatim isn't worth it for three fixed tabs.
+fn load_dock_layout() -> DockState<DockTab> {
+    let tabs: Vec<DockTab> = std::fs::read_to_string(DOCK_LAYOUT_PATH)
+        .ok()
+        .map(|contents| contents.lines().filter_map(DockTab::from_title).collect())
+        .filter(|tabs: &Vec<DockTab>| !tabs.is_empty())
+        .unwrap_or_else(|| DockTab::ALL.to_vec());
+
+    DockState::new(tabs)
+}
+
+fn save_dock_layout(dock_state: &mut DockState<DockTab>) {
+    let contents = DockTab::ALL
+        .into_iter()
+        .filter(|tab| dock_state.find_tab(tab).is_some())
+        .map(DockTab::title)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if let Err(err) = std::fs::write(DOCK_LAYOUT_PATH, contents) {
+        log::warn!("failed to save dock layout to {DOCK_LAYOUT_PATH}: {err}");
+    }
+}
+
+/// Renders whichever dock tab is active; borrows the simulation directly so
+/// the rotation-rate sliders can edit `angular_velocity` in place
+struct DockTabViewer<'a> {
+    simulation: &'a mut Hypercube4DSimulation,
+    render_mode_label: &'static str,
+    /// Set when the Stats tab's Export button is clicked; `App::render`
+    /// reads it back once the dock area's closure returns and the mutable
+    /// borrow of `simulation` above is released
+    export_requested: bool,
+}
+
+impl egui_dock::TabViewer for DockTabViewer<'_> {
+    type Tab = DockTab;
+
+    fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {
+        tab.title().into()
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
+        match tab {
+            DockTab::Equations => {
+                draw_equations_content(ui, "4D Geometry", HYPERCUBE_EQUATIONS, HYPERCUBE_VARIABLES);
+            }
+            DockTab::Stats => {
+                ui.label(format!("Polytope: {}", self.simulation.current_polytope_name()));
+                ui.label(format!("Vertices: {}", self.simulation.polytope.vertices.len()));
+                ui.label(format!("Edges: {}", self.simulation.polytope.edges.len()));
+                ui.label(format!("Render: {}", self.render_mode_label));
+                ui.separator();
+                if self.simulation.auto_rotate {
+                    ui.label(egui::RichText::new("AUTO-ROTATE").color(egui::Color32::GREEN));
+                }
+                ui.label(if self.simulation.use_perspective { "Perspective" } else { "Orthographic" });
+                if self.simulation.isoclinic_mode {
+                    ui.label(egui::RichText::new("ISOCLINIC").color(egui::Color32::LIGHT_BLUE));
+                }
+                ui.separator();
+                if ui.button("Export OBJ").clicked() {
+                    self.export_requested = true;
+                }
+            }
+            DockTab::RotationRates => {
+                ui.checkbox(&mut self.simulation.auto_rotate, "Auto-rotate");
+                ui.separator();
+
+                let omega = &mut self.simulation.angular_velocity;
+                ui.add(egui::Slider::new(&mut omega.xy, -2.0..=2.0).text("XY"));
+                ui.add(egui::Slider::new(&mut omega.xz, -2.0..=2.0).text("XZ"));
+                ui.add(egui::Slider::new(&mut omega.xw, -2.0..=2.0).text("XW"));
+                ui.add(egui::Slider::new(&mut omega.yz, -2.0..=2.0).text("YZ"));
+                ui.add(egui::Slider::new(&mut omega.yw, -2.0..=2.0).text("YW"));
+                ui.add(egui::Slider::new(&mut omega.zw, -2.0..=2.0).text("ZW"));
+
+                ui.separator();
+                let mut isoclinic = self.simulation.isoclinic_mode;
+                if ui.checkbox(&mut isoclinic, "Isoclinic (XY + ZW)").changed() {
+                    self.simulation.set_isoclinic(isoclinic);
+                }
+                if self.simulation.isoclinic_mode {
+                    let mut ratio = self.simulation.isoclinic_ratio;
+                    if ui.add(egui::Slider::new(&mut ratio, -2.0..=2.0).text("ZW:XY ratio")).changed() {
+                        self.simulation.set_isoclinic_ratio(ratio);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// How the polytope's edges, quad faces, and solid 3-cells are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RenderMode {
+    Wireframe,
+    ShadedSolid,
+    Combined,
+    SolidCells,
+}
+
+impl RenderMode {
+    fn next(self) -> Self {
+        match self {
+            RenderMode::Wireframe => RenderMode::ShadedSolid,
+            RenderMode::ShadedSolid => RenderMode::Combined,
+            RenderMode::Combined => RenderMode::SolidCells,
+            RenderMode::SolidCells => RenderMode::Wireframe,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            RenderMode::Wireframe => "Wireframe",
+            RenderMode::ShadedSolid => "Shaded",
+            RenderMode::Combined => "Wireframe + Shaded",
+            RenderMode::SolidCells => "Solid Cells",
+        }
+    }
+}
+
 struct EguiState {
     ctx: egui::Context,
     state: egui_winit::State,
@@ -47,12 +260,27 @@ struct App {
     renderer: QuantumRenderer,
     simulation: Hypercube4DSimulation,
     camera: Camera3D,
+    render_mode: RenderMode,
     egui: EguiState,
+    choreography: Option<ChoreographyScript>,
+    dock_state: DockState<DockTab>,
+    camera_mode: CameraMode,
+    fly_keys: FlyKeys,
+    mouse_pressed: bool,
+    last_mouse_pos: Option<(f64, f64)>,
+    /// NDC-space drag delta from the last orbit drag move, replayed against
+    /// a fixed `(0, 0)` arcball anchor and decayed in `update` after the
+    /// button is released, so a flicked drag eases to a stop instead of
+    /// snapping still the instant the mouse is lifted
+    orbit_velocity: Vec2,
 }
 
 impl App {
     fn new(ctx: GraphicsContext) -> Self {
-        let renderer = QuantumRenderer::new(&ctx, 50, 200);
+        let mut renderer = QuantumRenderer::new(&ctx, 50, 200);
+        if let Some(env) = environment::load_default_environment(&ctx.device, &ctx.queue) {
+            renderer.set_environment(&ctx.device, &env);
+        }
         let mut camera = Camera3D::new(ctx.aspect_ratio());
         camera.distance = 6.0;
 
@@ -73,28 +301,176 @@ impl App {
             1,
         );
 
+        let choreography = std::env::args().nth(1).and_then(|path| {
+            match ChoreographyScript::load(std::path::Path::new(&path)) {
+                Ok(script) => Some(script),
+                Err(err) => {
+                    log::warn!("not loading choreography script: {err}");
+                    None
+                }
+            }
+        });
+
         Self {
             ctx,
             renderer,
             simulation,
             camera,
+            render_mode: RenderMode::Wireframe,
             egui: EguiState {
                 ctx: egui_ctx,
                 state: egui_state,
                 renderer: egui_renderer,
             },
+            choreography,
+            dock_state: load_dock_layout(),
+            camera_mode: CameraMode::Orbital,
+            fly_keys: FlyKeys::default(),
+            mouse_pressed: false,
+            last_mouse_pos: None,
+            orbit_velocity: Vec2::ZERO,
         }
     }
 
+    fn save_dock_layout(&mut self) {
+        save_dock_layout(&mut self.dock_state);
+    }
+
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.ctx.resize(new_size);
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
+        self.renderer.resize(&self.ctx, new_size.width, new_size.height);
     }
 
     fn update(&mut self, dt: f32) {
+        if self.camera_mode == CameraMode::FreeFly {
+            let mut local = Vec3::ZERO;
+            if self.fly_keys.forward {
+                local.z += 1.0;
+            }
+            if self.fly_keys.backward {
+                local.z -= 1.0;
+            }
+            if self.fly_keys.right {
+                local.x += 1.0;
+            }
+            if self.fly_keys.left {
+                local.x -= 1.0;
+            }
+            if self.fly_keys.up {
+                local.y += 1.0;
+            }
+            if self.fly_keys.down {
+                local.y -= 1.0;
+            }
+            if local != Vec3::ZERO {
+                self.camera.fly_move(local.normalize() * FLY_SPEED, dt);
+            }
+        } else if !self.mouse_pressed && self.orbit_velocity.length() > 1e-4 {
+            self.camera.arcball_drag(Vec2::ZERO, self.orbit_velocity);
+            self.orbit_velocity *= (-8.0 * dt).exp();
+        } else if !self.mouse_pressed {
+            self.orbit_velocity = Vec2::ZERO;
+        }
+
+        if let Some(script) = &mut self.choreography {
+            script.reload_if_changed();
+            let commands = script.advance(dt);
+            for command in commands {
+                self.apply_command(command);
+            }
+        }
+
         self.simulation.step(dt);
     }
 
+    /// Bake the currently displayed 3D projection of the active polytope —
+    /// vertices and edges always, plus triangulated solid-cell facets with
+    /// flat face normals when [`RenderMode::SolidCells`] is active — into a
+    /// Wavefront OBJ string, with the current 4D orientation and
+    /// perspective/orthographic projection already applied by
+    /// `get_vertices_3d`/`get_edges_3d`/`get_cells_3d` so the file matches
+    /// exactly what's on screen. Plain-text OBJ rather than glTF's JSON +
+    /// binary buffers, matching `quarks.rs`'s `export_event` preference for
+    /// simple line-oriented export formats elsewhere in this crate.
+    fn export_obj(&self) -> String {
+        let mut lines = vec![
+            format!("# {} projection, exported from the 4D visualizer", self.simulation.current_polytope_name()),
+            "o Polytope4D".to_string(),
+        ];
+
+        let vertices = self.simulation.get_vertices_3d();
+        for (pos, _color) in &vertices {
+            lines.push(format!("v {:.6} {:.6} {:.6}", pos.x, pos.y, pos.z));
+        }
+        for edge in &self.simulation.polytope.edges {
+            lines.push(format!("l {} {}", edge.v1 + 1, edge.v2 + 1));
+        }
+
+        if self.render_mode == RenderMode::SolidCells {
+            let mut next_vertex = vertices.len();
+            let mut next_normal = 0usize;
+            for mesh in self.simulation.get_cells_3d() {
+                for (p0, p1, p2) in mesh.triangles {
+                    let normal = (p1 - p0).cross(p2 - p0).normalize_or_zero();
+                    lines.push(format!("v {:.6} {:.6} {:.6}", p0.x, p0.y, p0.z));
+                    lines.push(format!("v {:.6} {:.6} {:.6}", p1.x, p1.y, p1.z));
+                    lines.push(format!("v {:.6} {:.6} {:.6}", p2.x, p2.y, p2.z));
+                    lines.push(format!("vn {:.6} {:.6} {:.6}", normal.x, normal.y, normal.z));
+                    next_normal += 1;
+                    let (a, b, c) = (next_vertex + 1, next_vertex + 2, next_vertex + 3);
+                    lines.push(format!("f {a}//{next_normal} {b}//{next_normal} {c}//{next_normal}"));
+                    next_vertex += 3;
+                }
+            }
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
+    /// Write [`Self::export_obj`]'s output to [`EXPORT_OBJ_PATH`], bound to
+    /// the X key and the Stats tab's Export button
+    fn export_geometry(&self) {
+        match std::fs::write(EXPORT_OBJ_PATH, self.export_obj()) {
+            Ok(()) => log::info!("exported current projection to {EXPORT_OBJ_PATH}"),
+            Err(err) => log::warn!("failed to export geometry to {EXPORT_OBJ_PATH}: {err}"),
+        }
+    }
+
+    /// Apply one command a choreography script queued this frame, routing it
+    /// to the same `Hypercube4DSimulation`/`Camera3D` methods `handle_key`
+    /// calls for manual control
+    fn apply_command(&mut self, command: Command) {
+        match command {
+            Command::SetPolytope(name) => match name.as_str() {
+                "tesseract" => self.simulation.set_polytope(Polytope4D::tesseract(1.0)),
+                "16_cell" => self.simulation.set_polytope(Polytope4D::cell_16(1.0)),
+                "24_cell" => self.simulation.set_polytope(Polytope4D::cell_24(0.7)),
+                "5_cell" => self.simulation.set_polytope(Polytope4D::simplex_5(0.8)),
+                "600_cell" => self.simulation.set_polytope(Polytope4D::cell_600(0.8)),
+                "120_cell" => self.simulation.set_polytope(Polytope4D::cell_120(0.8)),
+                other => log::warn!("choreography script requested unknown polytope {other:?}"),
+            },
+            Command::RotatePlane(plane, angle) => {
+                let plane = match plane {
+                    RotationPlane::Xy => Plane4D::Xy,
+                    RotationPlane::Xz => Plane4D::Xz,
+                    RotationPlane::Xw => Plane4D::Xw,
+                    RotationPlane::Yz => Plane4D::Yz,
+                    RotationPlane::Yw => Plane4D::Yw,
+                    RotationPlane::Zw => Plane4D::Zw,
+                };
+                self.simulation.rotate_plane(plane, angle);
+            }
+            Command::SetAutoRotate(enabled) => self.simulation.auto_rotate = enabled,
+            Command::SetPerspective(enabled) => self.simulation.use_perspective = enabled,
+            Command::SetIsoclinic(enabled) => self.simulation.set_isoclinic(enabled),
+            Command::OrbitCamera(delta_yaw, delta_pitch) => self.camera.orbit(delta_yaw, delta_pitch),
+            Command::ZoomCamera(delta) => self.camera.zoom(delta),
+        }
+    }
+
     fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
         let output = self.ctx.surface.get_current_texture()?;
         let view = output
@@ -106,43 +482,59 @@ impl App {
         // Render vertices
         let vertex_data = self.simulation.get_vertices_3d();
         let points = hypercube_to_points(&vertex_data);
-        self.renderer.update_points(&self.ctx.queue, &points);
+        self.renderer.update_points(&self.ctx.device, &self.ctx.queue, &points);
 
         // Render edges
         let edges = self.simulation.get_edges_3d();
-        self.renderer.update_lines(&self.ctx.queue, &edges);
+        self.renderer.update_lines(&self.ctx.device, &self.ctx.queue, &edges);
+
+        // Render shaded faces (only populated for polytopes with quad cells,
+        // e.g. the tesseract), or the solid 3-cells in `SolidCells` mode —
+        // both go through the same non-indexed triangle pipeline, so only
+        // one of the two triangle lists is uploaded per frame
+        let faces = self.simulation.get_faces_3d();
+        let cell_meshes = if self.render_mode == RenderMode::SolidCells {
+            self.simulation.get_cells_3d()
+        } else {
+            Vec::new()
+        };
+        let cell_triangles: Vec<(Vec3, Vec3, Vec3, [f32; 4])> = cell_meshes
+            .iter()
+            .flat_map(|mesh| mesh.triangles.iter().map(|&(p0, p1, p2)| (p0, p1, p2, mesh.color)))
+            .collect();
+
+        match self.render_mode {
+            RenderMode::SolidCells => self.renderer.update_faces(&self.ctx.queue, &cell_triangles),
+            _ => self.renderer.update_faces(&self.ctx.queue, &faces),
+        }
 
-        // Build egui UI
+        // Build egui UI: the equations view, live stats, and the per-plane
+        // rotation-rate controls are separate dockable tabs rather than a
+        // fixed sidebar + status strip, so the user can split, resize, tear
+        // off, or hide whichever ones they aren't using
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
-        let polytope_name = self.simulation.current_polytope_name();
+        let render_mode_label = self.render_mode.label();
+        let mut export_requested = false;
         let full_output = self.egui.ctx.run(raw_input, |ctx| {
-            draw_equations_sidebar(
-                ctx,
-                "4D Geometry",
-                HYPERCUBE_EQUATIONS,
-                HYPERCUBE_VARIABLES,
-            );
-
-            egui::TopBottomPanel::top("status").show(ctx, |ui| {
-                ui.horizontal(|ui| {
-                    ui.label(format!("Polytope: {}", polytope_name));
-                    ui.separator();
-                    ui.label(format!("Vertices: {}", self.simulation.polytope.vertices.len()));
-                    ui.separator();
-                    ui.label(format!("Edges: {}", self.simulation.polytope.edges.len()));
-                    ui.separator();
-                    if self.simulation.auto_rotate {
-                        ui.label(egui::RichText::new("AUTO-ROTATE").color(egui::Color32::GREEN));
-                    }
-                    if self.simulation.use_perspective {
-                        ui.label("Perspective");
-                    } else {
-                        ui.label("Orthographic");
-                    }
+            egui::SidePanel::right("dock_panel")
+                .min_width(320.0)
+                .max_width(480.0)
+                .resizable(true)
+                .show(ctx, |ui| {
+                    let mut tab_viewer = DockTabViewer {
+                        simulation: &mut self.simulation,
+                        render_mode_label,
+                        export_requested: false,
+                    };
+                    DockArea::new(&mut self.dock_state).show_inside(ui, &mut tab_viewer);
+                    export_requested = tab_viewer.export_requested;
                 });
-            });
         });
 
+        if export_requested {
+            self.export_geometry();
+        }
+
         self.egui.state.handle_platform_output(&self.ctx.window, full_output.platform_output);
         let tris = self.egui.ctx.tessellate(full_output.shapes, full_output.pixels_per_point);
         for (id, image_delta) in &full_output.textures_delta.set {
@@ -161,10 +553,29 @@ impl App {
                 label: Some("Render Encoder"),
             });
 
+        self.renderer.render_skybox(&mut encoder, &view, true);
+
+        let draw_faces = matches!(self.render_mode, RenderMode::ShadedSolid | RenderMode::Combined);
+        let draw_wireframe = matches!(self.render_mode, RenderMode::Wireframe | RenderMode::Combined);
+        let draw_cells = self.render_mode == RenderMode::SolidCells;
+
+        if draw_faces {
+            self.renderer
+                .render_faces(&mut encoder, &view, (faces.len() * 3) as u32, false);
+        }
+        if draw_cells {
+            // Cells are alpha-blended and pre-sorted back-to-front by
+            // `get_cells_3d`, so nested cells composite correctly even
+            // though the pipeline has no depth buffer to rely on
+            self.renderer
+                .render_faces(&mut encoder, &view, cell_triangles.len() as u32, false);
+        }
+        if draw_wireframe {
+            self.renderer
+                .render_lines(&mut encoder, &view, edges.len() as u32, false, BlendMode::AlphaBlend);
+        }
         self.renderer
-            .render_lines(&mut encoder, &view, edges.len() as u32, true);
-        self.renderer
-            .render_points(&mut encoder, &view, points.len() as u32, false);
+            .render_points(&mut encoder, &view, points.len() as u32, false, BlendMode::AlphaBlend);
 
         self.egui.renderer.update_buffers(
             &self.ctx.device,
@@ -202,6 +613,22 @@ impl App {
     }
 
     fn handle_key(&mut self, key: KeyCode, state: ElementState) {
+        // WASD/QE only drive free-fly movement, and only while that mode is
+        // active, so Q/W/E keep their Orbital-mode rotation meaning below
+        // instead of the two bindings fighting over the same keys.
+        if self.camera_mode == CameraMode::FreeFly {
+            let pressed = state == ElementState::Pressed;
+            match key {
+                KeyCode::KeyW => self.fly_keys.forward = pressed,
+                KeyCode::KeyS => self.fly_keys.backward = pressed,
+                KeyCode::KeyA => self.fly_keys.left = pressed,
+                KeyCode::KeyD => self.fly_keys.right = pressed,
+                KeyCode::KeyQ => self.fly_keys.down = pressed,
+                KeyCode::KeyE => self.fly_keys.up = pressed,
+                _ => {}
+            }
+        }
+
         if state != ElementState::Pressed {
             return;
         }
@@ -212,13 +639,33 @@ impl App {
             KeyCode::Digit2 => self.simulation.set_polytope(Polytope4D::cell_16(1.0)),
             KeyCode::Digit3 => self.simulation.set_polytope(Polytope4D::cell_24(0.7)),
             KeyCode::Digit4 => self.simulation.set_polytope(Polytope4D::simplex_5(0.8)),
-            KeyCode::KeyQ => self.simulation.rotate_xw(0.1),
-            KeyCode::KeyW => self.simulation.rotate_xw(-0.1),
-            KeyCode::KeyE => self.simulation.rotate_yw(0.1),
+            KeyCode::KeyQ if self.camera_mode == CameraMode::Orbital => self.simulation.rotate_xw(0.1),
+            KeyCode::KeyW if self.camera_mode == CameraMode::Orbital => self.simulation.rotate_xw(-0.1),
+            KeyCode::KeyE if self.camera_mode == CameraMode::Orbital => self.simulation.rotate_yw(0.1),
             KeyCode::KeyR => self.simulation.rotate_yw(-0.1),
             KeyCode::KeyT => self.simulation.rotate_zw(0.1),
             KeyCode::KeyY => self.simulation.rotate_zw(-0.1),
+            KeyCode::KeyU => self.simulation.rotate_xy(0.1),
+            KeyCode::KeyI => self.simulation.rotate_xy(-0.1),
+            KeyCode::KeyO => self.simulation.rotate_xz(0.1),
+            KeyCode::KeyJ => self.simulation.rotate_xz(-0.1),
+            KeyCode::KeyK => self.simulation.rotate_yz(0.1),
+            KeyCode::KeyL => self.simulation.rotate_yz(-0.1),
+            KeyCode::KeyG => {
+                let enabled = !self.simulation.isoclinic_mode;
+                self.simulation.set_isoclinic(enabled);
+            }
             KeyCode::KeyP => self.simulation.use_perspective = !self.simulation.use_perspective,
+            KeyCode::KeyF => self.render_mode = self.render_mode.next(),
+            KeyCode::KeyX => self.export_geometry(),
+            KeyCode::KeyV => {
+                self.camera_mode = match self.camera_mode {
+                    CameraMode::Orbital => CameraMode::FreeFly,
+                    CameraMode::FreeFly => CameraMode::Orbital,
+                };
+                self.fly_keys = FlyKeys::default();
+                self.orbit_velocity = Vec2::ZERO;
+            }
             KeyCode::ArrowLeft => self.camera.orbit(-0.1, 0.0),
             KeyCode::ArrowRight => self.camera.orbit(0.1, 0.0),
             KeyCode::ArrowUp => self.camera.orbit(0.0, 0.1),
@@ -231,6 +678,49 @@ impl App {
         self.camera.zoom(delta);
     }
 
+    fn cursor_to_ndc(&self, x: f64, y: f64) -> Vec2 {
+        let width = self.ctx.size.width.max(1) as f32;
+        let height = self.ctx.size.height.max(1) as f32;
+        Vec2::new(
+            (x as f32 / width) * 2.0 - 1.0,
+            1.0 - (y as f32 / height) * 2.0,
+        )
+    }
+
+    fn handle_mouse_button(&mut self, button: MouseButton, state: ElementState) {
+        if button != MouseButton::Left {
+            return;
+        }
+        self.mouse_pressed = state == ElementState::Pressed;
+        if !self.mouse_pressed {
+            self.last_mouse_pos = None;
+        }
+    }
+
+    fn handle_mouse_move(&mut self, x: f64, y: f64) {
+        if !self.mouse_pressed {
+            self.last_mouse_pos = Some((x, y));
+            return;
+        }
+
+        if let Some(last) = self.last_mouse_pos {
+            match self.camera_mode {
+                CameraMode::Orbital => {
+                    let from = self.cursor_to_ndc(last.0, last.1);
+                    let to = self.cursor_to_ndc(x, y);
+                    self.camera.arcball_drag(from, to);
+                    self.orbit_velocity = to - from;
+                }
+                CameraMode::FreeFly => {
+                    let dx = (x - last.0) as f32;
+                    let dy = (y - last.1) as f32;
+                    self.camera.fly_rotate(dx * 0.005, -dy * 0.005);
+                }
+            }
+        }
+        self.last_mouse_pos = Some((x, y));
+    }
+
     fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
         self.egui.state.on_window_event(&self.ctx.window, event).consumed
     }
@@ -256,7 +746,10 @@ fn main() {
 
                     if !consumed {
                         match event {
-                            WindowEvent::CloseRequested => elwt.exit(),
+                            WindowEvent::CloseRequested => {
+                                app.save_dock_layout();
+                                elwt.exit();
+                            }
                             WindowEvent::Resized(size) => app.resize(*size),
                             WindowEvent::KeyboardInput {
                                 event:
@@ -274,6 +767,12 @@ fn main() {
                                 };
                                 app.handle_scroll(scroll);
                             }
+                            WindowEvent::MouseInput { state, button, .. } => {
+                                app.handle_mouse_button(*button, *state);
+                            }
+                            WindowEvent::CursorMoved { position, .. } => {
+                                app.handle_mouse_move(position.x, position.y);
+                            }
                             WindowEvent::RedrawRequested => {
                                 let now = std::time::Instant::now();
                                 let dt = (now - last_time).as_secs_f32().min(0.1);
@@ -7,29 +7,53 @@
 //! - Up/Down: Adjust magnetic field
 //! - +/-: Add/remove electrons
 //! - 1/2: Preset filling factors ν=1 and ν=2
+//! - P: Toggle |ψ|² probability-density iso-contours
+//! - Middle/right mouse drag: Pan camera
+//! - Shift+Arrows: Pan camera (plain arrows still adjust the magnetic field)
+//! - F: Frame all electrons
 
 mod wavefunction;
 mod quantum_state;
 mod tunneling;
 mod orbitals;
+mod marching_cubes;
+mod marching_squares;
 mod teleportation;
 mod quarks;
+mod reconnection;
 mod hall_effect;
 mod hypercube;
 mod renderer;
 mod equations_ui;
+mod hall_plot;
 
 use common::{Camera2D, GraphicsContext};
-use glam::Vec3;
+use glam::{Vec2, Vec3};
 use hall_effect::HallSimulation;
-use renderer::{QuantumRenderer, PointInstance};
+use hall_plot::PlateauHistory;
+use renderer::{QuantumRenderer, PointInstance, BlendMode, ElectronGpu};
 use equations_ui::{draw_equations_sidebar, HALL_EQUATIONS, HALL_VARIABLES};
 use winit::{
-    event::{ElementState, Event, KeyEvent, MouseScrollDelta, WindowEvent},
+    event::{ElementState, Event, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
-    keyboard::{KeyCode, PhysicalKey},
+    keyboard::{KeyCode, ModifiersState, PhysicalKey},
 };
 
+/// Mirror the CPU `Electron` state into the GPU-side layout `cs_step_electrons` expects
+fn electrons_to_gpu(simulation: &HallSimulation) -> Vec<ElectronGpu> {
+    simulation
+        .electrons
+        .iter()
+        .map(|e| ElectronGpu {
+            position: [e.position.x, e.position.y],
+            velocity: [e.velocity.x, e.velocity.y],
+            guiding_center: [e.guiding_center.x, e.guiding_center.y],
+            landau_index: e.landau_level,
+            _padding: 0,
+        })
+        .collect()
+}
+
 struct EguiState {
     ctx: egui::Context,
     state: egui_winit::State,
@@ -43,15 +67,30 @@ struct App {
     camera: Camera2D,
     paused: bool,
     egui: EguiState,
+    plateau_history: PlateauHistory,
+    dt: f32,
+    show_probability_field: bool,
+    modifiers: ModifiersState,
+    cursor_pos: (f64, f64),
+    /// Which mouse button is currently driving a camera drag, if any
+    drag_button: Option<MouseButton>,
+    /// Cursor position at the start of the drag / after the last processed
+    /// `CursorMoved` while dragging
+    last_drag_pos: (f64, f64),
 }
 
 impl App {
     fn new(ctx: GraphicsContext) -> Self {
-        let renderer = QuantumRenderer::new(&ctx, 500, 200);
+        let mut renderer = QuantumRenderer::new(&ctx, 500, 200);
         let mut camera = Camera2D::new(ctx.aspect_ratio());
         camera.zoom = 8.0;
 
         let simulation = HallSimulation::default();
+        let mut plateau_history = PlateauHistory::new();
+        plateau_history.record(simulation.magnetic_field, simulation.hall_conductance);
+
+        renderer.init_electron_compute(&ctx.device, simulation.electrons.len().next_power_of_two().max(1024));
+        renderer.upload_electrons_gpu(&ctx.queue, &electrons_to_gpu(&simulation));
 
         let egui_ctx = egui::Context::default();
         let egui_state = egui_winit::State::new(
@@ -79,15 +118,24 @@ impl App {
                 state: egui_state,
                 renderer: egui_renderer,
             },
+            plateau_history,
+            dt: 0.0,
+            show_probability_field: false,
+            modifiers: ModifiersState::empty(),
+            cursor_pos: (0.0, 0.0),
+            drag_button: None,
+            last_drag_pos: (0.0, 0.0),
         }
     }
 
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.ctx.resize(new_size);
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
+        self.renderer.resize(&self.ctx, new_size.width, new_size.height);
     }
 
     fn update(&mut self, dt: f32) {
+        self.dt = dt;
         if !self.paused {
             self.simulation.step(dt);
         }
@@ -104,17 +152,20 @@ impl App {
         let electron_data = self.simulation.get_electron_data();
         let points: Vec<PointInstance> = electron_data
             .iter()
-            .map(|(pos, color, is_edge)| {
+            .map(|(pos, color, is_edge, localized)| {
                 let size = if *is_edge { 0.15 } else { 0.1 };
+                // Localized bulk states carry no current, so they're drawn
+                // dimmer than the extended states that actually conduct
+                let alpha = if *localized { color[3] * 0.35 } else { color[3] };
                 PointInstance {
                     position: [pos.x, pos.y, 0.0],
                     size,
-                    color: *color,
+                    color: [color[0], color[1], color[2], alpha],
                 }
             })
             .collect();
 
-        self.renderer.update_points(&self.ctx.queue, &points);
+        self.renderer.update_points(&self.ctx.device, &self.ctx.queue, &points);
 
         let orbits = self.simulation.get_orbits();
         let mut lines: Vec<(Vec3, Vec3, [f32; 4])> = Vec::new();
@@ -137,7 +188,18 @@ impl App {
         lines.push((Vec3::new(hw, hh, 0.0), Vec3::new(-hw, hh, 0.0), [0.5, 0.5, 0.5, 0.5]));
         lines.push((Vec3::new(-hw, hh, 0.0), Vec3::new(-hw, -hh, 0.0), [0.5, 0.5, 0.5, 0.5]));
 
-        self.renderer.update_lines(&self.ctx.queue, &lines);
+        if self.show_probability_field {
+            let contours = self.simulation.probability_contours(64, 0.5);
+            lines.extend(contours.iter().map(|(a, b)| {
+                (
+                    Vec3::new(a.x, a.y, 0.0),
+                    Vec3::new(b.x, b.y, 0.0),
+                    [0.2, 0.8, 1.0, 0.8],
+                )
+            }));
+        }
+
+        self.renderer.update_lines(&self.ctx.device, &self.ctx.queue, &lines);
 
         // Build egui UI
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
@@ -161,8 +223,14 @@ impl App {
                     if self.paused {
                         ui.label(egui::RichText::new("PAUSED").color(egui::Color32::YELLOW));
                     }
+                    if self.show_probability_field {
+                        ui.separator();
+                        ui.label("|ψ|² contours [P]");
+                    }
                 });
             });
+
+            self.plateau_history.draw(ctx);
         });
 
         self.egui.state.handle_platform_output(&self.ctx.window, full_output.platform_output);
@@ -183,10 +251,22 @@ impl App {
                 label: Some("Render Encoder"),
             });
 
+        if !self.paused {
+            self.renderer.step_electrons_gpu(
+                &mut encoder,
+                &self.ctx.queue,
+                self.simulation.magnetic_field,
+                self.dt,
+                self.simulation.width / 2.0,
+                self.simulation.height / 2.0,
+                self.simulation.electrons.len() as u32,
+            );
+        }
+
         self.renderer
-            .render_lines(&mut encoder, &view, lines.len() as u32, true);
+            .render_lines(&mut encoder, &view, lines.len() as u32, true, BlendMode::AlphaBlend);
         self.renderer
-            .render_points(&mut encoder, &view, points.len() as u32, false);
+            .render_points(&mut encoder, &view, points.len() as u32, false, BlendMode::AlphaBlend);
 
         self.egui.renderer.update_buffers(
             &self.ctx.device,
@@ -232,11 +312,19 @@ impl App {
 
         match key {
             KeyCode::Space => self.paused = !self.paused,
+            KeyCode::KeyP => self.show_probability_field = !self.show_probability_field,
+            KeyCode::KeyF => self.frame_all(),
+            KeyCode::ArrowUp if self.modifiers.shift_key() => self.camera.position.y += self.camera.zoom * 0.1,
+            KeyCode::ArrowDown if self.modifiers.shift_key() => self.camera.position.y -= self.camera.zoom * 0.1,
+            KeyCode::ArrowLeft if self.modifiers.shift_key() => self.camera.position.x -= self.camera.zoom * 0.1,
+            KeyCode::ArrowRight if self.modifiers.shift_key() => self.camera.position.x += self.camera.zoom * 0.1,
             KeyCode::ArrowUp => {
                 self.simulation.set_magnetic_field(self.simulation.magnetic_field + 0.2);
+                self.plateau_history.record(self.simulation.magnetic_field, self.simulation.hall_conductance);
             }
             KeyCode::ArrowDown => {
                 self.simulation.set_magnetic_field(self.simulation.magnetic_field - 0.2);
+                self.plateau_history.record(self.simulation.magnetic_field, self.simulation.hall_conductance);
             }
             KeyCode::Equal => {
                 self.simulation.fill_electrons(n_electrons + 10);
@@ -248,9 +336,11 @@ impl App {
             }
             KeyCode::Digit1 => {
                 self.simulation = HallSimulation::preset_nu_1();
+                self.plateau_history.record(self.simulation.magnetic_field, self.simulation.hall_conductance);
             }
             KeyCode::Digit2 => {
                 self.simulation = HallSimulation::preset_nu_2();
+                self.plateau_history.record(self.simulation.magnetic_field, self.simulation.hall_conductance);
             }
             _ => {}
         }
@@ -261,6 +351,46 @@ impl App {
         self.camera.zoom = self.camera.zoom.clamp(2.0, 20.0);
     }
 
+    /// Recompute zoom+center to fit every current electron into view, with
+    /// a fixed world-unit margin so points near the edge aren't clipped
+    fn frame_all(&mut self) {
+        let mut min = Vec2::splat(f32::MAX);
+        let mut max = Vec2::splat(f32::MIN);
+        for electron in &self.simulation.electrons {
+            min = min.min(electron.position);
+            max = max.max(electron.position);
+        }
+        if min.x > max.x {
+            return;
+        }
+        self.camera.frame_bounds(min, max, 1.0, 2.0);
+    }
+
+    /// Track the cursor position and, if a drag button is held, pan the
+    /// camera by the movement since the last event
+    fn handle_cursor_moved(&mut self, x: f64, y: f64) {
+        if self.drag_button.is_some() {
+            let dx = (x - self.last_drag_pos.0) as f32;
+            let dy = (y - self.last_drag_pos.1) as f32;
+            self.camera.pan_screen(dx, dy, self.ctx.size.width as f32, self.ctx.size.height as f32);
+            self.last_drag_pos = (x, y);
+        }
+        self.cursor_pos = (x, y);
+    }
+
+    /// Begin a middle/right-drag pan: latch the drag button
+    fn start_drag(&mut self, button: MouseButton) {
+        self.drag_button = Some(button);
+        self.last_drag_pos = self.cursor_pos;
+    }
+
+    /// Release a drag
+    fn end_drag(&mut self, button: MouseButton) {
+        if self.drag_button == Some(button) {
+            self.drag_button = None;
+        }
+    }
+
     fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
         self.egui.state.on_window_event(&self.ctx.window, event).consumed
     }
@@ -288,6 +418,9 @@ fn main() {
                         match event {
                             WindowEvent::CloseRequested => elwt.exit(),
                             WindowEvent::Resized(size) => app.resize(*size),
+                            WindowEvent::ModifiersChanged(modifiers) => {
+                                app.modifiers = modifiers.state();
+                            }
                             WindowEvent::KeyboardInput {
                                 event:
                                     KeyEvent {
@@ -297,6 +430,18 @@ fn main() {
                                     },
                                 ..
                             } => app.handle_key(*key, *state),
+                            WindowEvent::MouseInput { state, button, .. } => match (button, state) {
+                                (MouseButton::Middle | MouseButton::Right, ElementState::Pressed) => {
+                                    app.start_drag(*button);
+                                }
+                                (MouseButton::Middle | MouseButton::Right, ElementState::Released) => {
+                                    app.end_drag(*button);
+                                }
+                                _ => {}
+                            },
+                            WindowEvent::CursorMoved { position, .. } => {
+                                app.handle_cursor_moved(position.x, position.y);
+                            }
                             WindowEvent::MouseWheel { delta, .. } => {
                                 let scroll = match delta {
                                     MouseScrollDelta::LineDelta(_, y) => *y,
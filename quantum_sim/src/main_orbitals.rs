@@ -8,21 +8,28 @@
 //! - 1-9: Switch orbitals (1s, 2s, 2p, 3s, 3p, 3d, etc.)
 //! - Space: Toggle phase animation
 //! - R: Regenerate points
+//! - M: Toggle between the stochastic point cloud and a marching-cubes
+//!   isosurface of the probability density
+//! - [ / ]: Shrink/grow the isosurface's isovalue, re-tessellating the mesh
 
 mod wavefunction;
 mod quantum_state;
 mod tunneling;
 mod orbitals;
+mod marching_cubes;
 mod teleportation;
 mod quarks;
+mod reconnection;
 mod hall_effect;
 mod hypercube;
 mod renderer;
 mod equations_ui;
+mod environment;
 
 use common::{Camera3D, GraphicsContext};
+use glam::Vec3;
 use orbitals::{OrbitalSimulation, QuantumNumbers};
-use renderer::{QuantumRenderer, PointInstance, orbital_to_points};
+use renderer::{QuantumRenderer, PointInstance, BlendMode, orbital_to_points};
 use equations_ui::{draw_equations_sidebar, ORBITAL_EQUATIONS, ORBITAL_VARIABLES};
 use winit::{
     event::{ElementState, Event, KeyEvent, MouseScrollDelta, WindowEvent},
@@ -36,6 +43,23 @@ struct EguiState {
     renderer: egui_wgpu::Renderer,
 }
 
+/// Whether the orbital is drawn as its stochastic point cloud or as a solid
+/// marching-cubes isosurface of the probability density
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RenderMode {
+    PointCloud,
+    Isosurface,
+}
+
+impl RenderMode {
+    fn label(self) -> &'static str {
+        match self {
+            RenderMode::PointCloud => "Point Cloud",
+            RenderMode::Isosurface => "Isosurface",
+        }
+    }
+}
+
 struct App {
     ctx: GraphicsContext,
     renderer: QuantumRenderer,
@@ -43,15 +67,26 @@ struct App {
     camera: Camera3D,
     paused: bool,
     egui: EguiState,
+    render_mode: RenderMode,
+    /// Probability density the isosurface is extracted at; re-derived from
+    /// `max_sampled_density` whenever the orbital changes
+    isovalue: f32,
+    /// Cached isosurface triangles (position + lit color) ready for
+    /// `QuantumRenderer::update_faces`; `None` until the mesh is current
+    iso_mesh: Option<Vec<(Vec3, Vec3, Vec3, [f32; 4])>>,
 }
 
 impl App {
     fn new(ctx: GraphicsContext) -> Self {
-        let renderer = QuantumRenderer::new(&ctx, 10000, 100);
+        let mut renderer = QuantumRenderer::new(&ctx, 10000, 100);
+        if let Some(env) = environment::load_default_environment(&ctx.device, &ctx.queue) {
+            renderer.set_environment(&ctx.device, &env);
+        }
         let mut camera = Camera3D::new(ctx.aspect_ratio());
         camera.distance = 8.0;
 
         let simulation = OrbitalSimulation::preset_2p();
+        let isovalue = default_isovalue(&simulation);
 
         let egui_ctx = egui::Context::default();
         let egui_state = egui_winit::State::new(
@@ -79,12 +114,37 @@ impl App {
                 state: egui_state,
                 renderer: egui_renderer,
             },
+            render_mode: RenderMode::PointCloud,
+            isovalue,
+            iso_mesh: None,
         }
     }
 
+    /// Re-extract the isosurface at the current isovalue and shade each
+    /// triangle by a single fixed light direction against its
+    /// finite-difference normal, caching the result for `render()` to upload
+    fn retessellate(&mut self) {
+        const LIGHT_DIR: Vec3 = Vec3::new(0.4, 0.7, 0.5);
+        let triangles = self.simulation.extract_isosurface(self.isovalue, 48);
+
+        self.iso_mesh = Some(
+            triangles
+                .into_iter()
+                .map(|[p0, p1, p2]| {
+                    let centroid = (p0 + p1 + p2) / 3.0;
+                    let normal = self.simulation.isosurface_normal(centroid);
+                    let brightness = (normal.dot(LIGHT_DIR.normalize()).max(0.0) * 0.7 + 0.3).min(1.0);
+                    let color = [brightness * 0.4, brightness * 0.7, brightness, 0.9];
+                    (p0, p1, p2, color)
+                })
+                .collect(),
+        );
+    }
+
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.ctx.resize(new_size);
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
+        self.renderer.resize(&self.ctx, new_size.width, new_size.height);
     }
 
     fn update(&mut self, dt: f32) {
@@ -104,7 +164,16 @@ impl App {
         let render_data = self.simulation.get_render_data();
         let points = orbital_to_points(&render_data);
 
-        self.renderer.update_points(&self.ctx.queue, &points);
+        self.renderer.update_points(&self.ctx.device, &self.ctx.queue, &points);
+
+        if self.render_mode == RenderMode::Isosurface {
+            if self.iso_mesh.is_none() {
+                self.retessellate();
+            }
+            if let Some(mesh) = &self.iso_mesh {
+                self.renderer.update_faces(&self.ctx.queue, mesh);
+            }
+        }
 
         // Build egui UI
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
@@ -126,6 +195,11 @@ impl App {
                         self.simulation.quantum_numbers.m));
                     ui.separator();
                     ui.label(format!("Points: {}", self.simulation.points.len()));
+                    ui.separator();
+                    ui.label(format!("Render: {}", self.render_mode.label()));
+                    if self.render_mode == RenderMode::Isosurface {
+                        ui.label(format!("iso={:.4}", self.isovalue));
+                    }
                     if self.paused {
                         ui.label(egui::RichText::new("PAUSED").color(egui::Color32::YELLOW));
                     }
@@ -151,8 +225,20 @@ impl App {
                 label: Some("Render Encoder"),
             });
 
-        self.renderer
-            .render_points(&mut encoder, &view, points.len() as u32, true);
+        self.renderer.render_skybox(&mut encoder, &view, true);
+
+        match self.render_mode {
+            RenderMode::PointCloud => {
+                // Probability cloud points accumulate brightness where they
+                // overlap instead of occluding each other by alpha coverage
+                self.renderer
+                    .render_points(&mut encoder, &view, points.len() as u32, false, BlendMode::Additive);
+            }
+            RenderMode::Isosurface => {
+                let num_vertices = self.iso_mesh.as_ref().map_or(0, |m| m.len() * 3) as u32;
+                self.renderer.render_faces(&mut encoder, &view, num_vertices, false);
+            }
+        }
 
         self.egui.renderer.update_buffers(
             &self.ctx.device,
@@ -197,15 +283,29 @@ impl App {
         match key {
             KeyCode::Space => self.paused = !self.paused,
             KeyCode::KeyR => self.simulation.regenerate_points(),
-            KeyCode::Digit1 => self.simulation.set_orbital(QuantumNumbers::s1()),
-            KeyCode::Digit2 => self.simulation.set_orbital(QuantumNumbers::s2()),
-            KeyCode::Digit3 => self.simulation.set_orbital(QuantumNumbers::p2_0()),
-            KeyCode::Digit4 => self.simulation.set_orbital(QuantumNumbers::p2_1()),
-            KeyCode::Digit5 => self.simulation.set_orbital(QuantumNumbers::s3()),
-            KeyCode::Digit6 => self.simulation.set_orbital(QuantumNumbers::p3_0()),
-            KeyCode::Digit7 => self.simulation.set_orbital(QuantumNumbers::d3_0()),
-            KeyCode::Digit8 => self.simulation.set_orbital(QuantumNumbers::d3_1()),
-            KeyCode::Digit9 => self.simulation.set_orbital(QuantumNumbers::d3_2()),
+            KeyCode::Digit1 => self.set_orbital(QuantumNumbers::s1()),
+            KeyCode::Digit2 => self.set_orbital(QuantumNumbers::s2()),
+            KeyCode::Digit3 => self.set_orbital(QuantumNumbers::p2_0()),
+            KeyCode::Digit4 => self.set_orbital(QuantumNumbers::p2_1()),
+            KeyCode::Digit5 => self.set_orbital(QuantumNumbers::s3()),
+            KeyCode::Digit6 => self.set_orbital(QuantumNumbers::p3_0()),
+            KeyCode::Digit7 => self.set_orbital(QuantumNumbers::d3_0()),
+            KeyCode::Digit8 => self.set_orbital(QuantumNumbers::d3_1()),
+            KeyCode::Digit9 => self.set_orbital(QuantumNumbers::d3_2()),
+            KeyCode::KeyM => {
+                self.render_mode = match self.render_mode {
+                    RenderMode::PointCloud => RenderMode::Isosurface,
+                    RenderMode::Isosurface => RenderMode::PointCloud,
+                };
+            }
+            KeyCode::BracketLeft => {
+                self.isovalue *= 0.8;
+                self.iso_mesh = None;
+            }
+            KeyCode::BracketRight => {
+                self.isovalue *= 1.25;
+                self.iso_mesh = None;
+            }
             KeyCode::ArrowLeft => self.camera.orbit(-0.1, 0.0),
             KeyCode::ArrowRight => self.camera.orbit(0.1, 0.0),
             KeyCode::ArrowUp => self.camera.orbit(0.0, 0.1),
@@ -214,6 +314,16 @@ impl App {
         }
     }
 
+    /// Switch the displayed orbital and re-derive the isosurface isovalue
+    /// (the old value, tuned for the previous orbital's density scale,
+    /// would otherwise show either nothing or a solid blob) and invalidate
+    /// the cached mesh so it gets re-tessellated on the next isosurface draw
+    fn set_orbital(&mut self, numbers: QuantumNumbers) {
+        self.simulation.set_orbital(numbers);
+        self.isovalue = default_isovalue(&self.simulation);
+        self.iso_mesh = None;
+    }
+
     fn handle_scroll(&mut self, delta: f32) {
         self.camera.zoom(delta);
     }
@@ -223,6 +333,13 @@ impl App {
     }
 }
 
+/// A starting isovalue scaled off the orbital's own peak density, so newly
+/// selected orbitals open on a visible shell instead of an empty or solid
+/// mesh before the user reaches for `[`/`]`
+fn default_isovalue(simulation: &OrbitalSimulation) -> f32 {
+    simulation.max_sampled_density(24) * 0.15
+}
+
 fn main() {
     let (ctx, event_loop) = pollster::block_on(GraphicsContext::new(
         "Atomic Orbitals - Probability Cloud Visualization",
@@ -8,22 +8,26 @@
 //! - 3: Pion+ (ud̄)
 //! - 4: J/ψ (cc̄)
 //! - Space: Pause/resume
+//! - R: Color-reconnect flux tubes (plain algorithm)
 //! - Arrow keys: Rotate view
 
 mod wavefunction;
 mod quantum_state;
 mod tunneling;
 mod orbitals;
+mod marching_cubes;
 mod teleportation;
 mod quarks;
+mod reconnection;
 mod hall_effect;
 mod hypercube;
 mod renderer;
+mod environment;
 
 use common::{Camera3D, GraphicsContext};
 use glam::Vec3;
 use quarks::QuarkSimulation;
-use renderer::{QuantumRenderer, PointInstance, quarks_to_points};
+use renderer::{QuantumRenderer, PointInstance, BlendMode, quarks_to_points};
 use winit::{
     event::{ElementState, Event, KeyEvent, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
@@ -40,7 +44,10 @@ struct App {
 
 impl App {
     fn new(ctx: GraphicsContext) -> Self {
-        let renderer = QuantumRenderer::new(&ctx, 100, 200);
+        let mut renderer = QuantumRenderer::new(&ctx, 100, 200);
+        if let Some(env) = environment::load_default_environment(&ctx.device, &ctx.queue) {
+            renderer.set_environment(&ctx.device, &env);
+        }
         let mut camera = Camera3D::new(ctx.aspect_ratio());
         camera.distance = 5.0;
 
@@ -59,6 +66,7 @@ impl App {
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.ctx.resize(new_size);
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
+        self.renderer.resize(&self.ctx, new_size.width, new_size.height);
     }
 
     fn update(&mut self, dt: f32) {
@@ -78,7 +86,7 @@ impl App {
         // Render quarks
         let quark_data = self.simulation.get_quark_data();
         let points = quarks_to_points(&quark_data);
-        self.renderer.update_points(&self.ctx.queue, &points);
+        self.renderer.update_points(&self.ctx.device, &self.ctx.queue, &points);
 
         // Render flux tubes
         let mut lines: Vec<(Vec3, Vec3, [f32; 4])> = Vec::new();
@@ -103,7 +111,7 @@ impl App {
             ));
         }
 
-        self.renderer.update_lines(&self.ctx.queue, &lines);
+        self.renderer.update_lines(&self.ctx.device, &self.ctx.queue, &lines);
 
         let mut encoder = self
             .ctx
@@ -112,10 +120,13 @@ impl App {
                 label: Some("Render Encoder"),
             });
 
+        self.renderer.render_skybox(&mut encoder, &view, true);
+        // Flux tubes and gluons are glowing color-field lines, so they
+        // accumulate brightness additively rather than occluding by alpha
         self.renderer
-            .render_lines(&mut encoder, &view, lines.len() as u32, true);
+            .render_lines(&mut encoder, &view, lines.len() as u32, false, BlendMode::Additive);
         self.renderer
-            .render_points(&mut encoder, &view, points.len() as u32, false);
+            .render_points(&mut encoder, &view, points.len() as u32, false, BlendMode::AlphaBlend);
 
         self.ctx.queue.submit(std::iter::once(encoder.finish()));
         output.present();
@@ -134,6 +145,7 @@ impl App {
             KeyCode::Digit2 => self.simulation.init_neutron(),
             KeyCode::Digit3 => self.simulation.init_pion_plus(),
             KeyCode::Digit4 => self.simulation.init_jpsi(),
+            KeyCode::KeyR => self.simulation.reconnect(reconnection::ReconnectionMode::Plain),
             KeyCode::ArrowLeft => self.camera.orbit(-0.1, 0.0),
             KeyCode::ArrowRight => self.camera.orbit(0.1, 0.0),
             KeyCode::ArrowUp => self.camera.orbit(0.0, 0.1),
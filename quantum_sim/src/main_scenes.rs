@@ -0,0 +1,701 @@
+//! Scene Switcher
+//!
+//! Hosts several simulation modules behind the `Simulation` trait so they
+//! can be swapped at runtime instead of each needing its own binary.
+//!
+//! Currently wired up: Quantum Teleportation, Superdense Coding, Atomic
+//! Orbitals, Quarks & Hadrons, and the Quantum Hall Effect — the modules
+//! whose visuals reduce to the `points`/`lines` primitives the trait exposes.
+//! All of them share the `Camera3D` orbit camera; the Hall effect's own
+//! binary (`main_hall`) uses a flat `Camera2D` instead, but its electron gas
+//! lives entirely in the z=0 plane, so here it's plotted as ordinary 3D
+//! points/lines and viewed top-down by pinning the orbit camera's pitch.
+//! Quantum Tunneling still needs its own 2D camera for the potential-profile
+//! plot, and the 4D hypercube renders shaded polytope faces rather than
+//! points/lines, so those two stay on their own dedicated binaries for now.
+//! `gravity_sim` and `atoms` are separate crates with their own
+//! `GraphicsContext`/pipelines, so they aren't scene-switcher candidates
+//! without a shared workspace to host them all in.
+//!
+//! A scene can also drive its own transition: `Simulation::poll_action`
+//! returns `SceneAction::GoTo(name)` once per frame, so e.g. finishing the
+//! teleportation protocol hands off straight to Superdense Coding instead of
+//! leaving the viewer parked on a completed animation.
+//!
+//! Controls:
+//! - Tab: Cycle to the next scene (or use the dropdown in the top bar)
+//! - Backspace: Reset the current scene
+//! - Arrow keys: Rotate view, Scroll: Zoom
+//! - (Remaining keys are scene-specific; see each scene's own binary for details)
+
+mod wavefunction;
+mod quantum_state;
+mod tunneling;
+mod orbitals;
+mod marching_cubes;
+mod teleportation;
+mod quarks;
+mod reconnection;
+mod hall_effect;
+mod hypercube;
+mod renderer;
+mod equations_ui;
+mod environment;
+mod superdense;
+mod simulation;
+
+use common::{Camera3D, GraphicsContext};
+use glam::Vec3;
+use simulation::{Simulation, SceneAction};
+use teleportation::{PauliBasis, TeleportationMode, TeleportationSimulation, TeleportationStage};
+use superdense::SuperdenseCodingSimulation;
+use orbitals::{OrbitalSimulation, QuantumNumbers};
+use quarks::QuarkSimulation;
+use hall_effect::HallSimulation;
+use renderer::{QuantumRenderer, BlendMode, orbital_to_points, quarks_to_points};
+use equations_ui::{
+    draw_equations_sidebar,
+    Equation,
+    TELEPORTATION_EQUATIONS, TELEPORTATION_VARIABLES,
+    SUPERDENSE_EQUATIONS, SUPERDENSE_VARIABLES,
+    ORBITAL_EQUATIONS, ORBITAL_VARIABLES,
+    QUARK_EQUATIONS, QUARK_VARIABLES,
+    HALL_EQUATIONS, HALL_VARIABLES,
+};
+use winit::{
+    event::{ElementState, Event, KeyEvent, MouseScrollDelta, WindowEvent},
+    event_loop::ControlFlow,
+    keyboard::{KeyCode, PhysicalKey},
+};
+
+impl Simulation for TeleportationSimulation {
+    fn step(&mut self, dt: f32) {
+        TeleportationSimulation::step(self, dt);
+    }
+
+    fn title(&self) -> &str {
+        "Quantum Teleportation"
+    }
+
+    fn equations(&self) -> (&[Equation], &[(&str, &str)]) {
+        (TELEPORTATION_EQUATIONS, TELEPORTATION_VARIABLES)
+    }
+
+    fn points(&self) -> Vec<renderer::PointInstance> {
+        let mut points = Vec::new();
+        for qubit in &self.qubits {
+            points.push(renderer::PointInstance {
+                position: [qubit.position.x, qubit.position.y, qubit.position.z],
+                size: 0.5,
+                color: qubit.color,
+            });
+            let tip = qubit.position + qubit.bloch_vector * 0.6;
+            points.push(renderer::PointInstance {
+                position: [tip.x, tip.y, tip.z],
+                size: 0.15,
+                color: [1.0, 1.0, 1.0, 1.0],
+            });
+        }
+        points
+    }
+
+    fn lines(&self) -> Vec<(Vec3, Vec3, [f32; 4])> {
+        self.entanglement_links
+            .iter()
+            .map(|link| {
+                let p1 = self.qubits[link.qubit_a].position;
+                let p2 = self.qubits[link.qubit_b].position;
+                (p1, p2, link.color)
+            })
+            .collect()
+    }
+
+    fn status(&self) -> String {
+        format!("{} | Fidelity: {:.3}", self.stage_description(), self.fidelity)
+    }
+
+    fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Space | KeyCode::Enter => self.next_stage(),
+            KeyCode::KeyR => self.reset(),
+            KeyCode::KeyM => {
+                let next_mode = match self.mode {
+                    TeleportationMode::Measured => TeleportationMode::Coherent,
+                    TeleportationMode::Coherent => TeleportationMode::Measured,
+                };
+                self.set_mode(next_mode);
+            }
+            KeyCode::BracketLeft => self.set_channel_noise(self.channel_noise - 0.05),
+            KeyCode::BracketRight => self.set_channel_noise(self.channel_noise + 0.05),
+            KeyCode::KeyQ => println!("{}", self.to_qasm()),
+            KeyCode::Digit1 => self.set_pauli_eigenstate(PauliBasis::Z, true),
+            KeyCode::Digit2 => self.set_pauli_eigenstate(PauliBasis::Z, false),
+            KeyCode::Digit3 => self.set_pauli_eigenstate(PauliBasis::X, true),
+            KeyCode::Digit4 => self.set_pauli_eigenstate(PauliBasis::X, false),
+            KeyCode::Digit5 => self.set_pauli_eigenstate(PauliBasis::Y, true),
+            KeyCode::Digit6 => self.set_pauli_eigenstate(PauliBasis::Y, false),
+            _ => {}
+        }
+    }
+
+    fn reset(&mut self) {
+        TeleportationSimulation::reset(self);
+    }
+
+    // Once the protocol has played through to completion, hand off to
+    // Superdense Coding rather than leaving the viewer parked on a finished
+    // animation
+    fn poll_action(&mut self) -> SceneAction {
+        if self.stage == TeleportationStage::Complete && self.stage_progress >= 1.0 {
+            SceneAction::GoTo("Superdense Coding")
+        } else {
+            SceneAction::None
+        }
+    }
+}
+
+impl Simulation for SuperdenseCodingSimulation {
+    fn step(&mut self, dt: f32) {
+        SuperdenseCodingSimulation::step(self, dt);
+    }
+
+    fn title(&self) -> &str {
+        "Superdense Coding"
+    }
+
+    fn equations(&self) -> (&[Equation], &[(&str, &str)]) {
+        (SUPERDENSE_EQUATIONS, SUPERDENSE_VARIABLES)
+    }
+
+    fn points(&self) -> Vec<renderer::PointInstance> {
+        let mut points = Vec::new();
+        for qubit in &self.qubits {
+            points.push(renderer::PointInstance {
+                position: [qubit.position.x, qubit.position.y, qubit.position.z],
+                size: 0.5,
+                color: qubit.color,
+            });
+            let tip = qubit.position + qubit.bloch_vector * 0.6;
+            points.push(renderer::PointInstance {
+                position: [tip.x, tip.y, tip.z],
+                size: 0.15,
+                color: [1.0, 1.0, 1.0, 1.0],
+            });
+        }
+        points
+    }
+
+    fn lines(&self) -> Vec<(Vec3, Vec3, [f32; 4])> {
+        self.entanglement_links
+            .iter()
+            .map(|link| {
+                let p1 = self.qubits[link.qubit_a].position;
+                let p2 = self.qubits[link.qubit_b].position;
+                (p1, p2, link.color)
+            })
+            .collect()
+    }
+
+    fn status(&self) -> String {
+        let (b0, b1) = self.bits_to_send;
+        format!(
+            "{} | Sending: ({}, {}) | Decoded: {:?}",
+            self.stage_description(),
+            b0 as u8,
+            b1 as u8,
+            self.bob_results,
+        )
+    }
+
+    fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Space | KeyCode::Enter => self.next_stage(),
+            KeyCode::KeyR => self.reset(),
+            KeyCode::Digit1 => self.set_bits_to_send(false, false),
+            KeyCode::Digit2 => self.set_bits_to_send(false, true),
+            KeyCode::Digit3 => self.set_bits_to_send(true, false),
+            KeyCode::Digit4 => self.set_bits_to_send(true, true),
+            _ => {}
+        }
+    }
+
+    fn reset(&mut self) {
+        SuperdenseCodingSimulation::reset(self);
+    }
+}
+
+impl Simulation for OrbitalSimulation {
+    fn step(&mut self, dt: f32) {
+        OrbitalSimulation::step(self, dt);
+    }
+
+    fn title(&self) -> &str {
+        "Atomic Orbitals"
+    }
+
+    fn equations(&self) -> (&[Equation], &[(&str, &str)]) {
+        (ORBITAL_EQUATIONS, ORBITAL_VARIABLES)
+    }
+
+    fn points(&self) -> Vec<renderer::PointInstance> {
+        orbital_to_points(&self.get_render_data())
+    }
+
+    fn lines(&self) -> Vec<(Vec3, Vec3, [f32; 4])> {
+        Vec::new()
+    }
+
+    fn status(&self) -> String {
+        format!("Orbital: {} | Points: {}", self.quantum_numbers.name(), self.points.len())
+    }
+
+    fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Space => self.animate_phase = !self.animate_phase,
+            KeyCode::KeyR => self.regenerate_points(),
+            KeyCode::Digit1 => self.set_orbital(QuantumNumbers::s1()),
+            KeyCode::Digit2 => self.set_orbital(QuantumNumbers::s2()),
+            KeyCode::Digit3 => self.set_orbital(QuantumNumbers::p2_0()),
+            KeyCode::Digit4 => self.set_orbital(QuantumNumbers::p2_1()),
+            KeyCode::Digit5 => self.set_orbital(QuantumNumbers::s3()),
+            KeyCode::Digit6 => self.set_orbital(QuantumNumbers::p3_0()),
+            KeyCode::Digit7 => self.set_orbital(QuantumNumbers::d3_0()),
+            KeyCode::Digit8 => self.set_orbital(QuantumNumbers::d3_1()),
+            KeyCode::Digit9 => self.set_orbital(QuantumNumbers::d3_2()),
+            _ => {}
+        }
+    }
+
+    fn reset(&mut self) {
+        self.set_orbital(QuantumNumbers::p2_0());
+    }
+}
+
+impl Simulation for QuarkSimulation {
+    fn step(&mut self, dt: f32) {
+        QuarkSimulation::step(self, dt);
+    }
+
+    fn title(&self) -> &str {
+        "Quarks & Hadrons"
+    }
+
+    fn equations(&self) -> (&[Equation], &[(&str, &str)]) {
+        (QUARK_EQUATIONS, QUARK_VARIABLES)
+    }
+
+    fn points(&self) -> Vec<renderer::PointInstance> {
+        quarks_to_points(&self.get_quark_data())
+    }
+
+    fn lines(&self) -> Vec<(Vec3, Vec3, [f32; 4])> {
+        let mut lines: Vec<(Vec3, Vec3, [f32; 4])> = Vec::new();
+
+        for tube in &self.flux_tubes {
+            let p1 = self.quarks[tube.quark_a].position;
+            let p2 = self.quarks[tube.quark_b].position;
+            lines.push((p1, p2, tube.color_flow));
+        }
+
+        for gluon in &self.gluons {
+            let color = [
+                (gluon.color.render_color()[0] + gluon.anticolor.render_color()[0]) / 2.0,
+                (gluon.color.render_color()[1] + gluon.anticolor.render_color()[1]) / 2.0,
+                (gluon.color.render_color()[2] + gluon.anticolor.render_color()[2]) / 2.0,
+                0.6,
+            ];
+            lines.push((
+                gluon.position - gluon.velocity.normalize() * 0.1,
+                gluon.position + gluon.velocity.normalize() * 0.1,
+                color,
+            ));
+        }
+
+        lines
+    }
+
+    fn status(&self) -> String {
+        format!("Hadron: {:?}", self.hadron_type)
+    }
+
+    fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Digit1 => self.init_proton(),
+            KeyCode::Digit2 => self.init_neutron(),
+            KeyCode::Digit3 => self.init_pion_plus(),
+            KeyCode::Digit4 => self.init_jpsi(),
+            _ => {}
+        }
+    }
+
+    fn reset(&mut self) {
+        self.init_proton();
+    }
+}
+
+impl Simulation for HallSimulation {
+    fn step(&mut self, dt: f32) {
+        HallSimulation::step(self, dt);
+    }
+
+    fn title(&self) -> &str {
+        "Quantum Hall Effect"
+    }
+
+    fn equations(&self) -> (&[Equation], &[(&str, &str)]) {
+        (HALL_EQUATIONS, HALL_VARIABLES)
+    }
+
+    fn points(&self) -> Vec<renderer::PointInstance> {
+        self.get_electron_data()
+            .iter()
+            .map(|(pos, color, is_edge, localized)| {
+                let size = if *is_edge { 0.15 } else { 0.1 };
+                let alpha = if *localized { color[3] * 0.35 } else { color[3] };
+                renderer::PointInstance {
+                    position: [pos.x, pos.y, 0.0],
+                    size,
+                    color: [color[0], color[1], color[2], alpha],
+                }
+            })
+            .collect()
+    }
+
+    fn lines(&self) -> Vec<(Vec3, Vec3, [f32; 4])> {
+        let hw = self.width / 2.0;
+        let hh = self.height / 2.0;
+        vec![
+            (Vec3::new(-hw, -hh, 0.0), Vec3::new(hw, -hh, 0.0), [0.5, 0.5, 0.5, 0.5]),
+            (Vec3::new(hw, -hh, 0.0), Vec3::new(hw, hh, 0.0), [0.5, 0.5, 0.5, 0.5]),
+            (Vec3::new(hw, hh, 0.0), Vec3::new(-hw, hh, 0.0), [0.5, 0.5, 0.5, 0.5]),
+            (Vec3::new(-hw, hh, 0.0), Vec3::new(-hw, -hh, 0.0), [0.5, 0.5, 0.5, 0.5]),
+        ]
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "B = {:.2} T | ν = {:.2} | σ_xy = {:.0} e²/h",
+            self.magnetic_field, self.filling_factor, self.hall_conductance
+        )
+    }
+
+    // The orbit camera's own Arrow keys are consumed by `App::handle_key`
+    // before scene keys are dispatched, so field strength uses PageUp/Down
+    // here instead of the Up/Down main_hall binds directly.
+    fn handle_key(&mut self, key: KeyCode) {
+        let n_electrons = self.electrons.len();
+        match key {
+            KeyCode::PageUp => self.set_magnetic_field(self.magnetic_field + 0.2),
+            KeyCode::PageDown => self.set_magnetic_field(self.magnetic_field - 0.2),
+            KeyCode::Equal => self.fill_electrons(n_electrons + 10),
+            KeyCode::Minus => {
+                if n_electrons > 10 {
+                    self.fill_electrons(n_electrons - 10);
+                }
+            }
+            KeyCode::Digit1 => *self = HallSimulation::preset_nu_1(),
+            KeyCode::Digit2 => *self = HallSimulation::preset_nu_2(),
+            _ => {}
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = HallSimulation::default();
+    }
+}
+
+/// A selectable scene: its display name and how to construct a fresh instance
+struct SceneEntry {
+    name: &'static str,
+    build: fn() -> Box<dyn Simulation>,
+}
+
+fn scene_registry() -> Vec<SceneEntry> {
+    vec![
+        SceneEntry { name: "Quantum Teleportation", build: || Box::new(TeleportationSimulation::new()) },
+        SceneEntry { name: "Superdense Coding", build: || Box::new(SuperdenseCodingSimulation::new()) },
+        SceneEntry { name: "Atomic Orbitals", build: || Box::new(OrbitalSimulation::preset_2p()) },
+        SceneEntry { name: "Quarks & Hadrons", build: || {
+            let mut sim = QuarkSimulation::new();
+            sim.init_proton();
+            Box::new(sim)
+        } },
+        SceneEntry { name: "Quantum Hall Effect", build: || Box::new(HallSimulation::default()) },
+    ]
+}
+
+struct EguiState {
+    ctx: egui::Context,
+    state: egui_winit::State,
+    renderer: egui_wgpu::Renderer,
+}
+
+struct App {
+    ctx: GraphicsContext,
+    renderer: QuantumRenderer,
+    camera: Camera3D,
+    egui: EguiState,
+    scenes: Vec<SceneEntry>,
+    current: usize,
+    simulation: Box<dyn Simulation>,
+}
+
+impl App {
+    fn new(ctx: GraphicsContext) -> Self {
+        let mut renderer = QuantumRenderer::new(&ctx, 10000, 300);
+        if let Some(env) = environment::load_default_environment(&ctx.device, &ctx.queue) {
+            renderer.set_environment(&ctx.device, &env);
+        }
+        let mut camera = Camera3D::new(ctx.aspect_ratio());
+        camera.distance = 10.0;
+        camera.set_pitch(0.3);
+
+        let scenes = scene_registry();
+        let current = 0;
+        let simulation = (scenes[current].build)();
+
+        let egui_ctx = egui::Context::default();
+        let egui_state = egui_winit::State::new(
+            egui_ctx.clone(),
+            egui::ViewportId::ROOT,
+            &ctx.window,
+            Some(ctx.window.scale_factor() as f32),
+            None,
+        );
+        let egui_renderer = egui_wgpu::Renderer::new(&ctx.device, ctx.config.format, None, 1);
+
+        Self {
+            ctx,
+            renderer,
+            camera,
+            egui: EguiState {
+                ctx: egui_ctx,
+                state: egui_state,
+                renderer: egui_renderer,
+            },
+            scenes,
+            current,
+            simulation,
+        }
+    }
+
+    fn switch_to(&mut self, index: usize) {
+        self.current = index % self.scenes.len();
+        self.simulation = (self.scenes[self.current].build)();
+    }
+
+    fn next_scene(&mut self) {
+        self.switch_to(self.current + 1);
+    }
+
+    /// Switch to the scene registered under `name`, if any; used to honor a
+    /// scripted `SceneAction::GoTo` from the currently running scene
+    fn switch_to_named(&mut self, name: &str) {
+        if let Some(index) = self.scenes.iter().position(|scene| scene.name == name) {
+            self.switch_to(index);
+        }
+    }
+
+    fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
+        self.ctx.resize(new_size);
+        self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
+        self.renderer.resize(&self.ctx, new_size.width, new_size.height);
+    }
+
+    fn update(&mut self, dt: f32) {
+        self.simulation.step(dt);
+
+        if let SceneAction::GoTo(name) = self.simulation.poll_action() {
+            self.switch_to_named(name);
+        }
+    }
+
+    fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
+        let output = self.ctx.surface.get_current_texture()?;
+        let view = output
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        self.renderer.update_camera_3d(&self.ctx.queue, &self.camera);
+
+        let points = self.simulation.points();
+        self.renderer.update_points(&self.ctx.device, &self.ctx.queue, &points);
+
+        let lines = self.simulation.lines();
+        self.renderer.update_lines(&self.ctx.device, &self.ctx.queue, &lines);
+
+        let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
+        let (equations, variables) = self.simulation.equations();
+        let title = self.simulation.title().to_string();
+        let status = self.simulation.status();
+        let mut selected = self.current;
+
+        let full_output = self.egui.ctx.run(raw_input, |ctx| {
+            draw_equations_sidebar(ctx, &title, equations, variables);
+
+            egui::TopBottomPanel::top("status").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_label("Scene")
+                        .selected_text(self.scenes[selected].name)
+                        .show_ui(ui, |ui| {
+                            for (i, scene) in self.scenes.iter().enumerate() {
+                                ui.selectable_value(&mut selected, i, scene.name);
+                            }
+                        });
+                    ui.separator();
+                    ui.label(status);
+                });
+            });
+        });
+
+        if selected != self.current {
+            self.switch_to(selected);
+        }
+
+        self.egui.state.handle_platform_output(&self.ctx.window, full_output.platform_output);
+        let tris = self.egui.ctx.tessellate(full_output.shapes, full_output.pixels_per_point);
+        for (id, image_delta) in &full_output.textures_delta.set {
+            self.egui.renderer.update_texture(&self.ctx.device, &self.ctx.queue, *id, image_delta);
+        }
+
+        let screen_descriptor = egui_wgpu::ScreenDescriptor {
+            size_in_pixels: [self.ctx.size.width, self.ctx.size.height],
+            pixels_per_point: full_output.pixels_per_point,
+        };
+
+        let mut encoder = self
+            .ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Render Encoder"),
+            });
+
+        self.renderer.render_skybox(&mut encoder, &view, true);
+        self.renderer
+            .render_lines(&mut encoder, &view, lines.len() as u32, false, BlendMode::Additive);
+        self.renderer
+            .render_points(&mut encoder, &view, points.len() as u32, false, BlendMode::AlphaBlend);
+
+        self.egui.renderer.update_buffers(
+            &self.ctx.device,
+            &self.ctx.queue,
+            &mut encoder,
+            &tris,
+            &screen_descriptor,
+        );
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Egui Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            self.egui.renderer.render(&mut render_pass, &tris, &screen_descriptor);
+        }
+
+        for id in &full_output.textures_delta.free {
+            self.egui.renderer.free_texture(id);
+        }
+
+        self.ctx.queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+
+        Ok(())
+    }
+
+    fn handle_key(&mut self, key: KeyCode, state: ElementState) {
+        if state != ElementState::Pressed {
+            return;
+        }
+
+        match key {
+            KeyCode::Tab => self.next_scene(),
+            KeyCode::Backspace => self.simulation.reset(),
+            KeyCode::ArrowLeft => self.camera.orbit(-0.1, 0.0),
+            KeyCode::ArrowRight => self.camera.orbit(0.1, 0.0),
+            KeyCode::ArrowUp => self.camera.orbit(0.0, 0.1),
+            KeyCode::ArrowDown => self.camera.orbit(0.0, -0.1),
+            other => self.simulation.handle_key(other),
+        }
+    }
+
+    fn handle_scroll(&mut self, delta: f32) {
+        self.camera.zoom(delta);
+    }
+
+    fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
+        self.egui.state.on_window_event(&self.ctx.window, event).consumed
+    }
+}
+
+fn main() {
+    let (ctx, event_loop) = pollster::block_on(GraphicsContext::new(
+        "Quantum Simulations - Scene Switcher",
+        1280,
+        720,
+    ));
+
+    let mut app = App::new(ctx);
+    let mut last_time = std::time::Instant::now();
+
+    event_loop
+        .run(move |event, elwt| {
+            elwt.set_control_flow(ControlFlow::Poll);
+
+            match event {
+                Event::WindowEvent { ref event, .. } => {
+                    let consumed = app.handle_window_event(event);
+
+                    if !consumed {
+                        match event {
+                            WindowEvent::CloseRequested => elwt.exit(),
+                            WindowEvent::Resized(size) => app.resize(*size),
+                            WindowEvent::KeyboardInput {
+                                event:
+                                    KeyEvent {
+                                        physical_key: PhysicalKey::Code(key),
+                                        state,
+                                        ..
+                                    },
+                                ..
+                            } => app.handle_key(*key, *state),
+                            WindowEvent::MouseWheel { delta, .. } => {
+                                let scroll = match delta {
+                                    MouseScrollDelta::LineDelta(_, y) => *y,
+                                    MouseScrollDelta::PixelDelta(pos) => pos.y as f32 / 100.0,
+                                };
+                                app.handle_scroll(scroll);
+                            }
+                            WindowEvent::RedrawRequested => {
+                                let now = std::time::Instant::now();
+                                let dt = (now - last_time).as_secs_f32().min(0.1);
+                                last_time = now;
+
+                                app.update(dt);
+                                match app.render() {
+                                    Ok(_) => {}
+                                    Err(wgpu::SurfaceError::Lost) => app.resize(app.ctx.size),
+                                    Err(wgpu::SurfaceError::OutOfMemory) => elwt.exit(),
+                                    Err(e) => eprintln!("Render error: {:?}", e),
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                Event::AboutToWait => {
+                    app.ctx.window.request_redraw();
+                }
+                _ => {}
+            }
+        })
+        .expect("Event loop error");
+}
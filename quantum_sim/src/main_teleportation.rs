@@ -5,31 +5,78 @@
 //! Controls:
 //! - Enter/Space: Advance to next stage
 //! - R: Reset simulation
-//! - 1-4: Set different initial states to teleport
+//! - 1-6: Teleport a Pauli eigenstate (Z+/Z-/X+/X-/Y+/Y-) and verify it round-trips
+//! - M: Toggle between measurement-based and coherent (deferred-measurement) correction
+//! - [ / ]: Decrease/increase Bell-pair channel noise
+//! - Q: Print the executed circuit as OpenQASM 2.0 to stdout
+//! - Tab: Cycle through protocol scripts found in `quantum_sim/protocols` (None = built-in)
+//! - 1-4: With a protocol script active, teleport one of its scripted initial states
 //! - Arrow keys: Rotate view
 
 mod wavefunction;
 mod quantum_state;
 mod tunneling;
 mod orbitals;
+mod marching_cubes;
 mod teleportation;
+mod teleport_scripts;
 mod quarks;
+mod reconnection;
 mod hall_effect;
 mod hypercube;
 mod renderer;
 mod equations_ui;
 
+use std::path::Path;
+
 use common::{Camera3D, GraphicsContext};
 use glam::Vec3;
-use teleportation::TeleportationSimulation;
-use renderer::{QuantumRenderer, PointInstance};
+use teleportation::{PauliBasis, TeleportationMode, TeleportationSimulation, TeleportationStage};
+use teleport_scripts::{discover_protocols, ProtocolScript};
+use renderer::{QuantumRenderer, PointInstance, BlendMode};
 use equations_ui::{draw_equations_sidebar, TELEPORTATION_EQUATIONS, TELEPORTATION_VARIABLES};
 use winit::{
     event::{ElementState, Event, KeyEvent, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
     keyboard::{KeyCode, PhysicalKey},
 };
-use std::f32::consts::PI;
+
+/// Index a `TeleportationStage` into a scripted protocol's `stages()` array
+fn stage_index(stage: TeleportationStage) -> usize {
+    match stage {
+        TeleportationStage::Initial => 0,
+        TeleportationStage::AliceCNOT => 1,
+        TeleportationStage::AliceHadamard => 2,
+        TeleportationStage::AliceMeasure => 3,
+        TeleportationStage::ClassicalChannel => 4,
+        TeleportationStage::BobCorrection => 5,
+        TeleportationStage::Complete => 6,
+    }
+}
+
+/// Simulation update rate, decoupled from the render/frame rate so stepping
+/// stays deterministic regardless of frame hitches
+const FIXED_DT: f32 = 1.0 / 120.0;
+
+/// Cap on the real elapsed time folded into the accumulator each frame, to
+/// avoid a "spiral of death" (a long stall causing an ever-growing catch-up
+/// loop) after a big hitch or a debugger pause
+const MAX_FRAME_TIME: f32 = 0.25;
+
+/// Spherical linear interpolation between two (approximately unit-length)
+/// Bloch vectors, so the rendered qubit orientation moves along the sphere
+/// surface rather than cutting through its interior
+fn slerp_vec3(a: Vec3, b: Vec3, t: f32) -> Vec3 {
+    let dot = a.dot(b).clamp(-1.0, 1.0);
+    let theta = dot.acos();
+    if theta < 1e-4 {
+        return a.lerp(b, t);
+    }
+    let sin_theta = theta.sin();
+    let wa = ((1.0 - t) * theta).sin() / sin_theta;
+    let wb = (t * theta).sin() / sin_theta;
+    a * wa + b * wb
+}
 
 struct EguiState {
     ctx: egui::Context,
@@ -43,6 +90,20 @@ struct App {
     simulation: TeleportationSimulation,
     camera: Camera3D,
     egui: EguiState,
+
+    /// Leftover real time not yet consumed by a fixed `FIXED_DT` step
+    accumulator: f32,
+    /// Qubit Bloch vectors from before the most recent fixed step, used to
+    /// interpolate render state between simulation ticks
+    previous_bloch: Vec<Vec3>,
+    /// Fraction of a fixed step into the future `previous_bloch` is from,
+    /// i.e. how far to interpolate toward the current simulation state
+    alpha: f32,
+
+    /// Protocol scripts discovered under `quantum_sim/protocols`, cycled
+    /// with Tab; `active_script` is `None` for the built-in protocol
+    scripts: Vec<ProtocolScript>,
+    active_script: Option<usize>,
 }
 
 impl App {
@@ -50,9 +111,11 @@ impl App {
         let renderer = QuantumRenderer::new(&ctx, 100, 50);
         let mut camera = Camera3D::new(ctx.aspect_ratio());
         camera.distance = 12.0;
-        camera.pitch = 0.3;
+        camera.set_pitch(0.3);
 
         let simulation = TeleportationSimulation::new();
+        let previous_bloch = simulation.qubits.iter().map(|q| q.bloch_vector).collect();
+        let scripts = discover_protocols(Path::new("quantum_sim/protocols"));
 
         let egui_ctx = egui::Context::default();
         let egui_state = egui_winit::State::new(
@@ -79,16 +142,80 @@ impl App {
                 state: egui_state,
                 renderer: egui_renderer,
             },
+            accumulator: 0.0,
+            previous_bloch,
+            alpha: 0.0,
+            scripts,
+            active_script: None,
+        }
+    }
+
+    /// The currently active protocol script, if any
+    fn current_script(&self) -> Option<&ProtocolScript> {
+        self.active_script.and_then(|i| self.scripts.get(i))
+    }
+
+    /// Keys 1-4 teleport one of the active script's `initial_states()` in
+    /// declaration order, or fall back to the built-in Pauli eigenstate
+    /// when no script is active (or it defines fewer than 4 states)
+    fn apply_digit_state(&mut self, slot: usize, fallback_basis: PauliBasis, fallback_eigenvalue: bool) {
+        let scripted = self.current_script().and_then(|script| script.initial_states().get(slot).cloned());
+
+        match scripted {
+            Some(state) => self.simulation.set_state_to_teleport(state.theta, state.phi),
+            None => self.simulation.set_pauli_eigenstate(fallback_basis, fallback_eigenvalue),
         }
     }
 
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.ctx.resize(new_size);
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
+        self.renderer.resize(&self.ctx, new_size.width, new_size.height);
     }
 
-    fn update(&mut self, dt: f32) {
-        self.simulation.step(dt);
+    fn update(&mut self, real_dt: f32) {
+        self.accumulator += real_dt.min(MAX_FRAME_TIME);
+
+        while self.accumulator >= FIXED_DT {
+            self.previous_bloch = self.simulation.qubits.iter().map(|q| q.bloch_vector).collect();
+            let stage_before = self.simulation.stage;
+            self.simulation.step(FIXED_DT);
+            if self.simulation.stage != stage_before {
+                self.emit_stage_transition_burst(stage_before);
+            }
+            self.accumulator -= FIXED_DT;
+        }
+
+        self.renderer.update_particles(real_dt);
+        self.alpha = self.accumulator / FIXED_DT;
+    }
+
+    /// Spray a burst of particles at the qubit(s) involved in the stage
+    /// that just completed, so discrete events (collapse, classical
+    /// transmission, reconstruction) read as more than a snapped arrow
+    fn emit_stage_transition_burst(&mut self, completed: TeleportationStage) {
+        let up = Vec3::Y;
+        match completed {
+            TeleportationStage::AliceHadamard => {
+                // Alice's measurement collapses her two qubits
+                for i in [0, 1] {
+                    let origin = self.simulation.qubits[i].position;
+                    self.renderer.emit_burst(origin, up, 24, [1.0, 0.9, 0.3, 1.0]);
+                }
+            }
+            TeleportationStage::AliceMeasure => {
+                // Classical bits travel from Alice's qubits toward Bob's
+                let origin = self.simulation.qubits[0].position;
+                let dir = self.simulation.qubits[2].position - origin;
+                self.renderer.emit_burst(origin, dir, 32, [0.4, 0.8, 1.0, 1.0]);
+            }
+            TeleportationStage::BobCorrection => {
+                // Bob's qubit snaps into the reconstructed state
+                let origin = self.simulation.qubits[2].position;
+                self.renderer.emit_burst(origin, up, 24, [0.6, 1.0, 0.6, 1.0]);
+            }
+            _ => {}
+        }
     }
 
     fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
@@ -102,14 +229,18 @@ impl App {
         // Create qubit visualizations
         let mut points: Vec<PointInstance> = Vec::new();
 
-        for qubit in &self.simulation.qubits {
+        for (i, qubit) in self.simulation.qubits.iter().enumerate() {
             points.push(PointInstance {
                 position: [qubit.position.x, qubit.position.y, qubit.position.z],
                 size: 0.5,
                 color: qubit.color,
             });
 
-            let tip = qubit.position + qubit.bloch_vector * 0.6;
+            let bloch_vector = match self.previous_bloch.get(i) {
+                Some(&previous) => slerp_vec3(previous, qubit.bloch_vector, self.alpha),
+                None => qubit.bloch_vector,
+            };
+            let tip = qubit.position + bloch_vector * 0.6;
             points.push(PointInstance {
                 position: [tip.x, tip.y, tip.z],
                 size: 0.15,
@@ -117,7 +248,8 @@ impl App {
             });
         }
 
-        self.renderer.update_points(&self.ctx.queue, &points);
+        points.extend(self.renderer.particle_points());
+        self.renderer.update_points(&self.ctx.device, &self.ctx.queue, &points);
 
         let lines: Vec<(Vec3, Vec3, [f32; 4])> = self.simulation.entanglement_links
             .iter()
@@ -128,25 +260,57 @@ impl App {
             })
             .collect();
 
-        self.renderer.update_lines(&self.ctx.queue, &lines);
+        self.renderer.update_lines(&self.ctx.device, &self.ctx.queue, &lines);
 
         // Build egui UI
+        let script_equations = self.current_script().and_then(|script| script.equations());
+        let script_stage_description = self.current_script().and_then(|script| {
+            script.stages().get(stage_index(self.simulation.stage)).map(|stage| stage.description.clone())
+        });
+
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
         let full_output = self.egui.ctx.run(raw_input, |ctx| {
-            draw_equations_sidebar(
-                ctx,
-                "Quantum Teleportation",
-                TELEPORTATION_EQUATIONS,
-                TELEPORTATION_VARIABLES,
-            );
+            match &script_equations {
+                Some(equations) => draw_equations_sidebar(ctx, "Scripted Protocol", equations, &[]),
+                None => draw_equations_sidebar(
+                    ctx,
+                    "Quantum Teleportation",
+                    TELEPORTATION_EQUATIONS,
+                    TELEPORTATION_VARIABLES,
+                ),
+            }
 
             egui::TopBottomPanel::top("status").show(ctx, |ui| {
                 ui.horizontal(|ui| {
-                    ui.label(egui::RichText::new(self.simulation.stage_description())
-                        .color(egui::Color32::WHITE));
+                    let description = script_stage_description
+                        .as_deref()
+                        .unwrap_or_else(|| self.simulation.stage_description());
+                    ui.label(egui::RichText::new(description).color(egui::Color32::WHITE));
                 });
                 ui.horizontal(|ui| {
+                    let mode_label = match self.simulation.mode {
+                        TeleportationMode::Measured => "Mode: Measured (M to toggle)",
+                        TeleportationMode::Coherent => "Mode: Coherent (M to toggle)",
+                    };
+                    ui.label(mode_label);
+                    ui.separator();
+                    ui.label(format!("Channel noise p: {:.2} ([ / ] to adjust)", self.simulation.channel_noise));
+                    ui.separator();
+                    ui.label("Press Q to print the circuit as OpenQASM 2.0");
+                    ui.separator();
                     ui.label(format!("Fidelity: {:.3}", self.simulation.fidelity));
+                    if self.simulation.pauli_target.is_some() {
+                        ui.separator();
+                        let verified = self.simulation.verify_in_basis();
+                        ui.label(egui::RichText::new(if verified { "Basis check: PASS" } else { "Basis check: ..." })
+                            .color(if verified { egui::Color32::GREEN } else { egui::Color32::GRAY }));
+                    }
+                    ui.separator();
+                    let script_label = match self.current_script() {
+                        Some(script) => format!("Protocol: {} (Tab to cycle)", script.name),
+                        None => "Protocol: built-in (Tab to cycle)".to_string(),
+                    };
+                    ui.label(script_label);
                     ui.separator();
                     ui.label("Press Space/Enter to advance");
                 });
@@ -172,9 +336,9 @@ impl App {
             });
 
         self.renderer
-            .render_lines(&mut encoder, &view, lines.len() as u32, true);
+            .render_lines(&mut encoder, &view, lines.len() as u32, true, BlendMode::AlphaBlend);
         self.renderer
-            .render_points(&mut encoder, &view, points.len() as u32, false);
+            .render_points(&mut encoder, &view, points.len() as u32, false, BlendMode::AlphaBlend);
 
         self.egui.renderer.update_buffers(
             &self.ctx.device,
@@ -219,10 +383,35 @@ impl App {
         match key {
             KeyCode::Space | KeyCode::Enter => self.simulation.next_stage(),
             KeyCode::KeyR => self.simulation.reset(),
-            KeyCode::Digit1 => self.simulation.set_state_to_teleport(0.0, 0.0),
-            KeyCode::Digit2 => self.simulation.set_state_to_teleport(PI, 0.0),
-            KeyCode::Digit3 => self.simulation.set_state_to_teleport(PI / 2.0, 0.0),
-            KeyCode::Digit4 => self.simulation.set_state_to_teleport(PI / 3.0, PI / 4.0),
+            KeyCode::KeyM => {
+                let next_mode = match self.simulation.mode {
+                    TeleportationMode::Measured => TeleportationMode::Coherent,
+                    TeleportationMode::Coherent => TeleportationMode::Measured,
+                };
+                self.simulation.set_mode(next_mode);
+            }
+            KeyCode::BracketLeft => {
+                self.simulation.set_channel_noise(self.simulation.channel_noise - 0.05);
+            }
+            KeyCode::BracketRight => {
+                self.simulation.set_channel_noise(self.simulation.channel_noise + 0.05);
+            }
+            KeyCode::KeyQ => {
+                println!("{}", self.simulation.to_qasm());
+            }
+            KeyCode::Tab => {
+                self.active_script = match self.active_script {
+                    None if !self.scripts.is_empty() => Some(0),
+                    Some(i) if i + 1 < self.scripts.len() => Some(i + 1),
+                    _ => None,
+                };
+            }
+            KeyCode::Digit1 => self.apply_digit_state(0, PauliBasis::Z, true),
+            KeyCode::Digit2 => self.apply_digit_state(1, PauliBasis::Z, false),
+            KeyCode::Digit3 => self.apply_digit_state(2, PauliBasis::X, true),
+            KeyCode::Digit4 => self.apply_digit_state(3, PauliBasis::X, false),
+            KeyCode::Digit5 => self.simulation.set_pauli_eigenstate(PauliBasis::Y, true),
+            KeyCode::Digit6 => self.simulation.set_pauli_eigenstate(PauliBasis::Y, false),
             KeyCode::ArrowLeft => self.camera.orbit(-0.1, 0.0),
             KeyCode::ArrowRight => self.camera.orbit(0.1, 0.0),
             KeyCode::ArrowUp => self.camera.orbit(0.0, 0.1),
@@ -240,15 +429,20 @@ impl App {
     }
 }
 
-fn main() {
-    let (ctx, event_loop) = pollster::block_on(GraphicsContext::new(
+/// Build the window/event loop and run the app. Native can block the thread
+/// on GPU setup; wasm can't block the browser's main thread at all, so the
+/// async setup has to run as a spawned task and the app is only built once
+/// it resolves.
+async fn run() {
+    let (ctx, event_loop) = GraphicsContext::new(
         "Quantum Teleportation - Bell State Protocol",
         1280,
         720,
-    ));
+    )
+    .await;
 
     let mut app = App::new(ctx);
-    let mut last_time = std::time::Instant::now();
+    let mut clock = common::Clock::new();
 
     event_loop
         .run(move |event, elwt| {
@@ -279,9 +473,7 @@ fn main() {
                                 app.handle_scroll(scroll);
                             }
                             WindowEvent::RedrawRequested => {
-                                let now = std::time::Instant::now();
-                                let dt = (now - last_time).as_secs_f32().min(0.1);
-                                last_time = now;
+                                let dt = clock.tick().min(0.1);
 
                                 app.update(dt);
                                 match app.render() {
@@ -303,3 +495,15 @@ fn main() {
         })
         .expect("Event loop error");
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    pollster::block_on(run());
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen::prelude::wasm_bindgen(start)]
+pub fn main() {
+    std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+    wasm_bindgen_futures::spawn_local(run());
+}
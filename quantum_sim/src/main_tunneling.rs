@@ -7,24 +7,31 @@
 //! - 1/2/3: Switch presets (single barrier, double barrier, step)
 //! - R: Reset simulation
 //! - Arrow keys: Pan camera
+//! - Left click: Drop a measurement probe on the wave packet
+//! - [ / ]: Decrease / increase bloom exposure
 
 mod wavefunction;
 mod quantum_state;
 mod tunneling;
 mod orbitals;
+mod marching_cubes;
 mod teleportation;
 mod quarks;
+mod reconnection;
 mod hall_effect;
 mod hypercube;
 mod renderer;
 mod equations_ui;
+mod logging;
 
 use common::{Camera2D, GraphicsContext};
-use tunneling::{Barrier, TunnelingSimulation};
+use glam::Vec2;
+use tunneling::{Propagator, ProbeReading, TunnelingSimulation};
 use renderer::{QuantumRenderer, PointInstance};
 use equations_ui::{draw_equations_sidebar, TUNNELING_EQUATIONS, TUNNELING_VARIABLES};
+use logging::SimLogger;
 use winit::{
-    event::{ElementState, Event, KeyEvent, WindowEvent},
+    event::{ElementState, Event, KeyEvent, MouseButton, WindowEvent},
     event_loop::ControlFlow,
     keyboard::{KeyCode, PhysicalKey},
 };
@@ -42,6 +49,13 @@ struct App {
     camera: Camera2D,
     paused: bool,
     current_preset: u8,
+    /// Measurement probe dropped by the last left click, if any
+    probe: Option<ProbeReading>,
+    cursor_pos: (f32, f32),
+    /// Exposure the HDR tonemap pass scales bloomed radiance by
+    exposure: f32,
+    /// Streams simulation state to Rerun (no-op unless built with the `rerun` feature)
+    logger: SimLogger,
     egui: EguiState,
 }
 
@@ -76,6 +90,10 @@ impl App {
             camera,
             paused: false,
             current_preset: 1,
+            probe: None,
+            cursor_pos: (0.0, 0.0),
+            exposure: 1.0,
+            logger: SimLogger::new("quantum_tunneling").expect("Failed to start Rerun recording stream"),
             egui: EguiState {
                 ctx: egui_ctx,
                 state: egui_state,
@@ -87,6 +105,8 @@ impl App {
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.ctx.resize(new_size);
         self.camera.update_aspect_ratio(self.ctx.aspect_ratio());
+        self.renderer.resize_hdr(&self.ctx.device, &self.ctx.queue, new_size.width, new_size.height);
+        self.renderer.resize(&self.ctx, new_size.width, new_size.height);
     }
 
     fn update(&mut self, dt: f32) {
@@ -95,6 +115,19 @@ impl App {
             for _ in 0..substeps {
                 self.simulation.step();
             }
+
+            let density: Vec<f32> = self
+                .simulation
+                .get_render_data()
+                .iter()
+                .map(|(_, prob, _, _)| *prob)
+                .collect();
+            self.logger.log_step(
+                &density,
+                &self.simulation.potential_profile(),
+                self.simulation.transmission,
+                self.simulation.reflection,
+            );
         }
     }
 
@@ -105,6 +138,7 @@ impl App {
             .create_view(&wgpu::TextureViewDescriptor::default());
 
         self.renderer.update_camera_2d(&self.ctx.queue, &self.camera);
+        self.logger.log_camera(&self.camera);
 
         // Convert wavefunction data to points
         let render_data = self.simulation.get_render_data();
@@ -139,7 +173,16 @@ impl App {
         let mut all_points = points;
         all_points.append(&mut barrier_points);
 
-        self.renderer.update_points(&self.ctx.queue, &all_points);
+        if let Some(probe) = self.probe {
+            all_points.push(PointInstance {
+                position: [probe.x, probe.density.sqrt() * 3.0, 0.0],
+                size: 0.2,
+                color: [1.0, 1.0, 1.0, 1.0],
+            });
+        }
+
+        self.renderer.update_points(&self.ctx.device, &self.ctx.queue, &all_points);
+        self.renderer.set_exposure(&self.ctx.queue, self.exposure);
 
         // Build egui UI
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
@@ -163,9 +206,26 @@ impl App {
                     ui.separator();
                     ui.label(format!("T = {:.3}", self.simulation.transmission));
                     ui.label(format!("R = {:.3}", self.simulation.reflection));
+                    ui.separator();
+                    ui.label(format!("Exposure: {:.1}", self.exposure));
+                    ui.separator();
+                    ui.label(format!(
+                        "Propagator: {}",
+                        match self.simulation.propagator {
+                            Propagator::SplitStepFourier => "Split-Step Fourier",
+                            Propagator::FiniteDifference => "Finite Difference",
+                        }
+                    ));
                     if self.paused {
                         ui.label(egui::RichText::new("PAUSED").color(egui::Color32::YELLOW));
                     }
+                    if let Some(probe) = self.probe {
+                        ui.separator();
+                        ui.label(format!(
+                            "Probe @ x={:.2}: |ψ|²={:.3}, V={:.2}, T_local={:.3}",
+                            probe.x, probe.density, probe.potential, probe.transmission_contribution
+                        ));
+                    }
                 });
             });
         });
@@ -189,7 +249,8 @@ impl App {
             });
 
         self.renderer
-            .render_points(&mut encoder, &view, all_points.len() as u32, true);
+            .render_points_hdr(&mut encoder, all_points.len() as u32, true);
+        self.renderer.composite_bloom(&mut encoder, &view);
 
         // Render egui
         self.egui.renderer.update_buffers(
@@ -242,6 +303,9 @@ impl App {
             KeyCode::ArrowDown => self.camera.position.y -= self.camera.zoom * 0.1,
             KeyCode::ArrowLeft => self.camera.position.x -= self.camera.zoom * 0.1,
             KeyCode::ArrowRight => self.camera.position.x += self.camera.zoom * 0.1,
+            KeyCode::BracketLeft => self.exposure = (self.exposure - 0.1).max(0.1),
+            KeyCode::BracketRight => self.exposure += 0.1,
+            KeyCode::KeyP => self.simulation.toggle_propagator(),
             _ => {}
         }
     }
@@ -256,6 +320,19 @@ impl App {
         };
     }
 
+    fn handle_mouse_move(&mut self, x: f32, y: f32) {
+        self.cursor_pos = (x, y);
+    }
+
+    /// Drop a measurement probe at the world-space x under the cursor,
+    /// unprojecting screen space through the camera's inverse view-projection
+    fn handle_mouse_click(&mut self) {
+        let ndc_x = 2.0 * self.cursor_pos.0 / self.ctx.size.width as f32 - 1.0;
+        let ndc_y = 1.0 - 2.0 * self.cursor_pos.1 / self.ctx.size.height as f32;
+        let world = self.camera.screen_to_world(Vec2::new(ndc_x, ndc_y));
+        self.probe = Some(self.simulation.probe(world.x));
+    }
+
     fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
         self.egui.state.on_window_event(&self.ctx.window, event).consumed
     }
@@ -292,6 +369,14 @@ fn main() {
                                     },
                                 ..
                             } => app.handle_key(*key, *state),
+                            WindowEvent::CursorMoved { position, .. } => {
+                                app.handle_mouse_move(position.x as f32, position.y as f32);
+                            }
+                            WindowEvent::MouseInput { state, button, .. } => {
+                                if *button == MouseButton::Left && *state == ElementState::Pressed {
+                                    app.handle_mouse_click();
+                                }
+                            }
                             WindowEvent::RedrawRequested => {
                                 let now = std::time::Instant::now();
                                 let dt = (now - last_time).as_secs_f32().min(0.1);
@@ -0,0 +1,148 @@
+//! Marching-squares iso-contour extraction, the 2D analogue of
+//! [`crate::marching_cubes`] for scalar fields sampled over a plane instead
+//! of a volume.
+//!
+//! Samples `field` on a regular `grid_res`×`grid_res` grid spanning
+//! `bounds`; for every cell of 4 adjacent corners, builds a 4-bit case index
+//! where bit `i` is set if corner `i`'s value is at or above the threshold
+//! (corners exactly on the threshold count as inside, matching
+//! `marching_cubes`'s tie-breaking so adjacent cells always agree), looks up
+//! which of the cell's 4 edges that case crosses in a 16-entry edge table,
+//! and linearly interpolates a point along each crossed edge before emitting
+//! line segments from the matching entry in the segment table.
+
+use glam::Vec2;
+
+/// Axis-aligned region of the plane to sample `field` over
+#[derive(Debug, Clone, Copy)]
+pub struct GridBounds2D {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+// Corners are numbered counter-clockwise from the bottom-left; bit `i` of
+// the case index is set when corner `i` is at or above the threshold.
+//
+//   3 --- 2
+//   |     |
+//   0 --- 1
+//
+// Edges: 0 = bottom (0-1), 1 = right (1-2), 2 = top (2-3), 3 = left (3-0).
+const EDGE_CORNERS: [(usize, usize); 4] = [(0, 1), (1, 2), (2, 3), (3, 0)];
+
+// Which edges each of the 16 cases crosses, as a bitmask over the 4 edges
+// above. Cases 0 and 15 (all corners on the same side) cross nothing;
+// ambiguous cases 5 and 10 (diagonal corners) are resolved by drawing both
+// segments of the saddle, a reasonable default since the field sampled here
+// doesn't need the asymptotic-decider treatment of noisier data.
+const EDGE_TABLE: [u8; 16] = [
+    0b0000, 0b1001, 0b0011, 0b1010, 0b0110, 0b1111, 0b0101, 0b1100, 0b1100, 0b0101, 0b1111,
+    0b0110, 0b1010, 0b0011, 0b1001, 0b0000,
+];
+
+// For each case, pairs of edges to connect into segments (-1 terminates).
+// Ambiguous cases 5 and 10 emit two segments, one per diagonal pairing.
+const SEGMENT_TABLE: [[i32; 4]; 16] = [
+    [-1, -1, -1, -1],
+    [0, 3, -1, -1],
+    [0, 1, -1, -1],
+    [1, 3, -1, -1],
+    [1, 2, -1, -1],
+    [0, 1, 2, 3],
+    [0, 2, -1, -1],
+    [2, 3, -1, -1],
+    [2, 3, -1, -1],
+    [0, 2, -1, -1],
+    [0, 3, 1, 2],
+    [1, 2, -1, -1],
+    [1, 3, -1, -1],
+    [0, 1, -1, -1],
+    [0, 3, -1, -1],
+    [-1, -1, -1, -1],
+];
+
+/// Extract iso-contour line segments of the `threshold` level set of
+/// `field`, sampled on a uniform `grid_res`×`grid_res` grid spanning
+/// `bounds`.
+pub fn extract_contours(
+    field: impl Fn(Vec2) -> f32,
+    bounds: GridBounds2D,
+    grid_res: usize,
+    threshold: f32,
+) -> Vec<(Vec2, Vec2)> {
+    let grid_res = grid_res.max(1);
+    let step = (bounds.max - bounds.min) / grid_res as f32;
+
+    // Values at every grid corner, sampled once and reused by neighbouring cells
+    let samples = grid_res + 1;
+    let mut values = vec![0.0f32; samples * samples];
+    let idx = |i: usize, j: usize| i * samples + j;
+    let corner = |i: usize, j: usize| bounds.min + Vec2::new(i as f32 * step.x, j as f32 * step.y);
+    for i in 0..samples {
+        for j in 0..samples {
+            values[idx(i, j)] = field(corner(i, j));
+        }
+    }
+
+    let corner_offset = [(0, 0), (1, 0), (1, 1), (0, 1)];
+
+    let mut segments = Vec::new();
+
+    for i in 0..grid_res {
+        for j in 0..grid_res {
+            let corner_pos: [Vec2; 4] = std::array::from_fn(|c| {
+                let (oi, oj) = corner_offset[c];
+                corner(i + oi, j + oj)
+            });
+            let corner_value: [f32; 4] = std::array::from_fn(|c| {
+                let (oi, oj) = corner_offset[c];
+                values[idx(i + oi, j + oj)]
+            });
+
+            let mut case_index = 0usize;
+            for (c, &v) in corner_value.iter().enumerate() {
+                if v >= threshold {
+                    case_index |= 1 << c;
+                }
+            }
+
+            let edges = EDGE_TABLE[case_index];
+            if edges == 0 {
+                continue;
+            }
+
+            let mut edge_point = [Vec2::ZERO; 4];
+            for (e, &(a, b)) in EDGE_CORNERS.iter().enumerate() {
+                if edges & (1 << e) != 0 {
+                    edge_point[e] = interpolate_edge(
+                        threshold,
+                        corner_pos[a],
+                        corner_pos[b],
+                        corner_value[a],
+                        corner_value[b],
+                    );
+                }
+            }
+
+            let pairs = &SEGMENT_TABLE[case_index];
+            let mut p = 0;
+            while p + 1 < pairs.len() && pairs[p] != -1 {
+                segments.push((edge_point[pairs[p] as usize], edge_point[pairs[p + 1] as usize]));
+                p += 2;
+            }
+        }
+    }
+
+    segments
+}
+
+/// Linearly interpolate the threshold-crossing point along a cell edge; an
+/// edge with (near-)equal endpoint values falls back to its first endpoint
+/// rather than dividing by a near-zero difference
+fn interpolate_edge(threshold: f32, p0: Vec2, p1: Vec2, f0: f32, f1: f32) -> Vec2 {
+    if (f1 - f0).abs() < 1e-6 {
+        return p0;
+    }
+    let t = ((threshold - f0) / (f1 - f0)).clamp(0.0, 1.0);
+    p0 + (p1 - p0) * t
+}
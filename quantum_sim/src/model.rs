@@ -0,0 +1,107 @@
+//! Declarative physics model loading
+//!
+//! Following the style of FeynRules `.fr` model files, a `PhysicsModel` bundles
+//! every scaled physical quantity used across the quantum simulations (quarks,
+//! Hall effect, orbitals) into one struct that can be parsed from a TOML/RON
+//! model file at runtime instead of being baked into `constants` or the
+//! `equations_ui` display strings. A `PhysicsModel::default()` reproduces
+//! today's hardcoded values exactly.
+
+use serde::{Deserialize, Serialize};
+
+/// Quark mass/color data for one flavor, as declared in a model file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarkSpec {
+    pub name: String,
+    pub charge_thirds: i32,
+    pub mass_mev: f32,
+}
+
+/// A named, loadable set of physics parameters for the quantum simulations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhysicsModel {
+    /// Model name, e.g. "Standard Model (default)"
+    pub name: String,
+
+    /// Reduced Planck constant (scaled for visualization)
+    pub hbar: f32,
+    /// Electron mass (scaled)
+    pub m_e: f32,
+    /// Elementary charge (scaled)
+    pub e: f32,
+    /// Speed of light (scaled)
+    pub c: f32,
+    /// Fine structure constant
+    pub alpha: f32,
+    /// Bohr radius (scaled)
+    pub a0: f32,
+
+    /// Strong coupling constant at the reference scale
+    pub alpha_s: f32,
+    /// String tension σ (confinement strength)
+    pub string_tension: f32,
+    /// QCD scale Λ_QCD
+    pub lambda_qcd: f32,
+    /// Quark flavors, in the order they should be offered in the UI
+    pub quarks: Vec<QuarkSpec>,
+
+    /// Effective Landau-level mass m* for the quantum Hall simulation
+    pub landau_effective_mass: f32,
+}
+
+impl Default for PhysicsModel {
+    fn default() -> Self {
+        Self {
+            name: "Standard Model (default)".to_string(),
+            hbar: 1.0,
+            m_e: 1.0,
+            e: 1.0,
+            c: 10.0,
+            alpha: 1.0 / 137.0,
+            a0: 1.0,
+            alpha_s: 0.5,
+            string_tension: 1.0,
+            lambda_qcd: 0.2,
+            quarks: vec![
+                QuarkSpec { name: "Up".to_string(), charge_thirds: 2, mass_mev: 2.2 },
+                QuarkSpec { name: "Down".to_string(), charge_thirds: -1, mass_mev: 4.7 },
+                QuarkSpec { name: "Charm".to_string(), charge_thirds: 2, mass_mev: 1275.0 },
+                QuarkSpec { name: "Strange".to_string(), charge_thirds: -1, mass_mev: 95.0 },
+                QuarkSpec { name: "Top".to_string(), charge_thirds: 2, mass_mev: 173000.0 },
+                QuarkSpec { name: "Bottom".to_string(), charge_thirds: -1, mass_mev: 4180.0 },
+            ],
+            landau_effective_mass: 1.0,
+        }
+    }
+}
+
+/// Errors that can occur while loading a model file
+#[derive(Debug, thiserror::Error)]
+pub enum ModelLoadError {
+    #[error("failed to read model file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to parse TOML model file: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("failed to parse RON model file: {0}")]
+    Ron(#[from] ron::de::SpannedError),
+    #[error("unrecognized model file extension: {0:?} (expected .toml or .ron)")]
+    UnknownExtension(Option<String>),
+}
+
+impl PhysicsModel {
+    /// Load a model from a TOML or RON file, chosen by extension
+    pub fn load_from_file(path: impl AsRef<std::path::Path>) -> Result<Self, ModelLoadError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Ok(toml::from_str(&text)?),
+            Some("ron") => Ok(ron::de::from_str(&text)?),
+            ext => Err(ModelLoadError::UnknownExtension(ext.map(str::to_string))),
+        }
+    }
+
+    /// Look up a quark spec by name (case-insensitive)
+    pub fn quark(&self, name: &str) -> Option<&QuarkSpec> {
+        self.quarks.iter().find(|q| q.name.eq_ignore_ascii_case(name))
+    }
+}
@@ -8,9 +8,42 @@ use crate::wavefunction::{Complex, hydrogen_radial, spherical_harmonic};
 /// Bohr radius (scaled)
 const A0: f32 = 1.0;
 use glam::Vec3;
-use rand::Rng;
 use std::f32::consts::PI;
 
+/// Minimal PCG32 generator, used instead of `rand::thread_rng()` so an
+/// [`OrbitalSimulation`] built `with_seed` samples the exact same cloud on
+/// every run, reproducible across machines and snapshot-testable
+#[derive(Debug, Clone)]
+struct Pcg32 {
+    state: u64,
+    inc: u64,
+}
+
+impl Pcg32 {
+    fn new(seed: u64) -> Self {
+        let mut rng = Self { state: 0, inc: (seed << 1) | 1 };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(self.inc | 1);
+        let xorshifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rot = (old_state >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+
+    /// Uniform float in `[0, 1)`
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u32() as f32) / (u32::MAX as f32 + 1.0)
+    }
+}
+
 /// Quantum numbers for an orbital
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct QuantumNumbers {
@@ -81,6 +114,28 @@ pub struct OrbitalSimulation {
     pub animate_phase: bool,
     /// Cross-section mode
     pub cross_section: Option<CrossSection>,
+    /// Seed driving the deterministic sampler
+    pub seed: u64,
+    /// Embedded PCG32 state, advanced by every sample draw
+    rng: Pcg32,
+    /// When set, render the real (cubic-harmonic) combination of ±m instead
+    /// of the raw complex `Y_l^m`, giving familiar px/py-style lobes
+    pub real_harmonic: Option<RealHarmonic>,
+    /// Time-dependent superposition terms `(quantum numbers, coefficient)`,
+    /// normalized so `Σ|c_k|² = 1`. Empty means "pure `quantum_numbers`
+    /// eigenstate", handled as the single-term case of the same formula.
+    pub superposition_terms: Vec<(QuantumNumbers, Complex)>,
+}
+
+/// Which real linear combination of `Y_l^{-m}` and `Y_l^{m}` to form; see
+/// [`OrbitalSimulation::wavefunction_at`]. Unused when `m == 0`, since
+/// `Y_l^0` is already real.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RealHarmonic {
+    /// `(Y_l^{-m} + (-1)^m Y_l^m) / √2` — e.g. `p_x`, `d_{x²-y²}`
+    Cos,
+    /// `i(Y_l^{-m} - (-1)^m Y_l^m) / √2` — e.g. `p_y`, `d_{xy}`
+    Sin,
 }
 
 /// Cross-section plane for viewing
@@ -93,20 +148,102 @@ pub enum CrossSection {
 
 impl OrbitalSimulation {
     pub fn new(quantum_numbers: QuantumNumbers, num_points: usize) -> Self {
+        Self::with_scale(quantum_numbers, num_points, A0 * 3.0, 0)
+    }
+
+    /// Construct from a loaded [`crate::model::PhysicsModel`], scaling the
+    /// cloud by the model's Bohr radius instead of the hardcoded `A0`
+    pub fn from_model(
+        quantum_numbers: QuantumNumbers,
+        num_points: usize,
+        model: &crate::model::PhysicsModel,
+    ) -> Self {
+        Self::with_scale(quantum_numbers, num_points, model.a0 * 3.0, 0)
+    }
+
+    /// Construct with an explicit sampler seed, so the resulting cloud is
+    /// reproducible across runs and machines
+    pub fn with_seed(quantum_numbers: QuantumNumbers, num_points: usize, seed: u64) -> Self {
+        Self::with_scale(quantum_numbers, num_points, A0 * 3.0, seed)
+    }
+
+    /// Reseed the sampler and regenerate the cloud from scratch
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = Pcg32::new(seed);
+        self.regenerate_points();
+    }
+
+    fn with_scale(quantum_numbers: QuantumNumbers, num_points: usize, scale: f32, seed: u64) -> Self {
         let mut sim = Self {
             quantum_numbers,
             points: Vec::new(),
             num_points,
-            scale: A0 * 3.0,
+            scale,
             time: 0.0,
             animate_phase: true,
             cross_section: None,
+            seed,
+            rng: Pcg32::new(seed),
+            real_harmonic: None,
+            superposition_terms: Vec::new(),
+        };
+        sim.regenerate_points();
+        sim
+    }
+
+    /// Construct with a real (cubic-harmonic) combination selected, for the
+    /// familiar px/py, dxy/dx²−y² lobe shapes instead of a toroidal |ψ|²
+    pub fn with_real_harmonic(quantum_numbers: QuantumNumbers, num_points: usize, kind: RealHarmonic) -> Self {
+        let mut sim = Self::new(quantum_numbers, num_points);
+        sim.real_harmonic = Some(kind);
+        sim.regenerate_points();
+        sim
+    }
+
+    /// Build a time-dependent superposition `Σ c_k ψ_{n_k l_k m_k}`,
+    /// normalizing the coefficients so `Σ|c_k|² = 1`. Because different `n`
+    /// carry different hydrogen energies `E_n = -0.5/n²`, `|ψ|²` genuinely
+    /// oscillates in time — e.g. a 1s+2p₀ superposition shows the electron
+    /// density sloshing along z — unlike a pure eigenstate, whose density is
+    /// stationary.
+    pub fn superposition(terms: Vec<(QuantumNumbers, Complex)>, num_points: usize) -> Self {
+        let norm = terms.iter().map(|(_, c)| c.norm_sq()).sum::<f32>().sqrt();
+        let terms: Vec<(QuantumNumbers, Complex)> = if norm > 1e-6 {
+            terms.into_iter().map(|(qn, c)| (qn, c * (1.0 / norm))).collect()
+        } else {
+            terms
         };
+
+        let base_qn = terms.first().map(|(qn, _)| *qn).unwrap_or_else(QuantumNumbers::s1);
+        let mut sim = Self::new(base_qn, num_points);
+        sim.superposition_terms = terms;
         sim.regenerate_points();
         sim
     }
 
-    /// Compute wavefunction at a point
+    /// Select (or clear) the real-harmonic combination and regenerate
+    pub fn set_real_harmonic(&mut self, real_harmonic: Option<RealHarmonic>) {
+        if self.real_harmonic != real_harmonic {
+            self.real_harmonic = real_harmonic;
+            self.regenerate_points();
+        }
+    }
+
+    /// Largest principal quantum number in play, for sizing the sampling
+    /// grid: a superposition's cloud can extend as far as its widest term
+    fn effective_n(&self) -> u32 {
+        self.superposition_terms
+            .iter()
+            .map(|(qn, _)| qn.n)
+            .max()
+            .unwrap_or(self.quantum_numbers.n)
+    }
+
+    /// Compute the (possibly time-dependent superposition) wavefunction at
+    /// a point: `ψ(r,t) = Σ_k c_k · R_{n_k l_k}(r) · Y_{l_k m_k}(θ,φ) ·
+    /// exp(-i E_{n_k} t)`. A plain `quantum_numbers` eigenstate is just the
+    /// one-term case of this same formula.
     pub fn wavefunction_at(&self, pos: Vec3) -> Complex {
         let r = pos.length();
         if r < 1e-6 {
@@ -116,21 +253,31 @@ impl OrbitalSimulation {
         let theta = (pos.z / r).acos();
         let phi = pos.y.atan2(pos.x);
 
-        let radial = hydrogen_radial(
-            self.quantum_numbers.n,
-            self.quantum_numbers.l,
-            r / self.scale,
-            1.0,
-        );
+        if self.superposition_terms.is_empty() {
+            let radial = hydrogen_radial(
+                self.quantum_numbers.n,
+                self.quantum_numbers.l,
+                r / self.scale,
+                1.0,
+            );
+
+            let angular = match self.real_harmonic {
+                None => spherical_harmonic(self.quantum_numbers.l as i32, self.quantum_numbers.m, theta, phi),
+                Some(kind) => real_spherical_harmonic(self.quantum_numbers.l as i32, self.quantum_numbers.m, theta, phi, kind),
+            };
 
-        let angular = spherical_harmonic(
-            self.quantum_numbers.l as i32,
-            self.quantum_numbers.m,
-            theta,
-            phi,
-        );
+            let time_phase = Complex::exp_i(-hydrogen_energy(self.quantum_numbers.n) * self.time);
+            return (angular * radial) * time_phase;
+        }
 
-        angular * radial
+        self.superposition_terms
+            .iter()
+            .fold(Complex::ZERO, |acc, (qn, coeff)| {
+                let radial = hydrogen_radial(qn.n, qn.l, r / self.scale, 1.0);
+                let angular = spherical_harmonic(qn.l as i32, qn.m, theta, phi);
+                let time_phase = Complex::exp_i(-hydrogen_energy(qn.n) * self.time);
+                acc + ((*coeff * radial) * angular) * time_phase
+            })
     }
 
     /// Probability density |ψ|² at a point
@@ -141,17 +288,16 @@ impl OrbitalSimulation {
     /// Generate sample points using rejection sampling
     pub fn regenerate_points(&mut self) {
         self.points.clear();
-        let mut rng = rand::thread_rng();
 
         // Estimate maximum probability for rejection sampling
-        let max_r = self.scale * self.quantum_numbers.n as f32 * 5.0;
+        let max_r = self.scale * self.effective_n() as f32 * 5.0;
         let mut max_prob = 0.0f32;
 
         // Sample to find approximate max
         for _ in 0..1000 {
-            let r = rng.gen::<f32>() * max_r;
-            let theta = rng.gen::<f32>() * PI;
-            let phi = rng.gen::<f32>() * 2.0 * PI;
+            let r = self.rng.next_f32() * max_r;
+            let theta = self.rng.next_f32() * PI;
+            let phi = self.rng.next_f32() * 2.0 * PI;
             let pos = Vec3::new(
                 r * theta.sin() * phi.cos(),
                 r * theta.sin() * phi.sin(),
@@ -171,10 +317,10 @@ impl OrbitalSimulation {
             attempts += 1;
 
             // Sample in spherical coordinates with r² weighting
-            let u = rng.gen::<f32>();
+            let u = self.rng.next_f32();
             let r = max_r * u.cbrt(); // r² dr weighting
-            let theta = (1.0 - 2.0 * rng.gen::<f32>()).acos();
-            let phi = rng.gen::<f32>() * 2.0 * PI;
+            let theta = (1.0 - 2.0 * self.rng.next_f32()).acos();
+            let phi = self.rng.next_f32() * 2.0 * PI;
 
             let pos = Vec3::new(
                 r * theta.sin() * phi.cos(),
@@ -199,10 +345,17 @@ impl OrbitalSimulation {
             let phase = psi.arg();
 
             // Rejection sampling
-            if rng.gen::<f32>() * max_prob < prob {
-                // Color based on phase
-                let hue = (phase + PI) / (2.0 * PI);
-                let (r, g, b) = hsv_to_rgb(hue, 0.8, 1.0);
+            if self.rng.next_f32() * max_prob < prob {
+                // A real-harmonic wavefunction has no phase to speak of;
+                // color its +/- lobes instead, matching the textbook
+                // red/blue px-py-dxy convention
+                let (r, g, b) = if self.real_harmonic.is_some() {
+                    let hue = if psi.re >= 0.0 { 0.0 } else { 0.6 };
+                    hsv_to_rgb(hue, 0.8, 1.0)
+                } else {
+                    let hue = (phase + PI) / (2.0 * PI);
+                    hsv_to_rgb(hue, 0.8, 1.0)
+                };
 
                 self.points.push(CloudPoint {
                     position: pos,
@@ -214,27 +367,39 @@ impl OrbitalSimulation {
         }
     }
 
-    /// Update animation
+    /// Advance time and recompute each point's genuine time-dependent
+    /// probability and phase from `wavefunction_at`, rather than faking
+    /// animation with an arbitrary hue rotation. A pure eigenstate's density
+    /// is stationary (only the unobservable global phase advances), but a
+    /// [`superposition`](Self::superposition) of different-energy terms
+    /// really does oscillate.
     pub fn step(&mut self, dt: f32) {
-        if self.animate_phase {
-            self.time += dt;
-
-            // Rotate phase colors
-            let omega = 2.0; // Angular frequency
-            for point in &mut self.points {
-                let animated_phase = point.phase + omega * self.time;
-                let hue = (animated_phase + PI) / (2.0 * PI);
-                let hue = hue.rem_euclid(1.0);
-                let (r, g, b) = hsv_to_rgb(hue, 0.8, 1.0);
-                point.color = [r, g, b, 0.6];
-            }
+        if !self.animate_phase {
+            return;
+        }
+        self.time += dt;
+
+        for point in &mut self.points {
+            let psi = self.wavefunction_at(point.position);
+            point.probability = psi.norm_sq();
+            point.phase = psi.arg();
+
+            let (r, g, b) = if self.real_harmonic.is_some() {
+                let hue = if psi.re >= 0.0 { 0.0 } else { 0.6 };
+                hsv_to_rgb(hue, 0.8, 1.0)
+            } else {
+                let hue = ((point.phase + PI) / (2.0 * PI)).rem_euclid(1.0);
+                hsv_to_rgb(hue, 0.8, 1.0)
+            };
+            point.color = [r, g, b, 0.6];
         }
     }
 
-    /// Set new quantum numbers and regenerate
+    /// Set new quantum numbers, clearing any superposition, and regenerate
     pub fn set_orbital(&mut self, qn: QuantumNumbers) {
-        if qn != self.quantum_numbers {
+        if qn != self.quantum_numbers || !self.superposition_terms.is_empty() {
             self.quantum_numbers = qn;
+            self.superposition_terms.clear();
             self.regenerate_points();
         }
     }
@@ -249,7 +414,7 @@ impl OrbitalSimulation {
 
     /// Get radial probability distribution P(r) = r²|R(r)|²
     pub fn radial_distribution(&self, num_points: usize) -> Vec<(f32, f32)> {
-        let max_r = self.scale * self.quantum_numbers.n as f32 * 5.0;
+        let max_r = self.scale * self.effective_n() as f32 * 5.0;
         let dr = max_r / num_points as f32;
 
         (0..num_points)
@@ -274,6 +439,159 @@ impl OrbitalSimulation {
             .map(|p| (p.position, p.probability, p.color))
             .collect()
     }
+
+    /// Sample the max probability density on a `grid_res`³ grid spanning
+    /// `[-max_r, max_r]³`, for picking a sensible default isovalue
+    pub fn max_sampled_density(&self, grid_res: usize) -> f32 {
+        let max_r = self.scale * self.effective_n() as f32 * 5.0;
+        let step = (2.0 * max_r) / grid_res as f32;
+        let mut max_density = 0.0f32;
+
+        for i in 0..=grid_res {
+            let x = -max_r + i as f32 * step;
+            for j in 0..=grid_res {
+                let y = -max_r + j as f32 * step;
+                for k in 0..=grid_res {
+                    let z = -max_r + k as f32 * step;
+                    let density = self.probability_at(Vec3::new(x, y, z));
+                    max_density = max_density.max(density);
+                }
+            }
+        }
+
+        max_density
+    }
+
+    /// Extract a closed triangle mesh of the `iso` probability-density
+    /// surface via marching cubes, sampling `probability_at` on a uniform
+    /// `grid_res`³ grid spanning `[-max_r, max_r]³` (the same `max_r`
+    /// heuristic `regenerate_points` uses). Gives orbitals a deterministic
+    /// surface representation alongside the stochastic point cloud, suitable
+    /// for 3D printing, import into other tools, or a solid render mode.
+    pub fn extract_isosurface(&self, iso: f32, grid_res: usize) -> Vec<[Vec3; 3]> {
+        let max_r = self.scale * self.effective_n() as f32 * 5.0;
+        let bounds = crate::marching_cubes::GridBounds {
+            min: Vec3::splat(-max_r),
+            max: Vec3::splat(max_r),
+        };
+        crate::marching_cubes::extract_isosurface(
+            |p| self.probability_at(p),
+            bounds,
+            grid_res,
+            iso,
+        )
+    }
+
+    /// Per-vertex outward normal for a point on the isosurface, via a
+    /// central-difference gradient of the density field (the field has no
+    /// analytic gradient worth inlining, so finite differences are used
+    /// uniformly instead of special-casing individual orbitals)
+    pub fn isosurface_normal(&self, p: Vec3) -> Vec3 {
+        let h = self.scale * 0.01;
+        let dx = self.probability_at(p + Vec3::X * h) - self.probability_at(p - Vec3::X * h);
+        let dy = self.probability_at(p + Vec3::Y * h) - self.probability_at(p - Vec3::Y * h);
+        let dz = self.probability_at(p + Vec3::Z * h) - self.probability_at(p - Vec3::Z * h);
+        // Density increases inward, so the gradient points in; negate it to
+        // get the outward-facing normal the renderer wants for lighting
+        (-Vec3::new(dx, dy, dz)).normalize_or_zero()
+    }
+
+    /// March a ray through the continuous density field and front-to-back
+    /// composite it into a single RGBA sample, so the orbital reads as a
+    /// smooth glowing gas instead of stippled points. Serves as a CPU
+    /// reference for the GPU volumetric path.
+    pub fn raymarch(&self, ray_origin: Vec3, ray_dir: Vec3, steps: usize) -> [f32; 4] {
+        let ray_dir = ray_dir.normalize_or_zero();
+        if steps == 0 || ray_dir == Vec3::ZERO {
+            return [0.0, 0.0, 0.0, 0.0];
+        }
+
+        let radius = self.scale * self.effective_n() as f32 * 5.0;
+        let Some((t_min, t_max)) = ray_sphere_intersection(ray_origin, ray_dir, radius) else {
+            return [0.0, 0.0, 0.0, 0.0];
+        };
+
+        let ds = (t_max - t_min) / steps as f32;
+        let mut transmittance = 1.0f32;
+        let mut color = Vec3::ZERO;
+
+        for i in 0..steps {
+            let t = t_min + (i as f32 + 0.5) * ds;
+            let psi = self.wavefunction_at(ray_origin + ray_dir * t);
+            let prob = psi.norm_sq();
+
+            let hue = ((psi.arg() + PI) / (2.0 * PI)).rem_euclid(1.0);
+            let (r, g, b) = hsv_to_rgb(hue, 0.8, 1.0);
+            let emission = Vec3::new(r, g, b);
+
+            color += emission * (transmittance * prob * ds);
+            transmittance *= (-RAYMARCH_EXTINCTION * prob * ds).exp();
+
+            if transmittance < 1e-4 {
+                break;
+            }
+        }
+
+        [color.x, color.y, color.z, 1.0 - transmittance]
+    }
+
+    /// Simple pinhole camera: cast one ray per pixel toward `look_at` and
+    /// raymarch the density field, producing a full `width`×`height` RGBA
+    /// image buffer (row-major, origin top-left)
+    pub fn render_raymarched(
+        &self,
+        width: usize,
+        height: usize,
+        camera_pos: Vec3,
+        look_at: Vec3,
+        fov_y: f32,
+        steps: usize,
+    ) -> Vec<[f32; 4]> {
+        let forward = (look_at - camera_pos).normalize_or_zero();
+        let right = forward.cross(Vec3::Y).normalize_or_zero();
+        let up = right.cross(forward);
+
+        let aspect = width as f32 / height.max(1) as f32;
+        let tan_half_fov = (fov_y * 0.5).tan();
+
+        (0..height)
+            .flat_map(|y| {
+                let ndc_y = 1.0 - 2.0 * (y as f32 + 0.5) / height as f32;
+                (0..width).map(move |x| (x, y, ndc_y))
+            })
+            .map(|(x, _y, ndc_y)| {
+                let ndc_x = 2.0 * (x as f32 + 0.5) / width as f32 - 1.0;
+                let dir = forward
+                    + right * (ndc_x * tan_half_fov * aspect)
+                    + up * (ndc_y * tan_half_fov);
+                self.raymarch(camera_pos, dir, steps)
+            })
+            .collect()
+    }
+}
+
+/// Tunable extinction coefficient for [`OrbitalSimulation::raymarch`]'s
+/// Beer-Lambert transmittance; higher values make dense regions more opaque
+const RAYMARCH_EXTINCTION: f32 = 40.0;
+
+/// Ray/origin-centered-sphere intersection, returning the near/far
+/// parametric distances `t` clipped to `t >= 0`, or `None` if the ray misses
+fn ray_sphere_intersection(origin: Vec3, dir: Vec3, radius: f32) -> Option<(f32, f32)> {
+    let b = origin.dot(dir);
+    let c = origin.dot(origin) - radius * radius;
+    let discriminant = b * b - c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let sqrt_d = discriminant.sqrt();
+    let t0 = -b - sqrt_d;
+    let t1 = -b + sqrt_d;
+    if t1 < 0.0 {
+        return None;
+    }
+
+    Some((t0.max(0.0), t1))
 }
 
 /// Common orbital presets
@@ -293,6 +611,67 @@ impl OrbitalSimulation {
     pub fn preset_3d() -> Self {
         Self::new(QuantumNumbers::d3_0(), 8000)
     }
+
+    /// Real p orbitals: dumbbell lobes along x/y, and the already-real p_z
+    pub fn p_x() -> Self {
+        Self::with_real_harmonic(QuantumNumbers::p2_1(), 5000, RealHarmonic::Cos)
+    }
+
+    pub fn p_y() -> Self {
+        Self::with_real_harmonic(QuantumNumbers::p2_1(), 5000, RealHarmonic::Sin)
+    }
+
+    pub fn p_z() -> Self {
+        Self::new(QuantumNumbers::p2_0(), 5000)
+    }
+
+    /// Real d orbitals: four-lobed clover shapes, and the already-real d_z2
+    pub fn d_xy() -> Self {
+        Self::with_real_harmonic(QuantumNumbers::d3_2(), 8000, RealHarmonic::Sin)
+    }
+
+    pub fn d_x2y2() -> Self {
+        Self::with_real_harmonic(QuantumNumbers::d3_2(), 8000, RealHarmonic::Cos)
+    }
+
+    pub fn d_xz() -> Self {
+        Self::with_real_harmonic(QuantumNumbers::d3_1(), 8000, RealHarmonic::Cos)
+    }
+
+    pub fn d_yz() -> Self {
+        Self::with_real_harmonic(QuantumNumbers::d3_1(), 8000, RealHarmonic::Sin)
+    }
+
+    pub fn d_z2() -> Self {
+        Self::new(QuantumNumbers::d3_0(), 8000)
+    }
+}
+
+/// Hydrogen energy level `E_n = -0.5/n²` (Rydberg units); scales alongside
+/// `scale`/`A0` for the visualization's unit system
+fn hydrogen_energy(n: u32) -> f32 {
+    -0.5 / (n * n) as f32
+}
+
+/// Real linear combination of `Y_l^{-m}` and `Y_l^{m}`, giving the familiar
+/// px/py, dxy/dx²−y² lobe shapes chemists expect instead of the toroidal
+/// |ψ|² a single complex `Y_l^m` produces. `m == 0` is passed through
+/// unchanged, since `Y_l^0` is already real.
+fn real_spherical_harmonic(l: i32, m: i32, theta: f32, phi: f32, kind: RealHarmonic) -> Complex {
+    if m == 0 {
+        return spherical_harmonic(l, 0, theta, phi);
+    }
+
+    let m = m.abs();
+    let y_pos = spherical_harmonic(l, m, theta, phi);
+    let y_neg = spherical_harmonic(l, -m, theta, phi);
+    let sign = if m % 2 == 1 { -1.0 } else { 1.0 };
+    let inv_sqrt2 = std::f32::consts::FRAC_1_SQRT_2;
+
+    match kind {
+        RealHarmonic::Cos => (y_neg + y_pos * sign) * inv_sqrt2,
+        RealHarmonic::Sin => ((y_neg - y_pos * sign) * Complex::I) * inv_sqrt2,
+    }
 }
 
 /// Helper: HSV to RGB conversion
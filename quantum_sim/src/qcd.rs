@@ -0,0 +1,48 @@
+//! Live-evaluated QCD formulas
+//!
+//! The `QUARK_EQUATIONS` sidebar shows the running-coupling and confinement
+//! formulas only as display strings. This module turns them into functions
+//! the `quarks` simulation can evaluate every frame, so the separation-
+//! dependent force can drive the confinement animation instead of using the
+//! fixed `ALPHA_S` constant.
+
+/// One-loop running coupling αₛ(Q²) = 12π / ((33 − 2·n_f)·ln(Q²/Λ²))
+///
+/// Guards the Landau pole: for Q² at or below Λ² the logarithm is undefined
+/// or divergent, so a capped value is returned instead.
+pub fn alpha_s(q2: f32, lambda: f32, n_f: u32) -> f32 {
+    const ALPHA_S_CAP: f32 = 2.0;
+
+    let lambda2 = lambda * lambda;
+    if q2 <= lambda2 {
+        return ALPHA_S_CAP;
+    }
+
+    let beta0 = 33.0 - 2.0 * n_f as f32;
+    let value = 12.0 * std::f32::consts::PI / (beta0 * (q2 / lambda2).ln());
+
+    if value.is_finite() && value > 0.0 {
+        value.min(ALPHA_S_CAP)
+    } else {
+        ALPHA_S_CAP
+    }
+}
+
+/// Cornell confinement potential V(r) = −(4/3)·αₛ/r + σ·r
+///
+/// Combines the one-gluon-exchange Coulomb term (asymptotic freedom at short
+/// range) with the linear string term (confinement at long range).
+pub fn cornell_potential(r: f32, alpha_s: f32, sigma: f32) -> f32 {
+    if r <= 1e-6 {
+        return f32::INFINITY;
+    }
+    -(4.0 / 3.0) * alpha_s / r + sigma * r
+}
+
+/// Force derived from the Cornell potential: F(r) = −dV/dr
+pub fn cornell_force(r: f32, alpha_s: f32, sigma: f32) -> f32 {
+    if r <= 1e-6 {
+        return 0.0;
+    }
+    -(4.0 / 3.0) * alpha_s / (r * r) - sigma
+}
@@ -141,13 +141,9 @@ impl TwoQubit {
 
     /// Bell state |Φ+⟩ = (|00⟩ + |11⟩)/√2
     pub fn bell_phi_plus() -> Self {
+        let reg = Register::bell_phi_plus();
         Self {
-            amplitudes: [
-                Complex::new(FRAC_1_SQRT_2, 0.0),
-                Complex::ZERO,
-                Complex::ZERO,
-                Complex::new(FRAC_1_SQRT_2, 0.0),
-            ],
+            amplitudes: reg.amplitudes.try_into().unwrap(),
         }
     }
 
@@ -227,6 +223,18 @@ impl TwoQubit {
         ];
     }
 
+    /// Apply Pauli-X to the first qubit
+    pub fn pauli_x_first(&mut self) {
+        self.amplitudes.swap(0, 2);
+        self.amplitudes.swap(1, 3);
+    }
+
+    /// Apply Pauli-Z to the first qubit
+    pub fn pauli_z_first(&mut self) {
+        self.amplitudes[2] = self.amplitudes[2] * (-1.0);
+        self.amplitudes[3] = self.amplitudes[3] * (-1.0);
+    }
+
     /// Get entanglement measure (simplified concurrence)
     pub fn concurrence(&self) -> f32 {
         // C = 2|α₀₀α₁₁ - α₀₁α₁₀|
@@ -257,6 +265,34 @@ impl TwoQubit {
     }
 }
 
+/// σy⊗σy, needed to spin-flip a two-qubit density matrix when computing
+/// Wootters concurrence. It happens to be real, so it's stored as plain f32s.
+const SPIN_FLIP: [[f32; 4]; 4] = [
+    [0.0, 0.0, 0.0, -1.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [-1.0, 0.0, 0.0, 0.0],
+];
+
+/// Multiply two 4x4 complex matrices
+fn mat4_mul(a: &[[Complex; 4]; 4], b: &[[Complex; 4]; 4]) -> [[Complex; 4]; 4] {
+    let mut out = [[Complex::ZERO; 4]; 4];
+    for i in 0..4 {
+        for j in 0..4 {
+            let mut sum = Complex::ZERO;
+            for k in 0..4 {
+                sum += a[i][k] * b[k][j];
+            }
+            out[i][j] = sum;
+        }
+    }
+    out
+}
+
+fn mat4_trace(a: &[[Complex; 4]; 4]) -> Complex {
+    a[0][0] + a[1][1] + a[2][2] + a[3][3]
+}
+
 /// Three-qubit state (for GHZ states and teleportation)
 #[derive(Debug, Clone)]
 pub struct ThreeQubit {
@@ -274,20 +310,18 @@ impl ThreeQubit {
 
     /// GHZ state (|000⟩ + |111⟩)/√2
     pub fn ghz() -> Self {
-        let mut amps = [Complex::ZERO; 8];
-        amps[0] = Complex::new(FRAC_1_SQRT_2, 0.0);
-        amps[7] = Complex::new(FRAC_1_SQRT_2, 0.0);
-        Self { amplitudes: amps }
+        let reg = Register::ghz(3);
+        Self {
+            amplitudes: reg.amplitudes.try_into().unwrap(),
+        }
     }
 
     /// W state (|001⟩ + |010⟩ + |100⟩)/√3
     pub fn w_state() -> Self {
-        let mut amps = [Complex::ZERO; 8];
-        let coeff = 1.0 / 3.0_f32.sqrt();
-        amps[1] = Complex::new(coeff, 0.0); // |001⟩
-        amps[2] = Complex::new(coeff, 0.0); // |010⟩
-        amps[4] = Complex::new(coeff, 0.0); // |100⟩
-        Self { amplitudes: amps }
+        let reg = Register::w_state(3);
+        Self {
+            amplitudes: reg.amplitudes.try_into().unwrap(),
+        }
     }
 
     /// Apply CNOT on qubits i (control) and j (target)
@@ -305,6 +339,53 @@ impl ThreeQubit {
         }
     }
 
+    /// Apply Pauli-X to qubit i
+    pub fn pauli_x(&mut self, qubit: usize) {
+        let mask = 1 << (2 - qubit);
+        for i in 0..8 {
+            let partner = i ^ mask;
+            if i < partner {
+                self.amplitudes.swap(i, partner);
+            }
+        }
+    }
+
+    /// Apply Pauli-Y to qubit i
+    pub fn pauli_y(&mut self, qubit: usize) {
+        let mask = 1 << (2 - qubit);
+        let mut new_amps = self.amplitudes;
+        for i in 0..8 {
+            let partner = i ^ mask;
+            if i < partner {
+                new_amps[i] = self.amplitudes[partner] * Complex::new(0.0, -1.0); // Y|1⟩ = -i|0⟩
+                new_amps[partner] = self.amplitudes[i] * Complex::new(0.0, 1.0); // Y|0⟩ = i|1⟩
+            }
+        }
+        self.amplitudes = new_amps;
+    }
+
+    /// Apply Pauli-Z to qubit i
+    pub fn pauli_z(&mut self, qubit: usize) {
+        let mask = 1 << (2 - qubit);
+        for i in 0..8 {
+            if i & mask != 0 {
+                self.amplitudes[i] = self.amplitudes[i] * (-1.0);
+            }
+        }
+    }
+
+    /// Apply controlled-Z on qubits i (control) and j (target)
+    pub fn cz(&mut self, control: usize, target: usize) {
+        let control_mask = 1 << (2 - control);
+        let target_mask = 1 << (2 - target);
+
+        for i in 0..8 {
+            if (i & control_mask) != 0 && (i & target_mask) != 0 {
+                self.amplitudes[i] = self.amplitudes[i] * (-1.0);
+            }
+        }
+    }
+
     /// Apply Hadamard to qubit i
     pub fn hadamard(&mut self, qubit: usize) {
         let mask = 1 << (2 - qubit);
@@ -340,6 +421,67 @@ impl ThreeQubit {
             }
         }
     }
+
+    /// Wootters concurrence of qubits `a` and `b`, tracing out the third
+    /// qubit to form their reduced density matrix ρ. Tracing out one qubit
+    /// from a 3-qubit pure state leaves ρ with rank at most 2, so ρρ̃ (where
+    /// ρ̃ = (σy⊗σy) ρ* (σy⊗σy)) has at most two nonzero eigenvalues and the
+    /// usual four-eigenvalue formula collapses to C = max(0, √λ1 − √λ2).
+    pub fn pairwise_concurrence(&self, a: usize, b: usize) -> f32 {
+        let c = 3 - a - b;
+
+        let index = |vals: [usize; 3]| -> usize { (vals[0] << 2) | (vals[1] << 1) | vals[2] };
+
+        let mut rho = [[Complex::ZERO; 4]; 4];
+        for ia in 0..2 {
+            for ib in 0..2 {
+                for ja in 0..2 {
+                    for jb in 0..2 {
+                        let mut sum = Complex::ZERO;
+                        for kc in 0..2 {
+                            let mut bra = [0usize; 3];
+                            bra[a] = ia;
+                            bra[b] = ib;
+                            bra[c] = kc;
+                            let mut ket = [0usize; 3];
+                            ket[a] = ja;
+                            ket[b] = jb;
+                            ket[c] = kc;
+                            sum += self.amplitudes[index(bra)] * self.amplitudes[index(ket)].conj();
+                        }
+                        rho[2 * ia + ib][2 * ja + jb] = sum;
+                    }
+                }
+            }
+        }
+
+        let mut rho_conj = [[Complex::ZERO; 4]; 4];
+        for i in 0..4 {
+            for j in 0..4 {
+                rho_conj[i][j] = rho[i][j].conj();
+            }
+        }
+
+        let mut spin_flip = [[Complex::ZERO; 4]; 4];
+        for i in 0..4 {
+            for j in 0..4 {
+                spin_flip[i][j] = Complex::new(SPIN_FLIP[i][j], 0.0);
+            }
+        }
+
+        let rho_tilde = mat4_mul(&spin_flip, &mat4_mul(&rho_conj, &spin_flip));
+        let m = mat4_mul(&rho, &rho_tilde);
+
+        // Only λ1, λ2 can be nonzero; recover them from tr(M) = λ1+λ2 and
+        // tr(M²) = λ1²+λ2² instead of solving the full quartic.
+        let tr_m = mat4_trace(&m).re;
+        let tr_m2 = mat4_trace(&mat4_mul(&m, &m)).re;
+        let discriminant = (2.0 * tr_m2 - tr_m * tr_m).max(0.0).sqrt();
+        let lambda1 = ((tr_m + discriminant) * 0.5).max(0.0);
+        let lambda2 = ((tr_m - discriminant) * 0.5).max(0.0);
+
+        (lambda1.sqrt() - lambda2.sqrt()).max(0.0)
+    }
 }
 
 /// Spin state for spin-1/2 particles
@@ -385,3 +527,145 @@ impl Spin {
         2.0 * cross.im
     }
 }
+
+const PAULI_X_GATE: [[Complex; 2]; 2] = [
+    [Complex { re: 0.0, im: 0.0 }, Complex { re: 1.0, im: 0.0 }],
+    [Complex { re: 1.0, im: 0.0 }, Complex { re: 0.0, im: 0.0 }],
+];
+
+const HADAMARD_GATE: [[Complex; 2]; 2] = [
+    [Complex { re: FRAC_1_SQRT_2, im: 0.0 }, Complex { re: FRAC_1_SQRT_2, im: 0.0 }],
+    [Complex { re: FRAC_1_SQRT_2, im: 0.0 }, Complex { re: -FRAC_1_SQRT_2, im: 0.0 }],
+];
+
+/// Generalized N-qubit register, storing 2ⁿ amplitudes explicitly
+///
+/// `TwoQubit` and `ThreeQubit` duplicate near-identical fixed-size arrays and
+/// hand-written gate logic; `Register` generalizes that to an arbitrary qubit
+/// count, following the mixing-matrix machinery in the FeynRules models
+/// (CKM/PMNS-style unitary rotations applied to a multi-component state
+/// vector): an arbitrary 2ᵏ×2ᵏ unitary can be applied to any chosen subset of
+/// qubits by iterating the conditioned index pairs.
+#[derive(Debug, Clone)]
+pub struct Register {
+    /// Amplitude for each computational basis state, qubit 0 most significant
+    pub amplitudes: Vec<Complex>,
+    pub n_qubits: usize,
+}
+
+impl Register {
+    /// Ground state |00...0⟩ of `n_qubits` qubits
+    pub fn new(n_qubits: usize) -> Self {
+        let mut amplitudes = vec![Complex::ZERO; 1 << n_qubits];
+        amplitudes[0] = Complex::ONE;
+        Self { amplitudes, n_qubits }
+    }
+
+    /// Apply a single-qubit gate to `target`
+    pub fn apply_single(&mut self, gate: &[[Complex; 2]; 2], target: usize) {
+        let mask = 1 << (self.n_qubits - 1 - target);
+        for i in 0..self.amplitudes.len() {
+            if i & mask == 0 {
+                let j = i | mask;
+                let (a0, a1) = (self.amplitudes[i], self.amplitudes[j]);
+                self.amplitudes[i] = gate[0][0] * a0 + gate[0][1] * a1;
+                self.amplitudes[j] = gate[1][0] * a0 + gate[1][1] * a1;
+            }
+        }
+    }
+
+    /// Apply a single-qubit gate to `target`, conditioned on `control` being |1⟩
+    pub fn apply_controlled(&mut self, gate: &[[Complex; 2]; 2], control: usize, target: usize) {
+        let cmask = 1 << (self.n_qubits - 1 - control);
+        let tmask = 1 << (self.n_qubits - 1 - target);
+        for i in 0..self.amplitudes.len() {
+            if i & cmask != 0 && i & tmask == 0 {
+                let j = i | tmask;
+                let (a0, a1) = (self.amplitudes[i], self.amplitudes[j]);
+                self.amplitudes[i] = gate[0][0] * a0 + gate[0][1] * a1;
+                self.amplitudes[j] = gate[1][0] * a0 + gate[1][1] * a1;
+            }
+        }
+    }
+
+    /// Apply an arbitrary 2ᵏ×2ᵏ unitary (row-major, `matrix.len() == dim*dim`)
+    /// to the chosen subset of qubits, e.g. a measured mixing matrix
+    pub fn apply_unitary(&mut self, matrix: &[Complex], qubits: &[usize]) {
+        let k = qubits.len();
+        let dim = 1 << k;
+        debug_assert_eq!(matrix.len(), dim * dim);
+
+        let masks: Vec<usize> = qubits.iter().map(|&q| 1 << (self.n_qubits - 1 - q)).collect();
+        let mut visited = vec![false; self.amplitudes.len()];
+
+        for base in 0..self.amplitudes.len() {
+            if visited[base] || masks.iter().any(|&m| base & m != 0) {
+                continue;
+            }
+
+            let group: Vec<usize> = (0..dim)
+                .map(|idx| {
+                    masks.iter().enumerate().fold(base, |acc, (bit, &m)| {
+                        if idx & (1 << bit) != 0 { acc | m } else { acc }
+                    })
+                })
+                .collect();
+
+            let old: Vec<Complex> = group.iter().map(|&idx| self.amplitudes[idx]).collect();
+            for row in 0..dim {
+                let mut sum = Complex::ZERO;
+                for (col, &amp) in old.iter().enumerate() {
+                    sum = sum + matrix[row * dim + col] * amp;
+                }
+                self.amplitudes[group[row]] = sum;
+                visited[group[row]] = true;
+            }
+        }
+    }
+
+    /// Bell state |Φ+⟩ = (|00⟩ + |11⟩)/√2, via H(0) then CNOT(0,1)
+    pub fn bell_phi_plus() -> Self {
+        let mut reg = Self::new(2);
+        reg.apply_single(&HADAMARD_GATE, 0);
+        reg.apply_controlled(&PAULI_X_GATE, 0, 1);
+        reg
+    }
+
+    /// GHZ state (|00...0⟩ + |11...1⟩)/√2 over `n_qubits` qubits
+    pub fn ghz(n_qubits: usize) -> Self {
+        let mut reg = Self::new(n_qubits);
+        reg.apply_single(&HADAMARD_GATE, 0);
+        for target in 1..n_qubits {
+            reg.apply_controlled(&PAULI_X_GATE, 0, target);
+        }
+        reg
+    }
+
+    /// W state: equal superposition of every basis state with exactly one
+    /// qubit set, over `n_qubits` qubits
+    pub fn w_state(n_qubits: usize) -> Self {
+        let mut reg = Self::new(n_qubits);
+        reg.amplitudes = vec![Complex::ZERO; 1 << n_qubits];
+        let coeff = 1.0 / (n_qubits as f32).sqrt();
+        for i in 0..n_qubits {
+            reg.amplitudes[1 << (n_qubits - 1 - i)] = Complex::new(coeff, 0.0);
+        }
+        reg
+    }
+
+    /// Probability of measuring each basis state
+    pub fn probabilities(&self) -> Vec<f32> {
+        self.amplitudes.iter().map(|c| c.norm_sq()).collect()
+    }
+
+    /// Normalize the state
+    pub fn normalize(&mut self) {
+        let norm_sq: f32 = self.amplitudes.iter().map(|c| c.norm_sq()).sum();
+        let norm = norm_sq.sqrt();
+        if norm > 1e-10 {
+            for c in &mut self.amplitudes {
+                *c = *c * (1.0 / norm);
+            }
+        }
+    }
+}
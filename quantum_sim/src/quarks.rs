@@ -55,6 +55,23 @@ impl ColorCharge {
                 | (ColorCharge::AntiBlue, ColorCharge::Blue)
         )
     }
+
+    /// The anticolor a diquark built from two quarks of colors `a` and `b`
+    /// carries (3⊗3 = 6⊕3̄, and the antisymmetric pair a diquark occupies is
+    /// the 3̄): red+green makes an effective anti-blue, red+blue an effective
+    /// anti-green, green+blue an effective anti-red. That lets a diquark
+    /// neutralize against a single quark the same way an antiquark does.
+    /// Returns `None` for any other pairing (a repeated color, or mixing a
+    /// color with an anticolor), which isn't a valid diquark.
+    pub fn diquark_anticolor(a: ColorCharge, b: ColorCharge) -> Option<ColorCharge> {
+        use ColorCharge::*;
+        match (a, b) {
+            (Red, Green) | (Green, Red) => Some(AntiBlue),
+            (Red, Blue) | (Blue, Red) => Some(AntiGreen),
+            (Green, Blue) | (Blue, Green) => Some(AntiRed),
+            _ => None,
+        }
+    }
 }
 
 /// Quark flavor
@@ -100,6 +117,35 @@ impl QuarkFlavor {
             QuarkFlavor::Bottom => "b",
         }
     }
+
+    /// PDG Monte Carlo particle numbering scheme id for this flavor
+    /// (unsigned; a `Quark`'s `pdg_id` negates it for antiquarks)
+    pub fn pdg_id(&self) -> i32 {
+        match self {
+            QuarkFlavor::Down => 1,
+            QuarkFlavor::Up => 2,
+            QuarkFlavor::Strange => 3,
+            QuarkFlavor::Charm => 4,
+            QuarkFlavor::Bottom => 5,
+            QuarkFlavor::Top => 6,
+        }
+    }
+
+    /// Inverse of `pdg_id`: the flavor and antiquark-ness named by a signed
+    /// PDG id. Unrecognized ids fall back to `Up`, matching how
+    /// `sample_fragment_flavor` defaults when its weighted roll runs out.
+    pub fn from_pdg_id(id: i32) -> (QuarkFlavor, bool) {
+        let flavor = match id.abs() {
+            1 => QuarkFlavor::Down,
+            2 => QuarkFlavor::Up,
+            3 => QuarkFlavor::Strange,
+            4 => QuarkFlavor::Charm,
+            5 => QuarkFlavor::Bottom,
+            6 => QuarkFlavor::Top,
+            _ => QuarkFlavor::Up,
+        };
+        (flavor, id < 0)
+    }
 }
 
 /// A quark particle
@@ -110,6 +156,10 @@ pub struct Quark {
     pub is_antiquark: bool,
     pub position: Vec3,
     pub velocity: Vec3,
+    /// Which hadron fragment this quark belongs to. All quarks in a hadron
+    /// that hasn't string-broken share fragment id `0`; `fragment()` assigns
+    /// a fresh id to the new fragment produced when a flux tube snaps.
+    pub fragment_id: usize,
 }
 
 impl Quark {
@@ -121,6 +171,7 @@ impl Quark {
             is_antiquark,
             position,
             velocity: Vec3::ZERO,
+            fragment_id: 0,
         }
     }
 
@@ -137,6 +188,87 @@ impl Quark {
             self.flavor.symbol().to_string()
         }
     }
+
+    /// Signed PDG Monte Carlo id: `flavor.pdg_id()`, negated for antiquarks
+    pub fn pdg_id(&self) -> i32 {
+        let id = self.flavor.pdg_id();
+        if self.is_antiquark {
+            -id
+        } else {
+            id
+        }
+    }
+}
+
+/// Flavor content of a diquark: the two quark flavors bound into it. Order
+/// doesn't matter (`ud` and `du` name the same diquark), so equality and the
+/// combiner below treat the pair as unordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiquarkFlavor(pub QuarkFlavor, pub QuarkFlavor);
+
+impl DiquarkFlavor {
+    /// Combine two quark flavors into the diquark they form
+    pub fn combine(a: QuarkFlavor, b: QuarkFlavor) -> Self {
+        Self(a, b)
+    }
+
+    /// Approximate mass in MeV/c², the sum of the two constituent quarks'
+    /// `QuarkFlavor::mass`
+    pub fn mass(&self) -> f32 {
+        self.0.mass() + self.1.mass()
+    }
+
+    /// Symbol for display, e.g. "ud"
+    pub fn symbol(&self) -> String {
+        format!("{}{}", self.0.symbol(), self.1.symbol())
+    }
+}
+
+/// A diquark: a bound pair of quarks carrying combined flavor and an
+/// antisymmetric color charge (see `ColorCharge::diquark_anticolor`), so it
+/// neutralizes a single quark exactly like an antiquark would. String
+/// fragmentation can produce a diquark–antidiquark pair instead of a
+/// quark–antiquark one, letting the diquark pair up with a spectator quark
+/// to form a three-quark color singlet (a baryon) rather than a meson.
+#[derive(Debug, Clone)]
+pub struct Diquark {
+    pub flavor: DiquarkFlavor,
+    pub color: ColorCharge,
+    pub is_anti: bool,
+    pub position: Vec3,
+    pub velocity: Vec3,
+    /// Which fragment this diquark belongs to, same bookkeeping as
+    /// `Quark::fragment_id`
+    pub fragment_id: usize,
+}
+
+impl Diquark {
+    pub fn new(flavor: DiquarkFlavor, color: ColorCharge, is_anti: bool, position: Vec3) -> Self {
+        let color = if is_anti { color.anti() } else { color };
+        Self {
+            flavor,
+            color,
+            is_anti,
+            position,
+            velocity: Vec3::ZERO,
+            fragment_id: 0,
+        }
+    }
+
+    /// Effective radius for visualization, heavier than a single quark since
+    /// it stands in for two
+    pub fn radius(&self) -> f32 {
+        0.3 + (self.flavor.mass().ln() / 12.0).clamp(0.0, 0.5)
+    }
+
+    /// Display symbol
+    pub fn symbol(&self) -> String {
+        if self.is_anti {
+            format!("{}̄", self.flavor.symbol())
+        } else {
+            self.flavor.symbol()
+        }
+    }
 }
 
 /// Gluon (force carrier)
@@ -159,8 +291,19 @@ impl Gluon {
             lifetime: 0.0,
         }
     }
+
+    /// PDG Monte Carlo id for the gluon, always 21 regardless of its
+    /// color/anticolor (those aren't part of the PDG numbering scheme)
+    pub fn pdg_id(&self) -> i32 {
+        21
+    }
 }
 
+/// Extra energy above `2 * QuarkFlavor::Up.mass()` a flux tube must store
+/// before it's allowed to snap, so the lightest on-shell pair has somewhere
+/// to put its kinetic energy
+const FRAGMENTATION_MARGIN: f32 = 2.0;
+
 /// Flux tube connecting quarks (string-like confinement)
 #[derive(Debug, Clone)]
 pub struct FluxTube {
@@ -176,6 +319,8 @@ pub struct FluxTube {
 pub enum HadronType {
     Proton,     // uud
     Neutron,    // udd
+    AntiProton, // ūūd̄
+    AntiNeutron, // ūd̄d̄
     PionPlus,   // ud̄
     PionMinus,  // ūd
     PionZero,   // (uū - dd̄)/√2
@@ -188,6 +333,8 @@ impl HadronType {
         match self {
             HadronType::Proton => "Proton",
             HadronType::Neutron => "Neutron",
+            HadronType::AntiProton => "p̄",
+            HadronType::AntiNeutron => "n̄",
             HadronType::PionPlus => "π+",
             HadronType::PionMinus => "π-",
             HadronType::PionZero => "π0",
@@ -195,12 +342,132 @@ impl HadronType {
             HadronType::Jpsi => "J/ψ",
         }
     }
+
+    /// Rest mass in GeV/c², matching the values `decay::Hadron::new` uses
+    pub fn mass(&self) -> f32 {
+        match self {
+            HadronType::Proton | HadronType::Neutron | HadronType::AntiProton | HadronType::AntiNeutron => 0.938,
+            HadronType::PionPlus | HadronType::PionMinus => 0.139,
+            HadronType::PionZero => 0.135,
+            HadronType::Kaon => 0.494,
+            HadronType::Jpsi => 3.097,
+        }
+    }
+
+    /// Spin quantum number J, in units of ħ
+    pub fn spin(&self) -> f32 {
+        match self {
+            HadronType::Proton | HadronType::Neutron | HadronType::AntiProton | HadronType::AntiNeutron => 0.5,
+            HadronType::PionPlus | HadronType::PionMinus | HadronType::PionZero | HadronType::Kaon => 0.0,
+            HadronType::Jpsi => 1.0,
+        }
+    }
+
+    /// Conventional PDG Monte Carlo numbering scheme code for this hadron
+    pub fn pdg_id(&self) -> i32 {
+        match self {
+            HadronType::Proton => 2212,
+            HadronType::Neutron => 2112,
+            HadronType::AntiProton => -2212,
+            HadronType::AntiNeutron => -2112,
+            HadronType::PionPlus => 211,
+            HadronType::PionMinus => -211,
+            HadronType::PionZero => 111,
+            HadronType::Kaon => 321,
+            HadronType::Jpsi => 443,
+        }
+    }
 }
 
+/// Picks which physical hadron a quark-antiquark pair becomes, using the
+/// Kupco weighting `(2J+1)·exp(-m/b)` standard in string-fragmentation event
+/// generators: spin multiplicity favors higher-spin states, the exponential
+/// in mass favors the lightest ones. `b` is a tunable scale (typically
+/// around 1 GeV) controlling how strongly heavier candidates are suppressed.
+pub struct HadronSelector {
+    pub b: f32,
+}
+
+impl HadronSelector {
+    pub fn new(b: f32) -> Self {
+        Self { b }
+    }
+
+    /// Meson `HadronType`s consistent with a given quark/antiquark flavor
+    /// pair. This crate only models a handful of species, so most pairs have
+    /// exactly one candidate and the Kupco weighting below has nothing to
+    /// discriminate between — the weighting still applies correctly once
+    /// more resonances (ρ, η, η′, ...) are added for the same quark content.
+    fn candidates(quark: QuarkFlavor, antiquark: QuarkFlavor) -> Vec<HadronType> {
+        use QuarkFlavor::*;
+        match (quark, antiquark) {
+            (Up, Down) => vec![HadronType::PionPlus],
+            (Down, Up) => vec![HadronType::PionMinus],
+            (Up, Up) | (Down, Down) => vec![HadronType::PionZero],
+            (Up, Strange) | (Down, Strange) | (Strange, Up) | (Strange, Down) => vec![HadronType::Kaon],
+            (Charm, Charm) => vec![HadronType::Jpsi],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Kupco weight `(2J+1)·exp(-m/b)` for one candidate
+    fn weight(&self, hadron: HadronType) -> f32 {
+        (2.0 * hadron.spin() + 1.0) * (-hadron.mass() / self.b).exp()
+    }
+
+    /// Normalized selection probability for every candidate consistent with
+    /// `quark`/`antiquark`, so callers can inspect the branching before a
+    /// pick is made
+    pub fn probabilities(&self, quark: QuarkFlavor, antiquark: QuarkFlavor) -> Vec<(HadronType, f32)> {
+        let candidates = Self::candidates(quark, antiquark);
+        let weights: Vec<f32> = candidates.iter().map(|&h| self.weight(h)).collect();
+        let total: f32 = weights.iter().sum();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+
+        candidates
+            .into_iter()
+            .zip(weights)
+            .map(|(h, w)| (h, w / total))
+            .collect()
+    }
+
+    /// Randomly pick a hadron type for this quark-antiquark pair, weighted
+    /// by `probabilities`
+    pub fn select(&self, quark: QuarkFlavor, antiquark: QuarkFlavor, rng: &mut impl Rng) -> Option<HadronType> {
+        let probabilities = self.probabilities(quark, antiquark);
+        let mut roll = rng.gen::<f32>();
+        for (hadron, p) in &probabilities {
+            if roll < *p {
+                return Some(*hadron);
+            }
+            roll -= p;
+        }
+        probabilities.last().map(|(h, _)| *h)
+    }
+
+    /// The single most probable hadron type for this quark-antiquark pair,
+    /// for deterministically labeling an already-formed fragment rather than
+    /// sampling a fresh outcome
+    pub fn most_likely(&self, quark: QuarkFlavor, antiquark: QuarkFlavor) -> Option<HadronType> {
+        self.probabilities(quark, antiquark)
+            .into_iter()
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(h, _)| h)
+    }
+}
+
+/// Default Kupco `b` scale (GeV) used where callers don't tune their own
+const DEFAULT_KUPCO_B: f32 = 1.0;
+
 /// QCD simulation
 pub struct QuarkSimulation {
     /// All quarks in the simulation
     pub quarks: Vec<Quark>,
+    /// Diquarks produced by string breaks that chose baryon-type
+    /// fragmentation instead of a quark-antiquark pair
+    pub diquarks: Vec<Diquark>,
     /// Gluons being exchanged
     pub gluons: Vec<Gluon>,
     /// Flux tubes connecting quarks
@@ -209,18 +476,25 @@ pub struct QuarkSimulation {
     pub hadron_type: Option<HadronType>,
     /// String tension (confinement strength)
     pub string_tension: f32,
-    /// Coupling constant
+    /// Asymptotic (low-scale) reference coupling, used for the short-range
+    /// confinement repulsion term; the pairwise Coulomb term instead uses
+    /// the distance-dependent running coupling from `alpha_s_at`
     pub alpha_s: f32,
     /// Simulation time
     pub time: f32,
     /// Confinement radius
     pub confinement_radius: f32,
+    /// Probability that a string break produces a diquark-antidiquark pair
+    /// (baryon/antibaryon fragmentation) instead of the usual
+    /// quark-antiquark pair (meson fragmentation)
+    pub diquark_probability: f32,
 }
 
 impl QuarkSimulation {
     pub fn new() -> Self {
         Self {
             quarks: Vec::new(),
+            diquarks: Vec::new(),
             gluons: Vec::new(),
             flux_tubes: Vec::new(),
             hadron_type: None,
@@ -228,12 +502,24 @@ impl QuarkSimulation {
             alpha_s: 0.5, // Strong coupling
             time: 0.0,
             confinement_radius: 1.0,
+            diquark_probability: 0.1,
+        }
+    }
+
+    /// Construct from a loaded [`crate::model::PhysicsModel`], using its
+    /// string tension and strong coupling instead of the hardcoded defaults
+    pub fn from_model(model: &crate::model::PhysicsModel) -> Self {
+        Self {
+            string_tension: model.string_tension,
+            alpha_s: model.alpha_s,
+            ..Self::new()
         }
     }
 
     /// Create a proton (uud)
     pub fn init_proton(&mut self) {
         self.quarks.clear();
+        self.diquarks.clear();
         self.gluons.clear();
 
         let r = 0.5;
@@ -265,6 +551,7 @@ impl QuarkSimulation {
     /// Create a neutron (udd)
     pub fn init_neutron(&mut self) {
         self.quarks.clear();
+        self.diquarks.clear();
         self.gluons.clear();
 
         let r = 0.5;
@@ -296,6 +583,7 @@ impl QuarkSimulation {
     /// Create a pion (quark-antiquark meson)
     pub fn init_pion_plus(&mut self) {
         self.quarks.clear();
+        self.diquarks.clear();
         self.gluons.clear();
 
         self.quarks.push(Quark::new(
@@ -318,6 +606,7 @@ impl QuarkSimulation {
     /// Create J/ψ (charmonium)
     pub fn init_jpsi(&mut self) {
         self.quarks.clear();
+        self.diquarks.clear();
         self.gluons.clear();
 
         self.quarks.push(Quark::new(
@@ -337,49 +626,238 @@ impl QuarkSimulation {
         self.update_flux_tubes();
     }
 
-    /// Update flux tubes based on current quark positions
+    /// Update flux tubes based on current quark positions. Each fragment
+    /// (see `fragment_id`) gets its own topology: a Y-shaped triangle for a
+    /// 3-quark baryon fragment, a single tube for a 2-quark meson fragment.
     fn update_flux_tubes(&mut self) {
         self.flux_tubes.clear();
 
-        let n = self.quarks.len();
-        if n < 2 {
-            return;
-        }
-
-        // For baryons (3 quarks): Y-shaped flux tube
-        // For mesons (2 quarks): single tube
-        if n == 3 {
-            // Connect to center (simplified Y topology)
-            let center: Vec3 = self.quarks.iter().map(|q| q.position).sum::<Vec3>() / 3.0;
-            for i in 0..3 {
-                let j = (i + 1) % 3;
+        for fragment_id in self.fragment_ids() {
+            let indices: Vec<usize> = self
+                .quarks
+                .iter()
+                .enumerate()
+                .filter(|(_, q)| q.fragment_id == fragment_id)
+                .map(|(i, _)| i)
+                .collect();
+
+            if indices.len() == 3 {
+                // Connect to center (simplified Y topology)
+                for k in 0..3 {
+                    let i = indices[k];
+                    let j = indices[(k + 1) % 3];
+                    let dist = (self.quarks[i].position - self.quarks[j].position).length();
+                    self.flux_tubes.push(FluxTube {
+                        quark_a: i,
+                        quark_b: j,
+                        tension: self.string_tension * dist,
+                        width: 0.1,
+                        color_flow: blend_colors(
+                            self.quarks[i].color.render_color(),
+                            self.quarks[j].color.render_color(),
+                        ),
+                    });
+                }
+            } else if indices.len() == 2 {
+                let i = indices[0];
+                let j = indices[1];
                 let dist = (self.quarks[i].position - self.quarks[j].position).length();
                 self.flux_tubes.push(FluxTube {
                     quark_a: i,
                     quark_b: j,
                     tension: self.string_tension * dist,
-                    width: 0.1,
+                    width: 0.15,
                     color_flow: blend_colors(
                         self.quarks[i].color.render_color(),
                         self.quarks[j].color.render_color(),
                     ),
                 });
             }
-        } else if n == 2 {
-            let dist = (self.quarks[0].position - self.quarks[1].position).length();
-            self.flux_tubes.push(FluxTube {
-                quark_a: 0,
-                quark_b: 1,
-                tension: self.string_tension * dist,
-                width: 0.15,
-                color_flow: blend_colors(
-                    self.quarks[0].color.render_color(),
-                    self.quarks[1].color.render_color(),
-                ),
-            });
         }
     }
 
+    /// Distinct fragment ids currently present, in ascending order. Includes
+    /// fragments that are carried by a diquark rather than by loose quarks.
+    pub fn fragment_ids(&self) -> Vec<usize> {
+        let mut ids: Vec<usize> = self
+            .quarks
+            .iter()
+            .map(|q| q.fragment_id)
+            .chain(self.diquarks.iter().map(|d| d.fragment_id))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// Color neutrality of a single fragment, using the same rule as
+    /// `is_color_neutral` but restricted to the quarks and diquarks sharing
+    /// `fragment_id`. A diquark's `color` already stands in for its
+    /// anti-triplet, so it plugs into the same pairwise/triple rules as a
+    /// quark's color.
+    pub fn is_fragment_color_neutral(&self, fragment_id: usize) -> bool {
+        let colors: Vec<ColorCharge> = self
+            .quarks
+            .iter()
+            .filter(|q| q.fragment_id == fragment_id)
+            .map(|q| q.color)
+            .chain(
+                self.diquarks
+                    .iter()
+                    .filter(|d| d.fragment_id == fragment_id)
+                    .map(|d| d.color),
+            )
+            .collect();
+
+        match colors.len() {
+            3 => {
+                let has_r = colors.contains(&ColorCharge::Red) || colors.contains(&ColorCharge::AntiRed);
+                let has_g = colors.contains(&ColorCharge::Green) || colors.contains(&ColorCharge::AntiGreen);
+                let has_b = colors.contains(&ColorCharge::Blue) || colors.contains(&ColorCharge::AntiBlue);
+                has_r && has_g && has_b
+            }
+            2 => colors[0].neutralizes(&colors[1]),
+            _ => false,
+        }
+    }
+
+    /// Best-guess `HadronType` for a fragment, inferred from its quark
+    /// content the same way `init_proton`/`init_pion_plus`/etc. build one. A
+    /// fragment carried by one quark plus a diquark (see `break_tube_diquark`)
+    /// is treated the same as a bare 3-quark fragment, the diquark's two
+    /// constituent flavors standing in for two of the three quarks. Returns
+    /// `None` for flavor combinations this simulation doesn't model.
+    pub fn fragment_hadron_type(&self, fragment_id: usize) -> Option<HadronType> {
+        let quarks: Vec<&Quark> = self
+            .quarks
+            .iter()
+            .filter(|q| q.fragment_id == fragment_id)
+            .collect();
+        let diquark = self.diquarks.iter().find(|d| d.fragment_id == fragment_id);
+
+        match (quarks.len(), diquark) {
+            (3, None) => baryon_type([
+                (quarks[0].flavor, quarks[0].is_antiquark),
+                (quarks[1].flavor, quarks[1].is_antiquark),
+                (quarks[2].flavor, quarks[2].is_antiquark),
+            ]),
+            (1, Some(diquark)) => baryon_type([
+                (quarks[0].flavor, quarks[0].is_antiquark),
+                (diquark.flavor.0, diquark.is_anti),
+                (diquark.flavor.1, diquark.is_anti),
+            ]),
+            (2, None) => {
+                let (q, qbar) = if quarks[0].is_antiquark {
+                    (quarks[1], quarks[0])
+                } else {
+                    (quarks[0], quarks[1])
+                };
+                HadronSelector::new(DEFAULT_KUPCO_B).most_likely(q.flavor, qbar.flavor)
+            }
+            _ => None,
+        }
+    }
+
+    /// Break overstretched flux tubes into new quark-antiquark pairs
+    /// (Lund-style string fragmentation), so pulling a confined quark far
+    /// enough always produces new hadrons rather than a free quark. Only
+    /// meson-topology (2-quark) fragments are considered: breaking one edge
+    /// of a baryon's Y topology leaves the other two edges still connecting
+    /// everything, so that case needs quark-diquark bookkeeping this
+    /// simulation doesn't model yet.
+    fn fragment(&mut self) {
+        let breaking_threshold = 2.0 * QuarkFlavor::Up.mass() + FRAGMENTATION_MARGIN;
+
+        let snapped = self
+            .flux_tubes
+            .iter()
+            .find(|tube| {
+                let fragment_id = self.quarks[tube.quark_a].fragment_id;
+                let fragment_size = self.quarks.iter().filter(|q| q.fragment_id == fragment_id).count();
+                fragment_size == 2 && tube.tension >= breaking_threshold
+            })
+            .cloned();
+
+        if let Some(tube) = snapped {
+            self.break_tube(&tube);
+        }
+    }
+
+    /// Insert a screening pair at the midpoint of `tube`, splitting its
+    /// 2-quark fragment into two independent, color-neutral fragments. With
+    /// probability `diquark_probability` the pair is a diquark-antidiquark
+    /// one (`break_tube_diquark`), producing a baryon and an antibaryon;
+    /// otherwise it's the usual quark-antiquark pair. Either way the new
+    /// object on `quark_a`'s side takes the anticolor of `quark_a`'s endpoint
+    /// color and the one on `quark_b`'s side takes the anticolor of
+    /// `quark_b`'s, so each half is neutral per `is_fragment_color_neutral`.
+    fn break_tube(&mut self, tube: &FluxTube) {
+        let pos_a = self.quarks[tube.quark_a].position;
+        let pos_b = self.quarks[tube.quark_b].position;
+        let axis = (pos_b - pos_a).try_normalize().unwrap_or(Vec3::X);
+        let midpoint = (pos_a + pos_b) / 2.0;
+
+        let color_a = self.quarks[tube.quark_a].color;
+        let color_b = self.quarks[tube.quark_b].color;
+
+        let old_fragment_id = self.quarks[tube.quark_a].fragment_id;
+        let new_fragment_id = self.fragment_ids().into_iter().max().unwrap_or(0) + 1;
+
+        if rand::thread_rng().gen::<f32>() < self.diquark_probability {
+            self.break_tube_diquark(tube, midpoint, axis, color_a, color_b, old_fragment_id, new_fragment_id);
+            return;
+        }
+
+        let flavor = sample_fragment_flavor(self.string_tension);
+
+        let mut new_quark = Quark::new(flavor, color_a.anti(), false, midpoint - axis * 0.05);
+        new_quark.fragment_id = old_fragment_id;
+
+        let mut new_antiquark = Quark::new(flavor, color_b.anti(), true, midpoint + axis * 0.05);
+        new_antiquark.fragment_id = new_fragment_id;
+
+        self.quarks[tube.quark_b].fragment_id = new_fragment_id;
+        self.quarks.push(new_quark);
+        self.quarks.push(new_antiquark);
+
+        self.update_flux_tubes();
+    }
+
+    /// Baryon-type string break: insert a diquark-antidiquark pair at the
+    /// midpoint of `tube` instead of a quark-antiquark one. `quark_a` plus
+    /// the new diquark becomes a three-quark color singlet (a baryon), and
+    /// `quark_b` plus the new antidiquark becomes its charge-conjugate (an
+    /// antibaryon); `fragment_hadron_type`/`is_fragment_color_neutral` treat
+    /// a quark-plus-diquark fragment the same as a bare 3-quark one.
+    /// Heavier diquarks are exponentially suppressed the same way heavier
+    /// fragment quarks are, via `sample_fragment_diquark_flavor`.
+    fn break_tube_diquark(
+        &mut self,
+        tube: &FluxTube,
+        midpoint: Vec3,
+        axis: Vec3,
+        color_a: ColorCharge,
+        color_b: ColorCharge,
+        old_fragment_id: usize,
+        new_fragment_id: usize,
+    ) {
+        let diquark_flavor = sample_fragment_diquark_flavor(self.string_tension);
+
+        let mut new_diquark =
+            Diquark::new(diquark_flavor, diquark_anticolor_for(color_a), false, midpoint - axis * 0.05);
+        new_diquark.fragment_id = old_fragment_id;
+
+        let mut new_antidiquark =
+            Diquark::new(diquark_flavor, diquark_anticolor_for(color_b), true, midpoint + axis * 0.05);
+        new_antidiquark.fragment_id = new_fragment_id;
+
+        self.quarks[tube.quark_b].fragment_id = new_fragment_id;
+        self.diquarks.push(new_diquark);
+        self.diquarks.push(new_antidiquark);
+
+        self.update_flux_tubes();
+    }
+
     /// Simulate one timestep
     pub fn step(&mut self, dt: f32) {
         self.time += dt;
@@ -421,13 +899,12 @@ impl QuarkSimulation {
                 if dist > 0.01 {
                     let dir = r / dist;
 
-                    // One-gluon exchange (Coulomb-like, but with running coupling)
-                    let coulomb = -self.alpha_s / (dist * dist + 0.1);
+                    // Running coupling: short range (large Q) is weak
+                    // (asymptotic freedom), long range (small Q) grows
+                    // toward the Landau-pole cap
+                    let running_alpha_s = self.alpha_s_at(dist);
 
-                    // Confining linear term
-                    let confine = self.string_tension * dist;
-
-                    let force_mag = coulomb + confine * 0.1;
+                    let force_mag = crate::qcd::cornell_force(dist, running_alpha_s, self.string_tension) * 0.1;
                     let force = dir * force_mag;
 
                     forces[i] += force;
@@ -444,12 +921,24 @@ impl QuarkSimulation {
             quark.position += quark.velocity * dt;
         }
 
+        // Diquarks aren't part of the confinement/Coulomb force loop above
+        // (they only ever appear as a baryon's two spectator quarks bound
+        // into one body after a break), so they just drift and damp like the
+        // gluon visualization does in `update_gluons`
+        for diquark in &mut self.diquarks {
+            diquark.velocity *= 0.98;
+            diquark.position += diquark.velocity * dt;
+        }
+
         // Spawn virtual gluons for visualization
         self.update_gluons(dt);
 
         // Update flux tube visualization
         self.update_flux_tubes();
 
+        // Break any flux tube stretched past the string-breaking threshold
+        self.fragment();
+
         // Color exchange (quantum fluctuation visualization)
         if rand::thread_rng().gen::<f32>() < 0.02 && n >= 2 {
             self.color_exchange();
@@ -500,6 +989,294 @@ impl QuarkSimulation {
         }
     }
 
+    /// Rearrange which quarks are joined by flux tubes using the given
+    /// `ReconnectionMode`, then re-derive `fragment_id` from the resulting
+    /// tube connectivity so `fragment_hadron_type`/`is_fragment_color_neutral`
+    /// stay meaningful (a reconnection can join quarks that used to belong
+    /// to different fragments, or split one fragment into two)
+    pub fn reconnect(&mut self, mode: crate::reconnection::ReconnectionMode) {
+        let reconnector =
+            crate::reconnection::ColorReconnector::new(&self.quarks, self.flux_tubes.clone(), self.string_tension);
+        self.flux_tubes = reconnector.reconnect(mode);
+        self.recompute_fragment_ids_from_tubes();
+    }
+
+    /// Re-derive `fragment_id` from current flux-tube connectivity via
+    /// union-find: quarks joined directly or transitively by a tube share a
+    /// fragment, quarks with no tube at all get their own singleton fragment
+    fn recompute_fragment_ids_from_tubes(&mut self) {
+        let n = self.quarks.len();
+        let mut parent: Vec<usize> = (0..n).collect();
+
+        fn find(parent: &mut [usize], x: usize) -> usize {
+            if parent[x] != x {
+                parent[x] = find(parent, parent[x]);
+            }
+            parent[x]
+        }
+
+        for tube in &self.flux_tubes {
+            let (ra, rb) = (find(&mut parent, tube.quark_a), find(&mut parent, tube.quark_b));
+            if ra != rb {
+                parent[ra] = rb;
+            }
+        }
+
+        let mut ids = std::collections::HashMap::new();
+        for i in 0..n {
+            let root = find(&mut parent, i);
+            let next_id = ids.len();
+            let fragment_id = *ids.entry(root).or_insert(next_id);
+            self.quarks[i].fragment_id = fragment_id;
+        }
+    }
+
+    /// Flux-tube tags touching each quark index, for serializing confinement
+    /// topology as PDG-style color-flow integers in `export_event` instead
+    /// of this crate's internal `ColorCharge`. Tube `i` is tagged `i + 1`
+    /// (tag `0` means "no flow"); a quark gets one tag per tube it's an
+    /// endpoint of, up to the two needed for a baryon's triangle topology.
+    fn color_flow_tags(&self) -> std::collections::HashMap<usize, (u32, u32)> {
+        let mut tags: std::collections::HashMap<usize, Vec<u32>> = std::collections::HashMap::new();
+        for (i, tube) in self.flux_tubes.iter().enumerate() {
+            let tag = i as u32 + 1;
+            tags.entry(tube.quark_a).or_default().push(tag);
+            tags.entry(tube.quark_b).or_default().push(tag);
+        }
+
+        tags.into_iter()
+            .map(|(i, v)| (i, (v.first().copied().unwrap_or(0), v.get(1).copied().unwrap_or(0))))
+            .collect()
+    }
+
+    /// One `export_event` row: `pdg_id status x y z vx vy vz color anticolor`
+    fn format_event_row(pdg_id: i32, status: i32, position: Vec3, velocity: Vec3, flow: (u32, u32)) -> String {
+        format!(
+            "{pdg_id} {status} {:.6} {:.6} {:.6} {:.6} {:.6} {:.6} {} {}",
+            position.x, position.y, position.z, velocity.x, velocity.y, velocity.z, flow.0, flow.1
+        )
+    }
+
+    /// Dump the current state as a portable, line-oriented event record (one
+    /// row per quark, gluon, and recognized hadron fragment) using the
+    /// standard PDG Monte Carlo numbering scheme, so external tools don't
+    /// need this crate's internal types to read it back.
+    ///
+    /// Confined partons (quarks, gluons) get the HEPEVT "intermediate"
+    /// status code `2`, since they never reach a detector on their own; each
+    /// fragment that's color-neutral and maps to a known `HadronType` gets
+    /// one extra status-`1` ("final state") row summarizing it, with
+    /// position/velocity averaged over its constituents and color/anticolor
+    /// `0` since a hadron is a color singlet. A diquark isn't PDG-numbered
+    /// on its own in this scheme, so it's written out as its two
+    /// constituent quarks sharing its position and velocity. Color/anticolor
+    /// on parton rows are flux-tube tags from `color_flow_tags`; pair them up
+    /// to recover which partons were joined by which tube. Round-trips
+    /// through `import_event`.
+    pub fn export_event(&self) -> String {
+        let flow = self.color_flow_tags();
+        let mut lines = Vec::new();
+
+        for (i, quark) in self.quarks.iter().enumerate() {
+            let flow = flow.get(&i).copied().unwrap_or((0, 0));
+            lines.push(Self::format_event_row(quark.pdg_id(), 2, quark.position, quark.velocity, flow));
+        }
+
+        for diquark in &self.diquarks {
+            for flavor in [diquark.flavor.0, diquark.flavor.1] {
+                let id = if diquark.is_anti { -flavor.pdg_id() } else { flavor.pdg_id() };
+                lines.push(Self::format_event_row(id, 2, diquark.position, diquark.velocity, (0, 0)));
+            }
+        }
+
+        for gluon in &self.gluons {
+            lines.push(Self::format_event_row(gluon.pdg_id(), 2, gluon.position, gluon.velocity, (0, 0)));
+        }
+
+        for fragment_id in self.fragment_ids() {
+            if !self.is_fragment_color_neutral(fragment_id) {
+                continue;
+            }
+            let Some(hadron) = self.fragment_hadron_type(fragment_id) else {
+                continue;
+            };
+
+            let members: Vec<(Vec3, Vec3)> = self
+                .quarks
+                .iter()
+                .filter(|q| q.fragment_id == fragment_id)
+                .map(|q| (q.position, q.velocity))
+                .chain(
+                    self.diquarks
+                        .iter()
+                        .filter(|d| d.fragment_id == fragment_id)
+                        .map(|d| (d.position, d.velocity)),
+                )
+                .collect();
+            if members.is_empty() {
+                continue;
+            }
+
+            let n = members.len() as f32;
+            let position = members.iter().map(|(p, _)| *p).sum::<Vec3>() / n;
+            let velocity = members.iter().map(|(_, v)| *v).sum::<Vec3>() / n;
+
+            lines.push(Self::format_event_row(hadron.pdg_id(), 1, position, velocity, (0, 0)));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Reconstruct a `QuarkSimulation` from an `export_event`-style record.
+    /// Only the status-`2` parton rows are used to rebuild quarks and
+    /// gluons; status-`1` hadron summary rows are informational only and
+    /// are skipped, since one averaged four-momentum can't be split back
+    /// into distinct partons. Quarks sharing a flux-tube tag are reconnected
+    /// into the same tube, from which `fragment_id` and the rendered
+    /// topology (`update_flux_tubes`) are rebuilt exactly like `reconnect`
+    /// does. Only the color *flow*, not a literal RGB label, is physical,
+    /// so `ColorCharge`s aren't in the record; they're re-synthesized
+    /// canonically per fragment the same way `init_proton`/`init_pion_plus`
+    /// assign theirs.
+    pub fn import_event(record: &str) -> Self {
+        let mut sim = Self::new();
+
+        let mut partons: Vec<(i32, Vec3, Vec3, u32, u32)> = Vec::new();
+        for line in record.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 10 {
+                continue;
+            }
+            let parsed: Option<(i32, i32, f32, f32, f32, f32, f32, f32, u32, u32)> = (|| {
+                Some((
+                    fields[0].parse().ok()?,
+                    fields[1].parse().ok()?,
+                    fields[2].parse().ok()?,
+                    fields[3].parse().ok()?,
+                    fields[4].parse().ok()?,
+                    fields[5].parse().ok()?,
+                    fields[6].parse().ok()?,
+                    fields[7].parse().ok()?,
+                    fields[8].parse().ok()?,
+                    fields[9].parse().ok()?,
+                ))
+            })();
+            let Some((pdg_id, status, x, y, z, vx, vy, vz, c1, c2)) = parsed else {
+                continue;
+            };
+            if status != 2 {
+                continue;
+            }
+
+            let position = Vec3::new(x, y, z);
+            let velocity = Vec3::new(vx, vy, vz);
+            if pdg_id == 21 {
+                let mut gluon = Gluon::new(ColorCharge::Red, ColorCharge::AntiRed, position);
+                gluon.velocity = velocity;
+                sim.gluons.push(gluon);
+            } else {
+                partons.push((pdg_id, position, velocity, c1, c2));
+            }
+        }
+
+        sim.quarks = partons
+            .iter()
+            .map(|&(pdg_id, position, velocity, ..)| {
+                let (flavor, is_antiquark) = QuarkFlavor::from_pdg_id(pdg_id);
+                let mut quark = Quark::new(flavor, ColorCharge::Red, is_antiquark, position);
+                quark.velocity = velocity;
+                quark
+            })
+            .collect();
+
+        let mut tag_members: std::collections::HashMap<u32, Vec<usize>> = std::collections::HashMap::new();
+        for (i, &(_, _, _, c1, c2)) in partons.iter().enumerate() {
+            for tag in [c1, c2] {
+                if tag != 0 {
+                    tag_members.entry(tag).or_default().push(i);
+                }
+            }
+        }
+        let mut tags: Vec<u32> = tag_members.keys().copied().collect();
+        tags.sort_unstable();
+        sim.flux_tubes = tags
+            .into_iter()
+            .filter_map(|tag| {
+                let members = &tag_members[&tag];
+                (members.len() == 2).then(|| FluxTube {
+                    quark_a: members[0],
+                    quark_b: members[1],
+                    tension: 0.0,
+                    width: 0.1,
+                    color_flow: [1.0, 1.0, 1.0, 1.0],
+                })
+            })
+            .collect();
+
+        sim.recompute_fragment_ids_from_tubes();
+        sim.synthesize_colors_from_flow();
+        sim.update_flux_tubes();
+        sim
+    }
+
+    /// Assign each fragment a canonical, neutral `ColorCharge` combination
+    /// from its member count alone (RGB for a 3-quark fragment, a
+    /// color-anticolor pair for a 2-quark one), the same shapes
+    /// `init_proton`/`init_pion_plus`/etc. use. Used by `import_event`,
+    /// where the record carries color *flow* tags but not literal colors.
+    fn synthesize_colors_from_flow(&mut self) {
+        for fragment_id in self.fragment_ids() {
+            let indices: Vec<usize> = self
+                .quarks
+                .iter()
+                .enumerate()
+                .filter(|(_, q)| q.fragment_id == fragment_id)
+                .map(|(i, _)| i)
+                .collect();
+
+            let palette: &[ColorCharge] = match indices.len() {
+                3 => &[ColorCharge::Red, ColorCharge::Green, ColorCharge::Blue],
+                2 => &[ColorCharge::Red, ColorCharge::AntiRed],
+                _ => &[],
+            };
+            for (&i, &color) in indices.iter().zip(palette.iter()) {
+                self.quarks[i].color = color;
+            }
+        }
+    }
+
+    /// Running strong coupling αₛ evaluated at inter-quark separation `r`,
+    /// for the pairwise Coulomb term and for a UI to plot the running-
+    /// coupling curve directly. Maps `r` to a momentum scale `Q ≈ ħc/r` (so
+    /// small `r` means large `Q`), picks `n_f` from how many quark flavors
+    /// are light enough to be active at that scale, and evaluates the
+    /// one-loop beta function via `crate::qcd::alpha_s`.
+    pub fn alpha_s_at(&self, r: f32) -> f32 {
+        let q = Self::momentum_scale(r);
+        let n_f = Self::active_flavors(q).max(1);
+        crate::qcd::alpha_s(q * q, crate::constants::LAMBDA_QCD, n_f)
+    }
+
+    /// Momentum-transfer scale `Q ≈ ħc/r` probed at separation `r`
+    fn momentum_scale(r: f32) -> f32 {
+        crate::constants::HBAR * crate::constants::C / r.max(1e-3)
+    }
+
+    /// Number of quark flavors light enough to be pair-produced at scale
+    /// `Q` (mass below `Q`), which sets `n_f` in the running-coupling beta
+    /// function
+    fn active_flavors(q: f32) -> u32 {
+        const FLAVORS: [QuarkFlavor; 6] = [
+            QuarkFlavor::Up,
+            QuarkFlavor::Down,
+            QuarkFlavor::Strange,
+            QuarkFlavor::Charm,
+            QuarkFlavor::Bottom,
+            QuarkFlavor::Top,
+        ];
+        FLAVORS.iter().filter(|f| f.mass() < q).count() as u32
+    }
+
     /// Attempt to separate quarks (demonstrates confinement)
     pub fn apply_separation_force(&mut self, quark_index: usize, force: Vec3) {
         if quark_index < self.quarks.len() {
@@ -507,13 +1284,24 @@ impl QuarkSimulation {
         }
     }
 
-    /// Get render data
+    /// Sample a decay cascade for the current hadron, if it has one, for the
+    /// renderer to animate as confinement/hadronization
+    pub fn sample_decay_cascade(&self, rng: &mut impl rand::Rng) -> Option<Vec<crate::decay::DecayProduct>> {
+        let hadron_type = self.hadron_type?;
+        crate::decay::Hadron::new(hadron_type).sample_decay(rng)
+    }
+
+    /// Get render data. Includes diquarks alongside quarks: both are single
+    /// color-charged bodies as far as the renderer is concerned.
     pub fn get_quark_data(&self) -> Vec<(Vec3, f32, [f32; 4], String)> {
         self.quarks
             .iter()
-            .map(|q| {
-                (q.position, q.radius(), q.color.render_color(), q.symbol())
-            })
+            .map(|q| (q.position, q.radius(), q.color.render_color(), q.symbol()))
+            .chain(
+                self.diquarks
+                    .iter()
+                    .map(|d| (d.position, d.radius(), d.color.render_color(), d.symbol())),
+            )
             .collect()
     }
 
@@ -541,8 +1329,116 @@ impl Default for QuarkSimulation {
     }
 }
 
+/// Pick a flavor for a newly created screening pair by tunneling
+/// suppression: probability ∝ `exp(-π·m²/κ)` with `κ = string_tension`, so
+/// light u/d quarks dominate pair production and heavier strange/charm
+/// pairs are exponentially rare (the Schwinger mechanism).
+fn sample_fragment_flavor(string_tension: f32) -> QuarkFlavor {
+    const CANDIDATES: [QuarkFlavor; 4] = [
+        QuarkFlavor::Up,
+        QuarkFlavor::Down,
+        QuarkFlavor::Strange,
+        QuarkFlavor::Charm,
+    ];
+
+    let kappa = string_tension.max(0.01);
+    let weights: Vec<f32> = CANDIDATES
+        .iter()
+        .map(|f| (-PI * f.mass() * f.mass() / kappa).exp())
+        .collect();
+    let total: f32 = weights.iter().sum();
+
+    let mut roll = rand::thread_rng().gen::<f32>() * total;
+    for (flavor, weight) in CANDIDATES.iter().zip(weights.iter()) {
+        if roll < *weight {
+            return *flavor;
+        }
+        roll -= *weight;
+    }
+    QuarkFlavor::Up
+}
+
+/// Pick a flavor for a newly created diquark-antidiquark pair, using the
+/// same tunneling-suppression weighting as `sample_fragment_flavor` but on
+/// the diquark's combined mass, so `ud` diquarks dominate and anything
+/// carrying a strange or charm quark is exponentially rarer.
+fn sample_fragment_diquark_flavor(string_tension: f32) -> DiquarkFlavor {
+    const CANDIDATES: [(QuarkFlavor, QuarkFlavor); 6] = [
+        (QuarkFlavor::Up, QuarkFlavor::Up),
+        (QuarkFlavor::Up, QuarkFlavor::Down),
+        (QuarkFlavor::Down, QuarkFlavor::Down),
+        (QuarkFlavor::Up, QuarkFlavor::Strange),
+        (QuarkFlavor::Down, QuarkFlavor::Strange),
+        (QuarkFlavor::Strange, QuarkFlavor::Strange),
+    ];
+
+    let kappa = string_tension.max(0.01);
+    let weights: Vec<f32> = CANDIDATES
+        .iter()
+        .map(|&(a, b)| {
+            let mass = DiquarkFlavor::combine(a, b).mass();
+            (-PI * mass * mass / kappa).exp()
+        })
+        .collect();
+    let total: f32 = weights.iter().sum();
+
+    let mut roll = rand::thread_rng().gen::<f32>() * total;
+    for (&(a, b), weight) in CANDIDATES.iter().zip(weights.iter()) {
+        if roll < *weight {
+            return DiquarkFlavor::combine(a, b);
+        }
+        roll -= *weight;
+    }
+    DiquarkFlavor::combine(QuarkFlavor::Up, QuarkFlavor::Down)
+}
+
+/// Anticolor a diquark must carry to neutralize a spectator quark of
+/// `spectator_color`, via `ColorCharge::diquark_anticolor`: a primary-colored
+/// spectator picks the other two primaries (e.g. a red spectator pairs with
+/// a green+blue diquark, which is anti-red). A spectator that's itself an
+/// antiquark (an anticolor, not one of `diquark_anticolor`'s primary inputs)
+/// falls back to the plain anticolor, matching how `break_tube` colors a
+/// fresh quark-antiquark pair.
+fn diquark_anticolor_for(spectator_color: ColorCharge) -> ColorCharge {
+    use ColorCharge::*;
+    let others = match spectator_color {
+        Red => Some((Green, Blue)),
+        Green => Some((Red, Blue)),
+        Blue => Some((Red, Green)),
+        _ => None,
+    };
+
+    others
+        .and_then(|(a, b)| ColorCharge::diquark_anticolor(a, b))
+        .unwrap_or_else(|| spectator_color.anti())
+}
+
+/// Baryon/antibaryon `HadronType` implied by three quark-equivalent flavors
+/// (either a bare 3-quark fragment, or a quark plus the two flavors bound in
+/// a diquark), matching the `uud`/`udd` content `init_proton`/`init_neutron`
+/// build and their charge-conjugate antiparticles. Returns `None` for any
+/// other flavor content.
+fn baryon_type(flavors: [(QuarkFlavor, bool); 3]) -> Option<HadronType> {
+    let ups = flavors.iter().filter(|(f, anti)| *f == QuarkFlavor::Up && !anti).count();
+    let downs = flavors.iter().filter(|(f, anti)| *f == QuarkFlavor::Down && !anti).count();
+    let anti_ups = flavors.iter().filter(|(f, anti)| *f == QuarkFlavor::Up && *anti).count();
+    let anti_downs = flavors.iter().filter(|(f, anti)| *f == QuarkFlavor::Down && *anti).count();
+
+    if ups == 2 && downs == 1 {
+        Some(HadronType::Proton)
+    } else if ups == 1 && downs == 2 {
+        Some(HadronType::Neutron)
+    } else if anti_ups == 2 && anti_downs == 1 {
+        Some(HadronType::AntiProton)
+    } else if anti_ups == 1 && anti_downs == 2 {
+        Some(HadronType::AntiNeutron)
+    } else {
+        None
+    }
+}
+
 /// Blend two colors for flux tube visualization
-fn blend_colors(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+pub(crate) fn blend_colors(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
     [
         (a[0] + b[0]) / 2.0,
         (a[1] + b[1]) / 2.0,
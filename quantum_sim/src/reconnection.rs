@@ -0,0 +1,216 @@
+//! Color reconnection between multiple hadron clusters
+//!
+//! `QuarkSimulation` tracks flux tubes as fixed pairings between quarks, but
+//! real color flow isn't locked to a particular pairing — when two color
+//! singlets (hadrons) pass close to each other, soft gluon exchange can
+//! reconnect which quarks their strings join. This mirrors the plain,
+//! statistical, and baryonic reconnection models used in event generators
+//! like Pythia to explain why final-state hadronization doesn't look like a
+//! naive sum of independent strings.
+
+use crate::quarks::{blend_colors, ColorCharge, FluxTube, Quark};
+use rand::Rng;
+
+/// Which reconnection search `ColorReconnector::reconnect` should run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReconnectionMode {
+    /// Iterate over all tube pairs, greedily accept any endpoint swap that
+    /// lowers the total length measure Λ
+    Plain,
+    /// Metropolis/simulated-annealing search: propose random endpoint
+    /// swaps, accept with probability `exp(-ΔΛ/T)`, and cool `T`
+    /// geometrically over `sweeps` proposals
+    Statistical { initial_temperature: f32, sweeps: u32 },
+    /// Like `Plain`, but afterwards also merges any three endpoints
+    /// carrying Red+Green+Blue (or their anticolors) into a baryonic
+    /// junction instead of leaving them as separate pairwise tubes
+    Baryonic,
+}
+
+/// Rearranges which quarks are joined by flux tubes, without moving the
+/// quarks themselves. Operates on a snapshot of tubes (possibly spanning
+/// several color-singlet clusters at once) and returns the new topology.
+pub struct ColorReconnector<'a> {
+    quarks: &'a [Quark],
+    tubes: Vec<FluxTube>,
+    string_tension: f32,
+}
+
+impl<'a> ColorReconnector<'a> {
+    pub fn new(quarks: &'a [Quark], tubes: Vec<FluxTube>, string_tension: f32) -> Self {
+        Self {
+            quarks,
+            tubes,
+            string_tension,
+        }
+    }
+
+    pub fn reconnect(&self, mode: ReconnectionMode) -> Vec<FluxTube> {
+        match mode {
+            ReconnectionMode::Plain => self.reconnect_plain(),
+            ReconnectionMode::Statistical {
+                initial_temperature,
+                sweeps,
+            } => self.reconnect_statistical(initial_temperature, sweeps),
+            ReconnectionMode::Baryonic => self.reconnect_baryonic(),
+        }
+    }
+
+    /// Length measure `Λ = Σ tube.length` that every mode tries to shrink
+    fn lambda(&self, tubes: &[FluxTube]) -> f32 {
+        tubes
+            .iter()
+            .map(|t| (self.quarks[t.quark_a].position - self.quarks[t.quark_b].position).length())
+            .sum()
+    }
+
+    /// Swap the `quark_b` endpoints of tubes `i` and `j` — `(a0,b0)` and
+    /// `(a1,b1)` become `(a0,b1)` and `(a1,b0)` — and refresh their cached
+    /// tension/color. Only applied when both results stay color-neutral.
+    fn try_swap(&self, tubes: &mut [FluxTube], i: usize, j: usize) -> bool {
+        if i == j {
+            return false;
+        }
+
+        let (a0, b0) = (tubes[i].quark_a, tubes[i].quark_b);
+        let (a1, b1) = (tubes[j].quark_a, tubes[j].quark_b);
+
+        let stays_neutral = self.quarks[a0].color.neutralizes(&self.quarks[b1].color)
+            && self.quarks[a1].color.neutralizes(&self.quarks[b0].color);
+        if !stays_neutral {
+            return false;
+        }
+
+        tubes[i].quark_b = b1;
+        tubes[j].quark_b = b0;
+        self.refresh_tube(&mut tubes[i]);
+        self.refresh_tube(&mut tubes[j]);
+        true
+    }
+
+    /// Recompute a tube's cached tension/color after its endpoints changed
+    fn refresh_tube(&self, tube: &mut FluxTube) {
+        let dist = (self.quarks[tube.quark_a].position - self.quarks[tube.quark_b].position).length();
+        tube.tension = self.string_tension * dist;
+        tube.color_flow = blend_colors(
+            self.quarks[tube.quark_a].color.render_color(),
+            self.quarks[tube.quark_b].color.render_color(),
+        );
+    }
+
+    /// Plain reconnection: try every tube pair, keep any swap that strictly
+    /// lowers Λ
+    fn reconnect_plain(&self) -> Vec<FluxTube> {
+        let mut tubes = self.tubes.clone();
+        let n = tubes.len();
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let before = self.lambda(&tubes);
+                let mut candidate = tubes.clone();
+                if self.try_swap(&mut candidate, i, j) && self.lambda(&candidate) < before {
+                    tubes = candidate;
+                }
+            }
+        }
+
+        tubes
+    }
+
+    /// Statistical reconnection: Metropolis search with geometric cooling,
+    /// so uphill moves (temporarily increasing Λ) are allowed early on and
+    /// become rare as `T` cools, letting the search escape local minima that
+    /// `reconnect_plain`'s strictly-greedy pass would get stuck in
+    fn reconnect_statistical(&self, initial_temperature: f32, sweeps: u32) -> Vec<FluxTube> {
+        let mut tubes = self.tubes.clone();
+        let n = tubes.len();
+        if n < 2 {
+            return tubes;
+        }
+
+        const COOLING_RATE: f32 = 0.95;
+        let mut temperature = initial_temperature.max(1e-6);
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..sweeps {
+            let i = rng.gen_range(0..n);
+            let j = rng.gen_range(0..n);
+
+            let before = self.lambda(&tubes);
+            let mut candidate = tubes.clone();
+            if self.try_swap(&mut candidate, i, j) {
+                let delta = self.lambda(&candidate) - before;
+                if delta < 0.0 || rng.gen::<f32>() < (-delta / temperature).exp() {
+                    tubes = candidate;
+                }
+            }
+
+            temperature *= COOLING_RATE;
+        }
+
+        tubes
+    }
+
+    /// Baryonic reconnection: run the plain search, then opportunistically
+    /// fuse any three still-unused tube endpoints carrying Red+Green+Blue
+    /// (or their anticolors) into a three-way baryonic junction, mirroring
+    /// how e.g. two nearby mesons can exchange color to form a baryon pair
+    fn reconnect_baryonic(&self) -> Vec<FluxTube> {
+        let mut tubes = self.reconnect_plain();
+
+        let endpoints: Vec<usize> = tubes.iter().flat_map(|t| [t.quark_a, t.quark_b]).collect();
+        let mut used = vec![false; endpoints.len()];
+
+        'endpoints: for a in 0..endpoints.len() {
+            if used[a] {
+                continue;
+            }
+            for b in (a + 1)..endpoints.len() {
+                if used[b] {
+                    continue;
+                }
+                for c in (b + 1)..endpoints.len() {
+                    if used[c] {
+                        continue;
+                    }
+
+                    let (qa, qb, qc) = (endpoints[a], endpoints[b], endpoints[c]);
+                    if !Self::is_junction_triplet(
+                        self.quarks[qa].color,
+                        self.quarks[qb].color,
+                        self.quarks[qc].color,
+                    ) {
+                        continue;
+                    }
+
+                    for (x, y) in [(qa, qb), (qb, qc), (qc, qa)] {
+                        let mut tube = FluxTube {
+                            quark_a: x,
+                            quark_b: y,
+                            tension: 0.0,
+                            width: 0.12,
+                            color_flow: [1.0, 1.0, 1.0, 1.0],
+                        };
+                        self.refresh_tube(&mut tube);
+                        tubes.push(tube);
+                    }
+
+                    used[a] = true;
+                    used[b] = true;
+                    used[c] = true;
+                    continue 'endpoints;
+                }
+            }
+        }
+
+        tubes
+    }
+
+    /// Whether three endpoint colors form a valid baryonic junction: all
+    /// three primary colors, or all three anticolors
+    fn is_junction_triplet(a: ColorCharge, b: ColorCharge, c: ColorCharge) -> bool {
+        use ColorCharge::*;
+        let has = |color: ColorCharge| a == color || b == color || c == color;
+        (has(Red) && has(Green) && has(Blue)) || (has(AntiRed) && has(AntiGreen) && has(AntiBlue))
+    }
+}
@@ -5,6 +5,9 @@
 use common::{Camera2D, Camera3D, CameraUniform, GraphicsContext};
 use wgpu::util::DeviceExt;
 use glam::Vec3;
+use rand::Rng;
+use std::collections::HashMap;
+use crate::environment::EnvironmentMap;
 
 /// Instance data for probability cloud points
 #[repr(C)]
@@ -31,6 +34,81 @@ impl PointInstance {
     }
 }
 
+/// A single short-lived particle spawned by `QuantumRenderer::emit_burst`
+/// for a discrete quantum event (measurement collapse, classical-bit
+/// transmission, state reconstruction)
+#[derive(Debug, Clone, Copy)]
+struct Particle {
+    position: Vec3,
+    velocity: Vec3,
+    acceleration: Vec3,
+    color: [f32; 4],
+    size: f32,
+    lifetime: f32,
+    age: f32,
+}
+
+impl Particle {
+    fn point_instance(&self) -> PointInstance {
+        let fade = (1.0 - self.age / self.lifetime).clamp(0.0, 1.0);
+        PointInstance {
+            position: [self.position.x, self.position.y, self.position.z],
+            size: self.size,
+            color: [self.color[0], self.color[1], self.color[2], self.color[3] * fade],
+        }
+    }
+}
+
+/// Configures a single `emit_burst` call: the origin, general direction,
+/// and color shared by every particle in the burst, with the randomized
+/// spread/speed/size/lifetime ranges they're drawn from
+struct ParticleBuilder {
+    origin: Vec3,
+    direction: Vec3,
+    color: [f32; 4],
+    spread: f32,
+    speed_range: (f32, f32),
+    size_range: (f32, f32),
+    lifetime_range: (f32, f32),
+}
+
+impl ParticleBuilder {
+    fn new(origin: Vec3, direction: Vec3, color: [f32; 4]) -> Self {
+        Self {
+            origin,
+            direction: direction.try_normalize().unwrap_or(Vec3::Y),
+            color,
+            spread: 0.6,
+            speed_range: (1.5, 3.5),
+            size_range: (0.05, 0.12),
+            lifetime_range: (0.4, 0.8),
+        }
+    }
+
+    fn spawn(&self, rng: &mut impl Rng) -> Particle {
+        // Perturb the burst direction within a cone so particles spray
+        // outward rather than all following the same straight line
+        let jitter = Vec3::new(
+            rng.gen_range(-self.spread..self.spread),
+            rng.gen_range(-self.spread..self.spread),
+            rng.gen_range(-self.spread..self.spread),
+        );
+        let velocity = (self.direction + jitter).try_normalize().unwrap_or(self.direction)
+            * rng.gen_range(self.speed_range.0..self.speed_range.1);
+
+        Particle {
+            position: self.origin,
+            velocity,
+            // Gentle drag so bursts settle rather than fly off indefinitely
+            acceleration: -velocity * 0.5,
+            color: self.color,
+            size: rng.gen_range(self.size_range.0..self.size_range.1),
+            lifetime: rng.gen_range(self.lifetime_range.0..self.lifetime_range.1),
+            age: 0.0,
+        }
+    }
+}
+
 /// Quad vertex for billboards
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
@@ -82,6 +160,77 @@ impl LineVertex {
     }
 }
 
+/// Triangle vertex for shaded solid faces (e.g. 4D polytope cells), already
+/// Lambert-shaded on the CPU so the shader just passes the color through
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct FaceVertex {
+    pub position: [f32; 3],
+    pub color: [f32; 4],
+}
+
+impl FaceVertex {
+    const ATTRIBS: [wgpu::VertexAttribute; 2] = wgpu::vertex_attr_array![
+        0 => Float32x3,
+        1 => Float32x4,
+    ];
+
+    pub fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<FaceVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
+/// How a primitive's color is combined with what's already in the target
+///
+/// `Additive` is what dense, glowing phenomena (probability clouds, flux
+/// tubes) want: overlapping draws accumulate brightness instead of
+/// occluding each other by alpha coverage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    AlphaBlend,
+    Additive,
+    Opaque,
+}
+
+impl BlendMode {
+    fn wgpu_state(self) -> Option<wgpu::BlendState> {
+        match self {
+            BlendMode::AlphaBlend => Some(wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::SrcAlpha,
+                    dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: wgpu::BlendComponent::OVER,
+            }),
+            BlendMode::Additive => Some(wgpu::BlendState {
+                color: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+                alpha: wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                },
+            }),
+            BlendMode::Opaque => None,
+        }
+    }
+}
+
+/// Which primitive pipeline a cached `(kind, blend mode)` pipeline belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum PrimitiveKind {
+    Point,
+    Line,
+}
+
 /// Wavefunction rendering data (for 1D plots)
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
@@ -107,22 +256,307 @@ impl WaveVertex {
     }
 }
 
+/// Per-vertex geometry for the shared unit sphere mesh; `PointInstance` rides
+/// alongside it in the sphere pipeline to provide per-instance position,
+/// scale, and color
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SphereVertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+}
+
+impl SphereVertex {
+    const ATTRIBS: [wgpu::VertexAttribute; 2] = wgpu::vertex_attr_array![
+        0 => Float32x3,
+        1 => Float32x3,
+    ];
+
+    pub fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<SphereVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
+/// Generate a unit UV-sphere (radius 1, centered at the origin) as a
+/// vertex/index pair, following the standard stacks-and-slices tessellation
+fn generate_uv_sphere(stacks: u32, slices: u32) -> (Vec<SphereVertex>, Vec<u16>) {
+    let mut vertices = Vec::with_capacity(((stacks + 1) * (slices + 1)) as usize);
+    for stack in 0..=stacks {
+        let phi = std::f32::consts::PI * stack as f32 / stacks as f32;
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        for slice in 0..=slices {
+            let theta = 2.0 * std::f32::consts::PI * slice as f32 / slices as f32;
+            let (sin_theta, cos_theta) = theta.sin_cos();
+            let normal = [sin_phi * cos_theta, cos_phi, sin_phi * sin_theta];
+            vertices.push(SphereVertex {
+                position: normal,
+                normal,
+            });
+        }
+    }
+
+    let mut indices = Vec::with_capacity((stacks * slices * 6) as usize);
+    let verts_per_ring = slices + 1;
+    for stack in 0..stacks {
+        for slice in 0..slices {
+            let a = (stack * verts_per_ring + slice) as u16;
+            let b = (stack * verts_per_ring + slice + 1) as u16;
+            let c = ((stack + 1) * verts_per_ring + slice) as u16;
+            let d = ((stack + 1) * verts_per_ring + slice + 1) as u16;
+            indices.extend_from_slice(&[a, c, b, b, c, d]);
+        }
+    }
+
+    (vertices, indices)
+}
+
+/// Per-pass parameters for the separable Gaussian blur used by the bloom chain
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct BlurParams {
+    direction: [f32; 2],
+    texel_size: [f32; 2],
+}
+
+/// Exposure control for the HDR tonemap pass
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ExposureUniform {
+    exposure: f32,
+    _padding: [f32; 3],
+}
+
+/// Create a render-attachment + sampled-texture pair for the HDR/bloom chain
+fn create_hdr_target(
+    device: &wgpu::Device,
+    width: u32,
+    height: u32,
+    format: wgpu::TextureFormat,
+    label: &str,
+) -> (wgpu::Texture, wgpu::TextureView) {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size: wgpu::Extent3d {
+            width: width.max(1),
+            height: height.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    (texture, view)
+}
+
+/// Create a `Depth32Float` render-attachment texture sized to the swapchain
+fn create_depth_texture(device: &wgpu::Device, width: u32, height: u32) -> (wgpu::Texture, wgpu::TextureView) {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Depth Texture"),
+        size: wgpu::Extent3d {
+            width: width.max(1),
+            height: height.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Depth32Float,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    (texture, view)
+}
+
+/// Reallocate `buffer` at the next power-of-two capacity if `needed` exceeds
+/// `*capacity`, so callers never silently truncate data that outgrew the
+/// buffer's initial size
+fn grow_vertex_buffer<T: bytemuck::Pod>(
+    device: &wgpu::Device,
+    label: &str,
+    buffer: &mut wgpu::Buffer,
+    capacity: &mut usize,
+    needed: usize,
+) {
+    if needed <= *capacity {
+        return;
+    }
+    let new_capacity = needed.next_power_of_two();
+    *buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some(label),
+        size: (std::mem::size_of::<T>() * new_capacity) as u64,
+        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    *capacity = new_capacity;
+}
+
+/// Procedurally generate a soft, radially symmetric point sprite: a white
+/// RGBA8 texture whose alpha falls off as `exp(-r²·k)` from the center, so
+/// point billboards blend into smooth density rather than hard quads
+fn gaussian_sprite_rgba(size: u32, k: f32) -> Vec<u8> {
+    let mut data = vec![0u8; (size * size * 4) as usize];
+    let center = (size as f32 - 1.0) / 2.0;
+    let radius = size as f32 / 2.0;
+    for y in 0..size {
+        for x in 0..size {
+            let dx = (x as f32 - center) / radius;
+            let dy = (y as f32 - center) / radius;
+            let r2 = dx * dx + dy * dy;
+            let alpha = (-r2 * k).exp();
+            let i = ((y * size + x) * 4) as usize;
+            data[i] = 255;
+            data[i + 1] = 255;
+            data[i + 2] = 255;
+            data[i + 3] = (alpha.clamp(0.0, 1.0) * 255.0) as u8;
+        }
+    }
+    data
+}
+
 /// General quantum renderer supporting multiple visualization modes
 pub struct QuantumRenderer {
+    // Point and line pipelines, one per (primitive, blend mode) pair so
+    // callers can pick alpha blending for UI-style overlays or additive
+    // blending for glowing/emissive phenomena without rebuilding a pipeline
+    // on the fly
+    pipelines: HashMap<(PrimitiveKind, BlendMode), wgpu::RenderPipeline>,
+
     // Point cloud pipeline (for orbitals, electrons)
-    point_pipeline: wgpu::RenderPipeline,
     quad_buffer: wgpu::Buffer,
     point_buffer: wgpu::Buffer,
     max_points: usize,
 
+    // Sprite sampled by point billboards, defaulting to a procedural
+    // Gaussian falloff blob so dense clouds read as continuous density
+    sprite_bind_group_layout: wgpu::BindGroupLayout,
+    sprite_sampler: wgpu::Sampler,
+    sprite_texture: wgpu::Texture,
+    sprite_view: wgpu::TextureView,
+    sprite_bind_group: wgpu::BindGroup,
+
     // Line pipeline (for edges, flux tubes)
-    line_pipeline: wgpu::RenderPipeline,
     line_buffer: wgpu::Buffer,
     max_lines: usize,
 
+    // Face pipeline (for shaded polytope cells and other solid triangles)
+    face_pipeline: wgpu::RenderPipeline,
+    face_buffer: wgpu::Buffer,
+    max_face_vertices: usize,
+
+    // Wave pipeline (1D wavefunction plots, domain-colored by phase/amplitude)
+    wave_pipeline: wgpu::RenderPipeline,
+    wave_buffer: wgpu::Buffer,
+    max_wave_verts: usize,
+
+    // Instanced lit-sphere mesh, a volumetric alternative to flat point
+    // billboards (e.g. quarks/nucleons rendered as shaded spheres)
+    sphere_pipeline: wgpu::RenderPipeline,
+    sphere_vertex_buffer: wgpu::Buffer,
+    sphere_index_buffer: wgpu::Buffer,
+    num_sphere_indices: u32,
+
     // Camera
     camera_buffer: wgpu::Buffer,
     camera_bind_group: wgpu::BindGroup,
+
+    // Depth buffer shared by the point and line pipelines so 3D scenes
+    // (orbitals, quarks, flux tubes) occlude by true depth, not draw order
+    depth_texture: wgpu::Texture,
+    depth_view: wgpu::TextureView,
+
+    // HDR offscreen target that wave-packet points render into, plus the
+    // bloom + tonemap chain that resolves it onto the swapchain
+    point_pipeline_hdr: wgpu::RenderPipeline,
+    hdr_texture: wgpu::Texture,
+    hdr_view: wgpu::TextureView,
+    bloom_texture_a: wgpu::Texture,
+    bloom_view_a: wgpu::TextureView,
+    bloom_texture_b: wgpu::Texture,
+    bloom_view_b: wgpu::TextureView,
+    hdr_sampler: wgpu::Sampler,
+
+    bright_pipeline: wgpu::RenderPipeline,
+    single_texture_bind_group_layout: wgpu::BindGroupLayout,
+    bright_bind_group: wgpu::BindGroup,
+
+    blur_pipeline: wgpu::RenderPipeline,
+    blur_bind_group_layout: wgpu::BindGroupLayout,
+    blur_params_h: wgpu::Buffer,
+    blur_params_v: wgpu::Buffer,
+    blur_bind_group_h: wgpu::BindGroup,
+    blur_bind_group_v: wgpu::BindGroup,
+
+    tonemap_pipeline: wgpu::RenderPipeline,
+    tonemap_bind_group_layout: wgpu::BindGroupLayout,
+    tonemap_bind_group: wgpu::BindGroup,
+    exposure_buffer: wgpu::Buffer,
+
+    hdr_format: wgpu::TextureFormat,
+    swapchain_format: wgpu::TextureFormat,
+
+    // Skybox (3D scenes only): fullscreen cubemap background sampled by a
+    // ray reconstructed from NDC via the camera's inverse view-projection
+    skybox_pipeline: wgpu::RenderPipeline,
+    skybox_bind_group_layout: wgpu::BindGroupLayout,
+    skybox_bind_group: Option<wgpu::BindGroup>,
+
+    // Short-lived particle bursts for discrete quantum events (measurement
+    // collapse, classical-bit transmission, state reconstruction), rendered
+    // by appending to the same instanced point buffer as the Bloch arrows
+    particles: Vec<Particle>,
+
+    // GPU compute path for Hall-effect electron dynamics: `cs_step_electrons`
+    // advances Lorentz-force / drift motion directly in `electron_buffer`, so
+    // the vertex stage can read positions straight from it with no per-frame
+    // CPU upload. `None` until `init_electron_compute` is called (only the
+    // Hall-effect demo uses it).
+    electron_compute: Option<ElectronCompute>,
+}
+
+/// GPU-side electron-stepping resources, created on demand by
+/// `QuantumRenderer::init_electron_compute`
+struct ElectronCompute {
+    pipeline: wgpu::ComputePipeline,
+    bind_group: wgpu::BindGroup,
+    electron_buffer: wgpu::Buffer,
+    params_buffer: wgpu::Buffer,
+    max_electrons: usize,
+}
+
+/// One electron's simulation state as uploaded to the `electron_buffer`
+/// storage array for `cs_step_electrons` to advance on the GPU, mirroring
+/// `HallSimulation`'s CPU-side `Electron` fields closely enough to drive the
+/// same Lorentz-force / drift dynamics without a per-frame CPU upload
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ElectronGpu {
+    pub position: [f32; 2],
+    pub velocity: [f32; 2],
+    pub guiding_center: [f32; 2],
+    pub landau_index: u32,
+    pub _padding: u32,
+}
+
+/// Parameters `cs_step_electrons` needs each dispatch: one small uniform
+/// block carrying whatever the kernel can't read off its own storage
+/// buffer, the same shape as the `SpecialUniform { t, center_x, center_y }`
+/// uniform driving the mandelbrot compute shader elsewhere in this codebase
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct HallComputeParams {
+    pub magnetic_field: f32,
+    pub dt: f32,
+    pub half_width: f32,
+    pub half_height: f32,
 }
 
 impl QuantumRenderer {
@@ -167,6 +601,81 @@ impl QuantumRenderer {
             }],
         });
 
+        // Sprite texture for point billboards: a soft Gaussian falloff by
+        // default so dense probability clouds sum into continuous density
+        // under additive blending instead of looking like hard quads
+        let sprite_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Sprite Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+
+        let sprite_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Sprite Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        const SPRITE_SIZE: u32 = 64;
+        let sprite_data = gaussian_sprite_rgba(SPRITE_SIZE, 4.0);
+        let sprite_texture = device.create_texture_with_data(
+            &ctx.queue,
+            &wgpu::TextureDescriptor {
+                label: Some("Point Sprite"),
+                size: wgpu::Extent3d {
+                    width: SPRITE_SIZE,
+                    height: SPRITE_SIZE,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8Unorm,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            &sprite_data,
+        );
+        let sprite_view = sprite_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let sprite_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Sprite Bind Group"),
+            layout: &sprite_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&sprite_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sprite_sampler),
+                },
+            ],
+        });
+
         // Pipeline layout
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Quantum Pipeline Layout"),
@@ -174,19 +683,118 @@ impl QuantumRenderer {
             push_constant_ranges: &[],
         });
 
-        // Point cloud pipeline
-        let point_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Point Pipeline"),
+        // Points sample the sprite texture in their fragment stage, so they
+        // get a second bind group the line pipeline doesn't need
+        let point_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Quantum Point Pipeline Layout"),
+            bind_group_layouts: &[&camera_bind_group_layout, &sprite_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // Point and line pipelines, one per blend mode. Everything but the
+        // blend state is identical to the original single-variant pipelines.
+        let make_point_pipeline = |blend: BlendMode| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Point Pipeline"),
+                layout: Some(&point_pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_point",
+                    buffers: &[QuadVertex::layout(), PointInstance::layout()],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_point",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: ctx.config.format,
+                        blend: blend.wgpu_state(),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Ccw,
+                    cull_mode: None,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    unclipped_depth: false,
+                    conservative: false,
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: wgpu::TextureFormat::Depth32Float,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::LessEqual,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        };
+
+        let make_line_pipeline = |blend: BlendMode| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Line Pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_line",
+                    buffers: &[LineVertex::layout()],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_line",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: ctx.config.format,
+                        blend: blend.wgpu_state(),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::LineList,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Ccw,
+                    cull_mode: None,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    unclipped_depth: false,
+                    conservative: false,
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: wgpu::TextureFormat::Depth32Float,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::LessEqual,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        };
+
+        let blend_modes = [BlendMode::AlphaBlend, BlendMode::Additive, BlendMode::Opaque];
+        let mut pipelines = HashMap::with_capacity(blend_modes.len() * 2);
+        for blend in blend_modes {
+            pipelines.insert((PrimitiveKind::Point, blend), make_point_pipeline(blend));
+            pipelines.insert((PrimitiveKind::Line, blend), make_line_pipeline(blend));
+        }
+
+        // Face pipeline (shaded solid triangles, e.g. polytope cells)
+        let face_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Face Pipeline"),
             layout: Some(&pipeline_layout),
             vertex: wgpu::VertexState {
                 module: &shader,
-                entry_point: "vs_point",
-                buffers: &[QuadVertex::layout(), PointInstance::layout()],
+                entry_point: "vs_face",
+                buffers: &[FaceVertex::layout()],
                 compilation_options: Default::default(),
             },
             fragment: Some(wgpu::FragmentState {
                 module: &shader,
-                entry_point: "fs_point",
+                entry_point: "fs_face",
                 targets: &[Some(wgpu::ColorTargetState {
                     format: ctx.config.format,
                     blend: Some(wgpu::BlendState {
@@ -215,19 +823,20 @@ impl QuantumRenderer {
             multiview: None,
         });
 
-        // Line pipeline
-        let line_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Line Pipeline"),
+        // Wave pipeline (1D wavefunction plots): a line strip, domain-colored
+        // by mapping phase to hue and amplitude to brightness in the shader
+        let wave_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Wave Pipeline"),
             layout: Some(&pipeline_layout),
             vertex: wgpu::VertexState {
                 module: &shader,
-                entry_point: "vs_line",
-                buffers: &[LineVertex::layout()],
+                entry_point: "vs_wave",
+                buffers: &[WaveVertex::layout()],
                 compilation_options: Default::default(),
             },
             fragment: Some(wgpu::FragmentState {
                 module: &shader,
-                entry_point: "fs_line",
+                entry_point: "fs_wave",
                 targets: &[Some(wgpu::ColorTargetState {
                     format: ctx.config.format,
                     blend: Some(wgpu::BlendState {
@@ -243,7 +852,7 @@ impl QuantumRenderer {
                 compilation_options: Default::default(),
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::LineList,
+                topology: wgpu::PrimitiveTopology::LineStrip,
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
                 cull_mode: None,
@@ -256,6 +865,61 @@ impl QuantumRenderer {
             multiview: None,
         });
 
+        // Sphere pipeline: a shared unit sphere mesh instanced by
+        // PointInstance, Lambert + ambient shaded against the fixed light
+        // direction carried in the camera uniform
+        let sphere_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Sphere Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_sphere",
+                buffers: &[SphereVertex::layout(), PointInstance::layout()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_sphere",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: Some(wgpu::Face::Back),
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::LessEqual,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        let (sphere_vertices, sphere_indices) = generate_uv_sphere(16, 24);
+        let sphere_vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sphere Vertex Buffer"),
+            contents: bytemuck::cast_slice(&sphere_vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let sphere_index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sphere Index Buffer"),
+            contents: bytemuck::cast_slice(&sphere_indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+        let num_sphere_indices = sphere_indices.len() as u32;
+
         // Vertex buffers
         let quad_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Quad Buffer"),
@@ -277,61 +941,763 @@ impl QuantumRenderer {
             mapped_at_creation: false,
         });
 
-        Self {
-            point_pipeline,
-            quad_buffer,
-            point_buffer,
-            max_points,
-            line_pipeline,
-            line_buffer,
-            max_lines,
-            camera_buffer,
-            camera_bind_group,
-        }
-    }
+        // Triangle soup isn't instanced like points/lines, so it gets its
+        // own vertex budget rather than reusing max_lines/max_points
+        let max_face_vertices = max_lines * 3;
+        let face_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Face Buffer"),
+            size: (std::mem::size_of::<FaceVertex>() * max_face_vertices) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
 
-    pub fn update_camera_3d(&self, queue: &wgpu::Queue, camera: &Camera3D) {
-        let uniform = CameraUniform::from_camera_3d(camera);
-        queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[uniform]));
-    }
+        // 1D wavefunction plots are sampled on a grid much like the point
+        // cloud, so they share its vertex budget rather than adding a new
+        // constructor parameter
+        let max_wave_verts = max_points;
+        let wave_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Wave Buffer"),
+            size: (std::mem::size_of::<WaveVertex>() * max_wave_verts) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
 
-    pub fn update_camera_2d(&self, queue: &wgpu::Queue, camera: &Camera2D) {
-        let uniform = CameraUniform::from_camera_2d(camera);
-        queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[uniform]));
-    }
+        let (depth_texture, depth_view) =
+            create_depth_texture(device, ctx.config.width, ctx.config.height);
 
-    pub fn update_points(&self, queue: &wgpu::Queue, points: &[PointInstance]) {
-        let data = &points[..points.len().min(self.max_points)];
-        queue.write_buffer(&self.point_buffer, 0, bytemuck::cast_slice(data));
-    }
+        // HDR point pipeline: same vertex/fragment stage as the swapchain
+        // point pipeline, but targeting a floating-point offscreen texture
+        // so bright probability peaks don't clip before bloom gets a look
+        let hdr_format = wgpu::TextureFormat::Rgba16Float;
+        let point_pipeline_hdr = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("HDR Point Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_point",
+                buffers: &[QuadVertex::layout(), PointInstance::layout()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_point",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: hdr_format,
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::SrcAlpha,
+                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                            operation: wgpu::BlendOperation::Add,
+                        },
+                        alpha: wgpu::BlendComponent::OVER,
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Fill,
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
 
-    pub fn update_lines(&self, queue: &wgpu::Queue, lines: &[(Vec3, Vec3, [f32; 4])]) {
-        let vertices: Vec<LineVertex> = lines
-            .iter()
-            .take(self.max_lines)
-            .flat_map(|(v1, v2, color)| {
-                [
-                    LineVertex {
-                        position: [v1.x, v1.y, v1.z],
-                        color: *color,
+        let hdr_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("HDR Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let (hdr_texture, hdr_view) =
+            create_hdr_target(device, ctx.config.width, ctx.config.height, hdr_format, "HDR");
+        let (bloom_texture_a, bloom_view_a) =
+            create_hdr_target(device, ctx.config.width, ctx.config.height, hdr_format, "Bloom A");
+        let (bloom_texture_b, bloom_view_b) =
+            create_hdr_target(device, ctx.config.width, ctx.config.height, hdr_format, "Bloom B");
+
+        let single_texture_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Single Texture Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
                     },
-                    LineVertex {
-                        position: [v2.x, v2.y, v2.z],
-                        color: *color,
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
                     },
-                ]
-            })
+                ],
+            });
+
+        let bright_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Bright Pass Bind Group"),
+            layout: &single_texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&hdr_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&hdr_sampler),
+                },
+            ],
+        });
+
+        let fullscreen_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Fullscreen Pipeline Layout"),
+                bind_group_layouts: &[&single_texture_bind_group_layout],
+                push_constant_ranges: &[],
+            });
+
+        let bright_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Bright Pass Pipeline"),
+            layout: Some(&fullscreen_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_bright",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: hdr_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        // Separable blur: one pipeline, run twice (horizontal then vertical)
+        // against ping-ponged bloom textures with per-pass direction uniforms
+        let blur_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Blur Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let blur_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Blur Pipeline Layout"),
+            bind_group_layouts: &[&blur_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let blur_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Blur Pipeline"),
+            layout: Some(&blur_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_blur",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: hdr_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        let texel_size = [1.0 / ctx.config.width as f32, 1.0 / ctx.config.height as f32];
+        let blur_params_h = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Blur Params Horizontal"),
+            contents: bytemuck::cast_slice(&[BlurParams {
+                direction: [1.0, 0.0],
+                texel_size,
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let blur_params_v = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Blur Params Vertical"),
+            contents: bytemuck::cast_slice(&[BlurParams {
+                direction: [0.0, 1.0],
+                texel_size,
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        // Horizontal pass reads the bright-pass result (bloom A) and writes
+        // bloom B; vertical pass reads bloom B back into bloom A
+        let blur_bind_group_h = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blur Bind Group Horizontal"),
+            layout: &blur_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&bloom_view_a),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&hdr_sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: blur_params_h.as_entire_binding(),
+                },
+            ],
+        });
+        let blur_bind_group_v = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blur Bind Group Vertical"),
+            layout: &blur_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&bloom_view_b),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&hdr_sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: blur_params_v.as_entire_binding(),
+                },
+            ],
+        });
+
+        // Tonemap pass: additively combines the base HDR scene with the
+        // blurred bloom, scales by exposure, then Reinhard-tonemaps into
+        // the swapchain format
+        let tonemap_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Tonemap Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let exposure_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Exposure Buffer"),
+            contents: bytemuck::cast_slice(&[ExposureUniform {
+                exposure: 1.0,
+                _padding: [0.0; 3],
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let tonemap_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Tonemap Bind Group"),
+            layout: &tonemap_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&hdr_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&bloom_view_a),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&hdr_sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: exposure_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let tonemap_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Tonemap Pipeline Layout"),
+                bind_group_layouts: &[&tonemap_bind_group_layout],
+                push_constant_ranges: &[],
+            });
+
+        let tonemap_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Tonemap Pipeline"),
+            layout: Some(&tonemap_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_tonemap",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        // Skybox: samples a cubemap behind the point cloud using a ray
+        // reconstructed from NDC via the camera's inverse view-projection.
+        // No cubemap is bound until `set_environment` is called, so the
+        // pass is a no-op for apps that never load one.
+        let skybox_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Skybox Bind Group Layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::Cube,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+
+        let skybox_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Skybox Pipeline Layout"),
+            bind_group_layouts: &[&camera_bind_group_layout, &skybox_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let skybox_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Skybox Pipeline"),
+            layout: Some(&skybox_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_fullscreen",
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_skybox",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            // The skybox is the farthest possible surface, so it never
+            // needs to write or test depth
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        Self {
+            pipelines,
+            quad_buffer,
+            point_buffer,
+            max_points,
+            sprite_bind_group_layout,
+            sprite_sampler,
+            sprite_texture,
+            sprite_view,
+            sprite_bind_group,
+            line_buffer,
+            max_lines,
+            face_pipeline,
+            face_buffer,
+            max_face_vertices,
+            wave_pipeline,
+            wave_buffer,
+            max_wave_verts,
+            sphere_pipeline,
+            sphere_vertex_buffer,
+            sphere_index_buffer,
+            num_sphere_indices,
+            camera_buffer,
+            camera_bind_group,
+            depth_texture,
+            depth_view,
+            point_pipeline_hdr,
+            hdr_texture,
+            hdr_view,
+            bloom_texture_a,
+            bloom_view_a,
+            bloom_texture_b,
+            bloom_view_b,
+            hdr_sampler,
+            bright_pipeline,
+            single_texture_bind_group_layout,
+            bright_bind_group,
+            blur_pipeline,
+            blur_bind_group_layout,
+            blur_params_h,
+            blur_params_v,
+            blur_bind_group_h,
+            blur_bind_group_v,
+            tonemap_pipeline,
+            tonemap_bind_group_layout,
+            tonemap_bind_group,
+            exposure_buffer,
+            hdr_format,
+            swapchain_format: ctx.config.format,
+            skybox_pipeline,
+            skybox_bind_group_layout,
+            skybox_bind_group: None,
+            particles: Vec::new(),
+            electron_compute: None,
+        }
+    }
+
+    /// Lazily create the GPU compute resources for Hall-effect electron
+    /// stepping; call once (e.g. from `App::new`) before `step_electrons_gpu`.
+    pub fn init_electron_compute(&mut self, device: &wgpu::Device, max_electrons: usize) {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Hall Electron Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/quantum.wgsl").into()),
+        });
+
+        let electron_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Electron Compute Buffer"),
+            size: (std::mem::size_of::<ElectronGpu>() * max_electrons) as u64,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::VERTEX
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Hall Compute Params Buffer"),
+            contents: bytemuck::cast_slice(&[HallComputeParams {
+                magnetic_field: 1.0,
+                dt: 0.0,
+                half_width: 1.0,
+                half_height: 1.0,
+            }]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Electron Compute Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Electron Compute Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: electron_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: params_buffer.as_entire_binding() },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Electron Compute Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Electron Compute Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: "cs_step_electrons",
+            compilation_options: Default::default(),
+        });
+
+        self.electron_compute = Some(ElectronCompute {
+            pipeline,
+            bind_group,
+            electron_buffer,
+            params_buffer,
+            max_electrons,
+        });
+    }
+
+    /// Upload electrons' initial GPU state; call once after
+    /// `init_electron_compute` and again whenever the electron count
+    /// changes (e.g. after `HallSimulation::fill_electrons`).
+    pub fn upload_electrons_gpu(&self, queue: &wgpu::Queue, electrons: &[ElectronGpu]) {
+        let compute = self.electron_compute.as_ref().expect("init_electron_compute must be called first");
+        let count = electrons.len().min(compute.max_electrons);
+        queue.write_buffer(&compute.electron_buffer, 0, bytemuck::cast_slice(&electrons[..count]));
+    }
+
+    /// Advance every electron's Lorentz-force / drift motion on the GPU by
+    /// `dt`, reading `magnetic_field` and the sample box half-extents from
+    /// `HallComputeParams` instead of re-uploading positions from the CPU
+    /// each frame.
+    pub fn step_electrons_gpu(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        queue: &wgpu::Queue,
+        magnetic_field: f32,
+        dt: f32,
+        half_width: f32,
+        half_height: f32,
+        num_electrons: u32,
+    ) {
+        let compute = self.electron_compute.as_ref().expect("init_electron_compute must be called first");
+
+        queue.write_buffer(
+            &compute.params_buffer,
+            0,
+            bytemuck::cast_slice(&[HallComputeParams { magnetic_field, dt, half_width, half_height }]),
+        );
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Electron Step Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&compute.pipeline);
+        pass.set_bind_group(0, &compute.bind_group, &[]);
+        pass.dispatch_workgroups(num_electrons.div_ceil(64), 1, 1);
+    }
+
+    /// The electron storage buffer the vertex stage can bind directly in
+    /// place of `point_buffer`, skipping the usual `update_points` CPU
+    /// upload entirely.
+    pub fn electron_vertex_buffer(&self) -> &wgpu::Buffer {
+        &self
+            .electron_compute
+            .as_ref()
+            .expect("init_electron_compute must be called first")
+            .electron_buffer
+    }
+
+    pub fn update_camera_3d(&self, queue: &wgpu::Queue, camera: &Camera3D) {
+        let uniform = CameraUniform::from_camera_3d(camera);
+        queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[uniform]));
+    }
+
+    pub fn update_camera_2d(&self, queue: &wgpu::Queue, camera: &Camera2D) {
+        let uniform = CameraUniform::from_camera_2d(camera);
+        queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[uniform]));
+    }
+
+    pub fn update_points(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, points: &[PointInstance]) {
+        grow_vertex_buffer::<PointInstance>(
+            device,
+            "Point Instance Buffer",
+            &mut self.point_buffer,
+            &mut self.max_points,
+            points.len(),
+        );
+        queue.write_buffer(&self.point_buffer, 0, bytemuck::cast_slice(points));
+    }
+
+    /// Spawn `count` short-lived particles around `origin`, sprayed along
+    /// `dir` with randomized spread/speed, for a discrete quantum event
+    /// (Bell-measurement collapse, classical-bit transmission, state
+    /// reconstruction). Call `update_particles` each simulation step and
+    /// fold `particle_points` into the buffer passed to `update_points`.
+    pub fn emit_burst(&mut self, origin: Vec3, dir: Vec3, count: usize, color: [f32; 4]) {
+        let builder = ParticleBuilder::new(origin, dir, color);
+        let mut rng = rand::thread_rng();
+        self.particles.extend((0..count).map(|_| builder.spawn(&mut rng)));
+    }
+
+    /// Advance every live particle with simple Euler integration and cull
+    /// any that have exceeded their lifetime
+    pub fn update_particles(&mut self, dt: f32) {
+        for particle in &mut self.particles {
+            particle.velocity += particle.acceleration * dt;
+            particle.position += particle.velocity * dt;
+            particle.age += dt;
+        }
+        self.particles.retain(|p| p.age < p.lifetime);
+    }
+
+    /// Point instances for the current particle burst, faded by `age /
+    /// lifetime`; append these to a scene's own points before calling
+    /// `update_points` to render them through the same instanced pipeline
+    pub fn particle_points(&self) -> Vec<PointInstance> {
+        self.particles.iter().map(Particle::point_instance).collect()
+    }
+
+    pub fn update_lines(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, lines: &[(Vec3, Vec3, [f32; 4])]) {
+        if lines.len() > self.max_lines {
+            let new_capacity = lines.len().next_power_of_two();
+            self.line_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Line Buffer"),
+                size: (std::mem::size_of::<LineVertex>() * new_capacity * 2) as u64,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            self.max_lines = new_capacity;
+        }
+
+        let vertices: Vec<LineVertex> = lines
+            .iter()
+            .flat_map(|(v1, v2, color)| {
+                [
+                    LineVertex {
+                        position: [v1.x, v1.y, v1.z],
+                        color: *color,
+                    },
+                    LineVertex {
+                        position: [v2.x, v2.y, v2.z],
+                        color: *color,
+                    },
+                ]
+            })
             .collect();
 
         queue.write_buffer(&self.line_buffer, 0, bytemuck::cast_slice(&vertices));
     }
 
+    pub fn update_faces(&self, queue: &wgpu::Queue, triangles: &[(Vec3, Vec3, Vec3, [f32; 4])]) {
+        let vertices: Vec<FaceVertex> = triangles
+            .iter()
+            .take(self.max_face_vertices / 3)
+            .flat_map(|(p0, p1, p2, color)| {
+                [p0, p1, p2].map(|p| FaceVertex {
+                    position: [p.x, p.y, p.z],
+                    color: *color,
+                })
+            })
+            .collect();
+
+        queue.write_buffer(&self.face_buffer, 0, bytemuck::cast_slice(&vertices));
+    }
+
+    pub fn update_wave(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, verts: &[WaveVertex]) {
+        grow_vertex_buffer::<WaveVertex>(
+            device,
+            "Wave Buffer",
+            &mut self.wave_buffer,
+            &mut self.max_wave_verts,
+            verts.len(),
+        );
+        queue.write_buffer(&self.wave_buffer, 0, bytemuck::cast_slice(verts));
+    }
+
     pub fn render_points(
         &self,
         encoder: &mut wgpu::CommandEncoder,
         view: &wgpu::TextureView,
         num_points: u32,
         clear: bool,
+        blend: BlendMode,
     ) {
         let load_op = if clear {
             wgpu::LoadOp::Clear(wgpu::Color {
@@ -354,13 +1720,22 @@ impl QuantumRenderer {
                     store: wgpu::StoreOp::Store,
                 },
             })],
-            depth_stencil_attachment: None,
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &self.depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: if clear { wgpu::LoadOp::Clear(1.0) } else { wgpu::LoadOp::Load },
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
             timestamp_writes: None,
             occlusion_query_set: None,
         });
 
-        render_pass.set_pipeline(&self.point_pipeline);
+        let pipeline = &self.pipelines[&(PrimitiveKind::Point, blend)];
+        render_pass.set_pipeline(pipeline);
         render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        render_pass.set_bind_group(1, &self.sprite_bind_group, &[]);
         render_pass.set_vertex_buffer(0, self.quad_buffer.slice(..));
         render_pass.set_vertex_buffer(1, self.point_buffer.slice(..));
         render_pass.draw(0..6, 0..num_points);
@@ -372,6 +1747,7 @@ impl QuantumRenderer {
         view: &wgpu::TextureView,
         num_lines: u32,
         clear: bool,
+        blend: BlendMode,
     ) {
         let load_op = if clear {
             wgpu::LoadOp::Clear(wgpu::Color {
@@ -394,16 +1770,509 @@ impl QuantumRenderer {
                     store: wgpu::StoreOp::Store,
                 },
             })],
-            depth_stencil_attachment: None,
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &self.depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: if clear { wgpu::LoadOp::Clear(1.0) } else { wgpu::LoadOp::Load },
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
             timestamp_writes: None,
             occlusion_query_set: None,
         });
 
-        render_pass.set_pipeline(&self.line_pipeline);
+        let pipeline = &self.pipelines[&(PrimitiveKind::Line, blend)];
+        render_pass.set_pipeline(pipeline);
         render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
         render_pass.set_vertex_buffer(0, self.line_buffer.slice(..));
         render_pass.draw(0..(num_lines * 2), 0..1);
     }
+
+    pub fn render_faces(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        num_vertices: u32,
+        clear: bool,
+    ) {
+        let load_op = if clear {
+            wgpu::LoadOp::Clear(wgpu::Color {
+                r: 0.02,
+                g: 0.02,
+                b: 0.08,
+                a: 1.0,
+            })
+        } else {
+            wgpu::LoadOp::Load
+        };
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Face Render Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: load_op,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&self.face_pipeline);
+        render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.face_buffer.slice(..));
+        render_pass.draw(0..num_vertices, 0..1);
+    }
+
+    /// Draw a 1D wavefunction trace as a domain-colored line strip: hue comes
+    /// from `WaveVertex::phase`, brightness from `WaveVertex::value`
+    pub fn render_wave(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        num_verts: u32,
+        clear: bool,
+    ) {
+        let load_op = if clear {
+            wgpu::LoadOp::Clear(wgpu::Color {
+                r: 0.02,
+                g: 0.02,
+                b: 0.08,
+                a: 1.0,
+            })
+        } else {
+            wgpu::LoadOp::Load
+        };
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Wave Render Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: load_op,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&self.wave_pipeline);
+        render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.wave_buffer.slice(..));
+        render_pass.draw(0..num_verts, 0..1);
+    }
+
+    /// Draw `num_instances` copies of the shared unit sphere mesh, each
+    /// positioned, scaled, and colored by a `PointInstance` in `point_buffer`.
+    /// A volumetric, Lambert-shaded alternative to `render_points`'
+    /// billboards, e.g. for quarks/nucleons.
+    pub fn render_spheres(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        num_instances: u32,
+        clear: bool,
+    ) {
+        let load_op = if clear {
+            wgpu::LoadOp::Clear(wgpu::Color {
+                r: 0.02,
+                g: 0.02,
+                b: 0.08,
+                a: 1.0,
+            })
+        } else {
+            wgpu::LoadOp::Load
+        };
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Sphere Render Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: load_op,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &self.depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: if clear { wgpu::LoadOp::Clear(1.0) } else { wgpu::LoadOp::Load },
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&self.sphere_pipeline);
+        render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.sphere_vertex_buffer.slice(..));
+        render_pass.set_vertex_buffer(1, self.point_buffer.slice(..));
+        render_pass.set_index_buffer(self.sphere_index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+        render_pass.draw_indexed(0..self.num_sphere_indices, 0, 0..num_instances);
+    }
+
+    /// Bind a converted cubemap as the skybox background; subsequent
+    /// `render_skybox` calls draw it until a new one is set
+    pub fn set_environment(&mut self, device: &wgpu::Device, env: &EnvironmentMap) {
+        self.skybox_bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Skybox Bind Group"),
+            layout: &self.skybox_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&env.cubemap_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&env.sampler),
+                },
+            ],
+        }));
+    }
+
+    /// Replace the point sprite with a caller-supplied RGBA8 texture, e.g. a
+    /// custom glyph in place of the default Gaussian falloff blob. `rgba`
+    /// must contain `dims.0 * dims.1 * 4` bytes.
+    pub fn set_sprite_texture(&mut self, ctx: &GraphicsContext, rgba: &[u8], dims: (u32, u32)) {
+        let (width, height) = dims;
+        let texture = ctx.device.create_texture_with_data(
+            &ctx.queue,
+            &wgpu::TextureDescriptor {
+                label: Some("Point Sprite"),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8Unorm,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            rgba,
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        self.sprite_bind_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Sprite Bind Group"),
+            layout: &self.sprite_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.sprite_sampler),
+                },
+            ],
+        });
+
+        self.sprite_texture = texture;
+        self.sprite_view = view;
+    }
+
+    /// Draw the bound cubemap as a fullscreen background, reconstructing a
+    /// view ray per fragment from NDC via the camera's inverse
+    /// view-projection. A no-op until `set_environment` has been called.
+    pub fn render_skybox(&self, encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView, clear: bool) {
+        let Some(skybox_bind_group) = &self.skybox_bind_group else {
+            return;
+        };
+
+        let load_op = if clear {
+            wgpu::LoadOp::Clear(wgpu::Color {
+                r: 0.02,
+                g: 0.02,
+                b: 0.08,
+                a: 1.0,
+            })
+        } else {
+            wgpu::LoadOp::Load
+        };
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Skybox Render Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: load_op,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&self.skybox_pipeline);
+        render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        render_pass.set_bind_group(1, skybox_bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+
+    /// Render wave-packet points into the HDR offscreen target instead of
+    /// the swapchain, so peaks above 1.0 luminance survive to bloom rather
+    /// than clipping
+    pub fn render_points_hdr(&self, encoder: &mut wgpu::CommandEncoder, num_points: u32, clear: bool) {
+        let load_op = if clear {
+            wgpu::LoadOp::Clear(wgpu::Color {
+                r: 0.02,
+                g: 0.02,
+                b: 0.08,
+                a: 1.0,
+            })
+        } else {
+            wgpu::LoadOp::Load
+        };
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("HDR Point Render Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &self.hdr_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: load_op,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        render_pass.set_pipeline(&self.point_pipeline_hdr);
+        render_pass.set_bind_group(0, &self.camera_bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.quad_buffer.slice(..));
+        render_pass.set_vertex_buffer(1, self.point_buffer.slice(..));
+        render_pass.draw(0..6, 0..num_points);
+    }
+
+    /// Set the exposure multiplier the tonemap pass scales bloomed radiance
+    /// by before the Reinhard curve
+    pub fn set_exposure(&self, queue: &wgpu::Queue, exposure: f32) {
+        let uniform = ExposureUniform {
+            exposure,
+            _padding: [0.0; 3],
+        };
+        queue.write_buffer(&self.exposure_buffer, 0, bytemuck::cast_slice(&[uniform]));
+    }
+
+    /// Resolve the HDR scene in `hdr_view` onto `view`: bright-pass
+    /// threshold, two-pass separable Gaussian blur, then additive combine +
+    /// Reinhard tonemap
+    pub fn composite_bloom(&self, encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Bright Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.bloom_view_a,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.bright_pipeline);
+            pass.set_bind_group(0, &self.bright_bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Blur Pass Horizontal"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.bloom_view_b,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.blur_pipeline);
+            pass.set_bind_group(0, &self.blur_bind_group_h, &[]);
+            pass.draw(0..3, 0..1);
+        }
+
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Blur Pass Vertical"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.bloom_view_a,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.blur_pipeline);
+            pass.set_bind_group(0, &self.blur_bind_group_v, &[]);
+            pass.draw(0..3, 0..1);
+        }
+
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Tonemap Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(&self.tonemap_pipeline);
+            pass.set_bind_group(0, &self.tonemap_bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        }
+    }
+
+    /// Recreate the HDR/bloom textures and their bind groups to match a new
+    /// swapchain size
+    /// Recreate the depth buffer to match a new swapchain size
+    pub fn resize(&mut self, ctx: &GraphicsContext, width: u32, height: u32) {
+        let (depth_texture, depth_view) = create_depth_texture(&ctx.device, width, height);
+        self.depth_texture = depth_texture;
+        self.depth_view = depth_view;
+    }
+
+    pub fn resize_hdr(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
+        let (hdr_texture, hdr_view) = create_hdr_target(device, width, height, self.hdr_format, "HDR");
+        let (bloom_texture_a, bloom_view_a) =
+            create_hdr_target(device, width, height, self.hdr_format, "Bloom A");
+        let (bloom_texture_b, bloom_view_b) =
+            create_hdr_target(device, width, height, self.hdr_format, "Bloom B");
+
+        self.bright_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Bright Pass Bind Group"),
+            layout: &self.single_texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&hdr_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.hdr_sampler),
+                },
+            ],
+        });
+
+        let texel_size = [1.0 / width.max(1) as f32, 1.0 / height.max(1) as f32];
+        queue.write_buffer(
+            &self.blur_params_h,
+            0,
+            bytemuck::cast_slice(&[BlurParams {
+                direction: [1.0, 0.0],
+                texel_size,
+            }]),
+        );
+        queue.write_buffer(
+            &self.blur_params_v,
+            0,
+            bytemuck::cast_slice(&[BlurParams {
+                direction: [0.0, 1.0],
+                texel_size,
+            }]),
+        );
+
+        self.blur_bind_group_h = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blur Bind Group Horizontal"),
+            layout: &self.blur_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&bloom_view_a),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.hdr_sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: self.blur_params_h.as_entire_binding(),
+                },
+            ],
+        });
+        self.blur_bind_group_v = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Blur Bind Group Vertical"),
+            layout: &self.blur_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&bloom_view_b),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.hdr_sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: self.blur_params_v.as_entire_binding(),
+                },
+            ],
+        });
+
+        self.tonemap_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Tonemap Bind Group"),
+            layout: &self.tonemap_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&hdr_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&bloom_view_a),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&self.hdr_sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: self.exposure_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        self.hdr_texture = hdr_texture;
+        self.hdr_view = hdr_view;
+        self.bloom_texture_a = bloom_texture_a;
+        self.bloom_view_a = bloom_view_a;
+        self.bloom_texture_b = bloom_texture_b;
+        self.bloom_view_b = bloom_view_b;
+    }
 }
 
 /// Convert orbital data to point instances
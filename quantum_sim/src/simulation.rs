@@ -0,0 +1,58 @@
+//! Common interface for runtime-switchable scenes
+//!
+//! Lets a single `App` host any simulation module behind a `Box<dyn
+//! Simulation>`, so scenes can be swapped at runtime (via `main_scenes`)
+//! instead of each module only being reachable through its own dedicated
+//! binary.
+
+use crate::equations_ui::Equation;
+use crate::renderer::PointInstance;
+use glam::Vec3;
+use winit::keyboard::KeyCode;
+
+/// A transition a running scene can request of the switcher, polled once per
+/// frame alongside `step`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SceneAction {
+    /// Stay on the current scene
+    #[default]
+    None,
+    /// Jump to the scene registered under this `SceneEntry::name`
+    GoTo(&'static str),
+}
+
+/// A simulation module that can be hosted by the scene switcher
+pub trait Simulation {
+    /// Advance the simulation by `dt` seconds
+    fn step(&mut self, dt: f32);
+
+    /// Display name shown in the scene picker and window chrome
+    fn title(&self) -> &str;
+
+    /// Equations and variable glossary to feed into `draw_equations_sidebar`
+    fn equations(&self) -> (&[Equation], &[(&str, &str)]);
+
+    /// Point instances to hand to `QuantumRenderer::update_points`
+    fn points(&self) -> Vec<PointInstance>;
+
+    /// Line segments to hand to `QuantumRenderer::update_lines`
+    fn lines(&self) -> Vec<(Vec3, Vec3, [f32; 4])>;
+
+    /// One-line status text for the top status bar
+    fn status(&self) -> String;
+
+    /// Handle a scene-specific key press (already filtered to key-down)
+    fn handle_key(&mut self, key: KeyCode);
+
+    /// Reset the scene to its initial state
+    fn reset(&mut self);
+
+    /// Polled once per frame after `step`; lets a scene drive itself to
+    /// another one by name once some internal condition is met (finishing a
+    /// stage animation, reaching a terminal state, ...) instead of requiring
+    /// the user to switch manually. Most scenes never transition on their
+    /// own, so this defaults to `SceneAction::None`.
+    fn poll_action(&mut self) -> SceneAction {
+        SceneAction::None
+    }
+}
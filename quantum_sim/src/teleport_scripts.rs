@@ -0,0 +1,241 @@
+//! Scriptable teleportation protocols via an embedded Rhai scene API
+//!
+//! Mirrors the gravity_sim `scene_scripts` module: `.rhai` files live under
+//! a `protocols/` directory next to the executable and describe a
+//! protocol's stages and selectable initial states without touching qubit
+//! amplitudes directly — the actual state evolution stays native Rust for
+//! correctness, the script only describes presentation, so educators can
+//! author new protocols (entanglement swapping, superdense coding
+//! walkthroughs, …) without recompiling.
+//!
+//! Each script exposes up to four functions:
+//! - `stages()` -> array of `#{ name, description, duration }` maps, one
+//!   per protocol stage, in order.
+//! - `initial_states()` -> array of `#{ label, theta, phi }` maps bound to
+//!   keys 1-4 in order (Bloch sphere angles for the state to teleport).
+//! - `equations()` (optional) -> array of `#{ name, formula, description }`
+//!   maps shown in the sidebar instead of the built-in teleportation table.
+//! - `bloch_vector(stage, qubit, t)` (optional) -> a `Vec3` giving the
+//!   qubit at index `qubit`'s displayed Bloch vector at stage index
+//!   `stage` and stage-local time `t` (0..1), for protocols that don't fit
+//!   the built-in stage machine.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use glam::Vec3;
+use rhai::{Engine, Scope, AST};
+use thiserror::Error;
+
+use crate::equations_ui::Equation;
+
+#[derive(Debug, Error)]
+pub enum ProtocolScriptError {
+    #[error("failed to read protocol script {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse protocol script {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: Box<rhai::ParseError>,
+    },
+}
+
+/// One stage of a scripted protocol
+#[derive(Debug, Clone)]
+pub struct StageSpec {
+    pub name: String,
+    pub description: String,
+    pub duration: f32,
+}
+
+/// One selectable initial state, bound to keys 1-4 in declaration order
+#[derive(Debug, Clone)]
+pub struct InitialStateSpec {
+    pub label: String,
+    pub theta: f32,
+    pub phi: f32,
+}
+
+/// A handle identifying a qubit in the protocol, passed into
+/// `bloch_vector()` so scripts can branch on which qubit they're
+/// positioning without hardcoding index numbers
+#[derive(Debug, Clone)]
+struct QubitHandle {
+    index: i64,
+    label: String,
+}
+
+impl QubitHandle {
+    fn index(&mut self) -> i64 {
+        self.index
+    }
+
+    fn label(&mut self) -> String {
+        self.label.clone()
+    }
+}
+
+fn register_rhai_types(engine: &mut Engine) {
+    engine
+        .register_type_with_name::<Vec3>("Vec3")
+        .register_fn("vec3", |x: f64, y: f64, z: f64| Vec3::new(x as f32, y as f32, z as f32))
+        .register_get("x", |v: &mut Vec3| v.x as f64)
+        .register_get("y", |v: &mut Vec3| v.y as f64)
+        .register_get("z", |v: &mut Vec3| v.z as f64);
+
+    engine
+        .register_type_with_name::<QubitHandle>("Qubit")
+        .register_get("index", QubitHandle::index)
+        .register_get("label", QubitHandle::label);
+}
+
+/// A loaded `.rhai` protocol script, ready to describe a teleportation-style
+/// protocol's stages, initial states, and (optionally) its own equations
+/// and per-qubit Bloch vector animation
+pub struct ProtocolScript {
+    pub name: String,
+    pub path: PathBuf,
+    engine: Engine,
+    ast: AST,
+}
+
+impl ProtocolScript {
+    pub fn load(path: &Path) -> Result<Self, ProtocolScriptError> {
+        let source = fs::read_to_string(path).map_err(|source| ProtocolScriptError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let mut engine = Engine::new();
+        register_rhai_types(&mut engine);
+
+        let ast = engine.compile(&source).map_err(|source| ProtocolScriptError::Parse {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })?;
+
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("protocol")
+            .to_string();
+
+        Ok(Self {
+            name,
+            path: path.to_path_buf(),
+            engine,
+            ast,
+        })
+    }
+
+    /// Run the script's `stages()` function; returns an empty list if the
+    /// script doesn't define one
+    pub fn stages(&self) -> Vec<StageSpec> {
+        let mut scope = Scope::new();
+        let Ok(stages) = self.engine.call_fn::<rhai::Array>(&mut scope, &self.ast, "stages", ()) else {
+            return Vec::new();
+        };
+
+        stages
+            .into_iter()
+            .filter_map(|entry| entry.try_cast::<rhai::Map>())
+            .map(|map| StageSpec {
+                name: map.get("name").and_then(|v| v.clone().into_string().ok()).unwrap_or_default(),
+                description: map
+                    .get("description")
+                    .and_then(|v| v.clone().into_string().ok())
+                    .unwrap_or_default(),
+                duration: map.get("duration").and_then(|v| v.as_float().ok()).unwrap_or(1.0) as f32,
+            })
+            .collect()
+    }
+
+    /// Run the script's `initial_states()` function; returns an empty list
+    /// if the script doesn't define one
+    pub fn initial_states(&self) -> Vec<InitialStateSpec> {
+        let mut scope = Scope::new();
+        let Ok(states) =
+            self.engine.call_fn::<rhai::Array>(&mut scope, &self.ast, "initial_states", ())
+        else {
+            return Vec::new();
+        };
+
+        states
+            .into_iter()
+            .filter_map(|entry| entry.try_cast::<rhai::Map>())
+            .map(|map| InitialStateSpec {
+                label: map.get("label").and_then(|v| v.clone().into_string().ok()).unwrap_or_default(),
+                theta: map.get("theta").and_then(|v| v.as_float().ok()).unwrap_or(0.0) as f32,
+                phi: map.get("phi").and_then(|v| v.as_float().ok()).unwrap_or(0.0) as f32,
+            })
+            .collect()
+    }
+
+    /// Run the script's `equations()` function, if it defines one. Each
+    /// string is leaked to `'static` since a `ProtocolScript` is loaded
+    /// once at startup and lives for the rest of the process, matching the
+    /// lifetime contract the built-in `Equation` tables already rely on.
+    pub fn equations(&self) -> Option<Vec<Equation>> {
+        let mut scope = Scope::new();
+        let entries = self.engine.call_fn::<rhai::Array>(&mut scope, &self.ast, "equations", ()).ok()?;
+
+        Some(
+            entries
+                .into_iter()
+                .filter_map(|entry| entry.try_cast::<rhai::Map>())
+                .map(|map| Equation {
+                    name: leak_field(&map, "name"),
+                    formula: leak_field(&map, "formula"),
+                    description: leak_field(&map, "description"),
+                })
+                .collect(),
+        )
+    }
+
+    /// Ask the script's `bloch_vector(stage, qubit, t)` function for a
+    /// displayed Bloch vector, if it defines one
+    pub fn bloch_vector(&self, stage: usize, qubit_index: usize, qubit_label: &str, t: f32) -> Option<Vec3> {
+        let handle = QubitHandle {
+            index: qubit_index as i64,
+            label: qubit_label.to_string(),
+        };
+
+        let mut scope = Scope::new();
+        self.engine
+            .call_fn::<Vec3>(&mut scope, &self.ast, "bloch_vector", (stage as i64, handle, t as f64))
+            .ok()
+    }
+}
+
+fn leak_field(map: &rhai::Map, key: &str) -> &'static str {
+    let value = map.get(key).and_then(|v| v.clone().into_string().ok()).unwrap_or_default();
+    Box::leak(value.into_boxed_str())
+}
+
+/// Discover `.rhai` scripts in `dir`, skipping (and logging) any that fail
+/// to parse rather than aborting the whole scan
+pub fn discover_protocols(dir: &Path) -> Vec<ProtocolScript> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut scripts = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("rhai") {
+            continue;
+        }
+        match ProtocolScript::load(&path) {
+            Ok(script) => scripts.push(script),
+            Err(err) => log::warn!("skipping protocol script: {err}"),
+        }
+    }
+
+    scripts.sort_by(|a, b| a.name.cmp(&b.name));
+    scripts
+}
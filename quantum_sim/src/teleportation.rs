@@ -28,6 +28,44 @@ pub enum TeleportationStage {
     Complete,
 }
 
+/// A Pauli operator basis used to prepare and verify cardinal eigenstates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauliBasis {
+    X,
+    Y,
+    Z,
+}
+
+/// A gate applied to `ThreeQubit` during the protocol, recorded so the exact
+/// circuit executed can be replayed as OpenQASM via `to_qasm`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateOp {
+    /// Hadamard on the given qubit
+    H(usize),
+    /// Controlled-X: (control, target)
+    Cx(usize, usize),
+    /// Controlled-Z: (control, target)
+    Cz(usize, usize),
+    /// Measure the given qubit into the given single-bit classical register
+    /// (0 → `c0`, 1 → `c1`)
+    Measure(usize, usize),
+    /// Classically-controlled X on the given qubit, conditioned on the given
+    /// single-bit classical register reading 1
+    IfX(usize, usize),
+    /// Classically-controlled Z on the given qubit, conditioned on the given
+    /// single-bit classical register reading 1
+    IfZ(usize, usize),
+}
+
+/// How Bob's correction is applied: by classically measuring Alice's qubits
+/// and conditionally flipping, or coherently via the deferred-measurement
+/// principle (controlled gates conditioned on Alice's qubits unitarily)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TeleportationMode {
+    Measured,
+    Coherent,
+}
+
 /// Visual representation of a qubit for rendering
 #[derive(Debug, Clone)]
 pub struct QubitVisual {
@@ -104,6 +142,26 @@ pub struct TeleportationSimulation {
 
     /// Fidelity of teleportation
     pub fidelity: f32,
+
+    /// The Pauli basis and expected eigenvalue the message qubit was
+    /// prepared in via `set_pauli_eigenstate`, if any; used by `verify_in_basis`
+    pub pauli_target: Option<(PauliBasis, bool)>,
+
+    /// Whether Bob's correction is measured classically or applied coherently
+    pub mode: TeleportationMode,
+
+    /// Set once the coherent CNOT/CZ corrections have been applied, so Bob's
+    /// qubit can be read back without a classical measurement outcome
+    coherent_applied: bool,
+
+    /// Depolarizing-channel noise parameter p ∈ [0,1] applied to Bob's half
+    /// of the Bell pair, modeling a noisy entanglement-distribution channel
+    pub channel_noise: f32,
+
+    /// Every H/CNOT/CZ/measurement/correction gate applied to `three_qubit`
+    /// since the last reset, in execution order, so the exact circuit this
+    /// run performed can be exported via `to_qasm`
+    pub gate_log: Vec<GateOp>,
 }
 
 impl TeleportationSimulation {
@@ -147,6 +205,11 @@ impl TeleportationSimulation {
             time: 0.0,
             stage_progress: 0.0,
             fidelity: 0.0,
+            pauli_target: None,
+            mode: TeleportationMode::Measured,
+            coherent_applied: false,
+            channel_noise: 0.0,
+            gate_log: Vec::new(),
         };
 
         sim.initialize_state();
@@ -174,17 +237,63 @@ impl TeleportationSimulation {
         ];
 
         // Apply H to qubit 1: |0⟩ → |+⟩
-        self.three_qubit.hadamard(1);
+        self.record_h(1);
 
         // Apply CNOT(1,2) to create Bell pair between qubits 1 and 2
-        self.three_qubit.cnot(1, 2);
+        self.record_cnot(1, 2);
 
         // Now state is: α(|000⟩+|011⟩)/√2 + β(|100⟩+|111⟩)/√2
         // = (α|0⟩+β|1⟩) ⊗ (|00⟩+|11⟩)/√2
 
+        self.apply_channel_noise();
+
         self.update_visuals();
     }
 
+    /// Apply a Hadamard to `three_qubit` and record it in `gate_log`
+    fn record_h(&mut self, qubit: usize) {
+        self.three_qubit.hadamard(qubit);
+        self.gate_log.push(GateOp::H(qubit));
+    }
+
+    /// Apply a CNOT to `three_qubit` and record it in `gate_log`
+    fn record_cnot(&mut self, control: usize, target: usize) {
+        self.three_qubit.cnot(control, target);
+        self.gate_log.push(GateOp::Cx(control, target));
+    }
+
+    /// Apply a CZ to `three_qubit` and record it in `gate_log`
+    fn record_cz(&mut self, control: usize, target: usize) {
+        self.three_qubit.cz(control, target);
+        self.gate_log.push(GateOp::Cz(control, target));
+    }
+
+    /// Apply a single-qubit depolarizing channel to qubit 2 (Bob's half of
+    /// the Bell pair), modeling a noisy entanglement-distribution channel.
+    /// Unraveled stochastically: with probability `channel_noise` one of
+    /// {X, Y, Z} is applied uniformly at random (each with probability p/4);
+    /// otherwise (probability 1 − 3p/4) the state is left alone. This single
+    /// random trajectory reproduces the depolarizing channel's average
+    /// effect on fidelity across repeated runs, without needing a full
+    /// density-matrix representation of `ThreeQubit`.
+    fn apply_channel_noise(&mut self) {
+        let p = self.channel_noise;
+        if p <= 0.0 {
+            return;
+        }
+
+        let mut rng = rand::thread_rng();
+        let r: f32 = rng.gen();
+        if r < p / 4.0 {
+            self.three_qubit.pauli_x(2);
+        } else if r < p / 2.0 {
+            self.three_qubit.pauli_y(2);
+        } else if r < 3.0 * p / 4.0 {
+            self.three_qubit.pauli_z(2);
+        }
+        // else: identity, with probability 1 - 3p/4
+    }
+
     /// Advance to next stage of the protocol
     pub fn next_stage(&mut self) {
         self.stage_progress = 0.0;
@@ -192,17 +301,21 @@ impl TeleportationSimulation {
         match self.stage {
             TeleportationStage::Initial => {
                 // Apply CNOT with qubit 0 as control, qubit 1 as target
-                self.three_qubit.cnot(0, 1);
+                self.record_cnot(0, 1);
                 self.stage = TeleportationStage::AliceCNOT;
             }
             TeleportationStage::AliceCNOT => {
                 // Apply Hadamard to qubit 0
-                self.three_qubit.hadamard(0);
+                self.record_h(0);
                 self.stage = TeleportationStage::AliceHadamard;
             }
             TeleportationStage::AliceHadamard => {
-                // Measure qubits 0 and 1
-                self.perform_measurement();
+                // Measure qubits 0 and 1, or apply the equivalent corrections
+                // coherently per the deferred-measurement principle
+                match self.mode {
+                    TeleportationMode::Measured => self.perform_measurement(),
+                    TeleportationMode::Coherent => self.apply_coherent_corrections(),
+                }
                 self.stage = TeleportationStage::AliceMeasure;
             }
             TeleportationStage::AliceMeasure => {
@@ -251,6 +364,8 @@ impl TeleportationSimulation {
         };
 
         self.alice_results = Some((m0, m1));
+        self.gate_log.push(GateOp::Measure(0, 0));
+        self.gate_log.push(GateOp::Measure(1, 1));
 
         // Update visual states
         self.qubits[0].measured = true;
@@ -273,10 +388,29 @@ impl TeleportationSimulation {
         self.three_qubit.normalize();
     }
 
+    /// Apply Bob's corrections coherently (deferred measurement): CNOT(1,2)
+    /// then CZ(0,2), conditioned unitarily on Alice's qubits instead of on a
+    /// classical measurement outcome. This leaves Bob's qubit in the pure
+    /// teleported state |ψ⟩, unentangled from Alice, without ever collapsing
+    /// qubits 0 and 1.
+    fn apply_coherent_corrections(&mut self) {
+        self.record_cnot(1, 2);
+        self.record_cz(0, 2);
+        self.coherent_applied = true;
+    }
+
     /// Apply Bob's corrections based on Alice's measurement
     fn apply_corrections(&mut self) {
+        if self.mode == TeleportationMode::Coherent {
+            // Already applied coherently in apply_coherent_corrections
+            return;
+        }
+
         if let Some((m0, m1)) = self.alice_results {
-            // If m1 = 1, apply X to Bob's qubit
+            // Classically-controlled X on Bob's qubit, conditioned on c1==1;
+            // recorded unconditionally since it's part of the fixed circuit,
+            // even though it's a no-op here when m1 is false
+            self.gate_log.push(GateOp::IfX(1, 2));
             if m1 {
                 // X on qubit 2: swap |xy0⟩ ↔ |xy1⟩
                 for i in 0..4 {
@@ -285,7 +419,8 @@ impl TeleportationSimulation {
                 }
             }
 
-            // If m0 = 1, apply Z to Bob's qubit
+            // Classically-controlled Z on Bob's qubit, conditioned on c0==1
+            self.gate_log.push(GateOp::IfZ(0, 2));
             if m0 {
                 // Z on qubit 2: |xy1⟩ → -|xy1⟩
                 for i in 0..4 {
@@ -296,31 +431,43 @@ impl TeleportationSimulation {
         }
     }
 
+    /// Extract Bob's reconstructed qubit from the three-qubit state
+    ///
+    /// In `Measured` mode, qubits 0,1 are measured, so Bob's state is the
+    /// remaining superposition: for an |ab⟩ measurement outcome, Bob's state
+    /// lives in the amplitudes with that prefix. In `Coherent` mode, the
+    /// CNOT/CZ corrections leave Alice's qubits in a product state
+    /// unentangled from Bob, so every prefix holds the same |ψ⟩ and the
+    /// |00x⟩ prefix can be read directly.
+    fn reconstructed_bob_qubit(&self) -> Option<Qubit> {
+        let offset = match self.mode {
+            TeleportationMode::Measured => {
+                let (m0, m1) = self.alice_results?;
+                (if m0 { 4 } else { 0 }) + (if m1 { 2 } else { 0 })
+            }
+            TeleportationMode::Coherent => {
+                if !self.coherent_applied {
+                    return None;
+                }
+                0
+            }
+        };
+        Some(Qubit {
+            alpha: self.three_qubit.amplitudes[offset],     // |ab0⟩
+            beta: self.three_qubit.amplitudes[offset + 1],  // |ab1⟩
+        })
+    }
+
     /// Compute teleportation fidelity
     fn compute_fidelity(&mut self) {
         // Bob's final state should match the original state to teleport
-        // Extract Bob's qubit state from the three-qubit state
-
-        // Since qubits 0,1 are measured, Bob's state is the remaining superposition
-        // For |ab⟩ measurement outcome, Bob's state is in amplitudes with that prefix
-
-        if let Some((m0, m1)) = self.alice_results {
-            let offset = (if m0 { 4 } else { 0 }) + (if m1 { 2 } else { 0 });
-
-            // Bob's qubit coefficients
-            let bob_alpha = self.three_qubit.amplitudes[offset];     // |ab0⟩
-            let bob_beta = self.three_qubit.amplitudes[offset + 1];  // |ab1⟩
-
+        if let Some(bob_qubit) = self.reconstructed_bob_qubit() {
             // Fidelity = |⟨ψ_original|ψ_bob⟩|²
-            let overlap = self.state_to_teleport.alpha.conj() * bob_alpha
-                + self.state_to_teleport.beta.conj() * bob_beta;
+            let overlap = self.state_to_teleport.alpha.conj() * bob_qubit.alpha
+                + self.state_to_teleport.beta.conj() * bob_qubit.beta;
             self.fidelity = overlap.norm_sq();
 
             // Update Bob's visual
-            let bob_qubit = Qubit {
-                alpha: bob_alpha,
-                beta: bob_beta,
-            };
             let (x, y, z) = bob_qubit.bloch_vector();
             self.qubits[2].bloch_vector = Vec3::new(x, y, z);
             self.qubits[2].color = self.qubits[0].color; // Same color as original
@@ -336,7 +483,7 @@ impl TeleportationSimulation {
                 self.entanglement_links = vec![EntanglementLink {
                     qubit_a: 1,
                     qubit_b: 2,
-                    strength: 1.0,
+                    strength: self.pairwise_concurrence(1, 2),
                     color: [1.0, 0.5, 1.0, 0.8],
                 }];
             }
@@ -346,19 +493,19 @@ impl TeleportationSimulation {
                     EntanglementLink {
                         qubit_a: 0,
                         qubit_b: 1,
-                        strength: 0.7,
+                        strength: self.pairwise_concurrence(0, 1),
                         color: [0.5, 1.0, 0.5, 0.6],
                     },
                     EntanglementLink {
                         qubit_a: 1,
                         qubit_b: 2,
-                        strength: 0.7,
+                        strength: self.pairwise_concurrence(1, 2),
                         color: [1.0, 0.5, 1.0, 0.6],
                     },
                     EntanglementLink {
                         qubit_a: 0,
                         qubit_b: 2,
-                        strength: 0.5,
+                        strength: self.pairwise_concurrence(0, 2),
                         color: [0.5, 0.5, 1.0, 0.4],
                     },
                 ];
@@ -392,6 +539,8 @@ impl TeleportationSimulation {
         self.fidelity = 0.0;
         self.time = 0.0;
         self.stage_progress = 0.0;
+        self.coherent_applied = false;
+        self.gate_log.clear();
 
         // Reset visual states
         for qubit in &mut self.qubits {
@@ -410,25 +559,165 @@ impl TeleportationSimulation {
     /// Set a new state to teleport
     pub fn set_state_to_teleport(&mut self, theta: f32, phi: f32) {
         self.state_to_teleport = Qubit::from_bloch(theta, phi);
+        self.pauli_target = None;
+        self.reset();
+    }
+
+    /// Switch between measurement-based correction and the coherent
+    /// (deferred-measurement) protocol variant, then reset so the two can be
+    /// compared on the same prepared state.
+    pub fn set_mode(&mut self, mode: TeleportationMode) {
+        self.mode = mode;
+        self.reset();
+    }
+
+    /// Set the depolarizing-channel noise parameter p ∈ [0,1] applied to the
+    /// Bell pair's Bob qubit, then reset. This caps the achievable
+    /// teleportation fidelity at (2 + F_entanglement)/3.
+    pub fn set_channel_noise(&mut self, p: f32) {
+        self.channel_noise = p.clamp(0.0, 1.0);
+        self.reset();
+    }
+
+    /// Prepare the message qubit as the ±1 eigenstate of σx, σy, or σz
+    /// (`eigenvalue` true = +1, false = -1), so `verify_in_basis` can later
+    /// confirm the teleported state reproduces the same eigenvalue.
+    pub fn set_pauli_eigenstate(&mut self, basis: PauliBasis, eigenvalue: bool) {
+        let (theta, phi) = match basis {
+            PauliBasis::Z => (if eigenvalue { 0.0 } else { PI }, 0.0),
+            PauliBasis::X => (PI / 2.0, if eigenvalue { 0.0 } else { PI }),
+            PauliBasis::Y => (PI / 2.0, if eigenvalue { PI / 2.0 } else { -PI / 2.0 }),
+        };
+        self.state_to_teleport = Qubit::from_bloch(theta, phi);
+        self.pauli_target = Some((basis, eigenvalue));
         self.reset();
     }
 
+    /// Confirm Bob's reconstructed qubit deterministically yields the
+    /// eigenvalue prepared via `set_pauli_eigenstate`, verifying that the
+    /// X-if-m1/Z-if-m0 correction mapping is correct. Returns `false` if no
+    /// Pauli eigenstate was prepared or the protocol hasn't reached
+    /// `BobCorrection`/`Complete` yet.
+    pub fn verify_in_basis(&self) -> bool {
+        let Some((basis, eigenvalue)) = self.pauli_target else {
+            return false;
+        };
+        if !matches!(
+            self.stage,
+            TeleportationStage::BobCorrection | TeleportationStage::Complete
+        ) {
+            return false;
+        }
+        let Some(bob_qubit) = self.reconstructed_bob_qubit() else {
+            return false;
+        };
+
+        let (x, y, z) = bob_qubit.bloch_vector();
+        let expectation = match basis {
+            PauliBasis::X => x,
+            PauliBasis::Y => y,
+            PauliBasis::Z => z,
+        };
+        let expected_sign = if eigenvalue { 1.0 } else { -1.0 };
+        expectation * expected_sign > 0.9
+    }
+
+    /// Wootters concurrence of qubits `a` and `b`, traced out of the full
+    /// three-qubit state. 0 means separable, 1 means maximally entangled.
+    pub fn pairwise_concurrence(&self, a: usize, b: usize) -> f32 {
+        self.three_qubit.pairwise_concurrence(a, b)
+    }
+
+    /// Export the circuit this run actually executed (`gate_log`) as
+    /// OpenQASM 2.0, so it can be replayed on an external simulator or
+    /// hardware instead of staying an opaque amplitude manipulation.
+    pub fn to_qasm(&self) -> String {
+        let mut qasm = String::new();
+        qasm.push_str("OPENQASM 2.0;\n");
+        qasm.push_str("include \"qelib1.inc\";\n");
+        qasm.push_str("qreg q[3];\n");
+        qasm.push_str("creg c0[1];\n");
+        qasm.push_str("creg c1[1];\n");
+
+        for op in &self.gate_log {
+            match *op {
+                GateOp::H(q) => qasm.push_str(&format!("h q[{q}];\n")),
+                GateOp::Cx(control, target) => {
+                    qasm.push_str(&format!("cx q[{control}],q[{target}];\n"))
+                }
+                GateOp::Cz(control, target) => {
+                    qasm.push_str(&format!("cz q[{control}],q[{target}];\n"))
+                }
+                GateOp::Measure(qubit, creg) => {
+                    qasm.push_str(&format!("measure q[{qubit}] -> c{creg}[0];\n"))
+                }
+                GateOp::IfX(creg, qubit) => {
+                    qasm.push_str(&format!("if(c{creg}==1) x q[{qubit}];\n"))
+                }
+                GateOp::IfZ(creg, qubit) => {
+                    qasm.push_str(&format!("if(c{creg}==1) z q[{qubit}];\n"))
+                }
+            }
+        }
+
+        qasm
+    }
+
+    /// Run the full protocol `trials` times on Haar-random single-qubit
+    /// states, letting `perform_measurement` pick a random Bell-measurement
+    /// outcome each time, and return the (mean, standard deviation) of the
+    /// resulting `fidelity`. A mean near 1 regardless of `channel_noise`
+    /// being zero confirms the protocol is correct independent of both the
+    /// input state and the measurement branch taken, not just for the one
+    /// fixed demo state.
+    pub fn sample_average_fidelity(&mut self, trials: usize) -> (f32, f32) {
+        if trials == 0 {
+            return (0.0, 0.0);
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut fidelities = Vec::with_capacity(trials);
+
+        for _ in 0..trials {
+            let cos_theta = rng.gen_range(-1.0f32..1.0);
+            let theta = cos_theta.acos();
+            let phi = rng.gen_range(0.0..2.0 * PI);
+            self.set_state_to_teleport(theta, phi);
+
+            while self.stage != TeleportationStage::Complete {
+                self.next_stage();
+            }
+
+            fidelities.push(self.fidelity);
+        }
+
+        let mean = fidelities.iter().sum::<f32>() / trials as f32;
+        let variance = fidelities.iter().map(|f| (f - mean).powi(2)).sum::<f32>() / trials as f32;
+        (mean, variance.sqrt())
+    }
+
     /// Get description of current stage
     pub fn stage_description(&self) -> &'static str {
-        match self.stage {
-            TeleportationStage::Initial =>
+        match (self.stage, self.mode) {
+            (TeleportationStage::Initial, _) =>
                 "Initial: Alice has |ψ⟩, shares Bell pair |Φ+⟩ with Bob",
-            TeleportationStage::AliceCNOT =>
+            (TeleportationStage::AliceCNOT, _) =>
                 "Alice applies CNOT between her qubits",
-            TeleportationStage::AliceHadamard =>
+            (TeleportationStage::AliceHadamard, _) =>
                 "Alice applies Hadamard to qubit |ψ⟩",
-            TeleportationStage::AliceMeasure =>
+            (TeleportationStage::AliceMeasure, TeleportationMode::Measured) =>
                 "Alice measures both her qubits",
-            TeleportationStage::ClassicalChannel =>
+            (TeleportationStage::AliceMeasure, TeleportationMode::Coherent) =>
+                "Deferred measurement: CNOT(1,2) and CZ(0,2) applied coherently instead",
+            (TeleportationStage::ClassicalChannel, TeleportationMode::Measured) =>
                 "Alice sends measurement results to Bob (classical)",
-            TeleportationStage::BobCorrection =>
+            (TeleportationStage::ClassicalChannel, TeleportationMode::Coherent) =>
+                "No classical bits needed — Bob's correction was already applied unitarily",
+            (TeleportationStage::BobCorrection, TeleportationMode::Measured) =>
                 "Bob applies corrections based on Alice's results",
-            TeleportationStage::Complete =>
+            (TeleportationStage::BobCorrection, TeleportationMode::Coherent) =>
+                "Bob already holds |ψ⟩; measuring Alice's qubits now would just reveal |+⟩,|+⟩",
+            (TeleportationStage::Complete, _) =>
                 "Teleportation complete! Bob now has |ψ⟩",
         }
     }
@@ -3,13 +3,24 @@
 //! Simulates a wave packet encountering a potential barrier,
 //! demonstrating the quantum mechanical phenomenon of barrier penetration.
 
-use crate::wavefunction::{Complex, Wavefunction1D};
+use crate::wavefunction::{fft, Complex, Wavefunction1D};
 
 /// Reduced Planck constant (scaled for visualization)
 const HBAR: f32 = 1.0;
 /// Electron mass (scaled)
 const M_E: f32 = 1.0;
 
+/// Which scheme `TunnelingSimulation::step` uses to advance the kinetic term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Propagator {
+    /// Split-operator method: exact in momentum space via FFT, unconditionally
+    /// stable and norm-conserving regardless of `dt`
+    SplitStepFourier,
+    /// Crank-Nicolson-like finite-difference Laplacian kept around for
+    /// comparison; numerically disperses and needs a very small `dt`
+    FiniteDifference,
+}
+
 /// Potential barrier types
 #[derive(Debug, Clone, Copy)]
 pub enum Barrier {
@@ -50,6 +61,20 @@ impl Barrier {
     }
 }
 
+/// A sampled reading at an arbitrary world-space x, returned by
+/// [`TunnelingSimulation::probe`] for the click-to-probe measurement marker
+#[derive(Debug, Clone, Copy)]
+pub struct ProbeReading {
+    /// The grid x-coordinate the probe snapped to
+    pub x: f32,
+    /// Probability density `|psi|^2` at this x
+    pub density: f32,
+    /// Potential energy at this x
+    pub potential: f32,
+    /// Fraction of total probability already transmitted past this x
+    pub transmission_contribution: f32,
+}
+
 /// Tunneling simulation using split-operator method
 pub struct TunnelingSimulation {
     /// Wavefunction on spatial grid
@@ -58,6 +83,12 @@ pub struct TunnelingSimulation {
     pub barrier: Barrier,
     /// Precomputed potential values
     potential: Vec<f32>,
+    /// Precomputed complex absorbing potential (CAP) damping rate per grid
+    /// point: `eta * (d/width)^2` inside the absorbing layers at each edge,
+    /// zero in the interior. Folded into the potential half-step as a real
+    /// decay factor so outgoing flux is smoothly absorbed instead of
+    /// reflecting at the domain boundary.
+    damping: Vec<f32>,
     /// Precomputed kinetic propagator (momentum space)
     kinetic_prop: Vec<Complex>,
     /// Time step
@@ -72,13 +103,18 @@ pub struct TunnelingSimulation {
     pub transmission: f32,
     /// Reflection coefficient
     pub reflection: f32,
+    /// Which kinetic-step scheme `step()` uses
+    pub propagator: Propagator,
 }
 
 impl TunnelingSimulation {
     pub fn new(n_points: usize, x_min: f32, x_max: f32, barrier: Barrier) -> Self {
         let wavefunction = Wavefunction1D::new(n_points, x_min, x_max);
         let dx = wavefunction.dx;
-        let dt = 0.001;
+        // The split-step Fourier method is unconditionally stable, so `dt`
+        // no longer has to be kept tiny to avoid the finite-difference
+        // scheme's numerical dispersion
+        let dt = 0.01;
         let mass = M_E;
 
         // Compute potential on grid
@@ -92,7 +128,7 @@ impl TunnelingSimulation {
         // Compute kinetic propagator in momentum space
         // k values for FFT: 0, 1, ..., N/2, -N/2+1, ..., -1
         let n = n_points;
-        let dk = 2.0 * std::f32::consts::PI / ((x_max - x_min) * n as f32);
+        let dk = 2.0 * std::f32::consts::PI / (x_max - x_min);
         let kinetic_prop: Vec<Complex> = (0..n)
             .map(|i| {
                 let k = if i <= n / 2 {
@@ -105,10 +141,11 @@ impl TunnelingSimulation {
             })
             .collect();
 
-        Self {
+        let mut sim = Self {
             wavefunction,
             barrier,
             potential,
+            damping: vec![0.0; n_points],
             kinetic_prop,
             dt,
             time: 0.0,
@@ -116,7 +153,37 @@ impl TunnelingSimulation {
             initial_k: 0.0,
             transmission: 0.0,
             reflection: 0.0,
-        }
+            propagator: Propagator::SplitStepFourier,
+        };
+        let width = (x_max - x_min) * 0.1;
+        sim.set_absorbing_layer(width, 20.0);
+        sim
+    }
+
+    /// Configure the complex absorbing potential (CAP) boundary layers: a
+    /// region of width `width` at each edge of the grid where an imaginary
+    /// potential `-i * strength * (d/width)^2` is applied, `d` being the
+    /// distance into the layer from its inner edge. This smoothly damps
+    /// outgoing flux across many grid points instead of reflecting it,
+    /// so a packet that has transmitted past the barrier doesn't bounce
+    /// back and corrupt `transmission`/`reflection`.
+    pub fn set_absorbing_layer(&mut self, width: f32, strength: f32) {
+        let x_min = self.wavefunction.x_min;
+        let x_max = self.wavefunction.x_max;
+        let dx = self.wavefunction.dx;
+
+        self.damping = (0..self.wavefunction.len())
+            .map(|i| {
+                if width <= 0.0 {
+                    return 0.0;
+                }
+                let x = x_min + i as f32 * dx;
+                let d_left = (x_min + width - x).max(0.0);
+                let d_right = (x - (x_max - width)).max(0.0);
+                let d = d_left.max(d_right);
+                strength * (d / width).powi(2)
+            })
+            .collect();
     }
 
     /// Initialize with a Gaussian wave packet
@@ -127,20 +194,65 @@ impl TunnelingSimulation {
         self.time = 0.0;
     }
 
-    /// Single time step using split-operator method
-    /// This is a simplified version using finite differences
+    /// Single time step, advancing the kinetic term with whichever scheme
+    /// `self.propagator` selects
     pub fn step(&mut self) {
-        let n = self.wavefunction.len();
-        let dx = self.wavefunction.dx;
+        match self.propagator {
+            Propagator::SplitStepFourier => self.step_split_step_fourier(),
+            Propagator::FiniteDifference => self.step_finite_difference(),
+        }
+
+        self.time += self.dt;
+
+        // Update transmission/reflection coefficients
+        self.compute_coefficients();
+    }
+
+    /// Apply the potential phase `exp(-iV dt/2ℏ)` in position space to every
+    /// grid point, plus the real decay `exp(-eta*(d/width)^2 * dt/ℏ)` from
+    /// the CAP absorbing layers (`self.damping`); shared by both half-steps
+    /// of the split-operator method
+    fn apply_potential_half_step(&mut self) {
         let dt = self.dt;
+        for i in 0..self.wavefunction.len() {
+            let phase = -self.potential[i] * dt / (2.0 * HBAR);
+            let decay = (-self.damping[i] * dt / HBAR).exp();
+            self.wavefunction.psi[i] = self.wavefunction.psi[i] * Complex::exp_i(phase) * decay;
+        }
+    }
+
+    /// Split-operator/Fourier step: half-step potential phase, then the exact
+    /// kinetic propagator applied in momentum space via FFT, then the other
+    /// half-step potential phase. Unconditionally stable and norm-conserving,
+    /// unlike the finite-difference Laplacian below.
+    fn step_split_step_fourier(&mut self) {
+        let n = self.wavefunction.len();
 
-        // Half step in potential (position space)
+        self.apply_potential_half_step();
+
+        fft(&mut self.wavefunction.psi, false);
         for i in 0..n {
-            let phase = -self.potential[i] * dt / (2.0 * HBAR);
-            self.wavefunction.psi[i] = self.wavefunction.psi[i] * Complex::exp_i(phase);
+            self.wavefunction.psi[i] = self.wavefunction.psi[i] * self.kinetic_prop[i];
+        }
+        fft(&mut self.wavefunction.psi, true);
+        let inv_n = 1.0 / n as f32;
+        for c in &mut self.wavefunction.psi {
+            *c = *c * inv_n;
         }
 
-        // Full step in kinetic energy using finite differences (Crank-Nicolson-like)
+        self.apply_potential_half_step();
+    }
+
+    /// Crank-Nicolson-like finite-difference Laplacian kept for comparison
+    /// against the split-step method; numerically disperses and needs a much
+    /// smaller `dt` to stay stable
+    fn step_finite_difference(&mut self) {
+        let n = self.wavefunction.len();
+        let dx = self.wavefunction.dx;
+        let dt = self.dt;
+
+        self.apply_potential_half_step();
+
         let alpha = Complex::new(0.0, HBAR * dt / (4.0 * self.mass * dx * dx));
         let mut new_psi = vec![Complex::ZERO; n];
 
@@ -151,22 +263,22 @@ impl TunnelingSimulation {
             new_psi[i] = self.wavefunction.psi[i] + alpha * laplacian;
         }
 
-        // Absorbing boundary conditions
-        new_psi[0] = self.wavefunction.psi[0] * 0.99;
-        new_psi[n - 1] = self.wavefunction.psi[n - 1] * 0.99;
+        // Edge points carry through unchanged; the CAP layers in
+        // `apply_potential_half_step` handle absorbing outgoing flux now
+        new_psi[0] = self.wavefunction.psi[0];
+        new_psi[n - 1] = self.wavefunction.psi[n - 1];
 
         self.wavefunction.psi = new_psi;
 
-        // Half step in potential again
-        for i in 0..n {
-            let phase = -self.potential[i] * dt / (2.0 * HBAR);
-            self.wavefunction.psi[i] = self.wavefunction.psi[i] * Complex::exp_i(phase);
-        }
-
-        self.time += dt;
+        self.apply_potential_half_step();
+    }
 
-        // Update transmission/reflection coefficients
-        self.compute_coefficients();
+    /// Cycle to the other propagator scheme, for A/B comparison
+    pub fn toggle_propagator(&mut self) {
+        self.propagator = match self.propagator {
+            Propagator::SplitStepFourier => Propagator::FiniteDifference,
+            Propagator::FiniteDifference => Propagator::SplitStepFourier,
+        };
     }
 
     /// Compute transmission and reflection coefficients
@@ -252,6 +364,57 @@ impl TunnelingSimulation {
         }
     }
 
+    /// Exact stationary transmission/reflection spectrum via the
+    /// transfer-matrix method, valid for every `Barrier` variant (including
+    /// the resonance peaks of `Double`), unlike `theoretical_transmission`
+    /// which only covers `Rectangular`.
+    ///
+    /// Samples `self.potential` (already a fine piecewise-constant grid) to
+    /// get segments `V_j`, builds the 2x2 boundary matrix at each junction
+    /// from continuity of psi and psi', and multiplies them in order to get
+    /// the total transfer matrix `M`. Returns `(E, T, R)` triples for `n`
+    /// energies between `e_min` and `e_max`.
+    pub fn transmission_spectrum(&self, e_min: f32, e_max: f32, n: usize) -> Vec<(f32, f32, f32)> {
+        let segments = self.potential.len();
+        let steps = n.max(1);
+
+        let wavevector = |e: f32, v: f32| -> Complex {
+            let value = 2.0 * self.mass * (e - v) / (HBAR * HBAR);
+            if value >= 0.0 {
+                Complex::new(value.sqrt(), 0.0)
+            } else {
+                Complex::new(0.0, (-value).sqrt())
+            }
+        };
+
+        let mut spectrum = Vec::with_capacity(steps);
+        for step in 0..steps {
+            let e = if steps == 1 {
+                e_min
+            } else {
+                e_min + (e_max - e_min) * step as f32 / (steps - 1) as f32
+            };
+
+            let mut total = [[Complex::ONE, Complex::ZERO], [Complex::ZERO, Complex::ONE]];
+            for j in 0..segments - 1 {
+                let k_l = wavevector(e, self.potential[j]);
+                let k_r = wavevector(e, self.potential[j + 1]);
+                let x = self.wavefunction.x_at(j + 1);
+                let boundary = boundary_matrix(k_l, k_r, x);
+                total = mat_mul(total, boundary);
+            }
+
+            let k_in = wavevector(e, self.potential[0]);
+            let k_out = wavevector(e, self.potential[segments - 1]);
+
+            let t = (k_out / k_in).re * (Complex::ONE / total[1][1]).norm_sq();
+            let r = (total[1][0] / total[1][1]).norm_sq();
+            spectrum.push((e, t, r));
+        }
+
+        spectrum
+    }
+
     /// Reset simulation with new parameters
     pub fn reset(&mut self, x0: f32, k0: f32, sigma: f32) {
         self.init_wave_packet(x0, k0, sigma);
@@ -259,6 +422,39 @@ impl TunnelingSimulation {
         self.reflection = 0.0;
     }
 
+    /// Sample the wavefunction at an arbitrary world-space x (clamped to the
+    /// grid), snapping to the nearest grid point. Backs the click-to-probe
+    /// measurement marker: a user drops a marker on the wave packet and sees
+    /// a reading at that exact point rather than only the barrier-centered
+    /// transmission/reflection totals.
+    pub fn probe(&self, x: f32) -> ProbeReading {
+        let n = self.wavefunction.len();
+        let index = (((x - self.wavefunction.x_min) / self.wavefunction.dx).round() as isize)
+            .clamp(0, n as isize - 1) as usize;
+
+        let dx = self.wavefunction.dx;
+        let mut transmitted_prob = 0.0;
+        let mut total_prob = 0.0;
+        for i in 0..n {
+            let prob = self.wavefunction.psi[i].norm_sq() * dx;
+            total_prob += prob;
+            if i > index {
+                transmitted_prob += prob;
+            }
+        }
+
+        ProbeReading {
+            x: self.wavefunction.x_at(index),
+            density: self.wavefunction.psi[index].norm_sq(),
+            potential: self.potential[index],
+            transmission_contribution: if total_prob > 1e-10 {
+                transmitted_prob / total_prob
+            } else {
+                0.0
+            },
+        }
+    }
+
     /// Set barrier type
     pub fn set_barrier(&mut self, barrier: Barrier) {
         self.barrier = barrier;
@@ -314,6 +510,41 @@ impl TunnelingSimulation {
     }
 }
 
+/// Transfer matrix across a single boundary between two piecewise-constant
+/// segments with wavevectors `k_l` (left) and `k_r` (right) at position `x`,
+/// built from continuity of psi and psi' across the junction
+fn boundary_matrix(k_l: Complex, k_r: Complex, x: f32) -> [[Complex; 2]; 2] {
+    let ratio = k_l / k_r;
+    let half = Complex::new(0.5, 0.0);
+    let sum = k_l + k_r;
+    let diff = k_l - k_r;
+
+    [
+        [
+            half * (Complex::ONE + ratio) * (Complex::I * diff * x).exp(),
+            half * (Complex::ONE - ratio) * (Complex::I * sum * x * -1.0).exp(),
+        ],
+        [
+            half * (Complex::ONE - ratio) * (Complex::I * sum * x).exp(),
+            half * (Complex::ONE + ratio) * (Complex::I * diff * x * -1.0).exp(),
+        ],
+    ]
+}
+
+/// 2x2 complex matrix multiplication
+fn mat_mul(a: [[Complex; 2]; 2], b: [[Complex; 2]; 2]) -> [[Complex; 2]; 2] {
+    [
+        [
+            a[0][0] * b[0][0] + a[0][1] * b[1][0],
+            a[0][0] * b[0][1] + a[0][1] * b[1][1],
+        ],
+        [
+            a[1][0] * b[0][0] + a[1][1] * b[1][0],
+            a[1][0] * b[0][1] + a[1][1] * b[1][1],
+        ],
+    ]
+}
+
 /// Helper: HSV to RGB conversion
 fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
     let h = h * 6.0;
@@ -332,3 +563,46 @@ fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
         _ => (v, p, q),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total_probability(sim: &TunnelingSimulation) -> f32 {
+        let dx = sim.wavefunction.dx;
+        sim.probability_density().iter().sum::<f32>() * dx
+    }
+
+    #[test]
+    fn split_step_fourier_conserves_norm() {
+        let mut sim = TunnelingSimulation::preset_single_barrier();
+        let initial_norm = total_probability(&sim);
+        assert!((initial_norm - 1.0).abs() < 1e-3);
+
+        for _ in 0..200 {
+            sim.step();
+        }
+
+        let final_norm = total_probability(&sim);
+        // The CAP absorbing layers bleed off a little probability as flux
+        // exits the grid, but nowhere near the ~512x energy blowup the
+        // dk=2*pi/(L*n) bug produced
+        assert!(final_norm > 0.9, "norm dropped to {final_norm}, propagator likely unstable");
+    }
+
+    #[test]
+    fn kinetic_propagator_dk_matches_fft_convention() {
+        // dk for an n-point grid over length L must be 2*pi/L, not 2*pi/(L*n)
+        let sim = TunnelingSimulation::preset_single_barrier();
+        let l = sim.wavefunction.x_max - sim.wavefunction.x_min;
+        let expected_dk = 2.0 * std::f32::consts::PI / l;
+
+        // kinetic_prop[1] = exp(-i * hbar^2 * dk^2 / (2m) * dt / hbar)
+        let expected_energy = HBAR * HBAR * expected_dk * expected_dk / (2.0 * M_E);
+        let expected = Complex::exp_i(-expected_energy * sim.dt / HBAR);
+        let actual = sim.kinetic_prop[1];
+
+        assert!((actual.re - expected.re).abs() < 1e-5);
+        assert!((actual.im - expected.im).abs() < 1e-5);
+    }
+}
@@ -110,6 +110,18 @@ impl std::ops::Mul<f32> for Complex {
     }
 }
 
+impl std::ops::Div for Complex {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let denom = rhs.norm_sq();
+        let num = self * rhs.conj();
+        Self {
+            re: num.re / denom,
+            im: num.im / denom,
+        }
+    }
+}
+
 impl std::ops::AddAssign for Complex {
     fn add_assign(&mut self, rhs: Self) {
         self.re += rhs.re;
@@ -117,6 +129,48 @@ impl std::ops::AddAssign for Complex {
     }
 }
 
+/// In-place iterative radix-2 Cooley-Tukey FFT. `data.len()` must be a power
+/// of two (grid sizes used throughout this crate, e.g. 512, already are).
+/// `inverse` selects the unnormalized inverse transform (conjugated twiddle
+/// factors); callers are responsible for the `1/n` scaling afterward.
+pub fn fft(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    assert!(n.is_power_of_two(), "fft length must be a power of two");
+    if n <= 1 {
+        return;
+    }
+
+    // Bit-reversal permutation
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if j > i {
+            data.swap(i, j);
+        }
+    }
+
+    // Iterative butterfly passes, doubling the sub-transform size each round
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2.0 * PI / len as f32;
+        let w_len = Complex::exp_i(angle);
+        let mut start = 0;
+        while start < n {
+            let mut w = Complex::ONE;
+            for k in 0..len / 2 {
+                let u = data[start + k];
+                let v = data[start + k + len / 2] * w;
+                data[start + k] = u + v;
+                data[start + k + len / 2] = u - v;
+                w = w * w_len;
+            }
+            start += len;
+        }
+        len <<= 1;
+    }
+}
+
 /// 1D Wavefunction on a discrete grid
 #[derive(Clone)]
 pub struct Wavefunction1D {
@@ -242,6 +296,45 @@ impl Wavefunction3D {
             self.bounds.0.z + iz as f32 * self.dz(),
         )
     }
+
+    /// Trilinearly interpolated probability density `|psi|²` at an arbitrary
+    /// point, clamped to the grid's bounds; lets marching cubes (and
+    /// anything else) sample the field off-grid
+    pub fn probability_at(&self, p: Vec3) -> f32 {
+        let fx = ((p.x - self.bounds.0.x) / self.dx()).clamp(0.0, (self.nx - 1) as f32);
+        let fy = ((p.y - self.bounds.0.y) / self.dy()).clamp(0.0, (self.ny - 1) as f32);
+        let fz = ((p.z - self.bounds.0.z) / self.dz()).clamp(0.0, (self.nz - 1) as f32);
+
+        let x0 = fx.floor() as usize;
+        let y0 = fy.floor() as usize;
+        let z0 = fz.floor() as usize;
+        let x1 = (x0 + 1).min(self.nx - 1);
+        let y1 = (y0 + 1).min(self.ny - 1);
+        let z1 = (z0 + 1).min(self.nz - 1);
+        let (tx, ty, tz) = (fx - x0 as f32, fy - y0 as f32, fz - z0 as f32);
+
+        let density = |ix, iy, iz| self.get(ix, iy, iz).norm_sq();
+        let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+
+        let c00 = lerp(density(x0, y0, z0), density(x1, y0, z0), tx);
+        let c10 = lerp(density(x0, y1, z0), density(x1, y1, z0), tx);
+        let c01 = lerp(density(x0, y0, z1), density(x1, y0, z1), tx);
+        let c11 = lerp(density(x0, y1, z1), density(x1, y1, z1), tx);
+        let c0 = lerp(c00, c10, ty);
+        let c1 = lerp(c01, c11, ty);
+        lerp(c0, c1, tz)
+    }
+
+    /// Extract a closed triangle mesh of the `iso` probability-density
+    /// surface via marching cubes, sampling `probability_at` across the
+    /// field's own bounds at `grid_res³` resolution
+    pub fn extract_isosurface(&self, iso: f32, grid_res: usize) -> Vec<[Vec3; 3]> {
+        let bounds = crate::marching_cubes::GridBounds {
+            min: self.bounds.0,
+            max: self.bounds.1,
+        };
+        crate::marching_cubes::extract_isosurface(|p| self.probability_at(p), bounds, grid_res, iso)
+    }
 }
 
 /// Spherical harmonics Y_l^m(theta, phi)
@@ -266,23 +359,42 @@ pub fn spherical_harmonic(l: i32, m: i32, theta: f32, phi: f32) -> Complex {
     phase * (norm * plm * cs_phase)
 }
 
-/// Associated Legendre polynomial (simplified for low l)
+/// Associated Legendre polynomial P_l^m(cos_theta), valid for any l >= m >= 0,
+/// via the standard upward recurrence: start from the diagonal term
+/// `P_m^m = (-1)^m (2m-1)!! sin^m(theta)`, raise to `P_{m+1}^m`, then climb
+/// to `P_l^m` with the three-term recurrence in `l`
 fn associated_legendre(l: i32, m: i32, cos_theta: f32, sin_theta: f32) -> f32 {
-    let sin_m = sin_theta.powi(m);
-
-    match (l, m) {
-        (0, 0) => 1.0,
-        (1, 0) => cos_theta,
-        (1, 1) => -sin_theta,
-        (2, 0) => 0.5 * (3.0 * cos_theta * cos_theta - 1.0),
-        (2, 1) => -3.0 * cos_theta * sin_theta,
-        (2, 2) => 3.0 * sin_theta * sin_theta,
-        (3, 0) => 0.5 * cos_theta * (5.0 * cos_theta * cos_theta - 3.0),
-        (3, 1) => -1.5 * sin_theta * (5.0 * cos_theta * cos_theta - 1.0),
-        (3, 2) => 15.0 * cos_theta * sin_theta * sin_theta,
-        (3, 3) => -15.0 * sin_m * sin_theta,
-        _ => sin_m, // Fallback
+    debug_assert!(m >= 0 && l >= m);
+
+    // P_m^m via (-1)^m (2m-1)!! sin^m(theta), built up one odd factor at a time
+    let mut pmm = 1.0f32;
+    let mut odd_factor = 1.0f32;
+    for _ in 0..m {
+        pmm *= -odd_factor * sin_theta;
+        odd_factor += 2.0;
     }
+
+    if l == m {
+        return pmm;
+    }
+
+    // P_{m+1}^m
+    let pmmp1 = cos_theta * (2.0 * m as f32 + 1.0) * pmm;
+    if l == m + 1 {
+        return pmmp1;
+    }
+
+    // Climb from P_{m+1}^m up to P_l^m
+    let mut p_prev2 = pmm;
+    let mut p_prev1 = pmmp1;
+    let mut p_l = p_prev1;
+    for ll in (m + 2)..=l {
+        p_l = ((2 * ll - 1) as f32 * cos_theta * p_prev1 - (ll + m - 1) as f32 * p_prev2)
+            / (ll - m) as f32;
+        p_prev2 = p_prev1;
+        p_prev1 = p_l;
+    }
+    p_l
 }
 
 /// Normalization constant for spherical harmonics
@@ -299,18 +411,40 @@ fn factorial(n: u32) -> f32 {
     (1..=n).map(|i| i as f32).product::<f32>().max(1.0)
 }
 
-/// Radial wavefunction for hydrogen-like atoms R_nl(r)
+/// Associated Laguerre polynomial L_k^alpha(rho), via the three-term
+/// recurrence `L_{k+1}^a = [(2k+1+a-rho)L_k^a - (k+a)L_{k-1}^a]/(k+1)`
+/// starting from `L_0^a = 1`, `L_1^a = 1+a-rho`
+fn associated_laguerre(k: u32, alpha: f32, rho: f32) -> f32 {
+    let mut l_prev2 = 1.0f32; // L_0
+    if k == 0 {
+        return l_prev2;
+    }
+
+    let mut l_prev1 = 1.0 + alpha - rho; // L_1
+    if k == 1 {
+        return l_prev1;
+    }
+
+    let mut l_k = l_prev1;
+    for j in 1..k {
+        l_k = ((2.0 * j as f32 + 1.0 + alpha - rho) * l_prev1 - (j as f32 + alpha) * l_prev2)
+            / (j as f32 + 1.0);
+        l_prev2 = l_prev1;
+        l_prev1 = l_k;
+    }
+    l_k
+}
+
+/// Radial wavefunction for hydrogen-like atoms R_nl(r), valid for any
+/// n > l >= 0 via the associated Laguerre polynomial `L_{n-l-1}^{2l+1}(rho)`
 pub fn hydrogen_radial(n: u32, l: u32, r: f32, a0: f32) -> f32 {
     let rho = 2.0 * r / (n as f32 * a0);
+    let alpha = (2 * l + 1) as f32;
+    let k = n - l - 1;
 
-    // Simplified radial functions for low n, l
-    match (n, l) {
-        (1, 0) => 2.0 * (-rho / 2.0).exp(), // 1s
-        (2, 0) => (1.0 / (2.0 * 2.0_f32.sqrt())) * (1.0 - rho / 2.0) * (-rho / 2.0).exp(), // 2s
-        (2, 1) => (1.0 / (2.0 * 6.0_f32.sqrt())) * rho * (-rho / 2.0).exp(), // 2p
-        (3, 0) => (2.0 / (81.0 * 3.0_f32.sqrt())) * (27.0 - 18.0 * rho + 2.0 * rho * rho) * (-rho / 2.0).exp(), // 3s
-        (3, 1) => (8.0 / (27.0 * 6.0_f32.sqrt())) * (1.0 - rho / 6.0) * rho * (-rho / 2.0).exp(), // 3p
-        (3, 2) => (4.0 / (81.0 * 30.0_f32.sqrt())) * rho * rho * (-rho / 2.0).exp(), // 3d
-        _ => (-r / a0).exp(), // Fallback
-    }
+    let laguerre = associated_laguerre(k, alpha, rho);
+    let norm = ((2.0 / (n as f32 * a0)).powi(3) * factorial(k) / (2.0 * n as f32 * factorial(n + l)))
+        .sqrt();
+
+    norm * rho.powi(l as i32) * (-rho / 2.0).exp() * laguerre
 }
@@ -0,0 +1,116 @@
+//! Autopilot guidance laws for `Spaceship`
+//!
+//! Provides simple guidance laws a pilot can hand off to: seek a target body
+//! directly, or intercept it using proportional navigation (steering
+//! proportional to the rate of rotation of the line-of-sight to the target,
+//! the same law used by homing missiles and orbital rendezvous burns).
+
+use glam::Vec3;
+
+use crate::solar_system::CelestialBody;
+use crate::spaceship::Spaceship;
+
+/// Guidance law the autopilot is currently flying
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuidanceMode {
+    /// Point directly at the target and burn
+    Pursuit,
+    /// Proportional navigation toward an intercept point
+    ProportionalNavigation,
+    /// Hold current course, thrust disabled
+    Off,
+}
+
+/// Autopilot state: guidance law, target, and proportional-navigation gain
+#[derive(Debug, Clone)]
+pub struct Autopilot {
+    pub mode: GuidanceMode,
+    pub target_index: Option<usize>,
+    /// Navigation constant (typically 3-5 for proportional navigation)
+    pub nav_gain: f32,
+    previous_los: Option<Vec3>,
+}
+
+impl Autopilot {
+    pub fn new() -> Self {
+        Self {
+            mode: GuidanceMode::Off,
+            target_index: None,
+            nav_gain: 4.0,
+            previous_los: None,
+        }
+    }
+
+    pub fn engage(&mut self, mode: GuidanceMode, target_index: usize) {
+        self.mode = mode;
+        self.target_index = Some(target_index);
+        self.previous_los = None;
+    }
+
+    pub fn disengage(&mut self) {
+        self.mode = GuidanceMode::Off;
+        self.target_index = None;
+        self.previous_los = None;
+    }
+
+    /// Compute the desired thrust direction and throttle for this frame,
+    /// and apply it to `ship` via `apply_thrust`
+    pub fn guide(&mut self, ship: &mut Spaceship, bodies: &[CelestialBody], dt: f32) {
+        let Some(target) = self.target_index.and_then(|i| bodies.get(i)) else {
+            return;
+        };
+
+        let desired_direction = match self.mode {
+            GuidanceMode::Off => return,
+            GuidanceMode::Pursuit => (target.position - ship.position).normalize_or_zero(),
+            GuidanceMode::ProportionalNavigation => {
+                self.proportional_navigation_direction(ship, target, dt)
+            }
+        };
+
+        if desired_direction.length_squared() < 1e-9 {
+            return;
+        }
+
+        // Rotate the ship to face the guidance direction, then burn
+        let current = ship.forward();
+        let angle = current.angle_between(desired_direction);
+        if angle > 1e-4 {
+            let axis = current.cross(desired_direction).normalize_or_zero();
+            if axis.length_squared() > 1e-9 {
+                ship.orientation = glam::Quat::from_axis_angle(axis, angle.min(2.0 * dt)) * ship.orientation;
+                ship.orientation = ship.orientation.normalize();
+            }
+        }
+
+        ship.apply_thrust(1.0, dt);
+    }
+
+    /// Proportional navigation: steer proportional to the line-of-sight
+    /// rotation rate, aiming at an intercept point rather than the target's
+    /// current position
+    fn proportional_navigation_direction(&mut self, ship: &Spaceship, target: &CelestialBody, dt: f32) -> Vec3 {
+        let line_of_sight = (target.position - ship.position).normalize_or_zero();
+
+        let los_rate = match self.previous_los {
+            Some(prev) if dt > 1e-6 => {
+                let delta = line_of_sight - prev;
+                delta / dt
+            }
+            _ => Vec3::ZERO,
+        };
+        self.previous_los = Some(line_of_sight);
+
+        let relative_velocity = target.velocity - ship.velocity;
+        let closing_speed = -relative_velocity.dot(line_of_sight).max(0.01);
+
+        let lateral_accel_command = los_rate * self.nav_gain * closing_speed;
+        (line_of_sight + lateral_accel_command).normalize_or_zero()
+    }
+}
+
+impl Default for Autopilot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
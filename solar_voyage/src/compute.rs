@@ -0,0 +1,286 @@
+//! GPU compute pipeline for N-body gravitational integration
+//!
+//! Wraps a `wgpu::ComputePipeline` the way [`crate::render_graph`] wraps
+//! render passes: body masses/positions/velocities live in storage buffers,
+//! and `dispatch` records one compute pass that steps every body forward
+//! with semi-implicit (symplectic) Euler — the simplest member of the
+//! leapfrog family — summing pairwise `G*m/r²` accelerations with Plummer
+//! softening `1/(r² + ε²)` so close encounters don't blow up. Positions are
+//! also appended to a per-body ring-buffer trail on the GPU, so a renderer
+//! can bind `position_buffer`/`trail_buffer` directly as vertex buffers
+//! instead of re-uploading the CPU `SolarSystem` state every frame.
+//!
+//! This is an additive path alongside [`crate::solar_system::SolarSystem`]'s
+//! CPU integrator, not a replacement for it — ephemeris-driven bodies, UI,
+//! and autopilot logic all still read the CPU state. Wiring the render
+//! instance buffers to read from here directly is future work.
+
+use wgpu::util::DeviceExt;
+
+use crate::solar_system::CelestialBody;
+
+/// Per-body position, with mass packed into the unused `w` component so a
+/// single storage buffer can serve both the integrator's mass lookup and
+/// the renderer's vertex position
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct GpuBodyPosition {
+    pub position: [f32; 3],
+    pub mass: f32,
+}
+
+/// Per-body velocity
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct GpuBodyVelocity {
+    pub velocity: [f32; 3],
+    pub _padding: f32,
+}
+
+/// Integration parameters uploaded once per dispatch
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct NBodyParams {
+    pub body_count: u32,
+    pub dt: f32,
+    pub softening: f32,
+    pub trail_write_index: u32,
+}
+
+/// Plummer softening length (AU) that keeps close encounters from producing
+/// a near-singular `1/r²` acceleration
+const SOFTENING: f32 = 0.01;
+
+/// Bodies per compute workgroup; matches the `@workgroup_size(64)` the
+/// `cs_integrate` entry point is expected to declare
+const WORKGROUP_SIZE: u32 = 64;
+
+pub struct GpuNBody {
+    pipeline: wgpu::ComputePipeline,
+    bind_group: wgpu::BindGroup,
+
+    position_buffer: wgpu::Buffer,
+    velocity_buffer: wgpu::Buffer,
+    trail_buffer: wgpu::Buffer,
+    params_buffer: wgpu::Buffer,
+
+    max_bodies: usize,
+    trail_ring_length: usize,
+    frame_index: u32,
+}
+
+impl GpuNBody {
+    pub fn new(device: &wgpu::Device, max_bodies: usize, trail_ring_length: usize) -> Self {
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("N-Body Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shaders/nbody.wgsl").into()),
+        });
+
+        let position_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("N-Body Position Buffer"),
+            size: (std::mem::size_of::<GpuBodyPosition>() * max_bodies) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let velocity_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("N-Body Velocity Buffer"),
+            size: (std::mem::size_of::<GpuBodyVelocity>() * max_bodies) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let trail_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("N-Body Trail Buffer"),
+            size: (std::mem::size_of::<GpuBodyPosition>() * max_bodies * trail_ring_length) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("N-Body Params Buffer"),
+            size: std::mem::size_of::<NBodyParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("N-Body Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let bind_group = Self::create_bind_group(
+            device,
+            &bind_group_layout,
+            &position_buffer,
+            &velocity_buffer,
+            &trail_buffer,
+            &params_buffer,
+        );
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("N-Body Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("N-Body Pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader,
+            entry_point: Some("cs_integrate"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            bind_group,
+            position_buffer,
+            velocity_buffer,
+            trail_buffer,
+            params_buffer,
+            max_bodies,
+            trail_ring_length,
+            frame_index: 0,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        position_buffer: &wgpu::Buffer,
+        velocity_buffer: &wgpu::Buffer,
+        trail_buffer: &wgpu::Buffer,
+        params_buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("N-Body Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: position_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: velocity_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: trail_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: params_buffer.as_entire_binding(),
+                },
+            ],
+        })
+    }
+
+    /// Seed the position/velocity storage buffers from CPU state, e.g. on
+    /// simulation (re)start
+    pub fn upload_initial(&self, queue: &wgpu::Queue, bodies: &[CelestialBody]) {
+        let positions: Vec<GpuBodyPosition> = bodies
+            .iter()
+            .take(self.max_bodies)
+            .map(|b| GpuBodyPosition {
+                position: [b.position.x, b.position.y, b.position.z],
+                mass: b.mass,
+            })
+            .collect();
+        let velocities: Vec<GpuBodyVelocity> = bodies
+            .iter()
+            .take(self.max_bodies)
+            .map(|b| GpuBodyVelocity {
+                velocity: [b.velocity.x, b.velocity.y, b.velocity.z],
+                _padding: 0.0,
+            })
+            .collect();
+
+        queue.write_buffer(&self.position_buffer, 0, bytemuck::cast_slice(&positions));
+        queue.write_buffer(&self.velocity_buffer, 0, bytemuck::cast_slice(&velocities));
+    }
+
+    /// Record one symplectic-Euler integration step: every invocation sums
+    /// pairwise gravity from all other bodies, advances its own velocity and
+    /// position, and appends the new position to its ring-buffer trail slot
+    pub fn dispatch(&mut self, queue: &wgpu::Queue, encoder: &mut wgpu::CommandEncoder, body_count: u32, dt: f32) {
+        let params = NBodyParams {
+            body_count,
+            dt,
+            softening: SOFTENING,
+            trail_write_index: self.frame_index % self.trail_ring_length as u32,
+        };
+        queue.write_buffer(&self.params_buffer, 0, bytemuck::cast_slice(&[params]));
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("N-Body Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &self.bind_group, &[]);
+        pass.dispatch_workgroups(body_count.div_ceil(WORKGROUP_SIZE), 1, 1);
+        drop(pass);
+
+        self.frame_index = self.frame_index.wrapping_add(1);
+    }
+
+    /// Per-body position+mass storage buffer, bindable directly as a vertex
+    /// buffer so the renderer can skip the CPU upload this frame
+    pub fn position_buffer(&self) -> &wgpu::Buffer {
+        &self.position_buffer
+    }
+
+    /// Ring-buffer of historical positions, `trail_ring_length` slots per
+    /// body, most recently written at `frame_index % trail_ring_length`
+    pub fn trail_buffer(&self) -> &wgpu::Buffer {
+        &self.trail_buffer
+    }
+
+    pub fn trail_ring_length(&self) -> usize {
+        self.trail_ring_length
+    }
+}
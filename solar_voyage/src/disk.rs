@@ -0,0 +1,135 @@
+//! Dynamical accretion disk: gravitating test particles instead of a
+//! purely shader-faked ring
+//!
+//! `AccretionDisk::new` seeds particles into a ring just outside a central
+//! mass's event horizon, with the same cylindrical spawn distribution
+//! (uniform angle, radius band, small vertical spread) `gravity_sim`'s
+//! `Simulation3D::init_accretion_disk` uses. Each frame `step` integrates
+//! every particle under the summed gravity of the solar system's bodies
+//! with a velocity-Verlet half-step, mirroring `SolarSystem::step`'s own
+//! integrator, and recycles anything that crosses the horizon back into the
+//! spawn ring rather than letting it vanish.
+
+use std::f32::consts::TAU;
+
+use glam::Vec3;
+use rand::Rng;
+
+use crate::solar_system::{CelestialBody, C, G};
+
+/// Softening length, matching `SolarSystem::compute_accelerations`, so a
+/// particle that strays very close to a body isn't flung out by a
+/// near-singular force
+const SOFTENING: f32 = 0.001;
+
+/// One accretion-disk particle: a massless tracer integrated under the
+/// gravity of the real `CelestialBody` set, not a full `Body3D` of its own
+#[derive(Debug, Clone, Copy)]
+pub struct Particle {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub mass: f32,
+    pub temperature: f32,
+}
+
+/// An N-body accretion disk orbiting `center`, coupled to the spacetime
+/// grid: `SpacetimeGrid::sample_curvature` can be sampled per particle to
+/// tint material crossing high-curvature regions
+pub struct AccretionDisk {
+    pub particles: Vec<Particle>,
+    /// World-space position of the central body this disk orbits
+    pub center: Vec3,
+    inner_radius: f32,
+    outer_radius: f32,
+    /// Particles within this radius of `center` are recycled back into the
+    /// spawn ring instead of integrated further; typically the central
+    /// body's `schwarzschild_radius`
+    horizon_radius: f32,
+}
+
+impl AccretionDisk {
+    /// Seed `count` particles in the ring `[inner_radius, outer_radius]`
+    /// around `center`, on near-circular orbits (speed set by `center_mass`)
+    /// with a small vertical spread
+    pub fn new(center: Vec3, center_mass: f32, horizon_radius: f32, inner_radius: f32, outer_radius: f32, count: usize) -> Self {
+        let mut rng = rand::thread_rng();
+        let particles = (0..count)
+            .map(|_| Self::spawn_particle(&mut rng, center, center_mass, inner_radius, outer_radius))
+            .collect();
+
+        Self { particles, center, inner_radius, outer_radius, horizon_radius }
+    }
+
+    fn spawn_particle(rng: &mut impl Rng, center: Vec3, center_mass: f32, inner_radius: f32, outer_radius: f32) -> Particle {
+        let distance = inner_radius + rng.gen::<f32>() * (outer_radius - inner_radius);
+        let angle: f32 = rng.gen::<f32>() * TAU;
+        let height = (rng.gen::<f32>() - 0.5) * 0.05 * outer_radius;
+
+        let position = center + Vec3::new(angle.cos() * distance, height, angle.sin() * distance);
+
+        let orbital_speed = (G * center_mass / distance).sqrt();
+        let speed_var = 0.95 + rng.gen::<f32>() * 0.1;
+        let velocity = Vec3::new(-angle.sin(), 0.0, angle.cos()) * orbital_speed * speed_var;
+
+        // Hot inner disk, cooler outer disk
+        let t = ((distance - inner_radius) / (outer_radius - inner_radius).max(1e-6)).clamp(0.0, 1.0);
+        let temperature = 30000.0 - t * 25000.0;
+
+        Particle {
+            position,
+            velocity,
+            mass: 0.1 + rng.gen::<f32>() * 0.4,
+            temperature,
+        }
+    }
+
+    /// Net gravitational acceleration on a test particle at `position` from
+    /// every body in `bodies`: `a = Σ G·mᵢ·r̂ / rᵢ²`, softened the same way
+    /// `SolarSystem::compute_accelerations` is
+    fn acceleration(position: Vec3, bodies: &[CelestialBody]) -> Vec3 {
+        bodies.iter().fold(Vec3::ZERO, |acc, body| {
+            let r = body.position - position;
+            let dist_sq = r.length_squared() + SOFTENING * SOFTENING;
+            let dist = dist_sq.sqrt();
+            acc + r / dist * (G * body.mass / dist_sq)
+        })
+    }
+
+    /// Velocity-Verlet step under `bodies`' combined gravity, recycling any
+    /// particle that has crossed `horizon_radius` of `center` back into the
+    /// spawn ring. `center`/`center_mass` are refreshed each call since the
+    /// central body itself may be moving (or have been removed).
+    pub fn step(&mut self, bodies: &[CelestialBody], center: Vec3, center_mass: f32, dt: f32) {
+        self.center = center;
+        let mut rng = rand::thread_rng();
+
+        for particle in &mut self.particles {
+            let accel_old = Self::acceleration(particle.position, bodies);
+            particle.position += particle.velocity * dt + 0.5 * accel_old * dt * dt;
+
+            if particle.position.distance(center) <= self.horizon_radius {
+                *particle = Self::spawn_particle(&mut rng, center, center_mass, self.inner_radius, self.outer_radius);
+                continue;
+            }
+
+            let accel_new = Self::acceleration(particle.position, bodies);
+            particle.velocity += 0.5 * (accel_old + accel_new) * dt;
+        }
+    }
+
+    /// Tint a particle by blackbody-ish temperature, then brighten/redden it
+    /// by a Doppler factor from its line-of-sight velocity relative to
+    /// `camera_pos` — material swinging toward the camera beams brighter,
+    /// material swinging away dims, the asymmetry a rotating disk shows in
+    /// rendered black hole images
+    pub fn particle_color(&self, particle: &Particle, camera_pos: Vec3) -> [f32; 4] {
+        let t = ((particle.temperature - 5000.0) / 25000.0).clamp(0.0, 1.0);
+        let base = [1.0, 0.5 + t * 0.3, 0.2 + t * 0.6];
+
+        let to_camera = (camera_pos - particle.position).normalize_or_zero();
+        let line_of_sight_speed = particle.velocity.dot(to_camera);
+        let doppler = (1.0 + 3.0 * line_of_sight_speed / C).clamp(0.4, 2.0);
+
+        [(base[0] * doppler).min(2.0), (base[1] * doppler).min(2.0), (base[2] * doppler).min(2.0), 0.9]
+    }
+}
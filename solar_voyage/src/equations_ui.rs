@@ -4,11 +4,18 @@
 
 use egui::{Context, RichText, Color32};
 
+use crate::expr_eval;
+
 /// An equation with its name and formula
 pub struct Equation {
     pub name: &'static str,
     pub formula: &'static str,
     pub description: &'static str,
+    /// Optional ASCII expression (e.g. `"2*G*M/c^2"`) evaluated against the
+    /// live `bindings` passed to `draw_equations_sidebar`, shown next to the
+    /// symbolic formula so the panel doubles as a calculator for the running
+    /// scene. `None` for equations with no natural live binding.
+    pub eval: Option<&'static str>,
 }
 
 /// Draw the equations sidebar
@@ -17,6 +24,7 @@ pub fn draw_equations_sidebar(
     title: &str,
     equations: &[Equation],
     variables: &[(&str, &str)],
+    bindings: &[(&str, f64)],
 ) {
     egui::SidePanel::right("equations_panel")
         .resizable(true)
@@ -31,6 +39,15 @@ pub fn draw_equations_sidebar(
                         ui.group(|ui| {
                             ui.label(RichText::new(eq.name).strong().color(Color32::YELLOW));
                             ui.label(RichText::new(eq.formula).monospace().color(Color32::WHITE));
+                            if let Some(expr) = eq.eval {
+                                if let Some(value) = expr_eval::eval(expr, bindings) {
+                                    ui.label(
+                                        RichText::new(format!("= {:.4}", value))
+                                            .monospace()
+                                            .color(Color32::LIGHT_GREEN),
+                                    );
+                                }
+                            }
                             ui.label(RichText::new(eq.description).small().italics());
                         });
                         ui.add_space(4.0);
@@ -64,46 +81,55 @@ pub const SOLAR_VOYAGE_EQUATIONS: &[Equation] = &[
         name: "Kepler's Third Law",
         formula: "T² = (4π²/GM)·a³",
         description: "Orbital period from semi-major axis",
+        eval: Some("sqrt(4*pi*pi*a^3/(G*M))"),
     },
     Equation {
         name: "Vis-viva Equation",
         formula: "v² = GM(2/r - 1/a)",
         description: "Orbital velocity at any point",
+        eval: Some("sqrt(G*M*(2/r - 1/a))"),
     },
     Equation {
         name: "Escape Velocity",
         formula: "v_esc = √(2GM/r)",
         description: "Minimum speed to escape gravity",
+        eval: Some("sqrt(2*G*M/r)"),
     },
     Equation {
         name: "Lorentz Factor",
         formula: "γ = 1/√(1 - v²/c²)",
         description: "Relativistic time dilation factor",
+        eval: Some("1/sqrt(1 - v^2/c^2)"),
     },
     Equation {
         name: "Time Dilation",
         formula: "Δt' = γ·Δt",
         description: "Moving clocks run slow",
+        eval: None,
     },
     Equation {
         name: "Length Contraction",
         formula: "L = L₀/γ",
         description: "Moving objects contract",
+        eval: None,
     },
     Equation {
         name: "Relativistic Momentum",
         formula: "p = γmv",
         description: "Momentum at high speeds",
+        eval: Some("gamma*m*v"),
     },
     Equation {
         name: "Schwarzschild Radius",
         formula: "rₛ = 2GM/c²",
         description: "Black hole event horizon",
+        eval: Some("rs_m"),
     },
     Equation {
         name: "Gravitational Time Dilation",
         formula: "τ = t√(1 - rₛ/r)",
         description: "Clocks slow near massive objects",
+        eval: Some("sqrt(1 - rs_over_r)"),
     },
 ];
 
@@ -0,0 +1,217 @@
+//! Tiny arithmetic expression evaluator
+//!
+//! Parses formulas like `"sqrt(2*G*M/r)"` over a small set of bound symbols
+//! (shunting-yard to RPN, then a stack evaluation pass) so the equations
+//! sidebar can show a live numeric result next to the symbolic formula.
+//! Supports `+ - * / ^`, parentheses, the `sqrt` function, and symbol/number
+//! literals.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Number(f64),
+    Symbol(usize, usize), // start/end byte offsets into the source expression
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    Sqrt,
+    LParen,
+    RParen,
+}
+
+fn tokenize(expr: &str) -> Option<Vec<Token>> {
+    let bytes = expr.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '+' => {
+                tokens.push(Token::Plus);
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Token::Minus);
+                i += 1;
+            }
+            '*' => {
+                tokens.push(Token::Star);
+                i += 1;
+            }
+            '/' => {
+                tokens.push(Token::Slash);
+                i += 1;
+            }
+            '^' => {
+                tokens.push(Token::Caret);
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            _ if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                while i < bytes.len() && matches!(bytes[i] as char, '0'..='9' | '.') {
+                    i += 1;
+                }
+                let number: f64 = expr[start..i].parse().ok()?;
+                tokens.push(Token::Number(number));
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < bytes.len() && ((bytes[i] as char).is_alphanumeric() || bytes[i] as char == '_') {
+                    i += 1;
+                }
+                if &expr[start..i] == "sqrt" {
+                    tokens.push(Token::Sqrt);
+                } else {
+                    tokens.push(Token::Symbol(start, i));
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    Some(tokens)
+}
+
+/// Insert explicit `*` tokens between adjacent value-producing tokens, so
+/// `"2G"` and `"(a+b)(c+d)"`-style implicit multiplication parses as expected
+fn insert_implicit_multiplication(tokens: Vec<Token>) -> Vec<Token> {
+    let ends_value = |t: &Token| matches!(t, Token::Number(_) | Token::Symbol(_, _) | Token::RParen);
+    let starts_value = |t: &Token| matches!(t, Token::Number(_) | Token::Symbol(_, _) | Token::Sqrt | Token::LParen);
+
+    let mut result = Vec::with_capacity(tokens.len());
+    for (i, token) in tokens.into_iter().enumerate() {
+        if i > 0 && starts_value(&token) && ends_value(&result[result.len() - 1]) {
+            result.push(Token::Star);
+        }
+        result.push(token);
+    }
+    result
+}
+
+fn precedence(token: &Token) -> u8 {
+    match token {
+        Token::Plus | Token::Minus => 1,
+        Token::Star | Token::Slash => 2,
+        Token::Caret => 3,
+        _ => 0,
+    }
+}
+
+/// Shunting-yard: infix tokens to reverse Polish notation
+fn to_rpn(tokens: Vec<Token>) -> Option<Vec<Token>> {
+    let mut output = Vec::new();
+    let mut operators = Vec::new();
+
+    for token in tokens {
+        match token {
+            Token::Number(_) | Token::Symbol(_, _) => output.push(token),
+            Token::Sqrt => operators.push(token),
+            Token::LParen => operators.push(token),
+            Token::RParen => {
+                while let Some(top) = operators.last() {
+                    if *top == Token::LParen {
+                        break;
+                    }
+                    output.push(operators.pop()?);
+                }
+                if operators.pop()? != Token::LParen {
+                    return None;
+                }
+                // A function call immediately wrapping the parens binds now
+                if let Some(Token::Sqrt) = operators.last() {
+                    output.push(operators.pop()?);
+                }
+            }
+            Token::Plus | Token::Minus | Token::Star | Token::Slash | Token::Caret => {
+                while let Some(top) = operators.last() {
+                    if *top == Token::LParen {
+                        break;
+                    }
+                    // `^` is right-associative, everything else left-associative
+                    let pops = if token == Token::Caret {
+                        precedence(top) > precedence(&token)
+                    } else {
+                        precedence(top) >= precedence(&token)
+                    };
+                    if !pops {
+                        break;
+                    }
+                    output.push(operators.pop()?);
+                }
+                operators.push(token);
+            }
+        }
+    }
+
+    while let Some(top) = operators.pop() {
+        if top == Token::LParen {
+            return None;
+        }
+        output.push(top);
+    }
+
+    Some(output)
+}
+
+fn eval_rpn(rpn: &[Token], expr: &str, bindings: &[(&str, f64)]) -> Option<f64> {
+    let mut stack: Vec<f64> = Vec::new();
+
+    for token in rpn {
+        match token {
+            Token::Number(n) => stack.push(*n),
+            Token::Symbol(start, end) => {
+                let name = &expr[*start..*end];
+                let value = bindings.iter().find(|(symbol, _)| *symbol == name)?.1;
+                stack.push(value);
+            }
+            Token::Sqrt => {
+                let a = stack.pop()?;
+                stack.push(a.sqrt());
+            }
+            Token::Plus | Token::Minus | Token::Star | Token::Slash | Token::Caret => {
+                let b = stack.pop()?;
+                let a = stack.pop()?;
+                stack.push(match token {
+                    Token::Plus => a + b,
+                    Token::Minus => a - b,
+                    Token::Star => a * b,
+                    Token::Slash => a / b,
+                    Token::Caret => a.powf(b),
+                    _ => unreachable!(),
+                });
+            }
+            Token::LParen | Token::RParen => return None,
+        }
+    }
+
+    if stack.len() == 1 {
+        stack.pop()
+    } else {
+        None
+    }
+}
+
+/// Evaluate `expr` over the given symbol bindings, returning `None` if the
+/// expression is malformed or references an unbound symbol
+pub fn eval(expr: &str, bindings: &[(&str, f64)]) -> Option<f64> {
+    let tokens = tokenize(expr)?;
+    let tokens = insert_implicit_multiplication(tokens);
+    let rpn = to_rpn(tokens)?;
+    eval_rpn(&rpn, expr, bindings)
+}
@@ -0,0 +1,166 @@
+//! Cockpit HUD: radial gauges and a frame-time readout
+//!
+//! `equations_ui` draws the live physics sidebar; this module draws the
+//! instrument panel proper, an overlay painted directly with
+//! `egui::Painter` rather than built out of regular widgets, same as how
+//! `spacetime.rs` builds its own mesh instead of using a library shape.
+//! `radial_gauge` is the one reusable primitive: an arc from a
+//! normalized `0.0..=1.0` value, colored along a green-to-red ramp, with a
+//! label and a numeric readout underneath.
+
+use egui::{Color32, Painter, Pos2, Stroke, Ui, Vec2};
+use std::collections::VecDeque;
+
+/// How many past frame times `FrameTimeHistory` keeps for its rolling
+/// average; 60 frames is ~1 second at 60 FPS, long enough to smooth out
+/// single-frame spikes without masking a sustained drop
+const HISTORY_LEN: usize = 60;
+
+/// A small ring buffer of recent frame times, fed one `dt` per `update`
+/// call, used to compute an averaged ms/FPS readout instead of the jittery
+/// single-frame number
+pub struct FrameTimeHistory {
+    samples: VecDeque<f32>,
+}
+
+impl FrameTimeHistory {
+    pub fn new() -> Self {
+        Self {
+            samples: VecDeque::with_capacity(HISTORY_LEN),
+        }
+    }
+
+    pub fn push(&mut self, dt: f32) {
+        if self.samples.len() == HISTORY_LEN {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(dt);
+    }
+
+    /// Average frame time in milliseconds, over whatever history has
+    /// accumulated so far (0 before the first frame)
+    pub fn avg_ms(&self) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let avg_dt: f32 = self.samples.iter().sum::<f32>() / self.samples.len() as f32;
+        avg_dt * 1000.0
+    }
+
+    pub fn avg_fps(&self) -> f32 {
+        let avg_ms = self.avg_ms();
+        if avg_ms <= 0.0 {
+            0.0
+        } else {
+            1000.0 / avg_ms
+        }
+    }
+}
+
+/// Color ramp for a gauge's arc: green at 0, yellow in the middle, red at 1
+fn ramp_color(value: f32) -> Color32 {
+    let value = value.clamp(0.0, 1.0);
+    if value < 0.5 {
+        let t = value / 0.5;
+        Color32::from_rgb((t * 255.0) as u8, 255, 0)
+    } else {
+        let t = (value - 0.5) / 0.5;
+        Color32::from_rgb(255, (255.0 * (1.0 - t)) as u8, 0)
+    }
+}
+
+/// Paint one radial gauge: a background ring, a foreground arc scaled by
+/// `value` (normalized against `min`/`max`), a label above, and a numeric
+/// readout below
+pub fn radial_gauge(ui: &mut Ui, label: &str, value: f32, min: f32, max: f32, display: &str) {
+    let size = Vec2::splat(72.0);
+    let (rect, _response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    let center = rect.center();
+    let radius = rect.width() * 0.5 - 4.0;
+    let normalized = ((value - min) / (max - min).max(1e-6)).clamp(0.0, 1.0);
+
+    paint_arc(&painter, center, radius, 0.0, 1.0, Color32::from_gray(60));
+    paint_arc(&painter, center, radius, 0.0, normalized, ramp_color(normalized));
+
+    painter.text(
+        Pos2::new(center.x, rect.top()),
+        egui::Align2::CENTER_TOP,
+        label,
+        egui::FontId::proportional(11.0),
+        Color32::LIGHT_GRAY,
+    );
+    painter.text(
+        center,
+        egui::Align2::CENTER_CENTER,
+        display,
+        egui::FontId::monospace(13.0),
+        Color32::WHITE,
+    );
+}
+
+/// Paint a `start..end` (both normalized `0.0..=1.0` around the gauge)
+/// stretch of a circular arc, swept clockwise from the top
+fn paint_arc(painter: &Painter, center: Pos2, radius: f32, start: f32, end: f32, color: Color32) {
+    const SEGMENTS: usize = 48;
+    let from = (start * SEGMENTS as f32).round() as usize;
+    let to = (end * SEGMENTS as f32).round() as usize;
+    if to <= from {
+        return;
+    }
+
+    let mut points = Vec::with_capacity(to - from + 1);
+    for i in from..=to {
+        let t = i as f32 / SEGMENTS as f32;
+        let angle = std::f32::consts::TAU * t - std::f32::consts::FRAC_PI_2;
+        points.push(Pos2::new(
+            center.x + angle.cos() * radius,
+            center.y + angle.sin() * radius,
+        ));
+    }
+    painter.add(egui::Shape::line(points, Stroke::new(5.0, color)));
+}
+
+/// The cockpit instrument panel: speed/γ/distance gauges plus a
+/// frame-time readout, anchored to the bottom-left of the viewport
+pub fn draw_cockpit_hud(
+    ctx: &egui::Context,
+    speed_frac_c: f32,
+    lorentz_factor: f32,
+    distance_to_focus: Option<f32>,
+    frame_times: &FrameTimeHistory,
+) {
+    egui::Area::new(egui::Id::new("cockpit_hud"))
+        .anchor(egui::Align2::LEFT_BOTTOM, Vec2::new(12.0, -12.0))
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style())
+                .fill(Color32::from_black_alpha(160))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        radial_gauge(ui, "SPEED", speed_frac_c, 0.0, 1.0, &format!("{:.3}c", speed_frac_c));
+                        radial_gauge(
+                            ui,
+                            "LORENTZ γ",
+                            (lorentz_factor - 1.0).min(1.0),
+                            0.0,
+                            1.0,
+                            &format!("{:.2}", lorentz_factor),
+                        );
+                        if let Some(distance) = distance_to_focus {
+                            radial_gauge(ui, "DIST (AU)", distance, 0.0, 10.0, &format!("{:.2}", distance));
+                        }
+                    });
+                    ui.separator();
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{:.1} ms  ({:.0} FPS)",
+                            frame_times.avg_ms(),
+                            frame_times.avg_fps()
+                        ))
+                        .monospace()
+                        .color(Color32::LIGHT_GREEN),
+                    );
+                });
+        });
+}
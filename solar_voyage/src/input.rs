@@ -0,0 +1,311 @@
+//! Rebindable action/input layer
+//!
+//! Rather than `main.rs` matching directly on `winit::keyboard::KeyCode` and
+//! storing a boolean flag per control, input is expressed as a layout of
+//! named [`Action`]s bound to physical keys. `ActionHandler` tracks which
+//! keys are currently held and turns that into what `App::update` actually
+//! reads: [`ActionHandler::axis`] for continuous controls (thrust, strafe,
+//! roll) and [`ActionHandler::held`]/[`ActionHandler::take_pressed`] for
+//! buttons. The layout is serializable to TOML (see
+//! [`InputLayout::load_from_file`], mirroring `quantum_sim::model`'s
+//! `PhysicsModel::load_from_file`) so controls can be remapped without a
+//! rebuild. Gamepad/scroll bindings aren't wired up yet — `Binding` only
+//! resolves keyboard keys today — but the `Action`/axis-vs-button split is
+//! designed so adding one is a change to `ActionHandler::handle_key`, not a
+//! redesign.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use winit::keyboard::KeyCode;
+
+/// A named control the simulation reads from, independent of any physical
+/// key. `is_axis` says whether [`ActionHandler::axis`] or
+/// [`ActionHandler::held`]/[`ActionHandler::take_pressed`] is the right way
+/// to read it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    MoveForwardBackward,
+    Strafe,
+    Ascend,
+    Roll,
+    Boost,
+    PauseResume,
+    CycleCameraMode,
+    ToggleFreeCam,
+    ToggleGrid,
+    ToggleTrails,
+    ToggleEphemeris,
+    ToggleJpl,
+    ToggleAutopilot,
+    ToggleBlackHole,
+    TimeScaleUp,
+    TimeScaleDown,
+    FocusSun,
+    FocusEarth,
+    FocusJupiter,
+    FocusSaturn,
+    ClearFocus,
+    SelectPresetSolarSystem,
+    SelectPresetBinaryStar,
+    SelectPresetDenseCluster,
+    SelectPresetGalaxyCollision,
+}
+
+impl Action {
+    const ALL: [Action; 25] = [
+        Action::MoveForwardBackward,
+        Action::Strafe,
+        Action::Ascend,
+        Action::Roll,
+        Action::Boost,
+        Action::PauseResume,
+        Action::CycleCameraMode,
+        Action::ToggleFreeCam,
+        Action::ToggleGrid,
+        Action::ToggleTrails,
+        Action::ToggleEphemeris,
+        Action::ToggleJpl,
+        Action::ToggleAutopilot,
+        Action::ToggleBlackHole,
+        Action::TimeScaleUp,
+        Action::TimeScaleDown,
+        Action::FocusSun,
+        Action::FocusEarth,
+        Action::FocusJupiter,
+        Action::FocusSaturn,
+        Action::ClearFocus,
+        Action::SelectPresetSolarSystem,
+        Action::SelectPresetBinaryStar,
+        Action::SelectPresetDenseCluster,
+        Action::SelectPresetGalaxyCollision,
+    ];
+
+    pub fn is_axis(self) -> bool {
+        matches!(self, Action::MoveForwardBackward | Action::Strafe | Action::Ascend | Action::Roll)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Action::MoveForwardBackward => "move_forward_backward",
+            Action::Strafe => "strafe",
+            Action::Ascend => "ascend",
+            Action::Roll => "roll",
+            Action::Boost => "boost",
+            Action::PauseResume => "pause_resume",
+            Action::CycleCameraMode => "cycle_camera_mode",
+            Action::ToggleFreeCam => "toggle_free_cam",
+            Action::ToggleGrid => "toggle_grid",
+            Action::ToggleTrails => "toggle_trails",
+            Action::ToggleEphemeris => "toggle_ephemeris",
+            Action::ToggleJpl => "toggle_jpl",
+            Action::ToggleAutopilot => "toggle_autopilot",
+            Action::ToggleBlackHole => "toggle_black_hole",
+            Action::TimeScaleUp => "time_scale_up",
+            Action::TimeScaleDown => "time_scale_down",
+            Action::FocusSun => "focus_sun",
+            Action::FocusEarth => "focus_earth",
+            Action::FocusJupiter => "focus_jupiter",
+            Action::FocusSaturn => "focus_saturn",
+            Action::ClearFocus => "clear_focus",
+            Action::SelectPresetSolarSystem => "select_preset_solar_system",
+            Action::SelectPresetBinaryStar => "select_preset_binary_star",
+            Action::SelectPresetDenseCluster => "select_preset_dense_cluster",
+            Action::SelectPresetGalaxyCollision => "select_preset_galaxy_collision",
+        }
+    }
+}
+
+/// How one [`Action`] maps to physical keys. Several keys can alias the same
+/// direction (e.g. `Equal`/`NumpadAdd` both driving `TimeScaleUp`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Binding {
+    Button(Vec<String>),
+    Axis { positive: Vec<String>, negative: Vec<String> },
+}
+
+/// A named-action-to-key layout, loadable from a TOML file so controls can
+/// be remapped without a rebuild
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputLayout {
+    bindings: HashMap<String, Binding>,
+}
+
+impl Default for InputLayout {
+    fn default() -> Self {
+        let mut bindings = HashMap::new();
+        let mut bind = |action: Action, binding: Binding| {
+            bindings.insert(action.as_str().to_string(), binding);
+        };
+
+        bind(Action::MoveForwardBackward, Binding::Axis { positive: vec!["KeyW".into()], negative: vec!["KeyS".into()] });
+        bind(Action::Strafe, Binding::Axis { positive: vec!["KeyD".into()], negative: vec!["KeyA".into()] });
+        bind(Action::Ascend, Binding::Axis { positive: vec!["KeyR".into()], negative: vec!["KeyF".into()] });
+        bind(Action::Roll, Binding::Axis { positive: vec!["KeyE".into()], negative: vec!["KeyQ".into()] });
+        bind(Action::Boost, Binding::Button(vec!["ShiftLeft".into(), "ShiftRight".into()]));
+        bind(Action::PauseResume, Binding::Button(vec!["Space".into()]));
+        bind(Action::CycleCameraMode, Binding::Button(vec!["Tab".into()]));
+        bind(Action::ToggleFreeCam, Binding::Button(vec!["KeyC".into()]));
+        bind(Action::ToggleGrid, Binding::Button(vec!["KeyG".into()]));
+        bind(Action::ToggleTrails, Binding::Button(vec!["KeyT".into()]));
+        bind(Action::ToggleEphemeris, Binding::Button(vec!["KeyO".into()]));
+        bind(Action::ToggleJpl, Binding::Button(vec!["KeyJ".into()]));
+        bind(Action::ToggleAutopilot, Binding::Button(vec!["KeyP".into()]));
+        bind(Action::ToggleBlackHole, Binding::Button(vec!["KeyB".into()]));
+        bind(Action::TimeScaleUp, Binding::Button(vec!["Equal".into(), "NumpadAdd".into()]));
+        bind(Action::TimeScaleDown, Binding::Button(vec!["Minus".into(), "NumpadSubtract".into()]));
+        bind(Action::FocusSun, Binding::Button(vec!["Digit0".into()]));
+        bind(Action::FocusEarth, Binding::Button(vec!["Digit1".into()]));
+        bind(Action::FocusJupiter, Binding::Button(vec!["Digit2".into()]));
+        bind(Action::FocusSaturn, Binding::Button(vec!["Digit3".into()]));
+        bind(Action::ClearFocus, Binding::Button(vec!["Escape".into()]));
+        bind(Action::SelectPresetSolarSystem, Binding::Button(vec!["F1".into()]));
+        bind(Action::SelectPresetBinaryStar, Binding::Button(vec!["F2".into()]));
+        bind(Action::SelectPresetDenseCluster, Binding::Button(vec!["F3".into()]));
+        bind(Action::SelectPresetGalaxyCollision, Binding::Button(vec!["F4".into()]));
+
+        Self { bindings }
+    }
+}
+
+/// Errors that can occur while loading an input layout file
+#[derive(Debug, Error)]
+pub enum InputLayoutError {
+    #[error("failed to read input layout file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to parse TOML input layout: {0}")]
+    Toml(#[from] toml::de::Error),
+}
+
+impl InputLayout {
+    /// Load a layout from a TOML file, e.g. one saved from a remapping UI
+    pub fn load_from_file(path: &Path) -> Result<Self, InputLayoutError> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(toml::from_str(&text)?)
+    }
+}
+
+/// Name winit gives a `KeyCode` in the layout's TOML, for the handful of
+/// physical keys the default layout (and any remap of it) actually uses
+fn key_name(key: KeyCode) -> Option<&'static str> {
+    Some(match key {
+        KeyCode::KeyW => "KeyW",
+        KeyCode::KeyA => "KeyA",
+        KeyCode::KeyS => "KeyS",
+        KeyCode::KeyD => "KeyD",
+        KeyCode::KeyR => "KeyR",
+        KeyCode::KeyF => "KeyF",
+        KeyCode::KeyQ => "KeyQ",
+        KeyCode::KeyE => "KeyE",
+        KeyCode::KeyG => "KeyG",
+        KeyCode::KeyT => "KeyT",
+        KeyCode::KeyO => "KeyO",
+        KeyCode::KeyJ => "KeyJ",
+        KeyCode::KeyP => "KeyP",
+        KeyCode::KeyB => "KeyB",
+        KeyCode::KeyC => "KeyC",
+        KeyCode::ShiftLeft => "ShiftLeft",
+        KeyCode::ShiftRight => "ShiftRight",
+        KeyCode::Space => "Space",
+        KeyCode::Tab => "Tab",
+        KeyCode::Equal => "Equal",
+        KeyCode::Minus => "Minus",
+        KeyCode::NumpadAdd => "NumpadAdd",
+        KeyCode::NumpadSubtract => "NumpadSubtract",
+        KeyCode::Digit0 => "Digit0",
+        KeyCode::Digit1 => "Digit1",
+        KeyCode::Digit2 => "Digit2",
+        KeyCode::Digit3 => "Digit3",
+        KeyCode::Escape => "Escape",
+        KeyCode::F1 => "F1",
+        KeyCode::F2 => "F2",
+        KeyCode::F3 => "F3",
+        KeyCode::F4 => "F4",
+        _ => return None,
+    })
+}
+
+/// Tracks held keys against an [`InputLayout`] and exposes them as
+/// [`Action`] values instead of raw `KeyCode`s
+pub struct ActionHandler {
+    layout: InputLayout,
+    keys_down: HashSet<KeyCode>,
+    pressed_this_frame: Vec<Action>,
+}
+
+impl ActionHandler {
+    pub fn new(layout: InputLayout) -> Self {
+        Self {
+            layout,
+            keys_down: HashSet::new(),
+            pressed_this_frame: Vec::new(),
+        }
+    }
+
+    /// Feed a raw winit key event in. Button actions bound to `key` are
+    /// queued for the next [`ActionHandler::take_pressed`] on the press
+    /// edge only, matching how `main.rs` used to match `_ if pressed`.
+    pub fn handle_key(&mut self, key: KeyCode, pressed: bool) {
+        if pressed {
+            self.keys_down.insert(key);
+        } else {
+            self.keys_down.remove(&key);
+        }
+
+        let Some(name) = key_name(key) else { return };
+        if !pressed {
+            return;
+        }
+
+        for action in Action::ALL {
+            if action.is_axis() {
+                continue;
+            }
+            if let Some(Binding::Button(keys)) = self.layout.bindings.get(action.as_str()) {
+                if keys.iter().any(|k| k == name) {
+                    self.pressed_this_frame.push(action);
+                }
+            }
+        }
+    }
+
+    /// Drain the button actions whose bound key was pressed since the last call
+    pub fn take_pressed(&mut self) -> Vec<Action> {
+        std::mem::take(&mut self.pressed_this_frame)
+    }
+
+    fn is_down(&self, name: &str) -> bool {
+        self.keys_down.iter().any(|k| key_name(*k) == Some(name))
+    }
+
+    /// Current held state of a button action (e.g. `Boost`, used as a
+    /// modifier rather than an edge-triggered press)
+    pub fn held(&self, action: Action) -> bool {
+        match self.layout.bindings.get(action.as_str()) {
+            Some(Binding::Button(keys)) => keys.iter().any(|k| self.is_down(k)),
+            _ => false,
+        }
+    }
+
+    /// Current value of an axis action in `-1.0..=1.0`. Keyboard bindings are
+    /// digital, so this is always `-1.0`, `0.0`, or `1.0` today; splitting
+    /// this from `held` means a future analog source (gamepad stick) can
+    /// return continuous values without changing call sites.
+    pub fn axis(&self, action: Action) -> f32 {
+        match self.layout.bindings.get(action.as_str()) {
+            Some(Binding::Axis { positive, negative }) => {
+                let pos = positive.iter().any(|k| self.is_down(k));
+                let neg = negative.iter().any(|k| self.is_down(k));
+                match (pos, neg) {
+                    (true, false) => 1.0,
+                    (false, true) => -1.0,
+                    _ => 0.0,
+                }
+            }
+            _ => 0.0,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Body inspector: mouse-pick a gravitating body and show its stats
+//!
+//! `App::pick_body` casts a screen-space ray (via `Camera3D::screen_ray`)
+//! against `SolarSystem::pick` the moment a left click (not a drag)
+//! releases, mirroring the click-vs-drag threshold `gravity_sim` uses for
+//! its own selection panel. `draw_inspector_panel` then surfaces the
+//! selected body's mass, Schwarzschild radius, and locally sampled
+//! spacetime curvature, the readouts named in the request this module
+//! implements.
+
+use egui::{Color32, Context, RichText};
+
+use crate::solar_system::CelestialBody;
+
+/// What the inspector panel asked for, applied after `egui::Context::run`
+/// returns (the panel closure only borrows `self` immutably)
+pub enum InspectorAction {
+    None,
+    Deselect,
+}
+
+/// Side panel showing the picked body's mass, Schwarzschild radius, and
+/// sampled local spacetime curvature, with a way to clear the selection
+pub fn draw_inspector_panel(ctx: &Context, body: &CelestialBody, curvature: f32) -> InspectorAction {
+    let mut action = InspectorAction::None;
+
+    egui::SidePanel::left("inspector_panel")
+        .resizable(true)
+        .default_width(240.0)
+        .show(ctx, |ui| {
+            ui.heading(RichText::new(&body.name).color(Color32::LIGHT_BLUE));
+            ui.separator();
+
+            egui::Grid::new("inspector_grid")
+                .num_columns(2)
+                .spacing([10.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Mass");
+                    ui.label(format!("{:.4} M☉", body.mass));
+                    ui.end_row();
+
+                    ui.label("Radius");
+                    ui.label(format!("{:.5} AU", body.radius));
+                    ui.end_row();
+
+                    ui.label("Schwarzschild radius");
+                    ui.label(format!("{:.3e} AU", body.schwarzschild_radius()));
+                    ui.end_row();
+
+                    ui.label("Local curvature");
+                    ui.label(format!("{:.4}", curvature));
+                    ui.end_row();
+                });
+
+            ui.add_space(12.0);
+            if ui.button("Deselect").clicked() {
+                action = InspectorAction::Deselect;
+            }
+        });
+
+    action
+}
@@ -0,0 +1,84 @@
+//! Landing scene state machine
+//!
+//! Up to now the ship just flies; nothing happens when it rendezvouses
+//! with a planet. `SceneState` adds a small `Flying`/`Landed` state
+//! machine to `App`, patterned on the flying/landed scene switching found
+//! in external game scripts: detect a slow, close approach to a body,
+//! freeze the ship relative to it, and swap the flight sidebar for a
+//! landed panel with a launch button. `SceneAction` is the transition
+//! enum `App::apply_scene_action` consumes, mirroring how
+//! `scenario::ScenarioAction` is applied to `App` elsewhere in this crate.
+
+use egui::{Color32, Context, RichText};
+use glam::Vec3;
+
+use crate::solar_system::{CelestialBody, C, G};
+
+/// Relative speed (AU/year) below which a close approach counts as a
+/// landing rather than a flyby
+const LANDING_SPEED_THRESHOLD: f32 = 0.05;
+
+/// Slack added to a body's radius so touchdown doesn't require passing
+/// exactly through the surface
+const LANDING_ALTITUDE_MARGIN: f32 = 0.01;
+
+/// Where the ship is relative to the flight loop: free-flying, or resting
+/// on a body's surface at a fixed offset from its center
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SceneState {
+    Flying,
+    Landed { body: usize, offset: Vec3 },
+}
+
+/// A transition `App::apply_scene_action` applies to `SceneState`
+pub enum SceneAction {
+    Land { body: usize, offset: Vec3 },
+    Launch,
+}
+
+/// Has the ship rendezvoused with `body`: inside its radius (plus margin)
+/// and slow relative to it?
+pub fn check_landing(ship_position: Vec3, ship_velocity: Vec3, body: &CelestialBody) -> bool {
+    let distance = (ship_position - body.position).length();
+    let relative_speed = (ship_velocity - body.velocity).length();
+    distance <= body.radius + LANDING_ALTITUDE_MARGIN && relative_speed <= LANDING_SPEED_THRESHOLD
+}
+
+/// Surface gravity, `g = GM/r^2`
+pub fn surface_gravity(body: &CelestialBody) -> f32 {
+    G * body.mass / (body.radius * body.radius)
+}
+
+/// Escape velocity, `v = sqrt(2GM/r)`, the same formula
+/// `Spaceship::launch_from` uses to relaunch from the surface
+pub fn escape_velocity(body: &CelestialBody) -> f32 {
+    (2.0 * G * body.mass / body.radius).sqrt()
+}
+
+/// Draw the landed panel in place of the flight equations sidebar: body
+/// name, surface gravity, escape velocity, and a launch button. Returns
+/// `true` the frame the pilot presses "Launch".
+pub fn draw_landed_panel(ctx: &Context, body_name: &str, gravity: f32, escape_v: f32) -> bool {
+    let mut launch = false;
+
+    egui::SidePanel::right("landed_panel")
+        .resizable(true)
+        .default_width(280.0)
+        .show(ctx, |ui| {
+            ui.heading(RichText::new(format!("Landed: {body_name}")).color(Color32::LIGHT_GREEN));
+            ui.separator();
+
+            ui.group(|ui| {
+                ui.label(RichText::new("Surface conditions").strong().color(Color32::YELLOW));
+                ui.label(format!("Gravity: {:.4} AU/yr²", gravity));
+                ui.label(format!("Escape velocity: {:.5} AU/yr ({:.5}c)", escape_v, escape_v / C));
+            });
+
+            ui.add_space(12.0);
+            if ui.button(RichText::new("🚀 Launch").strong()).clicked() {
+                launch = true;
+            }
+        });
+
+    launch
+}
@@ -5,45 +5,98 @@
 //! - Black hole with gravitational lensing
 //! - Controllable spaceship with relativistic effects
 //! - Spacetime curvature visualization
+//! - `.rhai` scenario scripts (see `scenario.rs`) under a `scenarios/`
+//!   directory that can replace the default solar system, ship launch, and
+//!   black hole with scripted content
+//! - A rebindable action layer (see `input.rs`): physical keys are bound to
+//!   named actions through an `input::InputLayout`, loaded from
+//!   `input.toml` next to the executable if present, else the defaults below
+//! - A cockpit HUD (see `hud.rs`) with radial gauges for speed/γ/distance
+//!   and a rolling FPS/frame-time readout
+//! - A landing scene state machine (see `landing.rs`): rendezvous slowly
+//!   with a body's surface to touch down, swapping the sidebar for a
+//!   landed panel with a launch button to return to flight
+//! - Screen-space ray picking (see `inspector.rs`): left-click a body
+//!   (without dragging) to select it and see its mass, Schwarzschild
+//!   radius, and locally sampled spacetime curvature
+//! - An N-body accretion disk (see `disk.rs`) around any active black hole:
+//!   particles are integrated under the real gravity of the solar system
+//!   instead of a shader-faked ring, and recycled into the spawn ring when
+//!   they cross the horizon
 //!
 //! Controls:
 //! - Mouse drag: Orbit camera
+//! - Left click (without dragging): Select a body to inspect
 //! - Scroll: Zoom
 //! - WASD: Move spaceship (thrust)
 //! - Q/E: Roll spaceship
 //! - Shift: Boost thrust
 //! - Space: Pause/resume
-//! - Tab: Toggle camera mode (orbit/follow ship)
+//! - Tab: Toggle camera mode (orbit/follow ship/ship view)
+//! - C: Toggle free-fly camera (WASD/R/F fly instead of piloting the ship,
+//!   mouse-look instead of orbiting)
 //! - G: Toggle spacetime grid
 //! - T: Toggle trails
 //! - B: Add/remove black hole
+//! - O: Toggle ephemeris mode (real orbital elements vs. integrated gravity)
+//! - J: Toggle JPL mode (seed planets from real heliocentric state vectors)
 //! - 1-9: Focus on planet
 //! - +/-: Time scale
+//! - F1-F4: Switch world preset (solar system/binary star/dense cluster/
+//!   galaxy collision, see `presets.rs`)
 
 mod solar_system;
 mod spaceship;
 mod spacetime;
 mod renderer;
+mod render_graph;
+mod profiler;
+mod compute;
 mod equations_ui;
+mod autopilot;
+mod ephemeris;
+mod expr_eval;
+mod scenario;
+mod input;
+mod presets;
+mod hud;
+mod landing;
+mod inspector;
+mod disk;
 
 use common::{Camera3D, GraphicsContext};
-use glam::Vec3;
+use glam::{Vec2, Vec3};
+use std::path::Path;
+use disk::AccretionDisk;
 use solar_system::SolarSystem;
 use spaceship::Spaceship;
 use spacetime::SpacetimeGrid;
 use renderer::Renderer;
 use equations_ui::{draw_equations_sidebar, SOLAR_VOYAGE_EQUATIONS, SOLAR_VOYAGE_VARIABLES};
+use scenario::{ScenarioAction, ScenarioEvent, ScenarioScript};
+use input::{Action, ActionHandler, InputLayout};
+use presets::ScenarioPreset;
+use hud::{draw_cockpit_hud, FrameTimeHistory};
+use landing::{draw_landed_panel, SceneAction, SceneState};
+use inspector::{draw_inspector_panel, InspectorAction};
 use winit::{
     event::{ElementState, Event, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent},
     event_loop::ControlFlow,
     keyboard::{KeyCode, PhysicalKey},
 };
 
+/// A left-click release further than this from its press position (in
+/// pixels) counts as a camera drag rather than a body pick
+const CLICK_DRAG_THRESHOLD: f32 = 4.0;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum CameraMode {
     Orbit,
     FollowShip,
     ShipView,
+    /// Inertia-damped flycam: WASD/R/F move the camera itself (instead of
+    /// the ship) and mouse drag looks around instead of orbiting
+    Free,
 }
 
 struct EguiState {
@@ -62,9 +115,20 @@ struct App {
     camera_mode: CameraMode,
 
     // Input state
-    keys_pressed: KeyState,
+    input: ActionHandler,
     mouse_pressed: bool,
     last_mouse_pos: Option<(f64, f64)>,
+    cursor_pos: (f64, f64),
+    /// Cursor position at the start of the current left-button press, used
+    /// to tell a click (pick) apart from a drag (orbit)
+    mouse_down_pos: Option<(f64, f64)>,
+    /// Accumulated raw mouse-drag delta (pixels) since the last `update`,
+    /// applied to the free-fly camera's yaw/pitch and then zeroed
+    mouse_dx: f32,
+    mouse_dy: f32,
+    /// Half-life-smoothed velocity of the free-fly camera; see
+    /// `CameraMode::Free` in `update`
+    free_camera_velocity: Vec3,
 
     // Simulation state
     paused: bool,
@@ -72,46 +136,122 @@ struct App {
     show_trails: bool,
     has_black_hole: bool,
     focused_body: Option<usize>,
+    autopilot: autopilot::Autopilot,
+    /// When true, planets follow real Keplerian orbital elements instead of
+    /// being integrated from mutual gravity (toggled with `O`)
+    ephemeris_mode: bool,
+    /// When true, planets were seeded from real JPL heliocentric state
+    /// vectors instead of `init_accurate`'s circular approximation (toggled
+    /// with `J`)
+    jpl_mode: bool,
+
+    /// GPU mirror of the N-body integration, stepped alongside
+    /// `solar_system.step` every frame. Not yet consumed by rendering or
+    /// gameplay code — see `compute.rs` for the plan to bind its buffers
+    /// directly once the renderer reads body state from the GPU.
+    gpu_nbody: compute::GpuNBody,
+
+    /// The `.rhai` scenario that populated this run's solar system, if any
+    /// was found under `scenarios/`; kept around so `update` can keep
+    /// firing `event()` into it every frame
+    scenario: Option<ScenarioScript>,
+
+    /// Hard-coded world presets offered via `SelectPreset*` actions; see
+    /// `presets.rs`
+    presets: Vec<Box<dyn ScenarioPreset>>,
+    current_preset: usize,
+
+    /// Rolling frame-time average for the cockpit HUD's FPS readout
+    frame_times: FrameTimeHistory,
+
+    /// Flying/landed state machine; see `landing.rs`
+    scene: SceneState,
+
+    /// Index into `solar_system.bodies` of the body picked via mouse click;
+    /// see `inspector.rs`
+    selected_body: Option<usize>,
+
+    /// N-body accretion disk around the active black hole, if any; see
+    /// `disk.rs`
+    accretion_disk: Option<AccretionDisk>,
 
     // UI
     egui: EguiState,
 }
 
-#[derive(Default)]
-struct KeyState {
-    forward: bool,
-    backward: bool,
-    left: bool,
-    right: bool,
-    up: bool,
-    down: bool,
-    roll_left: bool,
-    roll_right: bool,
-    boost: bool,
-}
-
 impl App {
     fn new(ctx: GraphicsContext) -> Self {
-        let renderer = Renderer::new(&ctx);
+        let renderer = Renderer::new(&ctx, 4);
 
         let mut camera = Camera3D::new(ctx.aspect_ratio());
         camera.distance = 5.0;
-        camera.pitch = 0.3;
-        camera.update_orbital();
+        camera.set_pitch(0.3);
 
-        let mut solar_system = SolarSystem::new();
-        solar_system.init_accurate();
-        solar_system.time_scale = 0.5; // Half a year per second
+        let presets = presets::preset_registry();
+        let current_preset = 0;
+        let mut solar_system = presets[current_preset].build();
 
         let mut spaceship = Spaceship::new();
-        // Start in orbit around Earth
-        if let Some(earth) = solar_system.find_body("Earth") {
-            spaceship.launch_from(earth, Vec3::new(0.0, 1.0, 0.0));
+
+        // Look for a `.rhai` scenario next to the executable; the first one
+        // found (alphabetically) replaces the preset solar system and ship
+        // launch above
+        let mut scenarios = scenario::discover_scenarios(Path::new("scenarios"));
+        let scenario = (!scenarios.is_empty()).then(|| scenarios.remove(0));
+
+        let mut show_grid = true;
+        let mut show_trails = true;
+        let mut has_black_hole = false;
+        let mut ship_launched = false;
+
+        if let Some(script) = &scenario {
+            let config = script.config();
+            show_grid = config.show_grid;
+            show_trails = config.show_trails;
+            has_black_hole = config.has_black_hole;
+            solar_system.time_scale = config.time_scale;
+
+            match script.init() {
+                Ok(init) => {
+                    if !init.bodies.is_empty() {
+                        solar_system.bodies = init.bodies;
+                    }
+                    if let Some(launch) = &init.ship_launch {
+                        if let Some(body) = solar_system.find_body(&launch.body) {
+                            spaceship.launch_from(body, launch.direction);
+                            ship_launched = true;
+                        }
+                    }
+                    if has_black_hole {
+                        if let Some(bh) = &init.black_hole {
+                            solar_system.add_black_hole(bh.mass, bh.position, bh.velocity);
+                        }
+                    }
+                }
+                Err(err) => log::warn!("scenario `{}` init() failed: {err}", script.name),
+            }
         }
 
+        // Fall back to the active preset's own anchor when no scenario
+        // supplied its own launch
+        if !ship_launched {
+            let (anchor_name, anchor_direction) = presets[current_preset].spawn_anchor();
+            if let Some(anchor) = solar_system.find_body(anchor_name) {
+                spaceship.launch_from(anchor, anchor_direction);
+            }
+        }
+
+        // Load a remapped layout from `input.toml` next to the executable if
+        // one exists; otherwise fall back to the hard-coded default bindings
+        let layout = InputLayout::load_from_file(Path::new("input.toml")).unwrap_or_default();
+        let input = ActionHandler::new(layout);
+
         let mut spacetime_grid = SpacetimeGrid::new(40, 35.0);
         spacetime_grid.deformation_scale = 50.0;
 
+        let gpu_nbody = compute::GpuNBody::new(&ctx.device, 100, 128);
+        gpu_nbody.upload_initial(&ctx.queue, &solar_system.bodies);
+
         let egui_ctx = egui::Context::default();
         let egui_state = egui_winit::State::new(
             egui_ctx.clone(),
@@ -127,7 +267,7 @@ impl App {
             1,
         );
 
-        Self {
+        let mut app = Self {
             ctx,
             renderer,
             solar_system,
@@ -135,20 +275,99 @@ impl App {
             spacetime_grid,
             camera,
             camera_mode: CameraMode::Orbit,
-            keys_pressed: KeyState::default(),
+            input,
             mouse_pressed: false,
             last_mouse_pos: None,
+            cursor_pos: (0.0, 0.0),
+            mouse_down_pos: None,
+            mouse_dx: 0.0,
+            mouse_dy: 0.0,
+            free_camera_velocity: Vec3::ZERO,
             paused: false,
-            show_grid: true,
-            show_trails: true,
-            has_black_hole: false,
+            show_grid,
+            show_trails,
+            has_black_hole,
             focused_body: None,
+            autopilot: autopilot::Autopilot::new(),
+            ephemeris_mode: false,
+            jpl_mode: false,
+            gpu_nbody,
+            scenario,
+            presets,
+            current_preset,
+            frame_times: FrameTimeHistory::new(),
+            scene: SceneState::Flying,
+            selected_body: None,
+            accretion_disk: None,
             egui: EguiState {
                 ctx: egui_ctx,
                 state: egui_state,
                 renderer: egui_renderer,
             },
+        };
+
+        if app.has_black_hole {
+            app.spawn_accretion_disk();
         }
+        app
+    }
+
+    /// (Re)seed the accretion disk around the active black hole, in a ring
+    /// just outside its photon sphere (`1.5 ×` the Schwarzschild radius);
+    /// clears the disk if there is no black hole to orbit
+    fn spawn_accretion_disk(&mut self) {
+        self.accretion_disk = self.solar_system.find_body("Black Hole").map(|bh| {
+            let horizon = bh.schwarzschild_radius();
+            let inner_radius = horizon * 1.5 * 1.2;
+            let outer_radius = inner_radius * 6.0;
+            AccretionDisk::new(bh.position, bh.mass, horizon, inner_radius, outer_radius, 2000)
+        });
+    }
+
+    /// Numeric bindings for the live equations sidebar: the central body is
+    /// the black hole if one is present, otherwise the Sun, and `r`/`a` track
+    /// the ship's distance to it / the focused body's orbit
+    fn equation_bindings(&self) -> Vec<(&'static str, f64)> {
+        let central = if self.has_black_hole {
+            self.solar_system.find_body("Black Hole")
+        } else {
+            self.solar_system.find_body(self.presets[self.current_preset].central_body_name())
+        };
+
+        let g = solar_system::G as f64;
+        let c = solar_system::C as f64;
+        let mass = central.map(|b| b.mass as f64).unwrap_or(1.0);
+        let r = central
+            .map(|b| (self.spaceship.position - b.position).length().max(1e-6) as f64)
+            .unwrap_or(1.0);
+        let a = self
+            .focused_body
+            .and_then(|i| self.solar_system.bodies.get(i))
+            .map(|b| b.semi_major_axis.max(1e-6) as f64)
+            .unwrap_or(1.0);
+        let gamma = self.spaceship.lorentz_factor() as f64;
+        let v = self.spaceship.velocity.length() as f64;
+        let m = self.spaceship.mass as f64;
+
+        // Schwarzschild radius, converted from AU to meters so the sidebar
+        // reads e.g. "rₛ = 2953 m" for a stellar-mass black hole
+        let rs_au = 2.0 * g * mass / (c * c);
+        let rs_m = rs_au * solar_system::AU_KM * 1000.0;
+        let rs_over_r = rs_au / r;
+
+        vec![
+            ("G", g),
+            ("M", mass),
+            ("r", r),
+            ("a", a),
+            ("c", c),
+            ("gamma", gamma),
+            ("v", v),
+            ("m", m),
+            ("pi", std::f64::consts::PI),
+            ("rs_m", rs_m),
+            ("rs_over_r", rs_over_r),
+        ]
     }
 
     fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
@@ -158,38 +377,76 @@ impl App {
     }
 
     fn update(&mut self, dt: f32) {
+        self.frame_times.push(dt);
+
+        // Button actions (toggles, focus, time scale, ...) apply on the
+        // press edge regardless of pause state, same as the old direct
+        // `handle_key` side effects did
+        self.apply_pressed_actions();
+
         if self.paused {
             return;
         }
 
-        // Handle spaceship input
-        let thrust_strength = if self.keys_pressed.boost { 5.0 } else { 1.0 };
-
-        if self.keys_pressed.forward {
-            self.spaceship.apply_thrust(thrust_strength, dt);
-        }
-        if self.keys_pressed.backward {
-            self.spaceship.apply_thrust(-thrust_strength * 0.5, dt);
+        if self.camera_mode == CameraMode::Free {
+            // Mouse-look: apply this frame's accumulated drag to yaw/pitch,
+            // then consume it. `fly_rotate` already clamps pitch.
+            let sensitivity = 0.003;
+            self.camera.fly_rotate(-self.mouse_dx * sensitivity, -self.mouse_dy * sensitivity);
+            self.mouse_dx = 0.0;
+            self.mouse_dy = 0.0;
         }
 
-        let rotation_speed = 1.0 * dt;
-        if self.keys_pressed.left {
-            self.spaceship.rotate(0.0, rotation_speed, 0.0);
-        }
-        if self.keys_pressed.right {
-            self.spaceship.rotate(0.0, -rotation_speed, 0.0);
-        }
-        if self.keys_pressed.up {
-            self.spaceship.rotate(-rotation_speed, 0.0, 0.0);
+        if self.autopilot.mode != autopilot::GuidanceMode::Off {
+            self.autopilot.guide(&mut self.spaceship, &self.solar_system.bodies, dt);
         }
-        if self.keys_pressed.down {
-            self.spaceship.rotate(rotation_speed, 0.0, 0.0);
-        }
-        if self.keys_pressed.roll_left {
-            self.spaceship.rotate(0.0, 0.0, rotation_speed);
-        }
-        if self.keys_pressed.roll_right {
-            self.spaceship.rotate(0.0, 0.0, -rotation_speed);
+
+        let boost = self.input.held(Action::Boost);
+        let forward_back = self.input.axis(Action::MoveForwardBackward);
+        let strafe = self.input.axis(Action::Strafe);
+        let ascend = self.input.axis(Action::Ascend);
+        let roll = self.input.axis(Action::Roll);
+
+        if self.camera_mode == CameraMode::Free {
+            // WASD/R/F fly the camera itself instead of piloting the ship
+            let speed = if boost { 15.0 } else { 5.0 };
+            let target_velocity = Vec3::new(strafe * speed, ascend * speed, forward_back * speed);
+
+            // Half-life decay toward the target velocity instead of snapping
+            // to it, so flycam motion eases in and out instead of jittering
+            let half_life = 0.1;
+            let factor = (0.5f32).powf(dt / half_life);
+            self.free_camera_velocity = target_velocity + (self.free_camera_velocity - target_velocity) * factor;
+        } else if matches!(self.scene, SceneState::Flying) {
+            // Handle spaceship input
+            let thrust_strength = if boost { 5.0 } else { 1.0 };
+
+            if forward_back > 0.0 {
+                self.spaceship.apply_thrust(thrust_strength * forward_back, dt);
+            } else if forward_back < 0.0 {
+                self.spaceship.apply_thrust(thrust_strength * 0.5 * forward_back, dt);
+            } else if self.autopilot.mode == autopilot::GuidanceMode::Off {
+                // No thrust input this frame: the felt g-force should read zero,
+                // not whatever thrust was last applied
+                self.spaceship.thrust = 0.0;
+            }
+
+            let rotation_speed = 1.0 * dt;
+            if strafe < 0.0 {
+                self.spaceship.rotate(0.0, rotation_speed, 0.0);
+            } else if strafe > 0.0 {
+                self.spaceship.rotate(0.0, -rotation_speed, 0.0);
+            }
+            if ascend > 0.0 {
+                self.spaceship.rotate(-rotation_speed, 0.0, 0.0);
+            } else if ascend < 0.0 {
+                self.spaceship.rotate(rotation_speed, 0.0, 0.0);
+            }
+            if roll < 0.0 {
+                self.spaceship.rotate(0.0, 0.0, rotation_speed);
+            } else if roll > 0.0 {
+                self.spaceship.rotate(0.0, 0.0, -rotation_speed);
+            }
         }
 
         // Update simulation
@@ -197,7 +454,48 @@ impl App {
         let sub_dt = dt / substeps as f32;
         for _ in 0..substeps {
             self.solar_system.step(sub_dt);
-            self.spaceship.update(&self.solar_system.bodies, sub_dt);
+            if matches!(self.scene, SceneState::Flying) {
+                self.spaceship.update(&self.solar_system.bodies, sub_dt);
+            }
+            if let Some(disk) = &mut self.accretion_disk {
+                if let Some(bh) = self.solar_system.find_body("Black Hole") {
+                    disk.step(&self.solar_system.bodies, bh.position, bh.mass, sub_dt);
+                }
+            }
+        }
+
+        match self.scene {
+            SceneState::Flying => {
+                if let Some((body, offset)) = self.detect_landing() {
+                    self.apply_scene_action(SceneAction::Land { body, offset });
+                }
+            }
+            SceneState::Landed { body, offset } => {
+                // Physics is frozen for a landed ship: it just rides along
+                // with the body it touched down on
+                if let Some(b) = self.solar_system.bodies.get(body) {
+                    self.spaceship.position = b.position + offset;
+                    self.spaceship.velocity = b.velocity;
+                }
+            }
+        }
+
+        // Step the GPU integrator in lockstep with the CPU one. Nothing
+        // reads its output yet, but keeping it seeded and dispatched every
+        // frame means its buffers are always a valid, current snapshot once
+        // the renderer is wired to bind them directly.
+        let mut gpu_encoder = self.ctx.device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("N-Body Compute Encoder"),
+        });
+        self.gpu_nbody.dispatch(&self.ctx.queue, &mut gpu_encoder, self.solar_system.bodies.len() as u32, dt);
+        self.ctx.queue.submit(std::iter::once(gpu_encoder.finish()));
+
+        if let Some(script) = &self.scenario {
+            match script.event(ScenarioEvent::TimeElapsed { dt }) {
+                Ok(Some(action)) => self.apply_scenario_action(action),
+                Ok(None) => {}
+                Err(err) => log::warn!("scenario `{}` event() failed: {err}", script.name),
+            }
         }
 
         // Update spacetime grid
@@ -205,28 +503,234 @@ impl App {
             self.spacetime_grid.update(&self.solar_system.bodies);
         }
 
-        // Update camera based on mode
-        match self.camera_mode {
-            CameraMode::Orbit => {
-                if let Some(idx) = self.focused_body {
-                    if idx < self.solar_system.bodies.len() {
-                        self.camera.target = self.solar_system.bodies[idx].position;
+        // Update camera based on mode, unless landed: a landed ship
+        // reparents the camera to a close, surface-relative view of the
+        // body it's sitting on regardless of the selected camera mode
+        if let SceneState::Landed { body, .. } = self.scene {
+            if let Some(b) = self.solar_system.bodies.get(body) {
+                self.camera.target = self.spaceship.position;
+                self.camera.distance = (b.radius * 4.0).max(0.05);
+                self.camera.update_orbital();
+            }
+        } else {
+            match self.camera_mode {
+                CameraMode::Orbit => {
+                    if let Some(idx) = self.focused_body {
+                        if idx < self.solar_system.bodies.len() {
+                            self.camera.target = self.solar_system.bodies[idx].position;
+                        }
                     }
+                    self.camera.update_orbital();
+                }
+                CameraMode::FollowShip => {
+                    self.camera.target = self.spaceship.position;
+                    self.camera.update_orbital();
+                }
+                CameraMode::ShipView => {
+                    self.camera.position = self.spaceship.position;
+                    self.camera.target = self.spaceship.position + self.spaceship.forward();
+                    self.camera.up = self.spaceship.up();
+                }
+                CameraMode::Free => {
+                    self.camera.fly_move(self.free_camera_velocity, dt);
                 }
-                self.camera.update_orbital();
             }
-            CameraMode::FollowShip => {
-                self.camera.target = self.spaceship.position;
-                self.camera.update_orbital();
+        }
+    }
+
+    /// Scan bodies for one the ship has rendezvoused with: close and slow
+    /// relative to it. Returns the body index and the ship's
+    /// surface-relative offset to freeze it at.
+    fn detect_landing(&self) -> Option<(usize, Vec3)> {
+        self.solar_system.bodies.iter().enumerate().find_map(|(i, body)| {
+            landing::check_landing(self.spaceship.position, self.spaceship.velocity, body)
+                .then(|| (i, self.spaceship.position - body.position))
+        })
+    }
+
+    /// Apply a landing-state transition; mirrors `apply_scenario_action`
+    fn apply_scene_action(&mut self, action: SceneAction) {
+        match action {
+            SceneAction::Land { body, offset } => {
+                self.scene = SceneState::Landed { body, offset };
+            }
+            SceneAction::Launch => {
+                if let SceneState::Landed { body, offset } = self.scene {
+                    if let Some(b) = self.solar_system.bodies.get(body) {
+                        self.spaceship.launch_from(b, offset);
+                    }
+                }
+                self.scene = SceneState::Flying;
+            }
+        }
+    }
+
+    /// Apply an action a scenario script's `event()` callback returned
+    fn apply_scenario_action(&mut self, action: ScenarioAction) {
+        match action {
+            ScenarioAction::FocusBody(name) => {
+                self.focused_body = self.solar_system.bodies.iter().position(|b| b.name == name);
             }
-            CameraMode::ShipView => {
-                self.camera.position = self.spaceship.position;
-                self.camera.target = self.spaceship.position + self.spaceship.forward();
-                self.camera.up = self.spaceship.up();
+            ScenarioAction::SetBlackHole(enabled) => {
+                if enabled && !self.has_black_hole {
+                    self.solar_system.add_black_hole(
+                        10.0,
+                        Vec3::new(50.0, 5.0, 30.0),
+                        Vec3::new(-2.0, -0.2, -1.0),
+                    );
+                    self.has_black_hole = true;
+                    self.spawn_accretion_disk();
+                } else if !enabled && self.has_black_hole {
+                    self.solar_system.bodies.retain(|b| b.body_type != solar_system::BodyType::BlackHole);
+                    self.has_black_hole = false;
+                    self.accretion_disk = None;
+                }
+            }
+            ScenarioAction::SetTimeScale(scale) => {
+                self.solar_system.time_scale = scale;
             }
         }
     }
 
+    /// Apply the button actions `ActionHandler` queued since the last call:
+    /// everything that used to run directly out of `handle_key`'s `_ if
+    /// pressed` arm
+    fn apply_pressed_actions(&mut self) {
+        for action in self.input.take_pressed() {
+            match action {
+                Action::PauseResume => self.paused = !self.paused,
+                Action::CycleCameraMode => {
+                    self.camera_mode = match self.camera_mode {
+                        CameraMode::Orbit => CameraMode::FollowShip,
+                        CameraMode::FollowShip => CameraMode::ShipView,
+                        CameraMode::ShipView | CameraMode::Free => CameraMode::Orbit,
+                    };
+                }
+                Action::ToggleFreeCam => {
+                    self.camera_mode = if self.camera_mode == CameraMode::Free {
+                        CameraMode::Orbit
+                    } else {
+                        self.free_camera_velocity = Vec3::ZERO;
+                        CameraMode::Free
+                    };
+                }
+                Action::ToggleGrid => self.show_grid = !self.show_grid,
+                Action::ToggleTrails => self.show_trails = !self.show_trails,
+                Action::ToggleEphemeris => {
+                    self.ephemeris_mode = !self.ephemeris_mode;
+                    let time_scale = self.solar_system.time_scale;
+                    if self.ephemeris_mode {
+                        self.solar_system.init_ephemeris();
+                    } else {
+                        self.solar_system.init_accurate();
+                    }
+                    self.solar_system.time_scale = time_scale;
+                    self.has_black_hole = false;
+                }
+                Action::ToggleJpl => {
+                    self.jpl_mode = !self.jpl_mode;
+                    let time_scale = self.solar_system.time_scale;
+                    if self.jpl_mode {
+                        self.solar_system.init_jpl();
+                    } else {
+                        self.solar_system.init_accurate();
+                    }
+                    self.solar_system.time_scale = time_scale;
+                    self.has_black_hole = false;
+                    self.ephemeris_mode = false;
+                }
+                Action::ToggleAutopilot => {
+                    if self.autopilot.mode == autopilot::GuidanceMode::Off {
+                        if let Some(target) = self.focused_body {
+                            self.autopilot.engage(autopilot::GuidanceMode::ProportionalNavigation, target);
+                        }
+                    } else {
+                        self.autopilot.disengage();
+                    }
+                }
+                Action::ToggleBlackHole => {
+                    if self.has_black_hole {
+                        // Remove black hole (keep only first 9 bodies)
+                        self.solar_system.bodies.truncate(9);
+                        self.has_black_hole = false;
+                        self.accretion_disk = None;
+                    } else {
+                        // Add a stellar-mass black hole approaching the solar system
+                        self.solar_system.add_black_hole(
+                            10.0, // 10 solar masses
+                            Vec3::new(50.0, 5.0, 30.0),
+                            Vec3::new(-2.0, -0.2, -1.0),
+                        );
+                        self.has_black_hole = true;
+                        self.spawn_accretion_disk();
+                    }
+                }
+                Action::TimeScaleUp => self.solar_system.time_scale *= 2.0,
+                Action::TimeScaleDown => self.solar_system.time_scale /= 2.0,
+                Action::FocusSun => {
+                    self.focused_body = Some(0); // Sun
+                    self.camera.distance = 5.0;
+                }
+                Action::FocusEarth => {
+                    self.focused_body = Some(3); // Earth
+                    self.camera.distance = 0.5;
+                }
+                Action::FocusJupiter => {
+                    self.focused_body = Some(5); // Jupiter
+                    self.camera.distance = 2.0;
+                }
+                Action::FocusSaturn => {
+                    self.focused_body = Some(6); // Saturn
+                    self.camera.distance = 2.0;
+                }
+                Action::ClearFocus => {
+                    self.focused_body = None;
+                    self.camera.distance = 5.0;
+                    self.camera.target = Vec3::ZERO;
+                }
+                Action::SelectPresetSolarSystem => self.switch_preset(0),
+                Action::SelectPresetBinaryStar => self.switch_preset(1),
+                Action::SelectPresetDenseCluster => self.switch_preset(2),
+                Action::SelectPresetGalaxyCollision => self.switch_preset(3),
+                // Axis actions and `Boost` are read continuously via
+                // `ActionHandler::axis`/`held`, not as press edges
+                Action::MoveForwardBackward | Action::Strafe | Action::Ascend | Action::Roll | Action::Boost => {}
+            }
+        }
+    }
+
+    /// Rebuild the world around a different `ScenarioPreset`, same as
+    /// swapping in a `.rhai` scenario's `init()` but for a built-in preset
+    fn switch_preset(&mut self, index: usize) {
+        if index >= self.presets.len() {
+            return;
+        }
+
+        self.solar_system = self.presets[index].build();
+
+        self.spaceship = Spaceship::new();
+        let (anchor_name, anchor_direction) = self.presets[index].spawn_anchor();
+        if let Some(anchor) = self.solar_system.find_body(anchor_name) {
+            self.spaceship.launch_from(anchor, anchor_direction);
+        }
+
+        self.spacetime_grid = SpacetimeGrid::new(40, 35.0);
+        self.spacetime_grid.deformation_scale = 50.0;
+
+        self.has_black_hole = false;
+        self.ephemeris_mode = false;
+        self.jpl_mode = false;
+        self.autopilot.disengage();
+        self.focused_body = None;
+        self.selected_body = None;
+        self.accretion_disk = None;
+        self.scene = SceneState::Flying;
+        self.camera.distance = 5.0;
+        self.camera.target = Vec3::ZERO;
+
+        self.current_preset = index;
+    }
+
     fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
         let output = self.ctx.surface.get_current_texture()?;
         let view = output.texture.create_view(&wgpu::TextureViewDescriptor::default());
@@ -234,24 +738,66 @@ impl App {
         let grid = if self.show_grid { Some(&self.spacetime_grid) } else { None };
 
         let render_data = self.renderer.update(
+            &self.ctx.device,
             &self.ctx.queue,
             &self.camera,
             &self.solar_system,
             &self.spaceship,
             grid,
+            self.accretion_disk.as_ref(),
         );
 
         // Build egui UI
         let raw_input = self.egui.state.take_egui_input(&self.ctx.window);
         let lorentz = self.spaceship.lorentz_factor();
+        let bindings = self.equation_bindings();
+        let distance_to_focus = self
+            .focused_body
+            .and_then(|i| self.solar_system.bodies.get(i))
+            .map(|b| (self.spaceship.position - b.position).length());
+        let landed_info = if let SceneState::Landed { body, .. } = self.scene {
+            self.solar_system
+                .bodies
+                .get(body)
+                .map(|b| (b.name.clone(), landing::surface_gravity(b), landing::escape_velocity(b)))
+        } else {
+            None
+        };
+
+        let selected_info = self.selected_body.and_then(|i| self.solar_system.bodies.get(i)).map(|b| {
+            let curvature = self.spacetime_grid.sample_curvature(b.position);
+            (b.clone(), curvature)
+        });
+
+        let mut launch_clicked = false;
+        let mut inspector_action = InspectorAction::None;
         let full_output = self.egui.ctx.run(raw_input, |ctx| {
-            draw_equations_sidebar(
+            if let Some((body, curvature)) = &selected_info {
+                inspector_action = draw_inspector_panel(ctx, body, *curvature);
+            }
+
+            draw_cockpit_hud(
                 ctx,
-                "Orbital Mechanics & Relativity",
-                SOLAR_VOYAGE_EQUATIONS,
-                SOLAR_VOYAGE_VARIABLES,
+                self.spaceship.velocity_fraction_c(),
+                lorentz,
+                distance_to_focus,
+                &self.frame_times,
             );
 
+            // A landed ship gets a landed panel instead of the flight
+            // equations sidebar
+            if let Some((name, gravity, escape_v)) = &landed_info {
+                launch_clicked = draw_landed_panel(ctx, name, *gravity, *escape_v);
+            } else {
+                draw_equations_sidebar(
+                    ctx,
+                    "Orbital Mechanics & Relativity",
+                    SOLAR_VOYAGE_EQUATIONS,
+                    SOLAR_VOYAGE_VARIABLES,
+                    &bindings,
+                );
+            }
+
             egui::TopBottomPanel::top("status").show(ctx, |ui| {
                 ui.horizontal(|ui| {
                     ui.label(format!("Time: {:.2} years", self.solar_system.time));
@@ -271,10 +817,21 @@ impl App {
                         ui.separator();
                         ui.label(egui::RichText::new("BLACK HOLE").color(egui::Color32::RED));
                     }
+                    if landed_info.is_some() {
+                        ui.separator();
+                        ui.label(egui::RichText::new("LANDED").color(egui::Color32::LIGHT_GREEN));
+                    }
                 });
             });
         });
 
+        if launch_clicked {
+            self.apply_scene_action(SceneAction::Launch);
+        }
+        if matches!(inspector_action, InspectorAction::Deselect) {
+            self.selected_body = None;
+        }
+
         self.egui.state.handle_platform_output(&self.ctx.window, full_output.platform_output);
         let tris = self.egui.ctx.tessellate(full_output.shapes, full_output.pixels_per_point);
         for (id, image_delta) in &full_output.textures_delta.set {
@@ -334,98 +891,78 @@ impl App {
     }
 
     fn handle_key(&mut self, key: KeyCode, pressed: bool) {
-        match key {
-            // Movement
-            KeyCode::KeyW => self.keys_pressed.forward = pressed,
-            KeyCode::KeyS => self.keys_pressed.backward = pressed,
-            KeyCode::KeyA => self.keys_pressed.left = pressed,
-            KeyCode::KeyD => self.keys_pressed.right = pressed,
-            KeyCode::KeyR => self.keys_pressed.up = pressed,
-            KeyCode::KeyF => self.keys_pressed.down = pressed,
-            KeyCode::KeyQ => self.keys_pressed.roll_left = pressed,
-            KeyCode::KeyE => self.keys_pressed.roll_right = pressed,
-            KeyCode::ShiftLeft | KeyCode::ShiftRight => self.keys_pressed.boost = pressed,
-
-            _ if pressed => {
-                // Only handle on press
-                match key {
-                    KeyCode::Space => self.paused = !self.paused,
-                    KeyCode::Tab => {
-                        self.camera_mode = match self.camera_mode {
-                            CameraMode::Orbit => CameraMode::FollowShip,
-                            CameraMode::FollowShip => CameraMode::ShipView,
-                            CameraMode::ShipView => CameraMode::Orbit,
-                        };
-                    }
-                    KeyCode::KeyG => self.show_grid = !self.show_grid,
-                    KeyCode::KeyT => self.show_trails = !self.show_trails,
-                    KeyCode::KeyB => {
-                        if self.has_black_hole {
-                            // Remove black hole (keep only first 9 bodies)
-                            self.solar_system.bodies.truncate(9);
-                            self.has_black_hole = false;
-                        } else {
-                            // Add a stellar-mass black hole approaching the solar system
-                            self.solar_system.add_black_hole(
-                                10.0, // 10 solar masses
-                                Vec3::new(50.0, 5.0, 30.0),
-                                Vec3::new(-2.0, -0.2, -1.0),
-                            );
-                            self.has_black_hole = true;
-                        }
-                    }
-                    KeyCode::Equal | KeyCode::NumpadAdd => {
-                        self.solar_system.time_scale *= 2.0;
-                    }
-                    KeyCode::Minus | KeyCode::NumpadSubtract => {
-                        self.solar_system.time_scale /= 2.0;
-                    }
-                    // Focus on bodies
-                    KeyCode::Digit0 => {
-                        self.focused_body = Some(0); // Sun
-                        self.camera.distance = 5.0;
-                    }
-                    KeyCode::Digit1 => {
-                        self.focused_body = Some(3); // Earth
-                        self.camera.distance = 0.5;
-                    }
-                    KeyCode::Digit2 => {
-                        self.focused_body = Some(5); // Jupiter
-                        self.camera.distance = 2.0;
-                    }
-                    KeyCode::Digit3 => {
-                        self.focused_body = Some(6); // Saturn
-                        self.camera.distance = 2.0;
-                    }
-                    KeyCode::Escape => {
-                        self.focused_body = None;
-                        self.camera.distance = 5.0;
-                        self.camera.target = Vec3::ZERO;
-                    }
-                    _ => {}
-                }
-            }
-            _ => {}
-        }
+        self.input.handle_key(key, pressed);
     }
 
     fn handle_mouse_move(&mut self, x: f64, y: f64) {
+        self.cursor_pos = (x, y);
+
         if self.mouse_pressed && self.camera_mode != CameraMode::ShipView {
             if let Some((last_x, last_y)) = self.last_mouse_pos {
-                let dx = (x - last_x) as f32 * 0.01;
-                let dy = (y - last_y) as f32 * 0.01;
-                self.camera.orbit(dx, dy);
+                if self.camera_mode == CameraMode::Free {
+                    self.mouse_dx += (x - last_x) as f32;
+                    self.mouse_dy += (y - last_y) as f32;
+                } else {
+                    let from = self.cursor_to_ndc(last_x, last_y);
+                    let to = self.cursor_to_ndc(x, y);
+                    self.camera.arcball_drag(from, to);
+                }
             }
             self.last_mouse_pos = Some((x, y));
         }
     }
 
+    /// Map a cursor position in physical pixels to normalized device
+    /// coordinates (`[-1, 1]`, Y up) for `Camera3D::arcball_drag`
+    fn cursor_to_ndc(&self, x: f64, y: f64) -> Vec2 {
+        let width = self.ctx.size.width as f32;
+        let height = self.ctx.size.height as f32;
+        Vec2::new(
+            (x as f32 / width) * 2.0 - 1.0,
+            1.0 - (y as f32 / height) * 2.0,
+        )
+    }
+
     fn handle_scroll(&mut self, delta: f32) {
-        if self.camera_mode != CameraMode::ShipView {
+        if self.camera_mode != CameraMode::ShipView && self.camera_mode != CameraMode::Free {
             self.camera.zoom(delta * self.camera.distance * 0.1);
         }
     }
 
+    /// Cast a ray from the camera through `cursor` and select the nearest
+    /// body whose bounding sphere it intersects, if any
+    fn pick_body(&mut self, cursor: (f64, f64)) {
+        let (origin, dir) = self.camera.screen_ray(
+            cursor.0 as f32,
+            cursor.1 as f32,
+            self.ctx.size.width as f32,
+            self.ctx.size.height as f32,
+        );
+
+        self.selected_body = self.solar_system.pick(origin, dir);
+    }
+
+    /// Left mouse button pressed or released: begins/ends an orbit drag, and
+    /// a press-release with negligible movement in between is treated as a
+    /// click that picks the body under the cursor
+    fn handle_mouse_button(&mut self, pressed: bool) {
+        self.mouse_pressed = pressed;
+
+        if pressed {
+            self.mouse_down_pos = Some(self.cursor_pos);
+            return;
+        }
+
+        self.last_mouse_pos = None;
+        if let Some((down_x, down_y)) = self.mouse_down_pos.take() {
+            let (x, y) = self.cursor_pos;
+            let dist = ((x - down_x).powi(2) + (y - down_y).powi(2)).sqrt() as f32;
+            if dist <= CLICK_DRAG_THRESHOLD {
+                self.pick_body(self.cursor_pos);
+            }
+        }
+    }
+
     fn handle_window_event(&mut self, event: &WindowEvent) -> bool {
         self.egui.state.on_window_event(&self.ctx.window, event).consumed
     }
@@ -455,10 +992,7 @@ fn main() {
                             WindowEvent::Resized(size) => app.resize(*size),
                             WindowEvent::MouseInput { state, button, .. } => {
                                 if *button == MouseButton::Left {
-                                    app.mouse_pressed = *state == ElementState::Pressed;
-                                    if !app.mouse_pressed {
-                                        app.last_mouse_pos = None;
-                                    }
+                                    app.handle_mouse_button(*state == ElementState::Pressed);
                                 }
                             }
                             WindowEvent::CursorMoved { position, .. } => {
@@ -0,0 +1,260 @@
+//! Hard-coded alternative solar-system presets, selectable at runtime
+//!
+//! `scenario.rs` lets a `.rhai` script describe a one-off `SolarSystem` to
+//! load from disk. `ScenarioPreset` is the Rust-side equivalent for presets
+//! that ship with the binary — an accurate solar system, a binary star, a
+//! dense N-body cluster, a galaxy collision — each just knowing how to build
+//! its own `SolarSystem` and where to anchor the ship. `App` still drives one
+//! concrete `SolarSystem`: the renderer, spaceship, autopilot, ephemeris, and
+//! GPU mirror in this crate all assume that type, so switching presets
+//! rebuilds it in place (`App::switch_preset`) the same way a `.rhai`
+//! scenario's `init()` does, rather than swapping out a trait object.
+
+use std::f32::consts::TAU;
+
+use glam::Vec3;
+use rand::Rng;
+
+use crate::solar_system::{BodyType, CelestialBody, SolarSystem, G};
+
+/// One selectable alternative to the default solar system
+pub trait ScenarioPreset {
+    /// Display name shown in the preset list
+    fn name(&self) -> &'static str;
+
+    /// Build a fresh `SolarSystem` for this preset
+    fn build(&self) -> SolarSystem;
+
+    /// Name of the body whose mass dominates the equations sidebar's central
+    /// bindings (`r`, Schwarzschild radius, ...), in place of the hard-coded
+    /// "Sun"
+    fn central_body_name(&self) -> &'static str;
+
+    /// Body to launch the ship from, and in which direction
+    fn spawn_anchor(&self) -> (&'static str, Vec3);
+}
+
+/// All presets, in the order they're offered to the player
+pub fn preset_registry() -> Vec<Box<dyn ScenarioPreset>> {
+    vec![
+        Box::new(AccurateSolarSystem),
+        Box::new(BinaryStarSystem),
+        Box::new(DenseCluster),
+        Box::new(GalaxyCollision),
+    ]
+}
+
+/// Zero a system's net linear momentum by offsetting its heaviest body — the
+/// same idea `SolarSystem::offset_momentum` uses for the Sun specifically,
+/// generalized to presets that have no single body named "Sun"
+fn zero_net_momentum(bodies: &mut [CelestialBody]) {
+    let Some(anchor) = bodies
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.mass.total_cmp(&b.1.mass))
+        .map(|(i, _)| i)
+    else {
+        return;
+    };
+
+    let anchor_mass = bodies[anchor].mass;
+    let momentum: Vec3 = bodies
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != anchor)
+        .map(|(_, b)| b.mass * b.velocity)
+        .sum();
+
+    bodies[anchor].velocity = -momentum / anchor_mass;
+}
+
+/// The default preset: real planetary data, unchanged from `App::new`'s
+/// previous hard-coded setup
+pub struct AccurateSolarSystem;
+
+impl ScenarioPreset for AccurateSolarSystem {
+    fn name(&self) -> &'static str {
+        "Solar System"
+    }
+
+    fn build(&self) -> SolarSystem {
+        let mut system = SolarSystem::new();
+        system.init_accurate();
+        system.time_scale = 0.5;
+        system
+    }
+
+    fn central_body_name(&self) -> &'static str {
+        "Sun"
+    }
+
+    fn spawn_anchor(&self) -> (&'static str, Vec3) {
+        ("Earth", Vec3::new(0.0, 1.0, 0.0))
+    }
+}
+
+/// Two stars on a circular mutual orbit, with a couple of circumbinary
+/// planets further out
+pub struct BinaryStarSystem;
+
+impl ScenarioPreset for BinaryStarSystem {
+    fn name(&self) -> &'static str {
+        "Binary Star"
+    }
+
+    fn build(&self) -> SolarSystem {
+        let mut system = SolarSystem::new();
+
+        let mass_a = 1.2;
+        let mass_b = 0.8;
+        let separation = 0.5; // AU between the two stars
+        let total_mass = mass_a + mass_b;
+
+        // Circular two-body orbit: each star traces a circle of radius
+        // proportional to the other's mass around the shared barycenter, at
+        // the angular speed of a Kepler orbit with the combined mass
+        let omega = (G * total_mass / separation.powi(3)).sqrt();
+        let r_a = separation * mass_b / total_mass;
+        let r_b = separation * mass_a / total_mass;
+
+        let mut star_a = CelestialBody::new("Star A", BodyType::Star, mass_a, 0.005, [1.0, 0.85, 0.6, 1.0])
+            .with_trail_length(400);
+        star_a.position = Vec3::new(-r_a, 0.0, 0.0);
+        star_a.velocity = Vec3::new(0.0, 0.0, omega * r_a);
+        system.bodies.push(star_a);
+
+        let mut star_b = CelestialBody::new("Star B", BodyType::Star, mass_b, 0.0045, [0.6, 0.75, 1.0, 1.0])
+            .with_trail_length(400);
+        star_b.position = Vec3::new(r_b, 0.0, 0.0);
+        star_b.velocity = Vec3::new(0.0, 0.0, -omega * r_b);
+        system.bodies.push(star_b);
+
+        // Circumbinary planets, far enough out that the binary looks like a
+        // single point mass to them
+        for (name, distance, mass, color) in [
+            ("Planet I", 3.0, 3.0e-6, [0.3, 0.6, 0.4, 1.0]),
+            ("Planet II", 5.0, 8.0e-6, [0.8, 0.5, 0.3, 1.0]),
+        ] {
+            let orbital_speed = (G * total_mass / distance).sqrt();
+            let mut planet = CelestialBody::new(name, BodyType::Planet, mass, 0.006, color).with_trail_length(300);
+            planet.position = Vec3::new(distance, 0.0, 0.0);
+            planet.velocity = Vec3::new(0.0, 0.0, orbital_speed);
+            system.bodies.push(planet);
+        }
+
+        zero_net_momentum(&mut system.bodies);
+        system.time_scale = 0.25;
+        system
+    }
+
+    fn central_body_name(&self) -> &'static str {
+        "Star A"
+    }
+
+    fn spawn_anchor(&self) -> (&'static str, Vec3) {
+        ("Planet I", Vec3::new(0.0, 1.0, 0.0))
+    }
+}
+
+/// A massive core with a disk of low-mass bodies in randomized circular
+/// orbits, dense enough that mutual perturbations between them (not just
+/// from the core) are visible within a few orbits
+pub struct DenseCluster;
+
+impl ScenarioPreset for DenseCluster {
+    fn name(&self) -> &'static str {
+        "Dense Cluster"
+    }
+
+    fn build(&self) -> SolarSystem {
+        let mut system = SolarSystem::new();
+        let mut rng = rand::thread_rng();
+
+        let core_mass = 3.0;
+        let core = CelestialBody::new("Cluster Core", BodyType::Star, core_mass, 0.01, [1.0, 0.9, 0.8, 1.0])
+            .with_trail_length(0);
+        system.bodies.push(core);
+
+        for i in 0..40 {
+            let distance = 0.3 + rng.gen::<f32>() * 4.0;
+            let angle = rng.gen::<f32>() * TAU;
+            let height = (rng.gen::<f32>() - 0.5) * 0.3;
+            let mass = 1.0e-6 + rng.gen::<f32>() * 5.0e-6;
+
+            let orbital_speed = (G * core_mass / distance).sqrt();
+            let mut body = CelestialBody::new(&format!("Cluster Body {i}"), BodyType::Asteroid, mass, 0.003, [0.7, 0.7, 0.8, 1.0])
+                .with_trail_length(150);
+            body.position = Vec3::new(angle.cos() * distance, height, angle.sin() * distance);
+            body.velocity = Vec3::new(-angle.sin() * orbital_speed, 0.0, angle.cos() * orbital_speed);
+            system.bodies.push(body);
+        }
+
+        system.time_scale = 0.1;
+        system
+    }
+
+    fn central_body_name(&self) -> &'static str {
+        "Cluster Core"
+    }
+
+    fn spawn_anchor(&self) -> (&'static str, Vec3) {
+        ("Cluster Core", Vec3::new(0.0, 1.0, 0.0))
+    }
+}
+
+/// Two mini-clusters, each orbiting its own core, on a collision course with
+/// each other
+pub struct GalaxyCollision;
+
+impl ScenarioPreset for GalaxyCollision {
+    fn name(&self) -> &'static str {
+        "Galaxy Collision"
+    }
+
+    fn build(&self) -> SolarSystem {
+        let mut system = SolarSystem::new();
+        let mut rng = rand::thread_rng();
+
+        let core_mass = 4.0;
+        let galaxies = [
+            ("Galaxy A", Vec3::new(-6.0, 0.0, 0.0), Vec3::new(0.3, 0.0, 0.0), [0.6, 0.7, 1.0, 1.0]),
+            ("Galaxy B", Vec3::new(6.0, 0.0, 0.0), Vec3::new(-0.3, 0.0, 0.0), [1.0, 0.7, 0.6, 1.0]),
+        ];
+
+        for (name, center, bulk_velocity, color) in galaxies {
+            let mut core = CelestialBody::new(&format!("{name} Core"), BodyType::Star, core_mass, 0.012, color)
+                .with_trail_length(0);
+            core.position = center;
+            core.velocity = bulk_velocity;
+            system.bodies.push(core);
+
+            for i in 0..20 {
+                let distance = 0.3 + rng.gen::<f32>() * 2.5;
+                let angle = rng.gen::<f32>() * TAU;
+                let height = (rng.gen::<f32>() - 0.5) * 0.2;
+                let mass = 1.0e-6 + rng.gen::<f32>() * 3.0e-6;
+
+                let orbital_speed = (G * core_mass / distance).sqrt();
+                let local_position = Vec3::new(angle.cos() * distance, height, angle.sin() * distance);
+                let local_velocity = Vec3::new(-angle.sin() * orbital_speed, 0.0, angle.cos() * orbital_speed);
+
+                let mut star = CelestialBody::new(&format!("{name} Star {i}"), BodyType::Asteroid, mass, 0.0025, color)
+                    .with_trail_length(120);
+                star.position = center + local_position;
+                star.velocity = bulk_velocity + local_velocity;
+                system.bodies.push(star);
+            }
+        }
+
+        system.time_scale = 0.15;
+        system
+    }
+
+    fn central_body_name(&self) -> &'static str {
+        "Galaxy A Core"
+    }
+
+    fn spawn_anchor(&self) -> (&'static str, Vec3) {
+        ("Galaxy A Core", Vec3::new(0.0, 1.0, 0.0))
+    }
+}
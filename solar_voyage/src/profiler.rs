@@ -0,0 +1,111 @@
+//! Optional per-pass GPU timestamp profiler
+//!
+//! The render graph's passes record with `timestamp_writes: None`, leaving
+//! frame cost readable only as a single CPU-side number. `PassProfiler`
+//! wraps a `wgpu::QuerySet` of type `Timestamp` around each named pass and
+//! resolves it into a mappable buffer once a frame, the kind of
+//! instrumentation the learn-wgpu performance research branch explored.
+//! Gated behind `Features::TIMESTAMP_QUERY`: on adapters that lack it,
+//! `PassProfiler::new` returns `None` and every call site already falls back
+//! to `timestamp_writes: None`, unchanged from before this existed.
+
+use std::collections::HashMap;
+
+/// Size in bytes of one resolved timestamp query
+const QUERY_SIZE: u64 = 8;
+
+pub struct PassProfiler {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    readback_buffer: wgpu::Buffer,
+    pass_names: Vec<&'static str>,
+    timestamp_period: f32,
+    last_timings: HashMap<&'static str, f32>,
+}
+
+impl PassProfiler {
+    /// `None` if the adapter doesn't support `Features::TIMESTAMP_QUERY`;
+    /// `pass_names` fixes both the query-set size and the order passes must
+    /// call `timestamp_writes` in
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, pass_names: &[&'static str]) -> Option<Self> {
+        if !device.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+            return None;
+        }
+
+        let count = (pass_names.len() * 2) as u32;
+        let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("Pass Profiler Query Set"),
+            ty: wgpu::QueryType::Timestamp,
+            count,
+        });
+        let resolve_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Pass Profiler Resolve Buffer"),
+            size: count as u64 * QUERY_SIZE,
+            usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Pass Profiler Readback Buffer"),
+            size: count as u64 * QUERY_SIZE,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        Some(Self {
+            query_set,
+            resolve_buffer,
+            readback_buffer,
+            pass_names: pass_names.to_vec(),
+            timestamp_period: queue.get_timestamp_period(),
+            last_timings: HashMap::new(),
+        })
+    }
+
+    /// Begin/end timestamp writes for the `index`-th pass in the
+    /// `pass_names` list passed to `new`, to splice into that pass's
+    /// `begin_render_pass` descriptor
+    pub fn timestamp_writes(&self, index: usize) -> wgpu::RenderPassTimestampWrites<'_> {
+        wgpu::RenderPassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some((index * 2) as u32),
+            end_of_pass_write_index: Some((index * 2 + 1) as u32),
+        }
+    }
+
+    /// Resolve this frame's queries into the mappable readback buffer; call
+    /// once per frame after every profiled pass has recorded, before
+    /// `queue.submit`
+    pub fn resolve(&self, encoder: &mut wgpu::CommandEncoder) {
+        let count = (self.pass_names.len() * 2) as u32;
+        encoder.resolve_query_set(&self.query_set, 0..count, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(&self.resolve_buffer, 0, &self.readback_buffer, 0, count as u64 * QUERY_SIZE);
+    }
+
+    /// Map back the most recently resolved queries and convert each pass's
+    /// (begin, end) pair into milliseconds via `Queue::get_timestamp_period`.
+    /// Blocks on `device.poll` for the map to complete, so call it at most
+    /// once a frame (e.g. from a debug overlay), not per draw call.
+    pub fn last_frame_timings(&mut self, device: &wgpu::Device) -> &HashMap<&'static str, f32> {
+        let slice = self.readback_buffer.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+
+        if let Ok(Ok(())) = rx.recv() {
+            let data = slice.get_mapped_range();
+            let raw: &[u64] = bytemuck::cast_slice(&data);
+            for (i, &name) in self.pass_names.iter().enumerate() {
+                let begin = raw[i * 2];
+                let end = raw[i * 2 + 1];
+                let ms = end.saturating_sub(begin) as f32 * self.timestamp_period / 1_000_000.0;
+                self.last_timings.insert(name, ms);
+            }
+            drop(data);
+            self.readback_buffer.unmap();
+        }
+
+        &self.last_timings
+    }
+}
@@ -0,0 +1,122 @@
+//! Minimal pass-based render graph
+//!
+//! Inspired by the `RenderGraphPass`/slot design used by engines like
+//! lyra-engine: a [`RenderGraph`] holds an ordered list of [`RenderPass`]
+//! nodes, each declaring the named resource slots it reads and writes.
+//! Passes are recorded in insertion order, so wiring in a new pass (bloom,
+//! a post-process warp, picking) is a matter of pushing a node between two
+//! existing ones rather than editing a monolithic `render()` method.
+//!
+//! This graph does not attempt automatic texture aliasing/lifetime analysis
+//! — resources are just named `wgpu::TextureView`s registered up front in a
+//! [`RenderGraphResources`] table, which `resize()` repopulates whenever the
+//! surface size changes.
+
+use std::collections::HashMap;
+
+/// Named, graph-managed textures that passes read from and write to.
+/// Views are borrowed for the duration of one `execute` call — the actual
+/// textures are owned and resized independently (see `Renderer::resize`),
+/// and re-registered under the same slot names every frame.
+#[derive(Default)]
+pub struct RenderGraphResources<'a> {
+    textures: HashMap<&'static str, &'a wgpu::TextureView>,
+}
+
+impl<'a> RenderGraphResources<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, slot: &'static str, view: &'a wgpu::TextureView) {
+        self.textures.insert(slot, view);
+    }
+
+    pub fn get(&self, slot: &str) -> &'a wgpu::TextureView {
+        self.textures
+            .get(slot)
+            .copied()
+            .unwrap_or_else(|| panic!("render graph: no resource registered for slot '{slot}'"))
+    }
+}
+
+/// Arguments handed to a pass when the graph executes it
+pub struct PassContext<'a> {
+    pub encoder: &'a mut wgpu::CommandEncoder,
+    pub resources: &'a RenderGraphResources<'a>,
+}
+
+/// One node in the render graph
+pub trait RenderPass {
+    /// Slot names this pass samples from (as shader inputs)
+    fn reads(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Slot names this pass renders into
+    fn writes(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn execute(&self, ctx: &mut PassContext<'_>);
+}
+
+/// Ordered sequence of render passes recorded into one command encoder.
+/// Generic over `'a` so passes (usually [`ClosurePass`]es) can borrow the
+/// renderer's pipelines/buffers and the current frame's data for the
+/// duration of a single `execute` call.
+pub struct RenderGraph<'a> {
+    passes: Vec<Box<dyn RenderPass + 'a>>,
+}
+
+impl<'a> Default for RenderGraph<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> RenderGraph<'a> {
+    pub fn new() -> Self {
+        Self { passes: Vec::new() }
+    }
+
+    /// Append a pass to the end of the graph's execution order
+    pub fn add_pass(&mut self, pass: Box<dyn RenderPass + 'a>) {
+        self.passes.push(pass);
+    }
+
+    pub fn execute(&self, encoder: &mut wgpu::CommandEncoder, resources: &RenderGraphResources<'_>) {
+        for pass in &self.passes {
+            let mut ctx = PassContext { encoder, resources };
+            pass.execute(&mut ctx);
+        }
+    }
+}
+
+/// A [`RenderPass`] built from a closure, so call sites can declare a node
+/// inline instead of naming a dedicated struct for every pass
+pub struct ClosurePass<F: Fn(&mut PassContext<'_>)> {
+    reads: &'static [&'static str],
+    writes: &'static [&'static str],
+    run: F,
+}
+
+impl<F: Fn(&mut PassContext<'_>)> ClosurePass<F> {
+    pub fn new(reads: &'static [&'static str], writes: &'static [&'static str], run: F) -> Self {
+        Self { reads, writes, run }
+    }
+}
+
+impl<F: Fn(&mut PassContext<'_>)> RenderPass for ClosurePass<F> {
+    fn reads(&self) -> &'static [&'static str] {
+        self.reads
+    }
+
+    fn writes(&self) -> &'static [&'static str] {
+        self.writes
+    }
+
+    fn execute(&self, ctx: &mut PassContext<'_>) {
+        (self.run)(ctx)
+    }
+}
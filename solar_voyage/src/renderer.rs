@@ -1,8 +1,15 @@
 //! Rendering system for Solar Voyage simulation
 
+use std::collections::HashMap;
+use std::f32::consts::PI;
+
 use common::{Camera3D, GraphicsContext};
+use glam::Vec3;
 use wgpu::util::DeviceExt;
 
+use crate::disk::{AccretionDisk, Particle};
+use crate::profiler::PassProfiler;
+use crate::render_graph::{ClosurePass, RenderGraph, RenderGraphResources};
 use crate::solar_system::{CelestialBody, BodyType, SolarSystem};
 use crate::spaceship::Spaceship;
 use crate::spacetime::SpacetimeGrid;
@@ -24,6 +31,33 @@ pub struct SimulationUniform {
     pub ship_gamma: f32,
     pub ship_speed_c: f32,
     pub curvature_scale: f32,
+    /// Unit vector along the ship's velocity, the boost axis that the
+    /// post-process pass aberrates and Doppler-shifts the image around
+    pub boost_direction: [f32; 3],
+    pub _padding: f32,
+}
+
+/// Maximum number of star lights the body fragment shader can sample;
+/// extra stars beyond this are simply not lit
+pub const MAX_LIGHTS: usize = 4;
+
+/// A single star treated as a point light for Blinn-Phong shading
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LightUniform {
+    pub position: [f32; 3],
+    pub intensity: f32,
+    pub color: [f32; 3],
+    pub _padding: f32,
+}
+
+/// Fixed-size array of star lights, uploaded once per frame
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LightsUniform {
+    pub lights: [LightUniform; MAX_LIGHTS],
+    pub light_count: u32,
+    pub _padding: [u32; 3],
 }
 
 /// Body instance for GPU
@@ -69,8 +103,94 @@ impl BodyInstance {
             _padding: [0.0; 3],
         }
     }
+
+    /// Body type tag for an accretion-disk particle, distinct from the
+    /// `CelestialBody` tags above so the shader could in principle shade
+    /// disk material differently (unlit emissive point, say)
+    const DISK_PARTICLE_TYPE: u32 = 4;
+
+    pub fn from_disk_particle(particle: &Particle, color: [f32; 4]) -> Self {
+        Self {
+            position: [particle.position.x, particle.position.y, particle.position.z],
+            radius: 0.01 + particle.mass * 0.01,
+            color,
+            body_type: Self::DISK_PARTICLE_TYPE,
+            _padding: [0.0; 3],
+        }
+    }
+}
+
+/// UV-sphere mesh vertex, used by the sphere geometry LOD path in place of
+/// the camera-facing billboard quad for bodies large enough on screen to
+/// show a real silhouette
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SphereVertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+}
+
+impl SphereVertex {
+    const ATTRIBS: [wgpu::VertexAttribute; 2] = wgpu::vertex_attr_array![
+        0 => Float32x3,
+        1 => Float32x3,
+    ];
+
+    pub fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<SphereVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
+/// Generate an indexed unit UV-sphere (radius 1, centered at the origin)
+/// with the given number of latitude/longitude bands. The body fragment
+/// shader scales and translates it per instance via `BodyInstance`.
+fn generate_uv_sphere(lat_bands: u32, lon_bands: u32) -> (Vec<SphereVertex>, Vec<u32>) {
+    let mut vertices = Vec::new();
+    for lat in 0..=lat_bands {
+        let theta = lat as f32 * PI / lat_bands as f32;
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        for lon in 0..=lon_bands {
+            let phi = lon as f32 * 2.0 * PI / lon_bands as f32;
+            let (sin_phi, cos_phi) = phi.sin_cos();
+            let x = cos_phi * sin_theta;
+            let y = cos_theta;
+            let z = sin_phi * sin_theta;
+            vertices.push(SphereVertex { position: [x, y, z], normal: [x, y, z] });
+        }
+    }
+
+    let mut indices = Vec::new();
+    for lat in 0..lat_bands {
+        for lon in 0..lon_bands {
+            let first = lat * (lon_bands + 1) + lon;
+            let second = first + lon_bands + 1;
+            indices.push(first);
+            indices.push(second);
+            indices.push(first + 1);
+            indices.push(second);
+            indices.push(second + 1);
+            indices.push(first + 1);
+        }
+    }
+
+    (vertices, indices)
 }
 
+/// Latitude/longitude bands for the near, high-poly sphere LOD
+const SPHERE_LOD_HIGH_BANDS: (u32, u32) = (24, 24);
+/// Latitude/longitude bands for the mid-distance, low-poly sphere LOD
+const SPHERE_LOD_LOW_BANDS: (u32, u32) = (10, 10);
+/// Angular size (radius / distance to camera) above which a body is drawn
+/// with the high-poly sphere mesh
+const SPHERE_LOD_HIGH_THRESHOLD: f32 = 0.08;
+/// Angular size above which a body is drawn with the low-poly sphere mesh;
+/// below this it falls back to the cheap billboard quad
+const SPHERE_LOD_LOW_THRESHOLD: f32 = 0.01;
+
 /// Quad vertex
 #[repr(C)]
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
@@ -122,35 +242,101 @@ const QUAD_VERTICES: &[QuadVertex] = &[
     QuadVertex { position: [-1.0, 1.0] },
 ];
 
+/// Offscreen target the scene is rendered into before the post-process
+/// pass warps it for a relativistic observer
+const HDR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+/// Sample counts tried, in order, when resolving a requested MSAA level
+/// against what the adapter actually supports
+const SAMPLE_COUNT_CANDIDATES: [u32; 4] = [8, 4, 2, 1];
+
+/// Render graph passes profiled by `PassProfiler`, in the order their
+/// `timestamp_writes` index is assigned
+const PROFILED_PASSES: [&str; 3] = ["skybox", "main", "post"];
+
+/// Pick the largest sample count no greater than `requested` that the
+/// adapter's `TextureFormatFeatureFlags` report as supported for both the
+/// HDR color target and the depth format, falling back to 1 (no MSAA) if
+/// nothing else works, like Ruffle's wgpu backend does for `msaa_sample_count`
+fn resolve_sample_count(adapter: &wgpu::Adapter, requested: u32) -> u32 {
+    let color_flags = adapter.get_texture_format_features(HDR_FORMAT).flags;
+    let depth_flags = adapter.get_texture_format_features(wgpu::TextureFormat::Depth32Float).flags;
+
+    SAMPLE_COUNT_CANDIDATES
+        .iter()
+        .copied()
+        .filter(|&count| count <= requested)
+        .find(|&count| color_flags.sample_count_supported(count) && depth_flags.sample_count_supported(count))
+        .unwrap_or(1)
+}
+
 pub struct Renderer {
     body_pipeline: wgpu::RenderPipeline,
     trail_pipeline: wgpu::RenderPipeline,
     grid_pipeline: wgpu::RenderPipeline,
     skybox_pipeline: wgpu::RenderPipeline,
+    post_pipeline: wgpu::RenderPipeline,
+    sphere_pipeline: wgpu::RenderPipeline,
 
     quad_buffer: wgpu::Buffer,
     body_buffer: wgpu::Buffer,
+    disk_buffer: wgpu::Buffer,
     trail_buffer: wgpu::Buffer,
     grid_buffer: wgpu::Buffer,
 
+    sphere_high_vertex_buffer: wgpu::Buffer,
+    sphere_high_index_buffer: wgpu::Buffer,
+    sphere_high_index_count: u32,
+    sphere_high_instance_buffer: wgpu::Buffer,
+
+    sphere_low_vertex_buffer: wgpu::Buffer,
+    sphere_low_index_buffer: wgpu::Buffer,
+    sphere_low_index_count: u32,
+    sphere_low_instance_buffer: wgpu::Buffer,
+
     camera_buffer: wgpu::Buffer,
     sim_buffer: wgpu::Buffer,
+    light_buffer: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
 
     depth_texture: wgpu::TextureView,
 
+    hdr_view: wgpu::TextureView,
+    hdr_sampler: wgpu::Sampler,
+    post_bind_group_layout: wgpu::BindGroupLayout,
+    post_bind_group: wgpu::BindGroup,
+
+    /// Multisampled color target the skybox/main passes actually draw into;
+    /// `None` when `sample_count == 1`, in which case they draw straight
+    /// into `hdr_view` and there is nothing to resolve
+    msaa_view: Option<wgpu::TextureView>,
+    /// Resolved sample count (1, 2, 4, or 8), after falling back from
+    /// whatever was requested to what the adapter supports
+    sample_count: u32,
+
+    /// Per-pass GPU timing, `None` on adapters without `TIMESTAMP_QUERY`
+    profiler: Option<PassProfiler>,
+
     max_bodies: usize,
     max_trail_vertices: usize,
     max_grid_vertices: usize,
+    max_disk_particles: usize,
 }
 
 impl Renderer {
-    pub fn new(ctx: &GraphicsContext) -> Self {
+    /// `requested_sample_count` is validated against the adapter's supported
+    /// MSAA levels for the HDR color format and the depth format (see
+    /// `resolve_sample_count`) and silently falls back to the nearest
+    /// supported level, down to 1 (no MSAA) if multisampling isn't
+    /// supported at all.
+    pub fn new(ctx: &GraphicsContext, requested_sample_count: u32) -> Self {
         let device = &ctx.device;
+        let sample_count = resolve_sample_count(&ctx.adapter, requested_sample_count);
 
         let max_bodies = 100;
         let max_trail_vertices = 50000;
         let max_grid_vertices = 50000;
+        let max_disk_particles = 4096;
 
         let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Voyage Shader"),
@@ -172,6 +358,13 @@ impl Renderer {
             mapped_at_creation: false,
         });
 
+        let light_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Light Buffer"),
+            size: std::mem::size_of::<LightsUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // Bind group layout
         let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("Bind Group Layout"),
@@ -196,6 +389,16 @@ impl Renderer {
                     },
                     count: None,
                 },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -211,6 +414,10 @@ impl Renderer {
                     binding: 1,
                     resource: sim_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: light_buffer.as_entire_binding(),
+                },
             ],
         });
 
@@ -221,7 +428,8 @@ impl Renderer {
         });
 
         // Depth texture
-        let depth_texture = Self::create_depth_texture(device, ctx.size.width, ctx.size.height);
+        let depth_texture =
+            Self::create_depth_texture(device, ctx.size.width, ctx.size.height, sample_count);
 
         let depth_stencil_state = Some(wgpu::DepthStencilState {
             format: wgpu::TextureFormat::Depth32Float,
@@ -231,6 +439,11 @@ impl Renderer {
             bias: wgpu::DepthBiasState::default(),
         });
 
+        let multisample_state = wgpu::MultisampleState {
+            count: sample_count,
+            ..Default::default()
+        };
+
         // Body pipeline
         let body_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
             label: Some("Body Pipeline"),
@@ -253,7 +466,36 @@ impl Renderer {
             }),
             primitive: wgpu::PrimitiveState::default(),
             depth_stencil: depth_stencil_state.clone(),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: multisample_state,
+            multiview: None,
+            cache: None,
+        });
+
+        // Sphere pipeline: true UV-sphere geometry LOD path, drawn with
+        // draw_indexed in place of the body billboard quad for bodies large
+        // enough on screen to need a real silhouette and correct normals
+        let sphere_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Sphere Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_sphere"),
+                buffers: &[SphereVertex::layout(), BodyInstance::layout()],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_sphere"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: depth_stencil_state.clone(),
+            multisample: multisample_state,
             multiview: None,
             cache: None,
         });
@@ -283,7 +525,7 @@ impl Renderer {
                 ..Default::default()
             },
             depth_stencil: depth_stencil_state.clone(),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: multisample_state,
             multiview: None,
             cache: None,
         });
@@ -313,7 +555,7 @@ impl Renderer {
                 ..Default::default()
             },
             depth_stencil: depth_stencil_state.clone(),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: multisample_state,
             multiview: None,
             cache: None,
         });
@@ -340,6 +582,80 @@ impl Renderer {
             }),
             primitive: wgpu::PrimitiveState::default(),
             depth_stencil: None,
+            multisample: multisample_state,
+            multiview: None,
+            cache: None,
+        });
+
+        // Offscreen HDR target the scene renders into, sampled by the post pass
+        let hdr_view = Self::create_hdr_target(device, ctx.size.width, ctx.size.height);
+        // Multisampled target the skybox/main passes actually draw into when
+        // MSAA is active; the main pass resolves it down into `hdr_view`
+        let msaa_view = (sample_count > 1)
+            .then(|| Self::create_msaa_target(device, ctx.size.width, ctx.size.height, sample_count));
+        let hdr_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("HDR Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let post_bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Post Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let post_bind_group = Self::create_post_bind_group(device, &post_bind_group_layout, &hdr_view, &hdr_sampler);
+
+        let post_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Post Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout, &post_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        // Post-process pipeline: aberration + Doppler warp of the HDR scene,
+        // drawn as a fullscreen triangle alongside skybox_pipeline's pattern
+        let post_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Post Pipeline"),
+            layout: Some(&post_pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_post"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_post"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ctx.config.format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
             multisample: wgpu::MultisampleState::default(),
             multiview: None,
             cache: None,
@@ -359,6 +675,57 @@ impl Renderer {
             mapped_at_creation: false,
         });
 
+        // Accretion-disk particles draw through the same billboard-quad
+        // pipeline/vertex buffer as distant bodies, just with their own
+        // instance buffer since they're populated from `AccretionDisk`
+        // rather than `SolarSystem::bodies`
+        let disk_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Disk Particle Buffer"),
+            size: (std::mem::size_of::<BodyInstance>() * max_disk_particles) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let (sphere_high_verts, sphere_high_idx) =
+            generate_uv_sphere(SPHERE_LOD_HIGH_BANDS.0, SPHERE_LOD_HIGH_BANDS.1);
+        let sphere_high_vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sphere High Vertex Buffer"),
+            contents: bytemuck::cast_slice(&sphere_high_verts),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let sphere_high_index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sphere High Index Buffer"),
+            contents: bytemuck::cast_slice(&sphere_high_idx),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+        let sphere_high_index_count = sphere_high_idx.len() as u32;
+        let sphere_high_instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Sphere High Instance Buffer"),
+            size: (std::mem::size_of::<BodyInstance>() * max_bodies) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let (sphere_low_verts, sphere_low_idx) =
+            generate_uv_sphere(SPHERE_LOD_LOW_BANDS.0, SPHERE_LOD_LOW_BANDS.1);
+        let sphere_low_vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sphere Low Vertex Buffer"),
+            contents: bytemuck::cast_slice(&sphere_low_verts),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let sphere_low_index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sphere Low Index Buffer"),
+            contents: bytemuck::cast_slice(&sphere_low_idx),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+        let sphere_low_index_count = sphere_low_idx.len() as u32;
+        let sphere_low_instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Sphere Low Instance Buffer"),
+            size: (std::mem::size_of::<BodyInstance>() * max_bodies) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         let trail_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Trail Buffer"),
             size: (std::mem::size_of::<LineVertex>() * max_trail_vertices) as u64,
@@ -378,23 +745,43 @@ impl Renderer {
             trail_pipeline,
             grid_pipeline,
             skybox_pipeline,
+            post_pipeline,
+            sphere_pipeline,
             quad_buffer,
             body_buffer,
+            disk_buffer,
             trail_buffer,
             grid_buffer,
+            sphere_high_vertex_buffer,
+            sphere_high_index_buffer,
+            sphere_high_index_count,
+            sphere_high_instance_buffer,
+            sphere_low_vertex_buffer,
+            sphere_low_index_buffer,
+            sphere_low_index_count,
+            sphere_low_instance_buffer,
             camera_buffer,
             sim_buffer,
+            light_buffer,
             bind_group,
             depth_texture,
+            hdr_view,
+            hdr_sampler,
+            post_bind_group_layout,
+            post_bind_group,
+            msaa_view,
+            sample_count,
+            profiler: PassProfiler::new(device, &ctx.queue, &PROFILED_PASSES),
             max_bodies,
             max_trail_vertices,
             max_grid_vertices,
+            max_disk_particles,
         }
     }
 
-    fn create_depth_texture(device: &wgpu::Device, width: u32, height: u32) -> wgpu::TextureView {
+    fn create_hdr_target(device: &wgpu::Device, width: u32, height: u32) -> wgpu::TextureView {
         let texture = device.create_texture(&wgpu::TextureDescriptor {
-            label: Some("Depth Texture"),
+            label: Some("HDR Target"),
             size: wgpu::Extent3d {
                 width: width.max(1),
                 height: height.max(1),
@@ -403,6 +790,66 @@ impl Renderer {
             mip_level_count: 1,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
+            format: HDR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        texture.create_view(&wgpu::TextureViewDescriptor::default())
+    }
+
+    /// Multisampled color target matching `HDR_FORMAT`; only rendered into,
+    /// never sampled, so it skips `TEXTURE_BINDING`
+    fn create_msaa_target(device: &wgpu::Device, width: u32, height: u32, sample_count: u32) -> wgpu::TextureView {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("HDR MSAA Target"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format: HDR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        texture.create_view(&wgpu::TextureViewDescriptor::default())
+    }
+
+    fn create_post_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        hdr_view: &wgpu::TextureView,
+        hdr_sampler: &wgpu::Sampler,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Post Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(hdr_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(hdr_sampler),
+                },
+            ],
+        })
+    }
+
+    fn create_depth_texture(device: &wgpu::Device, width: u32, height: u32, sample_count: u32) -> wgpu::TextureView {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Depth Texture"),
+            size: wgpu::Extent3d {
+                width: width.max(1),
+                height: height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count,
+            dimension: wgpu::TextureDimension::D2,
             format: wgpu::TextureFormat::Depth32Float,
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
             view_formats: &[],
@@ -410,17 +857,50 @@ impl Renderer {
         texture.create_view(&wgpu::TextureViewDescriptor::default())
     }
 
+    /// Reallocate `buffer` at the next power-of-two element count if `needed`
+    /// would overflow `*capacity`, mirroring the capacity-doubling growth
+    /// mesh/buffer pools use elsewhere instead of silently dropping data past
+    /// a fixed cap; `*capacity` is updated to match. No-op when `needed`
+    /// already fits.
+    fn grow_buffer(
+        device: &wgpu::Device,
+        buffer: &mut wgpu::Buffer,
+        capacity: &mut usize,
+        needed: usize,
+        elem_size: usize,
+        usage: wgpu::BufferUsages,
+        label: &'static str,
+    ) {
+        if needed <= *capacity {
+            return;
+        }
+        *capacity = needed.next_power_of_two();
+        *buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: (elem_size * *capacity) as u64,
+            usage,
+            mapped_at_creation: false,
+        });
+    }
+
     pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
-        self.depth_texture = Self::create_depth_texture(device, width, height);
+        self.depth_texture = Self::create_depth_texture(device, width, height, self.sample_count);
+        self.hdr_view = Self::create_hdr_target(device, width, height);
+        self.msaa_view = (self.sample_count > 1)
+            .then(|| Self::create_msaa_target(device, width, height, self.sample_count));
+        self.post_bind_group =
+            Self::create_post_bind_group(device, &self.post_bind_group_layout, &self.hdr_view, &self.hdr_sampler);
     }
 
     pub fn update(
-        &self,
+        &mut self,
+        device: &wgpu::Device,
         queue: &wgpu::Queue,
         camera: &Camera3D,
         solar_system: &SolarSystem,
         spaceship: &Spaceship,
         grid: Option<&SpacetimeGrid>,
+        disk: Option<&AccretionDisk>,
     ) -> RenderData {
         // Update camera uniform
         let camera_uniform = CameraUniform {
@@ -431,27 +911,145 @@ impl Renderer {
         queue.write_buffer(&self.camera_buffer, 0, bytemuck::cast_slice(&[camera_uniform]));
 
         // Update simulation uniform
+        let boost_direction = if spaceship.velocity.length_squared() > 1e-12 {
+            spaceship.velocity.normalize()
+        } else {
+            Vec3::Z
+        };
         let sim_uniform = SimulationUniform {
             time: solar_system.time,
             ship_gamma: spaceship.gamma,
             ship_speed_c: spaceship.velocity_fraction_c(),
             curvature_scale: 1.0,
+            boost_direction: [boost_direction.x, boost_direction.y, boost_direction.z],
+            _padding: 0.0,
         };
         queue.write_buffer(&self.sim_buffer, 0, bytemuck::cast_slice(&[sim_uniform]));
 
-        // Update body instances
-        let mut body_instances: Vec<BodyInstance> = solar_system
-            .bodies
-            .iter()
-            .take(self.max_bodies - 1)
-            .map(BodyInstance::from_body)
-            .collect();
+        // Update star lights for Blinn-Phong shading of the other bodies
+        let mut lights = [LightUniform {
+            position: [0.0; 3],
+            intensity: 0.0,
+            color: [0.0; 3],
+            _padding: 0.0,
+        }; MAX_LIGHTS];
+        let mut light_count = 0usize;
+        for body in &solar_system.bodies {
+            if body.body_type != BodyType::Star || light_count >= MAX_LIGHTS {
+                continue;
+            }
+            lights[light_count] = LightUniform {
+                position: [body.position.x, body.position.y, body.position.z],
+                intensity: body.display_radius.max(0.1) * 4.0,
+                color: [body.color[0], body.color[1], body.color[2]],
+                _padding: 0.0,
+            };
+            light_count += 1;
+        }
+        let lights_uniform = LightsUniform {
+            lights,
+            light_count: light_count as u32,
+            _padding: [0; 3],
+        };
+        queue.write_buffer(&self.light_buffer, 0, bytemuck::cast_slice(&[lights_uniform]));
 
-        // Add spaceship as a body
+        // Bucket bodies by projected screen size: large near bodies get the
+        // high-poly sphere mesh, mid-distance ones the low-poly sphere, and
+        // distant ones fall back to the cheap billboard quad
         let ship_body: CelestialBody = spaceship.into();
-        body_instances.push(BodyInstance::from_body(&ship_body));
+        let mut sphere_high_instances: Vec<BodyInstance> = Vec::new();
+        let mut sphere_low_instances: Vec<BodyInstance> = Vec::new();
+        let mut body_instances: Vec<BodyInstance> = Vec::new();
+
+        for body in solar_system.bodies.iter().chain(std::iter::once(&ship_body)) {
+            let angular_size = body.display_radius / camera.position.distance(body.position).max(1e-4);
+            let instance = BodyInstance::from_body(body);
+            if angular_size >= SPHERE_LOD_HIGH_THRESHOLD {
+                sphere_high_instances.push(instance);
+            } else if angular_size >= SPHERE_LOD_LOW_THRESHOLD {
+                sphere_low_instances.push(instance);
+            } else {
+                body_instances.push(instance);
+            }
+        }
 
-        queue.write_buffer(&self.body_buffer, 0, bytemuck::cast_slice(&body_instances));
+        // The three instance buffers share one capacity (they were all sized
+        // off `max_bodies` at construction time), so grow it to whichever
+        // bucket needs the most room this frame and resize all three to match
+        let needed_bodies = sphere_high_instances
+            .len()
+            .max(sphere_low_instances.len())
+            .max(body_instances.len());
+        if needed_bodies > self.max_bodies {
+            let elem_size = std::mem::size_of::<BodyInstance>();
+            let usage = wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST;
+            let mut new_max = self.max_bodies;
+            Self::grow_buffer(
+                device,
+                &mut self.sphere_high_instance_buffer,
+                &mut new_max,
+                needed_bodies,
+                elem_size,
+                usage,
+                "Sphere High Instance Buffer",
+            );
+            new_max = self.max_bodies;
+            Self::grow_buffer(
+                device,
+                &mut self.sphere_low_instance_buffer,
+                &mut new_max,
+                needed_bodies,
+                elem_size,
+                usage,
+                "Sphere Low Instance Buffer",
+            );
+            new_max = self.max_bodies;
+            Self::grow_buffer(device, &mut self.body_buffer, &mut new_max, needed_bodies, elem_size, usage, "Body Buffer");
+            self.max_bodies = new_max;
+        }
+
+        if !sphere_high_instances.is_empty() {
+            queue.write_buffer(&self.sphere_high_instance_buffer, 0, bytemuck::cast_slice(&sphere_high_instances));
+        }
+        if !sphere_low_instances.is_empty() {
+            queue.write_buffer(&self.sphere_low_instance_buffer, 0, bytemuck::cast_slice(&sphere_low_instances));
+        }
+        if !body_instances.is_empty() {
+            queue.write_buffer(&self.body_buffer, 0, bytemuck::cast_slice(&body_instances));
+        }
+
+        // Accretion-disk particles, colored by temperature and Doppler shift
+        // relative to the camera (see `AccretionDisk::particle_color`), then
+        // tinted blue-white toward regions of strong spacetime curvature
+        let disk_instances: Vec<BodyInstance> = disk
+            .map(|disk| {
+                disk.particles
+                    .iter()
+                    .map(|particle| {
+                        let mut color = disk.particle_color(particle, camera.position);
+                        if let Some(grid) = grid {
+                            let curvature = grid.sample_curvature(particle.position).min(1.0);
+                            color[0] = color[0] * (1.0 - curvature) + curvature;
+                            color[1] = color[1] * (1.0 - curvature) + curvature;
+                            color[2] = color[2] * (1.0 - curvature) + curvature * 1.2;
+                        }
+                        BodyInstance::from_disk_particle(particle, color)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        if !disk_instances.is_empty() {
+            Self::grow_buffer(
+                device,
+                &mut self.disk_buffer,
+                &mut self.max_disk_particles,
+                disk_instances.len(),
+                std::mem::size_of::<BodyInstance>(),
+                wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                "Disk Particle Buffer",
+            );
+            queue.write_buffer(&self.disk_buffer, 0, bytemuck::cast_slice(&disk_instances));
+        }
 
         // Update trails
         let mut trail_vertices = Vec::new();
@@ -490,7 +1088,16 @@ impl Renderer {
             trail_ranges.push((start, count));
         }
 
-        if !trail_vertices.is_empty() && trail_vertices.len() <= self.max_trail_vertices {
+        if !trail_vertices.is_empty() {
+            Self::grow_buffer(
+                device,
+                &mut self.trail_buffer,
+                &mut self.max_trail_vertices,
+                trail_vertices.len(),
+                std::mem::size_of::<LineVertex>(),
+                wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                "Trail Buffer",
+            );
             queue.write_buffer(&self.trail_buffer, 0, bytemuck::cast_slice(&trail_vertices));
         }
 
@@ -500,7 +1107,6 @@ impl Renderer {
             let grid_lines = grid.get_line_vertices();
             let grid_vertices: Vec<LineVertex> = grid_lines
                 .iter()
-                .take(self.max_grid_vertices)
                 .map(|(pos, color)| LineVertex {
                     position: [pos.x, pos.y, pos.z],
                     color: *color,
@@ -509,17 +1115,36 @@ impl Renderer {
 
             grid_vertex_count = grid_vertices.len() as u32;
             if !grid_vertices.is_empty() {
+                Self::grow_buffer(
+                    device,
+                    &mut self.grid_buffer,
+                    &mut self.max_grid_vertices,
+                    grid_vertices.len(),
+                    std::mem::size_of::<LineVertex>(),
+                    wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                    "Grid Buffer",
+                );
                 queue.write_buffer(&self.grid_buffer, 0, bytemuck::cast_slice(&grid_vertices));
             }
         }
 
         RenderData {
             body_count: body_instances.len() as u32,
+            sphere_high_count: sphere_high_instances.len() as u32,
+            sphere_low_count: sphere_low_instances.len() as u32,
+            disk_count: disk_instances.len() as u32,
             trail_ranges,
             grid_vertex_count,
         }
     }
 
+    /// Record the frame as a small render graph: skybox and main 3D passes
+    /// write the "hdr" slot, and the post-process pass reads it back and
+    /// writes "swapchain". Inserting a new pass (bloom, picking, ...) is a
+    /// matter of pushing another node here rather than editing pass bodies.
+    /// When `sample_count > 1`, "hdr" is the multisampled target and the
+    /// main pass resolves it into "hdr_resolve" as it finishes; otherwise
+    /// both names alias the same single-sampled texture.
     pub fn render(
         &self,
         encoder: &mut wgpu::CommandEncoder,
@@ -528,12 +1153,27 @@ impl Renderer {
         show_grid: bool,
         show_trails: bool,
     ) {
-        // Skybox pass
-        {
-            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        // When MSAA is active, the skybox/main passes draw into the
+        // multisampled "hdr" slot and the main pass resolves it down into
+        // "hdr_resolve" (= `hdr_view`) for the post pass to sample; with no
+        // MSAA, "hdr" and "hdr_resolve" are the same single-sampled texture
+        // and there's nothing to resolve.
+        let color_target = self.msaa_view.as_ref().unwrap_or(&self.hdr_view);
+        let resolve_target = self.msaa_view.as_ref().map(|_| &self.hdr_view);
+
+        let mut resources = RenderGraphResources::new();
+        resources.set("hdr", color_target);
+        resources.set("hdr_resolve", &self.hdr_view);
+        resources.set("depth", &self.depth_texture);
+        resources.set("swapchain", view);
+
+        let mut graph = RenderGraph::new();
+
+        graph.add_pass(Box::new(ClosurePass::new(&[], &["hdr"], |ctx| {
+            let mut pass = ctx.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Skybox Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view,
+                    view: ctx.resources.get("hdr"),
                     resolve_target: None,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
@@ -541,36 +1181,35 @@ impl Renderer {
                     },
                 })],
                 depth_stencil_attachment: None,
-                timestamp_writes: None,
+                timestamp_writes: self.profiler.as_ref().map(|p| p.timestamp_writes(0)),
                 occlusion_query_set: None,
             });
 
             pass.set_pipeline(&self.skybox_pipeline);
             pass.set_bind_group(0, &self.bind_group, &[]);
             pass.draw(0..3, 0..1);
-        }
+        })));
 
-        // Main 3D pass
-        {
-            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        graph.add_pass(Box::new(ClosurePass::new(&[], &["hdr", "hdr_resolve", "depth"], |ctx| {
+            let mut pass = ctx.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Main Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view,
-                    resolve_target: None,
+                    view: ctx.resources.get("hdr"),
+                    resolve_target,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Load,
                         store: wgpu::StoreOp::Store,
                     },
                 })],
                 depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                    view: &self.depth_texture,
+                    view: ctx.resources.get("depth"),
                     depth_ops: Some(wgpu::Operations {
                         load: wgpu::LoadOp::Clear(1.0),
                         store: wgpu::StoreOp::Store,
                     }),
                     stencil_ops: None,
                 }),
-                timestamp_writes: None,
+                timestamp_writes: self.profiler.as_ref().map(|p| p.timestamp_writes(1)),
                 occlusion_query_set: None,
             });
 
@@ -592,7 +1231,25 @@ impl Renderer {
                 }
             }
 
-            // Bodies
+            // Bodies large enough on screen: real sphere meshes
+            if data.sphere_high_count > 0 {
+                pass.set_pipeline(&self.sphere_pipeline);
+                pass.set_bind_group(0, &self.bind_group, &[]);
+                pass.set_vertex_buffer(0, self.sphere_high_vertex_buffer.slice(..));
+                pass.set_vertex_buffer(1, self.sphere_high_instance_buffer.slice(..));
+                pass.set_index_buffer(self.sphere_high_index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+                pass.draw_indexed(0..self.sphere_high_index_count, 0, 0..data.sphere_high_count);
+            }
+            if data.sphere_low_count > 0 {
+                pass.set_pipeline(&self.sphere_pipeline);
+                pass.set_bind_group(0, &self.bind_group, &[]);
+                pass.set_vertex_buffer(0, self.sphere_low_vertex_buffer.slice(..));
+                pass.set_vertex_buffer(1, self.sphere_low_instance_buffer.slice(..));
+                pass.set_index_buffer(self.sphere_low_index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+                pass.draw_indexed(0..self.sphere_low_index_count, 0, 0..data.sphere_low_count);
+            }
+
+            // Distant bodies: cheap camera-facing billboard quad
             if data.body_count > 0 {
                 pass.set_pipeline(&self.body_pipeline);
                 pass.set_bind_group(0, &self.bind_group, &[]);
@@ -600,12 +1257,65 @@ impl Renderer {
                 pass.set_vertex_buffer(1, self.body_buffer.slice(..));
                 pass.draw(0..6, 0..data.body_count);
             }
+
+            // Accretion-disk particles: same billboard-quad path, separate
+            // instance buffer since they come from `AccretionDisk` rather
+            // than `SolarSystem::bodies`
+            if data.disk_count > 0 {
+                pass.set_pipeline(&self.body_pipeline);
+                pass.set_bind_group(0, &self.bind_group, &[]);
+                pass.set_vertex_buffer(0, self.quad_buffer.slice(..));
+                pass.set_vertex_buffer(1, self.disk_buffer.slice(..));
+                pass.draw(0..6, 0..data.disk_count);
+            }
+        })));
+
+        // Post-process pass: samples the HDR scene and warps it for a
+        // relativistic observer boosted along the ship's velocity
+        // (aberration of the view direction, Doppler shift/beaming of color)
+        graph.add_pass(Box::new(ClosurePass::new(&["hdr"], &["swapchain"], |ctx| {
+            let mut pass = ctx.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Post Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: ctx.resources.get("swapchain"),
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: self.profiler.as_ref().map(|p| p.timestamp_writes(2)),
+                occlusion_query_set: None,
+            });
+
+            pass.set_pipeline(&self.post_pipeline);
+            pass.set_bind_group(0, &self.bind_group, &[]);
+            pass.set_bind_group(1, &self.post_bind_group, &[]);
+            pass.draw(0..3, 0..1);
+        })));
+
+        graph.execute(encoder, &resources);
+
+        if let Some(profiler) = &self.profiler {
+            profiler.resolve(encoder);
         }
     }
+
+    /// Per-pass GPU timings (skybox/main/post) from the most recently
+    /// submitted frame, in milliseconds; `None` on adapters without
+    /// `Features::TIMESTAMP_QUERY`. Blocks briefly on the query readback, so
+    /// call it from a debug overlay rather than every frame unconditionally.
+    pub fn last_frame_timings(&mut self, device: &wgpu::Device) -> Option<&HashMap<&'static str, f32>> {
+        self.profiler.as_mut().map(|p| p.last_frame_timings(device))
+    }
 }
 
 pub struct RenderData {
     pub body_count: u32,
+    pub sphere_high_count: u32,
+    pub sphere_low_count: u32,
+    pub disk_count: u32,
     pub trail_ranges: Vec<(u32, u32)>,
     pub grid_vertex_count: u32,
 }
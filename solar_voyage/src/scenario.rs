@@ -0,0 +1,331 @@
+//! Rhai-scripted scenario system
+//!
+//! `App::new` otherwise hard-codes `SolarSystem::init_accurate` plus an
+//! Earth-orbit ship launch. A `.rhai` file under a `scenarios/` directory
+//! next to the executable can replace that: it exposes a `config()`
+//! function returning sim-level flags, an `init()` function describing the
+//! bodies/ship/black hole to spawn, and an optional `event()` callback the
+//! host calls each frame with a typed event, returning an action for the
+//! host to apply. This mirrors the structured-data-return pattern
+//! `gravity_sim::scene_scripts` and `atoms::scripting` use: the script
+//! never touches simulation state directly, it just describes what to do
+//! and Rust applies it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use glam::Vec3;
+use rhai::{Engine, Scope, AST};
+use thiserror::Error;
+
+use crate::solar_system::{BodyType, CelestialBody};
+
+/// Errors that can occur while loading or running a scenario script
+#[derive(Debug, Error)]
+pub enum ScenarioError {
+    #[error("failed to read scenario script {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse scenario script {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: Box<rhai::ParseError>,
+    },
+    #[error("error running `{function}` in {path}: {source}")]
+    Eval {
+        path: PathBuf,
+        function: &'static str,
+        #[source]
+        source: Box<rhai::EvalAltResult>,
+    },
+}
+
+/// Sim-level toggles a script's `config()` can set
+#[derive(Debug, Clone, Copy)]
+pub struct ScenarioConfig {
+    pub show_grid: bool,
+    pub show_trails: bool,
+    pub has_black_hole: bool,
+    pub time_scale: f32,
+}
+
+impl Default for ScenarioConfig {
+    fn default() -> Self {
+        Self {
+            show_grid: true,
+            show_trails: true,
+            has_black_hole: false,
+            time_scale: 0.5,
+        }
+    }
+}
+
+/// Where and how the ship's `Spaceship::launch_from` should be called,
+/// as described by a scenario script's `init()`
+pub struct ShipLaunch {
+    pub body: String,
+    pub direction: Vec3,
+}
+
+/// A black hole to seed via `SolarSystem::add_black_hole`, as described by
+/// a scenario script's `init()`
+pub struct BlackHoleSpec {
+    pub mass: f32,
+    pub position: Vec3,
+    pub velocity: Vec3,
+}
+
+/// Everything a scenario script's `init()` can hand back for `App::new` to
+/// apply to a fresh `SolarSystem`/`Spaceship`
+#[derive(Default)]
+pub struct ScenarioInit {
+    pub bodies: Vec<CelestialBody>,
+    pub ship_launch: Option<ShipLaunch>,
+    pub black_hole: Option<BlackHoleSpec>,
+}
+
+/// A typed event the host fires into a scenario script's `event()`
+/// callback each frame. `ShipEnteredSoi` and `Collision` describe the
+/// contract scripts can match on; only `TimeElapsed` is actually fired by
+/// `App::update` today; wiring up sphere-of-influence and collision
+/// detection is future work, same as `App::gpu_nbody` being stepped every
+/// frame without yet being read by anything.
+pub enum ScenarioEvent {
+    ShipEnteredSoi { body: String },
+    TimeElapsed { dt: f32 },
+    Collision { body: String },
+}
+
+impl ScenarioEvent {
+    fn into_map(self) -> rhai::Map {
+        let mut map = rhai::Map::new();
+        match self {
+            ScenarioEvent::ShipEnteredSoi { body } => {
+                map.insert("type".into(), "ship_entered_soi".into());
+                map.insert("body".into(), body.into());
+            }
+            ScenarioEvent::TimeElapsed { dt } => {
+                map.insert("type".into(), "time_elapsed".into());
+                map.insert("dt".into(), (dt as f64).into());
+            }
+            ScenarioEvent::Collision { body } => {
+                map.insert("type".into(), "collision".into());
+                map.insert("body".into(), body.into());
+            }
+        }
+        map
+    }
+}
+
+/// An action a scenario script's `event()` handed back for the host to
+/// apply, parsed from the map it returned
+pub enum ScenarioAction {
+    FocusBody(String),
+    SetBlackHole(bool),
+    SetTimeScale(f32),
+}
+
+fn action_from_map(map: rhai::Map) -> Option<ScenarioAction> {
+    let action = map.get("action").and_then(|v| v.clone().into_string().ok())?;
+    match action.as_str() {
+        "focus" => map
+            .get("body")
+            .and_then(|v| v.clone().into_string().ok())
+            .map(ScenarioAction::FocusBody),
+        "black_hole" => map
+            .get("enabled")
+            .and_then(|v| v.as_bool().ok())
+            .map(ScenarioAction::SetBlackHole),
+        "time_scale" => map
+            .get("value")
+            .and_then(|v| v.as_float().ok())
+            .map(|v| ScenarioAction::SetTimeScale(v as f32)),
+        _ => None,
+    }
+}
+
+fn map_f32(map: &rhai::Map, key: &str, default: f32) -> f32 {
+    map.get(key).and_then(|v| v.as_float().ok()).map(|v| v as f32).unwrap_or(default)
+}
+
+fn body_type_from_str(kind: &str) -> BodyType {
+    match kind.to_ascii_lowercase().as_str() {
+        "star" => BodyType::Star,
+        "dwarf_planet" | "dwarfplanet" => BodyType::DwarfPlanet,
+        "moon" => BodyType::Moon,
+        "asteroid" => BodyType::Asteroid,
+        "black_hole" | "blackhole" => BodyType::BlackHole,
+        _ => BodyType::Planet,
+    }
+}
+
+fn body_from_map(map: rhai::Map) -> CelestialBody {
+    let name = map.get("name").and_then(|v| v.clone().into_string().ok()).unwrap_or_else(|| "Body".to_string());
+    let kind = map.get("kind").and_then(|v| v.clone().into_string().ok()).unwrap_or_default();
+    let mass = map_f32(&map, "mass", 1e-6);
+    let radius = map_f32(&map, "radius", 0.005);
+    let color = map
+        .get("color")
+        .and_then(|v| v.clone().try_cast::<rhai::Array>())
+        .filter(|c| c.len() == 4)
+        .map(|c| {
+            let v: Vec<f32> = c.iter().map(|x| x.as_float().unwrap_or(1.0) as f32).collect();
+            [v[0], v[1], v[2], v[3]]
+        })
+        .unwrap_or([0.8, 0.8, 0.8, 1.0]);
+
+    let mut body = CelestialBody::new(&name, body_type_from_str(&kind), mass, radius, color);
+    body.position = Vec3::new(map_f32(&map, "x", 0.0), map_f32(&map, "y", 0.0), map_f32(&map, "z", 0.0));
+    body.velocity = Vec3::new(map_f32(&map, "vx", 0.0), map_f32(&map, "vy", 0.0), map_f32(&map, "vz", 0.0));
+    body
+}
+
+/// A loaded `.rhai` scenario script, ready to configure, populate, and
+/// drive a simulation
+pub struct ScenarioScript {
+    pub name: String,
+    path: PathBuf,
+    engine: Engine,
+    ast: AST,
+}
+
+impl ScenarioScript {
+    pub fn load(path: &Path) -> Result<Self, ScenarioError> {
+        let source = fs::read_to_string(path).map_err(|source| ScenarioError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let engine = Engine::new();
+        let ast = engine.compile(&source).map_err(|source| ScenarioError::Parse {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })?;
+
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("scenario")
+            .to_string();
+
+        Ok(Self {
+            name,
+            path: path.to_path_buf(),
+            engine,
+            ast,
+        })
+    }
+
+    /// Run the script's `config()` function, falling back to
+    /// `ScenarioConfig::default()` for any field it doesn't set (or if the
+    /// script has no `config()` at all)
+    pub fn config(&self) -> ScenarioConfig {
+        let mut defaults = ScenarioConfig::default();
+
+        let mut scope = Scope::new();
+        let Ok(map) = self.engine.call_fn::<rhai::Map>(&mut scope, &self.ast, "config", ()) else {
+            return defaults;
+        };
+
+        if let Some(v) = map.get("show_grid").and_then(|v| v.as_bool().ok()) {
+            defaults.show_grid = v;
+        }
+        if let Some(v) = map.get("show_trails").and_then(|v| v.as_bool().ok()) {
+            defaults.show_trails = v;
+        }
+        if let Some(v) = map.get("has_black_hole").and_then(|v| v.as_bool().ok()) {
+            defaults.has_black_hole = v;
+        }
+        if let Some(v) = map.get("time_scale").and_then(|v| v.as_float().ok()) {
+            defaults.time_scale = v as f32;
+        }
+
+        defaults
+    }
+
+    /// Run the script's `init()` function and parse its returned map into
+    /// bodies to spawn plus optional ship-launch and black-hole specs
+    pub fn init(&self) -> Result<ScenarioInit, ScenarioError> {
+        let mut scope = Scope::new();
+        let map: rhai::Map = self
+            .engine
+            .call_fn(&mut scope, &self.ast, "init", ())
+            .map_err(|source| ScenarioError::Eval {
+                path: self.path.clone(),
+                function: "init",
+                source,
+            })?;
+
+        let bodies = map
+            .get("bodies")
+            .and_then(|v| v.clone().into_typed_array::<rhai::Map>().ok())
+            .unwrap_or_default()
+            .into_iter()
+            .map(body_from_map)
+            .collect();
+
+        let ship_launch = map.get("ship").and_then(|v| v.clone().try_cast::<rhai::Map>()).map(|m| ShipLaunch {
+            body: m.get("body").and_then(|v| v.clone().into_string().ok()).unwrap_or_default(),
+            direction: Vec3::new(map_f32(&m, "dx", 0.0), map_f32(&m, "dy", 1.0), map_f32(&m, "dz", 0.0)),
+        });
+
+        let black_hole = map.get("black_hole").and_then(|v| v.clone().try_cast::<rhai::Map>()).map(|m| {
+            BlackHoleSpec {
+                mass: map_f32(&m, "mass", 10.0),
+                position: Vec3::new(map_f32(&m, "x", 0.0), map_f32(&m, "y", 0.0), map_f32(&m, "z", 0.0)),
+                velocity: Vec3::new(map_f32(&m, "vx", 0.0), map_f32(&m, "vy", 0.0), map_f32(&m, "vz", 0.0)),
+            }
+        });
+
+        Ok(ScenarioInit { bodies, ship_launch, black_hole })
+    }
+
+    /// Fire `event` into the script's `event(event)` callback and parse
+    /// whatever action it returns. `Ok(None)` both when the script has no
+    /// `event()` function and when it returns nothing for this particular
+    /// event.
+    pub fn event(&self, event: ScenarioEvent) -> Result<Option<ScenarioAction>, ScenarioError> {
+        let mut scope = Scope::new();
+        let event_map = event.into_map();
+
+        match self
+            .engine
+            .call_fn::<rhai::Dynamic>(&mut scope, &self.ast, "event", (event_map,))
+        {
+            Ok(result) => Ok(result.try_cast::<rhai::Map>().and_then(action_from_map)),
+            Err(err) if matches!(*err, rhai::EvalAltResult::ErrorFunctionNotFound(..)) => Ok(None),
+            Err(err) => Err(ScenarioError::Eval {
+                path: self.path.clone(),
+                function: "event",
+                source: err,
+            }),
+        }
+    }
+}
+
+/// Discover `.rhai` scripts in `dir`, skipping (and logging) any that fail
+/// to parse rather than aborting the whole scan
+pub fn discover_scenarios(dir: &Path) -> Vec<ScenarioScript> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut scenarios = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("rhai") {
+            continue;
+        }
+        match ScenarioScript::load(&path) {
+            Ok(scenario) => scenarios.push(scenario),
+            Err(err) => log::warn!("skipping scenario script: {err}"),
+        }
+    }
+
+    scenarios.sort_by(|a, b| a.name.cmp(&b.name));
+    scenarios
+}
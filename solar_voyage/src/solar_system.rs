@@ -5,6 +5,8 @@
 use glam::Vec3;
 use std::f32::consts::PI;
 
+use crate::ephemeris::OrbitalElements;
+
 /// Gravitational constant in AU³/(solar mass * year²)
 pub const G: f32 = 4.0 * PI * PI; // ~39.478
 
@@ -44,6 +46,11 @@ pub struct CelestialBody {
     pub trail_max_length: usize,
     pub orbital_period: f32, // In years
     pub semi_major_axis: f32, // In AU
+    /// When set, this body's position/velocity are sampled each step from
+    /// real Keplerian orbital elements instead of integrated from gravity,
+    /// so it follows its true elliptical orbit rather than accumulating
+    /// N-body drift
+    pub ephemeris: Option<OrbitalElements>,
 }
 
 impl CelestialBody {
@@ -61,9 +68,24 @@ impl CelestialBody {
             trail_max_length: 500,
             orbital_period: 0.0,
             semi_major_axis: 0.0,
+            ephemeris: None,
         }
     }
 
+    /// Place this body on its real (mildly elliptical) orbit, sampled from
+    /// Keplerian orbital elements at time `t = 0`, and remember the elements
+    /// so `SolarSystem::step` can keep sampling the true orbit over time
+    /// instead of integrating it from gravity
+    pub fn at_ephemeris(mut self, elements: OrbitalElements) -> Self {
+        self.semi_major_axis = elements.semi_major_axis;
+        self.orbital_period = elements.period_years();
+        let (position, velocity) = elements.state_at_time(0.0);
+        self.position = position;
+        self.velocity = velocity;
+        self.ephemeris = Some(elements);
+        self
+    }
+
     pub fn at_orbit(mut self, distance: f32, angle: f32, inclination: f32) -> Self {
         self.semi_major_axis = distance;
 
@@ -89,6 +111,30 @@ impl CelestialBody {
         self
     }
 
+    /// Seed this body directly from a heliocentric position (AU) and
+    /// velocity (AU/year), as published by ephemeris sources like JPL
+    /// Horizons, instead of assuming a circular orbit. `semi_major_axis` and
+    /// `orbital_period` are derived from the resulting orbital energy via
+    /// the vis-viva equation and Kepler's third law, so eccentric orbits
+    /// (Mercury, Mars) report their true elements instead of their
+    /// instantaneous radius.
+    pub fn from_state(mut self, position: Vec3, velocity: Vec3) -> Self {
+        self.position = position;
+        self.velocity = velocity;
+
+        // Vis-viva: specific orbital energy ε = v²/2 - μ/r, and the
+        // semi-major axis follows from a = -μ/(2ε), with μ = G·M_sun
+        // (M_sun = 1 solar mass in these units)
+        let r = position.length();
+        let energy = velocity.length_squared() / 2.0 - G / r;
+        self.semi_major_axis = -G / (2.0 * energy);
+
+        // Kepler's third law: T² = a³ (in years and AU around the Sun)
+        self.orbital_period = self.semi_major_axis.powf(1.5);
+
+        self
+    }
+
     pub fn with_trail_length(mut self, length: usize) -> Self {
         self.trail_max_length = length;
         self
@@ -113,6 +159,11 @@ pub struct SolarSystem {
     pub bodies: Vec<CelestialBody>,
     pub time: f32, // In years
     pub time_scale: f32,
+    /// Accelerations from the end of the previous `step`, reused as the start
+    /// of the next Verlet half-step so only one force evaluation happens per
+    /// frame instead of two. Empty whenever it's stale (freshly initialized,
+    /// or the body count changed), which `step` detects and recomputes.
+    accelerations: Vec<Vec3>,
 }
 
 impl SolarSystem {
@@ -121,6 +172,7 @@ impl SolarSystem {
             bodies: Vec::new(),
             time: 0.0,
             time_scale: 1.0, // 1 second = 1 year of simulation
+            accelerations: Vec::new(),
         }
     }
 
@@ -228,6 +280,110 @@ impl SolarSystem {
         .at_orbit(30.07, 5.8, 0.031)
         .with_trail_length(1200);
         self.bodies.push(neptune);
+
+        self.offset_momentum();
+    }
+
+    /// Initialize planets from real heliocentric state vectors (approximate
+    /// J2000.0 position/velocity, AU and AU/day, as published by JPL
+    /// Horizons) instead of `at_orbit`'s circular approximation, so
+    /// eccentric worlds like Mercury and Mars show their true
+    /// perihelion/aphelion behavior once integrated by `step`.
+    pub fn init_jpl(&mut self) {
+        self.bodies.clear();
+
+        let sun = CelestialBody::new("Sun", BodyType::Star, 1.0, 0.00465, [1.0, 0.95, 0.8, 1.0])
+            .with_trail_length(0);
+        self.bodies.push(sun);
+
+        // (name, mass, radius, color, trail length, position [AU], velocity [AU/day])
+        const AU_PER_DAY_TO_AU_PER_YEAR: f32 = 365.25;
+        let planets: &[(&str, f32, f32, [f32; 4], usize, [f32; 3], [f32; 3])] = &[
+            (
+                "Mercury", 1.66e-7, 0.0024, [0.7, 0.7, 0.7, 1.0], 200,
+                [-0.1206, -0.4452, -0.0255],
+                [0.02134, -0.00577, -0.00254],
+            ),
+            (
+                "Venus", 2.45e-6, 0.006, [0.9, 0.7, 0.5, 1.0], 300,
+                [-0.7183, -0.0308, 0.0408],
+                [0.00068, -0.02014, -0.00031],
+            ),
+            (
+                "Earth", 3.0e-6, 0.0064, [0.2, 0.4, 0.8, 1.0], 365,
+                [-0.1756, 0.9694, 0.0001],
+                [-0.01720, -0.00297, 0.0000],
+            ),
+            (
+                "Mars", 3.23e-7, 0.0034, [0.8, 0.4, 0.2, 1.0], 400,
+                [1.3907, -0.0142, -0.0345],
+                [0.00145, 0.01513, 0.00031],
+            ),
+            (
+                "Jupiter", 9.55e-4, 0.07, [0.9, 0.8, 0.6, 1.0], 600,
+                [4.8414, -1.1603, -0.1036],
+                [1.6601e-3, 7.6990e-3, -6.9046e-5],
+            ),
+            (
+                "Saturn", 2.86e-4, 0.058, [0.9, 0.85, 0.6, 1.0], 800,
+                [8.4413, 4.6883, -0.4037],
+                [-3.2569e-3, 4.9833e-3, 9.5343e-5],
+            ),
+            (
+                "Uranus", 4.37e-5, 0.025, [0.6, 0.8, 0.9, 1.0], 1000,
+                [12.5840, -15.1122, -0.1862],
+                [2.9544e-3, 2.3853e-3, -2.9596e-5],
+            ),
+            (
+                "Neptune", 5.15e-5, 0.024, [0.3, 0.4, 0.8, 1.0], 1200,
+                [29.8122, -1.7308, -0.6541],
+                [1.7005e-4, 3.1193e-3, -7.6046e-5],
+            ),
+        ];
+
+        for (name, mass, radius, color, trail, pos, vel) in planets.iter().copied() {
+            let position = Vec3::from(pos);
+            let velocity = Vec3::from(vel) * AU_PER_DAY_TO_AU_PER_YEAR;
+            let body = CelestialBody::new(name, BodyType::Planet, mass, radius, color)
+                .from_state(position, velocity)
+                .with_trail_length(trail);
+            self.bodies.push(body);
+        }
+
+        self.offset_momentum();
+    }
+
+    /// Initialize with the same planets as `init_accurate`, but placed on
+    /// their real Keplerian orbital elements instead of idealized circles.
+    /// These bodies sample their true orbit each step via `ephemeris`
+    /// rather than being integrated from mutual gravity.
+    pub fn init_ephemeris(&mut self) {
+        self.bodies.clear();
+
+        let sun = CelestialBody::new("Sun", BodyType::Star, 1.0, 0.00465, [1.0, 0.95, 0.8, 1.0])
+            .with_trail_length(0);
+        self.bodies.push(sun);
+
+        let planets: &[(&str, f32, f32, [f32; 4], usize)] = &[
+            ("Mercury", 1.66e-7, 0.0024, [0.7, 0.7, 0.7, 1.0], 200),
+            ("Venus", 2.45e-6, 0.006, [0.9, 0.7, 0.5, 1.0], 300),
+            ("Earth", 3.0e-6, 0.0064, [0.2, 0.4, 0.8, 1.0], 365),
+            ("Mars", 3.23e-7, 0.0034, [0.8, 0.4, 0.2, 1.0], 400),
+            ("Jupiter", 9.55e-4, 0.07, [0.9, 0.8, 0.6, 1.0], 600),
+            ("Saturn", 2.86e-4, 0.058, [0.9, 0.85, 0.6, 1.0], 800),
+            ("Uranus", 4.37e-5, 0.025, [0.6, 0.8, 0.9, 1.0], 1000),
+            ("Neptune", 5.15e-5, 0.024, [0.3, 0.4, 0.8, 1.0], 1200),
+        ];
+
+        for &(name, mass, radius, color, trail_length) in planets {
+            let Some(elements) = crate::ephemeris::planet_elements(name) else {
+                continue;
+            };
+            let body = CelestialBody::new(name, BodyType::Planet, mass, radius, color)
+                .at_ephemeris(elements)
+                .with_trail_length(trail_length);
+            self.bodies.push(body);
+        }
     }
 
     /// Add a rogue black hole passing through the solar system
@@ -245,22 +401,64 @@ impl SolarSystem {
         self.bodies.push(bh);
     }
 
-    /// Step the simulation using Velocity Verlet integration
-    pub fn step(&mut self, dt: f32) {
-        let dt = dt * self.time_scale;
-        self.time += dt;
-
+    /// Total mechanical energy: kinetic `Σ ½mᵢ|vᵢ|²` plus pairwise
+    /// gravitational potential `-Σ_{i<j} G·mᵢ·mⱼ/|rⱼ-rᵢ|`, using the same
+    /// softening as `compute_accelerations` so this is consistent with what
+    /// the integrator actually evolves. Tracking this over time is an
+    /// integration-quality metric: a symplectic integrator should keep it
+    /// nearly constant.
+    pub fn total_energy(&self) -> f32 {
         let n = self.bodies.len();
-        if n == 0 {
-            return;
+        let softening = 0.001;
+
+        let kinetic: f32 = self
+            .bodies
+            .iter()
+            .map(|b| 0.5 * b.mass * b.velocity.length_squared())
+            .sum();
+
+        let mut potential = 0.0;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let r = self.bodies[j].position - self.bodies[i].position;
+                let dist = (r.length_squared() + softening * softening).sqrt();
+                potential -= G * self.bodies[i].mass * self.bodies[j].mass / dist;
+            }
         }
 
-        // Softening parameter to prevent singularities
-        let softening = 0.001;
+        kinetic + potential
+    }
+
+    /// Zero the system's total linear momentum by setting the Sun's velocity
+    /// to cancel everyone else's: `v_sun = -Σ_{i != sun} mᵢvᵢ / m_sun`. Without
+    /// this, planets initialized with a circular-velocity approximation while
+    /// the Sun starts at rest leave the barycenter with a net drift, so the
+    /// whole system slowly translates out of view over time.
+    pub fn offset_momentum(&mut self) {
+        let Some(sun_index) = self.bodies.iter().position(|b| b.name == "Sun") else {
+            return;
+        };
+
+        let momentum: Vec3 = self
+            .bodies
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != sun_index)
+            .map(|(_, b)| b.mass * b.velocity)
+            .sum();
+
+        let sun_mass = self.bodies[sun_index].mass;
+        self.bodies[sun_index].velocity = -momentum / sun_mass;
+    }
 
-        // Calculate accelerations
+    /// Gravitational acceleration on each body from every other body
+    fn compute_accelerations(&self) -> Vec<Vec3> {
+        let n = self.bodies.len();
         let mut accelerations = vec![Vec3::ZERO; n];
 
+        // Softening parameter to prevent singularities
+        let softening = 0.001;
+
         for i in 0..n {
             for j in (i + 1)..n {
                 let r = self.bodies[j].position - self.bodies[i].position;
@@ -274,10 +472,161 @@ impl SolarSystem {
             }
         }
 
-        // Update positions and velocities
+        accelerations
+    }
+
+    /// Fast approximate inverse square root: an initial bit-hack estimate
+    /// (the classic Quake III constant) refined by two Newton-Raphson steps
+    /// `y = y·(1.5 - 0.5·x·y²)`, trading a little accuracy for avoiding a
+    /// true `sqrt`/division per pair in `compute_accelerations_fast`.
+    fn fast_inv_sqrt(x: f32) -> f32 {
+        let i = x.to_bits();
+        let i = 0x5f3759df - (i >> 1);
+        let mut y = f32::from_bits(i);
+        y = y * (1.5 - 0.5 * x * y * y);
+        y = y * (1.5 - 0.5 * x * y * y);
+        y
+    }
+
+    /// Vectorizable variant of `compute_accelerations`: separations for every
+    /// unordered pair are gathered into flat component arrays up front so the
+    /// inverse-cube magnitude can be computed in a tight, branch-free loop
+    /// that LLVM can auto-vectorize, then scattered back into per-body
+    /// accelerations. Uses `fast_inv_sqrt` instead of an exact `sqrt`, so
+    /// results are approximate — intended for large swarms (asteroid belts,
+    /// particle clouds) via `step_fast`, not for orbits that need exact
+    /// energy conservation.
+    fn compute_accelerations_fast(&self) -> Vec<Vec3> {
+        let n = self.bodies.len();
+        let mut accelerations = vec![Vec3::ZERO; n];
+        let softening_sq = 0.001 * 0.001;
+
+        let pair_count = n * n.saturating_sub(1) / 2;
+        let mut dx = Vec::with_capacity(pair_count);
+        let mut dy = Vec::with_capacity(pair_count);
+        let mut dz = Vec::with_capacity(pair_count);
+        let mut dist_sq = Vec::with_capacity(pair_count);
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let r = self.bodies[j].position - self.bodies[i].position;
+                dx.push(r.x);
+                dy.push(r.y);
+                dz.push(r.z);
+                dist_sq.push(r.length_squared() + softening_sq);
+            }
+        }
+
+        let mut mag = vec![0.0f32; pair_count];
+        for p in 0..pair_count {
+            let d2 = dist_sq[p];
+            mag[p] = G * Self::fast_inv_sqrt(d2) / d2;
+        }
+
+        let mut pair = 0;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let force = Vec3::new(dx[pair], dy[pair], dz[pair]) * mag[pair];
+                accelerations[i] += force * self.bodies[j].mass;
+                accelerations[j] -= force * self.bodies[i].mass;
+                pair += 1;
+            }
+        }
+
+        accelerations
+    }
+
+    /// Like `step`, but evaluates forces with `compute_accelerations_fast`
+    /// instead of the exact pairwise loop. Intended for scenes with hundreds
+    /// of bodies where the fast reciprocal-sqrt's small error is an
+    /// acceptable trade for throughput; `step` remains the default for
+    /// scenes where orbital accuracy matters.
+    pub fn step_fast(&mut self, dt: f32) {
+        let dt = dt * self.time_scale;
+        self.time += dt;
+
+        let n = self.bodies.len();
+        if n == 0 {
+            return;
+        }
+
+        if self.accelerations.len() != n {
+            self.accelerations = self.compute_accelerations_fast();
+        }
+        let accelerations_old = std::mem::take(&mut self.accelerations);
+
+        for (i, body) in self.bodies.iter_mut().enumerate() {
+            body.position += body.velocity * dt + 0.5 * accelerations_old[i] * dt * dt;
+        }
+
+        let accelerations_new = self.compute_accelerations_fast();
+
         for (i, body) in self.bodies.iter_mut().enumerate() {
-            body.velocity += accelerations[i] * dt;
-            body.position += body.velocity * dt;
+            body.velocity += 0.5 * (accelerations_old[i] + accelerations_new[i]) * dt;
+        }
+
+        self.accelerations = accelerations_new;
+
+        for body in self.bodies.iter_mut() {
+            if let Some(elements) = &body.ephemeris {
+                let (position, velocity) = elements.state_at_time(self.time);
+                body.position = position;
+                body.velocity = velocity;
+            }
+        }
+
+        for body in self.bodies.iter_mut() {
+            body.update_trail();
+        }
+    }
+
+    /// Step the simulation using true velocity Verlet integration: a drift
+    /// using the old acceleration, a recomputed acceleration at the new
+    /// positions, then a velocity update using the average of the two. This
+    /// is symplectic (energy-conserving over long timescales), unlike the
+    /// symplectic-Euler update (v += a*dt; x += v*dt) it replaces. The old
+    /// acceleration is reused from the end of the previous step via
+    /// `self.accelerations`, so steady-state stepping costs one force
+    /// evaluation per frame rather than two.
+    pub fn step(&mut self, dt: f32) {
+        let dt = dt * self.time_scale;
+        self.time += dt;
+
+        let n = self.bodies.len();
+        if n == 0 {
+            return;
+        }
+
+        if self.accelerations.len() != n {
+            self.accelerations = self.compute_accelerations();
+        }
+        let accelerations_old = std::mem::take(&mut self.accelerations);
+
+        for (i, body) in self.bodies.iter_mut().enumerate() {
+            body.position += body.velocity * dt + 0.5 * accelerations_old[i] * dt * dt;
+        }
+
+        let accelerations_new = self.compute_accelerations();
+
+        for (i, body) in self.bodies.iter_mut().enumerate() {
+            body.velocity += 0.5 * (accelerations_old[i] + accelerations_new[i]) * dt;
+        }
+
+        self.accelerations = accelerations_new;
+
+        // Ephemeris-driven bodies skip N-body integration entirely: their
+        // position/velocity are resampled straight from their orbital
+        // elements at the new simulation time, so they stay on their true
+        // orbit instead of drifting from integrator error
+        for body in self.bodies.iter_mut() {
+            if let Some(elements) = &body.ephemeris {
+                let (position, velocity) = elements.state_at_time(self.time);
+                body.position = position;
+                body.velocity = velocity;
+            }
+        }
+
+        for body in self.bodies.iter_mut() {
             body.update_trail();
         }
     }
@@ -291,6 +640,44 @@ impl SolarSystem {
     pub fn find_body_mut(&mut self, name: &str) -> Option<&mut CelestialBody> {
         self.bodies.iter_mut().find(|b| b.name == name)
     }
+
+    /// Closest body hit by the ray `ray_origin + t * ray_dir`, testing each
+    /// body as a sphere (a `BlackHole` uses its event horizon, via
+    /// `schwarzschild_radius`, rather than its much smaller visual radius)
+    /// and keeping the smallest positive `t`. Used to turn a mouse cursor
+    /// (already unprojected into a world-space ray by `Camera3D::screen_ray`)
+    /// into a selection.
+    pub fn pick(&self, ray_origin: Vec3, ray_dir: Vec3) -> Option<usize> {
+        self.bodies
+            .iter()
+            .enumerate()
+            .filter_map(|(i, body)| {
+                let radius = if body.body_type == BodyType::BlackHole {
+                    body.schwarzschild_radius()
+                } else {
+                    body.radius
+                };
+                Self::ray_sphere_hit(ray_origin, ray_dir, body.position, radius).map(|t| (t, i))
+            })
+            .min_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap())
+            .map(|(_, i)| i)
+    }
+
+    /// Nearest positive intersection distance of ray `origin + t*dir` with
+    /// the sphere at `center`, or `None` if it misses or is entirely behind
+    /// `origin`
+    fn ray_sphere_hit(origin: Vec3, dir: Vec3, center: Vec3, radius: f32) -> Option<f32> {
+        let oc = origin - center;
+        let b = oc.dot(dir);
+        let c = oc.length_squared() - radius * radius;
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let t = -b - discriminant.sqrt();
+        (t >= 0.0).then_some(t)
+    }
 }
 
 impl Default for SolarSystem {
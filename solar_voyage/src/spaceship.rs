@@ -8,7 +8,18 @@
 
 use glam::{Mat4, Quat, Vec3};
 
-use crate::solar_system::{CelestialBody, BodyType, C, G};
+use crate::solar_system::{CelestialBody, BodyType, AU_KM, C, G};
+
+/// Earth surface gravity (9.81 m/s²)
+const EARTH_G_M_S2: f64 = 9.81;
+
+/// Earth surface gravity, converted into this sim's AU/year² units so
+/// `g_force` can report felt acceleration the way a pilot would read an
+/// accelerometer
+fn earth_g_au_per_year2() -> f32 {
+    let seconds_per_year = 365.25 * 24.0 * 3600.0;
+    (EARTH_G_M_S2 * seconds_per_year * seconds_per_year / (AU_KM * 1000.0)) as f32
+}
 
 /// Spaceship state and physics
 #[derive(Debug, Clone)]
@@ -140,16 +151,14 @@ impl Spaceship {
         self.fuel -= self.fuel_consumption * self.thrust.abs() * dt;
         self.fuel = self.fuel.max(0.0);
 
-        // Apply acceleration (F = ma, but we need relativistic mass)
-        // For simplicity, we'll use classical mechanics but display relativistic effects
-        let acceleration = self.forward() * self.thrust / self.mass;
-        self.velocity += acceleration * dt;
-
-        // Cap velocity at 0.99c
-        let max_speed = C * 0.99;
-        if self.velocity.length() > max_speed {
-            self.velocity = self.velocity.normalize() * max_speed;
-        }
+        // Relativistic equation of motion: dp/dt = F, with p = γm v. Applying
+        // the thrust impulse to momentum and recovering velocity from the
+        // resulting momentum keeps |v| < c automatically — no hard clamp
+        // needed, since p → ∞ as v → c.
+        let force = self.forward() * self.thrust;
+        let momentum = self.velocity * self.mass * self.gamma.max(1.0);
+        let new_momentum = momentum + force * dt;
+        self.velocity = velocity_from_momentum(new_momentum, self.mass);
     }
 
     /// Rotate the spaceship
@@ -162,9 +171,9 @@ impl Spaceship {
         self.orientation = self.orientation.normalize();
     }
 
-    /// Update spaceship physics
-    pub fn update(&mut self, bodies: &[CelestialBody], dt: f32) {
-        // Calculate gravitational acceleration from all bodies
+    /// Gravitational acceleration on the ship from all bodies, at its
+    /// current position
+    fn gravitational_acceleration(&self, bodies: &[CelestialBody]) -> Vec3 {
         let mut acceleration = Vec3::ZERO;
 
         for body in bodies {
@@ -178,9 +187,17 @@ impl Spaceship {
             }
         }
 
-        // Update velocity and position
-        self.velocity += acceleration * dt;
-        self.position += self.velocity * dt;
+        acceleration
+    }
+
+    /// Update spaceship physics using symplectic velocity Verlet integration
+    /// instead of a plain Euler step, so orbits don't secularly drift
+    pub fn update(&mut self, bodies: &[CelestialBody], dt: f32) {
+        let accel_old = self.gravitational_acceleration(bodies);
+        self.position += self.velocity * dt + 0.5 * accel_old * dt * dt;
+
+        let accel_new = self.gravitational_acceleration(bodies);
+        self.velocity += 0.5 * (accel_old + accel_new) * dt;
 
         // Update relativistic factors
         self.gamma = self.calculate_gamma();
@@ -231,15 +248,35 @@ impl Spaceship {
         self.gamma
     }
 
+    /// Felt (proper) acceleration: what an onboard accelerometer would read.
+    /// By the equivalence principle, free-fall under gravity alone reads
+    /// zero g no matter how strong the field — only non-gravitational
+    /// forces register, which here is just the thrust term. This is why
+    /// `update`'s gravitational acceleration must stay out of this
+    /// calculation entirely.
+    pub fn proper_acceleration(&self) -> Vec3 {
+        self.forward() * (self.thrust / self.mass)
+    }
+
+    /// Felt acceleration in multiples of Earth g, so the HUD can warn when
+    /// a thrust maneuver exceeds a survivable load
+    pub fn g_force(&self) -> f32 {
+        self.proper_acceleration().length() / earth_g_au_per_year2()
+    }
+
     /// Get info string for HUD
     pub fn info_string(&self) -> String {
+        let g_force = self.g_force();
+        let g_warning = if g_force > MAX_SURVIVABLE_G { " ⚠ CREW DANGER" } else { "" };
+
         format!(
             "Speed: {:.2}% c ({:.0} km/s)\n\
              Lorentz γ: {:.4}\n\
              Time dilation: {:.4}x\n\
              Ship time: {:.2} years\n\
              Coord time: {:.2} years\n\
-             Fuel: {:.1}%",
+             Fuel: {:.1}%\n\
+             Felt g-force: {:.2}g{}",
             self.velocity_fraction_c() * 100.0,
             self.speed_km_per_s(),
             self.gamma,
@@ -247,10 +284,27 @@ impl Spaceship {
             self.proper_time,
             self.coordinate_time,
             self.fuel * 100.0,
+            g_force,
+            g_warning,
         )
     }
 }
 
+/// Sustained felt acceleration above this is considered unsurvivable for a
+/// crewed vessel (roughly human g-LOC territory for more than a few seconds)
+const MAX_SURVIVABLE_G: f32 = 9.0;
+
+/// Recover velocity from relativistic momentum p = γmv:
+/// |v| = |p|c / sqrt(m²c² + |p|²), which stays below c for any finite p
+fn velocity_from_momentum(momentum: Vec3, mass: f32) -> Vec3 {
+    let p_mag = momentum.length();
+    if p_mag < 1e-20 {
+        return Vec3::ZERO;
+    }
+    let v_mag = p_mag * C / (mass * mass * C * C + p_mag * p_mag).sqrt();
+    momentum.normalize() * v_mag
+}
+
 impl Default for Spaceship {
     fn default() -> Self {
         Self::new()
@@ -12,6 +12,25 @@ pub struct GridVertex {
     pub rest_position: Vec3,   // Position without curvature
     pub curved_position: Vec3, // Position with gravitational curvature
     pub curvature: f32,        // Local curvature intensity
+    /// Set under `CurvatureModel::Flamm` when this vertex falls inside a
+    /// body's event horizon, where the embedding surface is undefined and
+    /// `curved_position` is clamped to the horizon rim instead
+    pub inside_horizon: bool,
+}
+
+/// How `SpacetimeGrid::update` computes vertex height from the gravitating
+/// bodies: the familiar Newtonian "rubber sheet" potential well, or the
+/// true Schwarzschild equatorial embedding (Flamm's paraboloid)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurvatureModel {
+    Newtonian,
+    Flamm,
+}
+
+impl Default for CurvatureModel {
+    fn default() -> Self {
+        CurvatureModel::Newtonian
+    }
 }
 
 /// Spacetime grid for visualization
@@ -20,6 +39,7 @@ pub struct SpacetimeGrid {
     pub grid_size: usize,
     pub extent: f32,
     pub deformation_scale: f32,
+    pub curvature_model: CurvatureModel,
 }
 
 impl SpacetimeGrid {
@@ -37,6 +57,7 @@ impl SpacetimeGrid {
                     rest_position: pos,
                     curved_position: pos,
                     curvature: 0.0,
+                    inside_horizon: false,
                 });
             }
         }
@@ -46,6 +67,7 @@ impl SpacetimeGrid {
             grid_size,
             extent,
             deformation_scale: 1.0,
+            curvature_model: CurvatureModel::default(),
         }
     }
 
@@ -56,6 +78,8 @@ impl SpacetimeGrid {
         for vertex in &mut self.vertices {
             let mut total_potential = 0.0;
             let mut max_potential = 0.0;
+            let mut deepest_height: Option<f32> = None;
+            let mut inside_horizon = false;
             let pos_2d = Vec3::new(vertex.rest_position.x, 0.0, vertex.rest_position.z);
 
             for body in bodies {
@@ -82,12 +106,29 @@ impl SpacetimeGrid {
                     } else {
                         max_potential = max_potential.max(potential);
                     }
+
+                    if self.curvature_model == CurvatureModel::Flamm {
+                        // Flamm's paraboloid: z(r) = 2*sqrt(rs*(r - rs)) for
+                        // r > rs, clamped to the horizon rim (z = 0) inside
+                        // it, where the embedding is undefined
+                        let rs = body.schwarzschild_radius();
+                        let height = if r > rs {
+                            -2.0 * (rs * (r - rs)).sqrt()
+                        } else {
+                            inside_horizon = true;
+                            0.0
+                        };
+                        // Superpose by depth, not sum: wells don't add
+                        deepest_height = Some(deepest_height.map_or(height, |d| d.min(height)));
+                    }
                 }
             }
 
-            // Deformation: y = -potential * scale
-            // This creates the "rubber sheet" visualization
-            let deformation = -total_potential * self.deformation_scale;
+            let deformation = match self.curvature_model {
+                // y = -potential * scale, the familiar "rubber sheet"
+                CurvatureModel::Newtonian => -total_potential * self.deformation_scale,
+                CurvatureModel::Flamm => deepest_height.unwrap_or(0.0) * self.deformation_scale,
+            };
 
             vertex.curved_position = Vec3::new(
                 vertex.rest_position.x,
@@ -97,6 +138,7 @@ impl SpacetimeGrid {
 
             // Curvature intensity for coloring
             vertex.curvature = (max_potential / (c_squared * 0.01)).min(1.0);
+            vertex.inside_horizon = inside_horizon;
         }
     }
 
@@ -114,6 +156,10 @@ impl SpacetimeGrid {
                 let v1 = &self.vertices[idx1];
                 let v2 = &self.vertices[idx2];
 
+                if v1.inside_horizon || v2.inside_horizon {
+                    continue;
+                }
+
                 let color1 = curvature_color(v1.curvature);
                 let color2 = curvature_color(v2.curvature);
 
@@ -131,6 +177,10 @@ impl SpacetimeGrid {
                 let v1 = &self.vertices[idx1];
                 let v2 = &self.vertices[idx2];
 
+                if v1.inside_horizon || v2.inside_horizon {
+                    continue;
+                }
+
                 let color1 = curvature_color(v1.curvature);
                 let color2 = curvature_color(v2.curvature);
 
@@ -188,7 +238,9 @@ impl LensingEffect {
         Self { rays: Vec::new() }
     }
 
-    /// Cast rays around a black hole to show lensing
+    /// Cast rays around a black hole to show lensing, each integrated along
+    /// its exact equatorial Schwarzschild null geodesic (see
+    /// `trace_geodesic`) rather than nudged by an ad-hoc deflection formula
     pub fn trace_around_black_hole(
         &mut self,
         black_hole: &CelestialBody,
@@ -220,47 +272,96 @@ impl LensingEffect {
             // Initial direction: toward observer with slight offset
             let to_observer = (observer_pos - start).normalize();
 
-            let mut ray = LensedRay {
-                path: Vec::new(),
-                color: [1.0, 0.8, 0.3, 0.5],
+            let Some(geodesic) = trace_geodesic(bh_pos, start, to_observer, rs, steps) else {
+                continue; // purely radial ray: no well-defined orbital plane
             };
 
-            // Trace the ray
-            let mut pos = start;
-            let mut dir = to_observer;
-            let step_size = rs * 0.1;
+            if geodesic.path.len() > 1 {
+                self.rays.push(LensedRay {
+                    path: geodesic.path,
+                    color: geodesic.color,
+                });
+            }
+        }
+    }
+}
 
-            for _ in 0..steps {
-                ray.path.push(pos);
+/// Angular step size (radians) for the RK4 integration of a photon's
+/// azimuthal equation of motion; small enough to resolve photon-sphere
+/// winding without needing an excessive step count
+const GEODESIC_DPHI: f32 = 0.02;
 
-                let r_vec = pos - bh_pos;
-                let r = r_vec.length();
+/// A traced photon path plus whether it ended up captured (red) or simply
+/// ran out of steps/escaped
+struct GeodesicTrace {
+    path: Vec<Vec3>,
+    color: [f32; 4],
+}
 
-                // Inside event horizon
-                if r < rs * 1.1 {
-                    ray.color = [0.5, 0.0, 0.0, 0.3]; // Red for captured
-                    break;
-                }
+/// Integrate one photon's equatorial Schwarzschild null geodesic.
+///
+/// Working in the plane spanned by the ray's starting radius vector and
+/// its direction, `u = 1/r` obeys `d²u/dφ² + u = (3/2)·r_s·u²` (Binet's
+/// equation for a massless particle). Starting from the impact parameter
+/// `b = |r_vec × dir|` and the angle `α` between the outward radial
+/// direction and the ray, `u(0) = 1/r_start` and `du/dφ(0) = -cos(α)/b`,
+/// integrated forward in φ with RK4. The path terminates when the photon
+/// falls inside the Schwarzschild radius (captured, colored red), when it
+/// escapes to infinity (`u <= 0`), or after `max_steps`.
+///
+/// Returns `None` for a purely radial ray, where `r_vec` and `dir` are
+/// parallel and the orbital plane (hence `b`) is undefined.
+fn trace_geodesic(bh_pos: Vec3, start: Vec3, dir: Vec3, rs: f32, max_steps: usize) -> Option<GeodesicTrace> {
+    let r_vec = start - bh_pos;
+    let r0 = r_vec.length();
+    let e1 = r_vec / r0;
+
+    let normal = r_vec.cross(dir);
+    if normal.length_squared() < 1e-12 {
+        return None;
+    }
+    let b = normal.length();
+    let e2 = normal.normalize().cross(e1).normalize();
+    let cos_alpha = e1.dot(dir);
 
-                // Gravitational deflection
-                let r_hat = r_vec / r;
-                let deflection_strength = rs / (r * r) * 2.0;
-                let deflection = -r_hat * deflection_strength * step_size;
+    let mut u = 1.0 / r0;
+    let mut v = -cos_alpha / b;
+    let mut phi = 0.0f32;
 
-                dir = (dir + deflection).normalize();
-                pos += dir * step_size;
+    let du_dphi = |_u: f32, v: f32| v;
+    let dv_dphi = |u: f32, _v: f32| -u + 1.5 * rs * u * u;
 
-                // Stop if far from black hole
-                if r > rs * 20.0 {
-                    break;
-                }
-            }
+    let mut path = Vec::with_capacity(max_steps);
+    let mut color = [1.0, 0.8, 0.3, 0.5];
 
-            if ray.path.len() > 1 {
-                self.rays.push(ray);
-            }
+    for _ in 0..max_steps {
+        let r = 1.0 / u;
+        path.push(bh_pos + (phi.cos() * e1 + phi.sin() * e2) * r);
+
+        if r < rs {
+            color = [0.5, 0.0, 0.0, 0.3]; // captured
+            break;
         }
+        if u <= 0.0 {
+            break; // escaped to infinity
+        }
+
+        let h = GEODESIC_DPHI;
+        let k1u = du_dphi(u, v);
+        let k1v = dv_dphi(u, v);
+        let k2u = du_dphi(u + 0.5 * h * k1u, v + 0.5 * h * k1v);
+        let k2v = dv_dphi(u + 0.5 * h * k1u, v + 0.5 * h * k1v);
+        let k3u = du_dphi(u + 0.5 * h * k2u, v + 0.5 * h * k2v);
+        let k3v = dv_dphi(u + 0.5 * h * k2u, v + 0.5 * h * k2v);
+        let k4u = du_dphi(u + h * k3u, v + h * k3v);
+        let k4v = dv_dphi(u + h * k3u, v + h * k3v);
+
+        u += h / 6.0 * (k1u + 2.0 * k2u + 2.0 * k3u + k4u);
+        v += h / 6.0 * (k1v + 2.0 * k2v + 2.0 * k3v + k4v);
+        phi += h;
     }
+
+    Some(GeodesicTrace { path, color })
 }
 
 impl Default for LensingEffect {